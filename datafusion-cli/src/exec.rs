@@ -39,6 +39,7 @@ use datafusion::logical_expr::{DdlStatement, LogicalPlan};
 use datafusion::physical_plan::{collect, execute_stream, ExecutionPlanProperties};
 use datafusion::prelude::SessionContext;
 use datafusion::sql::parser::{DFParser, Statement};
+use datafusion::sql::span::with_sql_span;
 use datafusion::sql::sqlparser::dialect::dialect_from_str;
 
 use datafusion::common::FileType;
@@ -76,9 +77,9 @@ pub async fn exec_from_lines(
                 let line = line.trim_end();
                 query.push_str(line);
                 if line.ends_with(';') {
-                    match exec_and_print(ctx, print_options, query).await {
+                    match exec_and_print(ctx, print_options, query.clone()).await {
                         Ok(_) => {}
-                        Err(err) => eprintln!("{err}"),
+                        Err(err) => eprintln!("{}", format_error(&err, &query)),
                     }
                     query = "".to_owned();
                 } else {
@@ -169,9 +170,9 @@ pub async fn exec_from_repl(
                 for line in lines {
                     rl.add_history_entry(line.trim_end())?;
                     tokio::select! {
-                        res = exec_and_print(ctx, print_options, line) => match res {
+                        res = exec_and_print(ctx, print_options, line.clone()) => match res {
                             Ok(_) => {}
-                            Err(err) => eprintln!("{err}"),
+                            Err(err) => eprintln!("{}", format_error(&err, &line)),
                         },
                         _ = signal::ctrl_c() => {
                             println!("^C");
@@ -219,9 +220,13 @@ async fn exec_and_print(
         )
     })?;
 
-    let statements = DFParser::parse_sql_with_dialect(&sql, dialect.as_ref())?;
+    // errors in the statements refer to the part of them they are about
+    let statements = DFParser::parse_sql_with_dialect(&sql, dialect.as_ref())
+        .map_err(|e| with_sql_span(e.into(), &sql, dialect.as_ref()))?;
     for statement in statements {
-        let plan = create_plan(ctx, statement).await?;
+        let plan = create_plan(ctx, statement)
+            .await
+            .map_err(|e| with_sql_span(e, &sql, dialect.as_ref()))?;
 
         // For plans like `Explain` ignore `MaxRows` option and always display all rows
         let should_ignore_maxrows = matches!(
@@ -252,6 +257,27 @@ async fn exec_and_print(
     Ok(())
 }
 
+/// Returns the message of `err`, followed by the line of `sql` its span
+/// starts at with the span underlined, if `err` has a span
+fn format_error(err: &DataFusionError, sql: &str) -> String {
+    let message = err.to_string();
+    let Some(span) = err.span() else {
+        return message;
+    };
+    let line = usize::try_from(span.start.line)
+        .ok()
+        .and_then(|line| sql.lines().nth(line.checked_sub(1)?));
+    match line {
+        Some(line) => {
+            let column = usize::try_from(span.start.column).unwrap_or(0);
+            let indent = " ".repeat(column.saturating_sub(1));
+            let underline = "^".repeat(span.text.chars().count().max(1));
+            format!("{message}\n{line}\n{indent}{underline}")
+        }
+        None => message,
+    }
+}
+
 async fn create_plan(
     ctx: &mut SessionContext,
     statement: Statement,
@@ -352,6 +378,9 @@ mod tests {
 
     use datafusion::common::config::FormatOptions;
     use datafusion::common::plan_err;
+    use datafusion::common::{unqualified_field_not_found, DFSchema};
+    use datafusion::sql::sqlparser::dialect::GenericDialect;
+    use datafusion::sql::sqlparser::parser::ParserError;
 
     use url::Url;
 
@@ -552,6 +581,61 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn format_error_with_span() {
+        let sql = "SELECT a\nFRM t";
+        let err = DataFusionError::SQL(
+            ParserError::ParserError(
+                "Expected end of statement, found: FRM at Line: 2, Column 1".to_string(),
+            ),
+            None,
+        );
+        // the location is only rendered from the span of the error
+        assert_eq!(format_error(&err, sql), err.to_string());
+
+        let err = with_sql_span(err, sql, &GenericDialect {});
+        assert_eq!(
+            format_error(&err, sql),
+            "SQL error: ParserError(\"Expected end of statement, found: FRM at Line: 2, Column 1\")\
+             \nFRM t\
+             \n^^^"
+        );
+
+        let sql = "SELECT a FROM t WHERE abc = 1";
+        let err = with_sql_span(
+            unqualified_field_not_found("abc", &DFSchema::empty()),
+            sql,
+            &GenericDialect {},
+        );
+        assert_eq!(
+            format_error(&err, sql).lines().skip(1).collect::<Vec<_>>(),
+            ["SELECT a FROM t WHERE abc = 1", "                      ^^^"]
+        );
+
+        // errors without a span
+        let err = plan_datafusion_err!("table 't' not found");
+        assert_eq!(format_error(&err, sql), err.to_string());
+    }
+
+    #[tokio::test]
+    async fn unknown_column_span() -> Result<()> {
+        let mut ctx = SessionContext::new();
+        let sql = "SELECT a\nFROM (VALUES (1)) AS t(a)\nWHERE b > 1".to_string();
+        let print_options = PrintOptions {
+            format: PrintFormat::Table,
+            quiet: true,
+            maxrows: MaxRows::Unlimited,
+            color: false,
+        };
+        let err = exec_and_print(&mut ctx, &print_options, sql)
+            .await
+            .unwrap_err();
+        let span = err.span().expect("span of the unknown column");
+        assert_eq!((span.start.line, span.start.column), (3, 7));
+        assert_eq!(span.text, "b");
+        Ok(())
+    }
+
     #[tokio::test]
     async fn create_external_table_local_file() -> Result<()> {
         let location = "path/to/file.parquet";
@@ -23,6 +23,7 @@ use std::fmt::{self, Display};
 use std::str::FromStr;
 
 use crate::error::_config_err;
+use crate::file_options::csv_writer::CsvQuoteStyle;
 use crate::parsers::CompressionTypeVariant;
 use crate::{DataFusionError, FileType, Result};
 
@@ -199,10 +200,26 @@ config_namespace! {
         /// When set to true, SQL parser will normalize ident (convert ident to lowercase when not quoted)
         pub enable_ident_normalization: bool, default = true
 
+        /// When set to true, a column identifier that matches no column exactly
+        /// refers to the column whose name matches it case insensitively, if
+        /// there is exactly one such column name among the columns of its
+        /// relation, or of all relations if it is unqualified. This applies to
+        /// identifiers in SQL and to columns referenced with the DataFrame API,
+        /// e.g. with `col`. It allows mixed case column names, such as those of
+        /// parquet files, to be referenced without quoting them
+        pub enable_case_insensitive_columns: bool, default = false
+
         /// Configure the SQL dialect used by DataFusion's parser; supported values include: Generic,
         /// MySQL, PostgreSQL, Hive, SQLite, Snowflake, Redshift, MsSQL, ClickHouse, BigQuery, and Ansi.
         pub dialect: String, default = "generic".to_string()
 
+        /// Specifies how to handle projections and joins whose output contains several
+        /// columns with the same name, e.g. `SELECT *` over a join of tables sharing
+        /// column names. Supported values are `keep` (keep the duplicate names), `error`
+        /// (return a planning error), `suffix` (rename later duplicates to `name_1`,
+        /// `name_2`, ...) and `qualify` (rename duplicates to `relation.name`).
+        pub duplicate_column_names: String, default = "keep".to_string()
+
     }
 }
 
@@ -268,6 +285,12 @@ config_namespace! {
         /// batches and merged.
         pub sort_in_place_threshold_bytes: usize, default = 1024 * 1024
 
+        /// The maximum number of sorted runs spilled to disk that a sort
+        /// merges at once. If a sort spills more runs, they are first merged
+        /// into fewer, larger runs, in as many passes as needed, which bounds
+        /// the number of open files and the memory for reading them.
+        pub sort_spill_merge_fan_in: usize, default = 64
+
         /// Number of files to read in parallel when inferring schema and statistics
         pub meta_fetch_concurrency: usize, default = 32
 
@@ -295,8 +318,33 @@ config_namespace! {
         /// tables (e.g. `/table/year=2021/month=01/data.parquet`).
         pub listing_table_ignore_subdirectory: bool, default = true
 
+        /// Should the schemas of the files of a listing table be merged when
+        /// inferring its schema, rather than requiring the files to have
+        /// compatible schemas. Columns missing from some files are read as
+        /// nulls, and columns of different types are widened to a common type,
+        /// e.g. `Int32` and `Int64` to `Int64`.
+        pub listing_table_schema_evolution: bool, default = false
+
+        /// Should the types of the partition columns of a listing table created
+        /// without a schema be inferred from the partition values, rather than
+        /// being strings. Columns of which all values are integers are `Int64`,
+        /// and columns of which all values are dates are `Date32`.
+        pub listing_table_infer_partition_types: bool, default = false
+
         /// Should DataFusion support recursive CTEs
         pub enable_recursive_ctes: bool, default = true
+
+        /// Sets the compression codec used for record batches buffered in the
+        /// channels of `RepartitionExec`. Compressing in-flight batches trades
+        /// CPU time for lower peak memory when consumers are slower than
+        /// producers. Supported values are `lz4`, `none`, or unset (the
+        /// default) for no compression.
+        pub repartition_compression: Option<String>, default = None
+
+        /// When set to true, a panic raised while executing a partition of a
+        /// plan through `collect`, `execute_stream` and friends is returned as
+        /// an execution error instead of unwinding into the caller
+        pub catch_panics: bool, default = true
     }
 }
 
@@ -470,6 +518,12 @@ config_namespace! {
         /// emitting groups once the limit is reached, before all rows in the group are read.
         pub enable_distinct_aggregation_soft_limit: bool, default = true
 
+        /// When set to true, grouped aggregations without aggregate expressions
+        /// whose input is sorted on the group keys, such as `SELECT DISTINCT`
+        /// on sorted data, are replaced by a `DedupExec` that removes
+        /// consecutive duplicate rows in bounded memory.
+        pub enable_sorted_distinct_dedup: bool, default = true
+
         /// When set to true, the physical plan optimizer will try to add round robin
         /// repartitioning to increase parallelism to leverage more CPU cores
         pub enable_round_robin_repartition: bool, default = true
@@ -484,6 +538,43 @@ config_namespace! {
         /// predicate push down.
         pub filter_null_join_keys: bool, default = false
 
+        /// When set to true, the optimizer will compute equijoin keys that are
+        /// expressions (such as implicit casts between `Int32` and `Int64` keys)
+        /// in projections below the join, once per input, and will cast
+        /// dictionary-encoded keys to a common dictionary type rather than
+        /// decoding them.
+        pub normalize_join_keys: bool, default = false
+
+        /// When set to true, the optimizer will replace an equijoin with a small
+        /// `VALUES` list by an `IN` list filter on the other join input, which can
+        /// then be pushed down into its scan, removing the join entirely
+        pub enable_semi_join_reduction: bool, default = false
+
+        /// The maximum number of rows of a `VALUES` list that will be turned into
+        /// an `IN` list filter by the semi-join reduction
+        pub semi_join_reduction_max_values: usize, default = 128
+
+        /// When set to true, the physical plan optimizer will read file scans that
+        /// occur several times in a plan with the same files, projection and
+        /// filters only once, sharing the results between all occurrences. The
+        /// results are buffered in memory until every occurrence has read them.
+        pub enable_scan_reuse: bool, default = false
+
+        /// When set to true, the physical plan optimizer will execute subplans that
+        /// occur several times in a plan, such as a common table expression referenced
+        /// more than once, only once, sharing the results between all occurrences.
+        /// Only subplans containing an aggregation, join, window or sort are shared,
+        /// as cheaper subplans are faster to recompute than to buffer. The results
+        /// are buffered in memory until every occurrence has read them.
+        pub materialize_ctes: bool, default = false
+
+        /// When set to true, comparisons and LIKE matches between a dictionary
+        /// encoded column and a literal of its value type keep the column
+        /// dictionary encoded, and are evaluated once per distinct dictionary
+        /// value instead of once per row. When set to false, the column is
+        /// decoded to its value type before the comparison.
+        pub enable_dictionary_aware_comparisons: bool, default = true
+
         /// Should DataFusion repartition data using the aggregate keys to execute aggregates
         /// in parallel using the provided `target_partitions` level
         pub repartition_aggregations: bool, default = true
@@ -510,7 +601,8 @@ config_namespace! {
         /// If set to `true`, all files will be repartitioned evenly (i.e., a single large file
         /// might be partitioned into smaller chunks) for parallel scanning.
         /// If set to `false`, different files will be read in parallel, but repartitioning won't
-        /// happen within a single file.
+        /// happen within a single file. Parquet files are split between their row groups, and
+        /// within their row groups between data pages if the files have a page index.
         pub repartition_file_scans: bool, default = true
 
         /// Should DataFusion repartition data using the partitions keys to execute window
@@ -567,10 +659,38 @@ config_namespace! {
         /// will be collected into a single partition
         pub hash_join_single_partition_threshold_rows: usize, default = 1024 * 128
 
+        /// When set to true, hash joins whose build side has no statistics to
+        /// choose the partition mode with are planned as an `AdaptiveJoinExec`,
+        /// which reads the build side first at execution time. It is collected
+        /// into a single partition if it is under both the
+        /// `hash_join_single_partition_threshold` and
+        /// `hash_join_single_partition_threshold_rows` thresholds, and both
+        /// inputs are hash repartitioned otherwise
+        pub adaptive_join_selection: bool, default = false
+
+        /// When set to true, chains of inner hash joins that collect their
+        /// build sides into a single partition and all join on columns of
+        /// the same probe input, as in star schema queries, are planned as a
+        /// single `MultiHashJoinExec`. It probes the hash tables of all build
+        /// sides in one pass over the probe input, without materializing the
+        /// results of the intermediate joins. This feature is experimental
+        pub enable_multi_hash_join: bool, default = false
+
         /// The default filter selectivity used by Filter Statistics
         /// when an exact selectivity cannot be determined. Valid values are
         /// between 0 (no selectivity) and 100 (all rows are selected).
         pub default_filter_selectivity: u8, default = 20
+
+        /// When set to true, the built-in physical optimizer rules are
+        /// skipped for point queries: plans that only filter, project and
+        /// limit a scan of a single file, or of a single partition of another
+        /// source, with comparisons of columns to literals. Parquet files must
+        /// prune their row groups with the filter pushed into the scan. Such
+        /// plans are already executable, and skipping the optimizer passes
+        /// reduces the planning latency of high QPS lookups. Rules added to
+        /// the session, e.g. with `SessionState::add_physical_optimizer_rule`,
+        /// still run
+        pub point_query_fast_path: bool, default = false
     }
 }
 
@@ -593,6 +713,10 @@ config_namespace! {
 
         /// When set to true, the explain statement will print the partition sizes
         pub show_sizes: bool, default = true
+
+        /// When set to true, the verbose explain statement will print the time
+        /// spent in every logical optimizer rule after the name of the rule
+        pub show_optimizer_timing: bool, default = false
     }
 }
 
@@ -965,6 +1089,17 @@ impl ConfigField for u8 {
     }
 }
 
+impl ConfigField for CsvQuoteStyle {
+    fn visit<V: Visit>(&self, v: &mut V, key: &str, description: &'static str) {
+        v.some(key, self, description)
+    }
+
+    fn set(&mut self, _: &str, value: &str) -> Result<()> {
+        *self = CsvQuoteStyle::from_str(value)?;
+        Ok(())
+    }
+}
+
 impl ConfigField for CompressionTypeVariant {
     fn visit<V: Visit>(&self, v: &mut V, key: &str, description: &'static str) {
         v.some(key, self, description)
@@ -1530,6 +1665,9 @@ config_namespace! {
         pub timestamp_tz_format: Option<String>,  default = None
         pub time_format: Option<String>,  default = None
         pub null_value: Option<String>,  default = None
+        pub double_quote: Option<bool>,  default = None
+        pub quote_style: CsvQuoteStyle,  default = CsvQuoteStyle::Minimal
+        pub newlines_in_values: bool, default = false
     }
 }
 
@@ -1594,6 +1732,14 @@ impl CsvOptions {
         self
     }
 
+    /// Set true to indicate that quoted values may contain newlines, so
+    /// that files are not split to be read in parallel.
+    /// - default to false
+    pub fn with_newlines_in_values(mut self, newlines_in_values: bool) -> Self {
+        self.newlines_in_values = newlines_in_values;
+        self
+    }
+
     /// The delimiter character.
     pub fn delimiter(&self) -> u8 {
         self.delimiter
@@ -1608,6 +1754,21 @@ impl CsvOptions {
     pub fn escape(&self) -> Option<u8> {
         self.escape
     }
+
+    /// Whether quotes in quoted fields are escaped by doubling them when
+    /// writing, rather than with the escape character.
+    /// - default to true
+    pub fn with_double_quote(mut self, double_quote: bool) -> Self {
+        self.double_quote = Some(double_quote);
+        self
+    }
+
+    /// Which fields are quoted when writing.
+    /// - default to `CsvQuoteStyle::Minimal`
+    pub fn with_quote_style(mut self, quote_style: CsvQuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
 }
 
 config_namespace! {
@@ -1615,6 +1776,16 @@ config_namespace! {
     pub struct JsonOptions {
         pub compression: CompressionTypeVariant, default = CompressionTypeVariant::UNCOMPRESSED
         pub schema_infer_max_rec: usize, default = 100
+        pub explicit_nulls: bool, default = false
+        /// When writing, start a new output file once the current file has
+        /// this many bytes
+        pub max_file_size_bytes: Option<usize>, default = None
+        /// When writing, start a new output file once the current file has
+        /// been open for this many milliseconds
+        pub rotation_interval_ms: Option<u64>, default = None
+        /// When writing, flush the current output file every this many
+        /// milliseconds
+        pub flush_interval_ms: Option<u64>, default = None
     }
 }
 
@@ -1626,6 +1797,7 @@ pub enum FormatOptions {
     PARQUET(TableParquetOptions),
     AVRO,
     ARROW,
+    ORC,
 }
 impl Display for FormatOptions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1636,6 +1808,7 @@ impl Display for FormatOptions {
             FormatOptions::PARQUET(_) => "parquet",
             FormatOptions::AVRO => "avro",
             FormatOptions::ARROW => "arrow",
+            FormatOptions::ORC => "orc",
         };
         write!(f, "{}", out)
     }
@@ -1650,6 +1823,7 @@ impl From<FileType> for FormatOptions {
             FileType::PARQUET => FormatOptions::PARQUET(TableParquetOptions::default()),
             FileType::CSV => FormatOptions::CSV(CsvOptions::default()),
             FileType::JSON => FormatOptions::JSON(JsonOptions::default()),
+            FileType::ORC => FormatOptions::ORC,
         }
     }
 }
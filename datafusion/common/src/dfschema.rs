@@ -541,6 +541,37 @@ impl DFSchema {
         self.qualified_field_with_name(column.relation.as_ref(), &column.name)
     }
 
+    /// Returns `column` with the name of the field it refers to when names are
+    /// compared case insensitively, if it refers to no field exactly.
+    ///
+    /// Only the fields of the relation of `column` are considered if it is
+    /// qualified. `column` is returned unchanged if no field matches, or if
+    /// fields with different names match, such as `Foo` and `FOO` for `foo`.
+    pub fn resolve_column_ignore_case(&self, column: Column) -> Column {
+        if self.is_column_from_schema(&column) {
+            return column;
+        }
+        let mut names = self
+            .iter()
+            .filter(|(qualifier, field)| {
+                let same_relation = match (&column.relation, qualifier) {
+                    (Some(relation), Some(qualifier)) => relation.resolved_eq(qualifier),
+                    (Some(_), None) => false,
+                    (None, _) => true,
+                };
+                same_relation && field.name().eq_ignore_ascii_case(&column.name)
+            })
+            .map(|(_, field)| field.name());
+        match names.next() {
+            // the fields of several relations may share the matching name
+            Some(first) if names.all(|name| name == first) => Column {
+                relation: column.relation.clone(),
+                name: first.clone(),
+            },
+            _ => column,
+        }
+    }
+
     /// Find if the field exists with the given name
     pub fn has_column_with_unqualified_name(&self, name: &str) -> bool {
         self.fields().iter().any(|field| field.name() == name)
@@ -1308,6 +1339,54 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn resolve_column_ignore_case() -> Result<()> {
+        let t1 = DFSchema::try_from_qualified_schema(
+            "t1",
+            &Schema::new(vec![
+                Field::new("Foo", DataType::Int32, true),
+                Field::new("bar", DataType::Int32, true),
+            ]),
+        )?;
+        let t2 = DFSchema::try_from_qualified_schema(
+            "t2",
+            &Schema::new(vec![
+                Field::new("FOO", DataType::Int32, true),
+                Field::new("BAR", DataType::Int32, true),
+            ]),
+        )?;
+        let schema = t1.join(&t2)?;
+
+        // exact matches are unchanged
+        let col = Column::from_qualified_name("t1.bar");
+        assert_eq!(schema.resolve_column_ignore_case(col.clone()), col);
+        // only the fields of the qualifier are considered
+        assert_eq!(
+            schema.resolve_column_ignore_case(Column::from_qualified_name("t1.foo")),
+            Column::from_qualified_name("t1.Foo")
+        );
+        assert_eq!(
+            schema.resolve_column_ignore_case(Column::from_qualified_name("t2.foo")),
+            Column::from_qualified_name("t2.FOO")
+        );
+        // ambiguous and missing columns are unchanged
+        let col = Column::from_name("foo");
+        assert_eq!(schema.resolve_column_ignore_case(col.clone()), col);
+        let col = Column::from_qualified_name("t3.foo");
+        assert_eq!(schema.resolve_column_ignore_case(col.clone()), col);
+
+        // the fields of several relations may share the matching name
+        let schema = t2.join(&DFSchema::try_from_qualified_schema(
+            "t3",
+            &Schema::new(vec![Field::new("FOO", DataType::Int32, true)]),
+        )?)?;
+        assert_eq!(
+            schema.resolve_column_ignore_case(Column::from_name("foo")),
+            Column::from_name("FOO")
+        );
+        Ok(())
+    }
+
     fn test_schema_2() -> Schema {
         Schema::new(vec![
             Field::new("c100", DataType::Boolean, true),
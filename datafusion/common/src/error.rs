@@ -33,6 +33,7 @@ use arrow::error::ArrowError;
 #[cfg(feature = "parquet")]
 use parquet::errors::ParquetError;
 use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::Location;
 
 /// Result type for operations that could result in an [DataFusionError]
 pub type Result<T, E = DataFusionError> = result::Result<T, E>;
@@ -125,6 +126,22 @@ pub enum DataFusionError {
     /// Errors from either mapping LogicalPlans to/from Substrait plans
     /// or serializing/deserializing protobytes to Substrait plans
     Substrait(String),
+    /// Error in a SQL statement, such as a syntax error or an unknown
+    /// column, with the span of the statement it refers to.
+    ///
+    /// The message is the one of the wrapped error, so that clients having
+    /// the statement can render the span, see [`DataFusionError::span`]
+    WithSpan(Box<DataFusionError>, Box<SqlSpan>),
+}
+
+/// The part of a SQL statement an error refers to, such as an unexpected
+/// token or an unknown column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqlSpan {
+    /// Line and column of the start of the span, counting from 1
+    pub start: Location,
+    /// Text of the span in the statement
+    pub text: String,
 }
 
 #[macro_export]
@@ -338,6 +355,7 @@ impl Display for DataFusionError {
             DataFusionError::Substrait(ref desc) => {
                 write!(f, "Substrait error: {desc}")
             }
+            DataFusionError::WithSpan(ref err, _) => write!(f, "{err}"),
         }
     }
 }
@@ -364,6 +382,7 @@ impl Error for DataFusionError {
             DataFusionError::External(e) => Some(e.as_ref()),
             DataFusionError::Context(_, e) => Some(e.as_ref()),
             DataFusionError::Substrait(_) => None,
+            DataFusionError::WithSpan(e, _) => Some(e.as_ref()),
         }
     }
 }
@@ -419,6 +438,21 @@ impl DataFusionError {
         Self::Context(description.into(), Box::new(self))
     }
 
+    /// wraps self in Self::WithSpan with the span of the SQL statement it
+    /// refers to
+    pub fn with_span(self, span: SqlSpan) -> Self {
+        Self::WithSpan(Box::new(self), Box::new(span))
+    }
+
+    /// Returns the span of the SQL statement this error refers to, if known
+    pub fn span(&self) -> Option<&SqlSpan> {
+        match self {
+            DataFusionError::WithSpan(_, span) => Some(span),
+            DataFusionError::Context(_, err) => err.span(),
+            _ => None,
+        }
+    }
+
     pub fn strip_backtrace(&self) -> String {
         self.to_string()
             .split(Self::BACK_TRACE_SEP)
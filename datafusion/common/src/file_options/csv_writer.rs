@@ -17,12 +17,57 @@
 
 //! Options related to how csv files should be written
 
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
 use crate::config::CsvOptions;
-use crate::error::{DataFusionError, Result};
+use crate::error::{_config_err, DataFusionError, Result};
 use crate::parsers::CompressionTypeVariant;
 
 use arrow::csv::WriterBuilder;
 
+/// Which fields are quoted when writing CSV files
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CsvQuoteStyle {
+    /// Quote every field
+    Always,
+    /// Only quote fields containing the delimiter, quote or line breaks
+    #[default]
+    Minimal,
+    /// Quote every field that is not a number
+    NonNumeric,
+    /// Never quote fields, even if the output is ambiguous
+    Never,
+}
+
+impl FromStr for CsvQuoteStyle {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "always" => Ok(Self::Always),
+            "minimal" | "necessary" => Ok(Self::Minimal),
+            "non_numeric" => Ok(Self::NonNumeric),
+            "never" => Ok(Self::Never),
+            _ => _config_err!(
+                "Unsupported CSV quote style {s}, expected one of always, minimal, non_numeric or never"
+            ),
+        }
+    }
+}
+
+impl Display for CsvQuoteStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let str = match self {
+            Self::Always => "always",
+            Self::Minimal => "minimal",
+            Self::NonNumeric => "non_numeric",
+            Self::Never => "never",
+        };
+        write!(f, "{str}")
+    }
+}
+
 /// Options for writing CSV files
 #[derive(Clone, Debug)]
 pub struct CsvWriterOptions {
@@ -31,6 +76,9 @@ pub struct CsvWriterOptions {
     /// Compression to apply after ArrowWriter serializes RecordBatches.
     /// This compression is applied by DataFusion not the ArrowWriter itself.
     pub compression: CompressionTypeVariant,
+    /// Which fields are quoted. Styles other than [`CsvQuoteStyle::Minimal`]
+    /// are not supported by the arrow writer and are applied by DataFusion.
+    pub quote_style: CsvQuoteStyle,
 }
 
 impl CsvWriterOptions {
@@ -41,8 +89,15 @@ impl CsvWriterOptions {
         Self {
             writer_options,
             compression,
+            quote_style: CsvQuoteStyle::default(),
         }
     }
+
+    /// Set which fields are quoted
+    pub fn with_quote_style(mut self, quote_style: CsvQuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
 }
 
 impl TryFrom<&CsvOptions> for CsvWriterOptions {
@@ -51,8 +106,15 @@ impl TryFrom<&CsvOptions> for CsvWriterOptions {
     fn try_from(value: &CsvOptions) -> Result<Self> {
         let mut builder = WriterBuilder::default()
             .with_header(value.has_header)
-            .with_delimiter(value.delimiter);
+            .with_delimiter(value.delimiter)
+            .with_quote(value.quote);
 
+        if let Some(v) = value.escape {
+            builder = builder.with_escape(v)
+        }
+        if let Some(v) = value.double_quote {
+            builder = builder.with_double_quote(v)
+        }
         if let Some(v) = &value.date_format {
             builder = builder.with_date_format(v.into())
         }
@@ -71,6 +133,7 @@ impl TryFrom<&CsvOptions> for CsvWriterOptions {
         Ok(CsvWriterOptions {
             writer_options: builder,
             compression: value.compression,
+            quote_style: value.quote_style,
         })
     }
 }
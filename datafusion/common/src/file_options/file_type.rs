@@ -33,6 +33,8 @@ pub const DEFAULT_CSV_EXTENSION: &str = ".csv";
 pub const DEFAULT_JSON_EXTENSION: &str = ".json";
 /// The default file extension of parquet files
 pub const DEFAULT_PARQUET_EXTENSION: &str = ".parquet";
+/// The default file extension of orc files
+pub const DEFAULT_ORC_EXTENSION: &str = ".orc";
 
 /// Define each `FileType`/`FileCompressionType`'s extension
 pub trait GetExt {
@@ -54,6 +56,8 @@ pub enum FileType {
     CSV,
     /// JSON file
     JSON,
+    /// Apache ORC file
+    ORC,
 }
 
 impl From<&FormatOptions> for FileType {
@@ -65,6 +69,7 @@ impl From<&FormatOptions> for FileType {
             FormatOptions::PARQUET(_) => FileType::PARQUET,
             FormatOptions::AVRO => FileType::AVRO,
             FormatOptions::ARROW => FileType::ARROW,
+            FormatOptions::ORC => FileType::ORC,
         }
     }
 }
@@ -78,6 +83,7 @@ impl GetExt for FileType {
             FileType::PARQUET => DEFAULT_PARQUET_EXTENSION.to_owned(),
             FileType::CSV => DEFAULT_CSV_EXTENSION.to_owned(),
             FileType::JSON => DEFAULT_JSON_EXTENSION.to_owned(),
+            FileType::ORC => DEFAULT_ORC_EXTENSION.to_owned(),
         }
     }
 }
@@ -91,6 +97,7 @@ impl Display for FileType {
             FileType::PARQUET => "parquet",
             FileType::AVRO => "avro",
             FileType::ARROW => "arrow",
+            FileType::ORC => "orc",
         };
         write!(f, "{}", out)
     }
@@ -102,12 +109,13 @@ impl FromStr for FileType {
     fn from_str(s: &str) -> Result<Self> {
         let s = s.to_uppercase();
         match s.as_str() {
-            "ARROW" => Ok(FileType::ARROW),
+            "ARROW" | "FEATHER" => Ok(FileType::ARROW),
             "AVRO" => Ok(FileType::AVRO),
             #[cfg(feature = "parquet")]
             "PARQUET" => Ok(FileType::PARQUET),
             "CSV" => Ok(FileType::CSV),
             "JSON" | "NDJSON" => Ok(FileType::JSON),
+            "ORC" => Ok(FileType::ORC),
             _ => Err(DataFusionError::NotImplemented(format!(
                 "Unknown FileType: {s}"
             ))),
@@ -130,10 +138,15 @@ mod tests {
             ("CSV", FileType::CSV),
             ("json", FileType::JSON),
             ("JSON", FileType::JSON),
+            ("arrow", FileType::ARROW),
+            ("feather", FileType::ARROW),
+            ("FEATHER", FileType::ARROW),
             ("avro", FileType::AVRO),
             ("AVRO", FileType::AVRO),
             ("parquet", FileType::PARQUET),
             ("PARQUET", FileType::PARQUET),
+            ("orc", FileType::ORC),
+            ("ORC", FileType::ORC),
         ] {
             assert_eq!(FileType::from_str(ext).unwrap(), file_type);
         }
@@ -17,9 +17,11 @@
 
 //! Options related to how json files should be written
 
+use std::time::Duration;
+
 use crate::{
     config::JsonOptions,
-    error::{DataFusionError, Result},
+    error::{_config_err, DataFusionError, Result},
     parsers::CompressionTypeVariant,
 };
 
@@ -27,11 +29,60 @@ use crate::{
 #[derive(Clone, Debug)]
 pub struct JsonWriterOptions {
     pub compression: CompressionTypeVariant,
+    /// Whether to write null values as `null` rather than omitting them
+    pub explicit_nulls: bool,
+    /// Start a new output file once the current file has this many bytes
+    pub max_file_size_bytes: Option<usize>,
+    /// Start a new output file once the current file has been open this long
+    pub rotation_interval: Option<Duration>,
+    /// Flush the current output file at this interval
+    pub flush_interval: Option<Duration>,
 }
 
 impl JsonWriterOptions {
     pub fn new(compression: CompressionTypeVariant) -> Self {
-        Self { compression }
+        Self {
+            compression,
+            explicit_nulls: false,
+            max_file_size_bytes: None,
+            rotation_interval: None,
+            flush_interval: None,
+        }
+    }
+
+    /// Set whether to write null values as `null` rather than omitting them
+    pub fn with_explicit_nulls(mut self, explicit_nulls: bool) -> Self {
+        self.explicit_nulls = explicit_nulls;
+        self
+    }
+
+    /// Set the size in bytes at which a new output file is started
+    pub fn with_max_file_size_bytes(
+        mut self,
+        max_file_size_bytes: Option<usize>,
+    ) -> Self {
+        self.max_file_size_bytes = max_file_size_bytes;
+        self
+    }
+
+    /// Set the age at which a new output file is started
+    pub fn with_rotation_interval(mut self, rotation_interval: Option<Duration>) -> Self {
+        self.rotation_interval = rotation_interval;
+        self
+    }
+
+    /// Set the interval at which the current output file is flushed
+    pub fn with_flush_interval(mut self, flush_interval: Option<Duration>) -> Self {
+        self.flush_interval = flush_interval;
+        self
+    }
+
+    /// Returns true if the output is written to a sequence of files that are
+    /// rotated or flushed as the input is written, rather than all at once
+    pub fn is_streaming(&self) -> bool {
+        self.max_file_size_bytes.is_some()
+            || self.rotation_interval.is_some()
+            || self.flush_interval.is_some()
     }
 }
 
@@ -39,8 +90,20 @@ impl TryFrom<&JsonOptions> for JsonWriterOptions {
     type Error = DataFusionError;
 
     fn try_from(value: &JsonOptions) -> Result<Self> {
+        if value.max_file_size_bytes == Some(0)
+            || value.rotation_interval_ms == Some(0)
+            || value.flush_interval_ms == Some(0)
+        {
+            return _config_err!(
+                "max_file_size_bytes, rotation_interval_ms and flush_interval_ms must be greater than zero"
+            );
+        }
         Ok(JsonWriterOptions {
             compression: value.compression,
+            explicit_nulls: value.explicit_nulls,
+            max_file_size_bytes: value.max_file_size_bytes,
+            rotation_interval: value.rotation_interval_ms.map(Duration::from_millis),
+            flush_interval: value.flush_interval_ms.map(Duration::from_millis),
         })
     }
 }
@@ -33,7 +33,10 @@ mod tests {
     use super::parquet_writer::ParquetWriterOptions;
     use crate::{
         config::TableOptions,
-        file_options::{csv_writer::CsvWriterOptions, json_writer::JsonWriterOptions},
+        file_options::{
+            csv_writer::{CsvQuoteStyle, CsvWriterOptions},
+            json_writer::JsonWriterOptions,
+        },
         parsers::CompressionTypeVariant,
         FileType, Result,
     };
@@ -278,6 +281,9 @@ mod tests {
         option_map.insert("format.null_value".to_owned(), "123".to_owned());
         option_map.insert("format.compression".to_owned(), "gzip".to_owned());
         option_map.insert("format.delimiter".to_owned(), ";".to_owned());
+        option_map.insert("format.quote_style".to_owned(), "always".to_owned());
+        option_map.insert("format.double_quote".to_owned(), "false".to_owned());
+        option_map.insert("format.escape".to_owned(), "\\".to_owned());
 
         let mut table_config = TableOptions::new();
         table_config.set_file_format(FileType::CSV);
@@ -290,6 +296,7 @@ mod tests {
         let buff = Vec::new();
         let _properties = builder.build(buff);
         assert_eq!(csv_options.compression, CompressionTypeVariant::GZIP);
+        assert_eq!(csv_options.quote_style, CsvQuoteStyle::Always);
         // TODO expand unit test if csv::WriterBuilder allows public read access to properties
 
         Ok(())
@@ -300,6 +307,7 @@ mod tests {
     fn test_writeroptions_json_from_statement_options() -> Result<()> {
         let mut option_map: HashMap<String, String> = HashMap::new();
         option_map.insert("format.compression".to_owned(), "gzip".to_owned());
+        option_map.insert("format.explicit_nulls".to_owned(), "true".to_owned());
 
         let mut table_config = TableOptions::new();
         table_config.set_file_format(FileType::JSON);
@@ -307,6 +315,36 @@ mod tests {
 
         let json_options = JsonWriterOptions::try_from(&table_config.json)?;
         assert_eq!(json_options.compression, CompressionTypeVariant::GZIP);
+        assert!(json_options.explicit_nulls);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_writeroptions_json_rolling_files() -> Result<()> {
+        let mut option_map: HashMap<String, String> = HashMap::new();
+        option_map.insert("format.max_file_size_bytes".to_owned(), "1024".to_owned());
+        option_map.insert("format.rotation_interval_ms".to_owned(), "60000".to_owned());
+        option_map.insert("format.flush_interval_ms".to_owned(), "500".to_owned());
+
+        let mut table_config = TableOptions::new();
+        table_config.set_file_format(FileType::JSON);
+        table_config.alter_with_string_hash_map(&option_map)?;
+
+        let json_options = JsonWriterOptions::try_from(&table_config.json)?;
+        assert_eq!(json_options.max_file_size_bytes, Some(1024));
+        assert_eq!(
+            json_options.rotation_interval,
+            Some(std::time::Duration::from_secs(60))
+        );
+        assert_eq!(
+            json_options.flush_interval,
+            Some(std::time::Duration::from_millis(500))
+        );
+        assert!(json_options.is_streaming());
+
+        table_config.json.flush_interval_ms = Some(0);
+        assert!(JsonWriterOptions::try_from(&table_config.json).is_err());
 
         Ok(())
     }
@@ -124,3 +124,42 @@ impl JoinSide {
         }
     }
 }
+
+/// Join strategy requested by the user through an optimizer hint such as
+/// `/*+ BROADCAST(t) */`, overriding the statistics-based choice between a
+/// collected (broadcast) and a partitioned hash join, or the configured
+/// preference for hash joins over sort-merge joins
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JoinStrategyHint {
+    /// Collect the left input into a single hash table shared by all partitions
+    BroadcastLeft,
+    /// Collect the right input into a single hash table shared by all partitions
+    BroadcastRight,
+    /// Hash repartition both inputs and join each partition separately
+    ShuffleHash,
+    /// Sort both inputs on the join keys and merge them
+    SortMerge,
+}
+
+impl JoinStrategyHint {
+    /// Returns the equivalent hint after the join inputs are swapped
+    pub fn swap(&self) -> Self {
+        match self {
+            JoinStrategyHint::BroadcastLeft => JoinStrategyHint::BroadcastRight,
+            JoinStrategyHint::BroadcastRight => JoinStrategyHint::BroadcastLeft,
+            JoinStrategyHint::ShuffleHash => JoinStrategyHint::ShuffleHash,
+            JoinStrategyHint::SortMerge => JoinStrategyHint::SortMerge,
+        }
+    }
+}
+
+impl Display for JoinStrategyHint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JoinStrategyHint::BroadcastLeft => write!(f, "BroadcastLeft"),
+            JoinStrategyHint::BroadcastRight => write!(f, "BroadcastRight"),
+            JoinStrategyHint::ShuffleHash => write!(f, "ShuffleHash"),
+            JoinStrategyHint::SortMerge => write!(f, "SortMerge"),
+        }
+    }
+}
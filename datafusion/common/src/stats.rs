@@ -254,12 +254,7 @@ impl Statistics {
             column_statistics: self
                 .column_statistics
                 .into_iter()
-                .map(|cs| ColumnStatistics {
-                    null_count: cs.null_count.to_inexact(),
-                    max_value: cs.max_value.to_inexact(),
-                    min_value: cs.min_value.to_inexact(),
-                    distinct_count: cs.distinct_count.to_inexact(),
-                })
+                .map(ColumnStatistics::to_inexact)
                 .collect::<Vec<_>>(),
         }
     }
@@ -335,6 +330,17 @@ impl ColumnStatistics {
         }
     }
 
+    /// If the exactness of a [`ColumnStatistics`] instance is lost, this function relaxes
+    /// the exactness of all information by converting them [`Precision::Inexact`].
+    pub fn to_inexact(self) -> Self {
+        ColumnStatistics {
+            null_count: self.null_count.to_inexact(),
+            max_value: self.max_value.to_inexact(),
+            min_value: self.min_value.to_inexact(),
+            distinct_count: self.distinct_count.to_inexact(),
+        }
+    }
+
     /// Returns a [`ColumnStatistics`] instance having all [`Precision::Absent`] parameters.
     pub fn new_unknown() -> ColumnStatistics {
         ColumnStatistics {
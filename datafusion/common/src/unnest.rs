@@ -17,11 +17,13 @@
 
 //! [`UnnestOptions`] for unnesting structured types
 
-/// Options for unnesting a column that contains a list type,
+/// Options for unnesting columns that contain a list type,
 /// replicating values in the other, non nested rows.
 ///
 /// Conceptually this operation is like joining each row with all the
-/// values in the list column.
+/// values in the list column. When several columns are unnested together,
+/// their lists are zipped: each row is repeated as many times as its longest
+/// list, and the shorter lists are padded with nulls.
 ///
 /// If `preserve_nulls` is false, nulls and empty lists
 /// from the input column are not carried through to the output. This
@@ -60,10 +62,27 @@
 ///      └─────────┘ └─────┘                └─────────┘ └─────┘
 ///        c1         c2                        c1        c2
 /// ```
+///
+/// ## `Unnest(c1, c2)`, ordinality: `"n"`
+/// ```text
+///      ┌─────────┐ ┌─────────┐            ┌─────────┐ ┌─────────┐ ┌─────┐
+///      │ {1, 2}  │ │   {A}   │   Unnest   │    1    │ │    A    │ │  1  │
+///      ├─────────┤ ├─────────┤            ├─────────┤ ├─────────┤ ├─────┤
+///      │   {3}   │ │ {B, C}  │ ────────▶  │    2    │ │  null   │ │  2  │
+///      └─────────┘ └─────────┘            ├─────────┤ ├─────────┤ ├─────┤
+///        c1          c2                   │    3    │ │    B    │ │  1  │
+///                                         ├─────────┤ ├─────────┤ ├─────┤
+///                                         │  null   │ │    C    │ │  2  │
+///                                         └─────────┘ └─────────┘ └─────┘
+///                                             c1          c2        n
+/// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq)]
 pub struct UnnestOptions {
     /// Should nulls in the input be preserved? Defaults to true
     pub preserve_nulls: bool,
+    /// The name of a column to add with the 1-based position of each value in
+    /// its list, like `WITH ORDINALITY` in Postgres. Defaults to none
+    pub ordinality: Option<String>,
 }
 
 impl Default for UnnestOptions {
@@ -71,6 +90,7 @@ impl Default for UnnestOptions {
         Self {
             // default to true to maintain backwards compatible behavior
             preserve_nulls: true,
+            ordinality: None,
         }
     }
 }
@@ -87,4 +107,11 @@ impl UnnestOptions {
         self.preserve_nulls = preserve_nulls;
         self
     }
+
+    /// Add a column called `name` with the position of each value in its
+    /// list, as described on [`Self`]
+    pub fn with_ordinality(mut self, name: impl Into<String>) -> Self {
+        self.ordinality = Some(name.into());
+        self
+    }
 }
@@ -0,0 +1,151 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Benchmarks for grouping on several columns, which converts the group keys
+//! to the arrow row format and emits the groups back as arrays
+//!
+//! The group keys cover null-free and nullable columns, narrow and wide
+//! schemas, and mixes of fixed and variable length columns.
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Float64Array, Int32Array, Int64Array, StringArray};
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+use criterion::{criterion_group, criterion_main, Criterion};
+use datafusion::datasource::MemTable;
+use datafusion::physical_plan::{collect, ExecutionPlan};
+use datafusion::prelude::SessionContext;
+use datafusion_execution::TaskContext;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use tokio::runtime::Runtime;
+
+const NUM_ROWS: usize = 65536;
+const BATCH_SIZE: usize = 8192;
+/// The number of distinct values of each column
+const CARDINALITY: i32 = 16;
+
+#[derive(Clone, Copy)]
+enum ColumnKind {
+    Int32,
+    Int64,
+    Float64,
+    Utf8,
+}
+
+/// Returns `num_batches` batches of random columns of the given kinds, of
+/// which a tenth of the values are null if `nullable` is set
+fn make_batches(
+    rng: &mut StdRng,
+    kinds: &[ColumnKind],
+    nullable: bool,
+) -> (SchemaRef, Vec<RecordBatch>) {
+    let null_density = if nullable { 0.1 } else { 0.0 };
+    let batches = (0..NUM_ROWS / BATCH_SIZE)
+        .map(|_| {
+            let columns = kinds.iter().enumerate().map(|(i, kind)| {
+                let mut value = || {
+                    (!rng.gen_bool(null_density)).then(|| rng.gen_range(0..CARDINALITY))
+                };
+                let values = (0..BATCH_SIZE).map(|_| value()).collect::<Vec<_>>();
+                let array: ArrayRef = match kind {
+                    ColumnKind::Int32 => Arc::new(Int32Array::from(values)),
+                    ColumnKind::Int64 => Arc::new(
+                        values
+                            .into_iter()
+                            .map(|v| v.map(i64::from))
+                            .collect::<Int64Array>(),
+                    ),
+                    ColumnKind::Float64 => Arc::new(
+                        values
+                            .into_iter()
+                            .map(|v| v.map(f64::from))
+                            .collect::<Float64Array>(),
+                    ),
+                    // strings of varying lengths
+                    ColumnKind::Utf8 => Arc::new(
+                        values
+                            .into_iter()
+                            .map(|v| v.map(|v| "x".repeat(v as usize * 4)))
+                            .collect::<StringArray>(),
+                    ),
+                };
+                (format!("c{i}"), array)
+            });
+            RecordBatch::try_from_iter(columns).unwrap()
+        })
+        .collect::<Vec<_>>();
+    (batches[0].schema(), batches)
+}
+
+/// Creates the plan grouping a table of the given columns on all of them
+async fn create_plan(
+    kinds: &[ColumnKind],
+    nullable: bool,
+) -> (Arc<dyn ExecutionPlan>, Arc<TaskContext>) {
+    let mut rng = StdRng::seed_from_u64(42);
+    let (schema, batches) = make_batches(&mut rng, kinds, nullable);
+    let table = MemTable::try_new(schema, vec![batches]).unwrap();
+    let ctx = SessionContext::new();
+    ctx.register_table("t", Arc::new(table)).unwrap();
+
+    let columns = (0..kinds.len())
+        .map(|i| format!("c{i}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let sql = format!("SELECT {columns}, COUNT(*) FROM t GROUP BY {columns}");
+    let plan = ctx
+        .sql(&sql)
+        .await
+        .unwrap()
+        .create_physical_plan()
+        .await
+        .unwrap();
+    (plan, ctx.task_ctx())
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    use ColumnKind::*;
+
+    let rt = Runtime::new().unwrap();
+    let wide = [Int32, Int64, Float64, Int32, Int64, Float64, Int32, Int64];
+    let cases: [(&str, &[ColumnKind]); 4] = [
+        ("narrow", &[Int32, Int64]),
+        ("wide", &wide),
+        ("utf8", &[Utf8, Utf8]),
+        ("mixed", &[Int64, Utf8, Float64, Utf8]),
+    ];
+
+    for (name, kinds) in cases {
+        for nullable in [false, true] {
+            let (plan, task_ctx) = rt.block_on(create_plan(kinds, nullable));
+            let nulls = if nullable { "nullable" } else { "null-free" };
+            c.bench_function(&format!("group by {name} {nulls} columns"), |b| {
+                b.iter(|| {
+                    criterion::black_box(
+                        rt.block_on(collect(plan.clone(), task_ctx.clone()))
+                            .unwrap(),
+                    )
+                })
+            });
+        }
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
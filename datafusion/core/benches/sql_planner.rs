@@ -25,6 +25,7 @@ use crate::criterion::Criterion;
 use arrow::datatypes::{DataType, Field, Fields, Schema};
 use datafusion::datasource::MemTable;
 use datafusion::execution::context::SessionContext;
+use datafusion::prelude::SessionConfig;
 use std::sync::Arc;
 use test_utils::tpcds::tpcds_schemas;
 use test_utils::tpch::tpch_schemas;
@@ -76,6 +77,18 @@ fn create_context() -> SessionContext {
     ctx
 }
 
+/// Create a context with the point query fast path enabled and a table with
+/// a single partition
+fn create_point_query_context() -> SessionContext {
+    let mut config = SessionConfig::new();
+    config.options_mut().optimizer.point_query_fast_path = true;
+    let ctx = SessionContext::new_with_config(config);
+    let schema = Arc::new(create_schema("e", 20));
+    let table = MemTable::try_new(schema, vec![vec![]]).unwrap();
+    ctx.register_table("t20", Arc::new(table)).unwrap();
+    ctx
+}
+
 /// Register the table definitions as a MemTable with the context and return the
 /// context
 fn register_defs(ctx: SessionContext, defs: Vec<TableDef>) -> SessionContext {
@@ -114,6 +127,16 @@ fn criterion_benchmark(c: &mut Criterion) {
         b.iter(|| physical_plan(&ctx, "SELECT * FROM t1000"))
     });
 
+    let point_query_ctx = create_point_query_context();
+    c.bench_function("physical_point_query_fast_path", |b| {
+        b.iter(|| {
+            physical_plan(
+                &point_query_ctx,
+                "SELECT e1, e2 FROM t20 WHERE e0 = 42 LIMIT 1",
+            )
+        })
+    });
+
     c.bench_function("logical_trivial_join_low_numbered_columns", |b| {
         b.iter(|| {
             logical_plan(
@@ -20,7 +20,8 @@
 //! [Information Schema]: https://en.wikipedia.org/wiki/Information_schema
 
 use async_trait::async_trait;
-use datafusion_common::DataFusionError;
+use datafusion_common::{Constraint, DataFusionError};
+use datafusion_expr::{Signature, Volatility};
 use std::{any::Any, sync::Arc};
 
 use arrow::{
@@ -48,10 +49,19 @@ pub(crate) const VIEWS: &str = "views";
 pub(crate) const COLUMNS: &str = "columns";
 pub(crate) const DF_SETTINGS: &str = "df_settings";
 pub(crate) const SCHEMATA: &str = "schemata";
+pub(crate) const ROUTINES: &str = "routines";
+pub(crate) const KEY_COLUMN_USAGE: &str = "key_column_usage";
 
 /// All information schema tables
-pub const INFORMATION_SCHEMA_TABLES: &[&str] =
-    &[TABLES, VIEWS, COLUMNS, DF_SETTINGS, SCHEMATA];
+pub const INFORMATION_SCHEMA_TABLES: &[&str] = &[
+    TABLES,
+    VIEWS,
+    COLUMNS,
+    DF_SETTINGS,
+    SCHEMATA,
+    ROUTINES,
+    KEY_COLUMN_USAGE,
+];
 
 /// Implements the `information_schema` virtual schema and tables
 ///
@@ -127,6 +137,18 @@ impl InformationSchemaConfig {
                 SCHEMATA,
                 TableType::View,
             );
+            builder.add_table(
+                &catalog_name,
+                INFORMATION_SCHEMA,
+                ROUTINES,
+                TableType::View,
+            );
+            builder.add_table(
+                &catalog_name,
+                INFORMATION_SCHEMA,
+                KEY_COLUMN_USAGE,
+                TableType::View,
+            );
         }
 
         Ok(())
@@ -211,6 +233,88 @@ impl InformationSchemaConfig {
         Ok(())
     }
 
+    /// Construct the `information_schema.key_column_usage` virtual table
+    async fn make_key_column_usage(
+        &self,
+        builder: &mut InformationSchemaKeyColumnUsageBuilder,
+    ) -> Result<(), DataFusionError> {
+        for catalog_name in self.catalog_list.catalog_names() {
+            let catalog = self.catalog_list.catalog(&catalog_name).unwrap();
+
+            for schema_name in catalog.schema_names() {
+                if schema_name != INFORMATION_SCHEMA {
+                    // schema name may not exist in the catalog, so we need to check
+                    if let Some(schema) = catalog.schema(&schema_name) {
+                        for table_name in schema.table_names() {
+                            let Some(table) = schema.table(&table_name).await? else {
+                                continue;
+                            };
+                            let Some(constraints) = table.constraints() else {
+                                continue;
+                            };
+                            let table_schema = table.schema();
+                            for constraint in constraints.iter() {
+                                let (constraint_name, indices) = constraint_name(
+                                    &table_name,
+                                    &table_schema,
+                                    constraint,
+                                );
+                                for (position, idx) in indices.iter().enumerate() {
+                                    builder.add_key_column(
+                                        &catalog_name,
+                                        &schema_name,
+                                        &constraint_name,
+                                        &table_name,
+                                        table_schema.field(*idx).name(),
+                                        position,
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Construct the `information_schema.routines` virtual table
+    fn make_routines(
+        &self,
+        ctx: &TaskContext,
+        builder: &mut InformationSchemaRoutinesBuilder,
+    ) {
+        // functions are not part of a catalog, so they are listed as part
+        // of the default schema
+        let options = &ctx.session_config().options().catalog;
+        let catalog_name = &options.default_catalog;
+        let schema_name = &options.default_schema;
+
+        let mut routines = vec![];
+        for (name, udf) in ctx.scalar_functions() {
+            routines.push((name, udf.name(), "SCALAR", udf.signature()));
+        }
+        for (name, udaf) in ctx.aggregate_functions() {
+            routines.push((name, udaf.name(), "AGGREGATE", udaf.signature()));
+        }
+        for (name, udwf) in ctx.window_functions() {
+            routines.push((name, udwf.name(), "WINDOW", udwf.signature()));
+        }
+        routines.sort_by(|a, b| (a.0, a.2).cmp(&(b.0, b.2)));
+
+        for (routine_name, specific_name, function_type, signature) in routines {
+            builder.add_routine(
+                catalog_name,
+                schema_name,
+                routine_name,
+                specific_name,
+                function_type,
+                signature,
+            );
+        }
+    }
+
     /// Construct the `information_schema.df_settings` virtual table
     fn make_df_settings(
         &self,
@@ -223,6 +327,27 @@ impl InformationSchemaConfig {
     }
 }
 
+/// Returns the name of the `constraint` of the table `table_name` and the
+/// indices of its columns. Constraints are unnamed, so they are named like
+/// PostgreSQL names them by default.
+fn constraint_name<'a>(
+    table_name: &str,
+    table_schema: &Schema,
+    constraint: &'a Constraint,
+) -> (String, &'a [usize]) {
+    match constraint {
+        Constraint::PrimaryKey(indices) => (format!("{table_name}_pkey"), indices),
+        Constraint::Unique(indices) => {
+            let columns = indices
+                .iter()
+                .map(|idx| table_schema.field(*idx).name().as_str())
+                .collect::<Vec<_>>()
+                .join("_");
+            (format!("{table_name}_{columns}_key"), indices)
+        }
+    }
+}
+
 #[async_trait]
 impl SchemaProvider for InformationSchemaProvider {
     fn as_any(&self) -> &(dyn Any + 'static) {
@@ -236,6 +361,8 @@ impl SchemaProvider for InformationSchemaProvider {
             COLUMNS.to_string(),
             DF_SETTINGS.to_string(),
             SCHEMATA.to_string(),
+            ROUTINES.to_string(),
+            KEY_COLUMN_USAGE.to_string(),
         ]
     }
 
@@ -254,6 +381,10 @@ impl SchemaProvider for InformationSchemaProvider {
             Arc::new(InformationSchemaDfSettings::new(config))
         } else if name.eq_ignore_ascii_case("schemata") {
             Arc::new(InformationSchemata::new(config))
+        } else if name.eq_ignore_ascii_case("routines") {
+            Arc::new(InformationSchemaRoutines::new(config))
+        } else if name.eq_ignore_ascii_case("key_column_usage") {
+            Arc::new(InformationSchemaKeyColumnUsage::new(config))
         } else {
             return Ok(None);
         };
@@ -266,7 +397,7 @@ impl SchemaProvider for InformationSchemaProvider {
     fn table_exist(&self, name: &str) -> bool {
         matches!(
             name.to_ascii_lowercase().as_str(),
-            TABLES | VIEWS | COLUMNS | SCHEMATA
+            TABLES | VIEWS | COLUMNS | SCHEMATA | ROUTINES | KEY_COLUMN_USAGE
         )
     }
 }
@@ -838,3 +969,220 @@ impl InformationSchemaDfSettingsBuilder {
         .unwrap()
     }
 }
+
+struct InformationSchemaRoutines {
+    schema: SchemaRef,
+    config: InformationSchemaConfig,
+}
+
+impl InformationSchemaRoutines {
+    fn new(config: InformationSchemaConfig) -> Self {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("routine_catalog", DataType::Utf8, false),
+            Field::new("routine_schema", DataType::Utf8, false),
+            Field::new("routine_name", DataType::Utf8, false),
+            Field::new("specific_name", DataType::Utf8, false),
+            Field::new("routine_type", DataType::Utf8, false),
+            Field::new("function_type", DataType::Utf8, false),
+            Field::new("is_deterministic", DataType::Utf8, false),
+        ]));
+
+        Self { schema, config }
+    }
+
+    fn builder(&self) -> InformationSchemaRoutinesBuilder {
+        InformationSchemaRoutinesBuilder {
+            catalog_names: StringBuilder::new(),
+            schema_names: StringBuilder::new(),
+            routine_names: StringBuilder::new(),
+            specific_names: StringBuilder::new(),
+            routine_types: StringBuilder::new(),
+            function_types: StringBuilder::new(),
+            is_deterministics: StringBuilder::new(),
+            schema: self.schema.clone(),
+        }
+    }
+}
+
+impl PartitionStream for InformationSchemaRoutines {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let config = self.config.clone();
+        let mut builder = self.builder();
+        Box::pin(RecordBatchStreamAdapter::new(
+            self.schema.clone(),
+            // TODO: Stream this
+            futures::stream::once(async move {
+                config.make_routines(&ctx, &mut builder);
+                Ok(builder.finish())
+            }),
+        ))
+    }
+}
+
+/// Builds the `information_schema.ROUTINES` table row by row
+///
+/// Columns are based on <https://www.postgresql.org/docs/current/infoschema-routines.html>,
+/// with the additional `function_type` column being one of `SCALAR`,
+/// `AGGREGATE` or `WINDOW`
+struct InformationSchemaRoutinesBuilder {
+    schema: SchemaRef,
+    catalog_names: StringBuilder,
+    schema_names: StringBuilder,
+    routine_names: StringBuilder,
+    specific_names: StringBuilder,
+    routine_types: StringBuilder,
+    function_types: StringBuilder,
+    is_deterministics: StringBuilder,
+}
+
+impl InformationSchemaRoutinesBuilder {
+    fn add_routine(
+        &mut self,
+        catalog_name: &str,
+        schema_name: &str,
+        routine_name: &str,
+        specific_name: &str,
+        function_type: &str,
+        signature: &Signature,
+    ) {
+        self.catalog_names.append_value(catalog_name);
+        self.schema_names.append_value(schema_name);
+        self.routine_names.append_value(routine_name);
+        // the name of the function an alias refers to
+        self.specific_names.append_value(specific_name);
+        self.routine_types.append_value("FUNCTION");
+        self.function_types.append_value(function_type);
+        let is_deterministic = match signature.volatility {
+            Volatility::Immutable => "YES",
+            Volatility::Stable | Volatility::Volatile => "NO",
+        };
+        self.is_deterministics.append_value(is_deterministic);
+    }
+
+    fn finish(&mut self) -> RecordBatch {
+        RecordBatch::try_new(
+            self.schema.clone(),
+            vec![
+                Arc::new(self.catalog_names.finish()),
+                Arc::new(self.schema_names.finish()),
+                Arc::new(self.routine_names.finish()),
+                Arc::new(self.specific_names.finish()),
+                Arc::new(self.routine_types.finish()),
+                Arc::new(self.function_types.finish()),
+                Arc::new(self.is_deterministics.finish()),
+            ],
+        )
+        .unwrap()
+    }
+}
+
+struct InformationSchemaKeyColumnUsage {
+    schema: SchemaRef,
+    config: InformationSchemaConfig,
+}
+
+impl InformationSchemaKeyColumnUsage {
+    fn new(config: InformationSchemaConfig) -> Self {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("constraint_catalog", DataType::Utf8, false),
+            Field::new("constraint_schema", DataType::Utf8, false),
+            Field::new("constraint_name", DataType::Utf8, false),
+            Field::new("table_catalog", DataType::Utf8, false),
+            Field::new("table_schema", DataType::Utf8, false),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("ordinal_position", DataType::UInt64, false),
+        ]));
+
+        Self { schema, config }
+    }
+
+    fn builder(&self) -> InformationSchemaKeyColumnUsageBuilder {
+        InformationSchemaKeyColumnUsageBuilder {
+            catalog_names: StringBuilder::new(),
+            schema_names: StringBuilder::new(),
+            constraint_names: StringBuilder::new(),
+            table_names: StringBuilder::new(),
+            column_names: StringBuilder::new(),
+            ordinal_positions: UInt64Builder::new(),
+            schema: self.schema.clone(),
+        }
+    }
+}
+
+impl PartitionStream for InformationSchemaKeyColumnUsage {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let mut builder = self.builder();
+        let config = self.config.clone();
+        Box::pin(RecordBatchStreamAdapter::new(
+            self.schema.clone(),
+            // TODO: Stream this
+            futures::stream::once(async move {
+                config.make_key_column_usage(&mut builder).await?;
+                Ok(builder.finish())
+            }),
+        ))
+    }
+}
+
+/// Builds the `information_schema.KEY_COLUMN_USAGE` table row by row, with
+/// the columns of the primary keys and unique constraints of the tables
+///
+/// Columns are based on <https://www.postgresql.org/docs/current/infoschema-key-column-usage.html>
+struct InformationSchemaKeyColumnUsageBuilder {
+    schema: SchemaRef,
+    catalog_names: StringBuilder,
+    schema_names: StringBuilder,
+    constraint_names: StringBuilder,
+    table_names: StringBuilder,
+    column_names: StringBuilder,
+    ordinal_positions: UInt64Builder,
+}
+
+impl InformationSchemaKeyColumnUsageBuilder {
+    fn add_key_column(
+        &mut self,
+        catalog_name: &str,
+        schema_name: &str,
+        constraint_name: &str,
+        table_name: &str,
+        column_name: &str,
+        position: usize,
+    ) {
+        self.catalog_names.append_value(catalog_name);
+        self.schema_names.append_value(schema_name);
+        self.constraint_names.append_value(constraint_name);
+        self.table_names.append_value(table_name);
+        self.column_names.append_value(column_name);
+        // the position of the column in the constraint, starting at 1
+        self.ordinal_positions.append_value(position as u64 + 1);
+    }
+
+    fn finish(&mut self) -> RecordBatch {
+        // constraints are always defined on tables of the same schema
+        let catalog_names = Arc::new(self.catalog_names.finish());
+        let schema_names = Arc::new(self.schema_names.finish());
+        RecordBatch::try_new(
+            self.schema.clone(),
+            vec![
+                catalog_names.clone(),
+                schema_names.clone(),
+                Arc::new(self.constraint_names.finish()),
+                catalog_names,
+                schema_names,
+                Arc::new(self.table_names.finish()),
+                Arc::new(self.column_names.finish()),
+                Arc::new(self.ordinal_positions.finish()),
+            ],
+        )
+        .unwrap()
+    }
+}
@@ -30,7 +30,9 @@ use crate::datasource::{provider_as_source, MemTable, TableProvider};
 use crate::error::Result;
 use crate::execution::context::{SessionState, TaskContext};
 use crate::execution::FunctionRegistry;
-use crate::logical_expr::utils::find_window_exprs;
+use crate::logical_expr::utils::{
+    find_window_exprs, resolve_duplicate_names, DuplicateNamePolicy,
+};
 use crate::logical_expr::{
     col, Expr, JoinType, LogicalPlan, LogicalPlanBuilder, Partitioning, TableType,
 };
@@ -45,6 +47,7 @@ use arrow::compute::{cast, concat};
 use arrow::datatypes::{DataType, Field};
 use arrow_schema::{Schema, SchemaRef};
 use datafusion_common::config::{CsvOptions, FormatOptions, JsonOptions};
+use datafusion_common::tree_node::{Transformed, TransformedResult, TreeNode};
 use datafusion_common::{
     plan_err, Column, DFSchema, DataFusionError, ParamValues, SchemaError, UnnestOptions,
 };
@@ -173,6 +176,18 @@ impl DataFrame {
         }
     }
 
+    /// Plan and execute this DataFrame with `target_partitions` partitions
+    /// instead of the session's setting, as requested by a
+    /// `/*+ REPARTITION(n) */` hint
+    pub(crate) fn with_target_partitions(mut self, target_partitions: usize) -> Self {
+        self.session_state
+            .config_mut()
+            .options_mut()
+            .execution
+            .target_partitions = target_partitions;
+        self
+    }
+
     /// Consume the DataFrame and produce a physical plan
     pub async fn create_physical_plan(self) -> Result<Arc<dyn ExecutionPlan>> {
         self.session_state.create_physical_plan(&self.plan).await
@@ -196,9 +211,10 @@ impl DataFrame {
         let fields = columns
             .iter()
             .map(|name| {
+                let name = self.resolve_column_name(name);
                 self.plan
                     .schema()
-                    .qualified_field_with_unqualified_name(name)
+                    .qualified_field_with_unqualified_name(&name)
             })
             .collect::<Result<Vec<_>>>()?;
         let expr: Vec<Expr> = fields
@@ -208,6 +224,43 @@ impl DataFrame {
         self.select(expr)
     }
 
+    /// Project all columns of the DataFrame except the specified columns,
+    /// like SQL `SELECT * EXCEPT (a, b)`. Returns an error if any of the
+    /// columns does not exist.
+    ///
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?;
+    /// let df = df.select_exclude(&["a"])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn select_exclude(self, columns: &[&str]) -> Result<DataFrame> {
+        let excluded = columns
+            .iter()
+            .map(|name| {
+                let name = self.resolve_column_name(name);
+                self.plan
+                    .schema()
+                    .qualified_field_with_unqualified_name(&name)
+                    .map(Column::from)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let expr: Vec<Expr> = self
+            .plan
+            .schema()
+            .iter()
+            .map(|(qualifier, field)| Column::from((qualifier, field.as_ref())))
+            .filter(|column| !excluded.contains(column))
+            .map(Expr::Column)
+            .collect();
+        self.select(expr)
+    }
+
     /// Project arbitrary expressions (like SQL SELECT expressions) into a new
     /// `DataFrame`.
     ///
@@ -226,12 +279,15 @@ impl DataFrame {
     /// # }
     /// ```
     pub fn select(self, expr_list: Vec<Expr>) -> Result<DataFrame> {
+        let policy = self.duplicate_name_policy()?;
+        let expr_list = self.resolve_columns(expr_list, self.plan.schema())?;
         let window_func_exprs = find_window_exprs(&expr_list);
         let plan = if window_func_exprs.is_empty() {
             self.plan
         } else {
             LogicalPlanBuilder::window_plan(self.plan, window_func_exprs)?
         };
+        let expr_list = resolve_duplicate_names(expr_list, plan.schema(), policy)?;
         let project_plan = LogicalPlanBuilder::from(plan).project(expr_list)?.build()?;
 
         Ok(DataFrame::new(self.session_state, project_plan))
@@ -276,6 +332,41 @@ impl DataFrame {
         Ok(DataFrame::new(self.session_state, plan))
     }
 
+    /// Expand the list elements of several columns to multiple rows, zipping
+    /// the lists of each row together, with behavior controlled by
+    /// [`UnnestOptions`].
+    ///
+    /// Each row is repeated as many times as its longest list, and the
+    /// shorter lists are padded with nulls. Use
+    /// [`UnnestOptions::with_ordinality`] to add a column with the position
+    /// of each element.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # use datafusion_common::UnnestOptions;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?;
+    /// let options = UnnestOptions::new().with_ordinality("ordinality");
+    /// let df = df.unnest_columns_with_options(&["a", "b"], options)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn unnest_columns_with_options(
+        self,
+        columns: &[&str],
+        options: UnnestOptions,
+    ) -> Result<DataFrame> {
+        let columns = columns.iter().map(|c| Column::from(*c)).collect();
+        let plan = LogicalPlanBuilder::from(self.plan)
+            .unnest_columns_with_options(columns, options)?
+            .build()?;
+        Ok(DataFrame::new(self.session_state, plan))
+    }
+
     /// Return a DataFrame with only rows for which `predicate` evaluates to
     /// `true`.
     ///
@@ -295,6 +386,7 @@ impl DataFrame {
     /// # }
     /// ```
     pub fn filter(self, predicate: Expr) -> Result<DataFrame> {
+        let predicate = self.resolve_column(predicate, self.plan.schema())?;
         let plan = LogicalPlanBuilder::from(self.plan)
             .filter(predicate)?
             .build()?;
@@ -326,6 +418,8 @@ impl DataFrame {
         group_expr: Vec<Expr>,
         aggr_expr: Vec<Expr>,
     ) -> Result<DataFrame> {
+        let group_expr = self.resolve_columns(group_expr, self.plan.schema())?;
+        let aggr_expr = self.resolve_columns(aggr_expr, self.plan.schema())?;
         let plan = LogicalPlanBuilder::from(self.plan)
             .aggregate(group_expr, aggr_expr)?
             .build()?;
@@ -335,6 +429,7 @@ impl DataFrame {
     /// Return a new DataFrame that adds the result of evaluating one or more
     /// window functions ([`Expr::WindowFunction`]) to the existing columns
     pub fn window(self, window_exprs: Vec<Expr>) -> Result<DataFrame> {
+        let window_exprs = self.resolve_columns(window_exprs, self.plan.schema())?;
         let plan = LogicalPlanBuilder::from(self.plan)
             .window(window_exprs)?
             .build()?;
@@ -636,6 +731,7 @@ impl DataFrame {
     /// # }
     /// ```
     pub fn sort(self, expr: Vec<Expr>) -> Result<DataFrame> {
+        let expr = self.resolve_columns(expr, self.plan.schema())?;
         let plan = LogicalPlanBuilder::from(self.plan).sort(expr)?.build()?;
         Ok(DataFrame::new(self.session_state, plan))
     }
@@ -683,6 +779,7 @@ impl DataFrame {
         right_cols: &[&str],
         filter: Option<Expr>,
     ) -> Result<DataFrame> {
+        let policy = self.duplicate_name_policy()?;
         let plan = LogicalPlanBuilder::from(self.plan)
             .join(
                 right.plan,
@@ -691,6 +788,7 @@ impl DataFrame {
                 filter,
             )?
             .build()?;
+        let plan = resolve_join_duplicate_names(plan, policy)?;
         Ok(DataFrame::new(self.session_state, plan))
     }
 
@@ -737,13 +835,77 @@ impl DataFrame {
         join_type: JoinType,
         on_exprs: impl IntoIterator<Item = Expr>,
     ) -> Result<DataFrame> {
-        let expr = on_exprs.into_iter().reduce(Expr::and);
+        let policy = self.duplicate_name_policy()?;
+        let expr = match on_exprs.into_iter().reduce(Expr::and) {
+            Some(expr) if self.case_insensitive_columns() => {
+                let join_schema = self.plan.schema().join(right.plan.schema())?;
+                Some(self.resolve_column(expr, &join_schema)?)
+            }
+            expr => expr,
+        };
         let plan = LogicalPlanBuilder::from(self.plan)
             .join_on(right.plan, join_type, expr)?
             .build()?;
+        let plan = resolve_join_duplicate_names(plan, policy)?;
         Ok(DataFrame::new(self.session_state, plan))
     }
 
+    /// Whether `datafusion.sql_parser.enable_case_insensitive_columns` is set
+    fn case_insensitive_columns(&self) -> bool {
+        self.session_state
+            .config_options()
+            .sql_parser
+            .enable_case_insensitive_columns
+    }
+
+    /// Returns the name of the column of this `DataFrame` that `name` refers
+    /// to when case insensitive columns are enabled, see
+    /// [`DFSchema::resolve_column_ignore_case`]
+    fn resolve_column_name(&self, name: &str) -> String {
+        let column = Column::new_unqualified(name);
+        if !self.case_insensitive_columns() {
+            return column.name;
+        }
+        self.plan.schema().resolve_column_ignore_case(column).name
+    }
+
+    /// Resolves the columns of `expr` which refer to no column of `schema`
+    /// when case insensitive columns are enabled, see
+    /// [`DFSchema::resolve_column_ignore_case`]
+    fn resolve_column(&self, expr: Expr, schema: &DFSchema) -> Result<Expr> {
+        if !self.case_insensitive_columns() {
+            return Ok(expr);
+        }
+        expr.transform_up(&|expr| {
+            Ok(match expr {
+                Expr::Column(column) if !schema.is_column_from_schema(&column) => {
+                    Transformed::yes(Expr::Column(
+                        schema.resolve_column_ignore_case(column),
+                    ))
+                }
+                expr => Transformed::no(expr),
+            })
+        })
+        .data()
+    }
+
+    /// Resolves the columns of `exprs` like [`Self::resolve_column`]
+    fn resolve_columns(&self, exprs: Vec<Expr>, schema: &DFSchema) -> Result<Vec<Expr>> {
+        exprs
+            .into_iter()
+            .map(|expr| self.resolve_column(expr, schema))
+            .collect()
+    }
+
+    /// The configured `datafusion.sql_parser.duplicate_column_names` policy
+    fn duplicate_name_policy(&self) -> Result<DuplicateNamePolicy> {
+        self.session_state
+            .config_options()
+            .sql_parser
+            .duplicate_column_names
+            .parse()
+    }
+
     /// Repartition a DataFrame based on a logical partitioning scheme.
     ///
     /// # Example
@@ -1233,6 +1395,7 @@ impl DataFrame {
     /// # }
     /// ```
     pub fn with_column(self, name: &str, expr: Expr) -> Result<DataFrame> {
+        let expr = self.resolve_column(expr, self.plan.schema())?;
         let window_func_exprs = find_window_exprs(&[expr.clone()]);
         let plan = if window_func_exprs.is_empty() {
             self.plan
@@ -1414,6 +1577,58 @@ impl DataFrame {
         let mem_table = MemTable::try_new(schema, partitions)?;
         context.read_table(Arc::new(mem_table))
     }
+
+    /// Cache DataFrame as a memory table registered in the session under
+    /// `table_name`, returning a new DataFrame that scans the registered
+    /// table.
+    ///
+    /// Unlike [`DataFrame::cache`], the materialized results remain
+    /// reachable by name (for example from SQL) until the table is
+    /// deregistered. Any existing table with the same name is replaced.
+    ///
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?;
+    /// let df = df.persist("example").await?;
+    /// let results = ctx.sql("SELECT count(*) FROM example").await?.collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn persist(self, table_name: &str) -> Result<DataFrame> {
+        let context = SessionContext::new_with_state(self.session_state.clone());
+        let plan = self.clone().create_physical_plan().await?;
+        let schema = plan.schema();
+        let task_ctx = Arc::new(self.task_ctx());
+        let partitions = collect_partitioned(plan, task_ctx).await?;
+        let mem_table = MemTable::try_new(schema, partitions)?;
+        context.register_table(table_name, Arc::new(mem_table))?;
+        context.table(table_name).await
+    }
+}
+
+/// Renames or rejects duplicate output column names of a join according to
+/// `policy`, adding a projection on top of `plan` if any column is renamed
+fn resolve_join_duplicate_names(
+    plan: LogicalPlan,
+    policy: DuplicateNamePolicy,
+) -> Result<LogicalPlan> {
+    if policy == DuplicateNamePolicy::Keep {
+        return Ok(plan);
+    }
+    let columns = plan
+        .schema()
+        .iter()
+        .map(|(qualifier, field)| Expr::Column(Column::from((qualifier, field.as_ref()))))
+        .collect::<Vec<_>>();
+    let exprs = resolve_duplicate_names(columns.clone(), plan.schema(), policy)?;
+    if exprs == columns {
+        return Ok(plan);
+    }
+    LogicalPlanBuilder::from(plan).project(exprs)?.build()
 }
 
 struct DataFrameTableProvider {
@@ -1482,7 +1697,9 @@ mod tests {
     use crate::execution::context::SessionConfig;
     use crate::physical_plan::{ColumnarValue, Partitioning, PhysicalExpr};
     use crate::test_util::{register_aggregate_csv, test_table, test_table_with_name};
-    use crate::{assert_batches_sorted_eq, execution::context::SessionContext};
+    use crate::{
+        assert_batches_eq, assert_batches_sorted_eq, execution::context::SessionContext,
+    };
 
     use arrow::array::{self, Int32Array};
     use arrow::datatypes::DataType;
@@ -1635,6 +1852,25 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn select_exclude() -> Result<()> {
+        // build plan using Table API
+        let t = test_table().await?;
+        let t2 = t.select_exclude(&["c1", "c11"])?;
+        let plan = t2.plan.clone();
+
+        // build query using SQL
+        let sql_plan =
+            create_plan("SELECT * EXCEPT (c1, c11) FROM aggregate_test_100").await?;
+
+        // the two plans should be identical
+        assert_same_plan(&plan, &sql_plan);
+
+        let err = test_table().await?.select_exclude(&["c42"]).unwrap_err();
+        assert!(err.to_string().contains("c42"), "{err}");
+        Ok(())
+    }
+
     #[tokio::test]
     async fn select_expr() -> Result<()> {
         // build plan using Table API
@@ -2050,6 +2286,50 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn join_duplicate_column_names() -> Result<()> {
+        let config = SessionConfig::new()
+            .set_str("datafusion.sql_parser.duplicate_column_names", "suffix");
+        let mut ctx = SessionContext::new_with_config(config);
+        register_aggregate_csv(&mut ctx, "a").await?;
+        register_aggregate_csv(&mut ctx, "b").await?;
+        let left = ctx.table("a").await?.select_columns(&["c1", "c2"])?;
+        let right = ctx.table("b").await?.select_columns(&["c1", "c2"])?;
+        let join = left.join_on(right, JoinType::Inner, [col("a.c1").eq(col("b.c1"))])?;
+
+        let expected_plan = "Projection: a.c1, a.c2, b.c1 AS c1_1, b.c2 AS c2_1\
+        \n  Inner Join:  Filter: a.c1 = b.c1\
+        \n    Projection: a.c1, a.c2\
+        \n      TableScan: a\
+        \n    Projection: b.c1, b.c2\
+        \n      TableScan: b";
+        assert_eq!(expected_plan, format!("{:?}", join.logical_plan()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn case_insensitive_columns() -> Result<()> {
+        let config = SessionConfig::new().set_bool(
+            "datafusion.sql_parser.enable_case_insensitive_columns",
+            true,
+        );
+        let mut ctx = SessionContext::new_with_config(config);
+        register_aggregate_csv(&mut ctx, "a").await?;
+        register_aggregate_csv(&mut ctx, "b").await?;
+        let left = ctx.table("a").await?.select_columns(&["C1", "c2"])?;
+        let right = ctx.table("b").await?.select(vec![col("C1"), col("b.C2")])?;
+        let join = left
+            .join_on(right, JoinType::Inner, [col("a.C1").eq(col("b.C1"))])?
+            .filter(col("a.C2").gt(lit(1)))?
+            .sort(vec![col("b.C2").sort(true, true)])?;
+
+        let expected_plan = "Sort: b.c2 ASC NULLS FIRST        \n  Filter: a.c2 > Int32(1)        \n    Inner Join:  Filter: a.c1 = b.c1        \n      Projection: a.c1, a.c2        \n        TableScan: a        \n      Projection: b.c1, b.c2        \n        TableScan: b";
+        assert_eq!(expected_plan, format!("{:?}", join.logical_plan()));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn join_ambiguous_filter() -> Result<()> {
         let left = test_table_with_name("a")
@@ -2793,6 +3073,36 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn persist_test() -> Result<()> {
+        let ctx = SessionContext::new();
+        let df = ctx
+            .sql("SELECT * FROM (VALUES (1, 'a'), (2, 'b'), (3, 'c')) AS t(id, name)")
+            .await?
+            .filter(col("id").gt(lit(1)))?;
+
+        let persisted_df = df.clone().persist("persisted").await?;
+        assert_eq!(
+            "TableScan: persisted projection=[id, name]",
+            format!("{:?}", persisted_df.clone().into_optimized_plan()?)
+        );
+
+        // the materialized table is visible to the original context
+        let sql_results = ctx
+            .sql("SELECT count(*) AS cnt FROM persisted")
+            .await?
+            .collect()
+            .await?;
+        assert_batches_eq!(
+            ["+-----+", "| cnt |", "+-----+", "| 2   |", "+-----+"],
+            &sql_results
+        );
+
+        assert_eq!(df.collect().await?, persisted_df.collect().await?);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn partition_aware_union() -> Result<()> {
         let left = test_table().await?.select_columns(&["c1", "c2"])?;
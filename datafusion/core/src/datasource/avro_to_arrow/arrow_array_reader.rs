@@ -34,6 +34,7 @@ use crate::arrow::datatypes::{
 use crate::arrow::error::ArrowError;
 use crate::arrow::record_batch::RecordBatch;
 use crate::arrow::util::bit_util;
+use crate::datasource::avro_to_arrow::projected_reader::ProjectedReader;
 use crate::error::{DataFusionError, Result};
 use apache_avro::schema::RecordSchema;
 use apache_avro::{
@@ -53,8 +54,37 @@ use std::sync::Arc;
 
 type RecordSlice<'a> = &'a [&'a Vec<(String, Value)>];
 
+/// Reads the records of an Avro file
+enum RecordReader<'a, R: Read> {
+    /// Decodes all the fields of the records
+    All(AvroReader<'a, R>),
+    /// Only decodes the projected fields of the records
+    Projected(ProjectedReader<R>),
+}
+
+impl<'a, R: Read> Iterator for RecordReader<'a, R> {
+    type Item = ArrowResult<Vec<(String, Value)>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::All(reader) => reader.next().map(|value| match value {
+                Ok(Value::Record(v)) => Ok(v),
+                Err(e) => Err(ArrowError::ParseError(format!(
+                    "Failed to parse avro value: {e:?}"
+                ))),
+                other => Err(ArrowError::ParseError(format!(
+                    "Row needs to be of type object, got: {other:?}"
+                ))),
+            }),
+            Self::Projected(reader) => reader
+                .next()
+                .map(|record| record.map_err(|e| ArrowError::ExternalError(Box::new(e)))),
+        }
+    }
+}
+
 pub struct AvroArrowArrayReader<'a, R: Read> {
-    reader: AvroReader<'a, R>,
+    reader: RecordReader<'a, R>,
     schema: SchemaRef,
     projection: Option<Vec<String>>,
     schema_lookup: BTreeMap<String, usize>,
@@ -66,9 +96,30 @@ impl<'a, R: Read> AvroArrowArrayReader<'a, R> {
         schema: SchemaRef,
         projection: Option<Vec<String>>,
     ) -> Result<Self> {
-        let reader = AvroReader::new(reader)?;
-        let writer_schema = reader.writer_schema().clone();
-        let schema_lookup = Self::schema_lookup(writer_schema)?;
+        let (reader, schema_lookup) = match projection.as_deref() {
+            Some(names) if !names.is_empty() => {
+                let reader = ProjectedReader::try_new(reader, names)?;
+                // the records only have the projected fields
+                let mut schema_lookup = BTreeMap::new();
+                for (i, field) in reader.projected_fields().enumerate() {
+                    schema_lookup.insert(field.name.clone(), i);
+                }
+                for field in reader.projected_fields() {
+                    Self::child_schema_lookup(
+                        &field.name,
+                        &field.schema,
+                        &mut schema_lookup,
+                    )?;
+                }
+                (RecordReader::Projected(reader), schema_lookup)
+            }
+            _ => {
+                let reader = AvroReader::new(reader)?;
+                let writer_schema = reader.writer_schema().clone();
+                let schema_lookup = Self::schema_lookup(writer_schema)?;
+                (RecordReader::All(reader), schema_lookup)
+            }
+        };
         Ok(Self {
             reader,
             schema,
@@ -151,15 +202,6 @@ impl<'a, R: Read> AvroArrowArrayReader<'a, R> {
             .reader
             .by_ref()
             .take(batch_size)
-            .map(|value| match value {
-                Ok(Value::Record(v)) => Ok(v),
-                Err(e) => Err(ArrowError::ParseError(format!(
-                    "Failed to parse avro value: {e:?}"
-                ))),
-                other => Err(ArrowError::ParseError(format!(
-                    "Row needs to be of type object, got: {other:?}"
-                ))),
-            })
             .collect::<ArrowResult<Vec<Vec<(String, Value)>>>>();
 
         let rows = match rows_result {
@@ -171,18 +213,16 @@ impl<'a, R: Read> AvroArrowArrayReader<'a, R> {
         };
 
         let rows = rows.iter().collect::<Vec<&Vec<(String, Value)>>>();
-        let projection = self.projection.clone().unwrap_or_default();
-        let arrays =
-            self.build_struct_array(&rows, "", self.schema.fields(), &projection);
-        let projected_fields = if projection.is_empty() {
-            self.schema.fields().clone()
-        } else {
-            projection
+        // the columns are in the order of the projection
+        let projected_fields: Fields = match &self.projection {
+            Some(projection) if !projection.is_empty() => projection
                 .iter()
                 .filter_map(|name| self.schema.column_with_name(name))
                 .map(|(_, field)| field.clone())
-                .collect()
+                .collect(),
+            _ => self.schema.fields().clone(),
         };
+        let arrays = self.build_struct_array(&rows, "", &projected_fields, &[]);
         let projected_schema = Arc::new(Schema::new(projected_fields));
         Some(arrays.and_then(|arr| RecordBatch::try_new(projected_schema, arr)))
     }
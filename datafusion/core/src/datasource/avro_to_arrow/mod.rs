@@ -15,16 +15,21 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! This module contains code for reading [Avro] data into `RecordBatch`es
+//! This module contains code for reading [Avro] data into `RecordBatch`es, and
+//! for converting `RecordBatch`es to Avro records
 //!
 //! [Avro]: https://avro.apache.org/docs/1.2.0/
 
 #[cfg(feature = "avro")]
 mod arrow_array_reader;
 #[cfg(feature = "avro")]
+mod projected_reader;
+#[cfg(feature = "avro")]
 mod reader;
 #[cfg(feature = "avro")]
 mod schema;
+#[cfg(feature = "avro")]
+pub(crate) mod writer;
 
 use crate::arrow::datatypes::Schema;
 use crate::error::Result;
@@ -0,0 +1,329 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Reading the projected fields of the records of Avro files
+
+use crate::error::{DataFusionError, Result};
+use apache_avro::schema::{
+    DecimalSchema, FixedSchema, RecordField, RecordSchema, Schema as AvroSchema,
+};
+use apache_avro::types::Value;
+use apache_avro::{from_avro_datum, Codec};
+use datafusion_common::exec_err;
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use std::str::FromStr;
+
+/// The magic bytes at the start of Avro object container files
+const MAGIC: [u8; 4] = [b'O', b'b', b'j', 1];
+
+/// The size of the sync marker which ends the header and every block
+const SYNC_SIZE: usize = 16;
+
+/// Reads the records of an Avro object container file, only decoding the
+/// top level fields that are part of a projection.
+///
+/// The other fields are skipped over without being materialized. Each record
+/// is returned as the name and value of the projected fields, in the order of
+/// the writer schema.
+pub struct ProjectedReader<R: Read> {
+    reader: R,
+    writer_schema: AvroSchema,
+    /// The fields of the writer schema, and whether they are projected
+    fields: Vec<(RecordField, bool)>,
+    /// Whether the writer schema refers to named types by name. As the
+    /// fields can't be decoded separately then, whole records are decoded.
+    decode_records: bool,
+    codec: Codec,
+    sync: [u8; SYNC_SIZE],
+    /// The decompressed data of the current block
+    block: Cursor<Vec<u8>>,
+    /// The number of records left in the current block
+    remaining: i64,
+}
+
+impl<R: Read> ProjectedReader<R> {
+    /// Reads the header of the file in `reader`, to read the fields named
+    /// in `projection`
+    pub fn try_new(mut reader: R, projection: &[String]) -> Result<Self> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return exec_err!("Not an Avro object container file");
+        }
+
+        let metadata = read_metadata(&mut reader)?;
+        let Some(schema) = metadata.get("avro.schema") else {
+            return exec_err!("Avro file has no schema");
+        };
+        let writer_schema = AvroSchema::parse_str(to_str(schema)?)?;
+        let codec = match metadata.get("avro.codec") {
+            Some(codec) => {
+                let codec = to_str(codec)?;
+                Codec::from_str(codec).map_err(|_| {
+                    DataFusionError::NotImplemented(format!(
+                        "Unsupported Avro codec {codec}"
+                    ))
+                })?
+            }
+            None => Codec::Null,
+        };
+        let mut sync = [0; SYNC_SIZE];
+        reader.read_exact(&mut sync)?;
+
+        let AvroSchema::Record(RecordSchema { fields, .. }) = &writer_schema else {
+            return exec_err!("expected avro schema to be a record");
+        };
+        let fields = fields
+            .iter()
+            .map(|field| (field.clone(), projection.contains(&field.name)))
+            .collect();
+        let decode_records = has_references(&writer_schema);
+
+        Ok(Self {
+            reader,
+            writer_schema,
+            fields,
+            decode_records,
+            codec,
+            sync,
+            block: Cursor::new(vec![]),
+            remaining: 0,
+        })
+    }
+
+    /// The projected fields of the writer schema
+    pub fn projected_fields(&self) -> impl Iterator<Item = &RecordField> {
+        self.fields
+            .iter()
+            .filter(|(_, projected)| *projected)
+            .map(|(field, _)| field)
+    }
+
+    /// Reads the next non empty block, returning false at the end of the file
+    fn read_block(&mut self) -> Result<bool> {
+        loop {
+            let mut first = [0; 1];
+            if self.reader.read(&mut first)? == 0 {
+                return Ok(false);
+            }
+            let count = read_long(&mut first.as_slice().chain(&mut self.reader))?;
+            let size = read_len(&mut self.reader)?;
+            let mut data = vec![0; size];
+            self.reader.read_exact(&mut data)?;
+
+            let mut sync = [0; SYNC_SIZE];
+            self.reader.read_exact(&mut sync)?;
+            if sync != self.sync {
+                return exec_err!("Avro block does not end with the sync marker");
+            }
+
+            if count > 0 {
+                self.codec.decompress(&mut data)?;
+                self.block = Cursor::new(data);
+                self.remaining = count;
+                return Ok(true);
+            }
+        }
+    }
+
+    fn read_record(&mut self) -> Result<Vec<(String, Value)>> {
+        if self.decode_records {
+            let Value::Record(values) =
+                from_avro_datum(&self.writer_schema, &mut self.block, None)?
+            else {
+                return exec_err!("expected avro value to be a record");
+            };
+            return Ok(values
+                .into_iter()
+                .zip(&self.fields)
+                .filter(|(_, (_, projected))| *projected)
+                .map(|(value, _)| value)
+                .collect());
+        }
+
+        let mut values = vec![];
+        for (field, projected) in &self.fields {
+            if *projected {
+                let value = from_avro_datum(&field.schema, &mut self.block, None)?;
+                values.push((field.name.clone(), value));
+            } else {
+                skip_value(&field.schema, &mut self.block)?;
+            }
+        }
+        Ok(values)
+    }
+}
+
+impl<R: Read> Iterator for ProjectedReader<R> {
+    type Item = Result<Vec<(String, Value)>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            match self.read_block() {
+                Ok(true) => {}
+                Ok(false) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        self.remaining -= 1;
+        Some(self.read_record())
+    }
+}
+
+fn to_str(bytes: &[u8]) -> Result<&str> {
+    std::str::from_utf8(bytes).map_err(|e| DataFusionError::External(Box::new(e)))
+}
+
+/// Reads the metadata map of the header of an Avro file
+fn read_metadata<R: Read>(reader: &mut R) -> Result<HashMap<String, Vec<u8>>> {
+    let mut metadata = HashMap::new();
+    loop {
+        let count = read_long(reader)?;
+        if count == 0 {
+            return Ok(metadata);
+        }
+        if count < 0 {
+            // the block starts with its size in bytes
+            read_long(reader)?;
+        }
+        for _ in 0..count.unsigned_abs() {
+            let key = String::from_utf8(read_bytes(reader)?)
+                .map_err(|e| DataFusionError::External(Box::new(e)))?;
+            metadata.insert(key, read_bytes(reader)?);
+        }
+    }
+}
+
+/// Reads a zigzag encoded variable length long
+fn read_long<R: Read>(reader: &mut R) -> Result<i64> {
+    let mut value = 0_u64;
+    let mut shift = 0;
+    loop {
+        let mut byte = [0; 1];
+        reader.read_exact(&mut byte)?;
+        value |= u64::from(byte[0] & 0x7f) << shift;
+        if byte[0] & 0x80 == 0 {
+            return Ok((value >> 1) as i64 ^ -((value & 1) as i64));
+        }
+        shift += 7;
+        if shift > 63 {
+            return exec_err!("Invalid variable length long in Avro data");
+        }
+    }
+}
+
+fn read_len<R: Read>(reader: &mut R) -> Result<usize> {
+    let len = read_long(reader)?;
+    usize::try_from(len).or_else(|_| exec_err!("Invalid length {len} in Avro data"))
+}
+
+fn read_bytes<R: Read>(reader: &mut R) -> Result<Vec<u8>> {
+    let mut bytes = vec![0; read_len(reader)?];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn skip_bytes<R: Read>(reader: &mut R, len: usize) -> Result<()> {
+    let skipped = std::io::copy(&mut reader.take(len as u64), &mut std::io::sink())?;
+    if skipped != len as u64 {
+        return exec_err!("Unexpected end of Avro data");
+    }
+    Ok(())
+}
+
+/// Skips over the blocks of an array or map, calling `skip_item` for
+/// the items of blocks without a size
+fn skip_blocks<R: Read>(
+    reader: &mut R,
+    mut skip_item: impl FnMut(&mut R) -> Result<()>,
+) -> Result<()> {
+    loop {
+        let count = read_long(reader)?;
+        if count == 0 {
+            return Ok(());
+        }
+        if count < 0 {
+            let size = read_len(reader)?;
+            skip_bytes(reader, size)?;
+        } else {
+            for _ in 0..count {
+                skip_item(reader)?;
+            }
+        }
+    }
+}
+
+/// Skips over a value of `schema`
+fn skip_value<R: Read>(schema: &AvroSchema, reader: &mut R) -> Result<()> {
+    match schema {
+        AvroSchema::Null => Ok(()),
+        AvroSchema::Boolean => skip_bytes(reader, 1),
+        AvroSchema::Int
+        | AvroSchema::Long
+        | AvroSchema::Enum(_)
+        | AvroSchema::Date
+        | AvroSchema::TimeMillis
+        | AvroSchema::TimeMicros
+        | AvroSchema::TimestampMillis
+        | AvroSchema::TimestampMicros
+        | AvroSchema::LocalTimestampMillis
+        | AvroSchema::LocalTimestampMicros => read_long(reader).map(|_| ()),
+        AvroSchema::Float => skip_bytes(reader, 4),
+        AvroSchema::Double => skip_bytes(reader, 8),
+        AvroSchema::Bytes | AvroSchema::String | AvroSchema::Uuid => {
+            let len = read_len(reader)?;
+            skip_bytes(reader, len)
+        }
+        AvroSchema::Fixed(FixedSchema { size, .. }) => skip_bytes(reader, *size),
+        AvroSchema::Duration => skip_bytes(reader, 12),
+        AvroSchema::Decimal(DecimalSchema { inner, .. }) => skip_value(inner, reader),
+        AvroSchema::Array(items) => skip_blocks(reader, |r| skip_value(items, r)),
+        AvroSchema::Map(values) => skip_blocks(reader, |r| {
+            let len = read_len(r)?;
+            skip_bytes(r, len)?;
+            skip_value(values, r)
+        }),
+        AvroSchema::Union(union) => {
+            let index = read_len(reader)?;
+            match union.variants().get(index) {
+                Some(variant) => skip_value(variant, reader),
+                None => exec_err!("Invalid union index {index} in Avro data"),
+            }
+        }
+        AvroSchema::Record(RecordSchema { fields, .. }) => fields
+            .iter()
+            .try_for_each(|field| skip_value(&field.schema, reader)),
+        AvroSchema::Ref { name } => {
+            exec_err!("Cannot skip Avro values of named type {}", name.name)
+        }
+    }
+}
+
+/// Returns true if `schema` refers to a named type by name
+fn has_references(schema: &AvroSchema) -> bool {
+    match schema {
+        AvroSchema::Ref { .. } => true,
+        AvroSchema::Array(items) => has_references(items),
+        AvroSchema::Map(values) => has_references(values),
+        AvroSchema::Union(union) => union.variants().iter().any(has_references),
+        AvroSchema::Record(RecordSchema { fields, .. }) => {
+            fields.iter().any(|field| has_references(&field.schema))
+        }
+        _ => false,
+    }
+}
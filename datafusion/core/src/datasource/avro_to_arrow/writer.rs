@@ -0,0 +1,363 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Converting `RecordBatch`es to Avro records for writing Avro files
+
+use crate::arrow::array::{Array, ArrayRef, AsArray};
+use crate::arrow::compute::{cast, cast_with_options, CastOptions};
+use crate::arrow::datatypes::{
+    DataType, Date32Type, Decimal128Type, Field, Fields, Float32Type, Float64Type,
+    Int32Type, Int64Type, Schema, Time32MillisecondType, Time64MicrosecondType, TimeUnit,
+    TimestampMicrosecondType, TimestampMillisecondType,
+};
+use crate::arrow::record_batch::RecordBatch;
+use crate::error::Result;
+use apache_avro::types::Value;
+use apache_avro::{Decimal, Schema as AvroSchema};
+use arrow::datatypes::ArrowPrimitiveType;
+use datafusion_common::not_impl_err;
+use serde_json::{json, Value as JsonValue};
+
+/// Returns the Avro schema of the records of a `RecordBatch` of `schema`.
+///
+/// Nullable columns are unions of `null` and the type of the column. Structs
+/// are records, lists are arrays and dictionaries have the type of their
+/// values.
+pub fn to_avro_schema(schema: &Schema) -> Result<AvroSchema> {
+    let mut names = 0;
+    let json = record_type(schema.fields(), &mut names)?;
+    Ok(AvroSchema::parse(&json)?)
+}
+
+/// Returns the rows of `batch` as Avro records of [`to_avro_schema`]
+pub fn to_avro_records(batch: &RecordBatch) -> Result<Vec<Value>> {
+    let schema = batch.schema();
+    let columns = batch
+        .columns()
+        .iter()
+        .zip(schema.fields())
+        .map(|(column, field)| field_values(column, field))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(records(schema.fields(), columns, batch.num_rows()))
+}
+
+/// Returns a name for a named Avro type, which is unique within a schema
+fn next_name(prefix: &str, names: &mut usize) -> String {
+    *names += 1;
+    format!("{prefix}{names}")
+}
+
+fn record_type(fields: &Fields, names: &mut usize) -> Result<JsonValue> {
+    let fields = fields
+        .iter()
+        .map(|field| {
+            Ok(json!({
+                "name": field.name(),
+                "type": field_type(field, names)?,
+            }))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(json!({
+        "type": "record",
+        "name": next_name("record", names),
+        "fields": fields,
+    }))
+}
+
+fn field_type(field: &Field, names: &mut usize) -> Result<JsonValue> {
+    let data_type = avro_type(field.data_type(), names)?;
+    Ok(if is_union(field) {
+        json!(["null", data_type])
+    } else {
+        data_type
+    })
+}
+
+/// Returns true if the values of `field` are unions with null
+fn is_union(field: &Field) -> bool {
+    field.is_nullable() && field.data_type() != &DataType::Null
+}
+
+fn avro_type(data_type: &DataType, names: &mut usize) -> Result<JsonValue> {
+    Ok(match data_type {
+        DataType::Null => json!("null"),
+        DataType::Boolean => json!("boolean"),
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::UInt8
+        | DataType::UInt16 => json!("int"),
+        DataType::Int64 | DataType::UInt32 | DataType::UInt64 => json!("long"),
+        DataType::Float32 => json!("float"),
+        DataType::Float64 => json!("double"),
+        DataType::Utf8 | DataType::LargeUtf8 => json!("string"),
+        DataType::Binary | DataType::LargeBinary => json!("bytes"),
+        DataType::FixedSizeBinary(size) => json!({
+            "type": "fixed",
+            "name": next_name("fixed", names),
+            "size": size,
+        }),
+        DataType::Decimal128(precision, scale) => json!({
+            "type": "bytes",
+            "logicalType": "decimal",
+            "precision": precision,
+            "scale": scale,
+        }),
+        DataType::Date32 => json!({"type": "int", "logicalType": "date"}),
+        DataType::Time32(TimeUnit::Millisecond) => {
+            json!({"type": "int", "logicalType": "time-millis"})
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            json!({"type": "long", "logicalType": "time-micros"})
+        }
+        DataType::Timestamp(TimeUnit::Second | TimeUnit::Millisecond, _) => {
+            json!({"type": "long", "logicalType": "timestamp-millis"})
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            json!({"type": "long", "logicalType": "timestamp-micros"})
+        }
+        DataType::List(field) | DataType::LargeList(field) => json!({
+            "type": "array",
+            "items": field_type(field, names)?,
+        }),
+        DataType::Struct(fields) => record_type(fields, names)?,
+        DataType::Dictionary(_, value_type) => avro_type(value_type, names)?,
+        other => {
+            return not_impl_err!("Writing {other} columns to Avro is not supported")
+        }
+    })
+}
+
+/// Returns the Avro values of a column of `field`
+fn field_values(array: &ArrayRef, field: &Field) -> Result<Vec<Value>> {
+    let union = is_union(field);
+    Ok(avro_values(array)?
+        .into_iter()
+        .map(|value| match (union, value) {
+            (true, Some(value)) => Value::Union(1, Box::new(value)),
+            (true, None) => Value::Union(0, Box::new(Value::Null)),
+            (false, value) => value.unwrap_or(Value::Null),
+        })
+        .collect())
+}
+
+/// Returns the records of the `columns` of `fields`
+fn records(fields: &Fields, columns: Vec<Vec<Value>>, len: usize) -> Vec<Value> {
+    let mut columns = columns
+        .into_iter()
+        .map(|column| column.into_iter())
+        .collect::<Vec<_>>();
+    (0..len)
+        .map(|_| {
+            let values = fields
+                .iter()
+                .zip(columns.iter_mut())
+                .map(|(field, column)| {
+                    (field.name().clone(), column.next().unwrap_or(Value::Null))
+                })
+                .collect();
+            Value::Record(values)
+        })
+        .collect()
+}
+
+fn primitive_values<T: ArrowPrimitiveType>(
+    array: &ArrayRef,
+    to_value: impl Fn(T::Native) -> Value,
+) -> Vec<Option<Value>> {
+    array
+        .as_primitive::<T>()
+        .iter()
+        .map(|v| v.map(&to_value))
+        .collect()
+}
+
+/// Returns the Avro values of `array`, which are `None` for nulls
+fn avro_values(array: &ArrayRef) -> Result<Vec<Option<Value>>> {
+    Ok(match array.data_type() {
+        DataType::Null => vec![None; array.len()],
+        DataType::Boolean => array
+            .as_boolean()
+            .iter()
+            .map(|v| v.map(Value::Boolean))
+            .collect(),
+        DataType::Int8 | DataType::Int16 | DataType::UInt8 | DataType::UInt16 => {
+            avro_values(&cast(array, &DataType::Int32)?)?
+        }
+        DataType::UInt32 | DataType::UInt64 => {
+            // fail rather than wrap around for values that don't fit
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
+            avro_values(&cast_with_options(array, &DataType::Int64, &options)?)?
+        }
+        DataType::Int32 => primitive_values::<Int32Type>(array, Value::Int),
+        DataType::Int64 => primitive_values::<Int64Type>(array, Value::Long),
+        DataType::Float32 => primitive_values::<Float32Type>(array, Value::Float),
+        DataType::Float64 => primitive_values::<Float64Type>(array, Value::Double),
+        DataType::Utf8 => array
+            .as_string::<i32>()
+            .iter()
+            .map(|v| v.map(|s| Value::String(s.to_string())))
+            .collect(),
+        DataType::LargeUtf8 => array
+            .as_string::<i64>()
+            .iter()
+            .map(|v| v.map(|s| Value::String(s.to_string())))
+            .collect(),
+        DataType::Binary => array
+            .as_binary::<i32>()
+            .iter()
+            .map(|v| v.map(|b| Value::Bytes(b.to_vec())))
+            .collect(),
+        DataType::LargeBinary => array
+            .as_binary::<i64>()
+            .iter()
+            .map(|v| v.map(|b| Value::Bytes(b.to_vec())))
+            .collect(),
+        DataType::FixedSizeBinary(size) => array
+            .as_fixed_size_binary()
+            .iter()
+            .map(|v| v.map(|b| Value::Fixed(*size as usize, b.to_vec())))
+            .collect(),
+        DataType::Decimal128(_, _) => primitive_values::<Decimal128Type>(array, |v| {
+            Value::Decimal(Decimal::from(v.to_be_bytes().to_vec()))
+        }),
+        DataType::Date32 => primitive_values::<Date32Type>(array, Value::Date),
+        DataType::Time32(TimeUnit::Millisecond) => {
+            primitive_values::<Time32MillisecondType>(array, Value::TimeMillis)
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            primitive_values::<Time64MicrosecondType>(array, Value::TimeMicros)
+        }
+        DataType::Timestamp(TimeUnit::Second, tz) => {
+            let data_type = DataType::Timestamp(TimeUnit::Millisecond, tz.clone());
+            avro_values(&cast(array, &data_type)?)?
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            primitive_values::<TimestampMillisecondType>(array, Value::TimestampMillis)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            primitive_values::<TimestampMicrosecondType>(array, Value::TimestampMicros)
+        }
+        DataType::List(field) => {
+            let list = array.as_list::<i32>();
+            list.iter()
+                .map(|v| v.map(|items| list_value(&items, field)).transpose())
+                .collect::<Result<_>>()?
+        }
+        DataType::LargeList(field) => {
+            let list = array.as_list::<i64>();
+            list.iter()
+                .map(|v| v.map(|items| list_value(&items, field)).transpose())
+                .collect::<Result<_>>()?
+        }
+        DataType::Struct(fields) => {
+            let array = array.as_struct();
+            let columns = array
+                .columns()
+                .iter()
+                .zip(fields)
+                .map(|(column, field)| field_values(column, field))
+                .collect::<Result<Vec<_>>>()?;
+            records(fields, columns, array.len())
+                .into_iter()
+                .enumerate()
+                .map(|(i, record)| array.is_valid(i).then_some(record))
+                .collect()
+        }
+        DataType::Dictionary(_, value_type) => avro_values(&cast(array, value_type)?)?,
+        other => {
+            return not_impl_err!("Writing {other} columns to Avro is not supported")
+        }
+    })
+}
+
+fn list_value(items: &ArrayRef, field: &Field) -> Result<Value> {
+    Ok(Value::Array(field_values(items, field)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::array::{Int32Array, ListArray, StringArray, StructArray};
+    use std::sync::Arc;
+
+    #[test]
+    fn nested_batch_to_avro() -> Result<()> {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+        ]);
+        let point = StructArray::from(vec![(
+            Arc::new(Field::new("x", DataType::Utf8, false)),
+            Arc::new(StringArray::from(vec!["a", "b"])) as ArrayRef,
+        )]);
+        let batch = RecordBatch::try_from_iter(vec![
+            ("list", Arc::new(list) as ArrayRef),
+            ("point", Arc::new(point) as ArrayRef),
+        ])?;
+
+        let schema = to_avro_schema(&batch.schema())?;
+        let records = to_avro_records(&batch)?;
+        for record in &records {
+            assert!(record.validate(&schema));
+        }
+
+        let null = || Box::new(Value::Null);
+        let expected = Value::Record(vec![
+            (
+                "list".to_string(),
+                Value::Union(
+                    1,
+                    Box::new(Value::Array(vec![
+                        Value::Union(1, Box::new(Value::Int(1))),
+                        Value::Union(0, null()),
+                    ])),
+                ),
+            ),
+            (
+                "point".to_string(),
+                Value::Record(vec![("x".to_string(), Value::String("a".to_string()))]),
+            ),
+        ]);
+        assert_eq!(records[0], expected);
+        let expected = Value::Record(vec![
+            ("list".to_string(), Value::Union(0, null())),
+            (
+                "point".to_string(),
+                Value::Record(vec![("x".to_string(), Value::String("b".to_string()))]),
+            ),
+        ]);
+        assert_eq!(records[1], expected);
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_type() {
+        let schema = Schema::new(vec![Field::new(
+            "t",
+            DataType::Timestamp(TimeUnit::Nanosecond, None),
+            false,
+        )]);
+        let err = to_avro_schema(&schema).unwrap_err();
+        assert!(err.to_string().contains(
+            "Writing Timestamp(Nanosecond, None) columns to Avro is not supported"
+        ));
+    }
+}
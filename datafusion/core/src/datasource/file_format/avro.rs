@@ -18,22 +18,37 @@
 //! [`AvroFormat`] Apache Avro [`FileFormat`] abstractions
 
 use std::any::Any;
+use std::fmt::{self, Debug};
 use std::sync::Arc;
 
 use arrow::datatypes::Schema;
 use arrow::{self, datatypes::SchemaRef};
 use async_trait::async_trait;
-use datafusion_common::FileType;
-use datafusion_physical_expr::PhysicalExpr;
+use datafusion_common::{not_impl_err, FileType};
+use datafusion_execution::{SendableRecordBatchStream, TaskContext};
+use datafusion_physical_expr::{PhysicalExpr, PhysicalSortRequirement};
+use datafusion_physical_plan::insert::{DataSink, FileSinkExec};
+use datafusion_physical_plan::metrics::MetricsSet;
 use object_store::{GetResultPayload, ObjectMeta, ObjectStore};
 
 use super::FileFormat;
 use crate::datasource::avro_to_arrow::read_avro_schema_from_reader;
-use crate::datasource::physical_plan::{AvroExec, FileScanConfig};
+use crate::datasource::physical_plan::{
+    AvroExec, FileGroupDisplay, FileScanConfig, FileSinkConfig,
+};
 use crate::error::Result;
 use crate::execution::context::SessionState;
-use crate::physical_plan::ExecutionPlan;
 use crate::physical_plan::Statistics;
+use crate::physical_plan::{DisplayAs, DisplayFormatType, ExecutionPlan};
+
+/// Initial writing buffer size. Note this is just a size hint for efficiency. It
+/// will grow beyond the set value if needed.
+#[cfg(feature = "avro")]
+const INITIAL_BUFFER_BYTES: usize = 1048576;
+
+/// If the buffered Avro data exceeds this size, it is flushed to object store
+#[cfg(feature = "avro")]
+const BUFFER_FLUSH_BYTES: usize = 1024000;
 
 /// Avro `FileFormat` implementation.
 #[derive(Default, Debug)]
@@ -90,16 +105,213 @@ impl FileFormat for AvroFormat {
         Ok(Arc::new(exec))
     }
 
+    async fn create_writer_physical_plan(
+        &self,
+        input: Arc<dyn ExecutionPlan>,
+        _state: &SessionState,
+        conf: FileSinkConfig,
+        order_requirements: Option<Vec<PhysicalSortRequirement>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        if conf.overwrite {
+            return not_impl_err!("Overwrites are not implemented yet for Avro format");
+        }
+
+        let sink_schema = conf.output_schema().clone();
+        let sink = Arc::new(AvroSink::new(conf));
+
+        Ok(Arc::new(FileSinkExec::new(
+            input,
+            sink,
+            sink_schema,
+            order_requirements,
+        )) as _)
+    }
+
     fn file_type(&self) -> FileType {
         FileType::AVRO
     }
 }
 
+/// Implements [`DataSink`] for writing to Avro files
+///
+/// Writing Avro files requires the `avro` feature.
+pub struct AvroSink {
+    config: FileSinkConfig,
+}
+
+impl AvroSink {
+    /// Create from config.
+    pub fn new(config: FileSinkConfig) -> Self {
+        Self { config }
+    }
+
+    /// Retrieve the inner [`FileSinkConfig`].
+    pub fn config(&self) -> &FileSinkConfig {
+        &self.config
+    }
+
+    /// Converts table schema to writer schema, which may differ in the case
+    /// of hive style partitioning where some columns are removed from the
+    /// underlying files.
+    #[cfg(feature = "avro")]
+    fn get_writer_schema(&self) -> Arc<Schema> {
+        if !self.config.table_partition_cols.is_empty() {
+            let schema = self.config.output_schema();
+            let partition_names: Vec<_> = self
+                .config
+                .table_partition_cols
+                .iter()
+                .map(|(s, _)| s)
+                .collect();
+            Arc::new(Schema::new(
+                schema
+                    .fields()
+                    .iter()
+                    .filter(|f| !partition_names.contains(&f.name()))
+                    .map(|f| (**f).clone())
+                    .collect::<Vec<_>>(),
+            ))
+        } else {
+            self.config.output_schema().clone()
+        }
+    }
+}
+
+impl Debug for AvroSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AvroSink").finish()
+    }
+}
+
+impl DisplayAs for AvroSink {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                write!(f, "AvroSink(file_groups=",)?;
+                FileGroupDisplay(&self.config.file_groups).fmt_as(t, f)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl DataSink for AvroSink {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        None
+    }
+
+    #[cfg(not(feature = "avro"))]
+    async fn write_all(
+        &self,
+        _data: SendableRecordBatchStream,
+        _context: &Arc<TaskContext>,
+    ) -> Result<u64> {
+        not_impl_err!("Cannot write avro files without avro feature enabled")
+    }
+
+    #[cfg(feature = "avro")]
+    async fn write_all(
+        &self,
+        data: SendableRecordBatchStream,
+        context: &Arc<TaskContext>,
+    ) -> Result<u64> {
+        use super::file_compression_type::FileCompressionType;
+        use super::write::demux::start_demuxer_task;
+        use super::write::{create_writer, SharedBuffer};
+        use crate::datasource::avro_to_arrow::writer::{to_avro_records, to_avro_schema};
+        use crate::error::DataFusionError;
+        use tokio::io::AsyncWriteExt;
+        use tokio::task::JoinSet;
+
+        let object_store = context
+            .runtime_env()
+            .object_store(&self.config.object_store_url)?;
+        let avro_schema = Arc::new(to_avro_schema(&self.get_writer_schema())?);
+
+        let part_col = if !self.config.table_partition_cols.is_empty() {
+            Some(self.config.table_partition_cols.clone())
+        } else {
+            None
+        };
+
+        let (demux_task, mut file_stream_rx) = start_demuxer_task(
+            data,
+            context,
+            part_col,
+            self.config.table_paths[0].clone(),
+            "avro".into(),
+        );
+
+        let mut file_write_tasks: JoinSet<std::result::Result<usize, DataFusionError>> =
+            JoinSet::new();
+
+        while let Some((path, mut rx)) = file_stream_rx.recv().await {
+            let avro_schema = avro_schema.clone();
+            let mut object_store_writer = create_writer(
+                FileCompressionType::UNCOMPRESSED,
+                &path,
+                object_store.clone(),
+            )
+            .await?;
+            file_write_tasks.spawn(async move {
+                let shared_buffer = SharedBuffer::new(INITIAL_BUFFER_BYTES);
+                let mut avro_writer =
+                    apache_avro::Writer::new(&avro_schema, shared_buffer.clone());
+                let mut row_count = 0;
+                while let Some(batch) = rx.recv().await {
+                    row_count += batch.num_rows();
+                    avro_writer.extend(to_avro_records(&batch)?)?;
+                    avro_writer.flush()?;
+                    let mut buff_to_flush = shared_buffer.buffer.try_lock().unwrap();
+                    if buff_to_flush.len() > BUFFER_FLUSH_BYTES {
+                        object_store_writer
+                            .write_all(buff_to_flush.as_slice())
+                            .await?;
+                        buff_to_flush.clear();
+                    }
+                }
+                // writes the header of files without rows
+                avro_writer.into_inner()?;
+                let final_buff = shared_buffer.buffer.try_lock().unwrap();
+
+                object_store_writer.write_all(final_buff.as_slice()).await?;
+                object_store_writer.shutdown().await?;
+                Ok(row_count)
+            });
+        }
+
+        let mut row_count = 0;
+        while let Some(result) = file_write_tasks.join_next().await {
+            match result {
+                Ok(r) => {
+                    row_count += r?;
+                }
+                Err(e) => {
+                    if e.is_panic() {
+                        std::panic::resume_unwind(e.into_panic());
+                    } else {
+                        unreachable!();
+                    }
+                }
+            }
+        }
+
+        demux_task.join_unwind().await?;
+        Ok(row_count as u64)
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "avro")]
 mod tests {
     use super::*;
     use crate::datasource::file_format::test_util::scan_format;
+    use crate::execution::options::AvroReadOptions;
     use crate::physical_plan::collect;
     use crate::prelude::{SessionConfig, SessionContext};
     use arrow::array::{as_string_array, Array};
@@ -439,6 +651,63 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn read_projection_out_of_order() -> Result<()> {
+        let session_ctx = SessionContext::new();
+        let state = session_ctx.state();
+        let task_ctx = state.task_ctx();
+        let projection = Some(vec![1, 0]);
+        let exec = get_exec(&state, "alltypes_plain.avro", projection, None).await?;
+
+        let batches = collect(exec, task_ctx).await?;
+        assert_eq!(batches.len(), 1);
+        let schema = batches[0].schema();
+        let names = schema.fields().iter().map(|f| f.name()).collect::<Vec<_>>();
+        assert_eq!(names, ["bool_col", "id"]);
+
+        let array = as_int32_array(batches[0].column(1))?;
+        let mut values: Vec<i32> = vec![];
+        for i in 0..batches[0].num_rows() {
+            values.push(array.value(i));
+        }
+        assert_eq!("[4, 5, 6, 7, 2, 3, 0, 1]", format!("{values:?}"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_and_read_avro() -> Result<()> {
+        let ctx = SessionContext::new();
+        let tmp_dir = tempfile::TempDir::new()?;
+        let path = format!("{}/", tmp_dir.path().to_str().unwrap());
+
+        ctx.sql(&format!(
+            "COPY (VALUES (1, 'a', [1, 2]), (2, NULL, NULL)) TO '{path}' STORED AS AVRO"
+        ))
+        .await?
+        .collect()
+        .await?;
+
+        ctx.register_avro("t", &path, AvroReadOptions::default())
+            .await?;
+        let batches = ctx
+            .sql("SELECT column3, column1 FROM t ORDER BY column1")
+            .await?
+            .collect()
+            .await?;
+        let expected = [
+            "+---------+---------+",
+            "| column3 | column1 |",
+            "+---------+---------+",
+            "| [1, 2]  | 1       |",
+            "|         | 2       |",
+            "+---------+---------+",
+        ];
+        crate::assert_batches_eq!(expected, &batches);
+
+        Ok(())
+    }
+
     async fn get_exec(
         state: &SessionState,
         file_name: &str,
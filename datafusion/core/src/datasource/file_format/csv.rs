@@ -27,7 +27,7 @@ use super::FileFormat;
 use crate::datasource::file_format::file_compression_type::FileCompressionType;
 use crate::datasource::file_format::write::BatchSerializer;
 use crate::datasource::physical_plan::{
-    CsvExec, FileGroupDisplay, FileScanConfig, FileSinkConfig,
+    null_regex, CsvExec, FileGroupDisplay, FileScanConfig, FileSinkConfig,
 };
 use crate::error::Result;
 use crate::execution::context::SessionState;
@@ -38,9 +38,10 @@ use crate::physical_plan::{ExecutionPlan, SendableRecordBatchStream};
 use arrow::array::RecordBatch;
 use arrow::csv::WriterBuilder;
 use arrow::datatypes::{DataType, Field, Fields, Schema};
+use arrow::util::display::{ArrayFormatter, FormatOptions};
 use arrow::{self, datatypes::SchemaRef};
 use datafusion_common::config::CsvOptions;
-use datafusion_common::file_options::csv_writer::CsvWriterOptions;
+use datafusion_common::file_options::csv_writer::{CsvQuoteStyle, CsvWriterOptions};
 use datafusion_common::{exec_err, not_impl_err, DataFusionError, FileType};
 use datafusion_execution::TaskContext;
 use datafusion_physical_expr::{PhysicalExpr, PhysicalSortRequirement};
@@ -166,6 +167,21 @@ impl CsvFormat {
         self
     }
 
+    /// The string that represents null values, instead of the empty string.
+    /// - default is None
+    pub fn with_null_value(mut self, null_value: Option<String>) -> Self {
+        self.options.null_value = null_value;
+        self
+    }
+
+    /// Set true to indicate that quoted values may contain newlines, so
+    /// that files are not split into byte ranges to be read in parallel.
+    /// - default to false
+    pub fn with_newlines_in_values(mut self, newlines_in_values: bool) -> Self {
+        self.options.newlines_in_values = newlines_in_values;
+        self
+    }
+
     /// Set a `FileCompressionType` of CSV
     /// - defaults to `FileCompressionType::UNCOMPRESSED`
     pub fn with_file_compression_type(
@@ -190,6 +206,11 @@ impl CsvFormat {
     pub fn escape(&self) -> Option<u8> {
         self.options.escape
     }
+
+    /// The string that represents null values.
+    pub fn null_value(&self) -> Option<&str> {
+        self.options.null_value.as_deref()
+    }
 }
 
 #[async_trait]
@@ -247,7 +268,9 @@ impl FileFormat for CsvFormat {
             self.options.quote,
             self.options.escape,
             self.options.compression.into(),
-        );
+        )
+        .with_null_value(self.options.null_value.clone())
+        .with_newlines_in_values(self.options.newlines_in_values);
         Ok(Arc::new(exec))
     }
 
@@ -297,9 +320,16 @@ impl CsvFormat {
         pin_mut!(stream);
 
         while let Some(chunk) = stream.next().await.transpose()? {
-            let format = arrow::csv::reader::Format::default()
+            let mut format = arrow::csv::reader::Format::default()
                 .with_header(self.options.has_header && first_chunk)
-                .with_delimiter(self.options.delimiter);
+                .with_delimiter(self.options.delimiter)
+                .with_quote(self.options.quote);
+            if let Some(escape) = self.options.escape {
+                format = format.with_escape(escape);
+            }
+            if let Some(null_value) = &self.options.null_value {
+                format = format.with_null_regex(null_regex(null_value)?);
+            }
 
             let (Schema { fields, .. }, records_read) =
                 format.infer_schema(chunk.reader(), Some(records_to_read))?;
@@ -392,6 +422,8 @@ pub struct CsvSerializer {
     builder: WriterBuilder,
     // Flag to indicate whether there will be a header
     header: bool,
+    // Which fields are quoted
+    quote_style: CsvQuoteStyle,
 }
 
 impl CsvSerializer {
@@ -400,6 +432,7 @@ impl CsvSerializer {
         Self {
             builder: WriterBuilder::new(),
             header: true,
+            quote_style: CsvQuoteStyle::default(),
         }
     }
 
@@ -414,20 +447,90 @@ impl CsvSerializer {
         self.header = header;
         self
     }
+
+    /// Method for setting which fields are quoted
+    pub fn with_quote_style(mut self, quote_style: CsvQuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
 }
 
 impl BatchSerializer for CsvSerializer {
     fn serialize(&self, batch: RecordBatch, initial: bool) -> Result<Bytes> {
         let mut buffer = Vec::with_capacity(4096);
-        let builder = self.builder.clone();
         let header = self.header && initial;
-        let mut writer = builder.with_header(header).build(&mut buffer);
-        writer.write(&batch)?;
-        drop(writer);
+        if self.quote_style == CsvQuoteStyle::Minimal {
+            let builder = self.builder.clone();
+            let mut writer = builder.with_header(header).build(&mut buffer);
+            writer.write(&batch)?;
+            drop(writer);
+        } else {
+            write_with_quote_style(
+                &mut buffer,
+                &batch,
+                &self.builder,
+                header,
+                self.quote_style,
+            )?;
+        }
         Ok(Bytes::from(buffer))
     }
 }
 
+/// Writes `batch` like the arrow CSV writer built by `builder`, but quotes
+/// fields according to `quote_style`, which the arrow writer does not support
+fn write_with_quote_style(
+    buffer: &mut Vec<u8>,
+    batch: &RecordBatch,
+    builder: &WriterBuilder,
+    header: bool,
+    quote_style: CsvQuoteStyle,
+) -> Result<()> {
+    let quote_style = match quote_style {
+        CsvQuoteStyle::Always => csv::QuoteStyle::Always,
+        CsvQuoteStyle::Minimal => csv::QuoteStyle::Necessary,
+        CsvQuoteStyle::NonNumeric => csv::QuoteStyle::NonNumeric,
+        CsvQuoteStyle::Never => csv::QuoteStyle::Never,
+    };
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(builder.delimiter())
+        .quote(builder.quote())
+        .escape(builder.escape())
+        .double_quote(builder.double_quote())
+        .quote_style(quote_style)
+        .from_writer(buffer);
+    let to_error = |e: csv::Error| DataFusionError::External(Box::new(e));
+
+    if header {
+        let schema = batch.schema();
+        let names = schema.fields().iter().map(|f| f.name());
+        writer.write_record(names).map_err(to_error)?;
+    }
+
+    let options = FormatOptions::default()
+        .with_null(builder.null())
+        .with_date_format(builder.date_format())
+        .with_datetime_format(builder.datetime_format())
+        .with_timestamp_format(builder.timestamp_format())
+        .with_timestamp_tz_format(builder.timestamp_tz_format())
+        .with_time_format(builder.time_format());
+    let formatters = batch
+        .columns()
+        .iter()
+        .map(|array| ArrayFormatter::try_new(array.as_ref(), &options))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut record = vec![String::new(); formatters.len()];
+    for row in 0..batch.num_rows() {
+        for (field, formatter) in record.iter_mut().zip(&formatters) {
+            field.clear();
+            formatter.value(row).write(field)?;
+        }
+        writer.write_record(&record).map_err(to_error)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
 /// Implements [`DataSink`] for writing to a CSV file.
 pub struct CsvSink {
     /// Config options for writing data
@@ -480,7 +583,8 @@ impl CsvSink {
             Arc::new(
                 CsvSerializer::new()
                     .with_builder(builder_clone.clone())
-                    .with_header(options_clone.writer_options.header()),
+                    .with_header(options_clone.writer_options.header())
+                    .with_quote_style(options_clone.quote_style),
             ) as _
         };
 
@@ -859,6 +963,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_csv_serializer_quote_style() -> Result<()> {
+        let batch = RecordBatch::try_from_iter(vec![
+            (
+                "a",
+                Arc::new(arrow::array::Int32Array::from(vec![Some(1), None])) as _,
+            ),
+            (
+                "b",
+                Arc::new(arrow::array::StringArray::from(vec!["x", "say \"hi\""])) as _,
+            ),
+        ])?;
+        let serialize = |quote_style| {
+            let builder = WriterBuilder::new().with_null("NULL".into());
+            let serializer = CsvSerializer::new()
+                .with_builder(builder)
+                .with_quote_style(quote_style);
+            let bytes = serializer.serialize(batch.clone(), true)?;
+            Result::<String>::Ok(String::from_utf8(bytes.into()).unwrap())
+        };
+
+        assert_eq!(
+            "a,b\n1,x\nNULL,\"say \"\"hi\"\"\"\n",
+            serialize(CsvQuoteStyle::Minimal)?
+        );
+        assert_eq!(
+            "\"a\",\"b\"\n\"1\",\"x\"\n\"NULL\",\"say \"\"hi\"\"\"\n",
+            serialize(CsvQuoteStyle::Always)?
+        );
+        assert_eq!(
+            "a,b\n1,x\nNULL,say \"hi\"\n",
+            serialize(CsvQuoteStyle::Never)?
+        );
+        Ok(())
+    }
+
     /// Explain the `sql` query under `ctx` to make sure the underlying csv scan is parallelized
     /// e.g. "CsvExec: file_groups={2 groups:" in plan means 2 CsvExec runs concurrently
     async fn count_query_csv_partitions(
@@ -251,7 +251,7 @@ impl FileTypeExt for FileType {
 
         match self {
             FileType::JSON | FileType::CSV => Ok(format!("{}{}", ext, c.get_ext())),
-            FileType::AVRO | FileType::ARROW => match c.variant {
+            FileType::AVRO | FileType::ARROW | FileType::ORC => match c.variant {
                 UNCOMPRESSED => Ok(ext),
                 _ => Err(DataFusionError::Internal(
                     "FileCompressionType can be specified for CSV/JSON FileType.".into(),
@@ -304,6 +304,7 @@ mod tests {
 
         let mut ty_ext_tuple = vec![];
         ty_ext_tuple.push((FileType::AVRO, ".avro"));
+        ty_ext_tuple.push((FileType::ORC, ".orc"));
         #[cfg(feature = "parquet")]
         ty_ext_tuple.push((FileType::PARQUET, ".parquet"));
 
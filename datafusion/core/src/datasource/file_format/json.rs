@@ -23,7 +23,9 @@ use std::fmt::Debug;
 use std::io::BufReader;
 use std::sync::Arc;
 
-use super::write::orchestration::stateless_multipart_put;
+use super::write::orchestration::{
+    rolling_multipart_put, stateless_multipart_put, RollingFileOptions,
+};
 use super::{FileFormat, FileScanConfig};
 use crate::datasource::file_format::file_compression_type::FileCompressionType;
 use crate::datasource::file_format::write::BatchSerializer;
@@ -197,19 +199,33 @@ impl Default for JsonSerializer {
 }
 
 /// Define a struct for serializing Json records to a stream
-pub struct JsonSerializer {}
+pub struct JsonSerializer {
+    // Flag to indicate whether null values are written as `null`
+    explicit_nulls: bool,
+}
 
 impl JsonSerializer {
     /// Constructor for the JsonSerializer object
     pub fn new() -> Self {
-        Self {}
+        Self {
+            explicit_nulls: false,
+        }
+    }
+
+    /// Method for setting whether null values are written as `null` rather
+    /// than omitted
+    pub fn with_explicit_nulls(mut self, explicit_nulls: bool) -> Self {
+        self.explicit_nulls = explicit_nulls;
+        self
     }
 }
 
 impl BatchSerializer for JsonSerializer {
     fn serialize(&self, batch: RecordBatch, _initial: bool) -> Result<Bytes> {
         let mut buffer = Vec::with_capacity(4096);
-        let mut writer = json::LineDelimitedWriter::new(&mut buffer);
+        let mut writer = json::WriterBuilder::new()
+            .with_explicit_nulls(self.explicit_nulls)
+            .build::<_, json::writer::LineDelimited>(&mut buffer);
         writer.write(&batch)?;
         Ok(Bytes::from(buffer))
     }
@@ -260,7 +276,28 @@ impl JsonSink {
         data: SendableRecordBatchStream,
         context: &Arc<TaskContext>,
     ) -> Result<u64> {
-        let get_serializer = move || Arc::new(JsonSerializer::new()) as _;
+        let explicit_nulls = self.writer_options.explicit_nulls;
+        let get_serializer = move || {
+            Arc::new(JsonSerializer::new().with_explicit_nulls(explicit_nulls)) as _
+        };
+
+        if self.writer_options.is_streaming() {
+            let options = RollingFileOptions {
+                max_file_size_bytes: self.writer_options.max_file_size_bytes,
+                rotation_interval: self.writer_options.rotation_interval,
+                flush_interval: self.writer_options.flush_interval,
+            };
+            return rolling_multipart_put(
+                data,
+                context,
+                "json".into(),
+                get_serializer(),
+                &self.config,
+                self.writer_options.compression.into(),
+                options,
+            )
+            .await;
+        }
 
         stateless_multipart_put(
             data,
@@ -526,4 +563,21 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_json_serializer_explicit_nulls() -> Result<()> {
+        let batch = RecordBatch::try_from_iter(vec![(
+            "a",
+            Arc::new(arrow::array::Int32Array::from(vec![Some(1), None])) as _,
+        )])?;
+        let serialize = |explicit_nulls| {
+            let serializer = JsonSerializer::new().with_explicit_nulls(explicit_nulls);
+            let bytes = serializer.serialize(batch.clone(), true)?;
+            Result::<String>::Ok(String::from_utf8(bytes.into()).unwrap())
+        };
+
+        assert_eq!("{\"a\":1}\n{}\n", serialize(false)?);
+        assert_eq!("{\"a\":1}\n{\"a\":null}\n", serialize(true)?);
+        Ok(())
+    }
 }
@@ -27,6 +27,7 @@ pub mod csv;
 pub mod file_compression_type;
 pub mod json;
 pub mod options;
+pub mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
 pub mod write;
@@ -0,0 +1,157 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`OrcFormat`] Apache ORC [`FileFormat`] abstractions
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::datatypes::Schema;
+use arrow::{self, datatypes::SchemaRef};
+use async_trait::async_trait;
+use datafusion_common::stats::Precision;
+use datafusion_common::FileType;
+use datafusion_physical_expr::PhysicalExpr;
+use object_store::{ObjectMeta, ObjectStore};
+
+use super::FileFormat;
+use crate::datasource::physical_plan::{read_orc_summary, FileScanConfig, OrcExec};
+use crate::error::Result;
+use crate::execution::context::SessionState;
+use crate::physical_plan::ExecutionPlan;
+use crate::physical_plan::Statistics;
+
+/// Apache ORC `FileFormat` implementation.
+///
+/// Reading ORC files requires the `orc` feature.
+#[derive(Default, Debug)]
+pub struct OrcFormat;
+
+#[async_trait]
+impl FileFormat for OrcFormat {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn infer_schema(
+        &self,
+        _state: &SessionState,
+        store: &Arc<dyn ObjectStore>,
+        objects: &[ObjectMeta],
+    ) -> Result<SchemaRef> {
+        let mut schemas = vec![];
+        for object in objects {
+            let (schema, _) = read_orc_summary(store, object).await?;
+            schemas.push(schema);
+        }
+        let merged_schema = Schema::try_merge(schemas)?;
+        Ok(Arc::new(merged_schema))
+    }
+
+    async fn infer_stats(
+        &self,
+        _state: &SessionState,
+        store: &Arc<dyn ObjectStore>,
+        table_schema: SchemaRef,
+        object: &ObjectMeta,
+    ) -> Result<Statistics> {
+        let (_, num_rows) = read_orc_summary(store, object).await?;
+        Ok(Statistics {
+            num_rows: Precision::Exact(num_rows),
+            total_byte_size: Precision::Inexact(object.size),
+            column_statistics: Statistics::unknown_column(&table_schema),
+        })
+    }
+
+    async fn create_physical_plan(
+        &self,
+        _state: &SessionState,
+        conf: FileScanConfig,
+        _filters: Option<&Arc<dyn PhysicalExpr>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let exec = OrcExec::new(conf);
+        Ok(Arc::new(exec))
+    }
+
+    fn file_type(&self) -> FileType {
+        FileType::ORC
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "orc")]
+mod tests {
+    use super::*;
+    use crate::datasource::file_format::test_util::scan_format;
+    use crate::physical_plan::collect;
+    use crate::prelude::{SessionConfig, SessionContext};
+
+    use datafusion_common::assert_batches_eq;
+
+    #[tokio::test]
+    async fn read_orc_with_projection() -> Result<()> {
+        let config = SessionConfig::new().with_batch_size(2);
+        let session_ctx = SessionContext::new_with_config(config);
+        let state = session_ctx.state();
+        let task_ctx = state.task_ctx();
+
+        let exec = scan_format(
+            &state,
+            &OrcFormat {},
+            &format!("{}/tests/data", env!("CARGO_MANIFEST_DIR")),
+            "example.orc",
+            Some(vec![1]),
+            None,
+        )
+        .await?;
+        assert_eq!(exec.schema().fields().len(), 1);
+        assert_eq!(exec.statistics()?.num_rows, Precision::Exact(4));
+
+        let batches = collect(exec, task_ctx).await?;
+        assert_eq!(batches.len(), 2);
+        let expected = [
+            "+------+", "| name |", "+------+", "| a    |", "|      |", "| ccc  |",
+            "| dd   |", "+------+",
+        ];
+        assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn infer_orc_schema() -> Result<()> {
+        let session_ctx = SessionContext::new();
+        let state = session_ctx.state();
+        let exec = scan_format(
+            &state,
+            &OrcFormat {},
+            &format!("{}/tests/data", env!("CARGO_MANIFEST_DIR")),
+            "example.orc",
+            None,
+            None,
+        )
+        .await?;
+
+        let x: Vec<String> = exec
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| format!("{}: {:?}", f.name(), f.data_type()))
+            .collect();
+        assert_eq!(vec!["id: Int32", "name: Utf8"], x);
+        Ok(())
+    }
+}
@@ -190,7 +190,7 @@ async fn row_count_demuxer(
 }
 
 /// Helper for row count demuxer
-fn generate_file_path(
+pub(crate) fn generate_file_path(
     base_output_path: &ListingTableUrl,
     write_id: &str,
     part_idx: usize,
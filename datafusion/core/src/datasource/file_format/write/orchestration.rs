@@ -20,8 +20,9 @@
 //! parallelization, and abort handling
 
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use super::demux::start_demuxer_task;
+use super::demux::{generate_file_path, start_demuxer_task};
 use super::{create_writer, BatchSerializer};
 use crate::datasource::file_format::file_compression_type::FileCompressionType;
 use crate::datasource::physical_plan::FileSinkConfig;
@@ -29,15 +30,19 @@ use crate::error::Result;
 use crate::physical_plan::SendableRecordBatchStream;
 
 use arrow_array::RecordBatch;
-use datafusion_common::{internal_datafusion_err, internal_err, DataFusionError};
+use datafusion_common::{
+    internal_datafusion_err, internal_err, not_impl_err, plan_err, DataFusionError,
+};
 use datafusion_common_runtime::SpawnedTask;
 use datafusion_execution::TaskContext;
 
 use bytes::Bytes;
-use futures::join;
+use futures::{join, StreamExt};
+use rand::distributions::DistString;
 use tokio::io::{AsyncWrite, AsyncWriteExt};
 use tokio::sync::mpsc::{self, Receiver};
 use tokio::task::JoinSet;
+use tokio::time::Interval;
 
 type WriterType = Box<dyn AsyncWrite + Send + Unpin>;
 type SerializerType = Arc<dyn BatchSerializer>;
@@ -267,3 +272,147 @@ pub(crate) async fn stateless_multipart_put(
 
     Ok(total_count)
 }
+
+/// Limits of the files written by [`rolling_multipart_put`]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RollingFileOptions {
+    /// Start a new file once the serialized, uncompressed size of the current
+    /// file reaches this many bytes
+    pub max_file_size_bytes: Option<usize>,
+    /// Start a new file once the current file has been open this long
+    pub rotation_interval: Option<Duration>,
+    /// Flush the current file at this interval
+    pub flush_interval: Option<Duration>,
+}
+
+/// The file currently written by [`rolling_multipart_put`]
+struct RollingFile {
+    writer: WriterType,
+    bytes_written: usize,
+    opened_at: Instant,
+}
+
+/// Serializes a possibly unbounded input stream into a sequence of files,
+/// one at a time. The current file is completed, and a new one started, when
+/// it reaches the size or age limit of `options`, so that it can be read while
+/// the input is still being written. Unlike [`stateless_multipart_put`], the
+/// files are written in the order of the input batches.
+pub(crate) async fn rolling_multipart_put(
+    mut data: SendableRecordBatchStream,
+    context: &Arc<TaskContext>,
+    file_extension: String,
+    serializer: Arc<dyn BatchSerializer>,
+    config: &FileSinkConfig,
+    compression: FileCompressionType,
+    options: RollingFileOptions,
+) -> Result<u64> {
+    if !config.table_partition_cols.is_empty() {
+        return not_impl_err!("Rotating or flushing partitioned output files");
+    }
+    let base_output_path = &config.table_paths[0];
+    let single_file_output = !base_output_path.is_collection();
+    if single_file_output
+        && (options.max_file_size_bytes.is_some() || options.rotation_interval.is_some())
+    {
+        return plan_err!(
+            "Rotating output files requires a directory output path, got {base_output_path}"
+        );
+    }
+
+    let object_store = context
+        .runtime_env()
+        .object_store(&config.object_store_url)?;
+    let write_id =
+        rand::distributions::Alphanumeric.sample_string(&mut rand::thread_rng(), 16);
+    let mut part_idx = 0;
+    let mut current: Option<RollingFile> = None;
+    let mut row_count = 0;
+
+    // wake up to flush the current file, or complete it when it is too old
+    // while the input is idle
+    let mut timer = options
+        .flush_interval
+        .or(options.rotation_interval)
+        .map(tokio::time::interval);
+
+    loop {
+        let batch = tokio::select! {
+            batch = data.next() => batch,
+            _ = tick(&mut timer) => {
+                if let Some(file) = current.as_mut() {
+                    if is_expired(file, &options) {
+                        file.writer.shutdown().await?;
+                        current = None;
+                    } else if options.flush_interval.is_some() {
+                        file.writer.flush().await?;
+                    }
+                }
+                continue;
+            }
+        };
+        let Some(batch) = batch.transpose()? else {
+            break;
+        };
+        if batch.num_rows() == 0 {
+            continue;
+        }
+
+        let file = match current.as_mut() {
+            Some(file) => file,
+            None => {
+                let location = generate_file_path(
+                    base_output_path,
+                    &write_id,
+                    part_idx,
+                    &file_extension,
+                    single_file_output,
+                );
+                part_idx += 1;
+                let writer =
+                    create_writer(compression, &location, object_store.clone()).await?;
+                current.insert(RollingFile {
+                    writer,
+                    bytes_written: 0,
+                    opened_at: Instant::now(),
+                })
+            }
+        };
+
+        row_count += batch.num_rows();
+        let bytes = serializer.serialize(batch, file.bytes_written == 0)?;
+        file.writer.write_all(&bytes).await?;
+        file.bytes_written += bytes.len();
+
+        if is_full(file, &options) || is_expired(file, &options) {
+            file.writer.shutdown().await?;
+            current = None;
+        }
+    }
+
+    if let Some(mut file) = current {
+        file.writer.shutdown().await?;
+    }
+    Ok(row_count as u64)
+}
+
+/// Waits for the next tick of `timer`, or forever if there is no timer
+async fn tick(timer: &mut Option<Interval>) {
+    match timer {
+        Some(timer) => {
+            timer.tick().await;
+        }
+        None => futures::future::pending().await,
+    }
+}
+
+fn is_full(file: &RollingFile, options: &RollingFileOptions) -> bool {
+    options
+        .max_file_size_bytes
+        .is_some_and(|max| file.bytes_written >= max)
+}
+
+fn is_expired(file: &RollingFile, options: &RollingFileOptions) -> bool {
+    options
+        .rotation_interval
+        .is_some_and(|interval| file.opened_at.elapsed() >= interval)
+}
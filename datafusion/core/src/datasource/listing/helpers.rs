@@ -17,15 +17,17 @@
 
 //! Helper functions for the table implementation
 
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use super::PartitionedFile;
 use crate::datasource::listing::ListingTableUrl;
 use crate::execution::context::SessionState;
+use crate::physical_optimizer::pruning::{PruningPredicate, PruningStatistics};
 use crate::{error::Result, scalar::ScalarValue};
 
 use arrow::{
-    array::{Array, ArrayRef, AsArray, StringBuilder},
+    array::{Array, ArrayRef, AsArray, BooleanArray, StringBuilder},
     compute::{and, cast, prep_null_mask_filter},
     datatypes::{DataType, Field, Schema},
     record_batch::RecordBatch,
@@ -38,6 +40,7 @@ use log::{debug, trace};
 
 use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
 use datafusion_common::{internal_err, Column, DFSchema, DataFusionError};
+use datafusion_expr::utils::conjunction;
 use datafusion_expr::{Expr, ScalarFunctionDefinition, Volatility};
 use datafusion_physical_expr::create_physical_expr;
 use object_store::path::Path;
@@ -182,11 +185,14 @@ impl Partition {
     }
 }
 
-/// Returns a recursive list of the partitions in `table_path` up to `max_depth`
+/// Returns a recursive list of the partitions in `table_path` up to `max_depth`,
+/// skipping the partitions that `pruner` proves to not contain matching files
+/// without listing them
 async fn list_partitions(
     store: &dyn ObjectStore,
     table_path: &ListingTableUrl,
     max_depth: usize,
+    pruner: Option<&PartitionPruner<'_>>,
 ) -> Result<Vec<Partition>> {
     let partition = Partition {
         path: table_path.prefix().clone(),
@@ -210,6 +216,10 @@ async fn list_partitions(
 
         let depth = partition.depth;
         out.push(partition);
+        let paths = match pruner {
+            Some(pruner) => pruner.prune(paths, depth + 1),
+            None => paths,
+        };
         for path in paths {
             let child = Partition {
                 path,
@@ -228,6 +238,159 @@ async fn list_partitions(
     Ok(out)
 }
 
+/// Prunes partitions while they are listed with a [`PruningPredicate`], using
+/// the values of the partition columns known from their paths so far.
+///
+/// For example, `year=2021/` can be skipped for the filter `year > 2022`
+/// before listing the `month` partitions below it.
+struct PartitionPruner<'a> {
+    predicate: PruningPredicate,
+    table_path: &'a ListingTableUrl,
+    partition_cols: &'a [(String, DataType)],
+}
+
+impl<'a> PartitionPruner<'a> {
+    /// Returns a pruner for the `filters` that only refer to partition
+    /// columns, if there are any the [`PruningPredicate`] can prune with
+    fn try_new(
+        table_path: &'a ListingTableUrl,
+        filters: &[Expr],
+        partition_cols: &'a [(String, DataType)],
+    ) -> Option<Self> {
+        let col_names = partition_cols
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        let filter = conjunction(
+            filters
+                .iter()
+                .filter(|filter| expr_applicable_for_cols(&col_names, filter))
+                .cloned(),
+        )?;
+
+        let fields: Fields = partition_cols
+            .iter()
+            .map(|(n, d)| Field::new(n, d.clone(), true))
+            .collect();
+        let schema = Arc::new(Schema::new(fields.clone()));
+        let df_schema =
+            DFSchema::from_unqualifed_fields(fields, Default::default()).ok()?;
+        let expr =
+            create_physical_expr(&filter, &df_schema, &ExecutionProps::new()).ok()?;
+        let predicate = PruningPredicate::try_new(expr, schema).ok()?;
+        (!predicate.always_true()).then_some(Self {
+            predicate,
+            table_path,
+            partition_cols,
+        })
+    }
+
+    /// Returns the `paths` of the partitions at `depth` that may contain
+    /// files matching the filters
+    fn prune(&self, paths: Vec<Path>, depth: usize) -> Vec<Path> {
+        let partition_cols = &self.partition_cols[..depth.min(self.partition_cols.len())];
+        let statistics =
+            PartitionStatistics::new(self.table_path, &paths, partition_cols);
+        match self.predicate.prune(&statistics) {
+            Ok(keep) => paths
+                .into_iter()
+                .zip(keep)
+                .filter_map(|(path, keep)| keep.then_some(path))
+                .collect(),
+            Err(e) => {
+                debug!("Ignoring error pruning partitions: {e}");
+                paths
+            }
+        }
+    }
+}
+
+/// The values of the partition columns parsed from the paths of partitions,
+/// which are both the minimum and the maximum of their column in a partition.
+/// Columns below the partitions have no statistics.
+struct PartitionStatistics {
+    values: HashMap<String, ArrayRef>,
+    num_partitions: usize,
+}
+
+impl PartitionStatistics {
+    fn new(
+        table_path: &ListingTableUrl,
+        paths: &[Path],
+        partition_cols: &[(String, DataType)],
+    ) -> Self {
+        let mut builders: Vec<_> = partition_cols
+            .iter()
+            .map(|_| StringBuilder::with_capacity(paths.len(), paths.len() * 10))
+            .collect();
+        for path in paths {
+            let cols = partition_cols.iter().map(|x| x.0.as_str());
+            let parsed =
+                parse_partitions_for_path(table_path, path, cols).unwrap_or_default();
+
+            let mut builders = builders.iter_mut();
+            for (p, b) in parsed.iter().zip(&mut builders) {
+                b.append_value(p);
+            }
+            builders.for_each(|b| b.append_null());
+        }
+
+        // values that can not be cast to the type of their column are unknown
+        let values = partition_cols
+            .iter()
+            .zip(builders)
+            .filter_map(|((name, data_type), mut builder)| {
+                let array = cast(&builder.finish(), data_type).ok()?;
+                Some((name.clone(), array))
+            })
+            .collect();
+        Self {
+            values,
+            num_partitions: paths.len(),
+        }
+    }
+}
+
+impl PruningStatistics for PartitionStatistics {
+    fn min_values(&self, column: &Column) -> Option<ArrayRef> {
+        self.values.get(&column.name).cloned()
+    }
+
+    fn max_values(&self, column: &Column) -> Option<ArrayRef> {
+        self.values.get(&column.name).cloned()
+    }
+
+    fn num_containers(&self) -> usize {
+        self.num_partitions
+    }
+
+    fn null_counts(&self, _column: &Column) -> Option<ArrayRef> {
+        None
+    }
+
+    fn row_counts(&self, _column: &Column) -> Option<ArrayRef> {
+        None
+    }
+
+    fn contained(
+        &self,
+        column: &Column,
+        values: &HashSet<ScalarValue>,
+    ) -> Option<BooleanArray> {
+        let array = self.values.get(&column.name)?;
+        if values.iter().any(|v| &v.data_type() != array.data_type()) {
+            return None;
+        }
+        let contained = (0..array.len())
+            .map(|i| {
+                let value = ScalarValue::try_from_array(array, i).ok()?;
+                (!value.is_null()).then(|| values.contains(&value))
+            })
+            .collect();
+        Some(contained)
+    }
+}
+
 async fn prune_partitions(
     table_path: &ListingTableUrl,
     partitions: Vec<Partition>,
@@ -345,7 +508,9 @@ pub async fn pruned_partition_list<'a>(
         ));
     }
 
-    let partitions = list_partitions(store, table_path, partition_cols.len()).await?;
+    let pruner = PartitionPruner::try_new(table_path, filters, partition_cols);
+    let partitions =
+        list_partitions(store, table_path, partition_cols.len(), pruner.as_ref()).await?;
     debug!("Listed {} partitions", partitions.len());
 
     let pruned =
@@ -398,6 +563,51 @@ pub async fn pruned_partition_list<'a>(
     Ok(stream)
 }
 
+/// Infers the types of the partition columns `partition_cols` from their values
+/// in the partition directories of `table_path`. Columns of which all values
+/// are integers are `Int64`, of which all values are dates are `Date32`, and
+/// other columns are `Utf8` dictionaries, like partition columns of which the
+/// types are not known.
+pub(crate) async fn infer_partition_types(
+    store: &dyn ObjectStore,
+    table_path: &ListingTableUrl,
+    partition_cols: &[String],
+) -> Result<Vec<(String, DataType)>> {
+    let partitions =
+        list_partitions(store, table_path, partition_cols.len(), None).await?;
+
+    let mut values = vec![HashSet::new(); partition_cols.len()];
+    for partition in &partitions {
+        let cols = partition_cols.iter().map(|x| x.as_str());
+        let parsed = parse_partitions_for_path(table_path, &partition.path, cols)
+            .unwrap_or_default();
+        for (value, values) in parsed.into_iter().zip(values.iter_mut()) {
+            values.insert(value);
+        }
+    }
+
+    Ok(partition_cols
+        .iter()
+        .zip(values)
+        .map(|(name, values)| (name.clone(), infer_partition_type(&values)))
+        .collect())
+}
+
+fn infer_partition_type(values: &HashSet<&str>) -> DataType {
+    let all_values_are = |data_type: &DataType| {
+        !values.is_empty()
+            && values.iter().all(|value| {
+                ScalarValue::try_from_string(value.to_string(), data_type).is_ok()
+            })
+    };
+    [DataType::Int64, DataType::Date32]
+        .into_iter()
+        .find(all_values_are)
+        .unwrap_or_else(|| {
+            DataType::Dictionary(Box::new(DataType::UInt16), Box::new(DataType::Utf8))
+        })
+}
+
 /// Extract the partition values for the given `file_path` (in the given `table_path`)
 /// associated to the partitions defined by `table_partition_cols`
 fn parse_partitions_for_path<'a, I>(
@@ -591,6 +801,96 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_prune_partitions_while_listing() {
+        let table_path = ListingTableUrl::parse("file:///tablepath/").unwrap();
+        let partition_cols = [
+            (String::from("year"), DataType::Int64),
+            (String::from("month"), DataType::Int64),
+        ];
+        let paths = || {
+            ["year=2020", "year=2021", "year=2022", "other"]
+                .map(|p| Path::from(format!("tablepath/{p}")))
+                .to_vec()
+        };
+        let pruned = |filter: Expr| {
+            PartitionPruner::try_new(&table_path, &[filter], &partition_cols)
+                .unwrap()
+                .prune(paths(), 1)
+                .iter()
+                .map(|p| p.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        // partitions that can not be parsed are kept
+        assert_eq!(
+            pruned(col("year").gt(lit(2020i64))),
+            [
+                "tablepath/year=2021",
+                "tablepath/year=2022",
+                "tablepath/other"
+            ]
+        );
+        assert_eq!(
+            pruned(col("year").in_list(vec![lit(2020i64), lit(2022i64)], false)),
+            [
+                "tablepath/year=2020",
+                "tablepath/year=2022",
+                "tablepath/other"
+            ]
+        );
+        // the months are not known yet
+        assert_eq!(
+            pruned(col("year").eq(lit(2021i64)).and(col("month").eq(lit(3i64)))),
+            ["tablepath/year=2021", "tablepath/other"]
+        );
+        // filters on other columns can not prune
+        assert!(PartitionPruner::try_new(
+            &table_path,
+            &[col("c").eq(lit(1i64))],
+            &partition_cols
+        )
+        .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_infer_partition_types() {
+        let (store, _) = make_test_store_and_state(&[
+            (
+                "tablepath/year=2021/day=2021-01-01/name=a/file.parquet",
+                100,
+            ),
+            (
+                "tablepath/year=2021/day=2021-01-02/name=1/file.parquet",
+                100,
+            ),
+            (
+                "tablepath/year=2022/day=2022-01-01/name=b/file.parquet",
+                100,
+            ),
+        ]);
+        let partition_cols = ["year", "day", "name", "missing"].map(String::from);
+        let types = infer_partition_types(
+            store.as_ref(),
+            &ListingTableUrl::parse("file:///tablepath/").unwrap(),
+            &partition_cols,
+        )
+        .await
+        .unwrap();
+
+        let dictionary =
+            DataType::Dictionary(Box::new(DataType::UInt16), Box::new(DataType::Utf8));
+        assert_eq!(
+            types,
+            [
+                (String::from("year"), DataType::Int64),
+                (String::from("day"), DataType::Date32),
+                (String::from("name"), dictionary.clone()),
+                (String::from("missing"), dictionary),
+            ]
+        );
+    }
+
     #[test]
     fn test_parse_partitions_for_path() {
         assert_eq!(
@@ -0,0 +1,158 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Merging differing file schemas into a table schema, see
+//! [`ListingOptions::with_schema_evolution`]
+//!
+//! [`ListingOptions::with_schema_evolution`]: super::ListingOptions::with_schema_evolution
+
+use std::collections::HashMap;
+
+use arrow::datatypes::{DataType, Field, Fields, Schema};
+use datafusion_common::{plan_datafusion_err, Result};
+use datafusion_expr::type_coercion::binary::comparison_coercion;
+
+/// Merges the schemas of the files of a table.
+///
+/// Unlike [`Schema::try_merge`], fields missing from some schemas are
+/// nullable in the merged schema, and fields of different types are widened
+/// to a common type where possible, such as `Int32` and `Int64` to `Int64`.
+/// Fields of structs are merged recursively.
+pub(crate) fn merge_schemas(schemas: &[Schema]) -> Result<Schema> {
+    let mut metadata = HashMap::new();
+    for schema in schemas {
+        for (key, value) in schema.metadata() {
+            metadata.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+    }
+    let fields = schemas
+        .iter()
+        .map(|schema| schema.fields())
+        .collect::<Vec<_>>();
+    Ok(Schema::new_with_metadata(merge_fields(&fields)?, metadata))
+}
+
+fn merge_fields(field_lists: &[&Fields]) -> Result<Fields> {
+    let mut merged: Vec<Field> = vec![];
+    for field in field_lists.iter().flat_map(|fields| fields.iter()) {
+        match merged.iter_mut().find(|f| f.name() == field.name()) {
+            Some(existing) => *existing = merge_field(existing, field)?,
+            None => merged.push(field.as_ref().clone()),
+        }
+    }
+    // fields missing from some files are read as nulls
+    for field in merged.iter_mut() {
+        if field_lists
+            .iter()
+            .any(|fields| fields.find(field.name()).is_none())
+        {
+            field.set_nullable(true);
+        }
+    }
+    Ok(merged.into())
+}
+
+fn merge_field(existing: &Field, field: &Field) -> Result<Field> {
+    let data_type = match (existing.data_type(), field.data_type()) {
+        (a, b) if a == b => a.clone(),
+        (DataType::Struct(a), DataType::Struct(b)) => {
+            DataType::Struct(merge_fields(&[a, b])?)
+        }
+        (a, b) => widen_type(a, b).ok_or_else(|| {
+            plan_datafusion_err!(
+                "Cannot merge field {} of type {a} with type {b} of another file",
+                field.name()
+            )
+        })?,
+    };
+    Ok(existing
+        .clone()
+        .with_data_type(data_type)
+        .with_nullable(existing.is_nullable() || field.is_nullable()))
+}
+
+/// Returns the type both `a` and `b` can be losslessly cast to, if they are
+/// both numbers, strings, binaries or timestamps
+fn widen_type(a: &DataType, b: &DataType) -> Option<DataType> {
+    use DataType::*;
+    match (a, b) {
+        (Null, other) | (other, Null) => Some(other.clone()),
+        (Utf8 | LargeUtf8, Utf8 | LargeUtf8) => Some(LargeUtf8),
+        (Binary | LargeBinary, Binary | LargeBinary) => Some(LargeBinary),
+        (Timestamp(_, _), Timestamp(_, _)) => comparison_coercion(a, b),
+        _ if a.is_numeric() && b.is_numeric() => comparison_coercion(a, b),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_evolved_schemas() -> Result<()> {
+        let struct_fields = |fields: Vec<Field>| DataType::Struct(fields.into());
+        let v1 = Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("name", DataType::Utf8, false),
+            Field::new(
+                "s",
+                struct_fields(vec![Field::new("a", DataType::Int32, false)]),
+                true,
+            ),
+        ]);
+        let v2 = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new(
+                "s",
+                struct_fields(vec![
+                    Field::new("a", DataType::Float64, false),
+                    Field::new("b", DataType::Utf8, false),
+                ]),
+                true,
+            ),
+            Field::new("score", DataType::Float32, false),
+        ]);
+
+        let merged = merge_schemas(&[v1, v2])?;
+        let expected = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+            Field::new(
+                "s",
+                struct_fields(vec![
+                    Field::new("a", DataType::Float64, false),
+                    Field::new("b", DataType::Utf8, true),
+                ]),
+                true,
+            ),
+            Field::new("score", DataType::Float32, true),
+        ]);
+        assert_eq!(merged, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn merge_incompatible_schemas() {
+        let v1 = Schema::new(vec![Field::new("id", DataType::Int32, false)]);
+        let v2 = Schema::new(vec![Field::new("id", DataType::Utf8, false)]);
+        let err = merge_schemas(&[v1, v2]).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Cannot merge field id of type Int32 with type Utf8"));
+    }
+}
@@ -21,7 +21,10 @@ use std::collections::HashMap;
 use std::str::FromStr;
 use std::{any::Any, sync::Arc};
 
-use super::helpers::{expr_applicable_for_cols, pruned_partition_list, split_files};
+use super::helpers::{
+    expr_applicable_for_cols, infer_partition_types, pruned_partition_list, split_files,
+};
+use super::schema_evolution::merge_schemas;
 use super::PartitionedFile;
 
 #[cfg(feature = "parquet")]
@@ -36,6 +39,7 @@ use crate::datasource::{
         csv::CsvFormat,
         file_compression_type::{FileCompressionType, FileTypeExt},
         json::JsonFormat,
+        orc::OrcFormat,
         FileFormat,
     },
     listing::ListingTableUrl,
@@ -119,7 +123,7 @@ impl ListingTableConfig {
         }
     }
 
-    fn infer_file_type(path: &str) -> Result<(FileType, String)> {
+    fn infer_file_type(path: &str) -> Result<(FileType, String, FileCompressionType)> {
         let err_msg = format!("Unable to infer file type from path: {path}");
 
         let mut exts = path.rsplit('.');
@@ -140,7 +144,7 @@ impl ListingTableConfig {
             .get_ext_with_compression(file_compression_type.to_owned())
             .map_err(|_| DataFusionError::Internal(err_msg))?;
 
-        Ok((file_type, ext))
+        Ok((file_type, ext, file_compression_type))
     }
 
     /// Infer `ListingOptions` based on `table_path` suffix.
@@ -161,11 +165,15 @@ impl ListingTableConfig {
             .await
             .ok_or_else(|| DataFusionError::Internal("No files for table".into()))??;
 
-        let (file_type, file_extension) =
+        let (file_type, file_extension, file_compression_type) =
             ListingTableConfig::infer_file_type(file.location.as_ref())?;
 
         let mut table_options = state.default_table_options();
         table_options.set_file_format(file_type.clone());
+        if file_compression_type.is_compressed() {
+            table_options.csv.compression = file_compression_type.into();
+            table_options.json.compression = file_compression_type.into();
+        }
         let file_format: Arc<dyn FileFormat> = match file_type {
             FileType::CSV => {
                 Arc::new(CsvFormat::default().with_options(table_options.csv))
@@ -179,6 +187,7 @@ impl ListingTableConfig {
                 Arc::new(JsonFormat::default().with_options(table_options.json))
             }
             FileType::ARROW => Arc::new(ArrowFormat),
+            FileType::ORC => Arc::new(OrcFormat),
         };
 
         let listing_options = ListingOptions::new(file_format)
@@ -251,6 +260,9 @@ pub struct ListingOptions {
     ///       multiple equivalent orderings, the outer `Vec` will have a
     ///       single element.
     pub file_sort_order: Vec<Vec<Expr>>,
+    /// Set true to merge differing file schemas when inferring the table
+    /// schema. See [Self::with_schema_evolution] for details
+    pub schema_evolution: bool,
 }
 
 impl ListingOptions {
@@ -268,6 +280,7 @@ impl ListingOptions {
             collect_stat: true,
             target_partitions: 1,
             file_sort_order: vec![],
+            schema_evolution: false,
         }
     }
 
@@ -376,6 +389,37 @@ impl ListingOptions {
         self
     }
 
+    /// Set schema evolution on [`ListingOptions`] and returns self.
+    ///
+    /// By default, [`Self::infer_schema`] fails if files have fields of the
+    /// same name with different types. With schema evolution, the schema of
+    /// each file is inferred separately and merged into the table schema:
+    ///
+    /// - columns added or dropped in some files are nullable, and are read as
+    ///   nulls from the files missing them
+    /// - columns with compatible types are widened to a common type, such as
+    ///   `Int32` and `Int64` to `Int64`, and cast when reading each file
+    /// - fields of struct columns are merged the same way, by name
+    ///
+    /// Columns are mapped by name, so this is only meaningful for formats
+    /// with named columns, such as Parquet.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use datafusion::datasource::{listing::ListingOptions, file_format::parquet::ParquetFormat};
+    ///
+    /// let listing_options = ListingOptions::new(Arc::new(
+    ///     ParquetFormat::default()
+    ///   ))
+    ///   .with_schema_evolution(true);
+    ///
+    /// assert_eq!(listing_options.schema_evolution, true);
+    /// ```
+    pub fn with_schema_evolution(mut self, schema_evolution: bool) -> Self {
+        self.schema_evolution = schema_evolution;
+        self
+    }
+
     /// Set number of target partitions on [`ListingOptions`] and returns self.
     ///
     /// ```
@@ -437,7 +481,29 @@ impl ListingOptions {
             .try_collect()
             .await?;
 
-        self.format.infer_schema(state, &store, &files).await
+        if !self.schema_evolution {
+            return self.format.infer_schema(state, &store, &files).await;
+        }
+
+        let store = &store;
+        let mut schemas: Vec<_> = stream::iter(&files)
+            .map(|file| async move {
+                let files = std::slice::from_ref(file);
+                let schema = self.format.infer_schema(state, store, files).await?;
+                Ok::<_, DataFusionError>((&file.location, schema))
+            })
+            .boxed() // Workaround https://github.com/rust-lang/rust/issues/64552
+            .buffered(state.config_options().execution.meta_fetch_concurrency)
+            .try_collect()
+            .await?;
+        // merge in a deterministic order, see `ParquetFormat::infer_schema`
+        schemas.sort_by(|(location1, _), (location2, _)| location1.cmp(location2));
+
+        let schemas = schemas
+            .into_iter()
+            .map(|(_, schema)| schema.as_ref().clone())
+            .collect::<Vec<_>>();
+        Ok(Arc::new(merge_schemas(&schemas)?))
     }
 
     /// Infers the partition columns stored in `LOCATION` and compares
@@ -496,6 +562,28 @@ impl ListingOptions {
         Ok(())
     }
 
+    /// Infers the types of the partition columns of this [`ListingOptions`]
+    /// from the values in the partition directories at the given path, and
+    /// returns the partition columns with these types.
+    ///
+    /// Columns of which all values are integers are `Int64`, of which all
+    /// values are dates are `Date32`, and other columns are `Utf8`
+    /// dictionaries. The types can be used with
+    /// [`Self::with_table_partition_cols`].
+    pub async fn infer_partition_types(
+        &self,
+        state: &SessionState,
+        table_path: &ListingTableUrl,
+    ) -> Result<Vec<(String, DataType)>> {
+        let store = state.runtime_env().object_store(table_path)?;
+        let partition_cols = self
+            .table_partition_cols
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect_vec();
+        infer_partition_types(store.as_ref(), table_path, &partition_cols).await
+    }
+
     /// Infer the partitioning at the given path on the provided object store.
     /// For performance reasons, it doesn't read all the files on disk
     /// and therefore may fail to detect invalid partitioning.
@@ -1010,7 +1098,7 @@ mod tests {
     #[cfg(feature = "parquet")]
     use crate::datasource::file_format::parquet::ParquetFormat;
     use crate::datasource::{provider_as_source, MemTable};
-    use crate::execution::options::ArrowReadOptions;
+    use crate::execution::options::{ArrowReadOptions, OrcReadOptions};
     use crate::physical_plan::collect;
     use crate::prelude::*;
     use crate::{
@@ -1101,6 +1189,54 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "compression")]
+    #[tokio::test]
+    async fn infer_compressed_csv() -> Result<()> {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+        use std::io::Write;
+
+        let tmp_dir = TempDir::new()?;
+        for (name, rows) in [("a.csv.gz", "a,b\n1,x\n2,y\n"), ("b.csv.gz", "a,b\n3,z\n")]
+        {
+            let file = std::fs::File::create(tmp_dir.path().join(name))?;
+            let mut encoder = GzEncoder::new(file, Compression::default());
+            encoder.write_all(rows.as_bytes())?;
+            encoder.finish()?;
+        }
+
+        let ctx = SessionContext::new_with_config(
+            SessionConfig::new().with_target_partitions(2),
+        );
+        let state = ctx.state();
+        let table_path = ListingTableUrl::parse(tmp_dir.path().to_str().unwrap())?;
+        let config = ListingTableConfig::new(table_path).infer(&state).await?;
+        assert_eq!(config.options.as_ref().unwrap().file_extension, ".csv.gz");
+        let table = ListingTable::try_new(config)?;
+
+        // the files are read in parallel
+        let exec = table.scan(&state, None, &[], None).await?;
+        assert_eq!(exec.output_partitioning().partition_count(), 2);
+
+        ctx.register_table("t", Arc::new(table))?;
+        let batches = ctx
+            .sql("SELECT * FROM t ORDER BY a")
+            .await?
+            .collect()
+            .await?;
+        let expected = [
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 1 | x |",
+            "| 2 | y |",
+            "| 3 | z |",
+            "+---+---+",
+        ];
+        assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+
     #[cfg(feature = "parquet")]
     #[tokio::test]
     async fn test_try_create_output_ordering() {
@@ -1825,6 +1961,15 @@ mod tests {
                     )
                     .await?;
             }
+            FileType::ORC => {
+                session_ctx
+                    .register_orc(
+                        "t",
+                        tmp_dir.path().to_str().unwrap(),
+                        OrcReadOptions::default().schema(schema.as_ref()),
+                    )
+                    .await?;
+            }
         }
 
         // Create and register the source table with the provided schema and inserted data
@@ -24,7 +24,8 @@ use std::sync::Arc;
 #[cfg(feature = "parquet")]
 use crate::datasource::file_format::parquet::ParquetFormat;
 use crate::datasource::file_format::{
-    arrow::ArrowFormat, avro::AvroFormat, csv::CsvFormat, json::JsonFormat, FileFormat,
+    arrow::ArrowFormat, avro::AvroFormat, csv::CsvFormat, json::JsonFormat,
+    orc::OrcFormat, FileFormat,
 };
 use crate::datasource::listing::{
     ListingOptions, ListingTable, ListingTableConfig, ListingTableUrl,
@@ -34,6 +35,7 @@ use crate::datasource::TableProvider;
 use crate::execution::context::SessionState;
 
 use arrow::datatypes::{DataType, SchemaRef};
+use datafusion_common::parsers::CompressionTypeVariant;
 use datafusion_common::{arrow_datafusion_err, DataFusionError, FileType};
 use datafusion_expr::CreateExternalTable;
 
@@ -63,14 +65,31 @@ impl TableProviderFactory for ListingTableFactory {
         })?;
         table_options.set_file_format(file_type.clone());
         table_options.alter_with_string_hash_map(&cmd.options)?;
+        // the dedicated clauses of the statement take precedence over OPTIONS
+        // when they are specified, i.e. differ from their defaults
+        let compressed =
+            cmd.file_compression_type != CompressionTypeVariant::UNCOMPRESSED;
+        match file_type {
+            FileType::CSV => {
+                if cmd.has_header {
+                    table_options.csv.has_header = true;
+                }
+                if cmd.delimiter != ',' {
+                    table_options.csv.delimiter = cmd.delimiter as u8;
+                }
+                if compressed {
+                    table_options.csv.compression = cmd.file_compression_type;
+                }
+            }
+            FileType::JSON if compressed => {
+                table_options.json.compression = cmd.file_compression_type;
+            }
+            _ => {}
+        }
         let file_extension = get_extension(cmd.location.as_str());
         let file_format: Arc<dyn FileFormat> = match file_type {
             FileType::CSV => {
-                let mut csv_options = table_options.csv;
-                csv_options.has_header = cmd.has_header;
-                csv_options.delimiter = cmd.delimiter as u8;
-                csv_options.compression = cmd.file_compression_type;
-                Arc::new(CsvFormat::default().with_options(csv_options))
+                Arc::new(CsvFormat::default().with_options(table_options.csv))
             }
             #[cfg(feature = "parquet")]
             FileType::PARQUET => {
@@ -78,11 +97,10 @@ impl TableProviderFactory for ListingTableFactory {
             }
             FileType::AVRO => Arc::new(AvroFormat),
             FileType::JSON => {
-                let mut json_options = table_options.json;
-                json_options.compression = cmd.file_compression_type;
-                Arc::new(JsonFormat::default().with_options(json_options))
+                Arc::new(JsonFormat::default().with_options(table_options.json))
             }
             FileType::ARROW => Arc::new(ArrowFormat),
+            FileType::ORC => Arc::new(OrcFormat),
         };
 
         let (provided_schema, table_partition_cols) = if cmd.schema.fields().is_empty() {
@@ -130,15 +148,32 @@ impl TableProviderFactory for ListingTableFactory {
 
         let table_path = ListingTableUrl::parse(&cmd.location)?;
 
-        let options = ListingOptions::new(file_format)
+        let mut options = ListingOptions::new(file_format)
             .with_collect_stat(state.config().collect_statistics())
             .with_file_extension(file_extension)
             .with_target_partitions(state.config().target_partitions())
             .with_table_partition_cols(table_partition_cols)
-            .with_file_sort_order(cmd.order_exprs.clone());
+            .with_file_sort_order(cmd.order_exprs.clone())
+            .with_schema_evolution(
+                state
+                    .config_options()
+                    .execution
+                    .listing_table_schema_evolution,
+            );
 
         options.validate_partitions(state, &table_path).await?;
 
+        if provided_schema.is_none()
+            && state
+                .config_options()
+                .execution
+                .listing_table_infer_partition_types
+        {
+            let table_partition_cols =
+                options.infer_partition_types(state, &table_path).await?;
+            options = options.with_table_partition_cols(table_partition_cols);
+        }
+
         let resolved_schema = match provided_schema {
             None => options.infer_schema(state, &table_path).await?,
             Some(s) => s,
@@ -172,7 +207,6 @@ mod tests {
     use super::*;
     use crate::execution::context::SessionContext;
 
-    use datafusion_common::parsers::CompressionTypeVariant;
     use datafusion_common::{Constraints, DFSchema, TableReference};
 
     #[tokio::test]
@@ -258,4 +292,56 @@ mod tests {
         let listing_options = listing_table.options();
         assert_eq!(".tbl", listing_options.file_extension);
     }
+
+    #[tokio::test]
+    async fn test_create_csv_with_format_options() {
+        let csv_file = tempfile::Builder::new()
+            .prefix("foo")
+            .suffix(".csv")
+            .tempfile()
+            .unwrap();
+
+        let factory = ListingTableFactory::new();
+        let context = SessionContext::new();
+        let state = context.state();
+        let name = TableReference::bare("foo");
+
+        let options = HashMap::from([
+            ("format.delimiter".to_owned(), "|".to_owned()),
+            ("format.quote".to_owned(), "'".to_owned()),
+            ("format.escape".to_owned(), "\\".to_owned()),
+            ("format.null_value".to_owned(), "NULL".to_owned()),
+        ]);
+        let cmd = CreateExternalTable {
+            name,
+            location: csv_file.path().to_str().unwrap().to_string(),
+            file_type: "csv".to_string(),
+            has_header: true,
+            delimiter: ',',
+            schema: Arc::new(DFSchema::empty()),
+            table_partition_cols: vec![],
+            if_not_exists: false,
+            file_compression_type: CompressionTypeVariant::UNCOMPRESSED,
+            definition: None,
+            order_exprs: vec![],
+            unbounded: false,
+            options,
+            constraints: Constraints::empty(),
+            column_defaults: HashMap::new(),
+        };
+        let table_provider = factory.create(&state, &cmd).await.unwrap();
+        let listing_table = table_provider
+            .as_any()
+            .downcast_ref::<ListingTable>()
+            .unwrap();
+
+        let format = listing_table.options().format.clone();
+        let csv_format = format.as_any().downcast_ref::<CsvFormat>().unwrap();
+        let csv_options = csv_format.options();
+        assert!(csv_options.has_header);
+        assert_eq!(csv_options.delimiter, b'|');
+        assert_eq!(csv_options.quote, b'\'');
+        assert_eq!(csv_options.escape, Some(b'\\'));
+        assert_eq!(csv_format.null_value(), Some("NULL"));
+    }
 }
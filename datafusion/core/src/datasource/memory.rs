@@ -26,19 +26,27 @@ use crate::datasource::{TableProvider, TableType};
 use crate::error::Result;
 use crate::execution::context::SessionState;
 use crate::logical_expr::Expr;
+use crate::physical_plan::empty::EmptyExec;
 use crate::physical_plan::insert::{DataSink, FileSinkExec};
 use crate::physical_plan::memory::MemoryExec;
 use crate::physical_plan::repartition::RepartitionExec;
 use crate::physical_plan::{
     common, DisplayAs, DisplayFormatType, ExecutionPlan, ExecutionPlanProperties,
-    Partitioning, SendableRecordBatchStream,
+    Partitioning, PhysicalExpr, SendableRecordBatchStream,
 };
 use crate::physical_planner::create_physical_sort_exprs;
 
+use arrow::array::{Array, AsArray, BooleanArray};
+use arrow::compute::kernels::zip::zip;
+use arrow::compute::{cast, filter_record_batch, not, prep_null_mask_filter};
 use arrow::datatypes::SchemaRef;
 use arrow::record_batch::RecordBatch;
-use datafusion_common::{not_impl_err, plan_err, Constraints, DFSchema, SchemaExt};
+use datafusion_common::{
+    internal_err, not_impl_err, plan_err, Constraints, DFSchema, SchemaExt,
+};
 use datafusion_execution::TaskContext;
+use datafusion_expr::utils::conjunction;
+use datafusion_physical_expr::create_physical_expr;
 use datafusion_physical_plan::metrics::MetricsSet;
 
 use async_trait::async_trait;
@@ -64,6 +72,9 @@ pub struct MemTable {
     /// Optional pre-known sort order(s). Must be `SortExpr`s.
     /// inserting data into this table removes the order
     pub sort_order: Arc<Mutex<Vec<Vec<Expr>>>>,
+    /// Optional pre-known hash partitioning of the partitions.
+    /// inserting data into this table removes the partitioning
+    pub hash_partitioning: Arc<Mutex<Vec<Expr>>>,
 }
 
 impl MemTable {
@@ -89,6 +100,7 @@ impl MemTable {
             constraints: Constraints::empty(),
             column_defaults: HashMap::new(),
             sort_order: Arc::new(Mutex::new(vec![])),
+            hash_partitioning: Arc::new(Mutex::new(vec![])),
         })
     }
 
@@ -122,6 +134,21 @@ impl MemTable {
         self
     }
 
+    /// Specify that the partitions are hash partitioned on `exprs`, e.g.
+    /// because they were written by a [`RepartitionExec`] with
+    /// [`Partitioning::Hash`], as bucketed datasets are.
+    ///
+    /// If the data is not partitioned with the same hash function as
+    /// [`RepartitionExec`], DataFusion may produce incorrect results.
+    ///
+    /// DataFusion may take advantage of this partitioning to omit
+    /// repartitioning, e.g. when joining tables that are hash partitioned on
+    /// their join keys into the same number of partitions.
+    pub fn with_hash_partitioning(self, mut exprs: Vec<Expr>) -> Self {
+        std::mem::swap(self.hash_partitioning.lock().as_mut(), &mut exprs);
+        self
+    }
+
     /// Create a mem table by reading from another data source
     pub async fn load(
         t: Arc<dyn TableProvider>,
@@ -241,6 +268,22 @@ impl TableProvider for MemTable {
             exec = exec.with_sort_information(file_sort_order);
         }
 
+        // add hash partitioning if present, unless some of its columns are
+        // not projected
+        let hash_partitioning = self.hash_partitioning.lock();
+        if !hash_partitioning.is_empty() {
+            let df_schema = DFSchema::try_from(exec.schema().as_ref().clone())?;
+            let exprs = hash_partitioning
+                .iter()
+                .map(|expr| {
+                    create_physical_expr(expr, &df_schema, state.execution_props())
+                })
+                .collect::<Result<Vec<_>>>();
+            if let Ok(exprs) = exprs {
+                exec = exec.with_hash_partitioning(exprs);
+            }
+        }
+
         Ok(Arc::new(exec))
     }
 
@@ -264,6 +307,8 @@ impl TableProvider for MemTable {
     ) -> Result<Arc<dyn ExecutionPlan>> {
         // If we are inserting into the table, any sort order may be messed up so reset it here
         *self.sort_order.lock() = vec![];
+        // and so is the hash partitioning of the partitions
+        *self.hash_partitioning.lock() = vec![];
 
         // Create a physical plan from the logical plan.
         // Check that the schema of the plan matches the schema of this table.
@@ -287,11 +332,92 @@ impl TableProvider for MemTable {
         )))
     }
 
+    /// Returns an ExecutionPlan that deletes the rows of this [`MemTable`]
+    /// for which all `filters` are true.
+    async fn delete_from(
+        &self,
+        state: &SessionState,
+        filters: Vec<Expr>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        // Rows are removed in place, so the sort order and hash partitioning
+        // are kept
+        let filter = self.create_dml_filter(state, filters)?;
+        let sink = Arc::new(MemDmlSink::new(
+            self.batches.clone(),
+            self.schema.clone(),
+            filter,
+            None,
+        ));
+        self.dml_exec(sink)
+    }
+
+    /// Returns an ExecutionPlan that sets the `assignments` columns of the
+    /// rows of this [`MemTable`] for which all `filters` are true.
+    async fn update(
+        &self,
+        state: &SessionState,
+        assignments: Vec<(String, Expr)>,
+        filters: Vec<Expr>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        // If we are updating the table, any sort order may be messed up so reset it here
+        *self.sort_order.lock() = vec![];
+        // and so is the hash partitioning, as the keys may be updated
+        *self.hash_partitioning.lock() = vec![];
+
+        let df_schema = DFSchema::try_from(self.schema.as_ref().clone())?;
+        let assignments = assignments
+            .iter()
+            .map(|(column, value)| {
+                let Ok(index) = self.schema.index_of(column) else {
+                    return plan_err!("Column '{column}' not found in table");
+                };
+                let value =
+                    create_physical_expr(value, &df_schema, state.execution_props())?;
+                Ok((index, value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let filter = self.create_dml_filter(state, filters)?;
+        let sink = Arc::new(MemDmlSink::new(
+            self.batches.clone(),
+            self.schema.clone(),
+            filter,
+            Some(assignments),
+        ));
+        self.dml_exec(sink)
+    }
+
     fn get_column_default(&self, column: &str) -> Option<&Expr> {
         self.column_defaults.get(column)
     }
 }
 
+impl MemTable {
+    /// Returns the conjunction of `filters`, or `None` to match all rows
+    fn create_dml_filter(
+        &self,
+        state: &SessionState,
+        filters: Vec<Expr>,
+    ) -> Result<Option<Arc<dyn PhysicalExpr>>> {
+        let df_schema = DFSchema::try_from(self.schema.as_ref().clone())?;
+        conjunction(filters)
+            .map(|filter| {
+                create_physical_expr(&filter, &df_schema, state.execution_props())
+            })
+            .transpose()
+    }
+
+    /// Returns a plan that runs `sink` once, ignoring its input
+    fn dml_exec(&self, sink: Arc<MemDmlSink>) -> Result<Arc<dyn ExecutionPlan>> {
+        let input = Arc::new(EmptyExec::new(self.schema.clone()));
+        Ok(Arc::new(FileSinkExec::new(
+            input,
+            sink,
+            self.schema.clone(),
+            None,
+        )))
+    }
+}
+
 /// Implements for writing to a [`MemTable`]
 struct MemSink {
     /// Target locations for writing data
@@ -361,20 +487,148 @@ impl DataSink for MemSink {
     }
 }
 
+/// Implements deleting from and updating a [`MemTable`] in place
+struct MemDmlSink {
+    /// The partitions of the table
+    batches: Vec<PartitionData>,
+    /// The schema of the table
+    schema: SchemaRef,
+    /// The rows to delete or update, or `None` for all rows
+    filter: Option<Arc<dyn PhysicalExpr>>,
+    /// The indices of the updated columns and their new values, or `None` to
+    /// delete the rows instead
+    assignments: Option<Vec<(usize, Arc<dyn PhysicalExpr>)>>,
+}
+
+impl Debug for MemDmlSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemDmlSink")
+            .field("num_partitions", &self.batches.len())
+            .field("filter", &self.filter)
+            .field("assignments", &self.assignments)
+            .finish()
+    }
+}
+
+impl DisplayAs for MemDmlSink {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                let partition_count = self.batches.len();
+                let op = match self.assignments {
+                    Some(_) => "update",
+                    None => "delete",
+                };
+                write!(f, "MemoryTable (partitions={partition_count}, op={op})")
+            }
+        }
+    }
+}
+
+impl MemDmlSink {
+    fn new(
+        batches: Vec<PartitionData>,
+        schema: SchemaRef,
+        filter: Option<Arc<dyn PhysicalExpr>>,
+        assignments: Option<Vec<(usize, Arc<dyn PhysicalExpr>)>>,
+    ) -> Self {
+        Self {
+            batches,
+            schema,
+            filter,
+            assignments,
+        }
+    }
+
+    /// Returns the mask of the rows of `batch` matched by the filter
+    fn matched_rows(&self, batch: &RecordBatch) -> Result<BooleanArray> {
+        let Some(filter) = &self.filter else {
+            return Ok(BooleanArray::from(vec![true; batch.num_rows()]));
+        };
+        let matched = filter.evaluate(batch)?.into_array(batch.num_rows())?;
+        let Some(matched) = matched.as_boolean_opt() else {
+            return internal_err!(
+                "Filter of DELETE or UPDATE must evaluate to a boolean, got {}",
+                matched.data_type()
+            );
+        };
+        // rows for which the filter is null are not matched
+        Ok(prep_null_mask_filter(matched))
+    }
+
+    /// Returns `batch` with the matched rows deleted or updated, and the
+    /// number of matched rows
+    fn apply(&self, batch: &RecordBatch) -> Result<(RecordBatch, usize)> {
+        let matched = self.matched_rows(batch)?;
+        let count = matched.true_count();
+        if count == 0 {
+            return Ok((batch.clone(), 0));
+        }
+        let Some(assignments) = &self.assignments else {
+            let kept = filter_record_batch(batch, &not(&matched)?)?;
+            return Ok((kept, count));
+        };
+
+        let mut columns = batch.columns().to_vec();
+        for (index, value) in assignments {
+            let data_type = self.schema.field(*index).data_type();
+            let value = value.evaluate(batch)?.into_array(batch.num_rows())?;
+            let value = cast(&value, data_type)?;
+            columns[*index] = zip(&matched, &value, batch.column(*index))?;
+        }
+        Ok((RecordBatch::try_new(batch.schema(), columns)?, count))
+    }
+}
+
+#[async_trait]
+impl DataSink for MemDmlSink {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        None
+    }
+
+    async fn write_all(
+        &self,
+        _data: SendableRecordBatchStream,
+        _context: &Arc<TaskContext>,
+    ) -> Result<u64> {
+        let mut row_count = 0;
+        for partition in self.batches.iter() {
+            // hold the lock until the entire partition is rewritten
+            let mut batches = partition.write().await;
+            let mut new_batches = Vec::with_capacity(batches.len());
+            for batch in batches.iter() {
+                let (batch, count) = self.apply(batch)?;
+                row_count += count;
+                if batch.num_rows() > 0 {
+                    new_batches.push(batch);
+                }
+            }
+            *batches = new_batches;
+        }
+        Ok(row_count as u64)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
     use super::*;
+    use crate::assert_batches_eq;
     use crate::datasource::provider_as_source;
-    use crate::physical_plan::collect;
-    use crate::prelude::SessionContext;
+    use crate::physical_plan::{collect, displayable};
+    use crate::prelude::{col, SessionConfig, SessionContext};
 
     use arrow::array::{AsArray, Int32Array};
     use arrow::datatypes::{DataType, Field, Schema, UInt64Type};
     use arrow::error::ArrowError;
     use datafusion_common::DataFusionError;
     use datafusion_expr::LogicalPlanBuilder;
+    use datafusion_physical_expr::expressions::Column;
 
     use futures::StreamExt;
 
@@ -766,4 +1020,140 @@ mod tests {
         assert_eq!(resulting_data_in_table[0].len(), 2);
         Ok(())
     }
+
+    async fn dml_experiment(sql: &str) -> Result<(u64, Vec<RecordBatch>)> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, true),
+        ]));
+        let batch = |a: Vec<i32>, b: Vec<Option<i32>>| {
+            RecordBatch::try_new(
+                schema.clone(),
+                vec![Arc::new(Int32Array::from(a)), Arc::new(Int32Array::from(b))],
+            )
+        };
+        let partitions = vec![
+            vec![batch(vec![1, 2], vec![Some(10), None])?],
+            vec![batch(vec![3, 4], vec![Some(30), Some(40)])?],
+        ];
+        let table = Arc::new(MemTable::try_new(schema, partitions)?);
+
+        let session_ctx = SessionContext::new();
+        session_ctx.register_table("t", table.clone())?;
+        let res = session_ctx.sql(sql).await?.collect().await?;
+
+        let mut batches = vec![];
+        for partition in table.batches.iter() {
+            batches.extend(partition.read().await.iter().cloned());
+        }
+        Ok((extract_count(res), batches))
+    }
+
+    #[tokio::test]
+    async fn test_delete_from() -> Result<()> {
+        let (count, batches) =
+            dml_experiment("DELETE FROM t WHERE a > 1 AND b < 35").await?;
+        assert_eq!(count, 1);
+        let expected = [
+            "+---+----+",
+            "| a | b  |",
+            "+---+----+",
+            "| 1 | 10 |",
+            "| 2 |    |",
+            "| 4 | 40 |",
+            "+---+----+",
+        ];
+        assert_batches_eq!(expected, &batches);
+
+        let (count, batches) = dml_experiment("DELETE FROM t").await?;
+        assert_eq!(count, 4);
+        assert!(batches.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update() -> Result<()> {
+        let (count, batches) =
+            dml_experiment("UPDATE t SET b = a * 100 WHERE b IS NULL OR a = 4").await?;
+        assert_eq!(count, 2);
+        let expected = [
+            "+---+-----+",
+            "| a | b   |",
+            "+---+-----+",
+            "| 1 | 10  |",
+            "| 2 | 200 |",
+            "| 3 | 30  |",
+            "| 4 | 400 |",
+            "+---+-----+",
+        ];
+        assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+
+    /// A table of `(key, value)` rows with `key` from 0 to 19, hash
+    /// partitioned on `key` by a [`RepartitionExec`]
+    async fn bucketed_table(
+        key: &str,
+        value: &str,
+        partitions: usize,
+    ) -> Result<MemTable> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new(key, DataType::Int32, false),
+            Field::new(value, DataType::Int32, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from_iter_values(0..20)),
+                Arc::new(Int32Array::from_iter_values((0..20).map(|i| i * 10))),
+            ],
+        )?;
+        let input = MemoryExec::try_new(&[vec![batch]], schema.clone(), None)?;
+        let exec = RepartitionExec::try_new(
+            Arc::new(input),
+            Partitioning::Hash(vec![Arc::new(Column::new(key, 0))], partitions),
+        )?;
+
+        let task_ctx = SessionContext::new().task_ctx();
+        let mut data = vec![];
+        for i in 0..partitions {
+            data.push(common::collect(exec.execute(i, task_ctx.clone())?).await?);
+        }
+        Ok(MemTable::try_new(schema, data)?.with_hash_partitioning(vec![col(key)]))
+    }
+
+    #[tokio::test]
+    async fn test_join_hash_partitioned_tables() -> Result<()> {
+        let mut config = SessionConfig::new().with_target_partitions(8);
+        let optimizer = &mut config.options_mut().optimizer;
+        optimizer.hash_join_single_partition_threshold = 0;
+        optimizer.hash_join_single_partition_threshold_rows = 0;
+        let ctx = SessionContext::new_with_config(config);
+        ctx.register_table("l", Arc::new(bucketed_table("a", "b", 4).await?))?;
+        ctx.register_table("r", Arc::new(bucketed_table("c", "d", 4).await?))?;
+        ctx.register_table("r2", Arc::new(bucketed_table("c", "d", 2).await?))?;
+
+        // the tables are co-partitioned on the join keys
+        let df = ctx.sql("SELECT a, b, d FROM l JOIN r ON a = c").await?;
+        let plan = df.clone().create_physical_plan().await?;
+        let formatted = displayable(plan.as_ref()).indent(true).to_string();
+        assert!(formatted.contains("mode=Partitioned"), "{formatted}");
+        assert!(!formatted.contains("RepartitionExec"), "{formatted}");
+        assert_eq!(plan.output_partitioning().partition_count(), 4);
+        let batches = df.collect().await?;
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 20);
+
+        // the tables have different numbers of partitions
+        let df = ctx.sql("SELECT a, b, d FROM l JOIN r2 ON a = c").await?;
+        let plan = df.create_physical_plan().await?;
+        let formatted = displayable(plan.as_ref()).indent(true).to_string();
+        assert!(formatted.contains("RepartitionExec"), "{formatted}");
+
+        // the join keys are not the partitioning columns
+        let df = ctx.sql("SELECT a, b, d FROM l JOIN r ON b = d").await?;
+        let plan = df.create_physical_plan().await?;
+        let formatted = displayable(plan.as_ref()).indent(true).to_string();
+        assert!(formatted.contains("RepartitionExec"), "{formatted}");
+        Ok(())
+    }
 }
@@ -46,6 +46,7 @@ use bytes::{Buf, Bytes};
 use futures::{ready, StreamExt, TryStreamExt};
 use object_store::buffered::BufWriter;
 use object_store::{GetOptions, GetResultPayload, ObjectStore};
+use regex::Regex;
 use tokio::io::AsyncWriteExt;
 use tokio::task::JoinSet;
 
@@ -58,6 +59,10 @@ pub struct CsvExec {
     delimiter: u8,
     quote: u8,
     escape: Option<u8>,
+    /// The string that represents null values
+    null_value: Option<String>,
+    /// Whether quoted values may contain newlines
+    newlines_in_values: bool,
     /// Execution metrics
     metrics: ExecutionPlanMetricsSet,
     /// Compression type of the file associated with CsvExec
@@ -89,6 +94,8 @@ impl CsvExec {
             delimiter,
             quote,
             escape,
+            null_value: None,
+            newlines_in_values: false,
             metrics: ExecutionPlanMetricsSet::new(),
             file_compression_type,
             cache,
@@ -118,6 +125,31 @@ impl CsvExec {
         self.escape
     }
 
+    /// Read the fields equal to `null_value` as nulls, instead of the empty
+    /// fields
+    pub fn with_null_value(mut self, null_value: Option<String>) -> Self {
+        self.null_value = null_value;
+        self
+    }
+
+    /// The string that represents null values
+    pub fn null_value(&self) -> Option<&str> {
+        self.null_value.as_deref()
+    }
+
+    /// Set whether quoted values may contain newlines. The start of a row
+    /// can't be found from an arbitrary position of such files, so they are
+    /// not split into byte ranges to be read in parallel.
+    pub fn with_newlines_in_values(mut self, newlines_in_values: bool) -> Self {
+        self.newlines_in_values = newlines_in_values;
+        self
+    }
+
+    /// true if quoted values may contain newlines
+    pub fn newlines_in_values(&self) -> bool {
+        self.newlines_in_values
+    }
+
     fn output_partitioning_helper(file_scan_config: &FileScanConfig) -> Partitioning {
         Partitioning::UnknownPartitioning(file_scan_config.file_groups.len())
     }
@@ -188,20 +220,22 @@ impl ExecutionPlan for CsvExec {
     /// Redistribute files across partitions according to their size
     /// See comments on [`FileGroupPartitioner`] for more detail.
     ///
-    /// Return `None` if can't get repartitioned(empty/compressed file).
+    /// Compressed files, and files whose values may contain newlines, are not
+    /// split, but distributed whole across partitions.
+    ///
+    /// Return `None` if can't get repartitioned(empty file).
     fn repartitioned(
         &self,
         target_partitions: usize,
         config: &ConfigOptions,
     ) -> Result<Option<Arc<dyn ExecutionPlan>>> {
         let repartition_file_min_size = config.optimizer.repartition_file_min_size;
-        // Parallel execution on compressed CSV file is not supported yet.
-        if self.file_compression_type.is_compressed() {
-            return Ok(None);
-        }
 
         let repartitioned_file_groups_option = FileGroupPartitioner::new()
             .with_target_partitions(target_partitions)
+            .with_split_files(
+                !self.file_compression_type.is_compressed() && !self.newlines_in_values,
+            )
             .with_preserve_order_within_groups(
                 self.properties().output_ordering().is_some(),
             )
@@ -233,6 +267,7 @@ impl ExecutionPlan for CsvExec {
             delimiter: self.delimiter,
             quote: self.quote,
             escape: self.escape,
+            null_regex: self.null_value.as_deref().map(null_regex).transpose()?,
             object_store,
         });
 
@@ -264,6 +299,7 @@ pub struct CsvConfig {
     delimiter: u8,
     quote: u8,
     escape: Option<u8>,
+    null_regex: Option<Regex>,
     object_store: Arc<dyn ObjectStore>,
 }
 
@@ -286,6 +322,7 @@ impl CsvConfig {
             delimiter,
             quote,
             escape: None,
+            null_regex: None,
             object_store,
         }
     }
@@ -309,11 +346,20 @@ impl CsvConfig {
         if let Some(escape) = self.escape {
             builder = builder.with_escape(escape)
         }
+        if let Some(null_regex) = &self.null_regex {
+            builder = builder.with_null_regex(null_regex.clone())
+        }
 
         builder
     }
 }
 
+/// Returns the regex matching the fields that are exactly `null_value`
+pub(crate) fn null_regex(null_value: &str) -> Result<Regex> {
+    Regex::new(&format!("^{}$", regex::escape(null_value)))
+        .map_err(|e| DataFusionError::External(Box::new(e)))
+}
+
 /// A [`FileOpener`] that opens a CSV file and yields a [`FileOpenFuture`]
 pub struct CsvOpener {
     config: Arc<CsvConfig>,
@@ -19,7 +19,7 @@
 
 use crate::datasource::listing::{FileRange, PartitionedFile};
 use itertools::Itertools;
-use std::cmp::min;
+use std::cmp::{min, Reverse};
 use std::collections::BinaryHeap;
 use std::iter::repeat_with;
 
@@ -121,6 +121,13 @@ use std::iter::repeat_with;
 ///                                    If target_partitions = 4,
 ///                                      divides into 4 groups
 /// ```
+///
+/// # Files That Can't Be Split
+///
+/// Compressed files can only be read from beginning to end. If files can't
+/// be split, whole files are distributed across the partitions instead,
+/// assigning the largest files first to the partition with the least data.
+/// Multiple files are then still read in parallel.
 #[derive(Debug, Clone, Copy)]
 pub struct FileGroupPartitioner {
     /// how many partitions should be created
@@ -129,6 +136,8 @@ pub struct FileGroupPartitioner {
     repartition_file_min_size: usize,
     /// if the order when reading the files must be preserved
     preserve_order_within_groups: bool,
+    /// if files can be split into byte ranges
+    split_files: bool,
 }
 
 impl Default for FileGroupPartitioner {
@@ -142,11 +151,13 @@ impl FileGroupPartitioner {
     /// 1. `target_partitions = 1`
     /// 2. `repartition_file_min_size = 10MB`
     /// 3. `preserve_order_within_groups = false`
+    /// 4. `split_files = true`
     pub fn new() -> Self {
         Self {
             target_partitions: 1,
             repartition_file_min_size: 10 * 1024 * 1024,
             preserve_order_within_groups: false,
+            split_files: true,
         }
     }
 
@@ -174,6 +185,13 @@ impl FileGroupPartitioner {
         self
     }
 
+    /// Set whether files can be split into byte ranges, which is not the
+    /// case for compressed files
+    pub fn with_split_files(mut self, split_files: bool) -> Self {
+        self.split_files = split_files;
+        self
+    }
+
     /// Repartition input files according to the settings on this [`FileGroupPartitioner`].
     ///
     /// If no repartitioning is needed or possible, return `None`.
@@ -191,6 +209,16 @@ impl FileGroupPartitioner {
             return None;
         }
 
+        // files which can't be split can only be moved to other groups
+        // if the order of the groups need not be preserved
+        if !self.split_files {
+            return if self.preserve_order_within_groups {
+                None
+            } else {
+                self.repartition_whole_files(file_groups)
+            };
+        }
+
         //  special case when order must be preserved
         if self.preserve_order_within_groups {
             self.repartition_preserving_order(file_groups)
@@ -264,6 +292,42 @@ impl FileGroupPartitioner {
         Some(repartitioned_files)
     }
 
+    /// Distribute whole files across partitions by size, ignoring any
+    /// existing grouping / ordering
+    fn repartition_whole_files(
+        &self,
+        file_groups: &[Vec<PartitionedFile>],
+    ) -> Option<Vec<Vec<PartitionedFile>>> {
+        let mut flattened_files = file_groups.iter().flatten().collect::<Vec<_>>();
+
+        let total_size = flattened_files
+            .iter()
+            .map(|f| f.object_meta.size)
+            .sum::<usize>();
+        if total_size < self.repartition_file_min_size || total_size == 0 {
+            return None;
+        }
+
+        let num_groups = min(self.target_partitions, flattened_files.len());
+        if num_groups <= 1 {
+            return None;
+        }
+
+        // Assign the largest files first, each to the group with the least
+        // data so far
+        flattened_files.sort_by_key(|f| Reverse(f.object_meta.size));
+        let mut groups = vec![vec![]; num_groups];
+        let mut heap: BinaryHeap<_> =
+            (0..num_groups).map(|index| Reverse((0, index))).collect();
+        for file in flattened_files {
+            let Reverse((size, index)) = heap.pop().unwrap();
+            groups[index].push(file.clone());
+            heap.push(Reverse((size + file.object_meta.size, index)));
+        }
+
+        Some(groups)
+    }
+
     /// Redistribute file groups across size preserving order
     fn repartition_preserving_order(
         &self,
@@ -578,6 +642,65 @@ mod test {
         assert_partitioned_files(None, repartition_test(partitioner, empty_partition))
     }
 
+    #[test]
+    fn repartition_whole_files() {
+        // Files that can't be split are distributed by size
+        let source_partitions = vec![vec![
+            pfile("a", 40),
+            pfile("b", 10),
+            pfile("c", 30),
+            pfile("d", 20),
+        ]];
+
+        let actual = FileGroupPartitioner::new()
+            .with_split_files(false)
+            .with_target_partitions(2)
+            .with_repartition_file_min_size(10)
+            .repartition_file_groups(&source_partitions);
+
+        let expected = Some(vec![
+            vec![pfile("a", 40), pfile("b", 10)],
+            vec![pfile("c", 30), pfile("d", 20)],
+        ]);
+        assert_partitioned_files(expected, actual);
+    }
+
+    #[test]
+    fn repartition_whole_files_too_much_partitions() {
+        // At most one group per file
+        let source_partitions = vec![vec![pfile("a", 50), pfile("b", 100)]];
+
+        let actual = FileGroupPartitioner::new()
+            .with_split_files(false)
+            .with_target_partitions(4)
+            .with_repartition_file_min_size(10)
+            .repartition_file_groups(&source_partitions);
+
+        let expected = Some(vec![vec![pfile("b", 100)], vec![pfile("a", 50)]]);
+        assert_partitioned_files(expected, actual);
+    }
+
+    #[test]
+    fn repartition_whole_files_no_action() {
+        // A single file can't be split
+        let single_partition = vec![vec![pfile("a", 123)]];
+        let partitioner = FileGroupPartitioner::new()
+            .with_split_files(false)
+            .with_target_partitions(4)
+            .with_repartition_file_min_size(10);
+        assert_partitioned_files(
+            None,
+            partitioner.repartition_file_groups(&single_partition),
+        );
+
+        // Files can't be moved to other groups if the order must be preserved
+        let source_partitions = vec![vec![pfile("a", 100), pfile("b", 200)]];
+        let actual = partitioner
+            .with_preserve_order_within_groups(true)
+            .repartition_file_groups(&source_partitions);
+        assert_partitioned_files(None, actual);
+    }
+
     #[test]
     fn repartition_ordered_no_action_too_few_partitions() {
         // No action as there are no new groups to redistribute to
@@ -86,6 +86,11 @@ impl NdJsonExec {
         &self.base_config
     }
 
+    /// The compression of the files
+    pub fn file_compression_type(&self) -> FileCompressionType {
+        self.file_compression_type
+    }
+
     fn output_partitioning_helper(file_scan_config: &FileScanConfig) -> Partitioning {
         Partitioning::UnknownPartitioning(file_scan_config.file_groups.len())
     }
@@ -154,9 +159,6 @@ impl ExecutionPlan for NdJsonExec {
         target_partitions: usize,
         config: &datafusion_common::config::ConfigOptions,
     ) -> Result<Option<Arc<dyn ExecutionPlan>>> {
-        if self.file_compression_type == FileCompressionType::GZIP {
-            return Ok(None);
-        }
         let repartition_file_min_size = config.optimizer.repartition_file_min_size;
         let preserve_order_within_groups = self.properties().output_ordering().is_some();
         let file_groups = &self.base_config.file_groups;
@@ -164,6 +166,7 @@ impl ExecutionPlan for NdJsonExec {
         let repartitioned_file_groups_option = FileGroupPartitioner::new()
             .with_target_partitions(target_partitions)
             .with_preserve_order_within_groups(preserve_order_within_groups)
+            .with_split_files(!self.file_compression_type.is_compressed())
             .with_repartition_file_min_size(repartition_file_min_size)
             .repartition_file_groups(file_groups);
 
@@ -819,6 +822,72 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn write_json_rotating_files() -> Result<()> {
+        let ctx = SessionContext::new_with_config(
+            SessionConfig::new()
+                .with_batch_size(1)
+                .with_target_partitions(1),
+        );
+
+        let path = format!("{TEST_DATA_BASE}/1.json");
+        ctx.register_json("test", path.as_str(), NdJsonReadOptions::default())
+            .await?;
+
+        let tmp_dir = TempDir::new()?;
+        let local = Arc::new(LocalFileSystem::new_with_prefix(&tmp_dir)?);
+        let local_url = Url::parse("file://local").unwrap();
+        ctx.runtime_env().register_object_store(&local_url, local);
+
+        // every batch of a single row fills a file
+        let out_dir = tmp_dir.as_ref().to_str().unwrap().to_string() + "/out/";
+        let out_dir_url = "file://local/out/";
+        let mut options = ctx.state().default_table_options().json;
+        options.max_file_size_bytes = Some(1);
+        options.flush_interval_ms = Some(10);
+        let df = ctx.sql("SELECT a, b FROM test").await?;
+        df.write_json(out_dir_url, DataFrameWriteOptions::new(), Some(options))
+            .await?;
+
+        let mut names = fs::read_dir(&out_dir)
+            .unwrap()
+            .map(|path| path.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names.len(), 4);
+        assert!(names[0].ends_with("_0.json"));
+        assert!(names[3].ends_with("_3.json"));
+
+        // rotating requires a directory to write the files to
+        let df = ctx.sql("SELECT a, b FROM test").await?;
+        let mut options = ctx.state().default_table_options().json;
+        options.rotation_interval_ms = Some(1000);
+        let e = df
+            .write_json(
+                "file://local/out.json",
+                DataFrameWriteOptions::new(),
+                Some(options),
+            )
+            .await
+            .unwrap_err();
+        assert!(e
+            .to_string()
+            .contains("Rotating output files requires a directory output path"));
+
+        let ctx = SessionContext::new();
+        ctx.register_json("allparts", &out_dir, NdJsonReadOptions::default())
+            .await?;
+        let allparts = ctx
+            .sql("SELECT a, b FROM allparts")
+            .await?
+            .collect()
+            .await?;
+        let allparts_count: usize = allparts.iter().map(|batch| batch.num_rows()).sum();
+        assert_eq!(allparts_count, 4);
+
+        Ok(())
+    }
+
     #[rstest(
         file_compression_type,
         case(FileCompressionType::UNCOMPRESSED),
@@ -24,8 +24,11 @@ mod file_groups;
 mod file_scan_config;
 mod file_stream;
 mod json;
+mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
+mod scan_filter;
+mod scan_summary;
 
 pub(crate) use self::csv::plan_to_csv;
 pub(crate) use self::json::plan_to_json;
@@ -34,6 +37,7 @@ pub use self::parquet::{ParquetExec, ParquetFileMetrics, ParquetFileReaderFactor
 
 pub use arrow_file::ArrowExec;
 pub use avro::AvroExec;
+pub(crate) use csv::null_regex;
 pub use csv::{CsvConfig, CsvExec, CsvOpener};
 pub use file_groups::FileGroupPartitioner;
 pub use file_scan_config::{
@@ -41,6 +45,10 @@ pub use file_scan_config::{
 };
 pub use file_stream::{FileOpenFuture, FileOpener, FileStream, OnError};
 pub use json::{JsonOpener, NdJsonExec};
+pub(crate) use orc::read_orc_summary;
+pub use orc::OrcExec;
+pub use scan_filter::ScanFilter;
+pub use scan_summary::{file_scan_summaries, FileScanSummary, ScannedFile};
 
 use std::{
     fmt::{Debug, Formatter, Result as FmtResult},
@@ -61,7 +69,7 @@ use crate::{
 };
 
 use arrow::{
-    array::new_null_array,
+    array::{new_null_array, Array, ArrayRef, StructArray},
     compute::{can_cast_types, cast},
     datatypes::{DataType, Schema, SchemaRef},
     record_batch::{RecordBatch, RecordBatchOptions},
@@ -126,6 +134,20 @@ impl DisplayAs for FileScanConfig {
 
         display_orderings(f, &orderings)?;
 
+        // The partitions selected after partition pruning
+        if matches!(t, DisplayFormatType::Verbose)
+            && !self.table_partition_cols.is_empty()
+        {
+            let cols = scan_summary::partition_col_names(self);
+            write!(f, ", partitions=[")?;
+            fmt_elements_split_by_commas(
+                scan_summary::file_partitions(&self.file_groups).into_iter(),
+                f,
+                |values, f| write!(f, "{}", scan_summary::partition_path(&cols, values)),
+            )?;
+            write!(f, "]")?;
+        }
+
         Ok(())
     }
 }
@@ -296,7 +318,7 @@ impl SchemaAdapter {
             if let Some((table_idx, table_field)) =
                 self.table_schema.fields().find(file_field.name())
             {
-                match can_cast_types(file_field.data_type(), table_field.data_type()) {
+                match can_adapt_types(file_field.data_type(), table_field.data_type()) {
                     true => {
                         field_mappings[table_idx] = Some(projection.len());
                         projection.push(file_idx);
@@ -345,7 +367,9 @@ impl SchemaMapping {
             .iter()
             .zip(&self.field_mappings)
             .map(|(field, file_idx)| match file_idx {
-                Some(batch_idx) => cast(&batch_cols[*batch_idx], field.data_type()),
+                Some(batch_idx) => {
+                    adapt_array(&batch_cols[*batch_idx], field.data_type())
+                }
                 None => Ok(new_null_array(field.data_type(), batch_rows)),
             })
             .collect::<Result<Vec<_>, _>>()?;
@@ -359,6 +383,49 @@ impl SchemaMapping {
     }
 }
 
+/// Returns true if arrays of type `from` can be adapted to type `to` by
+/// [`adapt_array`]
+fn can_adapt_types(from: &DataType, to: &DataType) -> bool {
+    match (from, to) {
+        (DataType::Struct(from_fields), DataType::Struct(to_fields)) => to_fields
+            .iter()
+            .all(|to_field| match from_fields.find(to_field.name()) {
+                Some((_, from_field)) => {
+                    can_adapt_types(from_field.data_type(), to_field.data_type())
+                }
+                None => to_field.is_nullable(),
+            }),
+        _ => can_cast_types(from, to),
+    }
+}
+
+/// Casts `array` to `data_type`. Unlike [`cast`], the fields of structs are
+/// matched by name, and fields missing from `array` are filled with nulls, as
+/// the files of a table may have evolved their struct columns.
+fn adapt_array(array: &ArrayRef, data_type: &DataType) -> Result<ArrayRef> {
+    let (Some(struct_array), DataType::Struct(fields)) =
+        (array.as_any().downcast_ref::<StructArray>(), data_type)
+    else {
+        return Ok(cast(array, data_type)?);
+    };
+    if array.data_type() == data_type {
+        return Ok(array.clone());
+    }
+
+    let children = fields
+        .iter()
+        .map(|field| match struct_array.column_by_name(field.name()) {
+            Some(child) => adapt_array(child, field.data_type()),
+            None => Ok(new_null_array(field.data_type(), array.len())),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Arc::new(StructArray::try_new(
+        fields.clone(),
+        children,
+        struct_array.nulls().cloned(),
+    )?))
+}
+
 /// A single file or part of a file that should be read, along with its schema, statistics
 pub struct FileMeta {
     /// Path for the file (e.g. URL, filesystem path, etc)
@@ -589,12 +656,13 @@ mod tests {
     use crate::physical_plan::{DefaultDisplay, VerboseDisplay};
 
     use arrow_array::cast::AsArray;
-    use arrow_array::types::{Float32Type, Float64Type, UInt32Type};
+    use arrow_array::types::{Float32Type, Float64Type, Int64Type, UInt32Type};
     use arrow_array::{
         BinaryArray, BooleanArray, Float32Array, Int32Array, Int64Array, StringArray,
         UInt64Array,
     };
-    use arrow_schema::Field;
+    use arrow_schema::{Field, Fields};
+    use datafusion_common::{ScalarValue, Statistics};
 
     use chrono::Utc;
 
@@ -714,6 +782,57 @@ mod tests {
         assert_eq!(c4.value(2), 3.0_f32);
     }
 
+    #[test]
+    fn schema_mapping_map_struct_by_name() {
+        let table_fields = Fields::from(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let table_schema = Arc::new(Schema::new(vec![Field::new(
+            "s",
+            DataType::Struct(table_fields),
+            true,
+        )]));
+        let adapter = SchemaAdapter::new(table_schema.clone());
+
+        // the file has no field `b`, and a field `c` the table does not have
+        let c: ArrayRef = Arc::new(StringArray::from(vec!["x", "y"]));
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None]));
+        let s = StructArray::from(vec![
+            (Arc::new(Field::new("c", DataType::Utf8, true)), c),
+            (Arc::new(Field::new("a", DataType::Int32, true)), a),
+        ]);
+        let file_schema = Schema::new(vec![Field::new("s", s.data_type().clone(), true)]);
+        let (mapping, _) = adapter.map_schema(&file_schema).unwrap();
+
+        let batch =
+            RecordBatch::try_new(Arc::new(file_schema), vec![Arc::new(s)]).unwrap();
+        let mapped_batch = mapping.map_batch(batch).unwrap();
+        assert_eq!(mapped_batch.schema(), table_schema);
+
+        let s = mapped_batch.column(0).as_struct();
+        let a = s.column(0).as_primitive::<Int64Type>();
+        assert_eq!(a.value(0), 1);
+        assert!(a.is_null(1));
+        assert_eq!(s.column(1).null_count(), 2);
+
+        // non-nullable fields missing from the file cannot be filled
+        let table_schema = Arc::new(Schema::new(vec![Field::new(
+            "s",
+            DataType::Struct(vec![Field::new("b", DataType::Utf8, false)].into()),
+            true,
+        )]));
+        let file_schema = Schema::new(vec![Field::new(
+            "s",
+            DataType::Struct(vec![Field::new("a", DataType::Int32, true)].into()),
+            true,
+        )]);
+        let err = SchemaAdapter::new(table_schema)
+            .map_schema(&file_schema)
+            .unwrap_err();
+        assert!(err.to_string().contains("Cannot cast file schema field s"));
+    }
+
     #[test]
     fn file_groups_display_empty() {
         let expected = "{0 groups: []}";
@@ -847,6 +966,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn file_scan_config_display_partitions() {
+        let file = |path: &str, date: &str| {
+            let mut file = partitioned_file(path);
+            file.partition_values = vec![ScalarValue::from(date)];
+            file
+        };
+        let file_schema =
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let config = FileScanConfig {
+            object_store_url: ObjectStoreUrl::local_filesystem(),
+            statistics: Statistics::new_unknown(&file_schema),
+            file_schema,
+            file_groups: vec![
+                vec![file("date=2024-01-01/foo", "2024-01-01")],
+                vec![
+                    file("date=2024-01-02/bar", "2024-01-02"),
+                    file("date=2024-01-01/baz", "2024-01-01"),
+                ],
+            ],
+            projection: None,
+            limit: None,
+            table_partition_cols: vec![Field::new("date", DataType::Utf8, false)],
+            output_ordering: vec![],
+        };
+
+        let expected = "file_groups={2 groups: [[date=2024-01-01/foo], [date=2024-01-02/bar, date=2024-01-01/baz]]}, projection=[a, date]";
+        assert_eq!(DefaultDisplay(config.clone()).to_string(), expected);
+
+        let expected =
+            format!("{expected}, partitions=[date=2024-01-01, date=2024-01-02]");
+        assert_eq!(VerboseDisplay(config).to_string(), expected);
+    }
+
     /// create a PartitionedFile for testing
     fn partitioned_file(path: &str) -> PartitionedFile {
         let object_meta = ObjectMeta {
@@ -0,0 +1,373 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Execution plan for reading Apache ORC files
+
+use std::any::Any;
+use std::sync::Arc;
+
+use super::FileScanConfig;
+use crate::error::Result;
+use crate::physical_plan::metrics::{ExecutionPlanMetricsSet, MetricsSet};
+use crate::physical_plan::{
+    DisplayAs, DisplayFormatType, ExecutionMode, ExecutionPlan, Partitioning,
+    PlanProperties, SendableRecordBatchStream, Statistics,
+};
+
+use arrow::datatypes::{Schema, SchemaRef};
+use datafusion_execution::TaskContext;
+use datafusion_physical_expr::{EquivalenceProperties, LexOrdering};
+use object_store::{ObjectMeta, ObjectStore};
+
+/// Execution plan for scanning ORC data source
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct OrcExec {
+    base_config: FileScanConfig,
+    projected_statistics: Statistics,
+    projected_schema: SchemaRef,
+    projected_output_ordering: Vec<LexOrdering>,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+    cache: PlanProperties,
+}
+
+impl OrcExec {
+    /// Create a new ORC reader execution plan provided base configurations
+    pub fn new(base_config: FileScanConfig) -> Self {
+        let (projected_schema, projected_statistics, projected_output_ordering) =
+            base_config.project();
+        let cache = Self::compute_properties(
+            projected_schema.clone(),
+            &projected_output_ordering,
+            &base_config,
+        );
+        Self {
+            base_config,
+            projected_schema,
+            projected_statistics,
+            projected_output_ordering,
+            metrics: ExecutionPlanMetricsSet::new(),
+            cache,
+        }
+    }
+
+    /// Ref to the base configs
+    pub fn base_config(&self) -> &FileScanConfig {
+        &self.base_config
+    }
+
+    /// This function creates the cache object that stores the plan properties such as schema, equivalence properties, ordering, partitioning, etc.
+    fn compute_properties(
+        schema: SchemaRef,
+        orderings: &[LexOrdering],
+        file_scan_config: &FileScanConfig,
+    ) -> PlanProperties {
+        let eq_properties = EquivalenceProperties::new_with_orderings(schema, orderings);
+        let n_partitions = file_scan_config.file_groups.len();
+
+        PlanProperties::new(
+            eq_properties,
+            Partitioning::UnknownPartitioning(n_partitions),
+            ExecutionMode::Bounded,
+        )
+    }
+}
+
+impl DisplayAs for OrcExec {
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        write!(f, "OrcExec: ")?;
+        self.base_config.fmt_as(t, f)
+    }
+}
+
+impl ExecutionPlan for OrcExec {
+    fn name(&self) -> &'static str {
+        "OrcExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        Vec::new()
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        _: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(self)
+    }
+
+    #[cfg(not(feature = "orc"))]
+    fn execute(
+        &self,
+        _partition: usize,
+        _context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        Err(crate::error::DataFusionError::NotImplemented(
+            "Cannot execute orc plan without orc feature enabled".to_string(),
+        ))
+    }
+
+    #[cfg(feature = "orc")]
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        use super::file_stream::FileStream;
+        let object_store = context
+            .runtime_env()
+            .object_store(&self.base_config.object_store_url)?;
+
+        let opener = private::OrcOpener {
+            projected_file_schema: self.base_config.projected_file_schema(),
+            batch_size: context.session_config().batch_size(),
+            object_store,
+        };
+
+        let stream =
+            FileStream::new(&self.base_config, partition, opener, &self.metrics)?;
+        Ok(Box::pin(stream))
+    }
+
+    fn statistics(&self) -> Result<Statistics> {
+        Ok(self.projected_statistics.clone())
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+}
+
+/// Reads the schema and the number of rows of an ORC file from its footer
+#[cfg(feature = "orc")]
+pub(crate) async fn read_orc_summary(
+    store: &Arc<dyn ObjectStore>,
+    object: &ObjectMeta,
+) -> Result<(Schema, usize)> {
+    use orc_rust::reader::metadata::read_metadata_async;
+
+    let mut reader = private::ObjectStoreReader::new(store.clone(), object);
+    let metadata = read_metadata_async(&mut reader)
+        .await
+        .map_err(|e| crate::error::DataFusionError::External(Box::new(e)))?;
+    let schema = private::arrow_schema(&metadata);
+    Ok((schema, metadata.number_of_rows() as usize))
+}
+
+/// Reads the schema and the number of rows of an ORC file from its footer
+#[cfg(not(feature = "orc"))]
+pub(crate) async fn read_orc_summary(
+    _store: &Arc<dyn ObjectStore>,
+    _object: &ObjectMeta,
+) -> Result<(Schema, usize)> {
+    Err(crate::error::DataFusionError::NotImplemented(
+        "cannot read orc schema without the 'orc' feature enabled".to_string(),
+    ))
+}
+
+#[cfg(feature = "orc")]
+mod private {
+    use std::collections::HashMap;
+    use std::ops::Range;
+
+    use super::*;
+    use crate::datasource::physical_plan::file_stream::{FileOpenFuture, FileOpener};
+    use crate::datasource::physical_plan::{FileMeta, SchemaAdapter};
+    use crate::error::DataFusionError;
+
+    use arrow::error::ArrowError;
+    use bytes::Bytes;
+    use futures::future::BoxFuture;
+    use futures::{FutureExt, StreamExt, TryFutureExt};
+    use object_store::path::Path;
+    use orc_rust::projection::ProjectionMask;
+    use orc_rust::reader::metadata::FileMetadata;
+    use orc_rust::reader::AsyncChunkReader;
+    use orc_rust::ArrowReaderBuilder;
+
+    /// Reads the byte ranges of an ORC file from an [`ObjectStore`]
+    pub struct ObjectStoreReader {
+        store: Arc<dyn ObjectStore>,
+        location: Path,
+        size: usize,
+    }
+
+    impl ObjectStoreReader {
+        pub fn new(store: Arc<dyn ObjectStore>, object: &ObjectMeta) -> Self {
+            Self {
+                store,
+                location: object.location.clone(),
+                size: object.size,
+            }
+        }
+    }
+
+    impl AsyncChunkReader for ObjectStoreReader {
+        fn len(&mut self) -> BoxFuture<'_, std::io::Result<u64>> {
+            let size = self.size as u64;
+            async move { Ok(size) }.boxed()
+        }
+
+        fn get_bytes(
+            &mut self,
+            offset_from_start: u64,
+            length: u64,
+        ) -> BoxFuture<'_, std::io::Result<Bytes>> {
+            let start = offset_from_start as usize;
+            let range: Range<usize> = start..start + length as usize;
+            self.store
+                .get_range(&self.location, range)
+                .map_err(std::io::Error::from)
+                .boxed()
+        }
+    }
+
+    /// Returns the arrow schema of the columns of an ORC file
+    pub fn arrow_schema(metadata: &FileMetadata) -> Schema {
+        metadata
+            .root_data_type()
+            .create_arrow_schema(&HashMap::new())
+    }
+
+    pub struct OrcOpener {
+        /// The schema of the columns read from each file
+        pub projected_file_schema: SchemaRef,
+        pub batch_size: usize,
+        pub object_store: Arc<dyn ObjectStore>,
+    }
+
+    impl FileOpener for OrcOpener {
+        fn open(&self, file_meta: FileMeta) -> Result<FileOpenFuture> {
+            let reader =
+                ObjectStoreReader::new(self.object_store.clone(), &file_meta.object_meta);
+            let schema_adapter = SchemaAdapter::new(self.projected_file_schema.clone());
+            let batch_size = self.batch_size;
+
+            Ok(Box::pin(async move {
+                let builder = ArrowReaderBuilder::try_new_async(reader)
+                    .await
+                    .map_err(|e| DataFusionError::External(Box::new(e)))?;
+                let file_schema = arrow_schema(builder.file_metadata());
+                let (schema_mapping, adapted_projections) =
+                    schema_adapter.map_schema(&file_schema)?;
+
+                // only read the projected columns of the file
+                let names = adapted_projections
+                    .iter()
+                    .map(|i| file_schema.field(*i).name().as_str())
+                    .collect::<Vec<_>>();
+                let mask = ProjectionMask::named_roots(
+                    builder.file_metadata().root_data_type(),
+                    &names,
+                );
+
+                let stream = builder
+                    .with_projection(mask)
+                    .with_batch_size(batch_size)
+                    .build_async();
+                let adapted = stream.map(move |maybe_batch| {
+                    maybe_batch
+                        .map_err(|e| ArrowError::ExternalError(Box::new(e)))
+                        .and_then(|b| schema_mapping.map_batch(b).map_err(Into::into))
+                });
+
+                Ok(adapted.boxed())
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "orc")]
+mod tests {
+    use super::*;
+    use crate::datasource::file_format::{orc::OrcFormat, FileFormat};
+    use crate::datasource::listing::PartitionedFile;
+    use crate::datasource::object_store::ObjectStoreUrl;
+    use crate::prelude::SessionContext;
+    use crate::test::object_store::local_unpartitioned_file;
+
+    use datafusion_common::assert_batches_eq;
+    use datafusion_common::stats::Precision;
+    use futures::StreamExt;
+    use object_store::local::LocalFileSystem;
+
+    #[tokio::test]
+    async fn orc_exec_with_projection() -> Result<()> {
+        let session_ctx = SessionContext::new();
+        let state = session_ctx.state();
+        let store = Arc::new(LocalFileSystem::new()) as Arc<dyn ObjectStore>;
+
+        let filename = format!("{}/tests/data/example.orc", env!("CARGO_MANIFEST_DIR"));
+        let meta = local_unpartitioned_file(filename);
+        let file_schema = OrcFormat {}
+            .infer_schema(&state, &store, &[meta.clone()])
+            .await?;
+        let statistics = OrcFormat {}
+            .infer_stats(&state, &store, file_schema.clone(), &meta)
+            .await?;
+        assert_eq!(statistics.num_rows, Precision::Exact(4));
+
+        let orc_exec = OrcExec::new(FileScanConfig {
+            object_store_url: ObjectStoreUrl::local_filesystem(),
+            file_groups: vec![vec![PartitionedFile::from(meta)]],
+            statistics: Statistics::new_unknown(&file_schema),
+            file_schema,
+            projection: Some(vec![1, 0]),
+            limit: None,
+            table_partition_cols: vec![],
+            output_ordering: vec![],
+        });
+        assert_eq!(
+            orc_exec
+                .properties()
+                .output_partitioning()
+                .partition_count(),
+            1
+        );
+
+        let mut results = orc_exec.execute(0, state.task_ctx())?;
+        let batch = results.next().await.expect("one batch")?;
+        let expected = [
+            "+------+----+",
+            "| name | id |",
+            "+------+----+",
+            "| a    | 1  |",
+            "|      | 2  |",
+            "| ccc  | 3  |",
+            "| dd   | 4  |",
+            "+------+----+",
+        ];
+        assert_batches_eq!(expected, &[batch]);
+        assert!(results.next().await.is_none());
+
+        Ok(())
+    }
+}
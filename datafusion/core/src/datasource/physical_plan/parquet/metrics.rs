@@ -45,6 +45,12 @@ pub struct ParquetFileMetrics {
     pub pushdown_eval_time: Time,
     /// Total rows filtered out by parquet page index
     pub page_index_rows_filtered: Count,
+    /// Number of pages, of the columns of the page index predicates, whose
+    /// page index was checked and matched
+    pub page_index_pages_matched: Count,
+    /// Number of pages, of the columns of the page index predicates, pruned
+    /// by their page index
+    pub page_index_pages_pruned: Count,
     /// Total time spent evaluating parquet page index filters
     pub page_index_eval_time: Time,
 }
@@ -91,6 +97,14 @@ impl ParquetFileMetrics {
             .with_new_label("filename", filename.to_string())
             .counter("page_index_rows_filtered", partition);
 
+        let page_index_pages_matched = MetricBuilder::new(metrics)
+            .with_new_label("filename", filename.to_string())
+            .counter("page_index_pages_matched", partition);
+
+        let page_index_pages_pruned = MetricBuilder::new(metrics)
+            .with_new_label("filename", filename.to_string())
+            .counter("page_index_pages_pruned", partition);
+
         let page_index_eval_time = MetricBuilder::new(metrics)
             .with_new_label("filename", filename.to_string())
             .subset_time("page_index_eval_time", partition);
@@ -105,6 +119,8 @@ impl ParquetFileMetrics {
             pushdown_rows_filtered,
             pushdown_eval_time,
             page_index_rows_filtered,
+            page_index_pages_matched,
+            page_index_pages_pruned,
             page_index_eval_time,
         }
     }
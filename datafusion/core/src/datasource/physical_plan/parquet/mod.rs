@@ -18,6 +18,7 @@
 //! Execution plan for reading Parquet files
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::ops::Range;
 use std::sync::Arc;
@@ -55,8 +56,10 @@ use log::debug;
 use object_store::buffered::BufWriter;
 use object_store::path::Path;
 use object_store::ObjectStore;
-use parquet::arrow::arrow_reader::ArrowReaderOptions;
-use parquet::arrow::async_reader::{AsyncFileReader, ParquetObjectReader};
+use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
+use parquet::arrow::async_reader::{
+    AsyncFileReader, MetadataLoader, ParquetObjectReader,
+};
 use parquet::arrow::{AsyncArrowWriter, ParquetRecordBatchStreamBuilder, ProjectionMask};
 use parquet::basic::{ConvertedType, LogicalType};
 use parquet::file::{metadata::ParquetMetaData, properties::WriterProperties};
@@ -68,8 +71,10 @@ mod page_filter;
 mod row_filter;
 mod row_groups;
 mod statistics;
+mod struct_fields;
 
 pub use metrics::ParquetFileMetrics;
+use struct_fields::{fill_pruned_struct_fields, struct_field_mask, StructFieldPaths};
 
 /// Execution plan for scanning one or more Parquet partitions
 #[derive(Debug, Clone)]
@@ -93,6 +98,9 @@ pub struct ParquetExec {
     cache: PlanProperties,
     /// Options for reading Parquet files
     table_parquet_options: TableParquetOptions,
+    /// Field paths read from struct columns, keyed by column index in the
+    /// file schema. Struct columns without an entry are read entirely
+    struct_field_projection: BTreeMap<usize, Vec<Vec<String>>>,
 }
 
 impl ParquetExec {
@@ -157,6 +165,7 @@ impl ParquetExec {
             parquet_file_reader_factory: None,
             cache,
             table_parquet_options,
+            struct_field_projection: BTreeMap::new(),
         }
     }
 
@@ -180,6 +189,26 @@ impl ParquetExec {
         self.pruning_predicate.as_ref()
     }
 
+    /// Only read the leaf columns of the given fields of struct columns,
+    /// keyed by column index in the file schema. For example, `{1: [["a"],
+    /// ["b", "c"]]}` reads the fields `a` and `b.c` of the second column.
+    ///
+    /// The output schema is unchanged: the other nullable fields are
+    /// returned as nulls, so the projection must cover every field that is
+    /// read from the output.
+    pub fn with_struct_field_projection(
+        mut self,
+        struct_field_projection: BTreeMap<usize, Vec<Vec<String>>>,
+    ) -> Self {
+        self.struct_field_projection = struct_field_projection;
+        self
+    }
+
+    /// Return the value described in [`Self::with_struct_field_projection`]
+    pub fn struct_field_projection(&self) -> &BTreeMap<usize, Vec<Vec<String>>> {
+        &self.struct_field_projection
+    }
+
     /// Optional user defined parquet file reader factory.
     ///
     /// `ParquetFileReaderFactory` complements `TableProvider`, It enables users to provide custom
@@ -195,6 +224,13 @@ impl ParquetExec {
         self
     }
 
+    /// Return the value described in [`Self::with_parquet_file_reader_factory`]
+    pub fn parquet_file_reader_factory(
+        &self,
+    ) -> Option<&Arc<dyn ParquetFileReaderFactory>> {
+        self.parquet_file_reader_factory.as_ref()
+    }
+
     /// If true, any filter [`Expr`]s on the scan will converted to a
     /// [`RowFilter`](parquet::arrow::arrow_reader::RowFilter) in the
     /// `ParquetRecordBatchStream`. These filters are applied by the
@@ -312,9 +348,30 @@ impl DisplayAs for ParquetExec {
                     })
                     .unwrap_or_default();
 
+                let struct_fields_string = if self.struct_field_projection.is_empty() {
+                    String::new()
+                } else {
+                    let file_schema = &self.base_config.file_schema;
+                    let fields = self
+                        .struct_field_projection
+                        .iter()
+                        .flat_map(|(index, paths)| {
+                            let name = file_schema.field(*index).name();
+                            paths.iter().map(move |path| {
+                                std::iter::once(name).chain(path).join(".")
+                            })
+                        })
+                        .join(", ");
+                    format!(", struct_fields=[{fields}]")
+                };
+
                 write!(f, "ParquetExec: ")?;
                 self.base_config.fmt_as(t, f)?;
-                write!(f, "{}{}", predicate_string, pruning_predicate_string,)
+                write!(
+                    f,
+                    "{}{}{}",
+                    struct_fields_string, predicate_string, pruning_predicate_string,
+                )
             }
         }
     }
@@ -408,6 +465,15 @@ impl ExecutionPlan for ParquetExec {
             reorder_filters: self.reorder_filters(),
             enable_page_index: self.enable_page_index(),
             enable_bloom_filter: self.enable_bloom_filter(),
+            struct_fields: Arc::new(
+                self.struct_field_projection
+                    .iter()
+                    .map(|(index, paths)| {
+                        let name = self.base_config.file_schema.field(*index).name();
+                        (name.clone(), paths.clone())
+                    })
+                    .collect(),
+            ),
         };
 
         let stream =
@@ -442,6 +508,7 @@ struct ParquetOpener {
     reorder_filters: bool,
     enable_page_index: bool,
     enable_bloom_filter: bool,
+    struct_fields: Arc<StructFieldPaths>,
 }
 
 impl FileOpener for ParquetOpener {
@@ -454,7 +521,7 @@ impl FileOpener for ParquetOpener {
             &self.metrics,
         );
 
-        let reader: Box<dyn AsyncFileReader> =
+        let mut reader: Box<dyn AsyncFileReader> =
             self.parquet_file_reader_factory.create_reader(
                 self.partition_index,
                 file_meta,
@@ -478,12 +545,32 @@ impl FileOpener for ParquetOpener {
         );
         let enable_bloom_filter = self.enable_bloom_filter;
         let limit = self.limit;
+        let struct_fields = self.struct_fields.clone();
 
         Ok(Box::pin(async move {
             let options = ArrowReaderOptions::new().with_page_index(enable_page_index);
+            let mut metadata =
+                ArrowReaderMetadata::load_async(&mut reader, options.clone()).await?;
+
+            // the offset index is used to split the row groups in the middle
+            // of the range of files scanned by several partitions
+            let needs_offset_index = file_range.as_ref().is_some_and(|range| {
+                row_groups::RowGroupsInRange::needs_offset_index(
+                    metadata.metadata().row_groups(),
+                    range,
+                )
+            });
+            if needs_offset_index && metadata.metadata().offset_index().is_none() {
+                let mut loader = MetadataLoader::new(
+                    &mut reader,
+                    metadata.metadata().as_ref().clone(),
+                );
+                loader.load_page_index(false, true).await?;
+                metadata =
+                    ArrowReaderMetadata::try_new(Arc::new(loader.finish()), options)?;
+            }
             let mut builder =
-                ParquetRecordBatchStreamBuilder::new_with_options(reader, options)
-                    .await?;
+                ParquetRecordBatchStreamBuilder::new_with_metadata(reader, metadata);
 
             let file_schema = builder.schema().clone();
 
@@ -491,10 +578,22 @@ impl FileOpener for ParquetOpener {
                 schema_adapter.map_schema(&file_schema)?;
             // let predicate = predicate.map(|p| reassign_predicate_columns(p, builder.schema(), true)).transpose()?;
 
-            let mask = ProjectionMask::roots(
-                builder.parquet_schema(),
-                adapted_projections.iter().cloned(),
-            );
+            // only read the referenced fields of struct columns, if any
+            let projected_file_schema = (!struct_fields.is_empty())
+                .then(|| file_schema.project(&adapted_projections).map(Arc::new))
+                .transpose()?;
+            let mask = match &projected_file_schema {
+                Some(_) => struct_field_mask(
+                    builder.parquet_schema(),
+                    &file_schema,
+                    &adapted_projections,
+                    &struct_fields,
+                ),
+                None => ProjectionMask::roots(
+                    builder.parquet_schema(),
+                    adapted_projections.iter().cloned(),
+                ),
+            };
 
             // Filter pushdown: evaluate predicates during scan
             if let Some(predicate) = pushdown_filters.then_some(predicate).flatten() {
@@ -524,12 +623,19 @@ impl FileOpener for ParquetOpener {
             // Row group pruning by statistics: attempt to skip entire row_groups
             // using metadata on the row groups
             let file_metadata = builder.metadata().clone();
+            let range = file_range.map(|range| {
+                row_groups::RowGroupsInRange::new(
+                    file_metadata.row_groups(),
+                    file_metadata.offset_index(),
+                    &range,
+                )
+            });
             let predicate = pruning_predicate.as_ref().map(|p| p.as_ref());
             let mut row_groups = row_groups::prune_row_groups_by_statistics(
                 &file_schema,
                 builder.parquet_schema(),
                 file_metadata.row_groups(),
-                file_range,
+                range.as_ref(),
                 predicate,
                 &file_metrics,
             );
@@ -553,21 +659,33 @@ impl FileOpener for ParquetOpener {
             // page index pruning: if all data on individual pages can
             // be ruled using page metadata, rows from other columns
             // with that range can be skipped as well
+            let mut row_selection = None;
             if enable_page_index && !row_groups.is_empty() {
                 if let Some(p) = page_pruning_predicate {
-                    let pruned = p.prune(
+                    row_selection = p.prune(
                         &file_schema,
                         builder.parquet_schema(),
                         &row_groups,
                         file_metadata.as_ref(),
                         &file_metrics,
                     )?;
-                    if let Some(row_selection) = pruned {
-                        builder = builder.with_row_selection(row_selection);
-                    }
                 }
             }
 
+            // only scan the pages of row groups split between partitions
+            // which are in the range of the file
+            if let Some(range_selection) = range
+                .and_then(|r| r.row_selection(file_metadata.row_groups(), &row_groups))
+            {
+                row_selection = Some(match row_selection {
+                    Some(selection) => selection.intersection(&range_selection),
+                    None => range_selection,
+                });
+            }
+            if let Some(row_selection) = row_selection {
+                builder = builder.with_row_selection(row_selection);
+            }
+
             if let Some(limit) = limit {
                 builder = builder.with_limit(limit)
             }
@@ -581,8 +699,13 @@ impl FileOpener for ParquetOpener {
             let adapted = stream
                 .map_err(|e| ArrowError::ExternalError(Box::new(e)))
                 .map(move |maybe_batch| {
-                    maybe_batch
-                        .and_then(|b| schema_mapping.map_batch(b).map_err(Into::into))
+                    maybe_batch.and_then(|b| {
+                        let b = match &projected_file_schema {
+                            Some(schema) => fill_pruned_struct_fields(b, schema)?,
+                            None => b,
+                        };
+                        schema_mapping.map_batch(b).map_err(Into::into)
+                    })
                 });
 
             Ok(adapted.boxed())
@@ -1611,6 +1734,78 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn parquet_exec_with_range_in_row_group() -> Result<()> {
+        use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
+
+        // a single row group, with a page every 2 rows
+        let batch = RecordBatch::try_from_iter(vec![(
+            "a",
+            Arc::new(Int64Array::from_iter_values(0..100)) as _,
+        )])?;
+        let file_schema = batch.schema();
+        let (meta, files) = store_parquet(vec![batch], true).await?;
+
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let metadata = ArrowReaderMetadata::load(&File::open(files[0].path())?, options)?;
+        let metadata = metadata.metadata();
+        assert_eq!(metadata.num_row_groups(), 1);
+        let pages = &metadata.offset_index().unwrap()[0][0];
+        assert!(pages.len() > 2);
+
+        // split the file at a page in the middle of the row group
+        let split = &pages[pages.len() / 2];
+        let file_range = |start, end| PartitionedFile {
+            object_meta: meta[0].clone(),
+            partition_values: vec![],
+            range: Some(FileRange { start, end }),
+            extensions: None,
+        };
+        let parquet_exec = ParquetExec::new(
+            FileScanConfig {
+                object_store_url: ObjectStoreUrl::local_filesystem(),
+                file_groups: vec![
+                    vec![file_range(0, split.offset)],
+                    vec![file_range(split.offset, i64::MAX)],
+                ],
+                statistics: Statistics::new_unknown(&file_schema),
+                file_schema,
+                projection: None,
+                limit: None,
+                table_partition_cols: vec![],
+                output_ordering: vec![],
+            },
+            None,
+            None,
+            Default::default(),
+        );
+
+        let task_ctx = SessionContext::new().task_ctx();
+        let mut partitions = vec![];
+        for partition in 0..2 {
+            let stream = parquet_exec.execute(partition, task_ctx.clone())?;
+            let values = crate::physical_plan::common::collect(stream)
+                .await?
+                .iter()
+                .flat_map(|batch| {
+                    let array = batch.column(0).as_any().downcast_ref::<Int64Array>();
+                    array.unwrap().values().to_vec()
+                })
+                .collect::<Vec<_>>();
+            partitions.push(values);
+        }
+        assert_eq!(
+            partitions[0],
+            (0..split.first_row_index).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            partitions[1],
+            (split.first_row_index..100).collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn parquet_exec_with_partition() -> Result<()> {
         let session_ctx = SessionContext::new();
@@ -1797,6 +1992,9 @@ mod tests {
         ];
         assert_batches_sorted_eq!(expected, &rt.batches.unwrap());
         assert_eq!(get_value(&metrics, "page_index_rows_filtered"), 4);
+        // of the pages [1, NULL], [2, 3] and [4, 5]
+        assert_eq!(get_value(&metrics, "page_index_pages_matched"), 1);
+        assert_eq!(get_value(&metrics, "page_index_pages_pruned"), 2);
         assert!(
             get_value(&metrics, "page_index_eval_time") > 0,
             "no eval time in metrics: {metrics:#?}"
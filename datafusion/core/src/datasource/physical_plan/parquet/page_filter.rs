@@ -324,6 +324,9 @@ fn prune_pages_in_one_row_group(
                 let mut sum_row = *row_vec.first().unwrap();
                 let mut selected = *values.first().unwrap();
                 trace!("Pruned to {:?} using {:?}", values, pruning_stats);
+                let matched = values.iter().filter(|&&v| v).count();
+                metrics.page_index_pages_matched.add(matched);
+                metrics.page_index_pages_pruned.add(values.len() - matched);
                 for (i, &f) in values.iter().enumerate().skip(1) {
                     if f == selected {
                         sum_row += *row_vec.get(i).unwrap();
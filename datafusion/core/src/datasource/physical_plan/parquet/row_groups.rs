@@ -19,9 +19,10 @@ use arrow::{array::ArrayRef, datatypes::Schema};
 use arrow_array::BooleanArray;
 use arrow_schema::FieldRef;
 use datafusion_common::{Column, ScalarValue};
+use parquet::arrow::arrow_reader::{RowSelection, RowSelector};
 use parquet::basic::Type;
 use parquet::data_type::Decimal;
-use parquet::file::metadata::ColumnChunkMetaData;
+use parquet::file::metadata::{ColumnChunkMetaData, ParquetOffsetIndex};
 use parquet::schema::types::SchemaDescriptor;
 use parquet::{
     arrow::{async_reader::AsyncFileReader, ParquetRecordBatchStreamBuilder},
@@ -38,6 +39,121 @@ use crate::physical_optimizer::pruning::{PruningPredicate, PruningStatistics};
 
 use super::ParquetFileMetrics;
 
+/// The rows of the row groups of a file which are scanned for a byte range
+/// of the file, when the file is split between several partitions.
+///
+/// A row group is scanned by the partition whose range contains the start of
+/// its first column. If the file has an offset index, the row groups are
+/// further split by the data pages of their first column, each page being
+/// scanned by the partition whose range contains it, so that a file with
+/// fewer row groups than partitions, down to a single row group, is still
+/// scanned in parallel.
+#[derive(Debug)]
+pub(crate) struct RowGroupsInRange {
+    /// The selected rows of the row groups overlapping with the range, or
+    /// `None` if all their rows are in the range
+    rows: HashMap<usize, Option<Vec<RowSelector>>>,
+}
+
+impl RowGroupsInRange {
+    pub(crate) fn new(
+        groups: &[RowGroupMetaData],
+        offset_index: Option<&ParquetOffsetIndex>,
+        range: &FileRange,
+    ) -> Self {
+        let contains = |offset: i64| range.start <= offset && offset < range.end;
+        let mut rows = HashMap::new();
+        for (idx, metadata) in groups.iter().enumerate() {
+            let start = row_group_start(metadata);
+            let pages = offset_index
+                .and_then(|index| index.get(idx))
+                .and_then(|columns| columns.first())
+                .filter(|pages| pages.len() > 1);
+            let Some(pages) = pages else {
+                if contains(start) {
+                    rows.insert(idx, None);
+                }
+                continue;
+            };
+
+            let num_rows = metadata.num_rows() as usize;
+            let mut selectors: Vec<RowSelector> = vec![];
+            for (page_idx, page) in pages.iter().enumerate() {
+                // the first page starts with the dictionary page, if any
+                let offset = if page_idx == 0 { start } else { page.offset };
+                let first_row = page.first_row_index as usize;
+                let end_row = pages
+                    .get(page_idx + 1)
+                    .map(|next| next.first_row_index as usize)
+                    .unwrap_or(num_rows);
+                let skip = !contains(offset);
+                match selectors.last_mut() {
+                    Some(last) if last.skip == skip => {
+                        last.row_count += end_row - first_row
+                    }
+                    _ => selectors.push(RowSelector {
+                        row_count: end_row - first_row,
+                        skip,
+                    }),
+                }
+            }
+            match selectors.as_slice() {
+                [selector] if selector.skip => {}
+                [_] => {
+                    rows.insert(idx, None);
+                }
+                _ => {
+                    rows.insert(idx, Some(selectors));
+                }
+            }
+        }
+        Self { rows }
+    }
+
+    /// Returns true if the start or the end of `range` is in the middle of
+    /// one of the row groups, whose pages can then only be assigned to the
+    /// range with the offset index
+    pub(crate) fn needs_offset_index(
+        groups: &[RowGroupMetaData],
+        range: &FileRange,
+    ) -> bool {
+        groups.iter().any(|metadata| {
+            let start = row_group_start(metadata);
+            let end = start + metadata.compressed_size();
+            let inside = |offset: i64| start < offset && offset < end;
+            inside(range.start) || inside(range.end)
+        })
+    }
+
+    /// Returns true if some rows of the row group `idx` are in the range
+    pub(crate) fn contains(&self, idx: usize) -> bool {
+        self.rows.contains_key(&idx)
+    }
+
+    /// Returns the selection of the rows in the range of the scanned
+    /// `row_groups`, or `None` if all their rows are in the range
+    pub(crate) fn row_selection(
+        &self,
+        groups: &[RowGroupMetaData],
+        row_groups: &[usize],
+    ) -> Option<RowSelection> {
+        if !row_groups
+            .iter()
+            .any(|idx| matches!(self.rows.get(idx), Some(Some(_))))
+        {
+            return None;
+        }
+        let selectors = row_groups
+            .iter()
+            .flat_map(|idx| match self.rows.get(idx) {
+                Some(Some(selectors)) => selectors.clone(),
+                _ => vec![RowSelector::select(groups[*idx].num_rows() as usize)],
+            })
+            .collect::<Vec<_>>();
+        Some(RowSelection::from(selectors))
+    }
+}
+
 /// Prune row groups based on statistics
 ///
 /// Returns a vector of indexes into `groups` which should be scanned.
@@ -54,21 +170,14 @@ pub(crate) fn prune_row_groups_by_statistics(
     arrow_schema: &Schema,
     parquet_schema: &SchemaDescriptor,
     groups: &[RowGroupMetaData],
-    range: Option<FileRange>,
+    range: Option<&RowGroupsInRange>,
     predicate: Option<&PruningPredicate>,
     metrics: &ParquetFileMetrics,
 ) -> Vec<usize> {
     let mut filtered = Vec::with_capacity(groups.len());
     for (idx, metadata) in groups.iter().enumerate() {
-        if let Some(range) = &range {
-            // figure out where the first dictionary page (or first data page are)
-            // note don't use the location of metadata
-            // <https://github.com/apache/arrow-datafusion/issues/5995>
-            let col = metadata.column(0);
-            let offset = col
-                .dictionary_page_offset()
-                .unwrap_or_else(|| col.data_page_offset());
-            if offset < range.start || offset >= range.end {
+        if let Some(range) = range {
+            if !range.contains(idx) {
                 continue;
             }
         }
@@ -102,6 +211,16 @@ pub(crate) fn prune_row_groups_by_statistics(
     filtered
 }
 
+/// Returns the offset of the first page of a row group
+fn row_group_start(metadata: &RowGroupMetaData) -> i64 {
+    // figure out where the first dictionary page (or first data page are)
+    // note don't use the location of metadata
+    // <https://github.com/apache/arrow-datafusion/issues/5995>
+    let col = metadata.column(0);
+    col.dictionary_page_offset()
+        .unwrap_or_else(|| col.data_page_offset())
+}
+
 /// Prune row groups by bloom filters
 ///
 /// Returns a vector of indexes into `groups` which should be scanned.
@@ -1017,6 +1136,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn row_groups_in_range_need_offset_index() {
+        let schema_descr = get_test_schema_descr(vec![PrimitiveTypeField::new(
+            "c1",
+            PhysicalType::INT32,
+        )]);
+        // two row groups of 100 bytes, starting at offsets 4 and 104
+        let groups = [4, 104]
+            .into_iter()
+            .map(|offset| {
+                let column = ColumnChunkMetaData::builder(schema_descr.column(0))
+                    .set_data_page_offset(offset)
+                    .set_total_compressed_size(100)
+                    .build()
+                    .unwrap();
+                RowGroupMetaData::builder(schema_descr.clone())
+                    .set_num_rows(1000)
+                    .set_column_metadata(vec![column])
+                    .build()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let needs_offset_index = |start, end| {
+            RowGroupsInRange::needs_offset_index(&groups, &FileRange { start, end })
+        };
+
+        assert!(!needs_offset_index(0, 104));
+        assert!(!needs_offset_index(104, 1000));
+        assert!(!needs_offset_index(0, 1000));
+        assert!(needs_offset_index(0, 50));
+        assert!(needs_offset_index(150, 1000));
+    }
+
     fn get_row_group_meta_data(
         schema_descr: &SchemaDescPtr,
         column_statistics: Vec<ParquetStatistics>,
@@ -0,0 +1,234 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Reading only the leaf columns of struct columns whose fields are
+//! referenced by a query, see [`ParquetExec::with_struct_field_projection`]
+//!
+//! [`ParquetExec::with_struct_field_projection`]: super::ParquetExec::with_struct_field_projection
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow::array::{new_null_array, Array, ArrayRef, StructArray};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::record_batch::{RecordBatch, RecordBatchOptions};
+use datafusion_common::Result;
+use parquet::arrow::ProjectionMask;
+use parquet::schema::types::SchemaDescriptor;
+
+/// Field paths read from struct columns, keyed by column name. An empty
+/// path reads the entire column.
+pub(crate) type StructFieldPaths = HashMap<String, Vec<Vec<String>>>;
+
+/// Returns the [`ProjectionMask`] that reads the `roots` columns of the file,
+/// skipping the leaf columns of struct fields that are not in `struct_fields`.
+///
+/// Only nullable fields are skipped, as they are restored as nulls by
+/// [`fill_pruned_struct_fields`]. If no leaf of a column would be read, the
+/// entire column is read.
+pub(crate) fn struct_field_mask(
+    parquet_schema: &SchemaDescriptor,
+    file_schema: &Schema,
+    roots: &[usize],
+    struct_fields: &StructFieldPaths,
+) -> ProjectionMask {
+    let mut leaves = vec![];
+    for &root in roots {
+        let field = file_schema.field(root);
+        let root_leaves = (0..parquet_schema.num_columns())
+            .filter(|leaf| parquet_schema.get_column_root_idx(*leaf) == root);
+        let Some(paths) = struct_fields.get(field.name()) else {
+            leaves.extend(root_leaves);
+            continue;
+        };
+
+        let (read, skipped): (Vec<_>, Vec<_>) = root_leaves.partition(|leaf| {
+            let path = parquet_schema.column(*leaf).path().parts().to_vec();
+            reads_leaf(field, paths, &path[1..])
+        });
+        if read.is_empty() {
+            leaves.extend(skipped);
+        } else {
+            leaves.extend(read);
+        }
+    }
+    leaves.sort_unstable();
+    ProjectionMask::leaves(parquet_schema, leaves)
+}
+
+/// Returns true if the leaf at `leaf_path` below `field` must be read to
+/// provide the field `paths` relative to `field`
+fn reads_leaf(field: &Field, paths: &[Vec<String>], leaf_path: &[String]) -> bool {
+    if paths.iter().any(|path| path.is_empty()) {
+        return true;
+    }
+    let (DataType::Struct(children), Some(name)) = (field.data_type(), leaf_path.first())
+    else {
+        return true;
+    };
+    let Some((_, child)) = children.find(name) else {
+        return true;
+    };
+
+    let child_paths = paths
+        .iter()
+        .filter(|path| &path[0] == name)
+        .map(|path| path[1..].to_vec())
+        .collect::<Vec<_>>();
+    if child_paths.is_empty() {
+        !child.is_nullable()
+    } else {
+        reads_leaf(child, &child_paths, &leaf_path[1..])
+    }
+}
+
+/// Restores the struct fields that were skipped by [`struct_field_mask`] as
+/// null arrays, so that `batch` has the `projected_file_schema` it was read
+/// with.
+pub(crate) fn fill_pruned_struct_fields(
+    batch: RecordBatch,
+    projected_file_schema: &SchemaRef,
+) -> Result<RecordBatch> {
+    let columns = batch
+        .columns()
+        .iter()
+        .zip(projected_file_schema.fields())
+        .map(|(array, field)| fill_pruned_fields(array, field.data_type()))
+        .collect::<Result<Vec<_>>>()?;
+    let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+    Ok(RecordBatch::try_new_with_options(
+        projected_file_schema.clone(),
+        columns,
+        &options,
+    )?)
+}
+
+fn fill_pruned_fields(array: &ArrayRef, data_type: &DataType) -> Result<ArrayRef> {
+    let (Some(struct_array), DataType::Struct(fields)) =
+        (array.as_any().downcast_ref::<StructArray>(), data_type)
+    else {
+        return Ok(array.clone());
+    };
+    if array.data_type() == data_type {
+        return Ok(array.clone());
+    }
+
+    let children = fields
+        .iter()
+        .map(|field| match struct_array.column_by_name(field.name()) {
+            Some(child) => fill_pruned_fields(child, field.data_type()),
+            None => Ok(new_null_array(field.data_type(), array.len())),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Arc::new(StructArray::try_new(
+        fields.clone(),
+        children,
+        struct_array.nulls().cloned(),
+    )?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int32Array, StringArray};
+    use arrow::datatypes::Fields;
+    use parquet::arrow::arrow_to_parquet_schema;
+
+    fn file_schema() -> Schema {
+        let inner = Fields::from(vec![
+            Field::new("x", DataType::Int32, true),
+            Field::new("y", DataType::Int32, true),
+        ]);
+        let outer = Fields::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Struct(inner), true),
+            Field::new("c", DataType::Utf8, false),
+        ]);
+        Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("s", DataType::Struct(outer), true),
+        ])
+    }
+
+    fn paths(paths: &[&[&str]]) -> StructFieldPaths {
+        let paths = paths
+            .iter()
+            .map(|path| path.iter().map(|p| p.to_string()).collect())
+            .collect();
+        HashMap::from([("s".to_string(), paths)])
+    }
+
+    fn mask(struct_fields: &StructFieldPaths) -> Result<Vec<bool>> {
+        let file_schema = file_schema();
+        let parquet_schema = arrow_to_parquet_schema(&file_schema)?;
+        let mask =
+            struct_field_mask(&parquet_schema, &file_schema, &[0, 1], struct_fields);
+        // leaves: id, s.a, s.b.x, s.b.y, s.c
+        Ok((0..5).map(|leaf| mask.leaf_included(leaf)).collect())
+    }
+
+    #[test]
+    fn struct_field_mask_leaves() -> Result<()> {
+        assert_eq!(mask(&HashMap::new())?, [true; 5]);
+        // non-nullable `c` is always read
+        assert_eq!(mask(&paths(&[&["a"]]))?, [true, true, false, false, true]);
+        assert_eq!(
+            mask(&paths(&[&["b", "y"]]))?,
+            [true, false, false, true, true]
+        );
+        assert_eq!(
+            mask(&paths(&[&["b"], &["a"]]))?,
+            [true, true, true, true, true]
+        );
+        assert_eq!(mask(&paths(&[&[]]))?, [true; 5]);
+        Ok(())
+    }
+
+    #[test]
+    fn fill_struct_fields() -> Result<()> {
+        let file_schema = Arc::new(file_schema());
+        let DataType::Struct(fields) = file_schema.field(1).data_type() else {
+            unreachable!()
+        };
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None]));
+        let c: ArrayRef = Arc::new(StringArray::from(vec!["x", "y"]));
+        let pruned = StructArray::from(vec![
+            (Arc::new(fields[0].as_ref().clone()), a.clone()),
+            (Arc::new(fields[2].as_ref().clone()), c.clone()),
+        ]);
+        let pruned_schema = Schema::new(vec![
+            file_schema.field(0).clone(),
+            Field::new("s", pruned.data_type().clone(), true),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(pruned_schema),
+            vec![Arc::new(Int32Array::from(vec![1, 2])), Arc::new(pruned)],
+        )?;
+
+        let filled = fill_pruned_struct_fields(batch, &file_schema)?;
+        assert_eq!(filled.schema(), file_schema);
+        let s = filled
+            .column(1)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert_eq!(s.column(0), &a);
+        assert_eq!(s.column(1).null_count(), 2);
+        assert_eq!(s.column(2), &c);
+        Ok(())
+    }
+}
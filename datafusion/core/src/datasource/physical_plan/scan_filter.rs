@@ -0,0 +1,249 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScanFilter`] evaluates the filters pushed down to a [`TableProvider`]
+//!
+//! [`TableProvider`]: crate::datasource::TableProvider
+
+use std::sync::Arc;
+
+use super::SchemaAdapter;
+use crate::error::Result;
+use crate::execution::context::SessionState;
+use crate::physical_plan::stream::RecordBatchStreamAdapter;
+use crate::physical_plan::{PhysicalExpr, SendableRecordBatchStream};
+
+use arrow::array::BooleanArray;
+use arrow::compute::filter_record_batch;
+use arrow::datatypes::{Schema, SchemaRef};
+use arrow::record_batch::RecordBatch;
+use datafusion_common::cast::as_boolean_array;
+use datafusion_common::ToDFSchema;
+use datafusion_expr::utils::conjunction;
+use datafusion_expr::Expr;
+use datafusion_physical_expr::create_physical_expr;
+use futures::StreamExt;
+
+/// The filters pushed down to a [`TableProvider::scan`], compiled into a
+/// [`PhysicalExpr`] that can be evaluated against the batches read by the
+/// provider.
+///
+/// This allows custom providers to return
+/// [`TableProviderFilterPushDown::Exact`] without planning the expressions
+/// themselves. The batches need not have the schema of the table: columns
+/// are matched by name and cast to the type of the table column where
+/// needed, and columns of the table missing from a batch are read as nulls.
+///
+/// The batches must contain the columns the filters reference, which are
+/// returned by [`Self::schema`], even if they are not part of the projection
+/// of the scan.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::Int32Array;
+/// # use arrow::datatypes::{DataType, Field, Schema};
+/// # use arrow::record_batch::RecordBatch;
+/// # use datafusion::datasource::physical_plan::ScanFilter;
+/// # use datafusion::error::Result;
+/// # use datafusion::prelude::*;
+/// # fn main() -> Result<()> {
+/// let state = SessionContext::new().state();
+/// let table_schema = Schema::new(vec![
+///     Field::new("a", DataType::Int32, false),
+///     Field::new("b", DataType::Int32, false),
+/// ]);
+/// let filter = ScanFilter::try_new(&state, &table_schema, &[col("b").gt(lit(1))])?
+///     .expect("one filter");
+///
+/// // the provider only read column `b`
+/// let batch = RecordBatch::try_from_iter(vec![(
+///     "b",
+///     Arc::new(Int32Array::from(vec![1, 2, 3])) as _,
+/// )])?;
+/// assert_eq!(filter.filter_batch(&batch)?.num_rows(), 2);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`TableProvider::scan`]: crate::datasource::TableProvider::scan
+/// [`TableProviderFilterPushDown::Exact`]: crate::logical_expr::TableProviderFilterPushDown::Exact
+#[derive(Debug, Clone)]
+pub struct ScanFilter {
+    /// The conjunction of the filters
+    predicate: Arc<dyn PhysicalExpr>,
+    /// The columns of the table referenced by the filters, in table order
+    schema: SchemaRef,
+}
+
+impl ScanFilter {
+    /// Compiles the conjunction of `filters` on a table of `table_schema`.
+    ///
+    /// Returns `None` if there are no filters.
+    pub fn try_new(
+        state: &SessionState,
+        table_schema: &Schema,
+        filters: &[Expr],
+    ) -> Result<Option<Self>> {
+        let Some(expr) = conjunction(filters.to_vec()) else {
+            return Ok(None);
+        };
+        let columns = expr.to_columns()?;
+        let fields = table_schema
+            .fields()
+            .iter()
+            .filter(|f| columns.iter().any(|c| &c.name == f.name()))
+            .cloned()
+            .collect::<Vec<_>>();
+        let schema = Arc::new(Schema::new(fields));
+
+        let df_schema = schema.clone().to_dfschema()?;
+        let predicate = create_physical_expr(&expr, &df_schema, state.execution_props())?;
+        Ok(Some(Self { predicate, schema }))
+    }
+
+    /// The compiled predicate, which evaluates against batches of
+    /// [`Self::schema`]
+    pub fn predicate(&self) -> &Arc<dyn PhysicalExpr> {
+        &self.predicate
+    }
+
+    /// The columns of the table referenced by the filters
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Returns which rows of `batch` match the filters. Rows for which the
+    /// filters evaluate to null are null.
+    pub fn evaluate(&self, batch: &RecordBatch) -> Result<BooleanArray> {
+        let (mapping, projection) =
+            SchemaAdapter::new(self.schema.clone()).map_schema(&batch.schema())?;
+        let mapped = mapping.map_batch(batch.project(&projection)?)?;
+        let result = self
+            .predicate
+            .evaluate(&mapped)?
+            .into_array(batch.num_rows())?;
+        Ok(as_boolean_array(&result)?.clone())
+    }
+
+    /// Returns the rows of `batch` that match the filters
+    pub fn filter_batch(&self, batch: &RecordBatch) -> Result<RecordBatch> {
+        let mask = self.evaluate(batch)?;
+        Ok(filter_record_batch(batch, &mask)?)
+    }
+
+    /// Returns the rows of the batches of `stream` that match the filters
+    pub fn filter_stream(
+        &self,
+        stream: SendableRecordBatchStream,
+    ) -> SendableRecordBatchStream {
+        let schema = stream.schema();
+        let filter = self.clone();
+        let filtered = stream.map(move |batch| filter.filter_batch(&batch?));
+        Box::pin(RecordBatchStreamAdapter::new(schema, filtered))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physical_plan::common::collect;
+    use crate::physical_plan::memory::MemoryStream;
+    use crate::prelude::{col, lit, SessionContext};
+
+    use arrow::array::{Int32Array, Int64Array, StringArray};
+    use arrow::datatypes::{DataType, Field};
+    use datafusion_common::assert_batches_eq;
+
+    fn table_schema() -> Schema {
+        Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Int64, true),
+        ])
+    }
+
+    #[test]
+    fn filter_batch_with_other_schema() -> Result<()> {
+        let state = SessionContext::new().state();
+        let filters = [col("a").gt(lit(1_i64)), col("b").not_eq(lit("x"))];
+        let filter = ScanFilter::try_new(&state, &table_schema(), &filters)?.unwrap();
+        let names = filter
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["a", "b"]);
+
+        // the columns are reordered, `a` has a narrower type and `d` is not
+        // part of the table
+        let batch = RecordBatch::try_from_iter(vec![
+            (
+                "b",
+                Arc::new(StringArray::from(vec![
+                    Some("x"),
+                    Some("y"),
+                    None,
+                    Some("z"),
+                ])) as _,
+            ),
+            ("d", Arc::new(Int32Array::from(vec![10, 20, 30, 40])) as _),
+            ("a", Arc::new(Int32Array::from(vec![2, 3, 4, 1])) as _),
+        ])?;
+        let expected =
+            BooleanArray::from(vec![Some(false), Some(true), None, Some(false)]);
+        assert_eq!(filter.evaluate(&batch)?, expected);
+
+        let expected = [
+            "+---+----+---+",
+            "| b | d  | a |",
+            "+---+----+---+",
+            "| y | 20 | 3 |",
+            "+---+----+---+",
+        ];
+        assert_batches_eq!(expected, &[filter.filter_batch(&batch)?]);
+        Ok(())
+    }
+
+    #[test]
+    fn no_filters() -> Result<()> {
+        let state = SessionContext::new().state();
+        assert!(ScanFilter::try_new(&state, &table_schema(), &[])?.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn filter_stream() -> Result<()> {
+        let state = SessionContext::new().state();
+        let filter =
+            ScanFilter::try_new(&state, &table_schema(), &[col("c").lt(lit(3_i64))])?
+                .unwrap();
+
+        let batch = RecordBatch::try_from_iter(vec![(
+            "c",
+            Arc::new(Int64Array::from(vec![1, 2, 3, 4])) as _,
+        )])?;
+        let schema = batch.schema();
+        let stream = MemoryStream::try_new(vec![batch.clone(), batch], schema, None)?;
+        let batches = collect(filter.filter_stream(Box::pin(stream))).await?;
+        let expected = [
+            "+---+", "| c |", "+---+", "| 1 |", "| 2 |", "| 1 |", "| 2 |", "+---+",
+        ];
+        assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+}
@@ -0,0 +1,280 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Summaries of the files and partitions selected by the file scans of a plan
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+#[cfg(feature = "parquet")]
+use super::ParquetExec;
+use super::{ArrowExec, AvroExec, CsvExec, FileScanConfig, NdJsonExec};
+use crate::datasource::listing::PartitionedFile;
+use crate::datasource::object_store::ObjectStoreUrl;
+use crate::error::Result;
+use crate::physical_plan::ExecutionPlan;
+
+use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
+use datafusion_common::ScalarValue;
+use object_store::path::Path;
+
+/// A file selected by a file scan
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScannedFile {
+    /// Location of the file in the object store
+    pub path: Path,
+    /// Size of the file in bytes
+    pub size: u64,
+    /// Values of the table partition columns of the file, in the order of
+    /// [`FileScanSummary::partition_cols`]
+    pub partition_values: Vec<ScalarValue>,
+}
+
+/// The files and Hive partitions a file scan reads, as selected at planning
+/// time after partition pruning.
+///
+/// This allows estimating the cost of a query, or validating that its
+/// partition filters select the expected partitions, before running it:
+///
+/// ```no_run
+/// # use datafusion::prelude::*;
+/// # use datafusion::error::Result;
+/// # use datafusion::datasource::physical_plan::file_scan_summaries;
+/// # async fn f(ctx: SessionContext) -> Result<()> {
+/// let df = ctx.sql("SELECT * FROM events WHERE year = 2024").await?;
+/// let plan = df.create_physical_plan().await?;
+/// for scan in file_scan_summaries(&plan)? {
+///     println!(
+///         "{}: {} files, {} bytes, partitions {:?}",
+///         scan.scan_name,
+///         scan.files.len(),
+///         scan.total_bytes(),
+///         scan.partition_paths()
+///     );
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileScanSummary {
+    /// Name of the scan, e.g. `ParquetExec`
+    pub scan_name: String,
+    /// Object store URL of the files
+    pub object_store_url: ObjectStoreUrl,
+    /// Names of the table partition columns
+    pub partition_cols: Vec<String>,
+    /// The selected files. A file read in several ranges is listed once.
+    pub files: Vec<ScannedFile>,
+}
+
+impl FileScanSummary {
+    /// Create a summary of the files of a file scan named `scan_name`
+    pub fn new(scan_name: impl Into<String>, config: &FileScanConfig) -> Self {
+        let mut seen = HashSet::new();
+        let files = config
+            .file_groups
+            .iter()
+            .flatten()
+            .filter(|file| seen.insert(&file.object_meta.location))
+            .map(|file| ScannedFile {
+                path: file.object_meta.location.clone(),
+                size: file.object_meta.size as u64,
+                partition_values: file.partition_values.clone(),
+            })
+            .collect();
+
+        Self {
+            scan_name: scan_name.into(),
+            object_store_url: config.object_store_url.clone(),
+            partition_cols: partition_col_names(config),
+            files,
+        }
+    }
+
+    /// Total size of the selected files in bytes
+    pub fn total_bytes(&self) -> u64 {
+        self.files.iter().map(|file| file.size).sum()
+    }
+
+    /// The distinct partition values of the selected files, in order of
+    /// appearance
+    pub fn partitions(&self) -> Vec<&[ScalarValue]> {
+        distinct_partitions(self.files.iter().map(|file| &file.partition_values))
+    }
+
+    /// The selected partitions as Hive style paths, e.g. `year=2024/month=1`
+    pub fn partition_paths(&self) -> Vec<String> {
+        self.partitions()
+            .into_iter()
+            .map(|values| partition_path(&self.partition_cols, values))
+            .collect()
+    }
+}
+
+/// Returns the summaries of all file scans of `plan`, in pre-order
+pub fn file_scan_summaries(
+    plan: &Arc<dyn ExecutionPlan>,
+) -> Result<Vec<FileScanSummary>> {
+    let mut summaries = vec![];
+    plan.apply(&mut |node| {
+        if let Some(config) = file_scan_config(node.as_ref()) {
+            summaries.push(FileScanSummary::new(node.name(), config));
+        }
+        Ok(TreeNodeRecursion::Continue)
+    })?;
+    Ok(summaries)
+}
+
+/// Returns the [`FileScanConfig`] of `plan` if it is a built-in file scan
+fn file_scan_config(plan: &dyn ExecutionPlan) -> Option<&FileScanConfig> {
+    let plan_any = plan.as_any();
+    #[cfg(feature = "parquet")]
+    if let Some(exec) = plan_any.downcast_ref::<ParquetExec>() {
+        return Some(exec.base_config());
+    }
+    if let Some(exec) = plan_any.downcast_ref::<CsvExec>() {
+        Some(exec.base_config())
+    } else if let Some(exec) = plan_any.downcast_ref::<NdJsonExec>() {
+        Some(exec.base_config())
+    } else if let Some(exec) = plan_any.downcast_ref::<ArrowExec>() {
+        Some(exec.base_config())
+    } else if let Some(exec) = plan_any.downcast_ref::<AvroExec>() {
+        Some(exec.base_config())
+    } else {
+        None
+    }
+}
+
+pub(crate) fn partition_col_names(config: &FileScanConfig) -> Vec<String> {
+    config
+        .table_partition_cols
+        .iter()
+        .map(|field| field.name().clone())
+        .collect()
+}
+
+/// The distinct partition values of `files`, in order of appearance
+pub(crate) fn file_partitions(files: &[Vec<PartitionedFile>]) -> Vec<&[ScalarValue]> {
+    distinct_partitions(files.iter().flatten().map(|file| &file.partition_values))
+}
+
+fn distinct_partitions<'a>(
+    partition_values: impl Iterator<Item = &'a Vec<ScalarValue>>,
+) -> Vec<&'a [ScalarValue]> {
+    let mut seen = HashSet::new();
+    partition_values
+        .filter(|values| seen.insert(*values))
+        .map(|values| values.as_slice())
+        .collect()
+}
+
+/// Formats partition values as a Hive style path, e.g. `year=2024/month=1`
+pub(crate) fn partition_path(cols: &[String], values: &[ScalarValue]) -> String {
+    cols.iter()
+        .zip(values)
+        .map(|(col, value)| format!("{col}={value}"))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datasource::listing::FileRange;
+    use crate::physical_plan::union::UnionExec;
+
+    use arrow::datatypes::{DataType, Field, Schema};
+    use datafusion_common::Statistics;
+
+    fn file(path: &str, size: u64, year: i32, month: i32) -> PartitionedFile {
+        let mut file = PartitionedFile::new(path, size);
+        file.partition_values = vec![
+            ScalarValue::Int32(Some(year)),
+            ScalarValue::Int32(Some(month)),
+        ];
+        file
+    }
+
+    fn scan(file_groups: Vec<Vec<PartitionedFile>>) -> Arc<dyn ExecutionPlan> {
+        let file_schema =
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        Arc::new(ArrowExec::new(FileScanConfig {
+            object_store_url: ObjectStoreUrl::local_filesystem(),
+            statistics: Statistics::new_unknown(&file_schema),
+            file_schema,
+            file_groups,
+            projection: None,
+            limit: None,
+            table_partition_cols: vec![
+                Field::new("year", DataType::Int32, false),
+                Field::new("month", DataType::Int32, false),
+            ],
+            output_ordering: vec![],
+        }))
+    }
+
+    #[test]
+    fn summarize_scans() -> Result<()> {
+        let plan: Arc<dyn ExecutionPlan> = Arc::new(UnionExec::new(vec![
+            scan(vec![
+                vec![file("t/year=2024/month=1/a.arrow", 10, 2024, 1)],
+                vec![
+                    file("t/year=2024/month=2/b.arrow", 20, 2024, 2),
+                    file("t/year=2024/month=1/c.arrow", 30, 2024, 1),
+                ],
+            ]),
+            scan(vec![]),
+        ]));
+
+        let summaries = file_scan_summaries(&plan)?;
+        assert_eq!(summaries.len(), 2);
+
+        let summary = &summaries[0];
+        assert_eq!(summary.scan_name, "ArrowExec");
+        assert_eq!(summary.partition_cols, vec!["year", "month"]);
+        assert_eq!(summary.files.len(), 3);
+        assert_eq!(
+            summary.files[1].path.as_ref(),
+            "t/year=2024/month=2/b.arrow"
+        );
+        assert_eq!(summary.total_bytes(), 60);
+        assert_eq!(
+            summary.partition_paths(),
+            vec!["year=2024/month=1", "year=2024/month=2"]
+        );
+
+        assert!(summaries[1].files.is_empty());
+        assert!(summaries[1].partitions().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn file_ranges_listed_once() -> Result<()> {
+        let ranged = |start, end| {
+            let mut file = file("t/year=2023/month=12/a.arrow", 100, 2023, 12);
+            file.range = Some(FileRange { start, end });
+            file
+        };
+        let plan = scan(vec![vec![ranged(0, 50)], vec![ranged(50, 100)]]);
+
+        let summaries = file_scan_summaries(&plan)?;
+        assert_eq!(summaries[0].files.len(), 1);
+        assert_eq!(summaries[0].total_bytes(), 100);
+        assert_eq!(summaries[0].partition_paths(), vec!["year=2023/month=12"]);
+        Ok(())
+    }
+}
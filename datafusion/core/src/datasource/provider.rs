@@ -185,6 +185,31 @@ pub trait TableProvider: Sync + Send {
         ])
     }
 
+    /// Create an [`ExecutionPlan`] for scanning the table, like
+    /// [`Self::scan`], whose output is sorted by `sort_exprs`, if the
+    /// TableProvider can produce its rows in that order more efficiently
+    /// than the `Sort` operator in DataFusion, for example by reading an
+    /// index or asking a remote database to sort them.
+    ///
+    /// `sort_exprs` are [`Expr::Sort`] expressions with unqualified columns.
+    /// The returned plan must report the order in its
+    /// [`ExecutionPlan::properties`], so that the sort can be removed by the
+    /// physical optimizer. If `limit` is provided, the plan must produce at
+    /// least the first `limit` rows in that order.
+    ///
+    /// By default, returns `None`, meaning the rows are scanned with
+    /// [`Self::scan`] and sorted by DataFusion.
+    async fn scan_sorted(
+        &self,
+        _state: &SessionState,
+        _projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        _sort_exprs: &[Expr],
+        _limit: Option<usize>,
+    ) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+        Ok(None)
+    }
+
     /// Get statistics for this table, if available
     fn statistics(&self) -> Option<Statistics> {
         None
@@ -218,6 +243,39 @@ pub trait TableProvider: Sync + Send {
     ) -> Result<Arc<dyn ExecutionPlan>> {
         not_impl_err!("Insert into not implemented for this table")
     }
+
+    /// Return an [`ExecutionPlan`] to delete the rows of this table for which
+    /// all `filters` are true, if supported. All rows are deleted if there
+    /// are no `filters`.
+    ///
+    /// This is called for `DELETE FROM t WHERE ...`. The columns in
+    /// `filters` are unqualified. Like for [`Self::insert_into`], the
+    /// returned plan should return a single row in a UInt64 column called
+    /// "count", with the number of deleted rows.
+    async fn delete_from(
+        &self,
+        _state: &SessionState,
+        _filters: Vec<Expr>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        not_impl_err!("Delete from not implemented for this table")
+    }
+
+    /// Return an [`ExecutionPlan`] to update the rows of this table for which
+    /// all `filters` are true, if supported. Each column in `assignments` is
+    /// set to its expression, evaluated on the row before it is updated.
+    ///
+    /// This is called for `UPDATE t SET ... WHERE ...`. The columns in
+    /// `assignments` and `filters` are unqualified. Like for
+    /// [`Self::insert_into`], the returned plan should return a single row in
+    /// a UInt64 column called "count", with the number of updated rows.
+    async fn update(
+        &self,
+        _state: &SessionState,
+        _assignments: Vec<(String, Expr)>,
+        _filters: Vec<Expr>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        not_impl_err!("Update not implemented for this table")
+    }
 }
 
 /// A factory which creates [`TableProvider`]s at runtime given a URL.
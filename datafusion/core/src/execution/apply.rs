@@ -0,0 +1,411 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ApplyExec`] evaluates a correlated `LATERAL` subquery once for every
+//! row of its input.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::execution::context::SessionState;
+use crate::logical_expr::expr::Alias;
+use crate::logical_expr::{Expr, JoinType, LogicalPlan};
+use crate::physical_plan::metrics::{
+    BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet,
+};
+use crate::physical_plan::stream::RecordBatchStreamAdapter;
+use crate::physical_plan::{
+    collect, DisplayAs, DisplayFormatType, ExecutionPlan, ExecutionPlanProperties,
+    PhysicalExpr, PlanProperties, SendableRecordBatchStream,
+};
+
+use arrow::array::{new_null_array, ArrayRef, UInt32Array};
+use arrow::compute::{concat_batches, filter_record_batch, take};
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+use datafusion_common::cast::as_boolean_array;
+use datafusion_common::tree_node::{Transformed, TransformedResult, TreeNode};
+use datafusion_common::{internal_err, Column, DFSchema, ScalarValue};
+use datafusion_execution::TaskContext;
+use datafusion_physical_expr::EquivalenceProperties;
+
+use futures::StreamExt;
+
+/// Joins every row of `left` with the rows produced by a correlated
+/// `LATERAL` subquery for that row.
+///
+/// This is the fallback for `LATERAL` subqueries that can not be
+/// decorrelated into a regular join: for every input row the outer
+/// references of the subquery are replaced with the values of that row, and
+/// the resulting plan is planned and executed on its own. Only `Inner` and
+/// `Left` joins are supported.
+pub struct ApplyExec {
+    /// The input providing the values of the outer references
+    left: Arc<dyn ExecutionPlan>,
+    /// The correlated subquery
+    subquery: Arc<LogicalPlan>,
+    /// The outer references of `subquery` to the columns of `left`
+    outer_ref_columns: Vec<Expr>,
+    /// Schema of `left`, used to resolve the outer references
+    left_schema: Arc<DFSchema>,
+    /// Schema of the rows produced by `subquery`
+    right_schema: SchemaRef,
+    /// Join filter evaluated on the joined rows
+    filter: Option<Arc<dyn PhysicalExpr>>,
+    /// `Inner` or `Left`
+    join_type: JoinType,
+    /// The output schema
+    schema: SchemaRef,
+    /// Used to plan the subquery for every input row
+    session_state: SessionState,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+    /// Cache holding plan properties like equivalences, output partitioning etc.
+    cache: PlanProperties,
+}
+
+impl ApplyExec {
+    /// Create a new [`ApplyExec`]
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        left: Arc<dyn ExecutionPlan>,
+        subquery: Arc<LogicalPlan>,
+        left_schema: Arc<DFSchema>,
+        filter: Option<Arc<dyn PhysicalExpr>>,
+        join_type: JoinType,
+        schema: SchemaRef,
+        session_state: SessionState,
+    ) -> Result<Self> {
+        if !matches!(join_type, JoinType::Inner | JoinType::Left) {
+            return internal_err!("ApplyExec does not support {join_type} joins");
+        }
+        // Outer references to other relations are bound by nested `ApplyExec`s
+        let outer_ref_columns = subquery
+            .all_out_ref_exprs()
+            .into_iter()
+            .filter(|expr| {
+                matches!(expr, Expr::OuterReferenceColumn(_, column)
+                    if left_schema.has_column(column))
+            })
+            .collect();
+        let right_schema = SchemaRef::new(subquery.schema().as_ref().into());
+        let cache = Self::compute_properties(&left, schema.clone());
+        Ok(Self {
+            left,
+            subquery,
+            outer_ref_columns,
+            left_schema,
+            right_schema,
+            filter,
+            join_type,
+            schema,
+            session_state,
+            metrics: ExecutionPlanMetricsSet::new(),
+            cache,
+        })
+    }
+
+    /// The input providing the values of the outer references
+    pub fn left(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.left
+    }
+
+    /// The correlated subquery
+    pub fn subquery(&self) -> &Arc<LogicalPlan> {
+        &self.subquery
+    }
+
+    /// The join type
+    pub fn join_type(&self) -> &JoinType {
+        &self.join_type
+    }
+
+    /// This function creates the cache object that stores the plan properties such as schema, equivalence properties, ordering, partitioning, etc.
+    fn compute_properties(
+        left: &Arc<dyn ExecutionPlan>,
+        schema: SchemaRef,
+    ) -> PlanProperties {
+        let eq_properties = EquivalenceProperties::new(schema);
+        // The left columns keep their positions in the output, so the
+        // partitioning of `left` is preserved
+        PlanProperties::new(
+            eq_properties,
+            left.output_partitioning().clone(),
+            left.execution_mode(),
+        )
+    }
+}
+
+impl fmt::Debug for ApplyExec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApplyExec")
+            .field("left", &self.left)
+            .field("join_type", &self.join_type)
+            .field("outer_ref_columns", &self.outer_ref_columns)
+            .finish()
+    }
+}
+
+impl DisplayAs for ApplyExec {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                let outer_refs = self
+                    .outer_ref_columns
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let filter = self
+                    .filter
+                    .as_ref()
+                    .map(|f| format!(", filter={f}"))
+                    .unwrap_or_default();
+                write!(
+                    f,
+                    "ApplyExec: join_type={}, outer_refs=[{}]{}",
+                    self.join_type, outer_refs, filter
+                )
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for ApplyExec {
+    fn name(&self) -> &'static str {
+        "ApplyExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![self.left.clone()]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(ApplyExec::try_new(
+            children[0].clone(),
+            self.subquery.clone(),
+            self.left_schema.clone(),
+            self.filter.clone(),
+            self.join_type,
+            self.schema.clone(),
+            self.session_state.clone(),
+        )?))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let left = self.left.execute(partition, context.clone())?;
+        let baseline_metrics = BaselineMetrics::new(&self.metrics, partition);
+        let apply = Arc::new(ApplyContext {
+            subquery: self.subquery.clone(),
+            outer_ref_columns: self.outer_ref_columns.clone(),
+            left_schema: self.left_schema.clone(),
+            right_schema: self.right_schema.clone(),
+            filter: self.filter.clone(),
+            join_type: self.join_type,
+            schema: self.schema.clone(),
+            session_state: self.session_state.clone(),
+            context,
+        });
+
+        let stream = left.then(move |batch| {
+            let apply = apply.clone();
+            let baseline_metrics = baseline_metrics.clone();
+            async move {
+                let batch = apply.apply_batch(batch?).await?;
+                baseline_metrics.record_output(batch.num_rows());
+                Ok(batch)
+            }
+        });
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            self.schema.clone(),
+            stream,
+        )))
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+}
+
+/// State shared by the per-batch futures of an [`ApplyExec`] stream
+struct ApplyContext {
+    subquery: Arc<LogicalPlan>,
+    outer_ref_columns: Vec<Expr>,
+    left_schema: Arc<DFSchema>,
+    right_schema: SchemaRef,
+    filter: Option<Arc<dyn PhysicalExpr>>,
+    join_type: JoinType,
+    schema: SchemaRef,
+    session_state: SessionState,
+    context: Arc<TaskContext>,
+}
+
+impl ApplyContext {
+    /// Joins every row of `left` with the result of the subquery for that row
+    async fn apply_batch(&self, left: RecordBatch) -> Result<RecordBatch> {
+        let mut output = Vec::with_capacity(left.num_rows());
+        for row in 0..left.num_rows() {
+            let right = self.execute_subquery(&left, row).await?;
+            let left_columns = repeat_row(&left, row, right.num_rows())?;
+            let joined = RecordBatch::try_new(
+                self.schema.clone(),
+                left_columns
+                    .into_iter()
+                    .chain(right.columns().iter().cloned())
+                    .collect(),
+            )?;
+            let joined = match &self.filter {
+                Some(filter) => {
+                    let mask = filter.evaluate(&joined)?.into_array(joined.num_rows())?;
+                    filter_record_batch(&joined, as_boolean_array(&mask)?)?
+                }
+                None => joined,
+            };
+            if joined.num_rows() == 0 && self.join_type == JoinType::Left {
+                // Preserve the left row, padding the subquery columns with nulls
+                let columns = repeat_row(&left, row, 1)?
+                    .into_iter()
+                    .chain(
+                        self.right_schema
+                            .fields()
+                            .iter()
+                            .map(|f| new_null_array(f.data_type(), 1)),
+                    )
+                    .collect();
+                output.push(RecordBatch::try_new(self.schema.clone(), columns)?);
+            } else {
+                output.push(joined);
+            }
+        }
+        Ok(concat_batches(&self.schema, &output)?)
+    }
+
+    /// Plans and runs the subquery with its outer references bound to the
+    /// values of `left` at `row`
+    async fn execute_subquery(
+        &self,
+        left: &RecordBatch,
+        row: usize,
+    ) -> Result<RecordBatch> {
+        let mut values = HashMap::with_capacity(self.outer_ref_columns.len());
+        for outer_ref in &self.outer_ref_columns {
+            let Expr::OuterReferenceColumn(_, column) = outer_ref else {
+                return internal_err!("Expected an outer reference, got {outer_ref}");
+            };
+            let index = self.left_schema.index_of_column(column)?;
+            let value = ScalarValue::try_from_array(left.column(index), row)?;
+            values.insert(column.clone(), value);
+        }
+
+        let plan = bind_outer_references(&self.subquery, &values)?;
+        let physical_plan = self.session_state.create_physical_plan(&plan).await?;
+        let batches = collect(physical_plan, self.context.clone()).await?;
+        // Binding the outer references may change the field names of the
+        // subquery, but not their order and types
+        let batches = batches
+            .into_iter()
+            .map(|batch| {
+                RecordBatch::try_new(self.right_schema.clone(), batch.columns().to_vec())
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(concat_batches(&self.right_schema, &batches)?)
+    }
+}
+
+/// Repeats row `row` of `batch` `n` times
+fn repeat_row(batch: &RecordBatch, row: usize, n: usize) -> Result<Vec<ArrayRef>> {
+    let indices = UInt32Array::from(vec![row as u32; n]);
+    Ok(batch
+        .columns()
+        .iter()
+        .map(|array| take(array, &indices, None))
+        .collect::<std::result::Result<Vec<_>, _>>()?)
+}
+
+/// Replaces the outer references in `plan` with literal `values`
+fn bind_outer_references(
+    plan: &LogicalPlan,
+    values: &HashMap<Column, ScalarValue>,
+) -> Result<LogicalPlan> {
+    // Filter predicates do not name any output, and must not be aliased
+    let keep_names = !matches!(plan, LogicalPlan::Filter(_));
+    let new_exprs = plan
+        .expressions()
+        .into_iter()
+        .map(|expr| match expr {
+            Expr::Alias(Alias {
+                expr,
+                relation,
+                name,
+            }) => Ok(Expr::Alias(Alias {
+                expr: Box::new(bind_expr(*expr, values, false)?),
+                relation,
+                name,
+            })),
+            expr => bind_expr(expr, values, keep_names),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let new_inputs = plan
+        .inputs()
+        .into_iter()
+        .map(|input| bind_outer_references(input, values))
+        .collect::<Result<Vec<_>>>()?;
+    plan.with_new_exprs(new_exprs, new_inputs)
+}
+
+/// Replaces the outer references in `expr` with literal `values`.
+///
+/// If `keep_names` is set the literals are aliased with the name of the outer
+/// reference, so that the columns referring to the output of `expr` remain
+/// valid.
+fn bind_expr(
+    expr: Expr,
+    values: &HashMap<Column, ScalarValue>,
+    keep_names: bool,
+) -> Result<Expr> {
+    expr.transform_up(&|expr| match &expr {
+        Expr::OuterReferenceColumn(_, column) if values.contains_key(column) => {
+            let value = Expr::Literal(values[column].clone());
+            if keep_names {
+                let name = expr.display_name()?;
+                Ok(Transformed::yes(value.alias(name)))
+            } else {
+                Ok(Transformed::yes(value))
+            }
+        }
+        _ => Ok(Transformed::no(expr)),
+    })
+    .data()
+}
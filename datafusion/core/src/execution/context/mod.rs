@@ -17,7 +17,7 @@
 
 //! [`SessionContext`] contains methods for registering data sources and executing queries
 
-use std::collections::{hash_map::Entry, HashMap, HashSet};
+use std::collections::{hash_map::Entry, HashMap, HashSet, VecDeque};
 use std::fmt::Debug;
 use std::ops::ControlFlow;
 use std::string::String;
@@ -55,7 +55,7 @@ use crate::{
     optimizer::optimizer::{Optimizer, OptimizerConfig, OptimizerRule},
     physical_optimizer::optimizer::{PhysicalOptimizer, PhysicalOptimizerRule},
     physical_plan::{udf::ScalarUDF, ExecutionPlan},
-    physical_planner::{DefaultPhysicalPlanner, PhysicalPlanner},
+    physical_planner::{DefaultPhysicalPlanner, ExtensionPlanner, PhysicalPlanner},
     variable::{VarProvider, VarType},
 };
 use crate::{functions, functions_aggregate, functions_array};
@@ -66,17 +66,20 @@ use arrow_schema::Schema;
 use datafusion_common::{
     alias::AliasGenerator,
     config::{ConfigExtension, TableOptions},
-    exec_err, not_impl_err, plan_datafusion_err, plan_err,
+    exec_err,
+    instant::Instant,
+    not_impl_err, plan_datafusion_err, plan_err,
     tree_node::{TreeNodeRecursion, TreeNodeVisitor},
     SchemaReference, TableReference,
 };
-use datafusion_execution::registry::SerializerRegistry;
+use datafusion_execution::registry::{FunctionPackage, SerializerRegistry};
 use datafusion_expr::{
     logical_plan::{DdlStatement, Statement},
     var_provider::is_system_variables,
     Expr, StringifiedPlan, UserDefinedLogicalNode, WindowUDF,
 };
 use datafusion_sql::{
+    hints::{apply_hints, extract_hints, target_partitions, SqlHint},
     parser::{CopyToSource, CopyToStatement, DFParser},
     planner::{object_name_to_table_reference, ContextProvider, ParserOptions, SqlToRel},
     ResolvedTableReference,
@@ -85,7 +88,7 @@ use datafusion_sql::{
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use parking_lot::RwLock;
-use sqlparser::dialect::dialect_from_str;
+use sqlparser::dialect::{dialect_from_str, Dialect};
 use url::Url;
 use uuid::Uuid;
 
@@ -97,6 +100,7 @@ use datafusion_expr::expr_rewriter::FunctionRewrite;
 mod avro;
 mod csv;
 mod json;
+mod orc;
 #[cfg(feature = "parquet")]
 mod parquet;
 
@@ -453,10 +457,150 @@ impl SessionContext {
         sql: &str,
         options: SQLOptions,
     ) -> Result<DataFrame> {
-        let plan = self.state().create_logical_plan(sql).await?;
+        let state = self.state();
+        let plan = state.create_logical_plan(sql).await?;
         options.verify_plan(&plan)?;
 
-        self.execute_logical_plan(plan).await
+        let df = self.execute_logical_plan(plan).await?;
+        // a `/*+ REPARTITION(n) */` hint only applies to this query
+        match target_partitions(&state.sql_hints(sql)?) {
+            Some(target_partitions) => Ok(df.with_target_partitions(target_partitions)),
+            None => Ok(df),
+        }
+    }
+
+    /// Executes the SQL script `sql` of one or more statements separated by
+    /// semicolons in order, and returns the results of every statement.
+    ///
+    /// Every statement is planned once the previous statements were executed,
+    /// so it can refer to the tables and views they created.
+    ///
+    /// If a statement fails, the tables, views and schemas created, replaced
+    /// or dropped by the previous statements of the script are restored
+    /// before the error is returned. Changes to the data of tables, such as
+    /// by `INSERT INTO`, and created catalogs and functions are not undone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let results = ctx
+    ///     .sql_batch("CREATE TABLE foo (x INTEGER); INSERT INTO foo VALUES (1)")
+    ///     .await?;
+    /// assert_eq!(results.len(), 2);
+    ///
+    /// // `bar` is dropped again as the script fails
+    /// ctx.sql_batch("CREATE TABLE bar (x INTEGER); SELECT * FROM missing")
+    ///     .await
+    ///     .unwrap_err();
+    /// assert!(!ctx.table_exist("bar")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sql_batch(&self, sql: &str) -> Result<Vec<Vec<RecordBatch>>> {
+        let statements = {
+            let state = self.state.read();
+            let dialect = state.config.options().sql_parser.dialect.as_str();
+            state.sql_to_statements(sql, dialect)?
+        };
+
+        let mut changes = vec![];
+        let mut results = Vec::with_capacity(statements.len());
+        for statement in statements {
+            match self.execute_statement(statement, &mut changes).await {
+                Ok(batches) => results.push(batches),
+                Err(e) => {
+                    self.undo_catalog_changes(changes)?;
+                    return Err(e);
+                }
+            }
+        }
+        Ok(results)
+    }
+
+    /// Plans and executes `statement` of a script, recording the change to
+    /// the catalog it makes, if any, in `changes`
+    async fn execute_statement(
+        &self,
+        statement: datafusion_sql::parser::Statement,
+        changes: &mut Vec<CatalogChange>,
+    ) -> Result<Vec<RecordBatch>> {
+        let plan = self.state().statement_to_plan(statement).await?;
+        let table = match &plan {
+            LogicalPlan::Ddl(DdlStatement::CreateExternalTable(cmd)) => Some(&cmd.name),
+            LogicalPlan::Ddl(DdlStatement::CreateMemoryTable(cmd)) => Some(&cmd.name),
+            LogicalPlan::Ddl(DdlStatement::CreateView(cmd)) => Some(&cmd.name),
+            LogicalPlan::Ddl(DdlStatement::DropTable(cmd)) => Some(&cmd.name),
+            LogicalPlan::Ddl(DdlStatement::DropView(cmd)) => Some(&cmd.name),
+            _ => None,
+        };
+        if let Some(name) = table {
+            changes.push(CatalogChange::Table {
+                name: name.clone(),
+                previous: self.table_provider(name.clone()).await.ok(),
+            });
+        } else if matches!(
+            plan,
+            LogicalPlan::Ddl(
+                DdlStatement::CreateCatalogSchema(_) | DdlStatement::DropCatalogSchema(_)
+            )
+        ) {
+            changes.push(CatalogChange::Schemas {
+                previous: self.schemas(),
+            });
+        }
+        self.execute_logical_plan(plan).await?.collect().await
+    }
+
+    /// Returns the schemas of every catalog
+    fn schemas(&self) -> CatalogSchemas {
+        let catalog_list = self.state.read().catalog_list.clone();
+        catalog_list
+            .catalog_names()
+            .iter()
+            .filter_map(|name| catalog_list.catalog(name))
+            .map(|catalog| {
+                let schemas = catalog
+                    .schema_names()
+                    .into_iter()
+                    .filter_map(|name| catalog.schema(&name).map(|schema| (name, schema)))
+                    .collect();
+                (catalog, schemas)
+            })
+            .collect()
+    }
+
+    /// Undoes `changes`, in reverse order
+    fn undo_catalog_changes(&self, changes: Vec<CatalogChange>) -> Result<()> {
+        for change in changes.into_iter().rev() {
+            match change {
+                CatalogChange::Table { name, previous } => {
+                    self.deregister_table(name.clone())?;
+                    if let Some(previous) = previous {
+                        self.register_table(name, previous)?;
+                    }
+                }
+                CatalogChange::Schemas { previous } => {
+                    for (catalog, schemas) in previous {
+                        for name in catalog.schema_names() {
+                            if !schemas.iter().any(|(previous, _)| *previous == name) {
+                                catalog.deregister_schema(&name, true)?;
+                            }
+                        }
+                        for (name, schema) in schemas {
+                            if catalog.schema(&name).is_none() {
+                                catalog.register_schema(&name, schema)?;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
     }
 
     /// Execute the [`LogicalPlan`], return a [`DataFrame`]. This API
@@ -736,6 +880,12 @@ impl SessionContext {
             variable, value, ..
         } = stmt;
 
+        // reject unsupported dialects here, as they would fail to parse any
+        // later statement, including one setting a supported dialect
+        if variable.eq_ignore_ascii_case("datafusion.sql_parser.dialect") {
+            parse_dialect(&value)?;
+        }
+
         let mut state = self.state.write();
         state.config.options_mut().set(&variable, &value)?;
         drop(state);
@@ -895,6 +1045,13 @@ impl SessionContext {
         self.state.write().register_udwf(Arc::new(f)).ok();
     }
 
+    /// Registers all functions of a [`FunctionPackage`] within this context.
+    ///
+    /// See [`FunctionPackage::register_all`] for details.
+    pub fn register_function_package(&self, package: &dyn FunctionPackage) -> Result<()> {
+        package.register_all(&mut *self.state.write())
+    }
+
     /// Deregisters a UDF within this context.
     pub fn deregister_udf(&self, name: &str) {
         self.state.write().deregister_udf(name).ok();
@@ -1264,6 +1421,27 @@ pub trait QueryPlanner {
     ) -> Result<Arc<dyn ExecutionPlan>>;
 }
 
+/// Returns the position of the rule named `name` among the rules `names`
+fn rule_position<'a>(
+    mut names: impl Iterator<Item = &'a str>,
+    name: &str,
+) -> Result<usize> {
+    names
+        .position(|n| n == name)
+        .ok_or_else(|| plan_datafusion_err!("There is no rule named {name}"))
+}
+
+/// Returns the SQL dialect named `dialect`
+fn parse_dialect(dialect: &str) -> Result<Box<dyn Dialect>> {
+    dialect_from_str(dialect).ok_or_else(|| {
+        plan_datafusion_err!(
+            "Unsupported SQL dialect: {dialect}. Available dialects: \
+                 Generic, MySQL, PostgreSQL, Hive, SQLite, Snowflake, Redshift, \
+                 MsSQL, ClickHouse, BigQuery, Ansi."
+        )
+    })
+}
+
 /// The query planner used if no user defined planner is provided
 struct DefaultQueryPlanner {}
 
@@ -1275,7 +1453,9 @@ impl QueryPlanner for DefaultQueryPlanner {
         logical_plan: &LogicalPlan,
         session_state: &SessionState,
     ) -> Result<Arc<dyn ExecutionPlan>> {
-        let planner = DefaultPhysicalPlanner::default();
+        let planner = DefaultPhysicalPlanner::with_extension_planners(
+            session_state.extension_planners.clone(),
+        );
         planner
             .create_physical_plan(logical_plan, session_state)
             .await
@@ -1294,6 +1474,26 @@ pub trait FunctionFactory: Sync + Send {
     ) -> Result<RegisterFunction>;
 }
 
+/// The catalogs, with the names and providers of their schemas
+type CatalogSchemas = Vec<(
+    Arc<dyn CatalogProvider>,
+    Vec<(String, Arc<dyn SchemaProvider>)>,
+)>;
+
+/// A change to the catalog made by a statement of a script executed with
+/// [`SessionContext::sql_batch`], which is undone if a later statement fails
+enum CatalogChange {
+    /// The table or view `name` was created, replaced or dropped. `previous`
+    /// is the table registered as `name` before.
+    Table {
+        name: TableReference,
+        previous: Option<Arc<dyn TableProvider>>,
+    },
+    /// Schemas were created or dropped. `previous` are the schemas of every
+    /// catalog before.
+    Schemas { previous: CatalogSchemas },
+}
+
 /// Type of function to create
 pub enum RegisterFunction {
     /// Scalar user defined function
@@ -1323,6 +1523,8 @@ pub struct SessionState {
     physical_optimizers: PhysicalOptimizer,
     /// Responsible for planning `LogicalPlan`s, and `ExecutionPlan`
     query_planner: Arc<dyn QueryPlanner + Send + Sync>,
+    /// Planners for [`UserDefinedLogicalNode`]s, used by the default query planner
+    extension_planners: Vec<Arc<dyn ExtensionPlanner + Send + Sync>>,
     /// Collection of catalogs containing schemas and ultimately TableProviders
     catalog_list: Arc<dyn CatalogProviderList>,
     /// Table Functions
@@ -1403,6 +1605,7 @@ impl SessionState {
         table_factories.insert("NDJSON".into(), Arc::new(DefaultTableFactory::new()));
         table_factories.insert("AVRO".into(), Arc::new(DefaultTableFactory::new()));
         table_factories.insert("ARROW".into(), Arc::new(DefaultTableFactory::new()));
+        table_factories.insert("FEATHER".into(), Arc::new(DefaultTableFactory::new()));
 
         if config.create_default_catalog_and_schema() {
             let default_catalog = MemoryCatalogProvider::new();
@@ -1433,6 +1636,7 @@ impl SessionState {
             optimizer: Optimizer::new(),
             physical_optimizers: PhysicalOptimizer::new(),
             query_planner: Arc::new(DefaultQueryPlanner {}),
+            extension_planners: vec![],
             catalog_list,
             table_functions: HashMap::new(),
             scalar_functions: HashMap::new(),
@@ -1630,6 +1834,105 @@ impl SessionState {
         self
     }
 
+    /// Add `analyzer_rule` to the list of [`AnalyzerRule`]s used to rewrite
+    /// queries, right before the rule named `name`.
+    ///
+    /// Returns an error if there is no analyzer rule named `name`.
+    pub fn add_analyzer_rule_before(
+        mut self,
+        name: &str,
+        analyzer_rule: Arc<dyn AnalyzerRule + Send + Sync>,
+    ) -> Result<Self> {
+        let idx = rule_position(self.analyzer.rules.iter().map(|r| r.name()), name)?;
+        self.analyzer.rules.insert(idx, analyzer_rule);
+        Ok(self)
+    }
+
+    /// Add `analyzer_rule` to the list of [`AnalyzerRule`]s used to rewrite
+    /// queries, right after the rule named `name`.
+    ///
+    /// Returns an error if there is no analyzer rule named `name`.
+    pub fn add_analyzer_rule_after(
+        mut self,
+        name: &str,
+        analyzer_rule: Arc<dyn AnalyzerRule + Send + Sync>,
+    ) -> Result<Self> {
+        let idx = rule_position(self.analyzer.rules.iter().map(|r| r.name()), name)?;
+        self.analyzer.rules.insert(idx + 1, analyzer_rule);
+        Ok(self)
+    }
+
+    /// Add `optimizer_rule` to the list of [`OptimizerRule`]s used to rewrite
+    /// queries, right before the rule named `name`.
+    ///
+    /// Returns an error if there is no optimizer rule named `name`.
+    pub fn add_optimizer_rule_before(
+        mut self,
+        name: &str,
+        optimizer_rule: Arc<dyn OptimizerRule + Send + Sync>,
+    ) -> Result<Self> {
+        let idx = rule_position(self.optimizer.rules.iter().map(|r| r.name()), name)?;
+        self.optimizer.rules.insert(idx, optimizer_rule);
+        Ok(self)
+    }
+
+    /// Add `optimizer_rule` to the list of [`OptimizerRule`]s used to rewrite
+    /// queries, right after the rule named `name`.
+    ///
+    /// Returns an error if there is no optimizer rule named `name`.
+    pub fn add_optimizer_rule_after(
+        mut self,
+        name: &str,
+        optimizer_rule: Arc<dyn OptimizerRule + Send + Sync>,
+    ) -> Result<Self> {
+        let idx = rule_position(self.optimizer.rules.iter().map(|r| r.name()), name)?;
+        self.optimizer.rules.insert(idx + 1, optimizer_rule);
+        Ok(self)
+    }
+
+    /// Add `physical_optimizer_rule` to the list of [`PhysicalOptimizerRule`]s
+    /// used to rewrite queries, right before the rule named `name`.
+    ///
+    /// Returns an error if there is no physical optimizer rule named `name`.
+    pub fn add_physical_optimizer_rule_before(
+        mut self,
+        name: &str,
+        physical_optimizer_rule: Arc<dyn PhysicalOptimizerRule + Send + Sync>,
+    ) -> Result<Self> {
+        let rules = &mut self.physical_optimizers.rules;
+        let idx = rule_position(rules.iter().map(|r| r.name()), name)?;
+        rules.insert(idx, physical_optimizer_rule);
+        Ok(self)
+    }
+
+    /// Add `physical_optimizer_rule` to the list of [`PhysicalOptimizerRule`]s
+    /// used to rewrite queries, right after the rule named `name`.
+    ///
+    /// Returns an error if there is no physical optimizer rule named `name`.
+    pub fn add_physical_optimizer_rule_after(
+        mut self,
+        name: &str,
+        physical_optimizer_rule: Arc<dyn PhysicalOptimizerRule + Send + Sync>,
+    ) -> Result<Self> {
+        let rules = &mut self.physical_optimizers.rules;
+        let idx = rule_position(rules.iter().map(|r| r.name()), name)?;
+        rules.insert(idx + 1, physical_optimizer_rule);
+        Ok(self)
+    }
+
+    /// Add `extension_planner` to the end of the list of [`ExtensionPlanner`]s
+    /// used by the default query planner to plan [`UserDefinedLogicalNode`]s.
+    ///
+    /// Extension planners are not used if the query planner is replaced with
+    /// [`Self::with_query_planner`].
+    pub fn add_extension_planner(
+        mut self,
+        extension_planner: Arc<dyn ExtensionPlanner + Send + Sync>,
+    ) -> Self {
+        self.extension_planners.push(extension_planner);
+        self
+    }
+
     /// Adds a new [`ConfigExtension`] to TableOptions
     pub fn add_table_options_extension<T: ConfigExtension>(
         mut self,
@@ -1674,6 +1977,18 @@ impl SessionState {
         &mut self.table_factories
     }
 
+    /// Parse an SQL string of statements separated by semicolons into
+    /// DataFusion specific ASTs. See [`SessionContext::sql_batch`] for
+    /// running them.
+    pub fn sql_to_statements(
+        &self,
+        sql: &str,
+        dialect: &str,
+    ) -> Result<VecDeque<datafusion_sql::parser::Statement>> {
+        let dialect = parse_dialect(dialect)?;
+        Ok(DFParser::parse_sql_with_dialect(sql, dialect.as_ref())?)
+    }
+
     /// Parse an SQL string into an DataFusion specific AST
     /// [`Statement`]. See [`SessionContext::sql`] for running queries.
     pub fn sql_to_statement(
@@ -1681,14 +1996,7 @@ impl SessionState {
         sql: &str,
         dialect: &str,
     ) -> Result<datafusion_sql::parser::Statement> {
-        let dialect = dialect_from_str(dialect).ok_or_else(|| {
-            plan_datafusion_err!(
-                "Unsupported SQL dialect: {dialect}. Available dialects: \
-                     Generic, MySQL, PostgreSQL, Hive, SQLite, Snowflake, Redshift, \
-                     MsSQL, ClickHouse, BigQuery, Ansi."
-            )
-        })?;
-        let mut statements = DFParser::parse_sql_with_dialect(sql, dialect.as_ref())?;
+        let mut statements = self.sql_to_statements(sql, dialect)?;
         if statements.len() > 1 {
             return not_impl_err!(
                 "The context currently only supports a single SQL statement"
@@ -1805,6 +2113,11 @@ impl SessionState {
             self.config.options().sql_parser.enable_ident_normalization;
         let parse_float_as_decimal =
             self.config.options().sql_parser.parse_float_as_decimal;
+        let enable_case_insensitive_columns = self
+            .config
+            .options()
+            .sql_parser
+            .enable_case_insensitive_columns;
         for reference in references {
             let resolved = &self.resolve_table_ref(reference);
             if let Entry::Vacant(v) = provider.tables.entry(resolved.to_string()) {
@@ -1821,6 +2134,7 @@ impl SessionState {
             ParserOptions {
                 parse_float_as_decimal,
                 enable_ident_normalization,
+                enable_case_insensitive_columns,
             },
         );
         query.statement_to_plan(statement)
@@ -1839,7 +2153,17 @@ impl SessionState {
         let dialect = self.config.options().sql_parser.dialect.as_str();
         let statement = self.sql_to_statement(sql, dialect)?;
         let plan = self.statement_to_plan(statement).await?;
-        Ok(plan)
+        apply_hints(plan, &self.sql_hints(sql)?)
+    }
+
+    /// Extracts the optimizer hints, such as `/*+ BROADCAST(t) */`, of `sql`.
+    /// Hints are comments, so they are not part of the parsed statement.
+    fn sql_hints(&self, sql: &str) -> Result<Vec<SqlHint>> {
+        let dialect = self.config.options().sql_parser.dialect.as_str();
+        match dialect_from_str(dialect) {
+            Some(dialect) => extract_hints(sql, dialect.as_ref()),
+            None => Ok(vec![]),
+        }
     }
 
     /// Optimizes the logical plan by applying optimizer rules.
@@ -1866,6 +2190,7 @@ impl SessionState {
 
                     return Ok(LogicalPlan::Explain(Explain {
                         verbose: e.verbose,
+                        estimate: e.estimate,
                         plan: e.plan.clone(),
                         stringified_plans,
                         schema: e.schema.clone(),
@@ -1879,14 +2204,24 @@ impl SessionState {
             stringified_plans
                 .push(analyzed_plan.to_stringified(PlanType::FinalAnalyzedLogicalPlan));
 
-            // optimize the child plan, capturing the output of each optimizer
+            // optimize the child plan, capturing the output of each optimizer,
+            // and the time spent in it if enabled. The observer is called right
+            // after each rule, so the time since the previous call is the time
+            // spent in the rule
+            let show_timing = self.config_options().explain.show_optimizer_timing;
+            let mut rule_start = Instant::now();
             let optimized_plan = self.optimizer.optimize(
                 &analyzed_plan,
                 self,
                 |optimized_plan, optimizer| {
-                    let optimizer_name = optimizer.name().to_string();
+                    let optimizer_name = if show_timing {
+                        format!("{} ({:?})", optimizer.name(), rule_start.elapsed())
+                    } else {
+                        optimizer.name().to_string()
+                    };
                     let plan_type = PlanType::OptimizedLogicalPlan { optimizer_name };
                     stringified_plans.push(optimized_plan.to_stringified(plan_type));
+                    rule_start = Instant::now();
                 },
             );
             let (plan, logical_optimization_succeeded) = match optimized_plan {
@@ -1902,6 +2237,7 @@ impl SessionState {
 
             Ok(LogicalPlan::Explain(Explain {
                 verbose: e.verbose,
+                estimate: e.estimate,
                 plan,
                 stringified_plans,
                 schema: e.schema.clone(),
@@ -1952,11 +2288,30 @@ impl SessionState {
         &self.config
     }
 
+    /// Return the mutable [`SessionConfig`]
+    pub fn config_mut(&mut self) -> &mut SessionConfig {
+        &mut self.config
+    }
+
     /// Return the physical optimizers
     pub fn physical_optimizers(&self) -> &[Arc<dyn PhysicalOptimizerRule + Send + Sync>] {
         &self.physical_optimizers.rules
     }
 
+    /// Returns true if `rule` is one of the built-in physical optimizer
+    /// rules of this session, see [`PhysicalOptimizer::is_built_in`]
+    pub fn is_built_in_physical_optimizer_rule(
+        &self,
+        rule: &Arc<dyn PhysicalOptimizerRule + Send + Sync>,
+    ) -> bool {
+        self.physical_optimizers.is_built_in(rule)
+    }
+
+    /// Return the extension planners used by the default query planner
+    pub fn extension_planners(&self) -> &[Arc<dyn ExtensionPlanner + Send + Sync>] {
+        &self.extension_planners
+    }
+
     /// return the configuration options
     pub fn config_options(&self) -> &ConfigOptions {
         self.config.options()
@@ -2349,6 +2704,7 @@ mod tests {
     use crate::test_util::{plan_and_collect, populate_csv_partitions};
     use crate::variable::VarType;
 
+    use datafusion_common::assert_contains;
     use datafusion_common_runtime::SpawnedTask;
     use datafusion_expr::Expr;
 
@@ -2432,6 +2788,34 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn sql_join_hints() -> Result<()> {
+        let ctx = SessionContext::new();
+        ctx.sql("CREATE TABLE t1(a INT) AS VALUES (1), (2)").await?;
+        ctx.sql("CREATE TABLE t2(a INT) AS VALUES (2), (3)").await?;
+
+        let plan = ctx
+            .state()
+            .create_logical_plan(
+                "SELECT /*+ BROADCAST(t2) */ * FROM t1 JOIN t2 ON t1.a = t2.a",
+            )
+            .await?;
+        let expected = "Projection: t1.a, t2.a\
+        \n  Inner Join:  Filter: t1.a = t2.a Hint: BroadcastRight\
+        \n    TableScan: t1\
+        \n    TableScan: t2";
+        assert_eq!(format!("{plan:?}"), expected);
+
+        let results = plan_and_collect(
+            &ctx,
+            "SELECT /*+ SHUFFLE_HASH */ t1.a FROM t1 JOIN t2 ON t1.a = t2.a",
+        )
+        .await?;
+        let expected = ["+---+", "| a |", "+---+", "| 2 |", "+---+"];
+        assert_batches_eq!(expected, &results);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn register_deregister() -> Result<()> {
         let tmp_dir = TempDir::new()?;
@@ -2520,6 +2904,100 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn sql_batch() -> Result<()> {
+        let ctx = SessionContext::new();
+        let results = ctx
+            .sql_batch(
+                "CREATE TABLE t (x INT); \
+                 INSERT INTO t VALUES (1), (2); \
+                 SELECT sum(x) AS s FROM t",
+            )
+            .await?;
+        assert_eq!(results.len(), 3);
+        assert_batches_eq!(["++", "++"], &results[0]);
+        let expected = ["+---+", "| s |", "+---+", "| 3 |", "+---+"];
+        assert_batches_eq!(expected, &results[2]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sql_batch_undoes_catalog_changes() -> Result<()> {
+        let ctx = SessionContext::new();
+        ctx.sql_batch("CREATE TABLE t AS VALUES (1); CREATE VIEW v AS SELECT 1")
+            .await?;
+
+        let err = ctx
+            .sql_batch(
+                "CREATE SCHEMA s; \
+                 CREATE TABLE s.a (x INT); \
+                 DROP TABLE t; \
+                 CREATE OR REPLACE VIEW v AS SELECT 2; \
+                 CREATE TABLE b (x INT); \
+                 SELECT * FROM missing",
+            )
+            .await
+            .unwrap_err();
+        assert_contains!(
+            err.to_string(),
+            "table 'datafusion.public.missing' not found"
+        );
+
+        assert!(ctx.table_exist("t")?);
+        assert!(!ctx.table_exist("b")?);
+        assert!(ctx.catalog("datafusion").unwrap().schema("s").is_none());
+        let batches = ctx.sql("SELECT * FROM v").await?.collect().await?;
+        let expected = [
+            "+----------+",
+            "| Int64(1) |",
+            "+----------+",
+            "| 1        |",
+            "+----------+",
+        ];
+        assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+
+    #[test]
+    fn add_rules_relative_to_other_rules() -> Result<()> {
+        let runtime = Arc::new(RuntimeEnv::default());
+        let state = SessionState::new_with_config_rt(SessionConfig::new(), runtime)
+            .with_physical_optimizer_rules(vec![
+                Arc::new(NamedPhysicalOptimizerRule("a")),
+                Arc::new(NamedPhysicalOptimizerRule("b")),
+            ])
+            .add_physical_optimizer_rule_before(
+                "a",
+                Arc::new(NamedPhysicalOptimizerRule("before_a")),
+            )?
+            .add_physical_optimizer_rule_after(
+                "a",
+                Arc::new(NamedPhysicalOptimizerRule("after_a")),
+            )?
+            .add_physical_optimizer_rule_after(
+                "b",
+                Arc::new(NamedPhysicalOptimizerRule("after_b")),
+            )?;
+        let names = state
+            .physical_optimizers()
+            .iter()
+            .map(|rule| rule.name())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["before_a", "a", "after_a", "b", "after_b"]);
+
+        let err = state
+            .add_physical_optimizer_rule_before(
+                "c",
+                Arc::new(NamedPhysicalOptimizerRule("before_c")),
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: There is no rule named c"
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn disabled_default_catalog_and_schema() -> Result<()> {
         let ctx = SessionContext::new_with_config(
@@ -2733,6 +3211,26 @@ mod tests {
         }
     }
 
+    struct NamedPhysicalOptimizerRule(&'static str);
+
+    impl PhysicalOptimizerRule for NamedPhysicalOptimizerRule {
+        fn optimize(
+            &self,
+            plan: Arc<dyn ExecutionPlan>,
+            _config: &crate::config::ConfigOptions,
+        ) -> Result<Arc<dyn ExecutionPlan>> {
+            Ok(plan)
+        }
+
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn schema_check(&self) -> bool {
+            true
+        }
+    }
+
     struct MyQueryPlanner {}
 
     #[async_trait]
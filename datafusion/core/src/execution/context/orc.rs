@@ -0,0 +1,90 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use super::super::options::{OrcReadOptions, ReadOptions};
+use super::{DataFilePaths, DataFrame, Result, SessionContext};
+
+impl SessionContext {
+    /// Creates a [`DataFrame`] for reading an Apache ORC data source.
+    ///
+    /// Reading ORC files requires the `orc` feature.
+    ///
+    /// For an example, see [`read_csv`](Self::read_csv)
+    pub async fn read_orc<P: DataFilePaths>(
+        &self,
+        table_paths: P,
+        options: OrcReadOptions<'_>,
+    ) -> Result<DataFrame> {
+        self._read_type(table_paths, options).await
+    }
+
+    /// Registers an Apache ORC file as a table that can be referenced from
+    /// SQL statements executed against this context.
+    pub async fn register_orc(
+        &self,
+        name: &str,
+        table_path: &str,
+        options: OrcReadOptions<'_>,
+    ) -> Result<()> {
+        let listing_options = options
+            .to_listing_options(&self.copied_config(), self.copied_table_options());
+
+        self.register_listing_table(
+            name,
+            table_path,
+            listing_options,
+            options.schema.map(|s| Arc::new(s.to_owned())),
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "orc")]
+mod tests {
+    use super::*;
+
+    use datafusion_common::assert_batches_eq;
+
+    #[tokio::test]
+    async fn read_and_register_orc() -> Result<()> {
+        let ctx = SessionContext::new();
+        let path = format!("{}/tests/data/example.orc", env!("CARGO_MANIFEST_DIR"));
+
+        let df = ctx
+            .read_orc(path.as_str(), OrcReadOptions::default())
+            .await?;
+        assert_eq!(df.count().await?, 4);
+
+        ctx.register_orc("example", &path, OrcReadOptions::default())
+            .await?;
+        let batches = ctx
+            .sql("SELECT name FROM example WHERE id > 2")
+            .await?
+            .collect()
+            .await?;
+        let expected = [
+            "+------+", "| name |", "+------+", "| ccc  |", "| dd   |", "+------+",
+        ];
+        assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+}
@@ -17,6 +17,7 @@
 
 //! Shared state for query planning and execution.
 
+pub mod apply;
 pub mod context;
 // backwards compatibility
 pub use crate::datasource::file_format::options;
@@ -907,6 +907,36 @@ fn add_hash_on_top(
     Ok(input)
 }
 
+/// Returns true if `plan` requires several of its `children` to be hash
+/// partitioned, and all of them already are, into the same number of
+/// partitions.
+///
+/// Such children are co-partitioned: rows with equal keys are in partitions
+/// with the same index, as long as all of them are partitioned with the hash
+/// function of [`RepartitionExec`]. This is the case for the outputs of hash
+/// repartitions, and for tables which declare they were bucketed that way.
+/// They can then be joined partition by partition, even when there are fewer
+/// partitions than `target_partitions`, instead of shuffling both of them.
+fn is_co_partitioned(
+    plan: &Arc<dyn ExecutionPlan>,
+    children: &[DistributionContext],
+) -> bool {
+    let mut partition_counts = vec![];
+    for (requirement, child) in plan.required_input_distribution().iter().zip(children) {
+        if !matches!(requirement, Distribution::HashPartitioned(_)) {
+            continue;
+        }
+        let partitioning = child.plan.output_partitioning();
+        if !matches!(partitioning, Partitioning::Hash(_, _))
+            || !partitioning.satisfy(requirement, child.plan.equivalence_properties())
+        {
+            return false;
+        }
+        partition_counts.push(partitioning.partition_count());
+    }
+    partition_counts.len() > 1 && partition_counts.windows(2).all(|w| w[0] == w[1])
+}
+
 /// Adds a [`SortPreservingMergeExec`] operator on top of input executor
 /// to satisfy single distribution requirement.
 ///
@@ -1080,6 +1110,10 @@ fn ensure_distribution(
         }
     };
 
+    // Inputs that are already hash partitioned on their keys into the same
+    // number of partitions, e.g. bucketed tables, are kept as they are.
+    let co_partitioned = is_co_partitioned(&plan, &children);
+
     // This loop iterates over all the children to:
     // - Increase parallelism for every child if it is beneficial.
     // - Satisfy the distribution requirements of every child, if it is not
@@ -1111,9 +1145,15 @@ fn ensure_distribution(
                 // Unless partitioning increases the partition count, it is not beneficial:
                 && child.plan.output_partitioning().partition_count() < target_partitions;
 
+            let keep_partitioning =
+                co_partitioned && matches!(requirement, Distribution::HashPartitioned(_));
+
             // When `repartition_file_scans` is set, attempt to increase
             // parallelism at the source.
-            if repartition_file_scans && repartition_beneficial_stats {
+            if repartition_file_scans
+                && repartition_beneficial_stats
+                && !keep_partitioning
+            {
                 if let Some(new_child) =
                     child.plan.repartitioned(target_partitions, config)?
                 {
@@ -1126,6 +1166,7 @@ fn ensure_distribution(
                 Distribution::SinglePartition => {
                     child = add_spm_on_top(child);
                 }
+                Distribution::HashPartitioned(_) if keep_partitioning => {}
                 Distribution::HashPartitioned(exprs) => {
                     if add_roundrobin {
                         // Add round-robin repartitioning on top of the operator
@@ -3745,6 +3786,43 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    #[test]
+    /// Compressed csv files are distributed whole across partitions
+    fn parallelization_compressed_csv_multiple_files() -> Result<()> {
+        let plan = aggregate_exec_with_alias(
+            Arc::new(CsvExec::new(
+                FileScanConfig {
+                    object_store_url: ObjectStoreUrl::parse("test:///").unwrap(),
+                    file_schema: schema(),
+                    file_groups: vec![vec![
+                        PartitionedFile::new("x".to_string(), 100),
+                        PartitionedFile::new("y".to_string(), 100),
+                    ]],
+                    statistics: Statistics::new_unknown(&schema()),
+                    projection: None,
+                    limit: None,
+                    table_partition_cols: vec![],
+                    output_ordering: vec![],
+                },
+                false,
+                b',',
+                b'"',
+                None,
+                FileCompressionType::GZIP,
+            )),
+            vec![("a".to_string(), "a".to_string())],
+        );
+
+        let expected = [
+            "AggregateExec: mode=FinalPartitioned, gby=[a@0 as a], aggr=[]",
+            "RepartitionExec: partitioning=Hash([a@0], 2), input_partitions=2",
+            "AggregateExec: mode=Partial, gby=[a@0 as a], aggr=[]",
+            "CsvExec: file_groups={2 groups: [[x], [y]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_optimized!(expected, plan, true, false, 2, true, 10);
+        Ok(())
+    }
+
     #[test]
     fn parallelization_two_partitions() -> Result<()> {
         let alias = vec![("a".to_string(), "a".to_string())];
@@ -1048,6 +1048,35 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_remove_unnecessary_sort_right_hash_join() -> Result<()> {
+        let left_schema = create_test_schema2()?;
+        let right_schema = create_test_schema3()?;
+        let left_input = memory_exec(&left_schema);
+        let parquet_sort_exprs = vec![sort_expr("a", &right_schema)];
+        let right_input = parquet_exec_sorted(&right_schema, parquet_sort_exprs);
+
+        let on = vec![(
+            Arc::new(Column::new_with_schema("col_a", &left_schema)?) as _,
+            Arc::new(Column::new_with_schema("c", &right_schema)?) as _,
+        )];
+        let join = hash_join_exec(left_input, right_input, on, None, &JoinType::Right)?;
+        let physical_plan = sort_exec(vec![sort_expr("a", &join.schema())], join);
+
+        // The probe (right) side ordering is preserved by right joins
+        let expected_input = ["SortExec: expr=[a@2 ASC]",
+            "  HashJoinExec: mode=Partitioned, join_type=Right, on=[(col_a@0, c@2)]",
+            "    MemoryExec: partitions=1, partition_sizes=[0]",
+            "    ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], output_ordering=[a@0 ASC]"];
+
+        let expected_optimized = ["HashJoinExec: mode=Partitioned, join_type=Right, on=[(col_a@0, c@2)]",
+            "  MemoryExec: partitions=1, partition_sizes=[0]",
+            "  ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], output_ordering=[a@0 ASC]"];
+        assert_optimized!(expected_input, expected_optimized, physical_plan, true);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_remove_unnecessary_spm1() -> Result<()> {
         let schema = create_test_schema()?;
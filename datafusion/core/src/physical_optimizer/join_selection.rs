@@ -30,17 +30,18 @@ use crate::error::Result;
 use crate::physical_optimizer::PhysicalOptimizerRule;
 use crate::physical_plan::joins::utils::{ColumnIndex, JoinFilter};
 use crate::physical_plan::joins::{
-    CrossJoinExec, HashJoinExec, PartitionMode, StreamJoinPartitionMode,
-    SymmetricHashJoinExec,
+    AdaptiveJoinExec, CrossJoinExec, HashJoinExec, MultiHashJoinExec, PartitionMode,
+    StreamJoinPartitionMode, SymmetricHashJoinExec,
 };
 use crate::physical_plan::projection::ProjectionExec;
 use crate::physical_plan::{ExecutionPlan, ExecutionPlanProperties};
 
 use arrow_schema::Schema;
 use datafusion_common::tree_node::{Transformed, TransformedResult, TreeNode};
-use datafusion_common::{internal_err, JoinSide, JoinType};
+use datafusion_common::{internal_err, JoinSide, JoinStrategyHint, JoinType};
 use datafusion_physical_expr::expressions::Column;
 use datafusion_physical_expr::sort_properties::SortProperties;
+use datafusion_physical_expr::utils::collect_columns;
 use datafusion_physical_expr::{PhysicalExpr, PhysicalSortExpr};
 
 /// The [`JoinSelection`] rule tries to modify a given plan so that it can
@@ -180,7 +181,8 @@ fn swap_hash_join(
         ),
         partition_mode,
         hash_join.null_equals_null(),
-    )?;
+    )?
+    .with_strategy_hint(hash_join.strategy_hint().map(|hint| hint.swap()));
     if matches!(
         hash_join.join_type(),
         JoinType::LeftSemi
@@ -270,7 +272,9 @@ impl PhysicalOptimizerRule for JoinSelection {
         // - For a hash join with partition mode [`PartitionMode::Auto`], we will
         //   make a cost-based decision to select which `PartitionMode` mode
         //   (`Partitioned`/`CollectLeft`) is optimal. If the statistics information
-        //   is not available, we will fall back to [`PartitionMode::Partitioned`].
+        //   is not available, we will fall back to [`PartitionMode::Partitioned`],
+        //   or defer the decision to execution time with an [`AdaptiveJoinExec`]
+        //   when `adaptive_join_selection` is enabled.
         // - We optimize/swap join sides so that the left (build) side of the join
         //   is the small side. If the statistics information is not available, we
         //   do not modify join sides.
@@ -279,15 +283,23 @@ impl PhysicalOptimizerRule for JoinSelection {
         let config = &config.optimizer;
         let collect_threshold_byte_size = config.hash_join_single_partition_threshold;
         let collect_threshold_num_rows = config.hash_join_single_partition_threshold_rows;
-        new_plan
+        let adaptive = config.adaptive_join_selection;
+        let new_plan = new_plan
             .transform_up(&|plan| {
                 statistical_join_selection_subrule(
                     plan,
                     collect_threshold_byte_size,
                     collect_threshold_num_rows,
+                    adaptive,
                 )
             })
-            .data()
+            .data()?;
+        // Finally, chains of inner hash joins in `CollectLeft` mode on columns
+        // of the same probe input are merged into a [`MultiHashJoinExec`].
+        if !config.enable_multi_hash_join {
+            return Ok(new_plan);
+        }
+        new_plan.transform_up(&multi_hash_join_subrule).data()
     }
 
     fn name(&self) -> &str {
@@ -390,6 +402,47 @@ fn try_collect_left(
     }
 }
 
+/// Creates an [`AdaptiveJoinExec`] choosing the partition mode of `hash_join`
+/// at execution time, if its build side has no statistics and it can be
+/// executed in [`PartitionMode::CollectLeft`] mode as is.
+fn adaptive_hash_join(
+    hash_join: &HashJoinExec,
+    threshold_byte_size: usize,
+    threshold_num_rows: usize,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    let left = hash_join.left();
+    let right = hash_join.right();
+    let Ok(stats) = left.statistics() else {
+        return Ok(None);
+    };
+    if stats.num_rows.get_value().is_some()
+        || stats.total_byte_size.get_value().is_some()
+        || matches!(
+            hash_join.join_type(),
+            JoinType::Left | JoinType::Full | JoinType::LeftSemi | JoinType::LeftAnti
+        )
+        || left.execution_mode().is_unbounded()
+        || right.execution_mode().is_unbounded()
+    {
+        return Ok(None);
+    }
+    let join = HashJoinExec::try_new(
+        Arc::clone(left),
+        Arc::clone(right),
+        hash_join.on().to_vec(),
+        hash_join.filter().cloned(),
+        hash_join.join_type(),
+        hash_join.projection.clone(),
+        PartitionMode::Partitioned,
+        hash_join.null_equals_null(),
+    )?;
+    Ok(Some(Arc::new(AdaptiveJoinExec::try_new(
+        Arc::new(join),
+        threshold_byte_size,
+        threshold_num_rows,
+    )?)))
+}
+
 fn partitioned_hash_join(hash_join: &HashJoinExec) -> Result<Arc<dyn ExecutionPlan>> {
     let left = hash_join.left();
     let right = hash_join.right();
@@ -410,60 +463,218 @@ fn partitioned_hash_join(hash_join: &HashJoinExec) -> Result<Arc<dyn ExecutionPl
     }
 }
 
+/// Applies the partition mode and build side requested by the strategy hint
+/// of a [`HashJoinExec`], if it has one. Returns `None` when there is no hint
+/// or the hint cannot be honored for the join type, in which case the join
+/// is optimized using statistics as usual.
+fn hinted_hash_join(hash_join: &HashJoinExec) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    let join_type = *hash_join.join_type();
+    let with_mode = |partition_mode| -> Result<Option<Arc<dyn ExecutionPlan>>> {
+        Ok(Some(Arc::new(
+            HashJoinExec::try_new(
+                Arc::clone(hash_join.left()),
+                Arc::clone(hash_join.right()),
+                hash_join.on().to_vec(),
+                hash_join.filter().cloned(),
+                &join_type,
+                hash_join.projection.clone(),
+                partition_mode,
+                hash_join.null_equals_null(),
+            )?
+            .with_strategy_hint(hash_join.strategy_hint()),
+        )))
+    };
+    match hash_join.strategy_hint() {
+        Some(JoinStrategyHint::BroadcastLeft)
+            if !matches!(
+                join_type,
+                JoinType::Left | JoinType::Full | JoinType::LeftAnti
+            ) =>
+        {
+            with_mode(PartitionMode::CollectLeft)
+        }
+        Some(JoinStrategyHint::BroadcastRight)
+            if supports_swap(join_type)
+                && !matches!(
+                    join_type,
+                    JoinType::Right | JoinType::Full | JoinType::RightAnti
+                ) =>
+        {
+            swap_hash_join(hash_join, PartitionMode::CollectLeft).map(Some)
+        }
+        Some(JoinStrategyHint::ShuffleHash) => with_mode(PartitionMode::Partitioned),
+        _ => Ok(None),
+    }
+}
+
+/// This subrule merges an inner [`HashJoinExec`] in [`PartitionMode::CollectLeft`]
+/// mode whose probe side is another such join, or a [`MultiHashJoinExec`],
+/// into a single [`MultiHashJoinExec`], if it joins on columns of the probe
+/// input of its probe side only.
+///
+/// ```text
+///      HashJoinExec                       MultiHashJoinExec
+///       /        \                      /        |        \
+///    dim1    HashJoinExec     ==>     dim1      dim2      fact
+///             /        \
+///          dim2        fact
+/// ```
+fn multi_hash_join_subrule(
+    plan: Arc<dyn ExecutionPlan>,
+) -> Result<Transformed<Arc<dyn ExecutionPlan>>> {
+    let Some(hash_join) = plan
+        .as_any()
+        .downcast_ref::<HashJoinExec>()
+        .filter(|hash_join| is_collected_inner_join(hash_join))
+    else {
+        return Ok(Transformed::no(plan));
+    };
+    let probe = hash_join.right();
+    let (builds, probe_input, on) = if let Some(inner) = probe
+        .as_any()
+        .downcast_ref::<HashJoinExec>()
+        .filter(|inner| is_collected_inner_join(inner))
+    {
+        (
+            vec![Arc::clone(inner.left())],
+            Arc::clone(inner.right()),
+            vec![inner.on().to_vec()],
+        )
+    } else if let Some(multi) = probe.as_any().downcast_ref::<MultiHashJoinExec>() {
+        (
+            multi.builds().to_vec(),
+            Arc::clone(multi.probe()),
+            multi.on().to_vec(),
+        )
+    } else {
+        return Ok(Transformed::no(plan));
+    };
+
+    // The columns of the build sides of the probe side precede the columns
+    // of its probe input, which the join keys must only refer to
+    let offset = probe.schema().fields().len() - probe_input.schema().fields().len();
+    let mut probe_on = Vec::with_capacity(hash_join.on().len());
+    for (build_key, probe_key) in hash_join.on() {
+        if collect_columns(probe_key)
+            .iter()
+            .any(|column| column.index() < offset)
+        {
+            return Ok(Transformed::no(plan));
+        }
+        let probe_key = probe_key
+            .clone()
+            .transform_up(&|expr| {
+                Ok(match expr.as_any().downcast_ref::<Column>() {
+                    Some(column) => Transformed::yes(Arc::new(Column::new(
+                        column.name(),
+                        column.index() - offset,
+                    )) as _),
+                    None => Transformed::no(expr),
+                })
+            })
+            .data()?;
+        probe_on.push((Arc::clone(build_key), probe_key));
+    }
+
+    let builds = [Arc::clone(hash_join.left())].into_iter().chain(builds);
+    let on = [probe_on].into_iter().chain(on);
+    Ok(Transformed::yes(Arc::new(MultiHashJoinExec::try_new(
+        builds.collect(),
+        probe_input,
+        on.collect(),
+    )?)))
+}
+
+/// Returns true if `hash_join` is an inner join without a filter or a
+/// projection, whose build side is collected into a single partition
+fn is_collected_inner_join(hash_join: &HashJoinExec) -> bool {
+    *hash_join.join_type() == JoinType::Inner
+        && *hash_join.partition_mode() == PartitionMode::CollectLeft
+        && hash_join.filter().is_none()
+        && !hash_join.contain_projection()
+        && !hash_join.null_equals_null()
+}
+
 /// This subrule tries to modify a given plan so that it can
 /// optimize hash and cross joins in the plan according to available statistical information.
 fn statistical_join_selection_subrule(
     plan: Arc<dyn ExecutionPlan>,
     collect_threshold_byte_size: usize,
     collect_threshold_num_rows: usize,
+    adaptive: bool,
 ) -> Result<Transformed<Arc<dyn ExecutionPlan>>> {
-    let transformed =
-        if let Some(hash_join) = plan.as_any().downcast_ref::<HashJoinExec>() {
-            match hash_join.partition_mode() {
-                PartitionMode::Auto => try_collect_left(
+    // A null-aware anti join needs its build side collected into a single
+    // hash table, so neither its mode nor its build side can change
+    if let Some(hash_join) = plan.as_any().downcast_ref::<HashJoinExec>() {
+        if hash_join.null_aware() {
+            return Ok(Transformed::no(plan));
+        }
+    }
+    let hinted = plan
+        .as_any()
+        .downcast_ref::<HashJoinExec>()
+        .map(hinted_hash_join)
+        .transpose()?
+        .flatten();
+    let transformed = if hinted.is_some() {
+        hinted
+    } else if let Some(hash_join) = plan.as_any().downcast_ref::<HashJoinExec>() {
+        match hash_join.partition_mode() {
+            PartitionMode::Auto => {
+                let collected = try_collect_left(
                     hash_join,
                     false,
                     collect_threshold_byte_size,
                     collect_threshold_num_rows,
-                )?
+                )?;
+                let adapted = if collected.is_none() && adaptive {
+                    adaptive_hash_join(
+                        hash_join,
+                        collect_threshold_byte_size,
+                        collect_threshold_num_rows,
+                    )?
+                } else {
+                    None
+                };
+                match collected.or(adapted) {
+                    Some(plan) => Some(plan),
+                    None => Some(partitioned_hash_join(hash_join)?),
+                }
+            }
+            PartitionMode::CollectLeft => try_collect_left(hash_join, true, 0, 0)?
                 .map_or_else(
                     || partitioned_hash_join(hash_join).map(Some),
                     |v| Ok(Some(v)),
                 )?,
-                PartitionMode::CollectLeft => try_collect_left(hash_join, true, 0, 0)?
-                    .map_or_else(
-                        || partitioned_hash_join(hash_join).map(Some),
-                        |v| Ok(Some(v)),
-                    )?,
-                PartitionMode::Partitioned => {
-                    let left = hash_join.left();
-                    let right = hash_join.right();
-                    if should_swap_join_order(&**left, &**right)?
-                        && supports_swap(*hash_join.join_type())
-                    {
-                        swap_hash_join(hash_join, PartitionMode::Partitioned).map(Some)?
-                    } else {
-                        None
-                    }
+            PartitionMode::Partitioned => {
+                let left = hash_join.left();
+                let right = hash_join.right();
+                if should_swap_join_order(&**left, &**right)?
+                    && supports_swap(*hash_join.join_type())
+                {
+                    swap_hash_join(hash_join, PartitionMode::Partitioned).map(Some)?
+                } else {
+                    None
                 }
             }
-        } else if let Some(cross_join) = plan.as_any().downcast_ref::<CrossJoinExec>() {
-            let left = cross_join.left();
-            let right = cross_join.right();
-            if should_swap_join_order(&**left, &**right)? {
-                let new_join = CrossJoinExec::new(Arc::clone(right), Arc::clone(left));
-                // TODO avoid adding ProjectionExec again and again, only adding Final Projection
-                let proj: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
-                    swap_reverting_projection(&left.schema(), &right.schema()),
-                    Arc::new(new_join),
-                )?);
-                Some(proj)
-            } else {
-                None
-            }
+        }
+    } else if let Some(cross_join) = plan.as_any().downcast_ref::<CrossJoinExec>() {
+        let left = cross_join.left();
+        let right = cross_join.right();
+        if should_swap_join_order(&**left, &**right)? {
+            let new_join = CrossJoinExec::new(Arc::clone(right), Arc::clone(left));
+            // TODO avoid adding ProjectionExec again and again, only adding Final Projection
+            let proj: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+                swap_reverting_projection(&left.schema(), &right.schema()),
+                Arc::new(new_join),
+            )?);
+            Some(proj)
         } else {
             None
-        };
+        }
+    } else {
+        None
+    };
 
     Ok(if let Some(transformed) = transformed {
         Transformed::yes(transformed)
@@ -500,7 +711,7 @@ fn hash_join_convert_symmetric_subrule(
         let left_unbounded = hash_join.left.execution_mode().is_unbounded();
         let right_unbounded = hash_join.right.execution_mode().is_unbounded();
         // Process only if both left and right sides are unbounded.
-        if left_unbounded && right_unbounded {
+        if left_unbounded && right_unbounded && !hash_join.null_aware() {
             // Determine the partition mode based on configuration.
             let mode = if config_options.optimizer.repartition_joins {
                 StreamJoinPartitionMode::Partitioned
@@ -853,6 +1064,7 @@ mod tests_statistical {
                 plan,
                 collect_left_threshold,
                 collect_threshold_num_rows,
+                config.adaptive_join_selection,
             )
         })?;
         Ok(())
@@ -915,6 +1127,121 @@ mod tests_statistical {
         crosscheck_plans(join.clone()).unwrap();
     }
 
+    #[tokio::test]
+    async fn test_join_with_strategy_hint() {
+        let (big, small) = create_big_and_small();
+        let hinted_join = |left: &Arc<dyn ExecutionPlan>,
+                           right: &Arc<dyn ExecutionPlan>,
+                           hint| {
+            let (left_col, right_col) = if Arc::ptr_eq(left, &big) {
+                ("big_col", "small_col")
+            } else {
+                ("small_col", "big_col")
+            };
+            let join: Arc<dyn ExecutionPlan> = Arc::new(
+                HashJoinExec::try_new(
+                    Arc::clone(left),
+                    Arc::clone(right),
+                    vec![(
+                        Arc::new(
+                            Column::new_with_schema(left_col, &left.schema()).unwrap(),
+                        ),
+                        Arc::new(
+                            Column::new_with_schema(right_col, &right.schema()).unwrap(),
+                        ),
+                    )],
+                    None,
+                    &JoinType::Inner,
+                    None,
+                    PartitionMode::Auto,
+                    false,
+                )
+                .unwrap()
+                .with_strategy_hint(Some(hint)),
+            );
+            JoinSelection::new()
+                .optimize(join, &ConfigOptions::new())
+                .unwrap()
+        };
+
+        // the big side is collected although it is over the threshold
+        let optimized = hinted_join(&big, &small, JoinStrategyHint::BroadcastLeft);
+        let join = optimized.as_any().downcast_ref::<HashJoinExec>().unwrap();
+        assert_eq!(*join.partition_mode(), PartitionMode::CollectLeft);
+        assert_col_expr(&join.on()[0].0, "big_col", 0);
+
+        // the sides are not swapped although the left side is bigger
+        let optimized = hinted_join(&big, &small, JoinStrategyHint::ShuffleHash);
+        let join = optimized.as_any().downcast_ref::<HashJoinExec>().unwrap();
+        assert_eq!(*join.partition_mode(), PartitionMode::Partitioned);
+        assert_col_expr(&join.on()[0].0, "big_col", 0);
+
+        // the big side is swapped to the build side
+        let optimized = hinted_join(&small, &big, JoinStrategyHint::BroadcastRight);
+        let swapping_projection = optimized
+            .as_any()
+            .downcast_ref::<ProjectionExec>()
+            .expect("A proj is required to swap columns back to their original order");
+        let join = swapping_projection
+            .input()
+            .as_any()
+            .downcast_ref::<HashJoinExec>()
+            .unwrap();
+        assert_eq!(*join.partition_mode(), PartitionMode::CollectLeft);
+        assert_eq!(join.strategy_hint(), Some(JoinStrategyHint::BroadcastLeft));
+        assert_col_expr(&join.on()[0].0, "big_col", 0);
+    }
+
+    #[tokio::test]
+    async fn test_adaptive_join_selection() {
+        let (big, _) = create_big_and_small();
+        let empty: Arc<dyn ExecutionPlan> = Arc::new(StatisticsExec::new(
+            empty_statistics(),
+            Schema::new(vec![Field::new("empty_col", DataType::Int32, false)]),
+        ));
+        let join_selection = |left: &Arc<dyn ExecutionPlan>,
+                              right: &Arc<dyn ExecutionPlan>,
+                              adaptive: bool| {
+            let col = |plan: &Arc<dyn ExecutionPlan>| -> PhysicalExprRef {
+                let name = plan.schema().field(0).name().clone();
+                Arc::new(Column::new_with_schema(&name, &plan.schema()).unwrap())
+            };
+            let join = Arc::new(
+                HashJoinExec::try_new(
+                    Arc::clone(left),
+                    Arc::clone(right),
+                    vec![(col(left), col(right))],
+                    None,
+                    &JoinType::Inner,
+                    None,
+                    PartitionMode::Auto,
+                    false,
+                )
+                .unwrap(),
+            );
+            let mut config = ConfigOptions::new();
+            config.optimizer.adaptive_join_selection = adaptive;
+            JoinSelection::new().optimize(join, &config).unwrap()
+        };
+
+        // the build side has no statistics
+        let optimized = join_selection(&empty, &big, true);
+        let adaptive = optimized
+            .as_any()
+            .downcast_ref::<AdaptiveJoinExec>()
+            .expect("The join should be adaptive");
+        assert_col_expr(&adaptive.join().on()[0].0, "empty_col", 0);
+
+        let optimized = join_selection(&empty, &big, false);
+        let join = optimized.as_any().downcast_ref::<HashJoinExec>().unwrap();
+        assert_eq!(*join.partition_mode(), PartitionMode::Partitioned);
+
+        // the statistics of the build side show it is too big to collect
+        let optimized = join_selection(&big, &empty, true);
+        let join = optimized.as_any().downcast_ref::<HashJoinExec>().unwrap();
+        assert_eq!(*join.partition_mode(), PartitionMode::Partitioned);
+    }
+
     #[tokio::test]
     async fn test_left_join_with_swap() {
         let (big, small) = create_big_and_small();
@@ -31,10 +31,13 @@ pub mod limited_distinct_aggregation;
 pub mod optimizer;
 pub mod output_requirements;
 pub mod pipeline_checker;
+pub mod point_query;
 mod projection_pushdown;
 pub mod pruning;
 pub mod replace_with_order_preserving_variants;
+pub mod reuse_scans;
 mod sort_pushdown;
+pub mod sorted_distinct;
 pub mod topk_aggregation;
 mod utils;
 
@@ -30,6 +30,8 @@ use crate::physical_optimizer::join_selection::JoinSelection;
 use crate::physical_optimizer::limited_distinct_aggregation::LimitedDistinctAggregation;
 use crate::physical_optimizer::output_requirements::OutputRequirements;
 use crate::physical_optimizer::pipeline_checker::PipelineChecker;
+use crate::physical_optimizer::reuse_scans::ReuseScans;
+use crate::physical_optimizer::sorted_distinct::SortedDistinct;
 use crate::physical_optimizer::topk_aggregation::TopKAggregation;
 use crate::{error::Result, physical_plan::ExecutionPlan};
 
@@ -63,6 +65,9 @@ pub trait PhysicalOptimizerRule {
 pub struct PhysicalOptimizer {
     /// All rules to apply
     pub rules: Vec<Arc<dyn PhysicalOptimizerRule + Send + Sync>>,
+    /// The recommended rules created by [`Self::new`], which are told apart
+    /// from other rules with the same name by their address
+    built_in: Vec<Arc<dyn PhysicalOptimizerRule + Send + Sync>>,
 }
 
 impl Default for PhysicalOptimizer {
@@ -101,6 +106,10 @@ impl PhysicalOptimizer {
             // Note that one should always run this rule after running the EnforceDistribution rule
             // as the latter may break local sorting requirements.
             Arc::new(EnforceSorting::new()),
+            // The SortedDistinct rule replaces aggregations which only deduplicate sorted
+            // input. It should run after the EnforceSorting rule, as the input ordering of
+            // the aggregations is only final then.
+            Arc::new(SortedDistinct::new()),
             // TODO: `try_embed_to_hash_join` in the ProjectionPushdown rule would be block by the CoalesceBatches, so add it before CoalesceBatches. Maybe optimize it in the future.
             Arc::new(ProjectionPushdown::new()),
             // The CoalesceBatches rule will not influence the distribution and ordering of the
@@ -126,13 +135,38 @@ impl PhysicalOptimizer {
             // are not present, the load of executors such as join or union will be
             // reduced by narrowing their input tables.
             Arc::new(ProjectionPushdown::new()),
+            // The ReuseScans rule shares identical scans between their occurrences
+            // in the plan. It should run last, as the scans must not be changed
+            // anymore once they are shared.
+            Arc::new(ReuseScans::new()),
         ];
 
-        Self::with_rules(rules)
+        Self {
+            built_in: rules.clone(),
+            rules,
+        }
     }
 
-    /// Create a new optimizer with the given rules
+    /// Create a new optimizer with the given rules, none of which are
+    /// considered built-in
     pub fn with_rules(rules: Vec<Arc<dyn PhysicalOptimizerRule + Send + Sync>>) -> Self {
-        Self { rules }
+        Self {
+            rules,
+            built_in: vec![],
+        }
+    }
+
+    /// Returns true if `rule` is one of the recommended rules created by
+    /// [`Self::new`], rather than a rule added to the optimizer later
+    pub fn is_built_in(
+        &self,
+        rule: &Arc<dyn PhysicalOptimizerRule + Send + Sync>,
+    ) -> bool {
+        self.built_in.iter().any(|built_in| {
+            std::ptr::eq(
+                Arc::as_ptr(built_in) as *const u8,
+                Arc::as_ptr(rule) as *const u8,
+            )
+        })
     }
 }
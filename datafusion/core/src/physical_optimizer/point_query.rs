@@ -0,0 +1,275 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Detection of point queries, for which the built-in physical optimizer
+//! rules are skipped when `datafusion.optimizer.point_query_fast_path` is
+//! enabled
+
+use std::sync::Arc;
+
+#[cfg(feature = "parquet")]
+use crate::datasource::physical_plan::ParquetExec;
+use crate::datasource::physical_plan::{
+    ArrowExec, AvroExec, CsvExec, FileScanConfig, NdJsonExec, OrcExec,
+};
+use crate::physical_plan::filter::FilterExec;
+use crate::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
+use crate::physical_plan::projection::ProjectionExec;
+use crate::physical_plan::ExecutionPlan;
+
+use datafusion_expr::Operator;
+use datafusion_physical_expr::expressions::{BinaryExpr, Column, Literal};
+use datafusion_physical_expr::split_conjunction;
+use datafusion_physical_expr::PhysicalExpr;
+
+/// Returns true if `plan` is a point query: a scan of a single file, or of
+/// an in memory table with one partition, whose rows are only filtered by
+/// comparisons of columns to literals, projected and limited. Parquet files
+/// must also prune their row groups with the predicate pushed into the scan.
+///
+/// Such plans are executable as planned. None of the built-in physical
+/// optimizer rules could improve them much, as there are no joins, aggregations or
+/// sorts and the single partition can't be read in parallel, so they only
+/// add to the planning latency.
+pub fn is_point_query(plan: &Arc<dyn ExecutionPlan>) -> bool {
+    let any = plan.as_any();
+    if let Some(filter) = any.downcast_ref::<FilterExec>() {
+        split_conjunction(filter.predicate())
+            .into_iter()
+            .all(is_simple_predicate)
+            && is_point_query(filter.input())
+    } else if let Some(projection) = any.downcast_ref::<ProjectionExec>() {
+        is_point_query(projection.input())
+    } else if let Some(limit) = any.downcast_ref::<GlobalLimitExec>() {
+        is_point_query(limit.input())
+    } else if let Some(limit) = any.downcast_ref::<LocalLimitExec>() {
+        is_point_query(limit.input())
+    } else {
+        is_single_scan(plan)
+    }
+}
+
+/// Returns true if `plan` is a scan that only reads a single file, or a
+/// single partition of another source
+fn is_single_scan(plan: &Arc<dyn ExecutionPlan>) -> bool {
+    if !plan.children().is_empty() || plan.output_partitioning().partition_count() != 1 {
+        return false;
+    }
+    let any = plan.as_any();
+    #[cfg(feature = "parquet")]
+    if let Some(parquet) = any.downcast_ref::<ParquetExec>() {
+        return is_single_file(parquet.base_config())
+            && parquet.pruning_predicate().is_some();
+    }
+    if let Some(csv) = any.downcast_ref::<CsvExec>() {
+        is_single_file(csv.base_config())
+    } else if let Some(json) = any.downcast_ref::<NdJsonExec>() {
+        is_single_file(json.base_config())
+    } else if let Some(avro) = any.downcast_ref::<AvroExec>() {
+        is_single_file(avro.base_config())
+    } else if let Some(arrow) = any.downcast_ref::<ArrowExec>() {
+        is_single_file(arrow.base_config())
+    } else if let Some(orc) = any.downcast_ref::<OrcExec>() {
+        is_single_file(orc.base_config())
+    } else {
+        true
+    }
+}
+
+/// Returns true if `config` scans a single file as a whole
+fn is_single_file(config: &FileScanConfig) -> bool {
+    match config.file_groups.as_slice() {
+        [files] => matches!(files.as_slice(), [file] if file.range.is_none()),
+        _ => false,
+    }
+}
+
+/// Returns true if `expr` compares a column to a literal
+fn is_simple_predicate(expr: &Arc<dyn PhysicalExpr>) -> bool {
+    let Some(binary) = expr.as_any().downcast_ref::<BinaryExpr>() else {
+        return false;
+    };
+    let is_column = |e: &Arc<dyn PhysicalExpr>| e.as_any().is::<Column>();
+    let is_literal = |e: &Arc<dyn PhysicalExpr>| e.as_any().is::<Literal>();
+    matches!(
+        binary.op(),
+        Operator::Eq
+            | Operator::NotEq
+            | Operator::Lt
+            | Operator::LtEq
+            | Operator::Gt
+            | Operator::GtEq
+    ) && ((is_column(binary.left()) && is_literal(binary.right()))
+        || (is_literal(binary.left()) && is_column(binary.right())))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::config::ConfigOptions;
+    use crate::datasource::listing::PartitionedFile;
+    use crate::datasource::object_store::ObjectStoreUrl;
+    use crate::datasource::MemTable;
+    use crate::error::Result;
+    use crate::physical_optimizer::PhysicalOptimizerRule;
+    use crate::physical_plan::{collect, displayable};
+    use crate::prelude::{SessionConfig, SessionContext};
+
+    use arrow::array::Int64Array;
+    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::record_batch::RecordBatch;
+    use datafusion_common::{assert_batches_eq, ScalarValue, Statistics};
+    use datafusion_physical_expr::expressions::{col, lit};
+
+    fn context(fast_path: bool) -> Result<SessionContext> {
+        // batches smaller than the table, so that repartitioning it pays off
+        let mut config = SessionConfig::new()
+            .with_target_partitions(4)
+            .with_batch_size(2);
+        config.options_mut().optimizer.point_query_fast_path = fast_path;
+        let ctx = SessionContext::new_with_config(config);
+
+        let batch = RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(Int64Array::from(vec![1, 2, 3])) as _),
+            ("b", Arc::new(Int64Array::from(vec![10, 20, 30])) as _),
+        ])?;
+        let table = MemTable::try_new(batch.schema(), vec![vec![batch]])?;
+        ctx.register_table("t", Arc::new(table))?;
+        Ok(ctx)
+    }
+
+    async fn physical_plan(
+        ctx: &SessionContext,
+        sql: &str,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        ctx.sql(sql).await?.create_physical_plan().await
+    }
+
+    #[tokio::test]
+    async fn skip_optimizer_for_point_query() -> Result<()> {
+        let sql = "SELECT b FROM t WHERE a = 2 LIMIT 1";
+
+        let plan = physical_plan(&context(false)?, sql).await?;
+        let formatted = displayable(plan.as_ref()).indent(true).to_string();
+        assert!(formatted.contains("RepartitionExec"), "{formatted}");
+
+        let ctx = context(true)?;
+        let plan = physical_plan(&ctx, sql).await?;
+        assert!(is_point_query(&plan));
+        let formatted = displayable(plan.as_ref()).indent(true).to_string();
+        assert!(!formatted.contains("RepartitionExec"), "{formatted}");
+        assert!(!formatted.contains("CoalesceBatchesExec"), "{formatted}");
+
+        let batches = collect(plan, ctx.task_ctx()).await?;
+        let expected = ["+----+", "| b  |", "+----+", "| 20 |", "+----+"];
+        assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn optimize_other_queries() -> Result<()> {
+        let ctx = context(true)?;
+        for sql in [
+            "SELECT b FROM t WHERE a + 1 = 2",
+            "SELECT b FROM t WHERE a = 2 OR b = 10",
+            "SELECT a, count(*) FROM t GROUP BY a",
+            "SELECT b FROM t ORDER BY a",
+        ] {
+            let plan = physical_plan(&ctx, sql).await?;
+            assert!(!is_point_query(&plan), "{sql}");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn point_query_of_pruned_file() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let predicate: Arc<dyn PhysicalExpr> = Arc::new(BinaryExpr::new(
+            col("a", &schema)?,
+            Operator::Eq,
+            lit(ScalarValue::Int64(Some(2))),
+        ));
+        let scan = |files: Vec<&str>, predicate: Option<Arc<dyn PhysicalExpr>>| {
+            let files = files
+                .into_iter()
+                .map(|name| PartitionedFile::new(name.to_string(), 100))
+                .collect();
+            let config = FileScanConfig {
+                object_store_url: ObjectStoreUrl::parse("test:///").unwrap(),
+                file_schema: schema.clone(),
+                file_groups: vec![files],
+                statistics: Statistics::new_unknown(&schema),
+                projection: None,
+                limit: None,
+                table_partition_cols: vec![],
+                output_ordering: vec![],
+            };
+            let parquet = ParquetExec::new(config, predicate, None, Default::default());
+            Arc::new(parquet) as Arc<dyn ExecutionPlan>
+        };
+
+        assert!(is_point_query(&scan(vec!["x"], Some(predicate.clone()))));
+        // the row groups of the file are not pruned
+        assert!(!is_point_query(&scan(vec!["x"], None)));
+        // several files are read by the single partition
+        assert!(!is_point_query(&scan(vec!["x", "y"], Some(predicate))));
+        Ok(())
+    }
+
+    /// Counts the plans it optimizes
+    struct CountingRule(&'static str, AtomicUsize);
+
+    impl PhysicalOptimizerRule for CountingRule {
+        fn optimize(
+            &self,
+            plan: Arc<dyn ExecutionPlan>,
+            _config: &ConfigOptions,
+        ) -> Result<Arc<dyn ExecutionPlan>> {
+            self.1.fetch_add(1, Ordering::Relaxed);
+            Ok(plan)
+        }
+
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn schema_check(&self) -> bool {
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn run_session_rules_for_point_query() -> Result<()> {
+        // the second rule shares the name of a built-in rule
+        for name in ["counting_rule", "coalesce_batches"] {
+            let rule = Arc::new(CountingRule(name, AtomicUsize::new(0)));
+            let state = context(true)?
+                .state()
+                .add_physical_optimizer_rule(rule.clone());
+            let ctx = SessionContext::new_with_state(state);
+
+            let plan = physical_plan(&ctx, "SELECT b FROM t WHERE a = 2 LIMIT 1").await?;
+            let formatted = displayable(plan.as_ref()).indent(true).to_string();
+            assert!(!formatted.contains("RepartitionExec"), "{formatted}");
+            assert_eq!(rule.1.load(Ordering::Relaxed), 1, "{name}");
+        }
+        Ok(())
+    }
+}
@@ -26,6 +26,8 @@ use std::sync::Arc;
 use super::output_requirements::OutputRequirementExec;
 use super::PhysicalOptimizerRule;
 use crate::datasource::physical_plan::CsvExec;
+#[cfg(feature = "parquet")]
+use crate::datasource::physical_plan::ParquetExec;
 use crate::error::Result;
 use crate::physical_plan::coalesce_partitions::CoalescePartitionsExec;
 use crate::physical_plan::filter::FilterExec;
@@ -104,6 +106,11 @@ pub fn remove_unnecessary_projections(
         }
         // If it does, check if we can push it under its child(ren):
         let input = projection.input().as_any();
+        #[cfg(feature = "parquet")]
+        if let Some(parquet) = input.downcast_ref::<ParquetExec>() {
+            let maybe_pruned = try_pruning_parquet_struct_fields(projection, parquet)?;
+            return Ok(maybe_pruned.map_or(Transformed::no(plan), Transformed::yes));
+        }
         if let Some(csv) = input.downcast_ref::<CsvExec>() {
             try_swapping_with_csv(projection, csv)
         } else if let Some(memory) = input.downcast_ref::<MemoryExec>() {
@@ -190,6 +197,91 @@ fn try_swapping_with_csv(
     })
 }
 
+/// Tries to restrict the struct columns read by `parquet` to the fields that
+/// `projection` accesses with `get_field`. If possible, returns `projection`
+/// on top of the new [`ParquetExec`]. Otherwise, returns `None`.
+#[cfg(feature = "parquet")]
+fn try_pruning_parquet_struct_fields(
+    projection: &ProjectionExec,
+    parquet: &ParquetExec,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    if !parquet.struct_field_projection().is_empty() {
+        return Ok(None);
+    }
+
+    let mut paths = HashMap::<usize, Vec<Vec<String>>>::new();
+    for (expr, _) in projection.expr() {
+        expr.apply(&mut |expr| {
+            Ok(match struct_field_path(expr) {
+                Some((index, path)) => {
+                    paths.entry(index).or_default().push(path);
+                    TreeNodeRecursion::Jump
+                }
+                None => TreeNodeRecursion::Continue,
+            })
+        })?;
+    }
+
+    let base_config = parquet.base_config();
+    let file_schema = &base_config.file_schema;
+    let struct_field_projection = paths
+        .into_iter()
+        .filter_map(|(index, paths)| {
+            let index = match &base_config.projection {
+                Some(projection) => projection[index],
+                None => index,
+            };
+            // partition columns and entirely read columns are not pruned
+            let is_struct = index < file_schema.fields().len()
+                && matches!(
+                    file_schema.field(index).data_type(),
+                    arrow_schema::DataType::Struct(_)
+                );
+            let reads_all = paths.iter().any(|path| path.is_empty());
+            (is_struct && !reads_all)
+                .then(|| (index, paths.into_iter().unique().collect()))
+        })
+        .collect::<std::collections::BTreeMap<_, _>>();
+    if struct_field_projection.is_empty() {
+        return Ok(None);
+    }
+
+    let parquet = parquet
+        .clone()
+        .with_struct_field_projection(struct_field_projection);
+    ProjectionExec::try_new(projection.expr().to_vec(), Arc::new(parquet))
+        .map(|e| Some(Arc::new(e) as _))
+}
+
+/// Returns the column index and field path of `expr` if it is a column, or a
+/// chain of `get_field` calls on a column such as `get_field(get_field(s@0,
+/// 'a'), 'b')`. The path of a column is empty.
+#[cfg(feature = "parquet")]
+fn struct_field_path(expr: &Arc<dyn PhysicalExpr>) -> Option<(usize, Vec<String>)> {
+    if let Some(column) = expr.as_any().downcast_ref::<Column>() {
+        return Some((column.index(), vec![]));
+    }
+    let func = expr
+        .as_any()
+        .downcast_ref::<datafusion_physical_expr::ScalarFunctionExpr>()?;
+    let [inner, field] = func.args() else {
+        return None;
+    };
+    let Some(datafusion_common::ScalarValue::Utf8(Some(name))) = field
+        .as_any()
+        .downcast_ref::<Literal>()
+        .map(|literal| literal.value())
+    else {
+        return None;
+    };
+    if func.name() != "get_field" {
+        return None;
+    }
+    let (index, mut path) = struct_field_path(inner)?;
+    path.push(name.clone());
+    Some((index, path))
+}
+
 /// Tries to embed `projection` to its input (`memory`). If possible, returns
 /// [`MemoryExec`] as the top plan. Otherwise, returns `None`.
 fn try_swapping_with_memory(
@@ -665,16 +757,19 @@ fn try_pushdown_through_hash_join(
         hash_join.right(),
     )?;
 
-    Ok(Some(Arc::new(HashJoinExec::try_new(
-        Arc::new(new_left),
-        Arc::new(new_right),
-        new_on,
-        new_filter,
-        hash_join.join_type(),
-        hash_join.projection.clone(),
-        *hash_join.partition_mode(),
-        hash_join.null_equals_null,
-    )?)))
+    Ok(Some(Arc::new(
+        HashJoinExec::try_new(
+            Arc::new(new_left),
+            Arc::new(new_right),
+            new_on,
+            new_filter,
+            hash_join.join_type(),
+            hash_join.projection.clone(),
+            *hash_join.partition_mode(),
+            hash_join.null_equals_null,
+        )?
+        .with_null_aware(hash_join.null_aware())?,
+    )))
 }
 
 /// Tries to swap the projection with its input [`CrossJoinExec`]. If it can be done,
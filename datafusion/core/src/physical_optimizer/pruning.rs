@@ -336,6 +336,7 @@ pub trait PruningStatistics {
 /// `x = 5 AND y = 10` | `CASE WHEN x_null_count = x_row_count THEN false ELSE x_min <= 5 AND 5 <= x_max END AND CASE WHEN y_null_count = y_row_count THEN false ELSE y_min <= 10 AND 10 <= y_max END`
 /// `x IS NULL`  | `x_null_count > 0`
 /// `CAST(x as int) = 5` | `CASE WHEN x_null_count = x_row_count THEN false ELSE CAST(x_min as int) <= 5 AND 5 <= CAST(x_max as int) END`
+/// `x + 1 > 5` | `CASE WHEN x_null_count = x_row_count THEN false ELSE x_max > 4 END OR CASE WHEN x_null_count = x_row_count THEN false ELSE x_max > 2147483646 END`
 ///
 /// ## Predicate Evaluation
 /// The PruningPredicate works in two passes
@@ -1131,6 +1132,145 @@ fn rewrite_expr_to_prunable(
     }
 }
 
+/// Rewrites the comparison of an integer expression plus or minus a literal
+/// to a literal, such as `col + 1 > 5`, to a comparison of the expression
+/// itself, `col > 4`, so that it can be pruned on the statistics of `col`.
+///
+/// As integer arithmetic wraps around on overflow, the expression is only
+/// rewritten when the shifted literal does not overflow, and containers with
+/// values for which the arithmetic may overflow are not pruned:
+/// `col + 1 > 5` is rewritten to `col > 4 OR col > i64::MAX - 1`.
+///
+/// Returns `None` if the comparison is not of this form.
+fn rewrite_offset_comparison(
+    left: &Arc<dyn PhysicalExpr>,
+    op: Operator,
+    right: &Arc<dyn PhysicalExpr>,
+    schema: &Schema,
+) -> Option<Arc<dyn PhysicalExpr>> {
+    if !is_compare_op(op) {
+        return None;
+    }
+    let literal = |expr: &Arc<dyn PhysicalExpr>| {
+        expr.as_any()
+            .downcast_ref::<phys_expr::Literal>()
+            .map(|literal| literal.value().clone())
+    };
+    let binary = |expr: &Arc<dyn PhysicalExpr>| {
+        expr.as_any()
+            .downcast_ref::<phys_expr::BinaryExpr>()
+            .cloned()
+    };
+    let (binary, op, value) = match (binary(left), literal(right)) {
+        (Some(binary), Some(value)) => (binary, op, value),
+        _ => match (binary(right), literal(left)) {
+            (Some(binary), Some(value)) => (binary, op.swap()?, value),
+            _ => return None,
+        },
+    };
+
+    // `expr + offset`, `offset + expr` or `expr - offset`
+    let (expr, offset, subtract) =
+        match (binary.op(), literal(binary.left()), literal(binary.right())) {
+            (Operator::Plus, None, Some(offset)) => (binary.left(), offset, false),
+            (Operator::Plus, Some(offset), None) => (binary.right(), offset, false),
+            (Operator::Minus, None, Some(offset)) => (binary.left(), offset, true),
+            _ => return None,
+        };
+    let data_type = expr.data_type(schema).ok()?;
+    let (min, max) = integer_bounds(&data_type)?;
+    if value.is_null()
+        || offset.is_null()
+        || value.data_type() != data_type
+        || offset.data_type() != data_type
+    {
+        return None;
+    }
+
+    // `expr + offset op value` => `expr op value - offset`, and
+    // `expr - offset op value` => `expr op value + offset`
+    let shifted = if subtract {
+        value.add_checked(&offset)
+    } else {
+        value.sub_checked(&offset)
+    }
+    .ok()?;
+    let comparison: Arc<dyn PhysicalExpr> = Arc::new(phys_expr::BinaryExpr::new(
+        expr.clone(),
+        op,
+        Arc::new(phys_expr::Literal::new(shifted)),
+    ));
+
+    // the values of `expr` for which the arithmetic overflows
+    let zero = ScalarValue::new_zero(&data_type).ok()?;
+    if offset == zero {
+        return Some(comparison);
+    }
+    let (overflow_op, bound) = if (offset > zero) != subtract {
+        // `expr + offset > max`
+        (Operator::Gt, max)
+    } else {
+        // `expr + offset < min`
+        (Operator::Lt, min)
+    };
+    let bound = if subtract {
+        bound.add_checked(&offset)
+    } else {
+        bound.sub_checked(&offset)
+    }
+    .ok()?;
+    let overflow = Arc::new(phys_expr::BinaryExpr::new(
+        expr.clone(),
+        overflow_op,
+        Arc::new(phys_expr::Literal::new(bound)),
+    ));
+    Some(Arc::new(phys_expr::BinaryExpr::new(
+        comparison,
+        Operator::Or,
+        overflow,
+    )))
+}
+
+/// The minimum and maximum values of integer types
+fn integer_bounds(data_type: &DataType) -> Option<(ScalarValue, ScalarValue)> {
+    let bounds = match data_type {
+        DataType::Int8 => (
+            ScalarValue::Int8(Some(i8::MIN)),
+            ScalarValue::Int8(Some(i8::MAX)),
+        ),
+        DataType::Int16 => (
+            ScalarValue::Int16(Some(i16::MIN)),
+            ScalarValue::Int16(Some(i16::MAX)),
+        ),
+        DataType::Int32 => (
+            ScalarValue::Int32(Some(i32::MIN)),
+            ScalarValue::Int32(Some(i32::MAX)),
+        ),
+        DataType::Int64 => (
+            ScalarValue::Int64(Some(i64::MIN)),
+            ScalarValue::Int64(Some(i64::MAX)),
+        ),
+        DataType::UInt8 => (
+            ScalarValue::UInt8(Some(0)),
+            ScalarValue::UInt8(Some(u8::MAX)),
+        ),
+        DataType::UInt16 => (
+            ScalarValue::UInt16(Some(0)),
+            ScalarValue::UInt16(Some(u16::MAX)),
+        ),
+        DataType::UInt32 => (
+            ScalarValue::UInt32(Some(0)),
+            ScalarValue::UInt32(Some(u32::MAX)),
+        ),
+        DataType::UInt64 => (
+            ScalarValue::UInt64(Some(0)),
+            ScalarValue::UInt64(Some(u64::MAX)),
+        ),
+        _ => return None,
+    };
+    Some(bounds)
+}
+
 fn is_compare_op(op: Operator) -> bool {
     matches!(
         op,
@@ -1365,6 +1505,10 @@ fn build_predicate_expression(
         return expr;
     }
 
+    if let Some(expr) = rewrite_offset_comparison(&left, op, &right, schema) {
+        return build_predicate_expression(&expr, schema, required_columns);
+    }
+
     let expr_builder =
         PruningExpressionBuilder::try_new(&left, &right, op, schema, required_columns);
     let mut expr_builder = match expr_builder {
@@ -3145,6 +3289,86 @@ mod tests {
         );
     }
 
+    #[test]
+    fn prune_int32_col_plus_one_gt_six() {
+        let (schema, statistics) = int32_setup();
+
+        // Expression "i + 1 > 6", "6 < 1 + i" and "i - 1 > 4", all "i > 5"
+        // i [-5, 5] ==> no rows can pass (not keep)
+        // i [1, 11] ==> some rows could pass (must keep)
+        // i [-11, -1] ==>  no rows can pass (not keep)
+        // i [NULL, NULL]  ==> unknown (must keep)
+        // i [1, NULL]  ==> unknown (must keep)
+        let expected_ret = &[false, true, false, true, true];
+
+        prune_with_expr(
+            // i + 1 > 6
+            (col("i") + lit(1)).gt(lit(6)),
+            &schema,
+            &statistics,
+            expected_ret,
+        );
+
+        prune_with_expr(
+            // 6 < 1 + i
+            lit(6).lt(lit(1) + col("i")),
+            &schema,
+            &statistics,
+            expected_ret,
+        );
+
+        prune_with_expr(
+            // i - 1 > 4
+            (col("i") - lit(1)).gt(lit(4)),
+            &schema,
+            &statistics,
+            expected_ret,
+        );
+    }
+
+    #[test]
+    fn prune_int32_col_plus_literal_overflow() {
+        let schema = Arc::new(Schema::new(vec![Field::new("i", DataType::Int32, true)]));
+        let statistics = TestStatistics::new().with(
+            "i",
+            ContainerStats::new_i32(
+                vec![Some(0), Some(i32::MAX - 1), Some(i32::MIN)], // min
+                vec![Some(5), Some(i32::MAX), Some(i32::MIN + 1)], // max
+            ),
+        );
+
+        // Expression "i + 10 < 0", which wraps around for i > i32::MAX - 10
+        // i [0, 5] ==> no rows can pass (not keep)
+        // i [i32::MAX - 1, i32::MAX] ==> all rows overflow and pass (must keep)
+        // i [i32::MIN, i32::MIN + 1] ==> all rows pass (must keep)
+        prune_with_expr(
+            (col("i") + lit(10)).lt(lit(0)),
+            &schema,
+            &statistics,
+            &[false, true, true],
+        );
+
+        // Expression "i - 10 > 0", which wraps around for i < i32::MIN + 10
+        // i [0, 5] ==> no rows can pass (not keep)
+        // i [i32::MAX - 1, i32::MAX] ==> all rows pass (must keep)
+        // i [i32::MIN, i32::MIN + 1] ==> all rows overflow and pass (must keep)
+        prune_with_expr(
+            (col("i") - lit(10)).gt(lit(0)),
+            &schema,
+            &statistics,
+            &[false, true, true],
+        );
+
+        // Expression "i - 10 > i32::MAX - 5", for which "i32::MAX - 5 + 10"
+        // overflows, is not rewritten
+        prune_with_expr(
+            (col("i") - lit(10)).gt(lit(i32::MAX - 5)),
+            &schema,
+            &statistics,
+            &[true, true, true],
+        );
+    }
+
     #[test]
     fn prune_int32_is_null() {
         let (schema, statistics) = int32_setup();
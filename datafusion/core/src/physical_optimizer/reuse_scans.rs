@@ -0,0 +1,584 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! ReuseScans optimizer that executes identical file scans and, optionally,
+//! identical expensive subplans of a plan only once
+
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+use std::sync::Arc;
+
+use crate::config::ConfigOptions;
+#[cfg(feature = "parquet")]
+use crate::datasource::physical_plan::ParquetExec;
+use crate::datasource::physical_plan::{
+    ArrowExec, AvroExec, CsvExec, FileScanConfig, NdJsonExec,
+};
+use crate::error::Result;
+use crate::physical_optimizer::PhysicalOptimizerRule;
+use crate::physical_plan::aggregates::AggregateExec;
+use crate::physical_plan::joins::{
+    CrossJoinExec, HashJoinExec, NestedLoopJoinExec, SortMergeJoinExec,
+    SymmetricHashJoinExec,
+};
+use crate::physical_plan::shared_scan::SharedScanExec;
+use crate::physical_plan::sorts::sort::SortExec;
+use crate::physical_plan::windows::{BoundedWindowAggExec, WindowAggExec};
+use crate::physical_plan::{DisplayAs, DisplayFormatType, ExecutionPlan};
+
+use arrow::datatypes::SchemaRef;
+use datafusion_common::tree_node::{
+    Transformed, TransformedResult, TreeNode, TreeNodeRecursion,
+};
+
+/// Optimizer rule that replaces file scans occurring several times in a plan
+/// with the same files, projection and filters (for example in self joins,
+/// or when a common table expression is referenced more than once) by
+/// [`SharedScanExec`]s, so that the files are read only once and the
+/// results are sent to every occurrence.
+///
+/// Scans that differ in any way, e.g. because different columns were pushed
+/// down into them, are not shared. Scans are compared on their
+/// [`FileScanConfig`] and format options, and scans with options that do not
+/// identify the data they read, such as a custom parquet file reader factory,
+/// are never shared.
+///
+/// If `materialize_ctes` is enabled, identical subplans that contain an
+/// expensive operator (an aggregation, join, window or sort), such as the
+/// plans of a common table expression referenced more than once, are shared
+/// the same way, so that they are executed only once. Only the outermost
+/// repeated subplans are shared, and only if all of their leaves are file
+/// scans, which are compared the same way as shared scans: other leaves,
+/// such as in memory tables, do not display the data they produce, so
+/// identically displayed subplans may differ.
+#[derive(Default)]
+pub struct ReuseScans {}
+
+impl ReuseScans {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl PhysicalOptimizerRule for ReuseScans {
+    fn optimize(
+        &self,
+        plan: Arc<dyn ExecutionPlan>,
+        config: &ConfigOptions,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let options = &config.optimizer;
+        if !options.enable_scan_reuse && !options.materialize_ctes {
+            return Ok(plan);
+        }
+        let reuse_key = |node: &Arc<dyn ExecutionPlan>| -> Result<Option<ReuseKey>> {
+            Ok(if is_file_scan(node) {
+                options.enable_scan_reuse.then(|| scan_key(node)).flatten()
+            } else if options.materialize_ctes && is_expensive(node)? {
+                subplan_key(node)
+            } else {
+                None
+            })
+        };
+
+        // Count the occurrences of every scan and subplan
+        let mut counts: HashMap<ReuseKey, usize> = HashMap::new();
+        plan.apply(&mut |node| {
+            if let Some(key) = reuse_key(node)? {
+                *counts.entry(key).or_default() += 1;
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })?;
+        let repeated_key = |node: &Arc<dyn ExecutionPlan>| -> Result<Option<ReuseKey>> {
+            Ok(reuse_key(node)?.filter(|key| counts[key] > 1))
+        };
+
+        // Find the outermost repeated scans and subplans, in order of appearance
+        let mut repeated: Vec<(ReuseKey, Arc<dyn ExecutionPlan>, usize)> = vec![];
+        plan.apply(&mut |node| {
+            let Some(key) = repeated_key(node)? else {
+                return Ok(TreeNodeRecursion::Continue);
+            };
+            match repeated.iter_mut().find(|(k, _, _)| *k == key) {
+                Some((_, _, count)) => *count += 1,
+                None => repeated.push((key, node.clone(), 1)),
+            }
+            Ok(TreeNodeRecursion::Jump)
+        })?;
+
+        let mut shared_plans: HashMap<ReuseKey, _> = repeated
+            .into_iter()
+            .filter(|(_, _, count)| *count > 1)
+            .map(|(key, plan, count)| {
+                (key, SharedScanExec::new_group(plan, count).into_iter())
+            })
+            .collect();
+        if shared_plans.is_empty() {
+            return Ok(plan);
+        }
+
+        plan.transform_down_mut(&mut |node| {
+            let Some(key) = repeated_key(&node)? else {
+                return Ok(Transformed::no(node));
+            };
+            Ok(match shared_plans.get_mut(&key).and_then(|c| c.next()) {
+                Some(shared_plan) => {
+                    Transformed::new(Arc::new(shared_plan), true, TreeNodeRecursion::Jump)
+                }
+                // the other occurrences are part of shared subplans
+                None => Transformed::new(node, false, TreeNodeRecursion::Jump),
+            })
+        })
+        .data()
+    }
+
+    fn name(&self) -> &str {
+        "ReuseScans"
+    }
+
+    fn schema_check(&self) -> bool {
+        true
+    }
+}
+
+/// Identifies the data produced by a scan or subplan: a description of all
+/// the inputs and options it depends on, and its output schema.
+type ReuseKey = (String, SchemaRef);
+
+fn is_file_scan(plan: &Arc<dyn ExecutionPlan>) -> bool {
+    let plan_any = plan.as_any();
+    #[cfg(feature = "parquet")]
+    let is_parquet = plan_any.is::<ParquetExec>();
+    #[cfg(not(feature = "parquet"))]
+    let is_parquet = false;
+    is_parquet
+        || plan_any.is::<CsvExec>()
+        || plan_any.is::<NdJsonExec>()
+        || plan_any.is::<ArrowExec>()
+        || plan_any.is::<AvroExec>()
+}
+
+/// The key of a file scan, made of all fields of its [`FileScanConfig`] and
+/// the options of its file format. The display string of a scan can't be
+/// used, as it omits some options and abbreviates long lists of files.
+///
+/// Returns `None` for scans that may read different data with equal keys.
+fn scan_key(plan: &Arc<dyn ExecutionPlan>) -> Option<ReuseKey> {
+    let plan_any = plan.as_any();
+    let (config, format) = if let Some(csv) = plan_any.downcast_ref::<CsvExec>() {
+        let format = format!(
+            "csv: has_header={}, delimiter={}, quote={}, escape={:?}, \
+             null_value={:?}, newlines_in_values={}, compression={:?}",
+            csv.has_header(),
+            csv.delimiter(),
+            csv.quote(),
+            csv.escape(),
+            csv.null_value(),
+            csv.newlines_in_values(),
+            csv.file_compression_type,
+        );
+        (csv.base_config(), format)
+    } else if let Some(json) = plan_any.downcast_ref::<NdJsonExec>() {
+        let format = format!("json: compression={:?}", json.file_compression_type());
+        (json.base_config(), format)
+    } else if let Some(arrow) = plan_any.downcast_ref::<ArrowExec>() {
+        (arrow.base_config(), "arrow".to_string())
+    } else if let Some(avro) = plan_any.downcast_ref::<AvroExec>() {
+        (avro.base_config(), "avro".to_string())
+    } else {
+        parquet_scan_format(plan)?
+    };
+
+    // user defined extensions of the files may change the data that is read
+    let files = config.file_groups.iter().flatten();
+    if files.clone().any(|file| file.extensions.is_some()) {
+        return None;
+    }
+    let key = format!(
+        "{format}; object_store_url={}, file_schema={:?}, file_groups={:?}, \
+         projection={:?}, limit={:?}, table_partition_cols={:?}, \
+         output_ordering={:?}",
+        config.object_store_url.as_str(),
+        config.file_schema,
+        config.file_groups,
+        config.projection,
+        config.limit,
+        config.table_partition_cols,
+        config.output_ordering,
+    );
+    Some((key, plan.schema()))
+}
+
+/// The [`FileScanConfig`] and format options of a parquet scan, or `None` if
+/// `plan` is not a parquet scan or reads files through a custom reader
+#[cfg(feature = "parquet")]
+fn parquet_scan_format(
+    plan: &Arc<dyn ExecutionPlan>,
+) -> Option<(&FileScanConfig, String)> {
+    let parquet = plan.as_any().downcast_ref::<ParquetExec>()?;
+    if parquet.parquet_file_reader_factory().is_some() {
+        return None;
+    }
+    let format = format!(
+        "parquet: predicate={:?}, options={:?}, struct_field_projection={:?}",
+        parquet.predicate(),
+        parquet.table_parquet_options(),
+        parquet.struct_field_projection(),
+    );
+    Some((parquet.base_config(), format))
+}
+
+#[cfg(not(feature = "parquet"))]
+fn parquet_scan_format(
+    _plan: &Arc<dyn ExecutionPlan>,
+) -> Option<(&FileScanConfig, String)> {
+    None
+}
+
+/// The key of a subplan: the verbose display of each of its operators, and
+/// the [`scan_key`] of each of its leaves.
+///
+/// Returns `None` unless all leaves are file scans with a key: other leaves,
+/// such as in memory tables, do not display the data they produce.
+fn subplan_key(plan: &Arc<dyn ExecutionPlan>) -> Option<ReuseKey> {
+    let mut key = String::new();
+    write_subplan_key(plan, 0, &mut key)?;
+    Some((key, plan.schema()))
+}
+
+fn write_subplan_key(
+    plan: &Arc<dyn ExecutionPlan>,
+    indent: usize,
+    key: &mut String,
+) -> Option<()> {
+    if is_file_scan(plan) {
+        let (scan_key, _) = scan_key(plan)?;
+        writeln!(key, "{:indent$}{scan_key}", "").ok()?;
+        return Some(());
+    }
+    let children = plan.children();
+    if children.is_empty() {
+        return None;
+    }
+    writeln!(key, "{:indent$}{}", "", VerboseDisplay(plan.as_ref())).ok()?;
+    for child in &children {
+        write_subplan_key(child, indent + 2, key)?;
+    }
+    Some(())
+}
+
+/// Displays a single operator in [`DisplayFormatType::Verbose`] format
+struct VerboseDisplay<'a>(&'a dyn ExecutionPlan);
+
+impl fmt::Display for VerboseDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt_as(DisplayFormatType::Verbose, f)
+    }
+}
+
+/// Returns true if `plan` contains an operator that is worth executing only
+/// once: an aggregation, join, window or sort
+fn is_expensive(plan: &Arc<dyn ExecutionPlan>) -> Result<bool> {
+    let mut expensive = false;
+    plan.apply(&mut |node| {
+        let node_any = node.as_any();
+        expensive = node_any.is::<AggregateExec>()
+            || node_any.is::<HashJoinExec>()
+            || node_any.is::<SortMergeJoinExec>()
+            || node_any.is::<NestedLoopJoinExec>()
+            || node_any.is::<CrossJoinExec>()
+            || node_any.is::<SymmetricHashJoinExec>()
+            || node_any.is::<WindowAggExec>()
+            || node_any.is::<BoundedWindowAggExec>()
+            || node_any.is::<SortExec>();
+        Ok(if expensive {
+            TreeNodeRecursion::Stop
+        } else {
+            TreeNodeRecursion::Continue
+        })
+    })?;
+    Ok(expensive)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physical_optimizer::test_utils::{
+        coalesce_partitions_exec, parquet_exec, parquet_exec_sorted, sort_exec,
+        sort_expr, union_exec,
+    };
+
+    use crate::datasource::file_format::file_compression_type::FileCompressionType;
+    use crate::datasource::listing::PartitionedFile;
+    use crate::datasource::MemTable;
+    use crate::physical_plan::{collect, displayable};
+    use crate::prelude::{SessionConfig, SessionContext};
+
+    use arrow::array::Int64Array;
+    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::record_batch::RecordBatch;
+    use datafusion_common::{assert_batches_sorted_eq, Statistics};
+    use datafusion_execution::object_store::ObjectStoreUrl;
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]))
+    }
+
+    fn scan(schema: &SchemaRef) -> Arc<dyn ExecutionPlan> {
+        parquet_exec(schema)
+    }
+
+    fn file_scan_config(schema: &SchemaRef, files: &[&str]) -> FileScanConfig {
+        FileScanConfig {
+            object_store_url: ObjectStoreUrl::parse("test:///").unwrap(),
+            file_schema: schema.clone(),
+            file_groups: files
+                .iter()
+                .map(|file| vec![PartitionedFile::new(file.to_string(), 100)])
+                .collect(),
+            statistics: Statistics::new_unknown(schema),
+            projection: None,
+            limit: None,
+            table_partition_cols: vec![],
+            output_ordering: vec![],
+        }
+    }
+
+    fn csv_scan(schema: &SchemaRef, delimiter: u8) -> Arc<dyn ExecutionPlan> {
+        Arc::new(CsvExec::new(
+            file_scan_config(schema, &["x"]),
+            true,
+            delimiter,
+            b'"',
+            None,
+            FileCompressionType::UNCOMPRESSED,
+        ))
+    }
+
+    fn is_shared(plan: &Arc<dyn ExecutionPlan>) -> bool {
+        displayable(plan.as_ref())
+            .indent(true)
+            .to_string()
+            .contains("SharedScanExec")
+    }
+
+    fn optimize(plan: Arc<dyn ExecutionPlan>, enabled: bool) -> Result<Vec<String>> {
+        let mut config = ConfigOptions::new();
+        config.optimizer.enable_scan_reuse = enabled;
+        run(plan, &config)
+    }
+
+    fn optimize_subplans(
+        plan: Arc<dyn ExecutionPlan>,
+        scan_reuse: bool,
+    ) -> Result<Vec<String>> {
+        let mut config = ConfigOptions::new();
+        config.optimizer.enable_scan_reuse = scan_reuse;
+        config.optimizer.materialize_ctes = true;
+        run(plan, &config)
+    }
+
+    fn run(plan: Arc<dyn ExecutionPlan>, config: &ConfigOptions) -> Result<Vec<String>> {
+        let optimized = ReuseScans::new().optimize(plan, config)?;
+        let plan_string = displayable(optimized.as_ref()).indent(true).to_string();
+        Ok(plan_string.trim().lines().map(String::from).collect())
+    }
+
+    #[test]
+    fn identical_scans_shared() -> Result<()> {
+        let schema = schema();
+        let plan =
+            union_exec(vec![scan(&schema), coalesce_partitions_exec(scan(&schema))]);
+
+        let expected = [
+            "UnionExec",
+            "  SharedScanExec: consumer=0, consumers=2",
+            "    ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+            "  CoalescePartitionsExec",
+            "    SharedScanExec: consumer=1, consumers=2",
+            "      ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+        ];
+        assert_eq!(optimize(plan, true)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn scans_not_shared_when_disabled() -> Result<()> {
+        let schema = schema();
+        let plan = union_exec(vec![scan(&schema), scan(&schema)]);
+
+        let expected = [
+            "UnionExec",
+            "  ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+            "  ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+        ];
+        assert_eq!(optimize(plan, false)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn different_scans_not_shared() -> Result<()> {
+        let schema = schema();
+        let plan = union_exec(vec![
+            scan(&schema),
+            parquet_exec_sorted(&schema, [sort_expr("a", &schema)]),
+        ]);
+
+        let expected = [
+            "UnionExec",
+            "  ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+            "  ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b], output_ordering=[a@0 ASC]",
+        ];
+        assert_eq!(optimize(plan, true)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn scans_of_different_files_not_shared() -> Result<()> {
+        let schema = schema();
+        let files = ["a", "b", "c", "d", "e", "f"];
+        let other_files = ["a", "b", "c", "d", "e", "g"];
+        let scan = |files: &[&str]| -> Arc<dyn ExecutionPlan> {
+            Arc::new(ParquetExec::new(
+                file_scan_config(&schema, files),
+                None,
+                None,
+                Default::default(),
+            ))
+        };
+        let (left, right) = (scan(&files), scan(&other_files));
+
+        // only the first 5 file groups are displayed
+        assert_eq!(
+            displayable(left.as_ref()).one_line().to_string(),
+            displayable(right.as_ref()).one_line().to_string()
+        );
+        let mut config = ConfigOptions::new();
+        config.optimizer.enable_scan_reuse = true;
+        let plan = union_exec(vec![left, right]);
+        assert!(!is_shared(&ReuseScans::new().optimize(plan, &config)?));
+
+        let plan = union_exec(vec![scan(&files), scan(&files)]);
+        assert!(is_shared(&ReuseScans::new().optimize(plan, &config)?));
+
+        // neither are subplans reading them
+        let mut config = ConfigOptions::new();
+        config.optimizer.materialize_ctes = true;
+        let sorted =
+            |files: &[&str]| sort_exec(vec![sort_expr("a", &schema)], scan(files));
+        let plan = union_exec(vec![sorted(&files), sorted(&other_files)]);
+        assert!(!is_shared(&ReuseScans::new().optimize(plan, &config)?));
+
+        let plan = union_exec(vec![sorted(&files), sorted(&files)]);
+        assert!(is_shared(&ReuseScans::new().optimize(plan, &config)?));
+        Ok(())
+    }
+
+    #[test]
+    fn csv_scans_with_different_options_not_shared() -> Result<()> {
+        let schema = schema();
+        let mut config = ConfigOptions::new();
+        config.optimizer.enable_scan_reuse = true;
+
+        let plan = union_exec(vec![csv_scan(&schema, b','), csv_scan(&schema, b';')]);
+        assert!(!is_shared(&ReuseScans::new().optimize(plan, &config)?));
+
+        let plan = union_exec(vec![csv_scan(&schema, b','), csv_scan(&schema, b',')]);
+        assert!(is_shared(&ReuseScans::new().optimize(plan, &config)?));
+        Ok(())
+    }
+
+    #[test]
+    fn identical_subplans_shared() -> Result<()> {
+        let schema = schema();
+        let sorted = || sort_exec(vec![sort_expr("a", &schema)], scan(&schema));
+        let plan = union_exec(vec![sorted(), sorted()]);
+
+        // the outermost repeated subplan is shared, the scans below it are not
+        let expected = [
+            "UnionExec",
+            "  SharedScanExec: consumer=0, consumers=2",
+            "    SortExec: expr=[a@0 ASC]",
+            "      ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+            "  SharedScanExec: consumer=1, consumers=2",
+            "    SortExec: expr=[a@0 ASC]",
+            "      ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+        ];
+        assert_eq!(optimize_subplans(plan.clone(), true)?, expected);
+        assert_eq!(optimize_subplans(plan, false)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn cheap_subplans_not_shared() -> Result<()> {
+        let schema = schema();
+        let plan = union_exec(vec![
+            coalesce_partitions_exec(scan(&schema)),
+            coalesce_partitions_exec(scan(&schema)),
+        ]);
+
+        let expected = [
+            "UnionExec",
+            "  CoalescePartitionsExec",
+            "    ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+            "  CoalescePartitionsExec",
+            "    ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b]",
+        ];
+        assert_eq!(optimize_subplans(plan, false)?, expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn identical_in_memory_subplans_not_shared() -> Result<()> {
+        let mut config = SessionConfig::new();
+        config.options_mut().optimizer.materialize_ctes = true;
+        let ctx = SessionContext::new_with_config(config);
+
+        // two tables of the same shape, whose scans display identically
+        for (name, values) in [("a", vec![1, 1, 2]), ("b", vec![3, 4, 4])] {
+            let batch = RecordBatch::try_from_iter(vec![(
+                "x",
+                Arc::new(Int64Array::from(values)) as _,
+            )])?;
+            let table = MemTable::try_new(batch.schema(), vec![vec![batch]])?;
+            ctx.register_table(name, Arc::new(table))?;
+        }
+
+        let sql = "SELECT x, count(*) AS c FROM a GROUP BY x \
+            UNION ALL SELECT x, count(*) AS c FROM b GROUP BY x";
+        let plan = ctx.sql(sql).await?.create_physical_plan().await?;
+        let formatted = displayable(plan.as_ref()).indent(true).to_string();
+        assert!(!formatted.contains("SharedScanExec"), "{formatted}");
+
+        let batches = collect(plan, ctx.task_ctx()).await?;
+        let expected = [
+            "+---+---+",
+            "| x | c |",
+            "+---+---+",
+            "| 1 | 2 |",
+            "| 2 | 1 |",
+            "| 3 | 1 |",
+            "| 4 | 2 |",
+            "+---+---+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+        Ok(())
+    }
+}
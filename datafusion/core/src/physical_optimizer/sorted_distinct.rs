@@ -0,0 +1,175 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! An optimizer rule that replaces grouped aggregations without aggregate
+//! expressions on sorted input with a [`DedupExec`]
+
+use std::sync::Arc;
+
+use crate::physical_optimizer::PhysicalOptimizerRule;
+use crate::physical_plan::aggregates::{AggregateExec, AggregateMode};
+use crate::physical_plan::dedup::DedupExec;
+use crate::physical_plan::{ExecutionPlan, InputOrderMode};
+
+use datafusion_common::config::ConfigOptions;
+use datafusion_common::tree_node::{Transformed, TransformedResult, TreeNode};
+use datafusion_common::Result;
+
+/// An optimizer rule that replaces the final stage of grouped aggregations
+/// which have no aggregate expressions, and whose input is sorted on the group
+/// keys, with a [`DedupExec`]. Example queries fitting this description on a
+/// table sorted on `l_orderkey` are:
+/// `SELECT DISTINCT l_orderkey FROM lineitem;`
+/// `SELECT l_orderkey FROM lineitem GROUP BY l_orderkey;`
+///
+/// The `DedupExec` only compares each row to the previous one, rather than
+/// tracking the groups seen so far.
+pub struct SortedDistinct {}
+
+impl SortedDistinct {
+    /// Create a new `SortedDistinct`
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn transform_agg(aggr: &AggregateExec) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+        let partitioned = match aggr.mode() {
+            AggregateMode::Final | AggregateMode::Single => false,
+            AggregateMode::FinalPartitioned | AggregateMode::SinglePartitioned => true,
+            // partial aggregations already emit sorted groups early
+            AggregateMode::Partial => return Ok(None),
+        };
+        let group_by = aggr.group_expr();
+        if group_by.is_empty()
+            || !group_by.is_single()
+            || !aggr.aggr_expr().is_empty()
+            || aggr.input_order_mode() != &InputOrderMode::Sorted
+        {
+            return Ok(None);
+        }
+
+        let dedup = DedupExec::try_new(
+            group_by.expr().to_vec(),
+            aggr.input().clone(),
+            partitioned,
+        )?;
+        // the output fields must be unchanged, including their nullability
+        if dedup.schema() != aggr.schema() {
+            return Ok(None);
+        }
+        Ok(Some(Arc::new(dedup)))
+    }
+}
+
+impl Default for SortedDistinct {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhysicalOptimizerRule for SortedDistinct {
+    fn optimize(
+        &self,
+        plan: Arc<dyn ExecutionPlan>,
+        config: &ConfigOptions,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        if !config.optimizer.enable_sorted_distinct_dedup {
+            return Ok(plan);
+        }
+        plan.transform_up(&|plan| {
+            let Some(aggr) = plan.as_any().downcast_ref::<AggregateExec>() else {
+                return Ok(Transformed::no(plan));
+            };
+            Ok(match Self::transform_agg(aggr)? {
+                Some(dedup) => Transformed::yes(dedup),
+                None => Transformed::no(plan),
+            })
+        })
+        .data()
+    }
+
+    fn name(&self) -> &str {
+        "SortedDistinct"
+    }
+
+    fn schema_check(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physical_optimizer::enforce_distribution::tests::{
+        parquet_exec_with_sort, schema, trim_plan_display,
+    };
+    use crate::physical_plan::aggregates::PhysicalGroupBy;
+    use crate::physical_plan::displayable;
+    use crate::physical_plan::expressions::col;
+    use arrow::compute::SortOptions;
+    use datafusion_physical_expr::PhysicalSortExpr;
+
+    fn distinct(
+        input: Arc<dyn ExecutionPlan>,
+        column: &str,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let schema = input.schema();
+        let group_by =
+            PhysicalGroupBy::new_single(vec![(col(column, &schema)?, column.into())]);
+        Ok(Arc::new(AggregateExec::try_new(
+            AggregateMode::Single,
+            group_by,
+            vec![],
+            vec![],
+            input,
+            schema,
+        )?))
+    }
+
+    fn optimized_plan(plan: Arc<dyn ExecutionPlan>) -> Result<Vec<String>> {
+        let optimized = SortedDistinct::new().optimize(plan, &ConfigOptions::new())?;
+        let formatted = displayable(optimized.as_ref()).indent(true).to_string();
+        Ok(trim_plan_display(&formatted)
+            .into_iter()
+            .map(String::from)
+            .collect())
+    }
+
+    #[test]
+    fn dedup_sorted_distinct() -> Result<()> {
+        let schema = schema();
+        let sort_key = vec![PhysicalSortExpr {
+            expr: col("a", &schema)?,
+            options: SortOptions::default(),
+        }];
+        let source = parquet_exec_with_sort(vec![sort_key]);
+
+        let expected = [
+            "DedupExec: expr=[a@0 as a]",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], output_ordering=[a@0 ASC]",
+        ];
+        assert_eq!(optimized_plan(distinct(source.clone(), "a")?)?, expected);
+
+        // the input is not sorted on `b`
+        let expected = [
+            "AggregateExec: mode=Single, gby=[b@1 as b], aggr=[]",
+            "ParquetExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], output_ordering=[a@0 ASC]",
+        ];
+        assert_eq!(optimized_plan(distinct(source, "b")?)?, expected);
+        Ok(())
+    }
+}
@@ -25,6 +25,7 @@ use crate::datasource::file_format::arrow::ArrowFormat;
 use crate::datasource::file_format::avro::AvroFormat;
 use crate::datasource::file_format::csv::CsvFormat;
 use crate::datasource::file_format::json::JsonFormat;
+use crate::datasource::file_format::orc::OrcFormat;
 #[cfg(feature = "parquet")]
 use crate::datasource::file_format::parquet::ParquetFormat;
 use crate::datasource::file_format::FileFormat;
@@ -32,11 +33,12 @@ use crate::datasource::listing::ListingTableUrl;
 use crate::datasource::physical_plan::FileSinkConfig;
 use crate::datasource::source_as_provider;
 use crate::error::{DataFusionError, Result};
+use crate::execution::apply::ApplyExec;
 use crate::execution::context::{ExecutionProps, SessionState};
-use crate::logical_expr::utils::generate_sort_key;
+use crate::logical_expr::utils::{conjunction, generate_sort_key, split_conjunction};
 use crate::logical_expr::{
-    Aggregate, EmptyRelation, Join, Projection, Sort, SubqueryAlias, TableScan, Unnest,
-    Window,
+    Aggregate, EmptyRelation, Join, JoinType, Projection, Sort, Subquery, SubqueryAlias,
+    TableScan, Unnest, Window,
 };
 use crate::logical_expr::{
     CrossJoin, Expr, LogicalPlan, Partitioning as LogicalPartitioning, PlanType,
@@ -45,6 +47,7 @@ use crate::logical_expr::{
 use crate::logical_expr::{Limit, Values};
 use crate::physical_expr::{create_physical_expr, create_physical_exprs};
 use crate::physical_optimizer::optimizer::PhysicalOptimizerRule;
+use crate::physical_optimizer::point_query::is_point_query;
 use crate::physical_plan::aggregates::{AggregateExec, AggregateMode, PhysicalGroupBy};
 use crate::physical_plan::analyze::AnalyzeExec;
 use crate::physical_plan::empty::EmptyExec;
@@ -75,8 +78,10 @@ use arrow::datatypes::{Schema, SchemaRef};
 use arrow_array::builder::StringBuilder;
 use arrow_array::RecordBatch;
 use datafusion_common::display::ToStringifiedPlan;
+use datafusion_common::tree_node::{Transformed, TransformedResult, TreeNode};
 use datafusion_common::{
-    exec_err, internal_err, not_impl_err, plan_err, DFSchema, FileType, ScalarValue,
+    exec_err, internal_err, not_impl_err, plan_err, DFSchema, FileType, JoinStrategyHint,
+    ScalarValue,
 };
 use datafusion_expr::dml::CopyTo;
 use datafusion_expr::expr::{
@@ -87,10 +92,10 @@ use datafusion_expr::expr::{
 use datafusion_expr::expr_rewriter::unnormalize_cols;
 use datafusion_expr::logical_plan::builder::wrap_projection_for_join_if_necessary;
 use datafusion_expr::{
-    DescribeTable, DmlStatement, RecursiveQuery, ScalarFunctionDefinition,
+    lit, DescribeTable, DmlStatement, RecursiveQuery, ScalarFunctionDefinition,
     StringifiedPlan, WindowFrame, WindowFrameBound, WriteOp,
 };
-use datafusion_physical_expr::expressions::Literal;
+use datafusion_physical_expr::expressions::{cache_constant_subtrees, Literal};
 use datafusion_physical_plan::placeholder_row::PlaceholderRowExec;
 use datafusion_sql::utils::window_expr_common_partition_keys;
 
@@ -475,13 +480,20 @@ impl PhysicalPlanner for DefaultPhysicalPlanner {
     /// `e`: the expression to convert
     ///
     /// `input_dfschema`: the logical plan schema for evaluating `e`
+    ///
+    /// Subtrees of the expression which do not depend on the input are
+    /// only evaluated once per execution, see [`cache_constant_subtrees`]
     fn create_physical_expr(
         &self,
         expr: &Expr,
         input_dfschema: &DFSchema,
         session_state: &SessionState,
     ) -> Result<Arc<dyn PhysicalExpr>> {
-        create_physical_expr(expr, input_dfschema, session_state.execution_props())
+        cache_constant_subtrees(create_physical_expr(
+            expr,
+            input_dfschema,
+            session_state.execution_props(),
+        )?)
     }
 }
 
@@ -615,6 +627,7 @@ impl DefaultPhysicalPlanner {
                         },
                         FormatOptions::AVRO => Arc::new(AvroFormat {} ),
                         FormatOptions::ARROW => Arc::new(ArrowFormat {}),
+                        FormatOptions::ORC => Arc::new(OrcFormat {}),
                     };
 
                     sink_format.create_writer_physical_plan(input_exec, session_state, config, None).await
@@ -653,6 +666,41 @@ impl DefaultPhysicalPlanner {
                         );
                     }
                 }
+                LogicalPlan::Dml(DmlStatement {
+                    table_name,
+                    op: WriteOp::Delete,
+                    input,
+                    ..
+                }) => {
+                    let name = table_name.table();
+                    let schema = session_state.schema_for_ref(table_name.clone())?;
+                    if let Some(provider) = schema.table(name).await? {
+                        let filters = extract_dml_filters(input)?;
+                        provider.delete_from(session_state, filters).await
+                    } else {
+                        return exec_err!(
+                            "Table '{table_name}' does not exist"
+                        );
+                    }
+                }
+                LogicalPlan::Dml(DmlStatement {
+                    table_name,
+                    op: WriteOp::Update,
+                    input,
+                    ..
+                }) => {
+                    let name = table_name.table();
+                    let schema = session_state.schema_for_ref(table_name.clone())?;
+                    if let Some(provider) = schema.table(name).await? {
+                        let assignments = extract_update_assignments(input)?;
+                        let filters = extract_dml_filters(input)?;
+                        provider.update(session_state, assignments, filters).await
+                    } else {
+                        return exec_err!(
+                            "Table '{table_name}' does not exist"
+                        );
+                    }
+                }
                 LogicalPlan::Values(Values {
                     values,
                     schema,
@@ -953,7 +1001,12 @@ impl DefaultPhysicalPlanner {
                     )?))
                 }
                 LogicalPlan::Sort(Sort { expr, input, fetch, .. }) => {
-                    let physical_input = self.create_initial_plan(input, session_state).await?;
+                    // Let the provider of a scanned table sort its rows, in
+                    // which case the sort is removed by EnforceSorting
+                    let physical_input = match create_sorted_scan(input, expr, *fetch, session_state).await? {
+                        Some(plan) => plan,
+                        None => self.create_initial_plan(input, session_state).await?,
+                    };
                     let input_dfschema = input.as_ref().schema();
                     let sort_expr = create_physical_sort_exprs(expr, input_dfschema, session_state.execution_props())?;
                     let new_sort = SortExec::new(sort_expr, physical_input)
@@ -968,10 +1021,24 @@ impl DefaultPhysicalPlanner {
                     join_type,
                     null_equals_null,
                     schema: join_schema,
+                    strategy_hint,
+                    null_aware,
                     ..
                 }) => {
                     let null_equals_null = *null_equals_null;
 
+                    // A LATERAL subquery that could not be decorrelated is
+                    // evaluated for every row of the left input
+                    if is_lateral_input(right) {
+                        let physical_left = self.create_initial_plan(left, session_state).await?;
+                        let filter = conjunction(
+                            keys.iter()
+                                .map(|(l, r)| l.clone().eq(r.clone()))
+                                .chain(filter.clone()),
+                        );
+                        return create_apply_exec(physical_left, left, right, filter, *join_type, join_schema, session_state);
+                    }
+
                     // If join has expression equijoin keys, add physical projection.
                     let has_expr_join_key = keys.iter().any(|(l, r)| {
                         !(matches!(l, Expr::Column(_))
@@ -1070,7 +1137,27 @@ impl DefaultPhysicalPlanner {
 
                     let prefer_hash_join = session_state.config_options().optimizer.prefer_hash_join;
 
-                    if join_on.is_empty() {
+                    if *null_aware {
+                        if join_on.len() != 1 || join_filter.is_some() {
+                            return not_impl_err!(
+                                "Null-aware anti join is only supported on a single equijoin key without a filter"
+                            );
+                        }
+                        // The `NOT IN` subquery (right) input is collected into a
+                        // single hash table, which knows whether it contains any
+                        // null key, and is probed by the outer (left) input
+                        let join_on = join_on.into_iter().map(|(l, r)| (r, l)).collect();
+                        Ok(Arc::new(HashJoinExec::try_new(
+                            physical_right,
+                            physical_left,
+                            join_on,
+                            None,
+                            &JoinType::RightAnti,
+                            None,
+                            PartitionMode::CollectLeft,
+                            null_equals_null,
+                        )?.with_null_aware(true)?))
+                    } else if join_on.is_empty() {
                         // there is no equal join condition, use the nested loop join
                         // TODO optimize the plan, and use the config of `target_partitions` and `repartition_joins`
                         Ok(Arc::new(NestedLoopJoinExec::try_new(
@@ -1079,11 +1166,15 @@ impl DefaultPhysicalPlanner {
                             join_filter,
                             join_type,
                         )?))
-                    } else if session_state.config().target_partitions() > 1
-                        && session_state.config().repartition_joins()
-                        && !prefer_hash_join
+                    } else if (*strategy_hint == Some(JoinStrategyHint::SortMerge)
+                        && *join_type != JoinType::RightSemi)
+                        || (session_state.config().target_partitions() > 1
+                            && session_state.config().repartition_joins()
+                            && !prefer_hash_join
+                            && strategy_hint.is_none())
                     {
-                        // Use SortMergeJoin if hash join is not preferred
+                        // Use SortMergeJoin if hash join is not preferred, or if it
+                        // is requested by a hint such as `/*+ MERGE(t) */`
                         // Sort-Merge join support currently is experimental
 
                         let join_on_len = join_on.len();
@@ -1097,8 +1188,7 @@ impl DefaultPhysicalPlanner {
                             null_equals_null,
                         )?))
                     } else if session_state.config().target_partitions() > 1
-                        && session_state.config().repartition_joins()
-                        && prefer_hash_join {
+                        && session_state.config().repartition_joins() {
                          let partition_mode = {
                             if session_state.config().collect_statistics() {
                                 PartitionMode::Auto
@@ -1115,7 +1205,7 @@ impl DefaultPhysicalPlanner {
 							None,
                             partition_mode,
                             null_equals_null,
-                        )?))
+                        )?.with_strategy_hint(*strategy_hint)))
                     } else {
                         Ok(Arc::new(HashJoinExec::try_new(
                             physical_left,
@@ -1126,15 +1216,22 @@ impl DefaultPhysicalPlanner {
 							None,
                             PartitionMode::CollectLeft,
                             null_equals_null,
-                        )?))
+                        )?.with_strategy_hint(*strategy_hint)))
                     }
                 }
-                LogicalPlan::CrossJoin(CrossJoin { left, right, .. }) => {
+                LogicalPlan::CrossJoin(CrossJoin { left, right, schema }) => {
+                    if is_lateral_input(right) {
+                        let physical_left = self.create_initial_plan(left, session_state).await?;
+                        return create_apply_exec(physical_left, left, right, None, JoinType::Inner, schema, session_state);
+                    }
                     let left_right = self.create_initial_plan_multi([left.as_ref(), right.as_ref()], session_state).await?;
                     let [left, right]: [Arc<dyn ExecutionPlan>; 2] = left_right.try_into().map_err(|_| DataFusionError::Internal("`create_initial_plan_multi` is broken".to_string()))?;
                     Ok(Arc::new(CrossJoinExec::new(left, right)))
                 }
-                LogicalPlan::Subquery(_) => todo!(),
+                // Only reachable below an `ApplyExec`, with its outer references bound
+                LogicalPlan::Subquery(Subquery { subquery, .. }) => {
+                    self.create_initial_plan(subquery, session_state).await
+                }
                 LogicalPlan::EmptyRelation(EmptyRelation {
                     produce_one_row: false,
                     schema,
@@ -1168,12 +1265,14 @@ impl DefaultPhysicalPlanner {
 
                     Ok(Arc::new(GlobalLimitExec::new(input, *skip, *fetch)))
                 }
-                LogicalPlan::Unnest(Unnest { input, column, schema, options }) => {
+                LogicalPlan::Unnest(Unnest { input, columns, schema, options }) => {
                     let input = self.create_initial_plan(input, session_state).await?;
-                    let column_exec = schema.index_of_column(column)
-                        .map(|idx| Column::new(&column.name, idx))?;
+                    let column_execs = columns.iter().map(|column| {
+                        schema.index_of_column(column)
+                            .map(|idx| Column::new(&column.name, idx))
+                    }).collect::<Result<_>>()?;
                     let schema = SchemaRef::new(schema.as_ref().to_owned().into());
-                    Ok(Arc::new(UnnestExec::new(input, column_exec, schema, options.clone())))
+                    Ok(Arc::new(UnnestExec::new(input, column_execs, schema, options.clone())))
                 }
                 LogicalPlan::Ddl(ddl) => {
                     // There is no default plan for DDl statements --
@@ -1320,6 +1419,34 @@ impl DefaultPhysicalPlanner {
     }
 }
 
+/// Creates the scan of a `TableScan` input of a `Sort`, if its provider can
+/// produce the rows sorted by `sort_exprs` with [`TableProvider::scan_sorted`]
+///
+/// [`TableProvider::scan_sorted`]: crate::datasource::TableProvider::scan_sorted
+async fn create_sorted_scan(
+    input: &LogicalPlan,
+    sort_exprs: &[Expr],
+    fetch: Option<usize>,
+    session_state: &SessionState,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    let LogicalPlan::TableScan(TableScan {
+        source,
+        projection,
+        filters,
+        fetch: None,
+        ..
+    }) = input
+    else {
+        return Ok(None);
+    };
+    let source = source_as_provider(source)?;
+    let filters = unnormalize_cols(filters.iter().cloned());
+    let sort_exprs = unnormalize_cols(sort_exprs.iter().cloned());
+    source
+        .scan_sorted(session_state, projection.as_ref(), &filters, &sort_exprs, fetch)
+        .await
+}
+
 /// Expand and align a GROUPING SET expression.
 /// (see <https://www.postgresql.org/docs/current/queries-table-expressions.html#QUERIES-GROUPING-SETS>)
 ///
@@ -1757,7 +1884,20 @@ impl DefaultPhysicalPlanner {
                 }
             }
 
-            if !config.logical_plan_only && e.logical_optimization_succeeded {
+            if !config.logical_plan_only && e.logical_optimization_succeeded && e.estimate
+            {
+                // EXPLAIN ESTIMATE only shows the final physical plan, annotated
+                // with the estimates derived from the statistics of each operator
+                let input = self
+                    .create_initial_plan(e.plan.as_ref(), session_state)
+                    .await?;
+                let input = self.optimize_internal(input, session_state, |_, _| {})?;
+                stringified_plans.push(
+                    displayable(input.as_ref())
+                        .set_show_estimates(true)
+                        .to_stringified(e.verbose, FinalPhysicalPlan),
+                );
+            } else if !config.logical_plan_only && e.logical_optimization_succeeded {
                 match self
                     .create_initial_plan(e.plan.as_ref(), session_state)
                     .await
@@ -1860,6 +2000,20 @@ impl DefaultPhysicalPlanner {
     where
         F: FnMut(&dyn ExecutionPlan, &dyn PhysicalOptimizerRule),
     {
+        // The built-in rules are skipped for point queries, but rules added
+        // to the session still run
+        let skip_built_in_rules = session_state
+            .config_options()
+            .optimizer
+            .point_query_fast_path
+            && is_point_query(&plan);
+        if skip_built_in_rules {
+            debug!(
+                "Skipping built-in physical optimizer rules for point query:\n{}\n",
+                displayable(plan.as_ref()).indent(false)
+            );
+        }
+
         let optimizers = session_state.physical_optimizers();
         debug!(
             "Input physical plan:\n{}\n",
@@ -1872,6 +2026,11 @@ impl DefaultPhysicalPlanner {
 
         let mut new_plan = plan;
         for optimizer in optimizers {
+            if skip_built_in_rules
+                && session_state.is_built_in_physical_optimizer_rule(optimizer)
+            {
+                continue;
+            }
             let before_schema = new_plan.schema();
             new_plan = optimizer
                 .optimize(new_plan, session_state.config_options())
@@ -1943,6 +2102,71 @@ impl DefaultPhysicalPlanner {
     }
 }
 
+/// Returns the conjuncts of the filters of the input of a `DELETE` or
+/// `UPDATE` statement, with unqualified columns
+fn extract_dml_filters(input: &Arc<LogicalPlan>) -> Result<Vec<Expr>> {
+    let mut filters = vec![];
+    let mut plan = match input.as_ref() {
+        LogicalPlan::Projection(projection) => projection.input.as_ref(),
+        plan => plan,
+    };
+    loop {
+        match plan {
+            LogicalPlan::Filter(filter) => {
+                filters.extend(split_conjunction(&filter.predicate).into_iter().cloned());
+                plan = filter.input.as_ref();
+            }
+            LogicalPlan::SubqueryAlias(alias) => plan = alias.input.as_ref(),
+            LogicalPlan::TableScan(scan) => {
+                filters.extend(scan.filters.iter().cloned());
+                break;
+            }
+            LogicalPlan::EmptyRelation(_) => {
+                filters.push(lit(false));
+                break;
+            }
+            other => {
+                return not_impl_err!(
+                    "Unsupported input for DELETE or UPDATE: {}",
+                    other.display()
+                )
+            }
+        }
+    }
+    filters.into_iter().map(strip_column_qualifiers).collect()
+}
+
+/// Returns the assigned columns and their values of the input of an `UPDATE`
+/// statement, which projects the unchanged columns as themselves
+fn extract_update_assignments(input: &Arc<LogicalPlan>) -> Result<Vec<(String, Expr)>> {
+    let LogicalPlan::Projection(projection) = input.as_ref() else {
+        return Ok(vec![]);
+    };
+    projection
+        .expr
+        .iter()
+        .zip(projection.schema.fields())
+        .filter_map(|(expr, field)| match expr.clone().unalias() {
+            Expr::Column(column) if &column.name == field.name() => None,
+            value => Some(
+                strip_column_qualifiers(value).map(|value| (field.name().clone(), value)),
+            ),
+        })
+        .collect()
+}
+
+fn strip_column_qualifiers(expr: Expr) -> Result<Expr> {
+    expr.transform(&|expr| {
+        Ok(match expr {
+            Expr::Column(column) => Transformed::yes(Expr::Column(
+                datafusion_common::Column::from_name(column.name),
+            )),
+            _ => Transformed::no(expr),
+        })
+    })
+    .data()
+}
+
 fn tuple_err<T, R>(value: (Result<T>, Result<R>)) -> Result<(T, R)> {
     match value {
         (Ok(e), Ok(e1)) => Ok((e, e1)),
@@ -2000,6 +2224,48 @@ fn project_expr_join_keys(
     }
 }
 
+/// Returns true if `plan` is a, possibly filtered or projected, `LATERAL`
+/// subquery that refers to the columns of the other join input
+fn is_lateral_input(plan: &LogicalPlan) -> bool {
+    match plan {
+        LogicalPlan::Subquery(_) => true,
+        _ => match plan.inputs()[..] {
+            [input] => is_lateral_input(input),
+            _ => false,
+        },
+    }
+}
+
+/// Creates an [`ApplyExec`] joining `physical_left` with the correlated
+/// `LATERAL` subquery `right`
+fn create_apply_exec(
+    physical_left: Arc<dyn ExecutionPlan>,
+    left: &Arc<LogicalPlan>,
+    right: &Arc<LogicalPlan>,
+    filter: Option<Expr>,
+    join_type: JoinType,
+    join_schema: &Arc<DFSchema>,
+    session_state: &SessionState,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    if !matches!(join_type, JoinType::Inner | JoinType::Left) {
+        return not_impl_err!("{join_type} join with a LATERAL subquery is not supported");
+    }
+    let filter = filter
+        .map(|filter| {
+            create_physical_expr(&filter, join_schema, session_state.execution_props())
+        })
+        .transpose()?;
+    Ok(Arc::new(ApplyExec::try_new(
+        physical_left,
+        right.clone(),
+        left.schema().clone(),
+        filter,
+        join_type,
+        SchemaRef::new(join_schema.as_ref().to_owned().into()),
+        session_state.clone(),
+    )?))
+}
+
 #[cfg(test)]
 mod tests {
     use std::any::Any;
@@ -16,4 +16,5 @@
 // under the License.
 
 mod provider_filter_pushdown;
+mod sort_pushdown;
 mod statistics;
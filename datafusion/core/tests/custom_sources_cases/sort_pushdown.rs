@@ -0,0 +1,181 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+
+use arrow::array::{Int32Array, RecordBatch};
+use arrow::compute::SortOptions;
+use arrow::datatypes::SchemaRef;
+use datafusion::datasource::provider::{TableProvider, TableType};
+use datafusion::error::Result;
+use datafusion::execution::context::{SessionContext, SessionState};
+use datafusion::logical_expr::Expr;
+use datafusion::physical_plan::memory::MemoryExec;
+use datafusion::physical_plan::{collect, displayable, ExecutionPlan};
+use datafusion::prelude::*;
+use datafusion_common::assert_batches_eq;
+use datafusion_physical_expr::expressions;
+use datafusion_physical_expr::PhysicalSortExpr;
+
+use async_trait::async_trait;
+
+/// A table which can scan its rows ordered by `a`, as if it had an index
+struct SortedProvider {
+    /// The rows in insertion order
+    unsorted: RecordBatch,
+    /// The rows ordered by `a`
+    sorted: RecordBatch,
+    /// The arguments of the calls to `scan_sorted`
+    requests: Mutex<Vec<(Vec<Expr>, Option<usize>)>>,
+}
+
+impl SortedProvider {
+    fn new() -> Result<Self> {
+        let batch = |values: Vec<i32>| {
+            RecordBatch::try_from_iter(vec![(
+                "a",
+                Arc::new(Int32Array::from(values)) as _,
+            )])
+        };
+        Ok(Self {
+            unsorted: batch(vec![3, 1, 4, 2])?,
+            sorted: batch(vec![1, 2, 3, 4])?,
+            requests: Mutex::new(vec![]),
+        })
+    }
+}
+
+#[async_trait]
+impl TableProvider for SortedProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.unsorted.schema()
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        _state: &SessionState,
+        projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        _limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(MemoryExec::try_new(
+            &[vec![self.unsorted.clone()]],
+            self.schema(),
+            projection.cloned(),
+        )?))
+    }
+
+    async fn scan_sorted(
+        &self,
+        _state: &SessionState,
+        projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        sort_exprs: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+        self.requests
+            .lock()
+            .unwrap()
+            .push((sort_exprs.to_vec(), limit));
+        if sort_exprs != [col("a").sort(true, false)] {
+            return Ok(None);
+        }
+        let ordering = vec![PhysicalSortExpr {
+            expr: expressions::col("a", &self.schema())?,
+            options: SortOptions {
+                descending: false,
+                nulls_first: false,
+            },
+        }];
+        let exec = MemoryExec::try_new(
+            &[vec![self.sorted.clone()]],
+            self.schema(),
+            projection.cloned(),
+        )?
+        .with_sort_information(vec![ordering]);
+        Ok(Some(Arc::new(exec)))
+    }
+}
+
+#[tokio::test]
+async fn sort_pushed_down_to_provider() -> Result<()> {
+    let ctx = SessionContext::new();
+    let provider = Arc::new(SortedProvider::new()?);
+    ctx.register_table("t", provider.clone())?;
+
+    let plan = ctx
+        .sql("SELECT a FROM t ORDER BY a")
+        .await?
+        .create_physical_plan()
+        .await?;
+    let formatted = displayable(plan.as_ref()).indent(true).to_string();
+    assert!(!formatted.contains("SortExec"), "{formatted}");
+
+    let batches = collect(plan, ctx.task_ctx()).await?;
+    let expected = [
+        "+---+", "| a |", "+---+", "| 1 |", "| 2 |", "| 3 |", "| 4 |", "+---+",
+    ];
+    assert_batches_eq!(expected, &batches);
+
+    let batches = ctx
+        .sql("SELECT a FROM t ORDER BY a LIMIT 2")
+        .await?
+        .collect()
+        .await?;
+    let expected = ["+---+", "| a |", "+---+", "| 1 |", "| 2 |", "+---+"];
+    assert_batches_eq!(expected, &batches);
+
+    let requests = provider.requests.lock().unwrap().clone();
+    assert_eq!(
+        requests,
+        vec![
+            (vec![col("a").sort(true, false)], None),
+            (vec![col("a").sort(true, false)], Some(2)),
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn unsupported_sort_not_pushed_down() -> Result<()> {
+    let ctx = SessionContext::new();
+    ctx.register_table("t", Arc::new(SortedProvider::new()?))?;
+
+    let plan = ctx
+        .sql("SELECT a FROM t ORDER BY a DESC")
+        .await?
+        .create_physical_plan()
+        .await?;
+    let formatted = displayable(plan.as_ref()).indent(true).to_string();
+    assert!(formatted.contains("SortExec"), "{formatted}");
+
+    let batches = collect(plan, ctx.task_ctx()).await?;
+    let expected = [
+        "+---+", "| a |", "+---+", "| 4 |", "| 3 |", "| 2 |", "| 1 |", "+---+",
+    ];
+    assert_batches_eq!(expected, &batches);
+    Ok(())
+}
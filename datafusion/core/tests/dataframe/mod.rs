@@ -981,6 +981,35 @@ async fn unnest_columns() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn unnest_multiple_columns() -> Result<()> {
+    // Unnest points and tags together, with the position of each element
+    let df = table_with_nested_types(4).await?;
+    let options = UnnestOptions::new().with_ordinality("ordinality");
+    let results = df
+        .unnest_columns_with_options(&["points", "tags"], options)?
+        .collect()
+        .await?;
+    let expected = [
+        "+----------+-----------------+------+------------+",
+        "| shape_id | points          | tags | ordinality |",
+        "+----------+-----------------+------+------------+",
+        "| 1        | {x: -3, y: -4}  | tag1 | 1          |",
+        "| 1        | {x: -3, y: 6}   |      | 2          |",
+        "| 1        | {x: 2, y: -2}   |      | 3          |",
+        "| 2        |                 | tag1 | 1          |",
+        "| 2        |                 | tag2 | 2          |",
+        "| 3        | {x: -9, y: 2}   |      | 1          |",
+        "| 3        | {x: -10, y: -4} |      | 2          |",
+        "| 4        | {x: -3, y: 5}   | tag1 | 1          |",
+        "| 4        | {x: 2, y: -1}   | tag2 | 2          |",
+        "| 4        |                 | tag3 | 3          |",
+        "+----------+-----------------+------+------------+",
+    ];
+    assert_batches_sorted_eq!(expected, &results);
+    Ok(())
+}
+
 #[tokio::test]
 async fn unnest_column_nulls() -> Result<()> {
     let df = table_with_lists_and_nulls().await?;
@@ -0,0 +1,134 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Fuzz test for [`DedupExec`], comparing its output to the distinct rows of
+//! its sorted input
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Int32Array, StringArray};
+use arrow::compute::{concat_batches, lexsort, SortColumn};
+use arrow::record_batch::RecordBatch;
+use datafusion::physical_plan::collect;
+use datafusion::physical_plan::dedup::DedupExec;
+use datafusion::physical_plan::memory::MemoryExec;
+use datafusion::prelude::SessionContext;
+use datafusion_common::ScalarValue;
+use datafusion_physical_expr::expressions::col;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use test_utils::stagger_batch_with_seed;
+
+#[tokio::test]
+async fn dedup_single_column() {
+    for seed in 0..8 {
+        for num_rows in [10, 1000, 5000] {
+            run_dedup_test(seed, 1, num_rows).await;
+        }
+    }
+}
+
+#[tokio::test]
+async fn dedup_multi_column() {
+    for seed in 0..8 {
+        for num_rows in [10, 1000, 5000] {
+            run_dedup_test(seed, 4, num_rows).await;
+        }
+    }
+}
+
+/// Deduplicates `num_rows` random sorted rows of `num_columns` columns, in
+/// batches of random sizes
+async fn run_dedup_test(seed: u64, num_columns: usize, num_rows: usize) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let batch = make_sorted_batch(&mut rng, num_columns, num_rows);
+    let schema = batch.schema();
+
+    let mut expected = rows(&batch);
+    expected.dedup();
+
+    let exprs = schema
+        .fields()
+        .iter()
+        .map(|field| (col(field.name(), &schema).unwrap(), field.name().clone()))
+        .collect();
+    let batches = stagger_batch_with_seed(batch, seed);
+    let input = Arc::new(MemoryExec::try_new(&[batches], schema.clone(), None).unwrap());
+    let dedup = Arc::new(DedupExec::try_new(exprs, input, false).unwrap());
+
+    let ctx = SessionContext::new();
+    let output = collect(dedup, ctx.task_ctx()).await.unwrap();
+    let actual = rows(&concat_batches(&schema, &output).unwrap());
+
+    assert_eq!(
+        expected, actual,
+        "seed: {seed}, num_columns: {num_columns}, num_rows: {num_rows}"
+    );
+}
+
+/// Returns a batch of random Int32 and Utf8 columns of few distinct values
+/// and some nulls, sorted on all of its columns
+fn make_sorted_batch(
+    rng: &mut StdRng,
+    num_columns: usize,
+    num_rows: usize,
+) -> RecordBatch {
+    let columns = (0..num_columns).map(|i| {
+        let array: ArrayRef = if i % 2 == 0 {
+            Arc::new(
+                (0..num_rows)
+                    .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..4)))
+                    .collect::<Int32Array>(),
+            )
+        } else {
+            Arc::new(
+                (0..num_rows)
+                    .map(|_| {
+                        rng.gen_bool(0.9)
+                            .then(|| ["", "a", "bb", "ccc"][rng.gen_range(0..4)])
+                    })
+                    .collect::<StringArray>(),
+            )
+        };
+        (format!("c{i}"), array)
+    });
+    let batch = RecordBatch::try_from_iter(columns).unwrap();
+
+    let sort_columns = batch
+        .columns()
+        .iter()
+        .map(|values| SortColumn {
+            values: values.clone(),
+            options: None,
+        })
+        .collect::<Vec<_>>();
+    let sorted = lexsort(&sort_columns, None).unwrap();
+    RecordBatch::try_new(batch.schema(), sorted).unwrap()
+}
+
+/// Returns the values of every row of `batch`
+fn rows(batch: &RecordBatch) -> Vec<Vec<ScalarValue>> {
+    (0..batch.num_rows())
+        .map(|row| {
+            batch
+                .columns()
+                .iter()
+                .map(|column| ScalarValue::try_from_array(column, row).unwrap())
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,153 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Fuzz test for grouping on several columns, checking that the groups
+//! survive the round trip through the arrow row format
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Int32Array};
+use arrow::compute::cast;
+use arrow::datatypes::DataType;
+use arrow::record_batch::RecordBatch;
+use datafusion::datasource::MemTable;
+use datafusion::prelude::{SessionConfig, SessionContext};
+use datafusion_common::ScalarValue;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use test_utils::stagger_batch_with_seed;
+
+/// The types of the group columns, which are cast from random integers
+const DATA_TYPES: [DataType; 11] = [
+    DataType::Boolean,
+    DataType::Int8,
+    DataType::Int64,
+    DataType::UInt32,
+    DataType::Float64,
+    DataType::Date32,
+    DataType::Decimal128(10, 2),
+    DataType::Utf8,
+    DataType::LargeUtf8,
+    DataType::Binary,
+    DataType::LargeBinary,
+];
+
+#[tokio::test]
+async fn group_by_narrow_columns() {
+    for seed in 0..8 {
+        for null_density in [0.0, 0.1, 0.5] {
+            run_group_by_test(seed, 2, null_density).await;
+        }
+    }
+}
+
+#[tokio::test]
+async fn group_by_wide_columns() {
+    for seed in 0..8 {
+        for null_density in [0.0, 0.1, 0.5] {
+            run_group_by_test(seed, 12, null_density).await;
+        }
+    }
+}
+
+/// Groups 1000 random rows of `num_columns` columns of random types on all
+/// of their columns, and compares the groups and their counts to those of
+/// the input rows
+async fn run_group_by_test(seed: u64, num_columns: usize, null_density: f64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let batch = make_batch(&mut rng, num_columns, 1000, null_density);
+    let schema = batch.schema();
+
+    let mut expected = HashMap::<Vec<ScalarValue>, i64>::new();
+    for row in rows(&batch) {
+        *expected.entry(row).or_default() += 1;
+    }
+
+    let batches = stagger_batch_with_seed(batch, seed);
+    let table = MemTable::try_new(schema.clone(), vec![batches]).unwrap();
+    let ctx =
+        SessionContext::new_with_config(SessionConfig::new().with_target_partitions(4));
+    ctx.register_table("t", Arc::new(table)).unwrap();
+
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| field.name().as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let sql = format!("SELECT {columns}, COUNT(*) FROM t GROUP BY {columns}");
+    let output = ctx.sql(&sql).await.unwrap().collect().await.unwrap();
+
+    let mut actual = HashMap::new();
+    for batch in &output {
+        for mut row in rows(batch) {
+            let Some(ScalarValue::Int64(Some(count))) = row.pop() else {
+                panic!("expected a count");
+            };
+            assert!(actual.insert(row, count).is_none(), "duplicate group");
+        }
+    }
+
+    assert_eq!(
+        expected, actual,
+        "seed: {seed}, num_columns: {num_columns}, null_density: {null_density}"
+    );
+}
+
+/// Returns a batch of columns of random types, with few distinct values and
+/// `null_density` nulls
+fn make_batch(
+    rng: &mut StdRng,
+    num_columns: usize,
+    num_rows: usize,
+    null_density: f64,
+) -> RecordBatch {
+    let columns = (0..num_columns).map(|i| {
+        let values = (0..num_rows)
+            .map(|_| (!rng.gen_bool(null_density)).then(|| rng.gen_range(0..3)))
+            .collect::<Int32Array>();
+        let data_type = &DATA_TYPES[rng.gen_range(0..DATA_TYPES.len())];
+        let array: ArrayRef = match data_type {
+            // casting integers to binary is not supported
+            DataType::Binary => {
+                let strings = cast(&values, &DataType::Utf8).unwrap();
+                cast(&strings, data_type).unwrap()
+            }
+            DataType::LargeBinary => {
+                let strings = cast(&values, &DataType::LargeUtf8).unwrap();
+                cast(&strings, data_type).unwrap()
+            }
+            _ => cast(&values, data_type).unwrap(),
+        };
+        (format!("c{i}"), array)
+    });
+    RecordBatch::try_from_iter(columns).unwrap()
+}
+
+/// Returns the values of every row of `batch`
+fn rows(batch: &RecordBatch) -> Vec<Vec<ScalarValue>> {
+    (0..batch.num_rows())
+        .map(|row| {
+            batch
+                .columns()
+                .iter()
+                .map(|column| ScalarValue::try_from_array(column, row).unwrap())
+                .collect()
+        })
+        .collect()
+}
@@ -16,7 +16,9 @@
 // under the License.
 
 mod aggregate_fuzz;
+mod dedup_fuzz;
 mod distinct_count_string_fuzz;
+mod group_values_fuzz;
 mod join_fuzz;
 mod merge_fuzz;
 mod sort_fuzz;
@@ -819,6 +819,29 @@ async fn explain_physical_plan_only() {
     assert_eq!(expected, actual);
 }
 
+#[tokio::test]
+async fn explain_verbose_optimizer_timing() {
+    let mut config = ConfigOptions::new();
+    config.explain.show_optimizer_timing = true;
+    let ctx = SessionContext::new_with_config(config.into());
+    let sql = "EXPLAIN VERBOSE select * from (values (1), (2)) as t (c1) where c1 > 1";
+    let actual = execute(&ctx, sql).await;
+
+    // every optimizer rule is followed by its elapsed time, such as
+    // `logical_plan after simplify_expressions (12.3µs)`
+    let simplify_rows = actual
+        .iter()
+        .map(|row| &row[0])
+        .filter(|plan_type| {
+            plan_type.starts_with("logical_plan after simplify_expressions (")
+        })
+        .collect::<Vec<_>>();
+    assert!(!simplify_rows.is_empty(), "{actual:?}");
+    for plan_type in simplify_rows {
+        assert!(plan_type.ends_with("s)"), "{plan_type}");
+    }
+}
+
 #[tokio::test]
 async fn csv_explain_analyze_with_statistics() {
     let mut config = ConfigOptions::new();
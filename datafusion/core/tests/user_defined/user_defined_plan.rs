@@ -214,6 +214,19 @@ async fn topk_query() -> Result<()> {
     run_and_compare_query(ctx, "Topk context").await
 }
 
+#[tokio::test]
+// Run the query using topk optimization, planned by the default query planner
+// with a registered extension planner
+async fn topk_query_with_extension_planner() -> Result<()> {
+    let config = SessionConfig::new().with_target_partitions(48);
+    let runtime = Arc::new(RuntimeEnv::default());
+    let state = SessionState::new_with_config_rt(config, runtime)
+        .add_extension_planner(Arc::new(TopKPlanner {}))
+        .add_optimizer_rule(Arc::new(TopKOptimizerRule {}));
+    let ctx = setup_table(SessionContext::new_with_state(state)).await?;
+    run_and_compare_query(ctx, "Topk extension planner context").await
+}
+
 #[tokio::test]
 // Run EXPLAIN PLAN and show the plan was in fact rewritten
 async fn topk_plan() -> Result<()> {
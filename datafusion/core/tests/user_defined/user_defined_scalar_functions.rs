@@ -22,8 +22,9 @@ use arrow_array::{
 use arrow_schema::DataType::Float64;
 use arrow_schema::{DataType, Field, Schema};
 use datafusion::execution::context::{FunctionFactory, RegisterFunction, SessionState};
+use datafusion::execution::registry::{FunctionPackage, FunctionRegistry};
 use datafusion::prelude::*;
-use datafusion::{execution::registry::FunctionRegistry, test_util};
+use datafusion::test_util;
 use datafusion_common::tree_node::{Transformed, TreeNode};
 use datafusion_common::{
     assert_batches_eq, assert_batches_sorted_eq, cast::as_float64_array,
@@ -520,6 +521,56 @@ async fn deregister_udf() -> Result<()> {
     Ok(())
 }
 
+struct TestPackage {}
+
+impl FunctionPackage for TestPackage {
+    fn name(&self) -> &str {
+        "test"
+    }
+
+    fn namespace(&self) -> Option<&str> {
+        Some("test_pkg")
+    }
+
+    fn scalar_functions(&self) -> Vec<Arc<ScalarUDF>> {
+        let identity = create_udf(
+            "identity",
+            vec![DataType::Int32],
+            Arc::new(DataType::Int32),
+            Volatility::Immutable,
+            Arc::new(|args: &[ColumnarValue]| Ok(args[0].clone())),
+        );
+        vec![Arc::new(identity)]
+    }
+}
+
+#[tokio::test]
+async fn register_function_package() -> Result<()> {
+    let ctx = SessionContext::new();
+    let arr = Int32Array::from(vec![1]);
+    let batch = RecordBatch::try_from_iter(vec![("i", Arc::new(arr) as _)])?;
+    ctx.register_batch("t", batch)?;
+
+    ctx.register_function_package(&TestPackage {})?;
+    assert!(ctx.udfs().contains("identity"));
+    assert!(ctx.udfs().contains("test_pkg.identity"));
+
+    let expected = [
+        "+---------------+",
+        "| identity(t.i) |",
+        "+---------------+",
+        "| 1             |",
+        "+---------------+",
+    ];
+    let result = plan_and_collect(&ctx, "SELECT identity(i) FROM t").await?;
+    assert_batches_eq!(expected, &result);
+
+    let result = plan_and_collect(&ctx, "SELECT test_pkg.identity(i) FROM t").await?;
+    assert_batches_eq!(expected, &result);
+
+    Ok(())
+}
+
 #[derive(Debug)]
 struct CastToI64UDF {
     signature: Signature,
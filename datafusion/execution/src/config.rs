@@ -229,6 +229,15 @@ impl SessionConfig {
         self.options.optimizer.repartition_joins
     }
 
+    /// The maximum estimated size in bytes of a hash join input that is
+    /// broadcast (collected into a single partition) instead of being
+    /// repartitioned. See [hash_join_single_partition_threshold] for more details
+    ///
+    /// [hash_join_single_partition_threshold]: datafusion_common::config::OptimizerOptions::hash_join_single_partition_threshold
+    pub fn broadcast_join_threshold(&self) -> usize {
+        self.options.optimizer.hash_join_single_partition_threshold
+    }
+
     /// Are aggregates repartitioned during execution?
     pub fn repartition_aggregations(&self) -> bool {
         self.options.optimizer.repartition_aggregations
@@ -287,6 +296,15 @@ impl SessionConfig {
         self
     }
 
+    /// Sets the maximum estimated size in bytes of a hash join input that is
+    /// broadcast (collected into a single partition) instead of being
+    /// repartitioned. Use the `/*+ BROADCAST(t) */` and `/*+ SHUFFLE_HASH */`
+    /// SQL hints to override this choice for individual joins.
+    pub fn with_broadcast_join_threshold(mut self, bytes: usize) -> Self {
+        self.options.optimizer.hash_join_single_partition_threshold = bytes;
+        self
+    }
+
     /// Enables or disables the use of repartitioning for aggregations to improve parallelism
     pub fn with_repartition_aggregations(mut self, enabled: bool) -> Self {
         self.options.optimizer.repartition_aggregations = enabled;
@@ -439,6 +457,27 @@ impl SessionConfig {
         self
     }
 
+    /// Set the maximum number of spilled runs that a sort merges at once,
+    /// see [`sort_spill_merge_fan_in`]
+    ///
+    /// [`sort_spill_merge_fan_in`]: datafusion_common::config::ExecutionOptions::sort_spill_merge_fan_in
+    pub fn with_sort_spill_merge_fan_in(
+        mut self,
+        sort_spill_merge_fan_in: usize,
+    ) -> Self {
+        self.options.execution.sort_spill_merge_fan_in = sort_spill_merge_fan_in;
+        self
+    }
+
+    /// Set the SQL dialect used to parse SQL statements, such as `MySQL` or
+    /// `Hive`, see [`dialect`]
+    ///
+    /// [`dialect`]: datafusion_common::config::SqlParserOptions::dialect
+    pub fn with_sql_dialect(mut self, dialect: &str) -> Self {
+        self.options.sql_parser.dialect = dialect.to_string();
+        self
+    }
+
     /// Convert configuration options to name-value pairs with values
     /// converted to strings.
     ///
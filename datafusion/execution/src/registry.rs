@@ -110,6 +110,104 @@ pub trait FunctionRegistry {
     }
 }
 
+/// A bundle of related user defined functions, such as a library of
+/// geospatial or cryptographic functions, that can be registered with a
+/// [`FunctionRegistry`] (for example a `SessionContext`) in one call.
+///
+/// If the package has a [`namespace`](Self::namespace), its functions can
+/// also be called by their name qualified by the namespace, e.g.
+/// `geo.st_distance(a, b)` as well as `st_distance(a, b)`.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use datafusion_execution::registry::{FunctionPackage, FunctionRegistry, MemoryFunctionRegistry};
+/// # use datafusion_expr::{create_udf, ColumnarValue, ScalarUDF, Volatility};
+/// # use arrow::datatypes::DataType;
+/// struct GeoPackage {}
+///
+/// impl FunctionPackage for GeoPackage {
+///     fn name(&self) -> &str {
+///         "geo"
+///     }
+///
+///     fn namespace(&self) -> Option<&str> {
+///         Some("geo")
+///     }
+///
+///     fn scalar_functions(&self) -> Vec<Arc<ScalarUDF>> {
+///         let st_distance = create_udf(
+///             "st_distance",
+///             vec![DataType::Float64, DataType::Float64],
+///             Arc::new(DataType::Float64),
+///             Volatility::Immutable,
+///             Arc::new(|args: &[ColumnarValue]| Ok(args[0].clone())),
+///         );
+///         vec![Arc::new(st_distance)]
+///     }
+/// }
+///
+/// let mut registry = MemoryFunctionRegistry::new();
+/// GeoPackage {}.register_all(&mut registry).unwrap();
+/// assert!(registry.udf("st_distance").is_ok());
+/// assert!(registry.udf("geo.st_distance").is_ok());
+/// ```
+pub trait FunctionPackage: Send + Sync {
+    /// Name of the package
+    fn name(&self) -> &str;
+
+    /// Namespace that qualifies the names of the functions of this package,
+    /// if any
+    fn namespace(&self) -> Option<&str> {
+        None
+    }
+
+    /// The scalar functions of this package
+    fn scalar_functions(&self) -> Vec<Arc<ScalarUDF>> {
+        vec![]
+    }
+
+    /// The aggregate functions of this package
+    fn aggregate_functions(&self) -> Vec<Arc<AggregateUDF>> {
+        vec![]
+    }
+
+    /// The window functions of this package
+    fn window_functions(&self) -> Vec<Arc<WindowUDF>> {
+        vec![]
+    }
+
+    /// Registers all functions of this package with `registry`, overwriting
+    /// any previously registered functions with the same names.
+    fn register_all(&self, registry: &mut dyn FunctionRegistry) -> Result<()> {
+        let qualify = |name: &str| {
+            self.namespace()
+                .map(|namespace| format!("{namespace}.{name}"))
+        };
+        for udf in self.scalar_functions() {
+            let udf = match qualify(udf.name()) {
+                Some(alias) => Arc::new(udf.as_ref().clone().with_aliases([alias])),
+                None => udf,
+            };
+            registry.register_udf(udf)?;
+        }
+        for udaf in self.aggregate_functions() {
+            let udaf = match qualify(udaf.name()) {
+                Some(alias) => Arc::new(udaf.as_ref().clone().with_aliases([alias])),
+                None => udaf,
+            };
+            registry.register_udaf(udaf)?;
+        }
+        for udwf in self.window_functions() {
+            let udwf = match qualify(udwf.name()) {
+                Some(alias) => Arc::new(udwf.as_ref().clone().with_aliases([alias])),
+                None => udwf,
+            };
+            registry.register_udwf(udwf)?;
+        }
+        Ok(())
+    }
+}
+
 /// Serializer and deserializer registry for extensions like [UserDefinedLogicalNode].
 pub trait SerializerRegistry: Send + Sync {
     /// Serialize this node to a byte array. This serialization should not include
@@ -104,6 +104,21 @@ impl TaskContext {
         &self.session_config
     }
 
+    /// Return the scalar functions of this [TaskContext], by name and alias
+    pub fn scalar_functions(&self) -> &HashMap<String, Arc<ScalarUDF>> {
+        &self.scalar_functions
+    }
+
+    /// Return the aggregate functions of this [TaskContext], by name and alias
+    pub fn aggregate_functions(&self) -> &HashMap<String, Arc<AggregateUDF>> {
+        &self.aggregate_functions
+    }
+
+    /// Return the window functions of this [TaskContext], by name and alias
+    pub fn window_functions(&self) -> &HashMap<String, Arc<WindowUDF>> {
+        &self.window_functions
+    }
+
     /// Return the `session_id` of this [TaskContext]
     pub fn session_id(&self) -> String {
         self.session_id.clone()
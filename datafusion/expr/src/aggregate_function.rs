@@ -47,6 +47,10 @@ pub enum AggregateFunction {
     Median,
     /// Approximate distinct function
     ApproxDistinct,
+    /// Binary sketch of an approximate distinct function
+    ApproxDistinctSketch,
+    /// Approximate distinct function over merged sketches
+    ApproxDistinctMerge,
     /// Aggregation into an array
     ArrayAgg,
     /// First value in a group according to some ordering
@@ -91,6 +95,10 @@ pub enum AggregateFunction {
     ApproxPercentileCont,
     /// Approximate continuous percentile function with weight
     ApproxPercentileContWithWeight,
+    /// Binary sketch of an approximate continuous percentile function
+    ApproxPercentileContSketch,
+    /// Approximate continuous percentile function over merged sketches
+    ApproxPercentileContMerge,
     /// ApproxMedian
     ApproxMedian,
     /// Grouping
@@ -120,6 +128,8 @@ impl AggregateFunction {
             Avg => "AVG",
             Median => "MEDIAN",
             ApproxDistinct => "APPROX_DISTINCT",
+            ApproxDistinctSketch => "APPROX_DISTINCT_SKETCH",
+            ApproxDistinctMerge => "APPROX_DISTINCT_MERGE",
             ArrayAgg => "ARRAY_AGG",
             FirstValue => "FIRST_VALUE",
             LastValue => "LAST_VALUE",
@@ -142,6 +152,8 @@ impl AggregateFunction {
             RegrSXY => "REGR_SXY",
             ApproxPercentileCont => "APPROX_PERCENTILE_CONT",
             ApproxPercentileContWithWeight => "APPROX_PERCENTILE_CONT_WITH_WEIGHT",
+            ApproxPercentileContSketch => "APPROX_PERCENTILE_CONT_SKETCH",
+            ApproxPercentileContMerge => "APPROX_PERCENTILE_CONT_MERGE",
             ApproxMedian => "APPROX_MEDIAN",
             Grouping => "GROUPING",
             BitAnd => "BIT_AND",
@@ -204,11 +216,19 @@ impl FromStr for AggregateFunction {
             "regr_sxy" => AggregateFunction::RegrSXY,
             // approximate
             "approx_distinct" => AggregateFunction::ApproxDistinct,
+            "approx_distinct_sketch" => AggregateFunction::ApproxDistinctSketch,
+            "approx_distinct_merge" => AggregateFunction::ApproxDistinctMerge,
             "approx_median" => AggregateFunction::ApproxMedian,
             "approx_percentile_cont" => AggregateFunction::ApproxPercentileCont,
             "approx_percentile_cont_with_weight" => {
                 AggregateFunction::ApproxPercentileContWithWeight
             }
+            "approx_percentile_cont_sketch" => {
+                AggregateFunction::ApproxPercentileContSketch
+            }
+            "approx_percentile_cont_merge" => {
+                AggregateFunction::ApproxPercentileContMerge
+            }
             // other
             "grouping" => AggregateFunction::Grouping,
             _ => {
@@ -290,6 +310,10 @@ impl AggregateFunction {
             AggregateFunction::ApproxPercentileContWithWeight => {
                 Ok(coerced_data_types[0].clone())
             }
+            AggregateFunction::ApproxDistinctSketch
+            | AggregateFunction::ApproxPercentileContSketch => Ok(DataType::Binary),
+            AggregateFunction::ApproxDistinctMerge => Ok(DataType::UInt64),
+            AggregateFunction::ApproxPercentileContMerge => Ok(DataType::Float64),
             AggregateFunction::ApproxMedian | AggregateFunction::Median => {
                 Ok(coerced_data_types[0].clone())
             }
@@ -322,6 +346,7 @@ impl AggregateFunction {
         match self {
             AggregateFunction::Count => Signature::variadic_any(Volatility::Immutable),
             AggregateFunction::ApproxDistinct
+            | AggregateFunction::ApproxDistinctSketch
             | AggregateFunction::Grouping
             | AggregateFunction::ArrayAgg => Signature::any(1, Volatility::Immutable),
             AggregateFunction::Min | AggregateFunction::Max => {
@@ -352,6 +377,7 @@ impl AggregateFunction {
             | AggregateFunction::StddevPop
             | AggregateFunction::Median
             | AggregateFunction::ApproxMedian
+            | AggregateFunction::ApproxPercentileContSketch
             | AggregateFunction::FirstValue
             | AggregateFunction::LastValue => {
                 Signature::uniform(1, NUMERICS.to_vec(), Volatility::Immutable)
@@ -404,6 +430,17 @@ impl AggregateFunction {
                     .collect(),
                 Volatility::Immutable,
             ),
+            AggregateFunction::ApproxDistinctMerge => {
+                Signature::uniform(1, BINARYS.to_vec(), Volatility::Immutable)
+            }
+            AggregateFunction::ApproxPercentileContMerge => Signature::one_of(
+                // Accept any binary sketch paired with a float64 percentile
+                BINARYS
+                    .iter()
+                    .map(|t| TypeSignature::Exact(vec![t.clone(), DataType::Float64]))
+                    .collect(),
+                Volatility::Immutable,
+            ),
             AggregateFunction::StringAgg => {
                 Signature::uniform(2, STRINGS.to_vec(), Volatility::Immutable)
             }
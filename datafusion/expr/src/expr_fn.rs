@@ -315,6 +315,31 @@ pub fn approx_distinct(expr: Expr) -> Expr {
     ))
 }
 
+/// Returns the HyperLogLog sketch of `expr` used by [`approx_distinct`] as binary.
+pub fn approx_distinct_sketch(expr: Expr) -> Expr {
+    Expr::AggregateFunction(AggregateFunction::new(
+        aggregate_function::AggregateFunction::ApproxDistinctSketch,
+        vec![expr],
+        false,
+        None,
+        None,
+        None,
+    ))
+}
+
+/// Returns the approximate number of distinct values of the union of the
+/// binary sketches produced by [`approx_distinct_sketch`].
+pub fn approx_distinct_merge(sketch: Expr) -> Expr {
+    Expr::AggregateFunction(AggregateFunction::new(
+        aggregate_function::AggregateFunction::ApproxDistinctMerge,
+        vec![sketch],
+        false,
+        None,
+        None,
+        None,
+    ))
+}
+
 /// Calculate the median for `expr`.
 pub fn median(expr: Expr) -> Expr {
     Expr::AggregateFunction(AggregateFunction::new(
@@ -351,6 +376,31 @@ pub fn approx_percentile_cont(expr: Expr, percentile: Expr) -> Expr {
     ))
 }
 
+/// Returns the t-digest sketch of `expr` used by [`approx_percentile_cont`] as binary.
+pub fn approx_percentile_cont_sketch(expr: Expr) -> Expr {
+    Expr::AggregateFunction(AggregateFunction::new(
+        aggregate_function::AggregateFunction::ApproxPercentileContSketch,
+        vec![expr],
+        false,
+        None,
+        None,
+        None,
+    ))
+}
+
+/// Calculate an approximation of the specified `percentile` of the union of the
+/// binary sketches produced by [`approx_percentile_cont_sketch`].
+pub fn approx_percentile_cont_merge(sketch: Expr, percentile: Expr) -> Expr {
+    Expr::AggregateFunction(AggregateFunction::new(
+        aggregate_function::AggregateFunction::ApproxPercentileContMerge,
+        vec![sketch, percentile],
+        false,
+        None,
+        None,
+        None,
+    ))
+}
+
 /// Calculate an approximation of the specified `percentile` for `expr` and `weight_expr`.
 pub fn approx_percentile_cont_with_weight(
     expr: Expr,
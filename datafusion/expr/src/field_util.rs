@@ -82,6 +82,8 @@ impl GetFieldAccessSchema {
                     (DataType::List(_), _) | (DataType::LargeList(_), _) => plan_err!(
                         "Only ints are valid as an indexed field in a List/LargeList"
                     ),
+                    // `map[key]` with a non-string key
+                    (DataType::Map(_, _), _) => Self::NamedStructField { name: ScalarValue::Null }.get_accessed_field(data_type),
                     (other, _) => plan_err!("The expression to get an indexed field is only valid for `List`, `LargeList` or `Struct` types, got {other}"),
                 }
             }
@@ -822,6 +822,8 @@ impl LogicalPlanBuilder {
             join_constraint: JoinConstraint::On,
             schema: DFSchemaRef::new(join_schema),
             null_equals_null,
+            strategy_hint: None,
+            null_aware: false,
         })))
     }
 
@@ -886,6 +888,8 @@ impl LogicalPlanBuilder {
                 join_constraint: JoinConstraint::Using,
                 schema: DFSchemaRef::new(join_schema),
                 null_equals_null: false,
+                strategy_hint: None,
+                null_aware: false,
             })))
         }
     }
@@ -962,6 +966,7 @@ impl LogicalPlanBuilder {
 
             Ok(Self::from(LogicalPlan::Explain(Explain {
                 verbose,
+                estimate: false,
                 plan: Arc::new(self.plan),
                 stringified_plans,
                 schema,
@@ -970,6 +975,26 @@ impl LogicalPlanBuilder {
         }
     }
 
+    /// Create an expression to represent the estimated cost of the plan
+    /// without running it: the physical plan is annotated with the
+    /// estimated number of rows, bytes and memory of each operator.
+    ///
+    /// if `verbose` is true, prints out additional details.
+    pub fn explain_estimate(self, verbose: bool) -> Result<Self> {
+        let schema = LogicalPlan::explain_schema().to_dfschema_ref()?;
+        let stringified_plans =
+            vec![self.plan.to_stringified(PlanType::InitialLogicalPlan)];
+
+        Ok(Self::from(LogicalPlan::Explain(Explain {
+            verbose,
+            estimate: true,
+            plan: Arc::new(self.plan),
+            stringified_plans,
+            schema,
+            logical_optimization_succeeded: false,
+        })))
+    }
+
     /// Process intersect set operator
     pub fn intersect(
         left_plan: LogicalPlan,
@@ -1107,6 +1132,8 @@ impl LogicalPlanBuilder {
             join_constraint: JoinConstraint::On,
             schema: DFSchemaRef::new(join_schema),
             null_equals_null: false,
+            strategy_hint: None,
+            null_aware: false,
         })))
     }
 
@@ -1127,6 +1154,17 @@ impl LogicalPlanBuilder {
             options,
         )?))
     }
+
+    /// Unnest the given columns together given [`UnnestOptions`]
+    pub fn unnest_columns_with_options(
+        self,
+        columns: Vec<Column>,
+        options: UnnestOptions,
+    ) -> Result<Self> {
+        Ok(Self::from(unnest_columns_with_options(
+            self.plan, columns, options,
+        )?))
+    }
 }
 pub fn change_redundant_column(fields: &Fields) -> Vec<Field> {
     let mut name_map = HashMap::new();
@@ -1544,47 +1582,71 @@ pub fn unnest_with_options(
     column: Column,
     options: UnnestOptions,
 ) -> Result<LogicalPlan> {
-    let (unnest_qualifier, unnest_field) =
-        input.schema().qualified_field_from_column(&column)?;
-
-    // Extract the type of the nested field in the list.
-    let unnested_field = match unnest_field.data_type() {
-        DataType::List(field)
-        | DataType::FixedSizeList(field, _)
-        | DataType::LargeList(field) => Arc::new(Field::new(
-            unnest_field.name(),
-            field.data_type().clone(),
-            unnest_field.is_nullable(),
-        )),
-        _ => {
-            // If the unnest field is not a list type return the input plan.
-            return Ok(input);
-        }
-    };
+    unnest_columns_with_options(input, vec![column], options)
+}
 
-    // Update the schema with the unnest column type changed to contain the nested type.
+/// Create a [`LogicalPlan::Unnest`] plan that unnests several columns
+/// together, see [`UnnestOptions`]
+pub fn unnest_columns_with_options(
+    input: LogicalPlan,
+    columns: Vec<Column>,
+    options: UnnestOptions,
+) -> Result<LogicalPlan> {
     let input_schema = input.schema();
-    let fields = input_schema
+    let mut fields = input_schema
         .iter()
-        .map(|(q, f)| {
-            if f.as_ref() == unnest_field && q == unnest_qualifier {
-                (unnest_qualifier.cloned(), unnested_field.clone())
-            } else {
-                (q.cloned(), f.clone())
-            }
-        })
+        .map(|(q, f)| (q.cloned(), f.clone()))
         .collect::<Vec<_>>();
+    let mut unnest_columns = Vec::with_capacity(columns.len());
+    for column in &columns {
+        let (qualifier, input_field) =
+            input_schema.qualified_field_from_column(column)?;
+        let index =
+            input_schema.index_of_column(&Column::from((qualifier, input_field)))?;
+        let (unnest_qualifier, unnest_field) = &fields[index];
+
+        // Extract the type of the nested field in the list.
+        let unnested_field = match unnest_field.data_type() {
+            DataType::List(field)
+            | DataType::FixedSizeList(field, _)
+            | DataType::LargeList(field) => Arc::new(Field::new(
+                unnest_field.name(),
+                field.data_type().clone(),
+                // values of shorter lists are padded with nulls
+                unnest_field.is_nullable() || columns.len() > 1,
+            )),
+            // Columns that are not a list type are left unchanged.
+            _ => continue,
+        };
+
+        // Update the schema with the unnest column type changed to contain the nested type.
+        let column = Column::from((unnest_qualifier.as_ref(), unnested_field.as_ref()));
+        if unnest_columns.contains(&column) {
+            return plan_err!("Column {column} is unnested more than once");
+        }
+        fields[index].1 = unnested_field;
+        unnest_columns.push(column);
+    }
+    if unnest_columns.is_empty() {
+        // If no unnest field is a list type return the input plan.
+        return Ok(input);
+    }
+    if let Some(ordinality) = &options.ordinality {
+        fields.push((
+            None,
+            Arc::new(Field::new(ordinality, DataType::Int64, false)),
+        ));
+    }
 
     let metadata = input_schema.metadata().clone();
     let df_schema = DFSchema::new_with_metadata(fields, metadata)?;
     // We can use the existing functional dependencies:
     let deps = input_schema.functional_dependencies().clone();
     let schema = Arc::new(df_schema.with_functional_dependencies(deps)?);
-    let column = Column::from((unnest_qualifier, unnested_field.as_ref()));
 
     Ok(LogicalPlan::Unnest(Unnest {
         input: Arc::new(input),
-        column,
+        columns: unnest_columns,
         schema,
         options,
     }))
@@ -2065,6 +2127,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn plan_builder_unnest_zipped_columns() -> Result<()> {
+        // Unnesting both lists together, skipping the scalar column.
+        let columns = vec!["scalar".into(), "strings".into(), "structs".into()];
+        let options = UnnestOptions::new().with_ordinality("ordinality");
+        let plan = nested_table_scan("test_table")?
+            .unnest_columns_with_options(columns, options)?
+            .build()?;
+
+        let expected = "\
+        Unnest: test_table.strings, test_table.structs ordinality=ordinality\
+        \n  TableScan: test_table";
+        assert_eq!(expected, format!("{plan:?}"));
+
+        // The shorter list is padded with nulls
+        let field = plan
+            .schema()
+            .field_with_name(Some(&TableReference::bare("test_table")), "strings")?;
+        assert_eq!(&DataType::Utf8, field.data_type());
+        assert!(field.is_nullable());
+        let field = plan.schema().field_with_unqualified_name("ordinality")?;
+        assert_eq!(&DataType::Int64, field.data_type());
+
+        // Unnesting the same column twice should fail.
+        let columns = vec!["strings".into(), "test_table.strings".into()];
+        let plan = nested_table_scan("test_table")?
+            .unnest_columns_with_options(columns, UnnestOptions::new());
+        assert!(plan.is_err());
+
+        Ok(())
+    }
+
     fn nested_table_scan(table_name: &str) -> Result<LogicalPlanBuilder> {
         // Create a schema with a scalar field, a list of strings, and a list of structs.
         let struct_field = Field::new_struct(
@@ -24,7 +24,7 @@ use std::sync::Arc;
 
 use super::dml::CopyTo;
 use super::DdlStatement;
-use crate::builder::change_redundant_column;
+use crate::builder::{change_redundant_column, unnest_columns_with_options};
 use crate::expr::{
     Alias, Exists, InSubquery, Placeholder, Sort as SortExpr, WindowFunction,
 };
@@ -58,7 +58,7 @@ use datafusion_common::{
 use crate::display::PgJsonVisitor;
 use crate::tree_node::transform_option_vec;
 pub use datafusion_common::display::{PlanType, StringifiedPlan, ToStringifiedPlan};
-pub use datafusion_common::{JoinConstraint, JoinType};
+pub use datafusion_common::{JoinConstraint, JoinStrategyHint, JoinType};
 
 /// A LogicalPlan represents the different types of relational
 /// operators (such as Projection, Filter, etc) and can be created by
@@ -370,9 +370,12 @@ impl LogicalPlan {
             LogicalPlan::TableScan(TableScan { filters, .. }) => {
                 filters.iter().apply_until_stop(f)
             }
-            LogicalPlan::Unnest(Unnest { column, .. }) => {
-                f(&Expr::Column(column.clone()))
-            }
+            LogicalPlan::Unnest(Unnest { columns, .. }) => columns
+                .iter()
+                .map(|column| Expr::Column(column.clone()))
+                .collect::<Vec<_>>()
+                .iter()
+                .apply_until_stop(f),
             LogicalPlan::Distinct(Distinct::On(DistinctOn {
                 on_expr,
                 select_expr,
@@ -502,6 +505,8 @@ impl LogicalPlan {
                 join_constraint,
                 schema,
                 null_equals_null,
+                strategy_hint,
+                null_aware,
             }) => map_until_stop_and_collect!(
                 on.into_iter().map_until_stop_and_collect(
                     |on| map_until_stop_and_collect!(f(on.0), on.1, f(on.1))
@@ -521,6 +526,8 @@ impl LogicalPlan {
                     join_constraint,
                     schema,
                     null_equals_null,
+                    strategy_hint,
+                    null_aware,
                 })
             }),
             LogicalPlan::Sort(Sort { expr, input, fetch }) => expr
@@ -569,18 +576,28 @@ impl LogicalPlan {
                 }),
             LogicalPlan::Unnest(Unnest {
                 input,
-                column,
+                columns,
                 schema,
                 options,
-            }) => f(Expr::Column(column))?.map_data(|column| match column {
-                Expr::Column(column) => Ok(LogicalPlan::Unnest(Unnest {
-                    input,
-                    column,
-                    schema,
-                    options,
-                })),
-                _ => internal_err!("Transformation should return Column"),
-            })?,
+            }) => columns
+                .into_iter()
+                .map(Expr::Column)
+                .map_until_stop_and_collect(&mut f)?
+                .map_data(|columns| {
+                    let columns = columns
+                        .into_iter()
+                        .map(|column| match column {
+                            Expr::Column(column) => Ok(column),
+                            _ => internal_err!("Transformation should return Column"),
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok(LogicalPlan::Unnest(Unnest {
+                        input,
+                        columns,
+                        schema,
+                        options,
+                    }))
+                })?,
             LogicalPlan::Distinct(Distinct::On(DistinctOn {
                 on_expr,
                 select_expr,
@@ -931,6 +948,8 @@ impl LogicalPlan {
                 join_constraint,
                 on,
                 null_equals_null,
+                strategy_hint,
+                null_aware,
                 ..
             }) => {
                 let schema =
@@ -971,6 +990,8 @@ impl LogicalPlan {
                     filter: filter_expr,
                     schema: DFSchemaRef::new(schema),
                     null_equals_null: *null_equals_null,
+                    strategy_hint: *strategy_hint,
+                    null_aware: *null_aware,
                 }))
             }
             LogicalPlan::CrossJoin(_) => {
@@ -1090,6 +1111,7 @@ impl LogicalPlan {
                 assert_eq!(inputs.len(), 1, "Invalid EXPLAIN command. Inputs are empty");
                 Ok(LogicalPlan::Explain(Explain {
                     verbose: e.verbose,
+                    estimate: e.estimate,
                     plan: Arc::new(inputs.swap_remove(0)),
                     stringified_plans: e.stringified_plans.clone(),
                     schema: e.schema.clone(),
@@ -1120,51 +1142,11 @@ impl LogicalPlan {
             }
             LogicalPlan::DescribeTable(_) => Ok(self.clone()),
             LogicalPlan::Unnest(Unnest {
-                column,
-                schema,
-                options,
-                ..
+                columns, options, ..
             }) => {
-                // Update schema with unnested column type.
-                let input = Arc::new(inputs.swap_remove(0));
-                let (nested_qualifier, nested_field) =
-                    input.schema().qualified_field_from_column(column)?;
-                let (unnested_qualifier, unnested_field) =
-                    schema.qualified_field_from_column(column)?;
-                let qualifiers_and_fields = input
-                    .schema()
-                    .iter()
-                    .map(|(qualifier, field)| {
-                        if qualifier.eq(&nested_qualifier)
-                            && field.as_ref() == nested_field
-                        {
-                            (
-                                unnested_qualifier.cloned(),
-                                Arc::new(unnested_field.clone()),
-                            )
-                        } else {
-                            (qualifier.cloned(), field.clone())
-                        }
-                    })
-                    .collect::<Vec<_>>();
-
-                let schema = Arc::new(
-                    DFSchema::new_with_metadata(
-                        qualifiers_and_fields,
-                        input.schema().metadata().clone(),
-                    )?
-                    // We can use the existing functional dependencies as is:
-                    .with_functional_dependencies(
-                        input.schema().functional_dependencies().clone(),
-                    )?,
-                );
-
-                Ok(LogicalPlan::Unnest(Unnest {
-                    input,
-                    column: column.clone(),
-                    schema,
-                    options: options.clone(),
-                }))
+                // Update schema with unnested column types.
+                let input = inputs.swap_remove(0);
+                unnest_columns_with_options(input, columns.clone(), options.clone())
             }
         }
     }
@@ -1994,6 +1976,8 @@ impl LogicalPlan {
                         filter,
                         join_constraint,
                         join_type,
+                        strategy_hint,
+                        null_aware,
                         ..
                     }) => {
                         let join_expr: Vec<String> =
@@ -2010,7 +1994,7 @@ impl LogicalPlan {
                                     join_type,
                                     join_expr.join(", "),
                                     filter_expr
-                                )
+                                )?;
                             }
                             JoinConstraint::Using => {
                                 write!(
@@ -2019,9 +2003,16 @@ impl LogicalPlan {
                                     join_type,
                                     join_expr.join(", "),
                                     filter_expr,
-                                )
+                                )?;
                             }
                         }
+                        if let Some(hint) = strategy_hint {
+                            write!(f, " Hint: {hint}")?;
+                        }
+                        if *null_aware {
+                            write!(f, " NullAware")?;
+                        }
+                        Ok(())
                     }
                     LogicalPlan::CrossJoin(_) => {
                         write!(f, "CrossJoin:")
@@ -2101,8 +2092,14 @@ impl LogicalPlan {
                     LogicalPlan::DescribeTable(DescribeTable { .. }) => {
                         write!(f, "DescribeTable")
                     }
-                    LogicalPlan::Unnest(Unnest { column, .. }) => {
-                        write!(f, "Unnest: {column}")
+                    LogicalPlan::Unnest(Unnest {
+                        columns, options, ..
+                    }) => {
+                        write!(f, "Unnest: {}", expr_vec_fmt!(columns))?;
+                        if let Some(ordinality) = &options.ordinality {
+                            write!(f, " ordinality={ordinality}")?;
+                        }
+                        Ok(())
                     }
                 }
             }
@@ -2655,6 +2652,9 @@ pub struct DescribeTable {
 pub struct Explain {
     /// Should extra (detailed, intermediate plans) be included?
     pub verbose: bool,
+    /// Should the physical plan be annotated with the estimated number of
+    /// rows, bytes and memory of each operator (`EXPLAIN ESTIMATE`)?
+    pub estimate: bool,
     /// The logical plan that is being EXPLAIN'd
     pub plan: Arc<LogicalPlan>,
     /// Represent the various stages plans have gone through
@@ -2989,6 +2989,13 @@ pub struct Join {
     pub schema: DFSchemaRef,
     /// If null_equals_null is true, null == null else null != null
     pub null_equals_null: bool,
+    /// Join strategy requested by an optimizer hint, if any
+    pub strategy_hint: Option<JoinStrategyHint>,
+    /// If true, this is a `LeftAnti` join planned from a `NOT IN` subquery
+    /// that follows SQL three-valued logic: no row is returned once the right
+    /// input contains a null key, and left rows with a null key are only
+    /// returned when the right input is empty
+    pub null_aware: bool,
 }
 
 impl Join {
@@ -3022,6 +3029,8 @@ impl Join {
             join_constraint: original_join.join_constraint,
             schema: Arc::new(join_schema),
             null_equals_null: original_join.null_equals_null,
+            strategy_hint: original_join.strategy_hint,
+            null_aware: original_join.null_aware,
         })
     }
 }
@@ -3070,14 +3079,14 @@ pub enum Partitioning {
     DistributeBy(Vec<Expr>),
 }
 
-/// Unnest a column that contains a nested list type. See
+/// Unnest columns that contain a nested list type. See
 /// [`UnnestOptions`] for more details.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Unnest {
     /// The incoming logical plan
     pub input: Arc<LogicalPlan>,
-    /// The column to unnest
-    pub column: Column,
+    /// The columns to unnest
+    pub columns: Vec<Column>,
     /// The output schema, containing the unnested field column.
     pub schema: DFSchemaRef,
     /// Options
@@ -127,6 +127,8 @@ impl TreeNode for LogicalPlan {
                 join_constraint,
                 schema,
                 null_equals_null,
+                strategy_hint,
+                null_aware,
             }) => map_until_stop_and_collect!(
                 rewrite_arc(left, &mut f),
                 right,
@@ -142,6 +144,8 @@ impl TreeNode for LogicalPlan {
                     join_constraint,
                     schema,
                     null_equals_null,
+                    strategy_hint,
+                    null_aware,
                 })
             }),
             LogicalPlan::CrossJoin(CrossJoin {
@@ -207,6 +211,7 @@ impl TreeNode for LogicalPlan {
             .update_data(LogicalPlan::Distinct),
             LogicalPlan::Explain(Explain {
                 verbose,
+                estimate,
                 plan,
                 stringified_plans,
                 schema,
@@ -214,6 +219,7 @@ impl TreeNode for LogicalPlan {
             }) => rewrite_arc(plan, f)?.update_data(|plan| {
                 LogicalPlan::Explain(Explain {
                     verbose,
+                    estimate,
                     plan,
                     stringified_plans,
                     schema,
@@ -305,13 +311,13 @@ impl TreeNode for LogicalPlan {
             }
             LogicalPlan::Unnest(Unnest {
                 input,
-                column,
+                columns,
                 schema,
                 options,
             }) => rewrite_arc(input, f)?.update_data(|input| {
                 LogicalPlan::Unnest(Unnest {
                     input,
-                    column,
+                    columns,
                     schema,
                     options,
                 })
@@ -96,9 +96,9 @@ pub fn coerce_types(
     check_arg_count(agg_fun, input_types, &signature.type_signature)?;
 
     match agg_fun {
-        AggregateFunction::Count | AggregateFunction::ApproxDistinct => {
-            Ok(input_types.to_vec())
-        }
+        AggregateFunction::Count
+        | AggregateFunction::ApproxDistinct
+        | AggregateFunction::ApproxDistinctSketch => Ok(input_types.to_vec()),
         AggregateFunction::ArrayAgg => Ok(input_types.to_vec()),
         AggregateFunction::Min | AggregateFunction::Max => {
             // min and max support the dictionary data type
@@ -283,7 +283,8 @@ pub fn coerce_types(
             }
             Ok(input_types.to_vec())
         }
-        AggregateFunction::ApproxMedian => {
+        AggregateFunction::ApproxMedian
+        | AggregateFunction::ApproxPercentileContSketch => {
             if !is_approx_percentile_cont_supported_arg_type(&input_types[0]) {
                 return plan_err!(
                     "The function {:?} does not support inputs of type {:?}.",
@@ -293,6 +294,32 @@ pub fn coerce_types(
             }
             Ok(input_types.to_vec())
         }
+        AggregateFunction::ApproxDistinctMerge => {
+            if !is_sketch_supported_arg_type(&input_types[0]) {
+                return plan_err!(
+                    "The sketch argument for {:?} must be binary, not {:?}.",
+                    agg_fun,
+                    input_types[0]
+                );
+            }
+            Ok(vec![Binary])
+        }
+        AggregateFunction::ApproxPercentileContMerge => {
+            if !is_sketch_supported_arg_type(&input_types[0]) {
+                return plan_err!(
+                    "The sketch argument for {:?} must be binary, not {:?}.",
+                    agg_fun,
+                    input_types[0]
+                );
+            }
+            if !can_coerce_from(&Float64, &input_types[1]) {
+                return plan_err!(
+                    "Could not coerce the percent argument for {:?} to Float64. Was {:?}.",
+                    agg_fun, input_types[1]
+                );
+            }
+            Ok(vec![Binary, Float64])
+        }
         AggregateFunction::Median
         | AggregateFunction::FirstValue
         | AggregateFunction::LastValue => Ok(input_types.to_vec()),
@@ -572,6 +599,16 @@ pub fn is_approx_percentile_cont_supported_arg_type(arg_type: &DataType) -> bool
     )
 }
 
+/// Return `true` if `arg_type` is of a [`DataType`] that can hold the binary
+/// sketches merged by the [`AggregateFunction::ApproxDistinctMerge`] and
+/// [`AggregateFunction::ApproxPercentileContMerge`] aggregations.
+pub fn is_sketch_supported_arg_type(arg_type: &DataType) -> bool {
+    matches!(
+        arg_type,
+        DataType::Binary | DataType::LargeBinary | DataType::Null
+    )
+}
+
 /// Return `true` if `arg_type` is of a [`DataType`] that the
 /// [`AggregateFunction::StringAgg`] aggregation can operate on.
 pub fn is_string_agg_supported_arg_type(arg_type: &DataType) -> bool {
@@ -121,7 +121,10 @@ impl AggregateUDF {
     /// addition to `name`
     ///
     /// If you implement [`AggregateUDFImpl`] directly you should return aliases directly.
-    pub fn with_aliases(self, aliases: impl IntoIterator<Item = &'static str>) -> Self {
+    pub fn with_aliases(
+        self,
+        aliases: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
         Self::new_from_impl(AliasedAggregateUDFImpl::new(self.inner.clone(), aliases))
     }
 
@@ -367,10 +370,10 @@ struct AliasedAggregateUDFImpl {
 impl AliasedAggregateUDFImpl {
     pub fn new(
         inner: Arc<dyn AggregateUDFImpl>,
-        new_aliases: impl IntoIterator<Item = &'static str>,
+        new_aliases: impl IntoIterator<Item = impl Into<String>>,
     ) -> Self {
         let mut aliases = inner.aliases().to_vec();
-        aliases.extend(new_aliases.into_iter().map(|s| s.to_string()));
+        aliases.extend(new_aliases.into_iter().map(Into::into));
 
         Self { inner, aliases }
     }
@@ -111,7 +111,10 @@ impl ScalarUDF {
     /// addition to `name`
     ///
     /// If you implement [`ScalarUDFImpl`] directly you should return aliases directly.
-    pub fn with_aliases(self, aliases: impl IntoIterator<Item = &'static str>) -> Self {
+    pub fn with_aliases(
+        self,
+        aliases: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
         Self::new_from_impl(AliasedScalarUDFImpl::new(self.inner.clone(), aliases))
     }
 
@@ -389,10 +392,10 @@ struct AliasedScalarUDFImpl {
 impl AliasedScalarUDFImpl {
     pub fn new(
         inner: Arc<dyn ScalarUDFImpl>,
-        new_aliases: impl IntoIterator<Item = &'static str>,
+        new_aliases: impl IntoIterator<Item = impl Into<String>>,
     ) -> Self {
         let mut aliases = inner.aliases().to_vec();
-        aliases.extend(new_aliases.into_iter().map(|s| s.to_string()));
+        aliases.extend(new_aliases.into_iter().map(Into::into));
 
         Self { inner, aliases }
     }
@@ -116,7 +116,10 @@ impl WindowUDF {
     /// addition to `name`
     ///
     /// If you implement [`WindowUDFImpl`] directly you should return aliases directly.
-    pub fn with_aliases(self, aliases: impl IntoIterator<Item = &'static str>) -> Self {
+    pub fn with_aliases(
+        self,
+        aliases: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
         Self::new_from_impl(AliasedWindowUDFImpl::new(self.inner.clone(), aliases))
     }
 
@@ -279,10 +282,10 @@ struct AliasedWindowUDFImpl {
 impl AliasedWindowUDFImpl {
     pub fn new(
         inner: Arc<dyn WindowUDFImpl>,
-        new_aliases: impl IntoIterator<Item = &'static str>,
+        new_aliases: impl IntoIterator<Item = impl Into<String>>,
     ) -> Self {
         let mut aliases = inner.aliases().to_vec();
-        aliases.extend(new_aliases.into_iter().map(|s| s.to_string()));
+        aliases.extend(new_aliases.into_iter().map(Into::into));
 
         Self { inner, aliases }
     }
@@ -18,7 +18,9 @@
 //! Expression utilities
 
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use crate::expr::{Alias, Sort, WindowFunction};
@@ -34,8 +36,8 @@ use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
 use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
 use datafusion_common::utils::get_at_indices;
 use datafusion_common::{
-    internal_err, plan_datafusion_err, plan_err, Column, DFSchema, DFSchemaRef, Result,
-    ScalarValue, TableReference,
+    config_err, internal_err, plan_datafusion_err, plan_err, Column, DFSchema,
+    DFSchemaRef, DataFusionError, Result, ScalarValue, TableReference,
 };
 
 use sqlparser::ast::{ExceptSelectItem, ExcludeSelectItem, WildcardAdditionalOptions};
@@ -914,6 +916,8 @@ pub fn can_hash(data_type: &DataType) -> bool {
         DataType::Decimal128(_, _) => true,
         DataType::Date32 => true,
         DataType::Date64 => true,
+        DataType::Time32(_) => true,
+        DataType::Time64(_) => true,
         DataType::FixedSizeBinary(_) => true,
         DataType::Dictionary(key_type, value_type)
             if *value_type.as_ref() == DataType::Utf8 =>
@@ -1246,6 +1250,122 @@ pub fn format_state_name(name: &str, state_name: &str) -> String {
     format!("{name}[{state_name}]")
 }
 
+/// How to handle several columns with the same name in the output of a
+/// projection, e.g. `SELECT *` over a join of tables sharing column names
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicateNamePolicy {
+    /// Keep the duplicate names, which are only distinguished by their relations
+    #[default]
+    Keep,
+    /// Return an error
+    Error,
+    /// Rename the later duplicates by appending a suffix: `a`, `a_1`, `a_2`, ...
+    Suffix,
+    /// Rename the duplicates to their qualified names, e.g. `t1.a` and `t2.a`
+    Qualify,
+}
+
+impl FromStr for DuplicateNamePolicy {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "keep" => Ok(Self::Keep),
+            "error" => Ok(Self::Error),
+            "suffix" => Ok(Self::Suffix),
+            "qualify" => Ok(Self::Qualify),
+            _ => config_err!(
+                "Invalid duplicate column name policy: {s}, expected one of keep, error, suffix or qualify"
+            ),
+        }
+    }
+}
+
+impl fmt::Display for DuplicateNamePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Self::Keep => "keep",
+            Self::Error => "error",
+            Self::Suffix => "suffix",
+            Self::Qualify => "qualify",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Applies `policy` to the projection `exprs` over `schema`, aliasing the
+/// expressions whose output names are duplicated.
+pub fn resolve_duplicate_names(
+    exprs: Vec<Expr>,
+    schema: &DFSchema,
+    policy: DuplicateNamePolicy,
+) -> Result<Vec<Expr>> {
+    if policy == DuplicateNamePolicy::Keep {
+        return Ok(exprs);
+    }
+    let fields = exprs
+        .iter()
+        .map(|expr| expr.to_field(schema))
+        .collect::<Result<Vec<_>>>()?;
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for (_, field) in &fields {
+        *counts.entry(field.name()).or_default() += 1;
+    }
+    let is_duplicate = |name: &str| counts[name] > 1;
+    if !fields.iter().any(|(_, field)| is_duplicate(field.name())) {
+        return Ok(exprs);
+    }
+
+    let mut names = fields
+        .iter()
+        .map(|(qualifier, field)| match qualifier {
+            Some(qualifier)
+                if policy == DuplicateNamePolicy::Qualify
+                    && is_duplicate(field.name()) =>
+            {
+                format!("{qualifier}.{}", field.name())
+            }
+            _ => field.name().clone(),
+        })
+        .collect::<Vec<_>>();
+    if policy == DuplicateNamePolicy::Error {
+        let name = names.iter().find(|name| is_duplicate(name)).unwrap();
+        return plan_err!(
+            "Projections require unique expression names but the expression name \"{name}\" is duplicated; \
+            set datafusion.sql_parser.duplicate_column_names to suffix or qualify to rename duplicates"
+        );
+    }
+
+    // suffix the names that are still duplicated, e.g. unqualified columns
+    let mut used: HashSet<String> = names.iter().cloned().collect();
+    let mut seen: HashSet<String> = HashSet::new();
+    for name in names.iter_mut() {
+        if seen.insert(name.clone()) {
+            continue;
+        }
+        let mut suffix = 1;
+        while used.contains(&format!("{name}_{suffix}")) {
+            suffix += 1;
+        }
+        *name = format!("{name}_{suffix}");
+        used.insert(name.clone());
+        seen.insert(name.clone());
+    }
+
+    Ok(exprs
+        .into_iter()
+        .zip(fields)
+        .zip(names)
+        .map(|((expr, (_, field)), name)| {
+            if *field.name() == name {
+                expr
+            } else {
+                expr.unalias().alias(name)
+            }
+        })
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1712,4 +1832,61 @@ mod tests {
         assert!(accum.contains(&Column::from_name("a")));
         Ok(())
     }
+
+    fn duplicate_names(policy: DuplicateNamePolicy) -> Result<Vec<String>> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("a_1", DataType::Int32, false),
+        ]);
+        let t1 = DFSchema::try_from_qualified_schema("t1", &schema)?;
+        let t2 = DFSchema::try_from_qualified_schema("t2", &schema)?;
+        let schema = t1.join(&t2)?;
+        let exprs = vec![
+            col("t1.a"),
+            col("t1.a_1"),
+            col("t2.a"),
+            col("t2.a_1"),
+            col("t2.a").alias("b"),
+        ];
+        let exprs = resolve_duplicate_names(exprs, &schema, policy)?;
+        exprs
+            .iter()
+            .map(|expr| Ok(expr.to_field(&schema)?.1.name().clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_resolve_duplicate_names() -> Result<()> {
+        assert_eq!(
+            duplicate_names(DuplicateNamePolicy::Keep)?,
+            ["a", "a_1", "a", "a_1", "b"]
+        );
+        assert_eq!(
+            duplicate_names(DuplicateNamePolicy::Suffix)?,
+            ["a", "a_1", "a_2", "a_1_1", "b"]
+        );
+        assert_eq!(
+            duplicate_names(DuplicateNamePolicy::Qualify)?,
+            ["t1.a", "t1.a_1", "t2.a", "t2.a_1", "b"]
+        );
+        let err = duplicate_names(DuplicateNamePolicy::Error).unwrap_err();
+        assert!(err.to_string().contains("\"a\" is duplicated"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate_name_policy_from_str() {
+        for policy in [
+            DuplicateNamePolicy::Keep,
+            DuplicateNamePolicy::Error,
+            DuplicateNamePolicy::Suffix,
+            DuplicateNamePolicy::Qualify,
+        ] {
+            assert_eq!(
+                policy.to_string().parse::<DuplicateNamePolicy>().unwrap(),
+                policy
+            );
+        }
+        assert!("rename".parse::<DuplicateNamePolicy>().is_err());
+    }
 }
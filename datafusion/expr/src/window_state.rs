@@ -406,7 +406,7 @@ impl WindowFrameStateRange {
                     }
                     if SEARCH_SIDE == is_descending {
                         // TODO: Handle positive overflows.
-                        value.add(delta)
+                        shift_range_value(value, delta, true)
                     } else if value.is_unsigned() && value < delta {
                         // NOTE: This gets a polymorphic zero without having long coercion code for ScalarValue.
                         //       If we decide to implement a "default" construction mechanism for ScalarValue,
@@ -414,7 +414,7 @@ impl WindowFrameStateRange {
                         value.sub(value)
                     } else {
                         // TODO: Handle negative overflows.
-                        value.sub(delta)
+                        shift_range_value(value, delta, false)
                     }
                 })
                 .collect::<Result<Vec<ScalarValue>>>()?
@@ -434,6 +434,78 @@ impl WindowFrameStateRange {
     }
 }
 
+/// Shifts the ORDER BY `value` of a row by the offset `delta` of a RANGE frame
+/// bound, adding the offset if `add` is true and subtracting it otherwise.
+///
+/// Times are shifted by durations of the same unit, which the arithmetic
+/// kernels do not support. The results may leave the range of a day, which
+/// keeps them comparable with all values of the column. Likewise, timestamps
+/// shifted beyond the range of their type saturate at its bounds, so that the
+/// frame extends to the first or last row.
+pub fn shift_range_value(
+    value: &ScalarValue,
+    delta: &ScalarValue,
+    add: bool,
+) -> Result<ScalarValue> {
+    let shift = |value: i64, delta: i64| {
+        if add {
+            value.saturating_add(delta)
+        } else {
+            value.saturating_sub(delta)
+        }
+    };
+    let shift_i32 = |value: i32, delta: i64| {
+        shift(value as i64, delta).clamp(i32::MIN as i64, i32::MAX as i64) as i32
+    };
+    match (value, delta) {
+        (ScalarValue::Time32Second(Some(v)), ScalarValue::DurationSecond(Some(d))) => {
+            Ok(ScalarValue::Time32Second(Some(shift_i32(*v, *d))))
+        }
+        (
+            ScalarValue::Time32Millisecond(Some(v)),
+            ScalarValue::DurationMillisecond(Some(d)),
+        ) => Ok(ScalarValue::Time32Millisecond(Some(shift_i32(*v, *d)))),
+        (
+            ScalarValue::Time64Microsecond(Some(v)),
+            ScalarValue::DurationMicrosecond(Some(d)),
+        ) => Ok(ScalarValue::Time64Microsecond(Some(shift(*v, *d)))),
+        (
+            ScalarValue::Time64Nanosecond(Some(v)),
+            ScalarValue::DurationNanosecond(Some(d)),
+        ) => Ok(ScalarValue::Time64Nanosecond(Some(shift(*v, *d)))),
+        (
+            ScalarValue::TimestampSecond(_, tz)
+            | ScalarValue::TimestampMillisecond(_, tz)
+            | ScalarValue::TimestampMicrosecond(_, tz)
+            | ScalarValue::TimestampNanosecond(_, tz),
+            _,
+        ) => {
+            let shifted = if add {
+                value.add(delta)
+            } else {
+                value.sub(delta)
+            };
+            shifted.or_else(|_| {
+                let bound = Some(if add { i64::MAX } else { i64::MIN });
+                Ok(match value {
+                    ScalarValue::TimestampSecond(..) => {
+                        ScalarValue::TimestampSecond(bound, tz.clone())
+                    }
+                    ScalarValue::TimestampMillisecond(..) => {
+                        ScalarValue::TimestampMillisecond(bound, tz.clone())
+                    }
+                    ScalarValue::TimestampMicrosecond(..) => {
+                        ScalarValue::TimestampMicrosecond(bound, tz.clone())
+                    }
+                    _ => ScalarValue::TimestampNanosecond(bound, tz.clone()),
+                })
+            })
+        }
+        _ if add => value.add(delta),
+        _ => value.sub(delta),
+    }
+}
+
 // In GROUPS mode, rows with duplicate sorting values are grouped together.
 // Therefore, there must be an ORDER BY clause in the window definition to use GROUPS mode.
 // The syntax is as follows:
@@ -674,6 +746,7 @@ mod tests {
     use crate::{WindowFrame, WindowFrameBound, WindowFrameUnits};
 
     use arrow::array::{ArrayRef, Float64Array};
+    use arrow::datatypes::IntervalMonthDayNanoType;
     use datafusion_common::{Result, ScalarValue};
 
     fn get_test_data() -> (Vec<ArrayRef>, Vec<SortOptions>) {
@@ -772,4 +845,65 @@ mod tests {
         ];
         assert_expected(expected_results, &window_frame)
     }
+
+    #[test]
+    fn test_shift_range_value_time() -> Result<()> {
+        let value = ScalarValue::Time32Second(Some(3_600));
+        let delta = ScalarValue::DurationSecond(Some(60));
+        assert_eq!(
+            shift_range_value(&value, &delta, true)?,
+            ScalarValue::Time32Second(Some(3_660))
+        );
+        assert_eq!(
+            shift_range_value(&value, &delta, false)?,
+            ScalarValue::Time32Second(Some(3_540))
+        );
+
+        // Shifted times may leave the range of a day
+        let value = ScalarValue::Time64Nanosecond(Some(0));
+        let delta = ScalarValue::DurationNanosecond(Some(1_000));
+        assert_eq!(
+            shift_range_value(&value, &delta, false)?,
+            ScalarValue::Time64Nanosecond(Some(-1_000))
+        );
+
+        // Other types use the arithmetic kernels
+        let value = ScalarValue::Int64(Some(10));
+        let delta = ScalarValue::Int64(Some(3));
+        assert_eq!(
+            shift_range_value(&value, &delta, false)?,
+            ScalarValue::Int64(Some(7))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_shift_range_value_timestamp() -> Result<()> {
+        let tz: Option<Arc<str>> = Some("+01:00".into());
+        let day = ScalarValue::IntervalMonthDayNano(Some(
+            IntervalMonthDayNanoType::make_value(0, 1, 0),
+        ));
+        let value = ScalarValue::TimestampSecond(Some(86_400), tz.clone());
+        assert_eq!(
+            shift_range_value(&value, &day, true)?,
+            ScalarValue::TimestampSecond(Some(172_800), tz.clone())
+        );
+        assert_eq!(
+            shift_range_value(&value, &day, false)?,
+            ScalarValue::TimestampSecond(Some(0), tz.clone())
+        );
+
+        // Shifted timestamps saturate at the bounds of their type
+        let value = ScalarValue::TimestampSecond(Some(i64::MAX - 10), tz.clone());
+        assert_eq!(
+            shift_range_value(&value, &day, true)?,
+            ScalarValue::TimestampSecond(Some(i64::MAX), tz.clone())
+        );
+        let value = ScalarValue::TimestampNanosecond(Some(i64::MIN + 10), None);
+        assert_eq!(
+            shift_range_value(&value, &day, false)?,
+            ScalarValue::TimestampNanosecond(Some(i64::MIN), None)
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,428 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for vector similarity functions.
+
+use std::any::Any;
+use std::ops::{Add, Mul, Range, Sub};
+use std::sync::Arc;
+
+use arrow::compute::cast;
+use arrow_array::types::{Float32Type, Float64Type};
+use arrow_array::{Array, ArrayRef, ArrowPrimitiveType, Float64Array, PrimitiveArray};
+use arrow_schema::DataType;
+use arrow_schema::DataType::{FixedSizeList, Float32, Float64, LargeList, List};
+use datafusion_common::cast::{
+    as_fixed_size_list_array, as_large_list_array, as_list_array,
+};
+use datafusion_common::{exec_err, plan_err, Result, ScalarValue};
+use datafusion_expr::expr::ScalarFunction;
+use datafusion_expr::{ColumnarValue, Expr, ScalarUDFImpl, Signature, Volatility};
+
+make_udf_function!(
+    CosineDistance,
+    cosine_distance,
+    array1 array2,
+    "returns the cosine distance between two vectors.",
+    cosine_distance_udf
+);
+
+make_udf_function!(
+    L2Distance,
+    l2_distance,
+    array1 array2,
+    "returns the euclidean distance between two vectors.",
+    l2_distance_udf
+);
+
+make_udf_function!(
+    DotProduct,
+    dot_product,
+    array1 array2,
+    "returns the dot product of two vectors.",
+    dot_product_udf
+);
+
+#[derive(Debug)]
+pub(super) struct CosineDistance {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl CosineDistance {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(2, Volatility::Immutable),
+            aliases: vec![String::from("cosine_distance")],
+        }
+    }
+}
+
+impl ScalarUDFImpl for CosineDistance {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "cosine_distance"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        vector_return_type(self.name(), arg_types)
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        vector_function::<Self>(self.name(), args)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct L2Distance {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl L2Distance {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(2, Volatility::Immutable),
+            aliases: vec![String::from("l2_distance")],
+        }
+    }
+}
+
+impl ScalarUDFImpl for L2Distance {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "l2_distance"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        vector_return_type(self.name(), arg_types)
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        vector_function::<Self>(self.name(), args)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct DotProduct {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl DotProduct {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(2, Volatility::Immutable),
+            aliases: vec![String::from("dot_product"), String::from("inner_product")],
+        }
+    }
+}
+
+impl ScalarUDFImpl for DotProduct {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "dot_product"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        vector_return_type(self.name(), arg_types)
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        vector_function::<Self>(self.name(), args)
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+}
+
+fn vector_return_type(name: &str, arg_types: &[DataType]) -> Result<DataType> {
+    for arg_type in arg_types {
+        match arg_type {
+            List(field) | LargeList(field) | FixedSizeList(field, _)
+                if field.data_type().is_numeric() => {}
+            DataType::Null => {}
+            other => {
+                return plan_err!(
+                    "The {name} function can only accept List/LargeList/FixedSizeList of numeric values, got {other}"
+                )
+            }
+        }
+    }
+    Ok(Float64)
+}
+
+/// Number of independent accumulators of the kernels, which allows the
+/// compiler to vectorize the loops with SIMD instructions
+const LANES: usize = 8;
+
+/// Element type of the vectors the kernels operate on
+trait VectorElement:
+    Copy + Default + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Into<f64>
+{
+}
+
+impl VectorElement for f32 {}
+impl VectorElement for f64 {}
+
+/// Returns the dot product of `a` and `b` and their squared norms
+#[allow(clippy::needless_range_loop)]
+fn dot_and_norms<T: VectorElement>(a: &[T], b: &[T]) -> (f64, f64, f64) {
+    let mut dot = [T::default(); LANES];
+    let mut norm_a = [T::default(); LANES];
+    let mut norm_b = [T::default(); LANES];
+    let chunks_a = a.chunks_exact(LANES);
+    let chunks_b = b.chunks_exact(LANES);
+    let (rem_a, rem_b) = (chunks_a.remainder(), chunks_b.remainder());
+    for (a, b) in chunks_a.zip(chunks_b) {
+        for i in 0..LANES {
+            dot[i] = dot[i] + a[i] * b[i];
+            norm_a[i] = norm_a[i] + a[i] * a[i];
+            norm_b[i] = norm_b[i] + b[i] * b[i];
+        }
+    }
+    for (a, b) in rem_a.iter().zip(rem_b) {
+        dot[0] = dot[0] + *a * *b;
+        norm_a[0] = norm_a[0] + *a * *a;
+        norm_b[0] = norm_b[0] + *b * *b;
+    }
+    (sum_lanes(dot), sum_lanes(norm_a), sum_lanes(norm_b))
+}
+
+/// Returns the squared euclidean distance between `a` and `b`
+#[allow(clippy::needless_range_loop)]
+fn squared_l2_distance<T: VectorElement>(a: &[T], b: &[T]) -> f64 {
+    let mut sum = [T::default(); LANES];
+    let chunks_a = a.chunks_exact(LANES);
+    let chunks_b = b.chunks_exact(LANES);
+    let (rem_a, rem_b) = (chunks_a.remainder(), chunks_b.remainder());
+    for (a, b) in chunks_a.zip(chunks_b) {
+        for i in 0..LANES {
+            let diff = a[i] - b[i];
+            sum[i] = sum[i] + diff * diff;
+        }
+    }
+    for (a, b) in rem_a.iter().zip(rem_b) {
+        let diff = *a - *b;
+        sum[0] = sum[0] + diff * diff;
+    }
+    sum_lanes(sum)
+}
+
+fn sum_lanes<T: VectorElement>(lanes: [T; LANES]) -> f64 {
+    lanes.into_iter().map(Into::into).sum()
+}
+
+/// A list argument of a vector function, with its values cast to a float type
+struct Vectors {
+    values: ArrayRef,
+    /// The value range of each row, `None` for null rows
+    ranges: Vec<Option<Range<usize>>>,
+    /// Whether this argument is a scalar, used for every row
+    is_scalar: bool,
+}
+
+impl Vectors {
+    fn try_new(arg: &ColumnarValue, value_type: &DataType) -> Result<Self> {
+        let (array, is_scalar) = match arg {
+            ColumnarValue::Array(array) => (array.clone(), false),
+            ColumnarValue::Scalar(scalar) => (scalar.to_array()?, true),
+        };
+        let row_range =
+            |i: usize, start: usize, end: usize| array.is_valid(i).then_some(start..end);
+        let (values, ranges) = match array.data_type() {
+            List(_) => {
+                let list = as_list_array(&array)?;
+                let offsets = list.value_offsets();
+                let ranges = (0..list.len())
+                    .map(|i| row_range(i, offsets[i] as usize, offsets[i + 1] as usize))
+                    .collect();
+                (list.values().clone(), ranges)
+            }
+            LargeList(_) => {
+                let list = as_large_list_array(&array)?;
+                let offsets = list.value_offsets();
+                let ranges = (0..list.len())
+                    .map(|i| row_range(i, offsets[i] as usize, offsets[i + 1] as usize))
+                    .collect();
+                (list.values().clone(), ranges)
+            }
+            FixedSizeList(_, _) => {
+                let list = as_fixed_size_list_array(&array)?;
+                let length = list.value_length() as usize;
+                let ranges = (0..list.len())
+                    .map(|i| {
+                        let start = list.value_offset(i) as usize;
+                        row_range(i, start, start + length)
+                    })
+                    .collect();
+                (list.values().clone(), ranges)
+            }
+            DataType::Null => (
+                Arc::new(Float64Array::new_null(0)) as ArrayRef,
+                vec![None; array.len()],
+            ),
+            other => return exec_err!("Unsupported vector type {other}"),
+        };
+        Ok(Self {
+            values: cast(&values, value_type)?,
+            ranges,
+            is_scalar,
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    fn range(&self, row: usize) -> Option<Range<usize>> {
+        let row = if self.is_scalar { 0 } else { row };
+        let range = self.ranges[row].clone()?;
+        // vectors containing nulls have no distance
+        let has_nulls =
+            self.values.null_count() > 0 && range.clone().any(|i| self.values.is_null(i));
+        (!has_nulls).then_some(range)
+    }
+}
+
+/// Evaluates the kernel `K` for each pair of vectors of the two arguments
+/// of the function `name`.
+///
+/// Float32 vectors are computed in single precision, all other numeric
+/// types are cast to Float64.
+fn vector_function<K: VectorKernel>(
+    name: &str,
+    args: &[ColumnarValue],
+) -> Result<ColumnarValue> {
+    if args.len() != 2 {
+        return exec_err!("{name} expects two arguments");
+    }
+    let is_float32 = |arg: &ColumnarValue| match arg.data_type() {
+        List(field) | LargeList(field) | FixedSizeList(field, _) => {
+            field.data_type() == &Float32
+        }
+        _ => false,
+    };
+    let result = if args.iter().all(is_float32) {
+        evaluate::<K, Float32Type>(name, args)?
+    } else {
+        evaluate::<K, Float64Type>(name, args)?
+    };
+
+    if args
+        .iter()
+        .all(|arg| matches!(arg, ColumnarValue::Scalar(_)))
+    {
+        Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+            &result, 0,
+        )?))
+    } else {
+        Ok(ColumnarValue::Array(result))
+    }
+}
+
+fn evaluate<K, T>(name: &str, args: &[ColumnarValue]) -> Result<ArrayRef>
+where
+    K: VectorKernel,
+    T: ArrowPrimitiveType,
+    T::Native: VectorElement,
+{
+    let left = Vectors::try_new(&args[0], &T::DATA_TYPE)?;
+    let right = Vectors::try_new(&args[1], &T::DATA_TYPE)?;
+    let left_values = left.values.as_any().downcast_ref::<PrimitiveArray<T>>();
+    let right_values = right.values.as_any().downcast_ref::<PrimitiveArray<T>>();
+    let (Some(left_values), Some(right_values)) = (left_values, right_values) else {
+        return exec_err!("{name} failed to cast the vectors to {}", T::DATA_TYPE);
+    };
+    let (left_values, right_values) = (left_values.values(), right_values.values());
+
+    let num_rows = match (left.is_scalar, right.is_scalar) {
+        (false, _) => left.len(),
+        (true, false) => right.len(),
+        (true, true) => 1,
+    };
+    (0..num_rows)
+        .map(|row| {
+            let (Some(a), Some(b)) = (left.range(row), right.range(row)) else {
+                return Ok(None);
+            };
+            if a.len() != b.len() {
+                return exec_err!(
+                    "{name} requires vectors of the same length, got {} and {}",
+                    a.len(),
+                    b.len()
+                );
+            }
+            Ok(Some(K::compute(&left_values[a], &right_values[b])))
+        })
+        .collect::<Result<Float64Array>>()
+        .map(|array| Arc::new(array) as ArrayRef)
+}
+
+/// Computes a value from two vectors of the same length
+trait VectorKernel {
+    fn compute<T: VectorElement>(a: &[T], b: &[T]) -> f64;
+}
+
+impl VectorKernel for CosineDistance {
+    fn compute<T: VectorElement>(a: &[T], b: &[T]) -> f64 {
+        let (dot, norm_a, norm_b) = dot_and_norms(a, b);
+        1.0 - dot / (norm_a.sqrt() * norm_b.sqrt())
+    }
+}
+
+impl VectorKernel for L2Distance {
+    fn compute<T: VectorElement>(a: &[T], b: &[T]) -> f64 {
+        squared_l2_distance(a, b).sqrt()
+    }
+}
+
+impl VectorKernel for DotProduct {
+    fn compute<T: VectorElement>(a: &[T], b: &[T]) -> f64 {
+        dot_and_norms(a, b).0
+    }
+}
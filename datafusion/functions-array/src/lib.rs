@@ -32,6 +32,7 @@ pub mod array_has;
 pub mod cardinality;
 pub mod concat;
 pub mod dimension;
+pub mod distance;
 pub mod empty;
 pub mod except;
 pub mod extract;
@@ -68,6 +69,9 @@ pub mod expr_fn {
     pub use super::concat::array_prepend;
     pub use super::dimension::array_dims;
     pub use super::dimension::array_ndims;
+    pub use super::distance::cosine_distance;
+    pub use super::distance::dot_product;
+    pub use super::distance::l2_distance;
     pub use super::empty::array_empty;
     pub use super::except::array_except;
     pub use super::extract::array_element;
@@ -108,6 +112,9 @@ pub fn register_all(registry: &mut dyn FunctionRegistry) -> Result<()> {
         dimension::array_dims_udf(),
         cardinality::cardinality_udf(),
         dimension::array_ndims_udf(),
+        distance::cosine_distance_udf(),
+        distance::l2_distance_udf(),
+        distance::dot_product_udf(),
         concat::array_append_udf(),
         concat::array_prepend_udf(),
         concat::array_concat_udf(),
@@ -20,6 +20,7 @@
 use crate::array_has::array_has_all;
 use crate::concat::{array_append, array_concat, array_prepend};
 use crate::extract::{array_element, array_slice};
+use arrow::datatypes::DataType;
 use datafusion_common::config::ConfigOptions;
 use datafusion_common::tree_node::Transformed;
 use datafusion_common::utils::list_ndims;
@@ -27,8 +28,10 @@ use datafusion_common::Result;
 use datafusion_common::{Column, DFSchema};
 use datafusion_expr::expr::ScalarFunction;
 use datafusion_expr::expr_rewriter::FunctionRewrite;
-use datafusion_expr::{BinaryExpr, Expr, GetFieldAccess, GetIndexedField, Operator};
-use datafusion_functions::expr_fn::get_field;
+use datafusion_expr::{
+    BinaryExpr, Expr, ExprSchemable, GetFieldAccess, GetIndexedField, Operator,
+};
+use datafusion_functions::expr_fn::{element_at, get_field};
 
 /// Rewrites expressions into function calls to array functions
 pub(crate) struct ArrayFunctionRewriter {}
@@ -157,6 +160,14 @@ impl FunctionRewrite for ArrayFunctionRewriter {
                 Transformed::yes(get_field(expr, name.clone()))
             }
 
+            // map[key] ==> element_at(map, key)
+            Expr::GetIndexedField(GetIndexedField {
+                expr,
+                field: GetFieldAccess::ListIndex { key },
+            }) if matches!(expr.get_type(schema), Ok(DataType::Map(_, _))) => {
+                Transformed::yes(element_at(*expr, *key))
+            }
+
             // expr[idx] ==> array_element(expr, idx)
             Expr::GetIndexedField(GetIndexedField {
                 expr,
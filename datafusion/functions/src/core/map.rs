@@ -0,0 +1,419 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Functions for the [`DataType::Map`] type: `make_map`, `map_keys`,
+//! `map_values` and `element_at`
+
+use arrow::array::{Array, ArrayRef, ListArray, MapArray, StructArray, UInt32Array};
+use arrow::buffer::OffsetBuffer;
+use arrow::compute::kernels::cmp::eq;
+use arrow::compute::{cast, interleave, take};
+use arrow::datatypes::{DataType, Field, FieldRef, Fields};
+use datafusion_common::cast::as_map_array;
+use datafusion_common::{exec_err, plan_err, Result};
+use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, Volatility};
+use std::any::Any;
+use std::iter;
+use std::sync::Arc;
+
+/// Returns the key and value fields of a map type
+fn map_key_value_fields(data_type: &DataType) -> Result<(&FieldRef, &FieldRef)> {
+    match data_type {
+        DataType::Map(entries, _) => match entries.data_type() {
+            DataType::Struct(fields) if fields.len() == 2 => Ok((&fields[0], &fields[1])),
+            _ => plan_err!("Map fields must contain a Struct with exactly 2 fields"),
+        },
+        other => plan_err!("Expected a Map argument, got {other}"),
+    }
+}
+
+/// The map type with the given key and value types
+fn map_type(key_type: DataType, value_type: DataType) -> DataType {
+    let fields = Fields::from(vec![
+        Field::new("key", key_type, false),
+        Field::new("value", value_type, true),
+    ]);
+    let entries = Field::new("entries", DataType::Struct(fields), false);
+    DataType::Map(Arc::new(entries), false)
+}
+
+/// Looks up `key` in each row of `map`, returning the value of the first
+/// matching entry, or NULL if there is none. `key` has one row per map row.
+pub(crate) fn map_lookup(map: &MapArray, key: &ArrayRef) -> Result<ArrayRef> {
+    let key = cast(key, map.keys().data_type())?;
+
+    // the row of each entry, to compare the entry keys with the row's key
+    let mut entry_rows = vec![None; map.keys().len()];
+    for (row, offsets) in map.value_offsets().windows(2).enumerate() {
+        for entry_row in &mut entry_rows[offsets[0] as usize..offsets[1] as usize] {
+            *entry_row = Some(row as u32);
+        }
+    }
+    let entry_keys = take(&key, &UInt32Array::from(entry_rows), None)?;
+    let matches = eq(map.keys(), &entry_keys)?;
+
+    let indices = map
+        .value_offsets()
+        .windows(2)
+        .enumerate()
+        .map(|(row, offsets)| {
+            if map.is_null(row) {
+                return None;
+            }
+            (offsets[0] as usize..offsets[1] as usize)
+                .find(|&i| matches.is_valid(i) && matches.value(i))
+                .map(|i| i as u32)
+        })
+        .collect::<UInt32Array>();
+    Ok(take(map.values(), &indices, None)?)
+}
+
+#[derive(Debug)]
+pub struct MakeMapFunc {
+    signature: Signature,
+}
+
+impl Default for MakeMapFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MakeMapFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+        }
+    }
+}
+
+// make_map(key1, value1, key2, value2, ...)
+impl ScalarUDFImpl for MakeMapFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "make_map"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        if arg_types.is_empty() || arg_types.len() % 2 != 0 {
+            return plan_err!(
+                "make_map requires an even number of arguments, got {} instead",
+                arg_types.len()
+            );
+        }
+        // the map has the types of the first key and the first non-null value
+        let key_type = arg_types[0].clone();
+        let value_type = arg_types
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .find(|t| !t.is_null())
+            .unwrap_or(&DataType::Null)
+            .clone();
+        Ok(map_type(key_type, value_type))
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        if args.is_empty() || args.len() % 2 != 0 {
+            return exec_err!(
+                "make_map requires an even number of arguments, got {} instead",
+                args.len()
+            );
+        }
+        let arg_types = args.iter().map(|arg| arg.data_type()).collect::<Vec<_>>();
+        let DataType::Map(entries_field, _) = self.return_type(&arg_types)? else {
+            unreachable!()
+        };
+        let (key_field, value_field) = match entries_field.data_type() {
+            DataType::Struct(fields) => (fields[0].clone(), fields[1].clone()),
+            _ => unreachable!(),
+        };
+
+        let arrays = ColumnarValue::values_to_arrays(args)?;
+        let num_rows = arrays[0].len();
+        let mut keys = vec![];
+        let mut values = vec![];
+        for pair in arrays.chunks_exact(2) {
+            let key = cast(&pair[0], key_field.data_type())?;
+            if key.null_count() > 0 {
+                return exec_err!("make_map keys cannot be NULL");
+            }
+            keys.push(key);
+            values.push(cast(&pair[1], value_field.data_type())?);
+        }
+
+        // the entries of each row are the pairs, in order
+        let num_pairs = keys.len();
+        let indices = (0..num_rows)
+            .flat_map(|row| (0..num_pairs).map(move |pair| (pair, row)))
+            .collect::<Vec<_>>();
+        let keys = keys.iter().map(|a| a.as_ref()).collect::<Vec<_>>();
+        let values = values.iter().map(|a| a.as_ref()).collect::<Vec<_>>();
+        let entries = StructArray::try_new(
+            Fields::from(vec![key_field, value_field]),
+            vec![interleave(&keys, &indices)?, interleave(&values, &indices)?],
+            None,
+        )?;
+        let offsets = OffsetBuffer::from_lengths(iter::repeat(num_pairs).take(num_rows));
+        let map = MapArray::try_new(entries_field, offsets, entries, None, false)?;
+        Ok(ColumnarValue::Array(Arc::new(map)))
+    }
+}
+
+#[derive(Debug)]
+pub struct MapKeysFunc {
+    signature: Signature,
+}
+
+impl Default for MapKeysFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MapKeysFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+        }
+    }
+}
+
+// map_keys(map)
+impl ScalarUDFImpl for MapKeysFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "map_keys"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let (key_field, _) = map_key_value_fields(&arg_types[0])?;
+        Ok(DataType::new_list(key_field.data_type().clone(), true))
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        let arrays = ColumnarValue::values_to_arrays(args)?;
+        let map = as_map_array(&arrays[0])?;
+        let field = Arc::new(Field::new("item", map.keys().data_type().clone(), true));
+        let keys = ListArray::try_new(
+            field,
+            map.offsets().clone(),
+            map.keys().clone(),
+            map.nulls().cloned(),
+        )?;
+        Ok(ColumnarValue::Array(Arc::new(keys)))
+    }
+}
+
+#[derive(Debug)]
+pub struct MapValuesFunc {
+    signature: Signature,
+}
+
+impl Default for MapValuesFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MapValuesFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+        }
+    }
+}
+
+// map_values(map)
+impl ScalarUDFImpl for MapValuesFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "map_values"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let (_, value_field) = map_key_value_fields(&arg_types[0])?;
+        Ok(DataType::new_list(value_field.data_type().clone(), true))
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        let arrays = ColumnarValue::values_to_arrays(args)?;
+        let map = as_map_array(&arrays[0])?;
+        let field = Arc::new(Field::new("item", map.values().data_type().clone(), true));
+        let values = ListArray::try_new(
+            field,
+            map.offsets().clone(),
+            map.values().clone(),
+            map.nulls().cloned(),
+        )?;
+        Ok(ColumnarValue::Array(Arc::new(values)))
+    }
+}
+
+#[derive(Debug)]
+pub struct ElementAtFunc {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl Default for ElementAtFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ElementAtFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(2, Volatility::Immutable),
+            aliases: vec![String::from("map_extract")],
+        }
+    }
+}
+
+// element_at(map, key)
+impl ScalarUDFImpl for ElementAtFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "element_at"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let (_, value_field) = map_key_value_fields(&arg_types[0])?;
+        Ok(value_field.data_type().clone())
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        let arrays = ColumnarValue::values_to_arrays(args)?;
+        let map = as_map_array(&arrays[0])?;
+        Ok(ColumnarValue::Array(map_lookup(map, &arrays[1])?))
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int64Array, StringArray};
+    use datafusion_common::cast::{as_int64_array, as_list_array, as_string_array};
+    use datafusion_common::ScalarValue;
+
+    fn make_map(args: &[ColumnarValue]) -> Result<ArrayRef> {
+        MakeMapFunc::new().invoke(args)?.into_array(1)
+    }
+
+    #[test]
+    fn test_make_map_keys_values() -> Result<()> {
+        let map = make_map(&[
+            ColumnarValue::Scalar(ScalarValue::from("a")),
+            ColumnarValue::Array(Arc::new(Int64Array::from(vec![Some(1), None]))),
+            ColumnarValue::Scalar(ScalarValue::from("b")),
+            ColumnarValue::Scalar(ScalarValue::Int64(Some(3))),
+        ])?;
+        assert_eq!(map.len(), 2);
+
+        let keys = MapKeysFunc::new()
+            .invoke(&[ColumnarValue::Array(map.clone())])?
+            .into_array(2)?;
+        let keys = as_list_array(&keys)?;
+        let row = keys.value(1);
+        assert_eq!(as_string_array(&row)?, &StringArray::from(vec!["a", "b"]));
+
+        let values = MapValuesFunc::new()
+            .invoke(&[ColumnarValue::Array(map)])?
+            .into_array(2)?;
+        let values = as_list_array(&values)?;
+        let row = values.value(1);
+        assert_eq!(
+            as_int64_array(&row)?,
+            &Int64Array::from(vec![None, Some(3)])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_element_at() -> Result<()> {
+        let map = make_map(&[
+            ColumnarValue::Array(Arc::new(StringArray::from(vec!["a", "b", "c"]))),
+            ColumnarValue::Scalar(ScalarValue::Int64(Some(1))),
+            ColumnarValue::Scalar(ScalarValue::from("b")),
+            ColumnarValue::Scalar(ScalarValue::Int64(Some(2))),
+        ])?;
+        let value = ElementAtFunc::new()
+            .invoke(&[
+                ColumnarValue::Array(map),
+                ColumnarValue::Scalar(ScalarValue::from("b")),
+            ])?
+            .into_array(3)?;
+        // the first matching entry is returned, and NULL if there is none
+        assert_eq!(
+            as_int64_array(&value)?,
+            &Int64Array::from(vec![Some(2), Some(1), Some(2)])
+        );
+
+        let value = map_lookup(
+            as_map_array(&make_map(&[
+                ColumnarValue::Scalar(ScalarValue::Int64(Some(1))),
+                ColumnarValue::Scalar(ScalarValue::from("x")),
+            ])?)?,
+            &(Arc::new(Int64Array::from(vec![2])) as ArrayRef),
+        )?;
+        assert_eq!(value.null_count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_map_null_key() {
+        let err = make_map(&[
+            ColumnarValue::Scalar(ScalarValue::Utf8(None)),
+            ColumnarValue::Scalar(ScalarValue::Int64(Some(1))),
+        ])
+        .unwrap_err();
+        assert!(err.to_string().contains("make_map keys cannot be NULL"));
+    }
+}
@@ -20,6 +20,7 @@
 pub mod arrow_cast;
 pub mod arrowtypeof;
 pub mod getfield;
+pub mod map;
 pub mod named_struct;
 pub mod nullif;
 pub mod nvl;
@@ -35,6 +36,10 @@ make_udf_function!(arrowtypeof::ArrowTypeOfFunc, ARROWTYPEOF, arrow_typeof);
 make_udf_function!(r#struct::StructFunc, STRUCT, r#struct);
 make_udf_function!(named_struct::NamedStructFunc, NAMED_STRUCT, named_struct);
 make_udf_function!(getfield::GetFieldFunc, GET_FIELD, get_field);
+make_udf_function!(map::MakeMapFunc, MAKE_MAP, make_map);
+make_udf_function!(map::MapKeysFunc, MAP_KEYS, map_keys);
+make_udf_function!(map::MapValuesFunc, MAP_VALUES, map_values);
+make_udf_function!(map::ElementAtFunc, ELEMENT_AT, element_at);
 
 // Export the functions out of this package, both as expr_fn as well as a list of functions
 export_functions!(
@@ -45,5 +50,9 @@ export_functions!(
     (arrow_typeof, arg_1, "Returns the Arrow type of the input expression."),
     (r#struct, args, "Returns a struct with the given arguments"),
     (named_struct, args, "Returns a struct with the given names and arguments pairs"),
-    (get_field, arg_1 arg_2, "Returns the value of the field with the given name from the struct")
+    (get_field, arg_1 arg_2, "Returns the value of the field with the given name from the struct"),
+    (make_map, args, "Returns a map with the given key and value pairs"),
+    (map_keys, arg_1, "Returns a list of the keys of the map"),
+    (map_values, arg_1, "Returns a list of the values of the map"),
+    (element_at, arg_1 arg_2, "Returns the value of the given key in the map, or NULL if the key is not found")
 );
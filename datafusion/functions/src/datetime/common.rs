@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::borrow::Cow;
 use std::sync::Arc;
 
 use arrow::array::{
@@ -62,7 +63,89 @@ pub(crate) fn validate_data_types(args: &[ColumnarValue], name: &str) -> Result<
     Ok(())
 }
 
-/// Accepts a string and parses it using the [`chrono::format::strftime`] specifiers
+/// Postgres style template patterns and the [`chrono::format::strftime`]
+/// specifiers they translate to, longer patterns first
+///
+/// [`chrono::format::strftime`]: https://docs.rs/chrono/latest/chrono/format/strftime/index.html
+const TEMPLATE_PATTERNS: &[(&str, &str)] = &[
+    ("HH24", "%H"),
+    ("HH12", "%I"),
+    ("HH", "%I"),
+    ("MI", "%M"),
+    ("SS", "%S"),
+    ("MS", "%3f"),
+    ("US", "%6f"),
+    ("AM", "%p"),
+    ("PM", "%p"),
+    ("am", "%P"),
+    ("pm", "%P"),
+    ("IYYY", "%G"),
+    ("YYYY", "%Y"),
+    ("YY", "%y"),
+    ("Month", "%B"),
+    ("Mon", "%b"),
+    ("MM", "%m"),
+    ("Day", "%A"),
+    ("Dy", "%a"),
+    ("DDD", "%j"),
+    ("DD", "%d"),
+    ("ID", "%u"),
+    ("IW", "%V"),
+    ("TZ", "%Z"),
+    ("OF", "%:z"),
+];
+
+/// Translates a format of Postgres style template patterns, such as
+/// `YYYY-MM-DD HH24:MI:SS`, to [`chrono::format::strftime`] specifiers.
+///
+/// Text in double quotes is copied as is, and the `FM` prefix suppresses
+/// the padding of the following numeric pattern. Formats containing `%` are
+/// already [`chrono::format::strftime`] formats and are returned unchanged.
+///
+/// [`chrono::format::strftime`]: https://docs.rs/chrono/latest/chrono/format/strftime/index.html
+pub(crate) fn chrono_format(format: &str) -> Cow<'_, str> {
+    if format.contains('%') {
+        return Cow::Borrowed(format);
+    }
+
+    let mut translated = String::with_capacity(format.len() * 2);
+    let mut fill_mode = false;
+    let mut rest = format;
+    while let Some(c) = rest.chars().next() {
+        if let Some(quoted) = rest.strip_prefix('"') {
+            let (text, remaining) = quoted.split_once('"').unwrap_or((quoted, ""));
+            translated.push_str(text);
+            rest = remaining;
+        } else if let Some(remaining) = rest.strip_prefix("FM") {
+            fill_mode = true;
+            rest = remaining;
+            continue;
+        } else if let Some((pattern, specifier)) = TEMPLATE_PATTERNS
+            .iter()
+            .find(|(pattern, _)| rest.starts_with(pattern))
+        {
+            match specifier.strip_prefix('%') {
+                // numeric specifiers are padded unless prefixed by `-`
+                Some(spec @ ("H" | "I" | "M" | "S" | "Y" | "m" | "d" | "j" | "V"))
+                    if fill_mode =>
+                {
+                    translated.push_str("%-");
+                    translated.push_str(spec);
+                }
+                _ => translated.push_str(specifier),
+            }
+            rest = &rest[pattern.len()..];
+        } else {
+            translated.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+        fill_mode = false;
+    }
+    Cow::Owned(translated)
+}
+
+/// Accepts a string and parses it using the [`chrono::format::strftime`] specifiers,
+/// or the Postgres style template patterns translated by [`chrono_format`],
 /// relative to the provided `timezone`
 ///
 /// [IANA timezones] are only supported if the `arrow-array/chrono-tz` feature is enabled
@@ -86,7 +169,8 @@ pub(crate) fn string_to_datetime_formatted<T: TimeZone>(
     };
 
     let mut parsed = Parsed::new();
-    parse(&mut parsed, s, StrftimeItems::new(format)).map_err(|e| err(&e.to_string()))?;
+    parse(&mut parsed, s, StrftimeItems::new(&chrono_format(format)))
+        .map_err(|e| err(&e.to_string()))?;
 
     // attempt to parse the string assuming it has a timezone
     let dt = parsed.to_datetime();
@@ -16,15 +16,16 @@
 // under the License.
 
 use std::any::Any;
+use std::borrow::Cow;
 use std::sync::Arc;
 
 use arrow::array::cast::AsArray;
 use arrow::array::{new_null_array, Array, ArrayRef, StringArray};
-use arrow::datatypes::DataType;
 use arrow::datatypes::DataType::{
-    Date32, Date64, Duration, Time32, Time64, Timestamp, Utf8,
+    Date32, Date64, Duration, Float64, Int64, Time32, Time64, Timestamp, Utf8,
 };
 use arrow::datatypes::TimeUnit::{Microsecond, Millisecond, Nanosecond, Second};
+use arrow::datatypes::{DataType, Float64Type, Int64Type};
 use arrow::error::ArrowError;
 use arrow::util::display::{ArrayFormatter, DurationFormat, FormatOptions};
 
@@ -34,6 +35,8 @@ use datafusion_expr::{
     ColumnarValue, ScalarUDFImpl, Signature, Volatility, TIMEZONE_WILDCARD,
 };
 
+use crate::datetime::common::chrono_format;
+
 #[derive(Debug)]
 pub struct ToCharFunc {
     signature: Signature,
@@ -81,6 +84,8 @@ impl ToCharFunc {
                     Exact(vec![Duration(Millisecond), Utf8]),
                     Exact(vec![Duration(Microsecond), Utf8]),
                     Exact(vec![Duration(Nanosecond), Utf8]),
+                    Exact(vec![Int64, Utf8]),
+                    Exact(vec![Float64, Utf8]),
                 ],
                 Volatility::Immutable,
             ),
@@ -114,6 +119,10 @@ impl ScalarUDFImpl for ToCharFunc {
             );
         }
 
+        if matches!(args[0].data_type(), Int64 | Float64) && args[1].data_type() == Utf8 {
+            return _to_char_number(args);
+        }
+
         match &args[1] {
             ColumnarValue::Scalar(ScalarValue::Utf8(None))
             | ColumnarValue::Scalar(ScalarValue::Null) => {
@@ -139,6 +148,14 @@ impl ScalarUDFImpl for ToCharFunc {
     }
 }
 
+/// Translates Postgres style formats of dates and times to chrono formats
+fn _chrono_format<'a>(data_type: &DataType, format: &'a str) -> Cow<'a, str> {
+    match data_type {
+        Duration(_) => Cow::Borrowed(format),
+        _ => chrono_format(format),
+    }
+}
+
 fn _build_format_options<'a>(
     data_type: &DataType,
     format: Option<&'a str>,
@@ -192,7 +209,8 @@ fn _to_char_scalar(
         }
     }
 
-    let format_options = match _build_format_options(data_type, format) {
+    let format = format.map(|format| _chrono_format(data_type, format));
+    let format_options = match _build_format_options(data_type, format.as_deref()) {
         Ok(value) => value,
         Err(value) => return value,
     };
@@ -229,11 +247,12 @@ fn _to_char_array(args: &[ColumnarValue]) -> Result<ColumnarValue> {
         } else {
             Some(format_array.value(idx))
         };
-        if format.is_none() {
+        let Some(format) = format else {
             results.push(None);
             continue;
-        }
-        let format_options = match _build_format_options(data_type, format) {
+        };
+        let format = _chrono_format(data_type, format);
+        let format_options = match _build_format_options(data_type, Some(&format)) {
             Ok(value) => value,
             Err(value) => return value,
         };
@@ -260,6 +279,179 @@ fn _to_char_array(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     }
 }
 
+/// Formats numbers with Postgres style patterns, such as `FM9,990.00`
+fn _to_char_number(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(args)?;
+    let formats = arrays[1].as_string::<i32>();
+
+    let results = (0..arrays[0].len())
+        .map(|idx| {
+            if arrays[0].is_null(idx) || formats.is_null(idx) {
+                return Ok(None);
+            }
+            let format = NumberFormat::try_new(formats.value(idx))?;
+            let formatted = match arrays[0].data_type() {
+                Int64 => format
+                    .format_integer(arrays[0].as_primitive::<Int64Type>().value(idx)),
+                _ => format
+                    .format_float(arrays[0].as_primitive::<Float64Type>().value(idx)),
+            };
+            Ok(Some(formatted))
+        })
+        .collect::<Result<StringArray>>()?;
+
+    match args[0] {
+        ColumnarValue::Array(_) => Ok(ColumnarValue::Array(Arc::new(results))),
+        ColumnarValue::Scalar(_) => Ok(ColumnarValue::Scalar(
+            ScalarValue::try_from_array(&results, 0)?,
+        )),
+    }
+}
+
+/// A Postgres style number format, made of
+///
+/// * `9`: a digit, omitted if it is a leading zero
+/// * `0`: a digit, including leading zeros
+/// * `.` or `D`: the decimal point
+/// * `,` or `G`: a group separator, omitted before the leading digit
+/// * `FM` prefix: suppresses padding and trailing zeros of `9` fraction digits
+///
+/// Numbers are right aligned and prefixed by their sign, a space for
+/// positive numbers. Numbers with more integer digits than the format are
+/// printed as `#`s.
+struct NumberFormat {
+    /// The digits and group separators before the decimal point
+    integer: Vec<char>,
+    /// The digits after the decimal point, if the format has one
+    fraction: Option<Vec<char>>,
+    fill_mode: bool,
+}
+
+impl NumberFormat {
+    fn try_new(format: &str) -> Result<Self> {
+        let (fill_mode, pattern) = match format.strip_prefix("FM") {
+            Some(pattern) => (true, pattern),
+            None => (false, format),
+        };
+
+        let mut integer = vec![];
+        let mut fraction: Option<Vec<char>> = None;
+        for c in pattern.chars() {
+            match (c, &mut fraction) {
+                ('9' | '0', None) => integer.push(c),
+                ('9' | '0', Some(fraction)) => fraction.push(c),
+                (',' | 'G', None) => integer.push(','),
+                ('.' | 'D', None) => fraction = Some(vec![]),
+                _ => {
+                    return exec_err!(
+                        "Unsupported pattern '{c}' in to_char number format '{format}'"
+                    )
+                }
+            }
+        }
+        Ok(Self {
+            integer,
+            fraction,
+            fill_mode,
+        })
+    }
+
+    fn fraction_digits(&self) -> usize {
+        self.fraction.as_ref().map_or(0, |fraction| fraction.len())
+    }
+
+    fn format_integer(&self, value: i64) -> String {
+        let fraction = "0".repeat(self.fraction_digits());
+        self.format(value < 0, &value.unsigned_abs().to_string(), &fraction)
+    }
+
+    fn format_float(&self, value: f64) -> String {
+        if !value.is_finite() {
+            return self.overflow();
+        }
+        let digits = format!("{:.*}", self.fraction_digits(), value.abs());
+        let (integer, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+        self.format(value < 0.0, integer, fraction)
+    }
+
+    /// Formats the decimal digits of a number
+    fn format(&self, negative: bool, integer: &str, fraction: &str) -> String {
+        // a zero integer part is only printed by `0` patterns, unless the
+        // number has no fraction digits
+        let integer = match integer.trim_start_matches('0') {
+            "" if self.fraction_digits() == 0 => "0",
+            integer => integer,
+        };
+        let positions = self.integer.iter().filter(|c| **c != ',').count();
+        if integer.len() > positions {
+            return self.overflow();
+        }
+
+        let mut formatted = String::new();
+        let mut padding = positions - integer.len();
+        let mut digits = integer.chars();
+        let mut started = false;
+        for c in &self.integer {
+            match c {
+                ',' => formatted.push(if started { ',' } else { ' ' }),
+                _ if padding > 0 => {
+                    padding -= 1;
+                    started |= *c == '0';
+                    formatted.push(if started { '0' } else { ' ' });
+                }
+                _ => {
+                    started = true;
+                    formatted.extend(digits.next());
+                }
+            }
+        }
+
+        // the sign precedes the leading digit
+        let leading = formatted.len() - formatted.trim_start().len();
+        formatted.insert(leading, if negative { '-' } else { ' ' });
+
+        if let Some(pattern) = &self.fraction {
+            formatted.push('.');
+            formatted.push_str(fraction);
+            if self.fill_mode {
+                let trailing = pattern
+                    .iter()
+                    .rev()
+                    .zip(fraction.chars().rev())
+                    .take_while(|(pattern, digit)| **pattern == '9' && *digit == '0')
+                    .count();
+                formatted.truncate(formatted.len() - trailing);
+            }
+        }
+
+        if self.fill_mode {
+            formatted.trim_start().to_string()
+        } else {
+            formatted
+        }
+    }
+
+    /// Formats a number that does not fit the format
+    fn overflow(&self) -> String {
+        let integer = self
+            .integer
+            .iter()
+            .map(|c| if *c == ',' { ',' } else { '#' });
+        let fraction = self.fraction.iter().flat_map(|fraction| {
+            std::iter::once('.').chain(fraction.iter().map(|_| '#'))
+        });
+        let formatted: String = std::iter::once(' ')
+            .chain(integer)
+            .chain(fraction)
+            .collect();
+        if self.fill_mode {
+            formatted.trim_start().to_string()
+        } else {
+            formatted
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::datetime::to_char::ToCharFunc;
@@ -602,4 +794,55 @@ mod tests {
             "Execution error: Format for `to_char` must be non-null Utf8, received Timestamp(Nanosecond, None)"
         );
     }
+
+    #[test]
+    fn test_to_char_number() {
+        let cases = vec![
+            (ScalarValue::Float64(Some(123.456)), "999.99", " 123.46"),
+            (ScalarValue::Float64(Some(-12.3)), "999.99", " -12.30"),
+            (ScalarValue::Float64(Some(0.5)), "999.99", "    .50"),
+            (ScalarValue::Float64(Some(0.5)), "0.99", " 0.50"),
+            (ScalarValue::Float64(Some(0.5)), "FM999.99", ".5"),
+            (ScalarValue::Float64(Some(12.0)), "FM999.99", "12."),
+            (ScalarValue::Float64(Some(1234.5)), "99.9", " ##.#"),
+            (ScalarValue::Float64(Some(f64::NAN)), "9", " #"),
+            (ScalarValue::Int64(Some(0)), "999", "   0"),
+            (
+                ScalarValue::Int64(Some(-1234567)),
+                "9G999G999",
+                "-1,234,567",
+            ),
+            (ScalarValue::Int64(Some(1234)), "FM9,999,999", "1,234"),
+            (ScalarValue::Int64(Some(42)), "0999D00", " 0042.00"),
+            (
+                ScalarValue::Int64(Some(i64::MIN)),
+                "FM9999999999999999999",
+                "-9223372036854775808",
+            ),
+        ];
+
+        for (value, format, expected) in cases {
+            let result = ToCharFunc::new()
+                .invoke(&[
+                    ColumnarValue::Scalar(value.clone()),
+                    ColumnarValue::Scalar(ScalarValue::Utf8(Some(format.to_string()))),
+                ])
+                .expect("that to_char formatted numbers without error");
+
+            if let ColumnarValue::Scalar(ScalarValue::Utf8(Some(formatted))) = result {
+                assert_eq!(expected, formatted, "{value:?} {format}");
+            } else {
+                panic!("Expected a scalar value")
+            }
+        }
+
+        let result = ToCharFunc::new().invoke(&[
+            ColumnarValue::Scalar(ScalarValue::Int64(Some(1))),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("9.9.9".to_string()))),
+        ]);
+        assert_eq!(
+            result.err().unwrap().strip_backtrace(),
+            "Execution error: Unsupported pattern '.' in to_char number format '9.9.9'"
+        );
+    }
 }
@@ -20,8 +20,8 @@ use std::any::Any;
 use arrow::datatypes::DataType::Timestamp;
 use arrow::datatypes::TimeUnit::{Microsecond, Millisecond, Nanosecond, Second};
 use arrow::datatypes::{
-    ArrowTimestampType, DataType, TimestampMicrosecondType, TimestampMillisecondType,
-    TimestampNanosecondType, TimestampSecondType,
+    ArrowTimestampType, DataType, TimeUnit, TimestampMicrosecondType,
+    TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType,
 };
 
 use datafusion_common::{exec_err, Result, ScalarType};
@@ -143,8 +143,8 @@ impl ScalarUDFImpl for ToTimestampFunc {
         &self.signature
     }
 
-    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
-        Ok(Timestamp(Nanosecond, None))
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(return_type_for(&arg_types[0], Nanosecond))
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
@@ -167,6 +167,9 @@ impl ScalarUDFImpl for ToTimestampFunc {
             DataType::Null | DataType::Float64 | Timestamp(_, None) => {
                 args[0].cast_to(&Timestamp(Nanosecond, None), None)
             }
+            Timestamp(_, Some(tz)) => {
+                args[0].cast_to(&Timestamp(Nanosecond, Some(tz)), None)
+            }
             DataType::Utf8 => {
                 to_timestamp_impl::<TimestampNanosecondType>(args, "to_timestamp")
             }
@@ -193,8 +196,8 @@ impl ScalarUDFImpl for ToTimestampSecondsFunc {
         &self.signature
     }
 
-    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
-        Ok(Timestamp(Second, None))
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(return_type_for(&arg_types[0], Second))
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
@@ -214,6 +217,7 @@ impl ScalarUDFImpl for ToTimestampSecondsFunc {
             DataType::Null | DataType::Int32 | DataType::Int64 | Timestamp(_, None) => {
                 args[0].cast_to(&Timestamp(Second, None), None)
             }
+            Timestamp(_, Some(tz)) => args[0].cast_to(&Timestamp(Second, Some(tz)), None),
             DataType::Utf8 => {
                 to_timestamp_impl::<TimestampSecondType>(args, "to_timestamp_seconds")
             }
@@ -240,8 +244,8 @@ impl ScalarUDFImpl for ToTimestampMillisFunc {
         &self.signature
     }
 
-    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
-        Ok(Timestamp(Millisecond, None))
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(return_type_for(&arg_types[0], Millisecond))
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
@@ -261,6 +265,9 @@ impl ScalarUDFImpl for ToTimestampMillisFunc {
             DataType::Null | DataType::Int32 | DataType::Int64 | Timestamp(_, None) => {
                 args[0].cast_to(&Timestamp(Millisecond, None), None)
             }
+            Timestamp(_, Some(tz)) => {
+                args[0].cast_to(&Timestamp(Millisecond, Some(tz)), None)
+            }
             DataType::Utf8 => {
                 to_timestamp_impl::<TimestampMillisecondType>(args, "to_timestamp_millis")
             }
@@ -287,8 +294,8 @@ impl ScalarUDFImpl for ToTimestampMicrosFunc {
         &self.signature
     }
 
-    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
-        Ok(Timestamp(Microsecond, None))
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(return_type_for(&arg_types[0], Microsecond))
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
@@ -308,6 +315,9 @@ impl ScalarUDFImpl for ToTimestampMicrosFunc {
             DataType::Null | DataType::Int32 | DataType::Int64 | Timestamp(_, None) => {
                 args[0].cast_to(&Timestamp(Microsecond, None), None)
             }
+            Timestamp(_, Some(tz)) => {
+                args[0].cast_to(&Timestamp(Microsecond, Some(tz)), None)
+            }
             DataType::Utf8 => {
                 to_timestamp_impl::<TimestampMicrosecondType>(args, "to_timestamp_micros")
             }
@@ -334,8 +344,8 @@ impl ScalarUDFImpl for ToTimestampNanosFunc {
         &self.signature
     }
 
-    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
-        Ok(Timestamp(Nanosecond, None))
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(return_type_for(&arg_types[0], Nanosecond))
     }
 
     fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
@@ -355,6 +365,9 @@ impl ScalarUDFImpl for ToTimestampNanosFunc {
             DataType::Null | DataType::Int32 | DataType::Int64 | Timestamp(_, None) => {
                 args[0].cast_to(&Timestamp(Nanosecond, None), None)
             }
+            Timestamp(_, Some(tz)) => {
+                args[0].cast_to(&Timestamp(Nanosecond, Some(tz)), None)
+            }
             DataType::Utf8 => {
                 to_timestamp_impl::<TimestampNanosecondType>(args, "to_timestamp_nanos")
             }
@@ -368,6 +381,15 @@ impl ScalarUDFImpl for ToTimestampNanosFunc {
     }
 }
 
+/// The return type of the `to_timestamp` functions returning timestamps of
+/// `unit`, which keep the timezone of timestamp arguments
+fn return_type_for(arg_type: &DataType, unit: TimeUnit) -> DataType {
+    match arg_type {
+        Timestamp(_, Some(tz)) => Timestamp(unit, Some(tz.clone())),
+        _ => Timestamp(unit, None),
+    }
+}
+
 fn to_timestamp_impl<T: ArrowTimestampType + ScalarType<i64>>(
     args: &[ColumnarValue],
     name: &str,
@@ -670,6 +692,23 @@ mod tests {
             parse_timestamp_formatted("09-08-2020 13/42/29", "%m-%d-%Y %H/%M/%S")
                 .unwrap()
         );
+        // Postgres style template patterns
+        assert_eq!(
+            1599572549190000000,
+            parse_timestamp_formatted(
+                "09-08-2020 01/42/29.190 PM",
+                "MM-DD-YYYY HH12/MI/SS.MS AM"
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            1599572549000000000,
+            parse_timestamp_formatted(
+                "8 September 2020 13:42:29",
+                "FMDD Month YYYY HH24:MI:SS"
+            )
+            .unwrap()
+        );
     }
 
     fn parse_timestamp_formatted(s: &str, format: &str) -> Result<i64, DataFusionError> {
@@ -851,4 +890,47 @@ mod tests {
             assert_contains!(actual, expected);
         }
     }
+
+    #[test]
+    fn to_timestamp_keeps_timezone() -> Result<()> {
+        let tz: Option<Arc<str>> = Some("+05:00".into());
+        let arg = ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(
+            Some(1599572549190850000),
+            tz.clone(),
+        ));
+
+        let funcs: Vec<(Box<dyn ScalarUDFImpl>, ScalarValue)> = vec![
+            (
+                Box::new(ToTimestampFunc::new()),
+                ScalarValue::TimestampNanosecond(Some(1599572549190850000), tz.clone()),
+            ),
+            (
+                Box::new(ToTimestampSecondsFunc::new()),
+                ScalarValue::TimestampSecond(Some(1599572549), tz.clone()),
+            ),
+            (
+                Box::new(ToTimestampMillisFunc::new()),
+                ScalarValue::TimestampMillisecond(Some(1599572549190), tz.clone()),
+            ),
+            (
+                Box::new(ToTimestampMicrosFunc::new()),
+                ScalarValue::TimestampMicrosecond(Some(1599572549190850), tz.clone()),
+            ),
+            (
+                Box::new(ToTimestampNanosFunc::new()),
+                ScalarValue::TimestampNanosecond(Some(1599572549190850000), tz.clone()),
+            ),
+        ];
+
+        for (func, expected) in funcs {
+            let return_type = func.return_type(&[arg.data_type()])?;
+            assert_eq!(return_type, expected.data_type(), "{}", func.name());
+
+            let ColumnarValue::Scalar(actual) = func.invoke(&[arg.clone()])? else {
+                panic!("Expected a scalar value")
+            };
+            assert_eq!(actual, expected, "{}", func.name());
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,370 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Compression expressions
+
+use std::any::Any;
+use std::io::{Read, Write};
+use std::sync::Arc;
+use std::{fmt, str::FromStr};
+
+use arrow::array::{ArrayRef, GenericBinaryArray, OffsetSizeTrait};
+use arrow::datatypes::DataType;
+use datafusion_common::cast::{as_generic_binary_array, as_generic_string_array};
+use datafusion_common::ScalarValue;
+use datafusion_common::{exec_err, not_impl_err, plan_err, DataFusionError, Result};
+use datafusion_expr::TypeSignature::*;
+use datafusion_expr::{ColumnarValue, ScalarUDFImpl, Signature, Volatility};
+use flate2::read::MultiGzDecoder;
+use flate2::write::GzEncoder;
+
+/// The default maximum size in bytes of the values decompressed from a
+/// batch, which protects against values that decompress to exhaust memory
+const DEFAULT_MAX_DECOMPRESSED_SIZE: u64 = 256 * 1024 * 1024;
+
+#[derive(Debug)]
+pub struct CompressFunc {
+    signature: Signature,
+}
+
+impl Default for CompressFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CompressFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: compression_signature(),
+        }
+    }
+}
+
+impl ScalarUDFImpl for CompressFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "compress"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        compression_return_type(self.name(), &arg_types[0])
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        if args.len() != 2 {
+            return exec_err!(
+                "{:?} args were supplied but {} takes exactly two arguments",
+                args.len(),
+                self.name()
+            );
+        }
+        let compression = compression_method(self.name(), &args[1])?;
+        process(&args[0], |value| compression.compress(value))
+    }
+}
+
+#[derive(Debug)]
+pub struct DecompressFunc {
+    signature: Signature,
+}
+
+impl Default for DecompressFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DecompressFunc {
+    pub fn new() -> Self {
+        use DataType::*;
+        let mut type_signatures = vec![];
+        for value_type in [Utf8, LargeUtf8, Binary, LargeBinary] {
+            type_signatures.push(Exact(vec![value_type.clone(), Utf8]));
+            type_signatures.push(Exact(vec![value_type, Utf8, Int64]));
+        }
+        Self {
+            signature: Signature::one_of(type_signatures, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for DecompressFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "decompress"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        compression_return_type(self.name(), &arg_types[0])
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        if !(2..=3).contains(&args.len()) {
+            return exec_err!(
+                "{:?} args were supplied but {} takes two or three arguments",
+                args.len(),
+                self.name()
+            );
+        }
+        let compression = compression_method(self.name(), &args[1])?;
+        let max_size = match args.get(2) {
+            Some(ColumnarValue::Scalar(ScalarValue::Int64(Some(max_size))))
+                if *max_size >= 0 =>
+            {
+                *max_size as u64
+            }
+            Some(_) => {
+                return exec_err!(
+                    "Third argument to {} must be a constant non negative maximum size",
+                    self.name()
+                )
+            }
+            None => DEFAULT_MAX_DECOMPRESSED_SIZE,
+        };
+        // the maximum size is shared by all values of the batch, as many
+        // values below it could still exhaust memory together
+        let mut remaining = max_size;
+        process(&args[0], |value| {
+            let Some(decompressed) = compression.decompress(value, remaining)? else {
+                return exec_err!(
+                    "Failed to decompress value using {compression}: the decompressed values exceed the maximum size of {max_size} bytes"
+                );
+            };
+            remaining -= decompressed.len() as u64;
+            Ok(decompressed)
+        })
+    }
+}
+
+fn compression_signature() -> Signature {
+    use DataType::*;
+    Signature::one_of(
+        vec![
+            Exact(vec![Utf8, Utf8]),
+            Exact(vec![LargeUtf8, Utf8]),
+            Exact(vec![Binary, Utf8]),
+            Exact(vec![LargeBinary, Utf8]),
+        ],
+        Volatility::Immutable,
+    )
+}
+
+fn compression_return_type(name: &str, arg_type: &DataType) -> Result<DataType> {
+    use DataType::*;
+
+    Ok(match arg_type {
+        Utf8 | Binary => Binary,
+        LargeUtf8 | LargeBinary => LargeBinary,
+        Null => Null,
+        _ => {
+            return plan_err!("The {name} function can only accept utf8 or binary.");
+        }
+    })
+}
+
+/// Returns the compression method named by the constant second argument
+fn compression_method(name: &str, arg: &ColumnarValue) -> Result<Compression> {
+    match arg {
+        ColumnarValue::Scalar(
+            ScalarValue::Utf8(Some(method)) | ScalarValue::LargeUtf8(Some(method)),
+        ) => method.parse::<Compression>(),
+        _ => not_impl_err!(
+            "Second argument to {name} must be a constant: {name} using dynamically decided method is not yet supported"
+        ),
+    }
+}
+
+/// Applies `f` to the bytes of every non null value of `value`, a string or
+/// binary array or scalar
+fn process(
+    value: &ColumnarValue,
+    mut f: impl FnMut(&[u8]) -> Result<Vec<u8>>,
+) -> Result<ColumnarValue> {
+    let mut f = |value: Option<&[u8]>| value.map(&mut f).transpose();
+    match value {
+        ColumnarValue::Array(a) => {
+            let array: ArrayRef = match a.data_type() {
+                DataType::Utf8 => {
+                    let values = as_generic_string_array::<i32>(a)?.iter();
+                    to_binary_array::<i32>(values.map(|v| f(v.map(str::as_bytes))))?
+                }
+                DataType::LargeUtf8 => {
+                    let values = as_generic_string_array::<i64>(a)?.iter();
+                    to_binary_array::<i64>(values.map(|v| f(v.map(str::as_bytes))))?
+                }
+                DataType::Binary => {
+                    let values = as_generic_binary_array::<i32>(a)?.iter();
+                    to_binary_array::<i32>(values.map(f))?
+                }
+                DataType::LargeBinary => {
+                    let values = as_generic_binary_array::<i64>(a)?.iter();
+                    to_binary_array::<i64>(values.map(f))?
+                }
+                other => return exec_err!("Unsupported data type {other:?}"),
+            };
+            Ok(ColumnarValue::Array(array))
+        }
+        ColumnarValue::Scalar(scalar) => {
+            let scalar = match scalar {
+                ScalarValue::Utf8(a) => {
+                    ScalarValue::Binary(f(a.as_ref().map(|s| s.as_bytes()))?)
+                }
+                ScalarValue::LargeUtf8(a) => {
+                    ScalarValue::LargeBinary(f(a.as_ref().map(|s| s.as_bytes()))?)
+                }
+                ScalarValue::Binary(a) => ScalarValue::Binary(f(a.as_deref())?),
+                ScalarValue::LargeBinary(a) => ScalarValue::LargeBinary(f(a.as_deref())?),
+                other => return exec_err!("Unsupported data type {other:?}"),
+            };
+            Ok(ColumnarValue::Scalar(scalar))
+        }
+    }
+}
+
+fn to_binary_array<T: OffsetSizeTrait>(
+    values: impl Iterator<Item = Result<Option<Vec<u8>>>>,
+) -> Result<ArrayRef> {
+    let array = values.collect::<Result<GenericBinaryArray<T>>>()?;
+    Ok(Arc::new(array))
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Compression {
+    Gzip,
+    Zstd,
+}
+
+impl Compression {
+    fn compress(self, value: &[u8]) -> Result<Vec<u8>> {
+        match self {
+            Self::Gzip => {
+                let mut encoder =
+                    GzEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(value)?;
+                Ok(encoder.finish()?)
+            }
+            Self::Zstd => Ok(zstd::stream::encode_all(value, 0)?),
+        }
+    }
+
+    /// Decompresses `value`, returning `None` if it decompresses to more
+    /// than `max_size` bytes. Gzip values may consist of several
+    /// concatenated members.
+    fn decompress(self, value: &[u8], max_size: u64) -> Result<Option<Vec<u8>>> {
+        let mut out = vec![];
+        // read one byte more than allowed to detect values that are too large
+        let decompressed = match self {
+            Self::Gzip => MultiGzDecoder::new(value)
+                .take(max_size.saturating_add(1))
+                .read_to_end(&mut out),
+            Self::Zstd => zstd::stream::read::Decoder::new(value).and_then(|decoder| {
+                decoder
+                    .take(max_size.saturating_add(1))
+                    .read_to_end(&mut out)
+            }),
+        };
+        decompressed.map_err(|e| {
+            DataFusionError::Execution(format!(
+                "Failed to decompress value using {self}: {e}"
+            ))
+        })?;
+        Ok((out.len() as u64 <= max_size).then_some(out))
+    }
+}
+
+impl fmt::Display for Compression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", format!("{self:?}").to_lowercase())
+    }
+}
+
+impl FromStr for Compression {
+    type Err = DataFusionError;
+    fn from_str(name: &str) -> Result<Compression> {
+        Ok(match name {
+            "gzip" => Self::Gzip,
+            "zstd" => Self::Zstd,
+            _ => {
+                let options = [Self::Gzip, Self::Zstd]
+                    .iter()
+                    .map(|i| i.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return plan_err!(
+                    "There is no built-in compression named '{name}', currently supported compressions are: {options}"
+                );
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decompress_concatenated_gzip_members() -> Result<()> {
+        let mut value = Compression::Gzip.compress(b"abc")?;
+        value.extend(Compression::Gzip.compress(b"def")?);
+        let decompressed = Compression::Gzip.decompress(&value, 6)?;
+        assert_eq!(decompressed.as_deref(), Some(b"abcdef".as_slice()));
+        assert_eq!(Compression::Gzip.decompress(&value, 5)?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn decompress_batch_within_maximum_size() -> Result<()> {
+        let compressed = Compression::Zstd.compress(b"abcdef")?;
+        let values: ArrayRef = Arc::new(GenericBinaryArray::<i32>::from(vec![
+            compressed.as_slice(),
+            compressed.as_slice(),
+        ]));
+        let decompress = |max_size: i64| {
+            DecompressFunc::new().invoke(&[
+                ColumnarValue::Array(values.clone()),
+                ColumnarValue::Scalar(ScalarValue::from("zstd")),
+                ColumnarValue::Scalar(ScalarValue::Int64(Some(max_size))),
+            ])
+        };
+
+        let ColumnarValue::Array(array) = decompress(12)? else {
+            unreachable!()
+        };
+        assert_eq!(array.len(), 2);
+        // each value is within the maximum size, but not both together
+        let err = decompress(11).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("the decompressed values exceed the maximum size of 11 bytes"),
+            "{err}"
+        );
+        Ok(())
+    }
+}
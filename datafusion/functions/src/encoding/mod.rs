@@ -15,14 +15,20 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub mod compression;
 pub mod inner;
 
 // create `encode` and `decode` UDFs
 make_udf_function!(inner::EncodeFunc, ENCODE, encode);
 make_udf_function!(inner::DecodeFunc, DECODE, decode);
+// create `compress` and `decompress` UDFs
+make_udf_function!(compression::CompressFunc, COMPRESS, compress);
+make_udf_function!(compression::DecompressFunc, DECOMPRESS, decompress);
 
 // Export the functions out of this package, both as expr_fn as well as a list of functions
 export_functions!(
     (encode, input encoding, "encode the `input`, using the `encoding`. encoding can be base64 or hex"),
-    (decode, input encoding, "decode the `input`, using the `encoding`. encoding can be base64 or hex")
+    (decode, input encoding, "decode the `input`, using the `encoding`. encoding can be base64 or hex"),
+    (compress, input compression, "compress the `input`, using the `compression`. compression can be gzip or zstd"),
+    (decompress, input compression, "decompress the `input`, using the `compression`. compression can be gzip or zstd")
 );
@@ -17,9 +17,17 @@
 
 //! "regx" DataFusion functions
 
+use std::collections::HashMap;
+
+use datafusion_common::{exec_err, DataFusionError, Result};
+use regex::Regex;
+
+pub mod regexpcount;
+pub mod regexpextract;
 pub mod regexplike;
 pub mod regexpmatch;
 pub mod regexpreplace;
+pub mod regexpsplit;
 // create UDFs
 make_udf_function!(regexpmatch::RegexpMatchFunc, REGEXP_MATCH, regexp_match);
 make_udf_function!(regexplike::RegexpLikeFunc, REGEXP_LIKE, regexp_like);
@@ -28,6 +36,17 @@ make_udf_function!(
     REGEXP_REPLACE,
     regexp_replace
 );
+make_udf_function!(regexpcount::RegexpCountFunc, REGEXP_COUNT, regexp_count);
+make_udf_function!(
+    regexpextract::RegexpExtractFunc,
+    REGEXP_EXTRACT,
+    regexp_extract
+);
+make_udf_function!(
+    regexpsplit::RegexpSplitToArrayFunc,
+    REGEXP_SPLIT_TO_ARRAY,
+    regexp_split_to_array
+);
 export_functions!((
     regexp_match,
     input_arg1 input_arg2,
@@ -36,4 +55,39 @@ export_functions!((
     regexp_like,
     input_arg1 input_arg2,
     "Returns true if a has at least one match in a string,false otherwise."
-),(regexp_replace, arg1 arg2 arg3 arg4, "Replaces substrings in a string that match"));
+),(regexp_replace, arg1 arg2 arg3 arg4, "Replaces substrings in a string that match"
+),(
+    regexp_count,
+    input_arg1 input_arg2,
+    "Returns the number of matches of a regular expression in a string."
+),(
+    regexp_extract,
+    input_arg1 input_arg2 input_arg3,
+    "Returns the substring of a string captured by a group of a regular expression."
+),(
+    regexp_split_to_array,
+    input_arg1 input_arg2,
+    "Splits a string at the matches of a regular expression into an array."
+));
+
+/// Returns the compiled regular expression of `pattern` with the optional
+/// `flags`, which is compiled once and then looked up in `patterns`
+fn compile_regex<'a>(
+    patterns: &'a mut HashMap<String, Regex>,
+    pattern: &str,
+    flags: Option<&str>,
+) -> Result<&'a Regex> {
+    let pattern = match flags {
+        Some(flags) if flags.contains('g') => {
+            return exec_err!("the \"global\" flag is not supported");
+        }
+        Some(flags) if !flags.is_empty() => format!("(?{flags}){pattern}"),
+        _ => pattern.to_string(),
+    };
+    if !patterns.contains_key(&pattern) {
+        let re = Regex::new(&pattern)
+            .map_err(|err| DataFusionError::External(Box::new(err)))?;
+        patterns.insert(pattern.clone(), re);
+    }
+    Ok(patterns.get(&pattern).unwrap())
+}
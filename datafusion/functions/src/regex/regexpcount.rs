@@ -0,0 +1,213 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Regx expressions
+use arrow::array::{Array, ArrayRef, Int64Array, OffsetSizeTrait};
+use arrow::datatypes::DataType;
+use datafusion_common::cast::{as_generic_string_array, as_int64_array};
+use datafusion_common::{exec_err, internal_err, plan_err, Result, ScalarValue};
+use datafusion_expr::ColumnarValue;
+use datafusion_expr::TypeSignature::*;
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use regex::Regex;
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::compile_regex;
+
+#[derive(Debug)]
+pub struct RegexpCountFunc {
+    signature: Signature,
+}
+impl Default for RegexpCountFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegexpCountFunc {
+    pub fn new() -> Self {
+        use DataType::*;
+        Self {
+            signature: Signature::one_of(
+                vec![
+                    Exact(vec![Utf8, Utf8]),
+                    Exact(vec![LargeUtf8, Utf8]),
+                    Exact(vec![Utf8, Utf8, Int64]),
+                    Exact(vec![LargeUtf8, Utf8, Int64]),
+                    Exact(vec![Utf8, Utf8, Int64, Utf8]),
+                    Exact(vec![LargeUtf8, Utf8, Int64, Utf8]),
+                ],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for RegexpCountFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "regexp_count"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        match &arg_types[0] {
+            DataType::Utf8 | DataType::LargeUtf8 => Ok(DataType::Int64),
+            other => {
+                plan_err!(
+                    "The regexp_count function can only accept strings. Got {other}"
+                )
+            }
+        }
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        let len = args
+            .iter()
+            .fold(Option::<usize>::None, |acc, arg| match arg {
+                ColumnarValue::Scalar(_) => acc,
+                ColumnarValue::Array(a) => Some(a.len()),
+            });
+
+        let is_scalar = len.is_none();
+        let inferred_length = len.unwrap_or(1);
+        let args = args
+            .iter()
+            .map(|arg| arg.clone().into_array(inferred_length))
+            .collect::<Result<Vec<_>>>()?;
+
+        let result = match args[0].data_type() {
+            DataType::Utf8 => regexp_count::<i32>(&args),
+            DataType::LargeUtf8 => regexp_count::<i64>(&args),
+            other => {
+                internal_err!("Unsupported data type {other:?} for function regexp_count")
+            }
+        };
+        if is_scalar {
+            // If all inputs are scalar, keeps output as scalar
+            let result = result.and_then(|arr| ScalarValue::try_from_array(&arr, 0));
+            result.map(ColumnarValue::Scalar)
+        } else {
+            result.map(ColumnarValue::Array)
+        }
+    }
+}
+
+/// Counts the matches of the patterns in `args[1]` in the strings in
+/// `args[0]`, starting at the optional 1-based character positions in
+/// `args[2]`, with the optional flags in `args[3]`
+pub fn regexp_count<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if !(2..=4).contains(&args.len()) {
+        return exec_err!(
+            "regexp_count was called with {} arguments. It requires at least 2 and at most 4.",
+            args.len()
+        );
+    }
+    let values = as_generic_string_array::<T>(&args[0])?;
+    let patterns = as_generic_string_array::<i32>(&args[1])?;
+    let starts = args.get(2).map(|a| as_int64_array(a)).transpose()?;
+    let flags = args
+        .get(3)
+        .map(|a| as_generic_string_array::<i32>(a))
+        .transpose()?;
+
+    // creating Regex is expensive so create hashmap for memoization
+    let mut compiled: HashMap<String, Regex> = HashMap::new();
+    let result = (0..values.len())
+        .map(|i| {
+            if values.is_null(i)
+                || patterns.is_null(i)
+                || starts.is_some_and(|a| a.is_null(i))
+                || flags.is_some_and(|a| a.is_null(i))
+            {
+                return Ok(None);
+            }
+            let value = values.value(i);
+            let start = starts.map_or(1, |a| a.value(i));
+            if start < 1 {
+                return exec_err!("regexp_count start must be positive, got {start}");
+            }
+            // the byte offset of the start character
+            let offset = value
+                .char_indices()
+                .nth(start as usize - 1)
+                .map_or(value.len(), |(offset, _)| offset);
+            let re = compile_regex(
+                &mut compiled,
+                patterns.value(i),
+                flags.map(|a| a.value(i)),
+            )?;
+            Ok(Some(re.find_iter(&value[offset..]).count() as i64))
+        })
+        .collect::<Result<Int64Array>>()?;
+    Ok(Arc::new(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{LargeStringArray, StringArray};
+
+    #[test]
+    fn test_regexp_count() -> Result<()> {
+        let values = Arc::new(StringArray::from(vec![
+            Some("abcabcabc"),
+            Some("ABCabc"),
+            Some("abcabc"),
+            None,
+        ])) as ArrayRef;
+        let patterns = Arc::new(StringArray::from(vec!["abc"; 4])) as ArrayRef;
+
+        let result = regexp_count::<i32>(&[values.clone(), patterns.clone()])?;
+        let expected = Int64Array::from(vec![Some(3), Some(1), Some(2), None]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let starts = Arc::new(Int64Array::from(vec![2, 1, 4, 1])) as ArrayRef;
+        let flags = Arc::new(StringArray::from(vec!["", "i", "", ""])) as ArrayRef;
+        let result = regexp_count::<i32>(&[values, patterns, starts, flags])?;
+        let expected = Int64Array::from(vec![Some(2), Some(2), Some(1), None]);
+        assert_eq!(result.as_ref(), &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_regexp_count_large_utf8() -> Result<()> {
+        let values = Arc::new(LargeStringArray::from(vec!["Düsseldorf"])) as ArrayRef;
+        let patterns = Arc::new(StringArray::from(vec!["[ds]"])) as ArrayRef;
+        let starts = Arc::new(Int64Array::from(vec![3])) as ArrayRef;
+        let result = regexp_count::<i64>(&[values, patterns, starts])?;
+        assert_eq!(result.as_ref(), &Int64Array::from(vec![3]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_regexp_count_invalid_start() {
+        let values = Arc::new(StringArray::from(vec!["abc"])) as ArrayRef;
+        let patterns = Arc::new(StringArray::from(vec!["a"])) as ArrayRef;
+        let starts = Arc::new(Int64Array::from(vec![0])) as ArrayRef;
+        let err = regexp_count::<i32>(&[values, patterns, starts]).unwrap_err();
+        assert!(err.to_string().contains("start must be positive"), "{err}");
+    }
+}
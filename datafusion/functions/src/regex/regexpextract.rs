@@ -0,0 +1,206 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Regx expressions
+use arrow::array::{Array, ArrayRef, GenericStringBuilder, OffsetSizeTrait};
+use arrow::datatypes::DataType;
+use datafusion_common::cast::{as_generic_string_array, as_int64_array};
+use datafusion_common::{exec_err, internal_err, plan_err, Result, ScalarValue};
+use datafusion_expr::ColumnarValue;
+use datafusion_expr::TypeSignature::*;
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use regex::Regex;
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::compile_regex;
+
+#[derive(Debug)]
+pub struct RegexpExtractFunc {
+    signature: Signature,
+}
+impl Default for RegexpExtractFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegexpExtractFunc {
+    pub fn new() -> Self {
+        use DataType::*;
+        Self {
+            signature: Signature::one_of(
+                vec![
+                    Exact(vec![Utf8, Utf8]),
+                    Exact(vec![LargeUtf8, Utf8]),
+                    Exact(vec![Utf8, Utf8, Int64]),
+                    Exact(vec![LargeUtf8, Utf8, Int64]),
+                ],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for RegexpExtractFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "regexp_extract"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        match &arg_types[0] {
+            DataType::Utf8 => Ok(DataType::Utf8),
+            DataType::LargeUtf8 => Ok(DataType::LargeUtf8),
+            other => plan_err!(
+                "The regexp_extract function can only accept strings. Got {other}"
+            ),
+        }
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        let len = args
+            .iter()
+            .fold(Option::<usize>::None, |acc, arg| match arg {
+                ColumnarValue::Scalar(_) => acc,
+                ColumnarValue::Array(a) => Some(a.len()),
+            });
+
+        let is_scalar = len.is_none();
+        let inferred_length = len.unwrap_or(1);
+        let args = args
+            .iter()
+            .map(|arg| arg.clone().into_array(inferred_length))
+            .collect::<Result<Vec<_>>>()?;
+
+        let result = match args[0].data_type() {
+            DataType::Utf8 => regexp_extract::<i32>(&args),
+            DataType::LargeUtf8 => regexp_extract::<i64>(&args),
+            other => internal_err!(
+                "Unsupported data type {other:?} for function regexp_extract"
+            ),
+        };
+        if is_scalar {
+            // If all inputs are scalar, keeps output as scalar
+            let result = result.and_then(|arr| ScalarValue::try_from_array(&arr, 0));
+            result.map(ColumnarValue::Scalar)
+        } else {
+            result.map(ColumnarValue::Array)
+        }
+    }
+}
+
+/// Extracts the capture groups in `args[2]`, 1 by default, of the first
+/// matches of the patterns in `args[1]` in the strings in `args[0]`.
+///
+/// Like in Spark, the group 0 is the whole match, and the result is an empty
+/// string if the pattern does not match or the group did not participate in
+/// the match.
+pub fn regexp_extract<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if !(2..=3).contains(&args.len()) {
+        return exec_err!(
+            "regexp_extract was called with {} arguments. It requires at least 2 and at most 3.",
+            args.len()
+        );
+    }
+    let values = as_generic_string_array::<T>(&args[0])?;
+    let patterns = as_generic_string_array::<i32>(&args[1])?;
+    let groups = args.get(2).map(|a| as_int64_array(a)).transpose()?;
+
+    // creating Regex is expensive so create hashmap for memoization
+    let mut compiled: HashMap<String, Regex> = HashMap::new();
+    let mut builder = GenericStringBuilder::<T>::with_capacity(values.len(), 0);
+    for i in 0..values.len() {
+        if values.is_null(i)
+            || patterns.is_null(i)
+            || groups.is_some_and(|a| a.is_null(i))
+        {
+            builder.append_null();
+            continue;
+        }
+        let re = compile_regex(&mut compiled, patterns.value(i), None)?;
+        let group = groups.map_or(1, |a| a.value(i));
+        if group < 0 || group as usize >= re.captures_len() {
+            return exec_err!(
+                "regexp_extract group {group} is out of range, the pattern '{}' has {} groups",
+                patterns.value(i),
+                re.captures_len() - 1
+            );
+        }
+        let extracted = re
+            .captures(values.value(i))
+            .and_then(|captures| captures.get(group as usize))
+            .map_or("", |m| m.as_str());
+        builder.append_value(extracted);
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int64Array, LargeStringArray, StringArray};
+
+    #[test]
+    fn test_regexp_extract() -> Result<()> {
+        let values = Arc::new(StringArray::from(vec![
+            Some("100-200"),
+            Some("foo"),
+            Some("300-400"),
+            None,
+        ])) as ArrayRef;
+        let patterns = Arc::new(StringArray::from(vec![r"(\d+)-(\d+)"; 4])) as ArrayRef;
+
+        let result = regexp_extract::<i32>(&[values.clone(), patterns.clone()])?;
+        let expected = StringArray::from(vec![Some("100"), Some(""), Some("300"), None]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let groups = Arc::new(Int64Array::from(vec![2, 1, 0, 1])) as ArrayRef;
+        let result = regexp_extract::<i32>(&[values, patterns, groups])?;
+        let expected =
+            StringArray::from(vec![Some("200"), Some(""), Some("300-400"), None]);
+        assert_eq!(result.as_ref(), &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_regexp_extract_large_utf8() -> Result<()> {
+        let values = Arc::new(LargeStringArray::from(vec!["key=value"])) as ArrayRef;
+        let patterns = Arc::new(StringArray::from(vec!["(\\w+)=(\\w+)"])) as ArrayRef;
+        let groups = Arc::new(Int64Array::from(vec![2])) as ArrayRef;
+        let result = regexp_extract::<i64>(&[values, patterns, groups])?;
+        assert_eq!(result.as_ref(), &LargeStringArray::from(vec!["value"]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_regexp_extract_invalid_group() {
+        let values = Arc::new(StringArray::from(vec!["abc"])) as ArrayRef;
+        let patterns = Arc::new(StringArray::from(vec!["(a)"])) as ArrayRef;
+        let groups = Arc::new(Int64Array::from(vec![2])) as ArrayRef;
+        let err = regexp_extract::<i32>(&[values, patterns, groups]).unwrap_err();
+        assert!(err.to_string().contains("group 2 is out of range"), "{err}");
+    }
+}
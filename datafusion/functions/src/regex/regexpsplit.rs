@@ -0,0 +1,220 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Regx expressions
+use arrow::array::{Array, ArrayRef, GenericStringBuilder, ListBuilder, OffsetSizeTrait};
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::cast::as_generic_string_array;
+use datafusion_common::{exec_err, internal_err, plan_err, Result, ScalarValue};
+use datafusion_expr::ColumnarValue;
+use datafusion_expr::TypeSignature::*;
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use regex::Regex;
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::compile_regex;
+
+#[derive(Debug)]
+pub struct RegexpSplitToArrayFunc {
+    signature: Signature,
+}
+impl Default for RegexpSplitToArrayFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegexpSplitToArrayFunc {
+    pub fn new() -> Self {
+        use DataType::*;
+        Self {
+            signature: Signature::one_of(
+                vec![
+                    Exact(vec![Utf8, Utf8]),
+                    Exact(vec![LargeUtf8, Utf8]),
+                    Exact(vec![Utf8, Utf8, Utf8]),
+                    Exact(vec![LargeUtf8, Utf8, Utf8]),
+                ],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for RegexpSplitToArrayFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "regexp_split_to_array"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        match &arg_types[0] {
+            data_type @ (DataType::Utf8 | DataType::LargeUtf8) => Ok(DataType::List(
+                Arc::new(Field::new("item", data_type.clone(), true)),
+            )),
+            other => plan_err!(
+                "The regexp_split_to_array function can only accept strings. Got {other}"
+            ),
+        }
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+        let len = args
+            .iter()
+            .fold(Option::<usize>::None, |acc, arg| match arg {
+                ColumnarValue::Scalar(_) => acc,
+                ColumnarValue::Array(a) => Some(a.len()),
+            });
+
+        let is_scalar = len.is_none();
+        let inferred_length = len.unwrap_or(1);
+        let args = args
+            .iter()
+            .map(|arg| arg.clone().into_array(inferred_length))
+            .collect::<Result<Vec<_>>>()?;
+
+        let result = match args[0].data_type() {
+            DataType::Utf8 => regexp_split_to_array::<i32>(&args),
+            DataType::LargeUtf8 => regexp_split_to_array::<i64>(&args),
+            other => internal_err!(
+                "Unsupported data type {other:?} for function regexp_split_to_array"
+            ),
+        };
+        if is_scalar {
+            // If all inputs are scalar, keeps output as scalar
+            let result = result.and_then(|arr| ScalarValue::try_from_array(&arr, 0));
+            result.map(ColumnarValue::Scalar)
+        } else {
+            result.map(ColumnarValue::Array)
+        }
+    }
+}
+
+/// Splits the strings in `args[0]` at the matches of the patterns in
+/// `args[1]`, with the optional flags in `args[2]`.
+///
+/// Like in PostgreSQL, empty matches at the start or the end of a string, or
+/// right after another match, do not split it.
+pub fn regexp_split_to_array<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+    if !(2..=3).contains(&args.len()) {
+        return exec_err!(
+            "regexp_split_to_array was called with {} arguments. It requires at least 2 and at most 3.",
+            args.len()
+        );
+    }
+    let values = as_generic_string_array::<T>(&args[0])?;
+    let patterns = as_generic_string_array::<i32>(&args[1])?;
+    let flags = args
+        .get(2)
+        .map(|a| as_generic_string_array::<i32>(a))
+        .transpose()?;
+
+    // creating Regex is expensive so create hashmap for memoization
+    let mut compiled: HashMap<String, Regex> = HashMap::new();
+    let mut builder = ListBuilder::new(GenericStringBuilder::<T>::new());
+    for i in 0..values.len() {
+        if values.is_null(i) || patterns.is_null(i) || flags.is_some_and(|a| a.is_null(i))
+        {
+            builder.append_null();
+            continue;
+        }
+        let value = values.value(i);
+        let re =
+            compile_regex(&mut compiled, patterns.value(i), flags.map(|a| a.value(i)))?;
+        let mut last = 0;
+        for m in re.find_iter(value) {
+            if m.start() == m.end()
+                && (m.start() == 0 || m.start() == value.len() || m.start() == last)
+            {
+                continue;
+            }
+            builder.values().append_value(&value[last..m.start()]);
+            last = m.end();
+        }
+        builder.values().append_value(&value[last..]);
+        builder.append(true);
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{LargeStringArray, ListArray, StringArray};
+
+    fn split<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<Vec<Option<Vec<String>>>> {
+        let result = regexp_split_to_array::<T>(args)?;
+        let result = result.as_any().downcast_ref::<ListArray>().unwrap();
+        Ok(result
+            .iter()
+            .map(|list| {
+                list.map(|list| {
+                    as_generic_string_array::<T>(&list)
+                        .unwrap()
+                        .iter()
+                        .map(|s| s.unwrap().to_string())
+                        .collect()
+                })
+            })
+            .collect())
+    }
+
+    #[test]
+    fn test_regexp_split_to_array() -> Result<()> {
+        let values = Arc::new(StringArray::from(vec![
+            Some("a1b22c"),
+            Some("abc"),
+            Some("1a1"),
+            None,
+        ])) as ArrayRef;
+        let patterns =
+            Arc::new(StringArray::from(vec![r"\d+", "", r"\d", "x"])) as ArrayRef;
+        let result = split::<i32>(&[values, patterns])?;
+        let expected = vec![
+            Some(vec!["a".to_string(), "b".to_string(), "c".to_string()]),
+            Some(vec!["a".to_string(), "b".to_string(), "c".to_string()]),
+            Some(vec!["".to_string(), "a".to_string(), "".to_string()]),
+            None,
+        ];
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_regexp_split_to_array_flags() -> Result<()> {
+        let values = Arc::new(LargeStringArray::from(vec!["aXbxc"])) as ArrayRef;
+        let patterns = Arc::new(StringArray::from(vec!["x"])) as ArrayRef;
+        let flags = Arc::new(StringArray::from(vec!["i"])) as ArrayRef;
+        let result = split::<i64>(&[values, patterns, flags])?;
+        let expected = vec![Some(vec![
+            "a".to_string(),
+            "b".to_string(),
+            "c".to_string(),
+        ])];
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
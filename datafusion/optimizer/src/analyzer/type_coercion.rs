@@ -19,7 +19,7 @@
 
 use std::sync::Arc;
 
-use arrow::datatypes::{DataType, IntervalUnit};
+use arrow::datatypes::{DataType, IntervalMonthDayNanoType, IntervalUnit, TimeUnit};
 
 use datafusion_common::config::ConfigOptions;
 use datafusion_common::tree_node::{Transformed, TreeNodeRewriter};
@@ -66,8 +66,12 @@ impl AnalyzerRule for TypeCoercion {
         "type_coercion"
     }
 
-    fn analyze(&self, plan: LogicalPlan, _: &ConfigOptions) -> Result<LogicalPlan> {
-        analyze_internal(&DFSchema::empty(), &plan)
+    fn analyze(&self, plan: LogicalPlan, config: &ConfigOptions) -> Result<LogicalPlan> {
+        analyze_internal(
+            &DFSchema::empty(),
+            &plan,
+            config.optimizer.enable_dictionary_aware_comparisons,
+        )
     }
 }
 
@@ -75,12 +79,13 @@ fn analyze_internal(
     // use the external schema to handle the correlated subqueries case
     external_schema: &DFSchema,
     plan: &LogicalPlan,
+    dictionary_aware_comparisons: bool,
 ) -> Result<LogicalPlan> {
     // optimize child plans first
     let new_inputs = plan
         .inputs()
         .iter()
-        .map(|p| analyze_internal(external_schema, p))
+        .map(|p| analyze_internal(external_schema, p, dictionary_aware_comparisons))
         .collect::<Result<Vec<_>>>()?;
     // get schema representing all available input fields. This is used for data type
     // resolution only, so order does not matter here
@@ -101,6 +106,7 @@ fn analyze_internal(
 
     let mut expr_rewrite = TypeCoercionRewriter {
         schema: Arc::new(schema),
+        dictionary_aware_comparisons,
     };
 
     let new_expr = plan
@@ -118,6 +124,20 @@ fn analyze_internal(
 
 pub(crate) struct TypeCoercionRewriter {
     pub(crate) schema: DFSchemaRef,
+    /// Keep dictionary encoded columns compared to literals dictionary encoded,
+    /// see [`coerce_dictionary_comparison`]
+    pub(crate) dictionary_aware_comparisons: bool,
+}
+
+impl TypeCoercionRewriter {
+    /// Create a rewriter coercing expressions over `schema` with the default
+    /// configuration
+    pub(crate) fn new(schema: DFSchemaRef) -> Self {
+        Self {
+            schema,
+            dictionary_aware_comparisons: true,
+        }
+    }
 }
 
 impl TreeNodeRewriter for TypeCoercionRewriter {
@@ -132,14 +152,22 @@ impl TreeNodeRewriter for TypeCoercionRewriter {
                 subquery,
                 outer_ref_columns,
             }) => {
-                let new_plan = analyze_internal(&self.schema, &subquery)?;
+                let new_plan = analyze_internal(
+                    &self.schema,
+                    &subquery,
+                    self.dictionary_aware_comparisons,
+                )?;
                 Ok(Transformed::yes(Expr::ScalarSubquery(Subquery {
                     subquery: Arc::new(new_plan),
                     outer_ref_columns,
                 })))
             }
             Expr::Exists(Exists { subquery, negated }) => {
-                let new_plan = analyze_internal(&self.schema, &subquery.subquery)?;
+                let new_plan = analyze_internal(
+                    &self.schema,
+                    &subquery.subquery,
+                    self.dictionary_aware_comparisons,
+                )?;
                 Ok(Transformed::yes(Expr::Exists(Exists {
                     subquery: Subquery {
                         subquery: Arc::new(new_plan),
@@ -153,7 +181,11 @@ impl TreeNodeRewriter for TypeCoercionRewriter {
                 subquery,
                 negated,
             }) => {
-                let new_plan = analyze_internal(&self.schema, &subquery.subquery)?;
+                let new_plan = analyze_internal(
+                    &self.schema,
+                    &subquery.subquery,
+                    self.dictionary_aware_comparisons,
+                )?;
                 let expr_type = expr.get_type(&self.schema)?;
                 let subquery_type = new_plan.schema().field(0).data_type();
                 let common_type = comparison_coercion(&expr_type, subquery_type).ok_or(plan_datafusion_err!(
@@ -211,6 +243,12 @@ impl TreeNodeRewriter for TypeCoercionRewriter {
                         "There isn't a common type to coerce {left_type} and {right_type} in {op_name} expression"
                     )
                 })?;
+                let coerced_type = coerce_dictionary_comparison(
+                    &left_type,
+                    &pattern,
+                    coerced_type,
+                    self.dictionary_aware_comparisons,
+                );
                 let expr = Box::new(expr.cast_to(&coerced_type, &self.schema)?);
                 let pattern = Box::new(pattern.cast_to(&coerced_type, &self.schema)?);
                 Ok(Transformed::yes(Expr::Like(Like::new(
@@ -222,11 +260,30 @@ impl TreeNodeRewriter for TypeCoercionRewriter {
                 ))))
             }
             Expr::BinaryExpr(BinaryExpr { left, op, right }) => {
-                let (left_type, right_type) = get_input_types(
-                    &left.get_type(&self.schema)?,
-                    &op,
-                    &right.get_type(&self.schema)?,
-                )?;
+                let left_type = left.get_type(&self.schema)?;
+                let right_type = right.get_type(&self.schema)?;
+                let (left_type, right_type) =
+                    match get_input_types(&left_type, &op, &right_type)? {
+                        (coerced_type, other_type)
+                            if is_dictionary_comparison_operator(&op)
+                                && coerced_type == other_type =>
+                        {
+                            let coerced_type = coerce_dictionary_comparison(
+                                &left_type,
+                                &right,
+                                coerced_type,
+                                self.dictionary_aware_comparisons,
+                            );
+                            let coerced_type = coerce_dictionary_comparison(
+                                &right_type,
+                                &left,
+                                coerced_type,
+                                self.dictionary_aware_comparisons,
+                            );
+                            (coerced_type.clone(), coerced_type)
+                        }
+                        coerced_types => coerced_types,
+                    };
                 Ok(Transformed::yes(Expr::BinaryExpr(BinaryExpr::new(
                     Box::new(left.cast_to(&left_type, &self.schema)?),
                     op,
@@ -439,9 +496,17 @@ impl TreeNodeRewriter for TypeCoercionRewriter {
 fn coerce_scalar(target_type: &DataType, value: &ScalarValue) -> Result<ScalarValue> {
     match value {
         // Coerce Utf8 values:
-        ScalarValue::Utf8(Some(val)) => {
-            ScalarValue::try_from_string(val.clone(), target_type)
-        }
+        ScalarValue::Utf8(Some(val)) => match target_type {
+            // Durations can not be parsed from strings, parse an interval instead
+            DataType::Duration(unit) => {
+                let interval = ScalarValue::try_from_string(
+                    val.clone(),
+                    &DataType::Interval(IntervalUnit::MonthDayNano),
+                )?;
+                interval_to_duration(&interval, unit)
+            }
+            _ => ScalarValue::try_from_string(val.clone(), target_type),
+        },
         s => {
             if s.is_null() {
                 // Coerce `Null` values:
@@ -456,6 +521,35 @@ fn coerce_scalar(target_type: &DataType, value: &ScalarValue) -> Result<ScalarVa
     }
 }
 
+/// Converts a (window frame offset) `interval` to a duration of the given
+/// `unit`, truncating it to whole units. Intervals with months can not be
+/// converted, as the length of a month varies.
+fn interval_to_duration(interval: &ScalarValue, unit: &TimeUnit) -> Result<ScalarValue> {
+    const NANOS_PER_DAY: i64 = 86_400_000_000_000;
+
+    let ScalarValue::IntervalMonthDayNano(Some(value)) = interval else {
+        return plan_err!("Cannot convert {interval:?} to a duration");
+    };
+    let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(*value);
+    if months != 0 {
+        return plan_err!("Cannot convert interval {interval} with months to a duration");
+    }
+    let nanos = (days as i64)
+        .checked_mul(NANOS_PER_DAY)
+        .and_then(|days| days.checked_add(nanos))
+        .ok_or_else(|| {
+            plan_datafusion_err!("Interval {interval} overflows a duration")
+        })?;
+    Ok(match unit {
+        TimeUnit::Second => ScalarValue::DurationSecond(Some(nanos / 1_000_000_000)),
+        TimeUnit::Millisecond => {
+            ScalarValue::DurationMillisecond(Some(nanos / 1_000_000))
+        }
+        TimeUnit::Microsecond => ScalarValue::DurationMicrosecond(Some(nanos / 1_000)),
+        TimeUnit::Nanosecond => ScalarValue::DurationNanosecond(Some(nanos)),
+    })
+}
+
 /// This function coerces `value` to `target_type` in a range-aware fashion.
 /// If the coercion is successful, we return an `Ok` value with the result.
 /// If the coercion fails because `target_type` is not wide enough (i.e. we
@@ -526,28 +620,117 @@ fn coerce_window_frame(
                     || is_utf8_or_large_utf8(col_type)
                     || matches!(col_type, DataType::Null)
                 {
-                    col_type
+                    col_type.clone()
                 } else if is_datetime(col_type) {
-                    &DataType::Interval(IntervalUnit::MonthDayNano)
+                    DataType::Interval(IntervalUnit::MonthDayNano)
+                } else if let DataType::Duration(unit)
+                | DataType::Time32(unit)
+                | DataType::Time64(unit) = col_type
+                {
+                    // Times are shifted by durations of the same unit
+                    DataType::Duration(*unit)
+                } else if !has_frame_offset(&window_frame) {
+                    // Without offsets, any orderable type can be used
+                    return Ok(window_frame);
                 } else {
-                    return internal_err!(
-                        "Cannot run range queries on datatype: {col_type:?}"
+                    return plan_err!(
+                        "RANGE frames with offsets are not supported for ORDER BY type {col_type}"
                     );
                 }
             } else {
                 return internal_err!("ORDER BY column cannot be empty");
             }
         }
-        WindowFrameUnits::Rows | WindowFrameUnits::Groups => &DataType::UInt64,
+        WindowFrameUnits::Rows | WindowFrameUnits::Groups => DataType::UInt64,
     };
     window_frame.start_bound =
-        coerce_frame_bound(target_type, &window_frame.start_bound)?;
-    window_frame.end_bound = coerce_frame_bound(target_type, &window_frame.end_bound)?;
+        coerce_frame_bound(&target_type, &window_frame.start_bound)?;
+    window_frame.end_bound = coerce_frame_bound(&target_type, &window_frame.end_bound)?;
+    if window_frame.units == WindowFrameUnits::Range {
+        check_temporal_frame_offset(&window_frame.start_bound)?;
+        check_temporal_frame_offset(&window_frame.end_bound)?;
+    }
     Ok(window_frame)
 }
 
+/// Checks that a (coerced) interval or duration offset of a RANGE frame
+/// `bound` is not negative, which would move PRECEDING bounds after the
+/// current row and FOLLOWING bounds before it.
+fn check_temporal_frame_offset(bound: &WindowFrameBound) -> Result<()> {
+    let (WindowFrameBound::Preceding(offset) | WindowFrameBound::Following(offset)) =
+        bound
+    else {
+        return Ok(());
+    };
+    let negative = match offset {
+        ScalarValue::IntervalMonthDayNano(Some(value)) => {
+            let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(*value);
+            months < 0 || days < 0 || nanos < 0
+        }
+        ScalarValue::DurationSecond(Some(value))
+        | ScalarValue::DurationMillisecond(Some(value))
+        | ScalarValue::DurationMicrosecond(Some(value))
+        | ScalarValue::DurationNanosecond(Some(value)) => *value < 0,
+        _ => false,
+    };
+    if negative {
+        return plan_err!("RANGE frame offset {offset} must not be negative");
+    }
+    Ok(())
+}
+
+/// Returns true if a bound of `window_frame` is an offset from the current row.
+fn has_frame_offset(window_frame: &WindowFrame) -> bool {
+    [&window_frame.start_bound, &window_frame.end_bound]
+        .into_iter()
+        .any(|bound| bound != &WindowFrameBound::CurrentRow && !bound.is_unbounded())
+}
+
 // Support the `IsTrue` `IsNotTrue` `IsFalse` `IsNotFalse` type coercion.
 // The above op will be rewrite to the binary op when creating the physical op.
+/// Operators evaluated once per distinct dictionary value when comparing a
+/// dictionary encoded expression to a literal
+fn is_dictionary_comparison_operator(op: &Operator) -> bool {
+    matches!(
+        op,
+        Operator::Eq
+            | Operator::NotEq
+            | Operator::Lt
+            | Operator::LtEq
+            | Operator::Gt
+            | Operator::GtEq
+            | Operator::IsDistinctFrom
+            | Operator::IsNotDistinctFrom
+    )
+}
+
+/// Coerces the type of a comparison or `LIKE` match between an expression of
+/// type `expr_type` and the expression `other`, given the `coerced_type` of
+/// both sides according to the default coercion rules.
+///
+/// If `expr_type` is a dictionary and `other` a literal, the dictionary
+/// encoding is kept when `dictionary_aware` is true, so that the comparison is
+/// evaluated once per distinct dictionary value. Otherwise the expression is
+/// decoded to the value type of the dictionary.
+fn coerce_dictionary_comparison(
+    expr_type: &DataType,
+    other: &Expr,
+    coerced_type: DataType,
+    dictionary_aware: bool,
+) -> DataType {
+    let (DataType::Dictionary(_, value_type), Expr::Literal(_)) = (expr_type, other)
+    else {
+        return coerced_type;
+    };
+    if dictionary_aware && coerced_type == **value_type {
+        expr_type.clone()
+    } else if !dictionary_aware && coerced_type == *expr_type {
+        value_type.as_ref().clone()
+    } else {
+        coerced_type
+    }
+}
+
 fn get_casted_expr_for_bool_op(expr: &Expr, schema: &DFSchemaRef) -> Result<Expr> {
     let left_type = expr.get_type(schema)?;
     get_input_types(&left_type, &Operator::IsDistinctFrom, &DataType::Boolean)?;
@@ -761,6 +944,7 @@ mod test {
 
     use arrow::datatypes::{DataType, Field, TimeUnit};
 
+    use datafusion_common::config::ConfigOptions;
     use datafusion_common::tree_node::{TransformedResult, TreeNode};
     use datafusion_common::{DFSchema, DFSchemaRef, Result, ScalarValue};
     use datafusion_expr::expr::{self, InSubquery, Like, ScalarFunction};
@@ -776,6 +960,7 @@ mod test {
     use crate::analyzer::type_coercion::{
         coerce_case_expression, TypeCoercion, TypeCoercionRewriter,
     };
+    use crate::analyzer::AnalyzerRule;
     use crate::test::assert_analyzed_plan_eq;
 
     fn empty() -> Arc<LogicalPlan> {
@@ -1202,6 +1387,37 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn dictionary_comparison_coercion() -> Result<()> {
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let like_expr = Expr::Like(Like::new(
+            false,
+            Box::new(col("a")),
+            Box::new(lit("x%")),
+            None,
+            false,
+        ));
+        let plan = LogicalPlan::Projection(Projection::try_new(
+            vec![col("a").eq(lit("x")), like_expr],
+            empty_with_type(dict_type),
+        )?);
+
+        // By default the column stays dictionary encoded
+        let expected = "Projection: a = CAST(Utf8(\"x\") AS Dictionary(Int32, Utf8)), a LIKE CAST(Utf8(\"x%\") AS Dictionary(Int32, Utf8))\
+            \n  EmptyRelation";
+        assert_analyzed_plan_eq(Arc::new(TypeCoercion::new()), &plan, expected)?;
+
+        // Otherwise it is decoded
+        let mut options = ConfigOptions::default();
+        options.optimizer.enable_dictionary_aware_comparisons = false;
+        let analyzed_plan = TypeCoercion::new().analyze(plan, &options)?;
+        let expected = "Projection: CAST(a AS Utf8) = Utf8(\"x\"), CAST(a AS Utf8) LIKE Utf8(\"x%\")\
+            \n  EmptyRelation";
+        assert_eq!(format!("{analyzed_plan:?}"), expected);
+        Ok(())
+    }
+
     #[test]
     fn unknown_for_type_coercion() -> Result<()> {
         // unknown
@@ -1264,7 +1480,7 @@ mod test {
             vec![Field::new("a", DataType::Int64, true)].into(),
             std::collections::HashMap::new(),
         )?);
-        let mut rewriter = TypeCoercionRewriter { schema };
+        let mut rewriter = TypeCoercionRewriter::new(schema);
         let expr = is_true(lit(12i32).gt(lit(13i64)));
         let expected = is_true(cast(lit(12i32), DataType::Int64).gt(lit(13i64)));
         let result = expr.rewrite(&mut rewriter).data()?;
@@ -1275,7 +1491,7 @@ mod test {
             vec![Field::new("a", DataType::Int64, true)].into(),
             std::collections::HashMap::new(),
         )?);
-        let mut rewriter = TypeCoercionRewriter { schema };
+        let mut rewriter = TypeCoercionRewriter::new(schema);
         let expr = is_true(lit(12i32).eq(lit(13i64)));
         let expected = is_true(cast(lit(12i32), DataType::Int64).eq(lit(13i64)));
         let result = expr.rewrite(&mut rewriter).data()?;
@@ -1286,7 +1502,7 @@ mod test {
             vec![Field::new("a", DataType::Int64, true)].into(),
             std::collections::HashMap::new(),
         )?);
-        let mut rewriter = TypeCoercionRewriter { schema };
+        let mut rewriter = TypeCoercionRewriter::new(schema);
         let expr = is_true(lit(12i32).lt(lit(13i64)));
         let expected = is_true(cast(lit(12i32), DataType::Int64).lt(lit(13i64)));
         let result = expr.rewrite(&mut rewriter).data()?;
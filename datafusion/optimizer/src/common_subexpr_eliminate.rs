@@ -663,7 +663,9 @@ impl TreeNodeVisitor for ExprIdentifierVisitor<'_> {
 
     fn f_down(&mut self, expr: &Expr) -> Result<TreeNodeRecursion> {
         // related to https://github.com/apache/arrow-datafusion/issues/8814
-        // If the expr contain volatile expression or is a short-circuit expression, skip it.
+        // If the expr contain volatile expression or is a short-circuit expression, skip
+        // its children, as they may not be evaluated for every row. A non-volatile
+        // short-circuit expression is still recorded as a whole in `f_up`.
         if expr.short_circuits() || is_volatile_expression(expr)? {
             self.visit_stack
                 .push(VisitRecord::JumpMark(self.node_count));
@@ -719,12 +721,13 @@ impl TreeNodeRewriter for CommonSubexprRewriter<'_> {
     fn f_down(&mut self, expr: Expr) -> Result<Transformed<Expr>> {
         // The `CommonSubexprRewriter` relies on `ExprIdentifierVisitor` to generate
         // the `id_array`, which records the expr's identifier used to rewrite expr. So if we
-        // skip an expr in `ExprIdentifierVisitor`, we should skip it here, too.
-        if expr.short_circuits() || is_volatile_expression(&expr)? {
+        // skip an expr's children in `ExprIdentifierVisitor`, we should skip them here, too.
+        if is_volatile_expression(&expr)? {
             return Ok(Transformed::new(expr, false, TreeNodeRecursion::Jump));
         }
 
         let curr_id = &ExprSet::expr_identifier(&expr);
+        let short_circuits = expr.short_circuits();
 
         // lookup previously visited expression
         match self.expr_set.get(curr_id) {
@@ -742,10 +745,16 @@ impl TreeNodeRewriter for CommonSubexprRewriter<'_> {
                         true,
                         TreeNodeRecursion::Jump,
                     ))
+                } else if short_circuits {
+                    Ok(Transformed::new(expr, false, TreeNodeRecursion::Jump))
                 } else {
                     Ok(Transformed::no(expr))
                 }
             }
+            // a short-circuit expression can only be replaced as a whole
+            None if short_circuits => {
+                Ok(Transformed::new(expr, false, TreeNodeRecursion::Jump))
+            }
             None => Ok(Transformed::no(expr)),
         }
     }
@@ -774,7 +783,7 @@ mod test {
     use datafusion_common::DFSchema;
     use datafusion_expr::logical_plan::{table_scan, JoinType};
     use datafusion_expr::{
-        avg, col, lit, logical_plan::builder::LogicalPlanBuilder, sum,
+        avg, col, lit, logical_plan::builder::LogicalPlanBuilder, sum, when,
     };
     use datafusion_expr::{
         grouping_set, AccumulatorFactoryFunction, AggregateUDF, Signature,
@@ -1035,6 +1044,46 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn short_circuit_subexpr() -> Result<()> {
+        let table_scan = test_table_scan()?;
+
+        let case = || when(col("a").eq(lit(1)), lit(2)).otherwise(lit(3));
+        let plan = LogicalPlanBuilder::from(table_scan)
+            .project(vec![
+                case()?.alias("first"),
+                (case()? + lit(1)).alias("second"),
+            ])?
+            .build()?;
+
+        let expected = "Projection: CASE WHEN test.a = Int32(1) THEN Int32(2) ELSE Int32(3) END AS CASE WHEN test.a = Int32(1) THEN Int32(2) ELSE Int32(3) END AS first, CASE WHEN test.a = Int32(1) THEN Int32(2) ELSE Int32(3) END AS CASE WHEN test.a = Int32(1) THEN Int32(2) ELSE Int32(3) END + Int32(1) AS second\
+        \n  Projection: CASE WHEN test.a = Int32(1) THEN Int32(2) ELSE Int32(3) END AS CASE WHEN test.a = Int32(1) THEN Int32(2) ELSE Int32(3) END, test.a, test.b, test.c\
+        \n    TableScan: test";
+
+        assert_optimized_plan_eq(expected, &plan);
+        Ok(())
+    }
+
+    #[test]
+    fn subexpr_in_short_circuit_branch() -> Result<()> {
+        let table_scan = test_table_scan()?;
+
+        // `Int32(1) / test.a` is not evaluated when `test.a = Int32(0)`, so it
+        // must not be computed ahead of the `CASE`
+        let plan = LogicalPlanBuilder::from(table_scan)
+            .project(vec![
+                when(col("a").eq(lit(0)), lit(0)).otherwise(lit(1) / col("a"))?,
+                lit(1) / col("a"),
+            ])?
+            .build()?;
+
+        let expected = "Projection: CASE WHEN test.a = Int32(0) THEN Int32(0) ELSE Int32(1) / test.a END, Int32(1) / test.a\
+        \n  TableScan: test";
+
+        assert_optimized_plan_eq(expected, &plan);
+        Ok(())
+    }
+
     #[test]
     fn redundant_project_fields() {
         let table_scan = test_table_scan().unwrap();
@@ -0,0 +1,304 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`DecorrelateLateralJoin`] rewrites joins with `LATERAL` subqueries into regular joins
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use crate::decorrelate::PullUpCorrelatedExpr;
+use crate::optimizer::ApplyOrder;
+use crate::utils::replace_qualified_name;
+use crate::{OptimizerConfig, OptimizerRule};
+
+use datafusion_common::tree_node::{TransformedResult, TreeNode};
+use datafusion_common::Result;
+use datafusion_expr::logical_plan::{build_join_schema, Join, JoinConstraint, JoinType};
+use datafusion_expr::utils::conjunction;
+use datafusion_expr::{Distinct, Expr, LogicalPlan, Projection, Subquery};
+
+/// Optimizer rule for rewriting joins whose right side is a correlated
+/// `LATERAL` subquery into regular joins.
+///
+/// The correlated predicates of the subquery are pulled up into the join
+/// filter, for example
+///
+/// ```text
+/// SELECT * FROM t1 CROSS JOIN LATERAL (SELECT t2.b FROM t2 WHERE t2.a = t1.a) x
+/// ```
+///
+/// is rewritten to
+///
+/// ```text
+/// SELECT * FROM t1 INNER JOIN (SELECT t2.b, t2.a FROM t2) x ON x.a = t1.a
+/// ```
+///
+/// Subqueries that can not be decorrelated (for example because they compute
+/// a single aggregate row for every outer row) are left unchanged and are
+/// evaluated row by row by the physical planner instead.
+#[derive(Default)]
+pub struct DecorrelateLateralJoin {}
+
+impl DecorrelateLateralJoin {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl OptimizerRule for DecorrelateLateralJoin {
+    fn try_optimize(
+        &self,
+        plan: &LogicalPlan,
+        _config: &dyn OptimizerConfig,
+    ) -> Result<Option<LogicalPlan>> {
+        let new_join = match plan {
+            LogicalPlan::Join(join)
+                if matches!(join.join_type, JoinType::Inner | JoinType::Left) =>
+            {
+                let LogicalPlan::Subquery(subquery) = join.right.as_ref() else {
+                    return Ok(None);
+                };
+                let Some((right, correlated_filter)) = pull_up_lateral(subquery)? else {
+                    return Ok(None);
+                };
+                let filter =
+                    conjunction(join.filter.clone().into_iter().chain(correlated_filter));
+                let schema = build_join_schema(
+                    join.left.schema(),
+                    right.schema(),
+                    &join.join_type,
+                )?;
+                Join {
+                    left: join.left.clone(),
+                    right: Arc::new(right),
+                    on: join.on.clone(),
+                    filter,
+                    join_type: join.join_type,
+                    join_constraint: join.join_constraint,
+                    schema: Arc::new(schema),
+                    null_equals_null: join.null_equals_null,
+                    strategy_hint: join.strategy_hint,
+                    null_aware: join.null_aware,
+                }
+            }
+            LogicalPlan::CrossJoin(cross_join) => {
+                let LogicalPlan::Subquery(subquery) = cross_join.right.as_ref() else {
+                    return Ok(None);
+                };
+                let Some((right, correlated_filter)) = pull_up_lateral(subquery)? else {
+                    return Ok(None);
+                };
+                let schema = build_join_schema(
+                    cross_join.left.schema(),
+                    right.schema(),
+                    &JoinType::Inner,
+                )?;
+                Join {
+                    left: cross_join.left.clone(),
+                    right: Arc::new(right),
+                    on: vec![],
+                    filter: correlated_filter,
+                    join_type: JoinType::Inner,
+                    join_constraint: JoinConstraint::On,
+                    schema: Arc::new(schema),
+                    null_equals_null: false,
+                    strategy_hint: None,
+                    null_aware: false,
+                }
+            }
+            _ => return Ok(None),
+        };
+
+        // Pulling up the correlated predicates may have added columns to the
+        // output of the subquery, project them away again
+        let new_plan = if new_join.schema.as_ref() == plan.schema().as_ref() {
+            LogicalPlan::Join(new_join)
+        } else {
+            LogicalPlan::Projection(Projection::new_from_schema(
+                Arc::new(LogicalPlan::Join(new_join)),
+                plan.schema().clone(),
+            ))
+        };
+        Ok(Some(new_plan))
+    }
+
+    fn name(&self) -> &str {
+        "decorrelate_lateral_join"
+    }
+
+    fn apply_order(&self) -> Option<ApplyOrder> {
+        Some(ApplyOrder::TopDown)
+    }
+}
+
+/// Pulls the correlated predicates out of a `LATERAL` subquery.
+///
+/// Returns the uncorrelated subquery plan together with the predicates that
+/// have to be evaluated by the join, or `None` if the subquery can not be
+/// decorrelated.
+fn pull_up_lateral(subquery: &Subquery) -> Result<Option<(LogicalPlan, Option<Expr>)>> {
+    let subquery_plan = subquery.subquery.as_ref();
+    // The result of these operators depends on all of their input rows, so
+    // they can not be evaluated once for all outer rows
+    let has_per_row_operator = subquery_plan.exists(|plan| match plan {
+        LogicalPlan::Aggregate(aggregate) => aggregate.group_expr.is_empty(),
+        LogicalPlan::Window(_) | LogicalPlan::Distinct(Distinct::On(_)) => true,
+        _ => false,
+    });
+    if has_per_row_operator {
+        return Ok(None);
+    }
+
+    let mut pull_up = PullUpCorrelatedExpr {
+        join_filters: vec![],
+        correlated_subquery_cols_map: Default::default(),
+        in_predicate_opt: None,
+        exists_sub_query: false,
+        can_pull_up: true,
+        need_handle_count_bug: false,
+        collected_count_expr_map: Default::default(),
+        pull_up_having_expr: None,
+    };
+    let new_plan = subquery_plan.clone().rewrite(&mut pull_up).data()?;
+    if !pull_up.can_pull_up
+        || pull_up.pull_up_having_expr.is_some()
+        || !new_plan.all_out_ref_exprs().is_empty()
+    {
+        return Ok(None);
+    }
+
+    let correlated_filter = match (conjunction(pull_up.join_filters), &new_plan) {
+        // Columns pulled up through the alias of the derived table have to be
+        // referenced by that alias from now on
+        (Some(filter), LogicalPlan::SubqueryAlias(alias)) => {
+            let mut all_correlated_cols = BTreeSet::new();
+            pull_up
+                .correlated_subquery_cols_map
+                .values()
+                .for_each(|cols| all_correlated_cols.extend(cols.clone()));
+            Some(replace_qualified_name(
+                filter,
+                &all_correlated_cols,
+                &alias.alias.to_string(),
+            )?)
+        }
+        (filter, _) => filter,
+    };
+    Ok(Some((new_plan, correlated_filter)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::*;
+
+    use arrow::datatypes::DataType;
+    use datafusion_expr::{col, count, lit, out_ref_col, LogicalPlanBuilder};
+
+    fn lateral(plan: LogicalPlan) -> LogicalPlan {
+        let outer_ref_columns = plan.all_out_ref_exprs();
+        LogicalPlan::Subquery(Subquery {
+            subquery: Arc::new(plan),
+            outer_ref_columns,
+        })
+    }
+
+    #[test]
+    fn cross_join_lateral() -> Result<()> {
+        let subquery = LogicalPlanBuilder::from(scan_tpch_table("orders"))
+            .filter(
+                col("orders.o_custkey")
+                    .eq(out_ref_col(DataType::Int64, "customer.c_custkey")),
+            )?
+            .project(vec![col("orders.o_orderkey")])?
+            .alias("o")?
+            .build()?;
+
+        let plan = LogicalPlanBuilder::from(scan_tpch_table("customer"))
+            .cross_join(lateral(subquery))?
+            .project(vec![col("customer.c_name"), col("o.o_orderkey")])?
+            .build()?;
+
+        let expected = "Projection: customer.c_name, o.o_orderkey [c_name:Utf8, o_orderkey:Int64]\
+        \n  Projection: customer.c_custkey, customer.c_name, o.o_orderkey [c_custkey:Int64, c_name:Utf8, o_orderkey:Int64]\
+        \n    Inner Join:  Filter: o.o_custkey = customer.c_custkey [c_custkey:Int64, c_name:Utf8, o_orderkey:Int64, o_custkey:Int64]\
+        \n      TableScan: customer [c_custkey:Int64, c_name:Utf8]\
+        \n      SubqueryAlias: o [o_orderkey:Int64, o_custkey:Int64]\
+        \n        Projection: orders.o_orderkey, orders.o_custkey [o_orderkey:Int64, o_custkey:Int64]\
+        \n          TableScan: orders [o_orderkey:Int64, o_custkey:Int64, o_orderstatus:Utf8, o_totalprice:Float64;N]";
+        assert_optimized_plan_eq_display_indent(
+            Arc::new(DecorrelateLateralJoin::new()),
+            &plan,
+            expected,
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn left_join_lateral() -> Result<()> {
+        let subquery = LogicalPlanBuilder::from(scan_tpch_table("orders"))
+            .filter(
+                col("orders.o_custkey")
+                    .eq(out_ref_col(DataType::Int64, "customer.c_custkey"))
+                    .and(col("orders.o_totalprice").gt(lit(100.0))),
+            )?
+            .project(vec![col("orders.o_orderkey")])?
+            .alias("o")?
+            .build()?;
+
+        let plan = LogicalPlanBuilder::from(scan_tpch_table("customer"))
+            .join_on(lateral(subquery), JoinType::Left, Some(lit(true)))?
+            .project(vec![col("customer.c_name"), col("o.o_orderkey")])?
+            .build()?;
+
+        let expected = "Projection: customer.c_name, o.o_orderkey [c_name:Utf8, o_orderkey:Int64;N]\
+        \n  Projection: customer.c_custkey, customer.c_name, o.o_orderkey [c_custkey:Int64, c_name:Utf8, o_orderkey:Int64;N]\
+        \n    Left Join:  Filter: Boolean(true) AND o.o_custkey = customer.c_custkey [c_custkey:Int64, c_name:Utf8, o_orderkey:Int64;N, o_custkey:Int64;N]\
+        \n      TableScan: customer [c_custkey:Int64, c_name:Utf8]\
+        \n      SubqueryAlias: o [o_orderkey:Int64, o_custkey:Int64]\
+        \n        Projection: orders.o_orderkey, orders.o_custkey [o_orderkey:Int64, o_custkey:Int64]\
+        \n          Filter: orders.o_totalprice > Float64(100) [o_orderkey:Int64, o_custkey:Int64, o_orderstatus:Utf8, o_totalprice:Float64;N]\
+        \n            TableScan: orders [o_orderkey:Int64, o_custkey:Int64, o_orderstatus:Utf8, o_totalprice:Float64;N]";
+        assert_optimized_plan_eq_display_indent(
+            Arc::new(DecorrelateLateralJoin::new()),
+            &plan,
+            expected,
+        );
+        Ok(())
+    }
+
+    /// A scalar aggregate produces one row for every outer row, even if no
+    /// inner row matches, so it can not be turned into a join
+    #[test]
+    fn lateral_scalar_aggregate_not_decorrelated() -> Result<()> {
+        let subquery = LogicalPlanBuilder::from(scan_tpch_table("orders"))
+            .filter(
+                col("orders.o_custkey")
+                    .eq(out_ref_col(DataType::Int64, "customer.c_custkey")),
+            )?
+            .aggregate(Vec::<Expr>::new(), vec![count(col("orders.o_orderkey"))])?
+            .alias("o")?
+            .build()?;
+
+        let plan = LogicalPlanBuilder::from(scan_tpch_table("customer"))
+            .cross_join(lateral(subquery))?
+            .build()?;
+
+        assert_optimization_skipped(Arc::new(DecorrelateLateralJoin::new()), &plan)
+    }
+}
@@ -31,10 +31,12 @@ use datafusion_common::{plan_err, Result};
 use datafusion_expr::expr::{Exists, InSubquery};
 use datafusion_expr::expr_rewriter::create_col_from_scalar_expr;
 use datafusion_expr::logical_plan::{JoinType, Subquery};
-use datafusion_expr::utils::{conjunction, split_conjunction};
+use datafusion_expr::utils::{
+    can_hash, conjunction, find_valid_equijoin_key_pair, split_conjunction,
+};
 use datafusion_expr::{
-    exists, in_subquery, not_exists, not_in_subquery, BinaryExpr, Expr, Filter,
-    LogicalPlan, LogicalPlanBuilder, Operator,
+    exists, in_subquery, not_exists, not_in_subquery, BinaryExpr, Expr, ExprSchemable,
+    Filter, LogicalPlan, LogicalPlanBuilder, Operator,
 };
 
 use log::debug;
@@ -253,42 +255,71 @@ fn build_join(
                 .map(Option::Some)
         })?;
 
-    if let Some(join_filter) = match (join_filter_opt, in_predicate_opt) {
-        (
-            Some(join_filter),
-            Some(Expr::BinaryExpr(BinaryExpr {
-                left,
-                op: Operator::Eq,
-                right,
-            })),
-        ) => {
+    // the `IN` comparison, on the aliased output column of the subquery
+    let in_keys = match in_predicate_opt {
+        Some(Expr::BinaryExpr(BinaryExpr {
+            left,
+            op: Operator::Eq,
+            right,
+        })) => {
             let right_col = create_col_from_scalar_expr(right.deref(), subquery_alias)?;
-            let in_predicate = Expr::eq(left.deref().clone(), Expr::Column(right_col));
-            Some(in_predicate.and(join_filter))
+            Some((*left, Expr::Column(right_col)))
         }
-        (Some(join_filter), _) => Some(join_filter),
-        (
-            _,
-            Some(Expr::BinaryExpr(BinaryExpr {
-                left,
-                op: Operator::Eq,
-                right,
-            })),
-        ) => {
-            let right_col = create_col_from_scalar_expr(right.deref(), subquery_alias)?;
-            let in_predicate = Expr::eq(left.deref().clone(), Expr::Column(right_col));
+        _ => None,
+    };
+
+    // `NOT IN` is null rather than true when either side of the comparison
+    // is null, which a plain anti join does not honor. If the comparison is
+    // the only join condition and a valid equijoin key, the join is planned
+    // as a null-aware anti join. Otherwise, e.g. for correlated subqueries,
+    // the anti join also matches the rows whose comparison is null.
+    let mut null_aware = false;
+    let in_predicate = match in_keys {
+        Some((left_key, right_key)) => {
+            let mut in_predicate = Expr::eq(left_key.clone(), right_key.clone());
+            let (left_nullable, right_nullable) = if query_info.negated {
+                (
+                    left_key.nullable(left.schema())?,
+                    right_key.nullable(sub_query_alias.schema())?,
+                )
+            } else {
+                (false, false)
+            };
+            if left_nullable || right_nullable {
+                if join_filter_opt.is_none()
+                    && is_equijoin_key(&left_key, &right_key, left, &sub_query_alias)?
+                {
+                    null_aware = true;
+                } else {
+                    if left_nullable {
+                        in_predicate = in_predicate.or(left_key.is_null());
+                    }
+                    if right_nullable {
+                        in_predicate = in_predicate.or(right_key.is_null());
+                    }
+                }
+            }
             Some(in_predicate)
         }
-        _ => None,
-    } {
+        None => None,
+    };
+
+    let join_filter = match (in_predicate, join_filter_opt) {
+        (Some(in_predicate), Some(join_filter)) => Some(in_predicate.and(join_filter)),
+        (in_predicate, join_filter) => in_predicate.or(join_filter),
+    };
+    if let Some(join_filter) = join_filter {
         // join our sub query into the main plan
         let join_type = match query_info.negated {
             true => JoinType::LeftAnti,
             false => JoinType::LeftSemi,
         };
-        let new_plan = LogicalPlanBuilder::from(left.clone())
+        let mut new_plan = LogicalPlanBuilder::from(left.clone())
             .join_on(sub_query_alias, join_type, Some(join_filter))?
             .build()?;
+        if let LogicalPlan::Join(join) = &mut new_plan {
+            join.null_aware = null_aware;
+        }
         debug!(
             "predicate subquery optimized:\n{}",
             new_plan.display_indent()
@@ -299,6 +330,27 @@ fn build_join(
     }
 }
 
+/// Returns true if the join of `left` and `right` on `left_key = right_key`
+/// can be executed as a hash join on these keys
+fn is_equijoin_key(
+    left_key: &Expr,
+    right_key: &Expr,
+    left: &LogicalPlan,
+    right: &LogicalPlan,
+) -> Result<bool> {
+    let key_pair = find_valid_equijoin_key_pair(
+        left_key,
+        right_key,
+        left.schema().clone(),
+        right.schema().clone(),
+    )?;
+    Ok(
+        key_pair.is_some_and(|(l, r)| &l == left_key && &r == right_key)
+            && can_hash(&left_key.get_type(left.schema())?)
+            && can_hash(&right_key.get_type(right.schema())?),
+    )
+}
+
 struct SubqueryInfo {
     query: Subquery,
     where_in_expr: Option<Expr>,
@@ -330,12 +382,12 @@ mod tests {
     use super::*;
     use crate::test::*;
 
-    use arrow::datatypes::DataType;
+    use arrow::datatypes::{DataType, Field, Schema};
     use datafusion_common::Result;
     use datafusion_expr::{
         and, binary_expr, col, exists, in_subquery, lit,
-        logical_plan::LogicalPlanBuilder, not_exists, not_in_subquery, or, out_ref_col,
-        Operator,
+        logical_plan::{table_scan, LogicalPlanBuilder},
+        not_exists, not_in_subquery, or, out_ref_col, Operator,
     };
 
     fn assert_optimized_plan_equal(plan: &LogicalPlan, expected: &str) -> Result<()> {
@@ -1084,6 +1136,101 @@ mod tests {
         Ok(())
     }
 
+    /// Test for NOT IN subquery filter on nullable columns
+    #[test]
+    fn not_in_subquery_nullable() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::UInt32, true),
+            Field::new("b", DataType::UInt32, true),
+        ]);
+        let table_scan = table_scan(Some("test"), &schema, None)?.build()?;
+        let subquery = table_scan(Some("sq"), &schema, None)?
+            .project(vec![col("a")])?
+            .build()?;
+        let plan = LogicalPlanBuilder::from(table_scan)
+            .filter(not_in_subquery(col("a"), Arc::new(subquery)))?
+            .project(vec![col("test.b")])?
+            .build()?;
+
+        let expected = "Projection: test.b [b:UInt32;N]\
+        \n  LeftAnti Join:  Filter: test.a = __correlated_sq_1.a NullAware [a:UInt32;N, b:UInt32;N]\
+        \n    TableScan: test [a:UInt32;N, b:UInt32;N]\
+        \n    SubqueryAlias: __correlated_sq_1 [a:UInt32;N]\
+        \n      Projection: sq.a [a:UInt32;N]\
+        \n        TableScan: sq [a:UInt32;N, b:UInt32;N]";
+
+        assert_optimized_plan_eq_display_indent(
+            Arc::new(DecorrelatePredicateSubquery::new()),
+            &plan,
+            expected,
+        );
+        Ok(())
+    }
+
+    /// Test for NOT IN subquery filter on a nullable column without an
+    /// equijoin key
+    #[test]
+    fn not_in_subquery_nullable_literal() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::UInt32, true),
+            Field::new("b", DataType::UInt32, true),
+        ]);
+        let table_scan = table_scan(Some("test"), &schema, None)?.build()?;
+        let subquery = table_scan(Some("sq"), &schema, None)?
+            .project(vec![col("a")])?
+            .build()?;
+        let plan = LogicalPlanBuilder::from(table_scan)
+            .filter(not_in_subquery(lit(1u32), Arc::new(subquery)))?
+            .project(vec![col("test.b")])?
+            .build()?;
+
+        let expected = "Projection: test.b [b:UInt32;N]\
+        \n  LeftAnti Join:  Filter: UInt32(1) = __correlated_sq_1.a OR __correlated_sq_1.a IS NULL [a:UInt32;N, b:UInt32;N]\
+        \n    TableScan: test [a:UInt32;N, b:UInt32;N]\
+        \n    SubqueryAlias: __correlated_sq_1 [a:UInt32;N]\
+        \n      Projection: sq.a [a:UInt32;N]\
+        \n        TableScan: sq [a:UInt32;N, b:UInt32;N]";
+
+        assert_optimized_plan_eq_display_indent(
+            Arc::new(DecorrelatePredicateSubquery::new()),
+            &plan,
+            expected,
+        );
+        Ok(())
+    }
+
+    /// Test for correlated NOT IN subquery filter on nullable columns
+    #[test]
+    fn not_in_subquery_nullable_correlated() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::UInt32, true),
+            Field::new("b", DataType::UInt32, true),
+        ]);
+        let table_scan = table_scan(Some("test"), &schema, None)?.build()?;
+        let subquery = table_scan(Some("sq"), &schema, None)?
+            .filter(out_ref_col(DataType::UInt32, "test.b").eq(col("sq.b")))?
+            .project(vec![col("a")])?
+            .build()?;
+        let plan = LogicalPlanBuilder::from(table_scan)
+            .filter(not_in_subquery(col("a"), Arc::new(subquery)))?
+            .project(vec![col("test.b")])?
+            .build()?;
+
+        let expected = "Projection: test.b [b:UInt32;N]\
+        \n  LeftAnti Join:  Filter: (test.a = __correlated_sq_1.a OR test.a IS NULL OR __correlated_sq_1.a IS NULL) AND test.b = __correlated_sq_1.b [a:UInt32;N, b:UInt32;N]\
+        \n    TableScan: test [a:UInt32;N, b:UInt32;N]\
+        \n    SubqueryAlias: __correlated_sq_1 [a:UInt32;N, b:UInt32;N]\
+        \n      Projection: sq.a, sq.b [a:UInt32;N, b:UInt32;N]\
+        \n        TableScan: sq [a:UInt32;N, b:UInt32;N]";
+
+        assert_optimized_plan_eq_display_indent(
+            Arc::new(DecorrelatePredicateSubquery::new()),
+            &plan,
+            expected,
+        );
+        Ok(())
+    }
+
     #[test]
     fn in_subquery_both_side_expr() -> Result<()> {
         let table_scan = test_table_scan()?;
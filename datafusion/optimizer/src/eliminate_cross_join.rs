@@ -21,6 +21,7 @@ use std::sync::Arc;
 
 use crate::{utils, OptimizerConfig, OptimizerRule};
 
+use datafusion_common::tree_node::TreeNode;
 use datafusion_common::{plan_err, Result};
 use datafusion_expr::expr::{BinaryExpr, Expr};
 use datafusion_expr::logical_plan::{
@@ -63,6 +64,7 @@ impl OptimizerRule for EliminateCrossJoin {
                 match input {
                     LogicalPlan::Join(Join {
                         join_type: JoinType::Inner,
+                        strategy_hint: None,
                         ..
                     })
                     | LogicalPlan::CrossJoin(_) => {
@@ -86,6 +88,7 @@ impl OptimizerRule for EliminateCrossJoin {
             }
             LogicalPlan::Join(Join {
                 join_type: JoinType::Inner,
+                strategy_hint: None,
                 ..
             }) => {
                 if !try_flatten_join_inputs(
@@ -175,8 +178,14 @@ fn try_flatten_join_inputs(
 
     for child in children.iter() {
         match *child {
+            // A LATERAL subquery has to stay on the right of the relation it refers to
+            _ if child.exists(|plan| matches!(plan, LogicalPlan::Subquery(_))) => {
+                return Ok(false)
+            }
+            // Joins with a strategy hint are kept as they are
             LogicalPlan::Join(Join {
                 join_type: JoinType::Inner,
+                strategy_hint: None,
                 ..
             })
             | LogicalPlan::CrossJoin(_) => {
@@ -233,6 +242,8 @@ fn find_inner_join(
                 filter: None,
                 schema: join_schema,
                 null_equals_null: false,
+                strategy_hint: None,
+                null_aware: false,
             }));
         }
     }
@@ -105,6 +105,8 @@ impl OptimizerRule for EliminateOuterJoin {
                         filter: join.filter.clone(),
                         schema: join.schema.clone(),
                         null_equals_null: join.null_equals_null,
+                        strategy_hint: join.strategy_hint,
+                        null_aware: join.null_aware,
                     });
                     let exprs = plan.expressions();
                     plan.with_new_exprs(exprs, vec![new_join]).map(Some)
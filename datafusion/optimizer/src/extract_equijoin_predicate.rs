@@ -64,6 +64,8 @@ impl OptimizerRule for ExtractEquijoinPredicate {
                 join_constraint,
                 schema,
                 null_equals_null,
+                strategy_hint,
+                null_aware,
             }) => {
                 let left_schema = left.schema();
                 let right_schema = right.schema();
@@ -89,6 +91,8 @@ impl OptimizerRule for ExtractEquijoinPredicate {
                             join_constraint: *join_constraint,
                             schema: schema.clone(),
                             null_equals_null: *null_equals_null,
+                            strategy_hint: *strategy_hint,
+                            null_aware: *null_aware,
                         })
                     });
 
@@ -31,6 +31,7 @@
 pub mod analyzer;
 pub mod common_subexpr_eliminate;
 pub mod decorrelate;
+pub mod decorrelate_lateral_join;
 pub mod decorrelate_predicate_subquery;
 pub mod eliminate_cross_join;
 pub mod eliminate_duplicated_expr;
@@ -42,6 +43,7 @@ pub mod eliminate_one_union;
 pub mod eliminate_outer_join;
 pub mod extract_equijoin_predicate;
 pub mod filter_null_join_keys;
+pub mod normalize_join_keys;
 pub mod optimize_projections;
 pub mod optimizer;
 pub mod propagate_empty_relation;
@@ -51,6 +53,7 @@ pub mod push_down_projection;
 pub mod replace_distinct_aggregate;
 pub mod rewrite_disjunctive_predicate;
 pub mod scalar_subquery_to_join;
+pub mod semi_join_reduction;
 pub mod simplify_expressions;
 pub mod single_distinct_to_groupby;
 pub mod unwrap_cast_in_comparison;
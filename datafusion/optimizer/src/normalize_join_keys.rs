@@ -0,0 +1,316 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`NormalizeJoinKeys`] computes expression join keys in projections below the join
+
+use std::sync::Arc;
+
+use crate::optimizer::ApplyOrder;
+use crate::{OptimizerConfig, OptimizerRule};
+
+use arrow::datatypes::DataType;
+use datafusion_common::{Column, DFSchema, Result};
+use datafusion_expr::expr::Cast;
+use datafusion_expr::logical_plan::builder::wrap_projection_for_join_if_necessary;
+use datafusion_expr::logical_plan::{Join, Projection};
+use datafusion_expr::{cast, Expr, ExprSchemable, LogicalPlan};
+
+/// The NormalizeJoinKeys rule identifies equijoins whose keys are expressions
+/// rather than columns (typically the implicit casts added by type coercion,
+/// e.g. when joining an `Int32` column to an `Int64` column) and computes those
+/// keys once per input in a projection below the join. A projection above the
+/// join removes the temporary key columns again so the output schema is
+/// unchanged.
+///
+/// Dictionary encodings are preserved where possible: when both keys decode
+/// dictionary-encoded columns that share a value type, the keys are cast to a
+/// common dictionary type instead of being unpacked to their values.
+///
+/// ```text
+/// Inner Join: CAST(t1.a AS Int64) = t2.b
+///   TableScan: t1
+///   TableScan: t2
+/// ```
+///
+/// becomes
+///
+/// ```text
+/// Projection: t1.a, t2.b
+///   Inner Join: CAST(t1.a AS Int64) = t2.b
+///     Projection: t1.a, CAST(t1.a AS Int64) AS CAST(t1.a AS Int64)
+///       TableScan: t1
+///     TableScan: t2
+/// ```
+#[derive(Default)]
+pub struct NormalizeJoinKeys {}
+
+impl NormalizeJoinKeys {
+    pub const NAME: &'static str = "normalize_join_keys";
+
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for NormalizeJoinKeys {
+    fn try_optimize(
+        &self,
+        plan: &LogicalPlan,
+        config: &dyn OptimizerConfig,
+    ) -> Result<Option<LogicalPlan>> {
+        if !config.options().optimizer.normalize_join_keys {
+            return Ok(None);
+        }
+
+        let LogicalPlan::Join(join) = plan else {
+            return Ok(None);
+        };
+
+        let has_expr_join_key = join.on.iter().any(|(l, r)| {
+            !(matches!(l, Expr::Column(_)) && matches!(r, Expr::Column(_)))
+        });
+        if !has_expr_join_key {
+            return Ok(None);
+        }
+
+        let left_schema = join.left.schema();
+        let right_schema = join.right.schema();
+        let (left_keys, right_keys): (Vec<_>, Vec<_>) = join
+            .on
+            .iter()
+            .map(|(l, r)| preserve_dictionary_keys(l, r, left_schema, right_schema))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        let (left, left_on, _) = wrap_projection_for_join_if_necessary(
+            &left_keys,
+            join.left.as_ref().clone(),
+        )?;
+        let (right, right_on, _) = wrap_projection_for_join_if_necessary(
+            &right_keys,
+            join.right.as_ref().clone(),
+        )?;
+
+        let new_join = Join::try_new_with_project_input(
+            plan,
+            Arc::new(left),
+            Arc::new(right),
+            (left_on, right_on),
+        )?;
+
+        // Remove the temporary key columns
+        let output_columns = join
+            .schema
+            .iter()
+            .map(|(qualifier, field)| {
+                Expr::Column(Column::from((qualifier, field.as_ref())))
+            })
+            .collect();
+        let projection =
+            Projection::try_new(output_columns, Arc::new(LogicalPlan::Join(new_join)))?;
+        Ok(Some(LogicalPlan::Projection(projection)))
+    }
+
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn apply_order(&self) -> Option<ApplyOrder> {
+        Some(ApplyOrder::BottomUp)
+    }
+}
+
+/// If both keys are casts of dictionary-encoded expressions to their common
+/// value type, cast them to a shared dictionary type instead, picking the
+/// wider of the two index types.
+fn preserve_dictionary_keys(
+    left: &Expr,
+    right: &Expr,
+    left_schema: &DFSchema,
+    right_schema: &DFSchema,
+) -> Result<(Expr, Expr)> {
+    if let (
+        Expr::Cast(Cast {
+            expr: left_expr,
+            data_type: left_type,
+        }),
+        Expr::Cast(Cast {
+            expr: right_expr,
+            data_type: right_type,
+        }),
+    ) = (left, right)
+    {
+        if let (
+            DataType::Dictionary(left_index, left_value),
+            DataType::Dictionary(right_index, right_value),
+        ) = (
+            left_expr.get_type(left_schema)?,
+            right_expr.get_type(right_schema)?,
+        ) {
+            if left_type == right_type
+                && left_value.as_ref() == left_type
+                && right_value.as_ref() == right_type
+            {
+                let index_type =
+                    if left_index.primitive_width() >= right_index.primitive_width() {
+                        left_index
+                    } else {
+                        right_index
+                    };
+                let dictionary = DataType::Dictionary(index_type, left_value);
+                return Ok((
+                    cast_if_needed(left_expr.as_ref(), &dictionary, left_schema)?,
+                    cast_if_needed(right_expr.as_ref(), &dictionary, right_schema)?,
+                ));
+            }
+        }
+    }
+
+    Ok((left.clone(), right.clone()))
+}
+
+fn cast_if_needed(expr: &Expr, data_type: &DataType, schema: &DFSchema) -> Result<Expr> {
+    if &expr.get_type(schema)? == data_type {
+        Ok(expr.clone())
+    } else {
+        Ok(cast(expr.clone(), data_type.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimizer::{assert_schema_is_the_same, Optimizer};
+    use crate::OptimizerContext;
+    use arrow::datatypes::{Field, Schema};
+    use datafusion_expr::logical_plan::table_scan;
+    use datafusion_expr::{col, lit, JoinType, LogicalPlanBuilder};
+
+    fn assert_optimized_plan_equal(plan: &LogicalPlan, expected: &str) -> Result<()> {
+        let rule = Arc::new(NormalizeJoinKeys::new());
+        let optimizer = Optimizer::with_rules(vec![rule.clone()]);
+        let config = OptimizerContext::new().normalize_join_keys(true);
+        let optimized_plan = optimizer
+            .optimize_recursively(optimizer.rules.first().unwrap(), plan, &config)?
+            .unwrap_or_else(|| plan.clone());
+
+        assert_schema_is_the_same(rule.name(), plan, &optimized_plan)?;
+        assert_eq!(format!("{optimized_plan:?}"), expected);
+        Ok(())
+    }
+
+    fn test_table(name: &str, key_type: DataType) -> Result<LogicalPlan> {
+        let schema = Schema::new(vec![
+            Field::new("k", key_type, false),
+            Field::new("v", DataType::UInt32, true),
+        ]);
+        table_scan(Some(name), &schema, None)?.build()
+    }
+
+    fn dictionary(index_type: DataType) -> DataType {
+        DataType::Dictionary(Box::new(index_type), Box::new(DataType::Utf8))
+    }
+
+    #[test]
+    fn cast_key_projected_below_join() -> Result<()> {
+        let t1 = test_table("t1", DataType::Int32)?;
+        let t2 = test_table("t2", DataType::Int64)?;
+        let plan = LogicalPlanBuilder::from(t1)
+            .join_with_expr_keys(
+                t2,
+                JoinType::Inner,
+                (vec![cast(col("t1.k"), DataType::Int64)], vec![col("t2.k")]),
+                None,
+            )?
+            .build()?;
+
+        let expected = "Projection: t1.k, t1.v, t2.k, t2.v\
+        \n  Inner Join: CAST(t1.k AS Int64) = t2.k\
+        \n    Projection: t1.k, t1.v, CAST(t1.k AS Int64) AS CAST(t1.k AS Int64)\
+        \n      TableScan: t1\
+        \n    TableScan: t2";
+        assert_optimized_plan_equal(&plan, expected)
+    }
+
+    #[test]
+    fn expression_keys_on_both_sides() -> Result<()> {
+        let t1 = test_table("t1", DataType::UInt32)?;
+        let t2 = test_table("t2", DataType::UInt32)?;
+        let plan = LogicalPlanBuilder::from(t1)
+            .join_with_expr_keys(
+                t2,
+                JoinType::LeftSemi,
+                (vec![col("t1.k") + lit(1u32)], vec![col("t2.k") * lit(2u32)]),
+                None,
+            )?
+            .build()?;
+
+        let expected = "Projection: t1.k, t1.v\
+        \n  LeftSemi Join: t1.k + UInt32(1) = t2.k * UInt32(2)\
+        \n    Projection: t1.k, t1.v, t1.k + UInt32(1)\
+        \n      TableScan: t1\
+        \n    Projection: t2.k, t2.v, t2.k * UInt32(2)\
+        \n      TableScan: t2";
+        assert_optimized_plan_equal(&plan, expected)
+    }
+
+    #[test]
+    fn column_keys_unchanged() -> Result<()> {
+        let t1 = test_table("t1", DataType::Int64)?;
+        let t2 = test_table("t2", DataType::Int64)?;
+        let plan = LogicalPlanBuilder::from(t1)
+            .join_with_expr_keys(
+                t2,
+                JoinType::Inner,
+                (vec![col("t1.k")], vec![col("t2.k")]),
+                None,
+            )?
+            .build()?;
+
+        let expected = "Inner Join: t1.k = t2.k\
+        \n  TableScan: t1\
+        \n  TableScan: t2";
+        assert_optimized_plan_equal(&plan, expected)
+    }
+
+    #[test]
+    fn dictionary_keys_preserved() -> Result<()> {
+        let t1 = test_table("t1", dictionary(DataType::Int8))?;
+        let t2 = test_table("t2", dictionary(DataType::Int32))?;
+        let plan = LogicalPlanBuilder::from(t1)
+            .join_with_expr_keys(
+                t2,
+                JoinType::Inner,
+                (
+                    vec![cast(col("t1.k"), DataType::Utf8)],
+                    vec![cast(col("t2.k"), DataType::Utf8)],
+                ),
+                None,
+            )?
+            .build()?;
+
+        // only the narrower dictionary is recast, neither side is decoded
+        let expected = "Projection: t1.k, t1.v, t2.k, t2.v\
+        \n  Inner Join: CAST(t1.k AS Dictionary(Int32, Utf8)) = t2.k\
+        \n    Projection: t1.k, t1.v, CAST(t1.k AS Dictionary(Int32, Utf8)) AS CAST(t1.k AS Dictionary(Int32, Utf8))\
+        \n      TableScan: t1\
+        \n    TableScan: t2";
+        assert_optimized_plan_equal(&plan, expected)
+    }
+}
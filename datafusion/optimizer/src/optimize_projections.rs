@@ -339,7 +339,9 @@ fn optimize_projections(
             let left_len = join.left.schema().fields().len();
             let (left_req_indices, right_req_indices) =
                 split_join_requirements(left_len, indices, &join.join_type);
-            let exprs = plan.expressions();
+            let mut exprs = plan.expressions();
+            // A LATERAL subquery needs the columns of the left side it refers to
+            exprs.extend(join.right.all_out_ref_exprs());
             let left_indices =
                 get_all_required_indices(&left_req_indices, &join.left, exprs.iter())?;
             let right_indices =
@@ -352,6 +354,12 @@ fn optimize_projections(
             let left_len = cross_join.left.schema().fields().len();
             let (left_child_indices, right_child_indices) =
                 split_join_requirements(left_len, indices, &JoinType::Inner);
+            let left_child_indices = get_all_required_indices(
+                &left_child_indices,
+                &cross_join.left,
+                // A LATERAL subquery needs the columns of the left side it refers to
+                cross_join.right.all_out_ref_exprs().iter(),
+            )?;
             // Joins benefit from "small" input tables (lower memory usage).
             // Therefore, each child benefits from projection:
             vec![(left_child_indices, true), (right_child_indices, true)]
@@ -21,6 +21,7 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use crate::common_subexpr_eliminate::CommonSubexprEliminate;
+use crate::decorrelate_lateral_join::DecorrelateLateralJoin;
 use crate::decorrelate_predicate_subquery::DecorrelatePredicateSubquery;
 use crate::eliminate_cross_join::EliminateCrossJoin;
 use crate::eliminate_duplicated_expr::EliminateDuplicatedExpr;
@@ -32,6 +33,7 @@ use crate::eliminate_one_union::EliminateOneUnion;
 use crate::eliminate_outer_join::EliminateOuterJoin;
 use crate::extract_equijoin_predicate::ExtractEquijoinPredicate;
 use crate::filter_null_join_keys::FilterNullJoinKeys;
+use crate::normalize_join_keys::NormalizeJoinKeys;
 use crate::optimize_projections::OptimizeProjections;
 use crate::plan_signature::LogicalPlanSignature;
 use crate::propagate_empty_relation::PropagateEmptyRelation;
@@ -40,6 +42,7 @@ use crate::push_down_limit::PushDownLimit;
 use crate::replace_distinct_aggregate::ReplaceDistinctWithAggregate;
 use crate::rewrite_disjunctive_predicate::RewriteDisjunctivePredicate;
 use crate::scalar_subquery_to_join::ScalarSubqueryToJoin;
+use crate::semi_join_reduction::SemiJoinReduction;
 use crate::simplify_expressions::SimplifyExpressions;
 use crate::single_distinct_to_groupby::SingleDistinctToGroupBy;
 use crate::unwrap_cast_in_comparison::UnwrapCastInComparison;
@@ -132,6 +135,18 @@ impl OptimizerContext {
         self
     }
 
+    /// Specify whether to enable the normalize_join_keys rule
+    pub fn normalize_join_keys(mut self, normalize_join_keys: bool) -> Self {
+        self.options.optimizer.normalize_join_keys = normalize_join_keys;
+        self
+    }
+
+    /// Specify whether to enable the semi_join_reduction rule
+    pub fn semi_join_reduction(mut self, semi_join_reduction: bool) -> Self {
+        self.options.optimizer.enable_semi_join_reduction = semi_join_reduction;
+        self
+    }
+
     /// Specify whether the optimizer should skip rules that produce
     /// errors, or fail the query
     pub fn with_query_execution_start_time(
@@ -239,6 +254,7 @@ impl Optimizer {
             Arc::new(EliminateJoin::new()),
             Arc::new(DecorrelatePredicateSubquery::new()),
             Arc::new(ScalarSubqueryToJoin::new()),
+            Arc::new(DecorrelateLateralJoin::new()),
             Arc::new(ExtractEquijoinPredicate::new()),
             // simplify expressions does not simplify expressions in subqueries, so we
             // run it again after running the optimizations that potentially converted
@@ -255,10 +271,16 @@ impl Optimizer {
             Arc::new(EliminateOneUnion::new()),
             Arc::new(FilterNullJoinKeys::default()),
             Arc::new(EliminateOuterJoin::new()),
+            // Must be before PushDownFilter, so that the IN list filters replacing
+            // joins are pushed into the scans
+            Arc::new(SemiJoinReduction::new()),
             // Filters can't be pushed down past Limits, we should do PushDownFilter after PushDownLimit
             Arc::new(PushDownLimit::new()),
             Arc::new(PushDownFilter::new()),
             Arc::new(SingleDistinctToGroupBy::new()),
+            // Must be after PushDownFilter, so that filters are pushed below the
+            // projections computing the join keys
+            Arc::new(NormalizeJoinKeys::new()),
             // The previous optimizations added expressions and projections,
             // that might benefit from the following rules
             Arc::new(SimplifyExpressions::new()),
@@ -28,7 +28,7 @@ use datafusion_common::{
     internal_err, plan_datafusion_err, qualified_name, Column, DFSchema, DFSchemaRef,
     JoinConstraint, Result,
 };
-use datafusion_expr::expr::Alias;
+use datafusion_expr::expr::{Alias, WindowFunction};
 use datafusion_expr::expr_rewriter::replace_col;
 use datafusion_expr::logical_plan::{
     CrossJoin, Join, JoinType, LogicalPlan, TableScan, Union,
@@ -825,6 +825,66 @@ impl OptimizerRule for PushDownFilter {
                     None => new_agg,
                 }
             }
+            LogicalPlan::Window(window) => {
+                // A predicate on columns that all window functions are
+                // partitioned by removes whole partitions, which does not
+                // change the result of the window functions of the others
+                let mut partition_columns: Option<HashSet<Column>> = None;
+                for expr in &window.window_expr {
+                    let expr = match expr {
+                        Expr::Alias(Alias { expr, .. }) => expr.as_ref(),
+                        expr => expr,
+                    };
+                    let columns = match expr {
+                        Expr::WindowFunction(WindowFunction { partition_by, .. }) => {
+                            partition_by
+                                .iter()
+                                .filter_map(|e| match e {
+                                    Expr::Column(c) => Some(c.clone()),
+                                    _ => None,
+                                })
+                                .collect()
+                        }
+                        _ => HashSet::new(),
+                    };
+                    partition_columns = Some(match partition_columns {
+                        Some(p) => p.intersection(&columns).cloned().collect(),
+                        None => columns,
+                    });
+                }
+                let partition_columns = partition_columns.unwrap_or_default();
+
+                let mut keep_predicates = vec![];
+                let mut push_predicates = vec![];
+                for expr in split_conjunction_owned(filter.predicate.clone()) {
+                    let cols = expr.to_columns()?;
+                    if !is_volatile_expression(&expr)?
+                        && cols.iter().all(|c| partition_columns.contains(c))
+                    {
+                        push_predicates.push(expr);
+                    } else {
+                        keep_predicates.push(expr);
+                    }
+                }
+
+                let Some(predicate) = conjunction(push_predicates) else {
+                    return Ok(None);
+                };
+                let child = LogicalPlan::Filter(Filter::try_new(
+                    predicate,
+                    window.input.clone(),
+                )?);
+                let new_window = filter
+                    .input
+                    .with_new_exprs(filter.input.expressions(), vec![child])?;
+                match conjunction(keep_predicates) {
+                    Some(predicate) => LogicalPlan::Filter(Filter::try_new(
+                        predicate,
+                        Arc::new(new_window),
+                    )?),
+                    None => new_window,
+                }
+            }
             LogicalPlan::Join(join) => {
                 match push_down_join(&filter.input, join, Some(&filter.predicate))? {
                     Some(optimized_plan) => optimized_plan,
@@ -966,6 +1026,8 @@ fn convert_cross_join_to_inner_join(cross_join: CrossJoin) -> Result<Join> {
         filter: None,
         schema: DFSchemaRef::new(join_schema),
         null_equals_null: true,
+        strategy_hint: None,
+        null_aware: false,
     })
 }
 
@@ -1043,9 +1105,9 @@ mod tests {
     use datafusion_expr::logical_plan::table_scan;
     use datafusion_expr::{
         and, col, in_list, in_subquery, lit, logical_plan::JoinType, or, sum, BinaryExpr,
-        ColumnarValue, Expr, Extension, LogicalPlanBuilder, Operator, ScalarUDF,
-        ScalarUDFImpl, Signature, TableSource, TableType, UserDefinedLogicalNodeCore,
-        Volatility,
+        BuiltInWindowFunction, ColumnarValue, Expr, Extension, LogicalPlanBuilder,
+        Operator, ScalarUDF, ScalarUDFImpl, Signature, TableSource, TableType,
+        UserDefinedLogicalNodeCore, Volatility, WindowFrame, WindowFunctionDefinition,
     };
 
     use async_trait::async_trait;
@@ -1198,6 +1260,64 @@ mod tests {
         assert_optimized_plan_eq(&plan, expected)
     }
 
+    fn window_partitioned_by(partition_by: Vec<Expr>) -> Expr {
+        Expr::WindowFunction(WindowFunction::new(
+            WindowFunctionDefinition::BuiltInWindowFunction(
+                BuiltInWindowFunction::RowNumber,
+            ),
+            vec![],
+            partition_by,
+            vec![],
+            WindowFrame::new(None),
+            None,
+        ))
+    }
+
+    #[test]
+    fn filter_move_window() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(test_table_scan()?)
+            .window(vec![window_partitioned_by(vec![col("a"), col("b")])])?
+            .filter(and(col("a").gt(lit(10i64)), col("c").gt(lit(10i64))))?
+            .build()?;
+        // filter of a partition key is commutative
+        let expected = "\
+            Filter: test.c > Int64(10)\
+            \n  WindowAggr: windowExpr=[[ROW_NUMBER() PARTITION BY [test.a, test.b] ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING]]\
+            \n    TableScan: test, full_filters=[test.a > Int64(10)]";
+        assert_optimized_plan_eq(&plan, expected)
+    }
+
+    #[test]
+    fn filter_window_common_partition_keys() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(test_table_scan()?)
+            .window(vec![
+                window_partitioned_by(vec![col("a"), col("b")]),
+                window_partitioned_by(vec![col("b")]).alias("r"),
+            ])?
+            .filter(and(col("a").gt(lit(10i64)), col("b").gt(lit(10i64))))?
+            .build()?;
+        // only `b` partitions both window functions
+        let expected = "\
+            Filter: test.a > Int64(10)\
+            \n  WindowAggr: windowExpr=[[ROW_NUMBER() PARTITION BY [test.a, test.b] ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING, ROW_NUMBER() PARTITION BY [test.b] ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING AS r]]\
+            \n    TableScan: test, full_filters=[test.b > Int64(10)]";
+        assert_optimized_plan_eq(&plan, expected)
+    }
+
+    #[test]
+    fn filter_keep_window() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(test_table_scan()?)
+            .window(vec![window_partitioned_by(vec![col("a")]).alias("r")])?
+            .filter(col("r").gt(lit(1u64)))?
+            .build()?;
+        // filter of a window function is non-commutative
+        let expected = "\
+            Filter: r > UInt64(1)\
+            \n  WindowAggr: windowExpr=[[ROW_NUMBER() PARTITION BY [test.a] ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING AS r]]\
+            \n    TableScan: test";
+        assert_optimized_plan_eq(&plan, expected)
+    }
+
     /// verifies that a filter is pushed to before a projection, the filter expression is correctly re-written
     #[test]
     fn alias() -> Result<()> {
@@ -20,6 +20,7 @@
 use std::collections::{BTreeSet, HashMap};
 use std::sync::Arc;
 
+use crate::analyzer::subquery::can_pullup_over_aggregation;
 use crate::decorrelate::{PullUpCorrelatedExpr, UN_MATCHED_ROW_INDICATOR};
 use crate::optimizer::ApplyOrder;
 use crate::utils::replace_qualified_name;
@@ -31,9 +32,11 @@ use datafusion_common::tree_node::{
 };
 use datafusion_common::{plan_err, Column, Result, ScalarValue};
 use datafusion_expr::expr_rewriter::create_col_from_scalar_expr;
-use datafusion_expr::logical_plan::{JoinType, Subquery};
-use datafusion_expr::utils::conjunction;
-use datafusion_expr::{expr, EmptyRelation, Expr, LogicalPlan, LogicalPlanBuilder};
+use datafusion_expr::logical_plan::{JoinType, Limit, Sort, Subquery};
+use datafusion_expr::utils::{conjunction, split_conjunction};
+use datafusion_expr::{
+    expr, AggregateFunction, EmptyRelation, Expr, LogicalPlan, LogicalPlanBuilder,
+};
 
 /// Optimizer rule for rewriting subquery filters to joins
 #[derive(Default)]
@@ -217,6 +220,13 @@ impl TreeNodeRewriter for ExtractScalarSubQuery {
     fn f_down(&mut self, expr: Expr) -> Result<Transformed<Expr>> {
         match expr {
             Expr::ScalarSubquery(subquery) => {
+                let subquery = match limit_one_to_first_value(&subquery.subquery)? {
+                    Some(plan) => Subquery {
+                        subquery: Arc::new(plan),
+                        outer_ref_columns: subquery.outer_ref_columns,
+                    },
+                    None => subquery,
+                };
                 let subqry_alias = self.alias_gen.next("__scalar_sq");
                 self.sub_query_info
                     .push((subquery.clone(), subqry_alias.clone()));
@@ -377,6 +387,88 @@ fn build_join(
     Ok(Some((new_plan, computation_project_expr)))
 }
 
+/// Takes a correlated subquery returning its first row, like:
+///
+/// ```text
+/// select total from orders where orders.c_id = customers.id order by ts limit 1
+/// ```
+///
+/// and rewrites it into an aggregation, which can be pulled up like other
+/// aggregated subqueries:
+///
+/// ```text
+/// select first_value(total order by ts) from orders where orders.c_id = customers.id
+/// ```
+///
+/// Returns `None` if the subquery is not of this form, or if it is correlated
+/// by other predicates than equalities, which can't be pulled up over the
+/// aggregation.
+fn limit_one_to_first_value(plan: &LogicalPlan) -> Result<Option<LogicalPlan>> {
+    let LogicalPlan::Limit(Limit {
+        skip: 0,
+        fetch: Some(1),
+        input,
+    }) = plan
+    else {
+        return Ok(None);
+    };
+    if input.all_out_ref_exprs().is_empty() || !is_correlated_by_equalities(input)? {
+        return Ok(None);
+    }
+
+    let (value, input) = match input.as_ref() {
+        LogicalPlan::Projection(projection) if projection.expr.len() == 1 => (
+            projection.expr[0].clone().unalias(),
+            projection.input.as_ref(),
+        ),
+        input => (
+            Expr::Column(Column::from(input.schema().qualified_field(0))),
+            input,
+        ),
+    };
+    let (order_by, input) = match input {
+        LogicalPlan::Sort(Sort {
+            expr,
+            input,
+            fetch: None,
+        }) => (Some(expr.clone()), input.as_ref()),
+        input => (None, input),
+    };
+
+    let first_value = Expr::AggregateFunction(expr::AggregateFunction::new(
+        AggregateFunction::FirstValue,
+        vec![value],
+        false,
+        None,
+        order_by,
+        None,
+    ));
+    LogicalPlanBuilder::from(input.clone())
+        .aggregate(Vec::<Expr>::new(), vec![first_value])?
+        .build()
+        .map(Some)
+}
+
+/// Returns true if all the correlated predicates of the filters in `plan`
+/// are equalities
+fn is_correlated_by_equalities(plan: &LogicalPlan) -> Result<bool> {
+    let mut equalities = true;
+    plan.apply(&mut |plan| {
+        if let LogicalPlan::Filter(filter) = plan {
+            equalities = split_conjunction(&filter.predicate)
+                .into_iter()
+                .filter(|expr| expr.contains_outer())
+                .all(can_pullup_over_aggregation);
+        }
+        Ok(if equalities {
+            TreeNodeRecursion::Continue
+        } else {
+            TreeNodeRecursion::Stop
+        })
+    })?;
+    Ok(equalities)
+}
+
 #[cfg(test)]
 mod tests {
     use std::ops::Add;
@@ -738,6 +830,79 @@ mod tests {
         Ok(())
     }
 
+    /// Test for correlated scalar subquery returning its first row
+    #[test]
+    fn scalar_subquery_limit_one() -> Result<()> {
+        let sq = Arc::new(
+            LogicalPlanBuilder::from(scan_tpch_table("orders"))
+                .filter(
+                    out_ref_col(DataType::Int64, "customer.c_custkey")
+                        .eq(col("orders.o_custkey")),
+                )?
+                .sort(vec![col("orders.o_orderkey").sort(false, true)])?
+                .project(vec![col("orders.o_totalprice")])?
+                .limit(0, Some(1))?
+                .build()?,
+        );
+
+        let plan = LogicalPlanBuilder::from(scan_tpch_table("customer"))
+            .project(vec![
+                col("customer.c_custkey"),
+                scalar_subquery(sq).alias("last_total"),
+            ])?
+            .build()?;
+
+        let expected = "Projection: customer.c_custkey, __scalar_sq_1.FIRST_VALUE(orders.o_totalprice) ORDER BY [orders.o_orderkey DESC NULLS FIRST] AS last_total [c_custkey:Int64, last_total:Float64;N]\
+        \n  Left Join:  Filter: customer.c_custkey = __scalar_sq_1.o_custkey [c_custkey:Int64, c_name:Utf8, o_custkey:Int64;N, FIRST_VALUE(orders.o_totalprice) ORDER BY [orders.o_orderkey DESC NULLS FIRST]:Float64;N]\
+        \n    TableScan: customer [c_custkey:Int64, c_name:Utf8]\
+        \n    SubqueryAlias: __scalar_sq_1 [o_custkey:Int64, FIRST_VALUE(orders.o_totalprice) ORDER BY [orders.o_orderkey DESC NULLS FIRST]:Float64;N]\
+        \n      Aggregate: groupBy=[[orders.o_custkey]], aggr=[[FIRST_VALUE(orders.o_totalprice) ORDER BY [orders.o_orderkey DESC NULLS FIRST]]] [o_custkey:Int64, FIRST_VALUE(orders.o_totalprice) ORDER BY [orders.o_orderkey DESC NULLS FIRST]:Float64;N]\
+        \n        TableScan: orders [o_orderkey:Int64, o_custkey:Int64, o_orderstatus:Utf8, o_totalprice:Float64;N]";
+
+        assert_multi_rules_optimized_plan_eq_display_indent(
+            vec![Arc::new(ScalarSubqueryToJoin::new())],
+            &plan,
+            expected,
+        );
+        Ok(())
+    }
+
+    /// Test for correlated scalar subquery returning its first row, correlated by
+    /// a non equal predicate which can't be pulled up over an aggregation
+    #[test]
+    fn scalar_subquery_limit_one_where_less_than() -> Result<()> {
+        let sq = Arc::new(
+            LogicalPlanBuilder::from(scan_tpch_table("orders"))
+                .filter(
+                    out_ref_col(DataType::Int64, "customer.c_custkey")
+                        .lt(col("orders.o_custkey")),
+                )?
+                .project(vec![col("orders.o_totalprice")])?
+                .limit(0, Some(1))?
+                .build()?,
+        );
+
+        let plan = LogicalPlanBuilder::from(scan_tpch_table("customer"))
+            .project(vec![col("customer.c_custkey"), scalar_subquery(sq)])?
+            .build()?;
+
+        // the subquery is not rewritten
+        let expected = "Projection: customer.c_custkey, (<subquery>) [c_custkey:Int64, o_totalprice:Float64;N]\
+        \n  Subquery: [o_totalprice:Float64;N]\
+        \n    Limit: skip=0, fetch=1 [o_totalprice:Float64;N]\
+        \n      Projection: orders.o_totalprice [o_totalprice:Float64;N]\
+        \n        Filter: outer_ref(customer.c_custkey) < orders.o_custkey [o_orderkey:Int64, o_custkey:Int64, o_orderstatus:Utf8, o_totalprice:Float64;N]\
+        \n          TableScan: orders [o_orderkey:Int64, o_custkey:Int64, o_orderstatus:Utf8, o_totalprice:Float64;N]\
+        \n  TableScan: customer [c_custkey:Int64, c_name:Utf8]";
+
+        assert_multi_rules_optimized_plan_eq_display_indent(
+            vec![Arc::new(ScalarSubqueryToJoin::new())],
+            &plan,
+            expected,
+        );
+        Ok(())
+    }
+
     /// Test for correlated scalar subquery multiple projected columns
     #[test]
     fn scalar_subquery_multi_col() -> Result<()> {
@@ -0,0 +1,373 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`SemiJoinReduction`] replaces joins with small `VALUES` lists by `IN` list filters
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::optimizer::ApplyOrder;
+use crate::{OptimizerConfig, OptimizerRule};
+
+use datafusion_common::{Column, Result, ScalarValue};
+use datafusion_expr::logical_plan::{Filter, Join, JoinType, Projection, Values};
+use datafusion_expr::{Expr, ExprSchemable, LogicalPlan};
+
+/// The SemiJoinReduction rule replaces an equijoin whose input on one side is
+/// a small `VALUES` list by an `IN` list filter on the other input. The filter
+/// is then pushed down into the scan of that input by [`PushDownFilter`],
+/// removing the join entirely.
+///
+/// ```text
+/// LeftSemi Join: t.a = v.column1
+///   TableScan: t
+///   SubqueryAlias: v
+///     Values: (Int64(1)), (Int64(2))
+/// ```
+///
+/// becomes
+///
+/// ```text
+/// Filter: t.a IN ([Int64(1), Int64(2)])
+///   TableScan: t
+/// ```
+///
+/// Semi and anti joins are rewritten whenever the `VALUES` list is their
+/// filtering input. Inner joins are only rewritten when the `VALUES` list has
+/// a single column of distinct values, so that every row of the other input
+/// matches at most once, and the column is then computed from the join key.
+///
+/// [`PushDownFilter`]: crate::push_down_filter::PushDownFilter
+#[derive(Default)]
+pub struct SemiJoinReduction {}
+
+impl SemiJoinReduction {
+    pub const NAME: &'static str = "semi_join_reduction";
+
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for SemiJoinReduction {
+    fn try_optimize(
+        &self,
+        plan: &LogicalPlan,
+        config: &dyn OptimizerConfig,
+    ) -> Result<Option<LogicalPlan>> {
+        let options = &config.options().optimizer;
+        if !options.enable_semi_join_reduction {
+            return Ok(None);
+        }
+
+        let LogicalPlan::Join(join) = plan else {
+            return Ok(None);
+        };
+        if join.on.len() != 1
+            || join.filter.is_some()
+            || join.null_equals_null
+            || join.null_aware
+            || join.strategy_hint.is_some()
+        {
+            return Ok(None);
+        }
+
+        let max_values = options.semi_join_reduction_max_values;
+        let (left_key, right_key) = &join.on[0];
+        match join.join_type {
+            JoinType::LeftSemi | JoinType::LeftAnti => {
+                match values_list(&join.right, right_key, max_values) {
+                    Some(list) => semi_join_filter(
+                        &join.left,
+                        left_key,
+                        list,
+                        join.join_type == JoinType::LeftAnti,
+                    ),
+                    None => Ok(None),
+                }
+            }
+            JoinType::RightSemi | JoinType::RightAnti => {
+                match values_list(&join.left, left_key, max_values) {
+                    Some(list) => semi_join_filter(
+                        &join.right,
+                        right_key,
+                        list,
+                        join.join_type == JoinType::RightAnti,
+                    ),
+                    None => Ok(None),
+                }
+            }
+            JoinType::Inner => {
+                if let Some(list) = values_list(&join.right, right_key, max_values) {
+                    if let Some(plan) = inner_join_filter(join, list, false)? {
+                        return Ok(Some(plan));
+                    }
+                }
+                match values_list(&join.left, left_key, max_values) {
+                    Some(list) => inner_join_filter(join, list, true),
+                    None => Ok(None),
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn apply_order(&self) -> Option<ApplyOrder> {
+        Some(ApplyOrder::BottomUp)
+    }
+}
+
+/// Returns the literal values of the join key `key` if `plan` is a `VALUES`
+/// list of at most `max_values` rows, possibly renamed by aliases and
+/// projections of plain columns.
+fn values_list(
+    plan: &LogicalPlan,
+    key: &Expr,
+    max_values: usize,
+) -> Option<Vec<ScalarValue>> {
+    let Expr::Column(column) = key else {
+        return None;
+    };
+    let index = plan.schema().index_of_column(column).ok()?;
+    let (values, index) = resolve_values_column(plan, index)?;
+    if values.values.len() > max_values {
+        return None;
+    }
+
+    let data_type = values.schema.field(index).data_type();
+    values
+        .values
+        .iter()
+        .map(|row| match &row[index] {
+            Expr::Literal(value)
+                if value.is_null() || &value.data_type() == data_type =>
+            {
+                Some(value.clone())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Follows the output column at `index` of `plan` down to the `VALUES` list
+/// it is read from.
+fn resolve_values_column(plan: &LogicalPlan, index: usize) -> Option<(&Values, usize)> {
+    match plan {
+        LogicalPlan::Values(values) => Some((values, index)),
+        LogicalPlan::SubqueryAlias(alias) => resolve_values_column(&alias.input, index),
+        LogicalPlan::Projection(projection) => {
+            let column = match &projection.expr[index] {
+                Expr::Column(column) => column,
+                Expr::Alias(alias) => match alias.expr.as_ref() {
+                    Expr::Column(column) => column,
+                    _ => return None,
+                },
+                _ => return None,
+            };
+            let index = projection.input.schema().index_of_column(column).ok()?;
+            resolve_values_column(&projection.input, index)
+        }
+        _ => None,
+    }
+}
+
+/// Replaces a semi or anti join by a filter on `input`.
+fn semi_join_filter(
+    input: &Arc<LogicalPlan>,
+    key: &Expr,
+    list: Vec<ScalarValue>,
+    anti: bool,
+) -> Result<Option<LogicalPlan>> {
+    // Null keys never match, so they neither select nor exclude any row
+    let list: Vec<_> = list
+        .into_iter()
+        .filter(|value| !value.is_null())
+        .map(Expr::Literal)
+        .collect();
+
+    let predicate = match (list.is_empty(), anti) {
+        // An empty semi join is left to `PropagateEmptyRelation`
+        (true, false) => return Ok(None),
+        (true, true) => return Ok(Some(input.as_ref().clone())),
+        (false, false) => key.clone().in_list(list, false),
+        (false, true) => key.clone().is_null().or(key.clone().in_list(list, true)),
+    };
+    let filter = Filter::try_new(predicate, input.clone())?;
+    Ok(Some(LogicalPlan::Filter(filter)))
+}
+
+/// Replaces an inner join with a single column `VALUES` list of distinct
+/// values by a filter on the other input, computing the column of the
+/// `VALUES` list from the join key.
+fn inner_join_filter(
+    join: &Join,
+    list: Vec<ScalarValue>,
+    values_on_left: bool,
+) -> Result<Option<LogicalPlan>> {
+    let (left_key, right_key) = &join.on[0];
+    let (input, key, values_input) = if values_on_left {
+        (&join.right, right_key, &join.left)
+    } else {
+        (&join.left, left_key, &join.right)
+    };
+    if values_input.schema().fields().len() != 1 {
+        return Ok(None);
+    }
+    let (qualifier, values_field) = values_input.schema().qualified_field(0);
+    if &key.get_type(input.schema())? != values_field.data_type() {
+        return Ok(None);
+    }
+
+    let list: Vec<_> = list.into_iter().filter(|value| !value.is_null()).collect();
+    let distinct: HashSet<_> = list.iter().collect();
+    if list.is_empty() || distinct.len() != list.len() {
+        return Ok(None);
+    }
+
+    let list = list.into_iter().map(Expr::Literal).collect();
+    let filter = Filter::try_new(key.clone().in_list(list, false), input.clone())?;
+
+    let values_expr = key
+        .clone()
+        .alias_qualified(qualifier.cloned(), values_field.name());
+    let input_exprs = input.schema().iter().map(|(qualifier, field)| {
+        Expr::Column(Column::from((qualifier, field.as_ref())))
+    });
+    let exprs = if values_on_left {
+        std::iter::once(values_expr).chain(input_exprs).collect()
+    } else {
+        input_exprs.chain(std::iter::once(values_expr)).collect()
+    };
+    let projection = Projection::try_new(exprs, Arc::new(LogicalPlan::Filter(filter)))?;
+    Ok(Some(LogicalPlan::Projection(projection)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimizer::{assert_schema_is_the_same, Optimizer};
+    use crate::test::*;
+    use crate::OptimizerContext;
+    use datafusion_expr::{col, lit, LogicalPlanBuilder};
+
+    fn assert_optimized_plan_equal(plan: &LogicalPlan, expected: &str) -> Result<()> {
+        let rule = Arc::new(SemiJoinReduction::new());
+        let optimizer = Optimizer::with_rules(vec![rule.clone()]);
+        let config = OptimizerContext::new().semi_join_reduction(true);
+        let optimized_plan = optimizer
+            .optimize_recursively(optimizer.rules.first().unwrap(), plan, &config)?
+            .unwrap_or_else(|| plan.clone());
+
+        assert_schema_is_the_same(rule.name(), plan, &optimized_plan)?;
+        assert_eq!(format!("{optimized_plan:?}"), expected);
+        Ok(())
+    }
+
+    fn values(rows: Vec<Expr>) -> Result<LogicalPlan> {
+        LogicalPlanBuilder::values(rows.into_iter().map(|value| vec![value]).collect())?
+            .alias("v")?
+            .build()
+    }
+
+    #[test]
+    fn left_semi_join_to_in_list() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(test_table_scan()?)
+            .join(
+                values(vec![lit(1u32), lit(2u32)])?,
+                JoinType::LeftSemi,
+                (vec!["test.a"], vec!["v.column1"]),
+                None,
+            )?
+            .build()?;
+
+        let expected = "Filter: test.a IN ([UInt32(1), UInt32(2)])\
+        \n  TableScan: test";
+        assert_optimized_plan_equal(&plan, expected)
+    }
+
+    #[test]
+    fn left_anti_join_to_not_in_list() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(test_table_scan()?)
+            .join(
+                values(vec![lit(1u32), lit(ScalarValue::UInt32(None))])?,
+                JoinType::LeftAnti,
+                (vec!["test.a"], vec!["v.column1"]),
+                None,
+            )?
+            .build()?;
+
+        let expected = "Filter: test.a IS NULL OR test.a NOT IN ([UInt32(1)])\
+        \n  TableScan: test";
+        assert_optimized_plan_equal(&plan, expected)
+    }
+
+    #[test]
+    fn inner_join_with_distinct_values() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(values(vec![lit(3u32)])?)
+            .join(
+                test_table_scan()?,
+                JoinType::Inner,
+                (vec!["v.column1"], vec!["test.b"]),
+                None,
+            )?
+            .build()?;
+
+        let expected = "Projection: test.b AS column1, test.a, test.b, test.c\
+        \n  Filter: test.b IN ([UInt32(3)])\
+        \n    TableScan: test";
+        assert_optimized_plan_equal(&plan, expected)
+    }
+
+    #[test]
+    fn inner_join_with_duplicate_values_unchanged() -> Result<()> {
+        let plan = LogicalPlanBuilder::from(test_table_scan()?)
+            .join(
+                values(vec![lit(1u32), lit(1u32)])?,
+                JoinType::Inner,
+                (vec!["test.a"], vec!["v.column1"]),
+                None,
+            )?
+            .build()?;
+
+        let expected = "Inner Join: test.a = v.column1\
+        \n  TableScan: test\
+        \n  SubqueryAlias: v\
+        \n    Values: (UInt32(1)), (UInt32(1))";
+        assert_optimized_plan_equal(&plan, expected)
+    }
+
+    #[test]
+    fn too_many_values_unchanged() -> Result<()> {
+        let rows = (0..200u32).map(lit).collect();
+        let plan = LogicalPlanBuilder::from(test_table_scan()?)
+            .join(
+                values(rows)?,
+                JoinType::LeftSemi,
+                (vec!["test.a"], vec!["v.column1"]),
+                None,
+            )?
+            .project(vec![col("test.a")])?
+            .build()?;
+
+        assert_optimized_plan_equal(&plan, &format!("{plan:?}"))
+    }
+}
@@ -17,7 +17,7 @@
 
 //! Defines physical expressions that can evaluated at runtime during query execution
 
-use super::hyperloglog::HyperLogLog;
+use super::hyperloglog::{HyperLogLog, HLL_HASH_ID, NUM_REGISTERS, SERIALIZED_SIZE};
 use crate::aggregate::utils::down_cast_any_ref;
 use crate::expressions::format_state_name;
 use crate::{AggregateExpr, PhysicalExpr};
@@ -30,7 +30,8 @@ use arrow::datatypes::{
     UInt16Type, UInt32Type, UInt64Type, UInt8Type,
 };
 use datafusion_common::{
-    downcast_value, internal_err, not_impl_err, DataFusionError, Result, ScalarValue,
+    downcast_value, exec_err, internal_err, not_impl_err, DataFusionError, Result,
+    ScalarValue,
 };
 use datafusion_expr::Accumulator;
 use std::any::Any;
@@ -195,9 +196,12 @@ where
     }
 }
 
+/// Serializes the registers, preceded by the id of their hash function
 impl<T: Hash> From<&HyperLogLog<T>> for ScalarValue {
     fn from(v: &HyperLogLog<T>) -> ScalarValue {
-        let values = v.as_ref().to_vec();
+        let mut values = Vec::with_capacity(SERIALIZED_SIZE);
+        values.push(HLL_HASH_ID);
+        values.extend_from_slice(v.as_ref());
         ScalarValue::Binary(Some(values))
     }
 }
@@ -205,7 +209,16 @@ impl<T: Hash> From<&HyperLogLog<T>> for ScalarValue {
 impl<T: Hash> TryFrom<&[u8]> for HyperLogLog<T> {
     type Error = DataFusionError;
     fn try_from(v: &[u8]) -> Result<HyperLogLog<T>> {
-        let arr: [u8; 16384] = v.try_into().map_err(|_| {
+        let Some((&hash_id, registers)) = v.split_first() else {
+            return internal_err!("Impossibly got invalid binary array from states");
+        };
+        // registers computed with another hash function can't be merged
+        if hash_id != HLL_HASH_ID {
+            return exec_err!(
+                "Unsupported HyperLogLog hash function {hash_id}, expected {HLL_HASH_ID}"
+            );
+        }
+        let arr: [u8; NUM_REGISTERS] = registers.try_into().map_err(|_| {
             DataFusionError::Internal(
                 "Impossibly got invalid binary array from states".into(),
             )
@@ -0,0 +1,292 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines physical expressions for APPROX_DISTINCT_SKETCH and
+//! APPROX_DISTINCT_MERGE, which export and merge the HyperLogLog sketches
+//! used by APPROX_DISTINCT
+
+use super::hyperloglog::{HyperLogLog, SERIALIZED_SIZE};
+use crate::aggregate::utils::down_cast_any_ref;
+use crate::expressions::ApproxDistinct;
+use crate::{AggregateExpr, PhysicalExpr};
+use arrow::array::{ArrayRef, BinaryArray};
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::{downcast_value, exec_err, DataFusionError, Result, ScalarValue};
+use datafusion_expr::Accumulator;
+use std::any::Any;
+use std::sync::Arc;
+
+/// APPROX_DISTINCT_SKETCH aggregate expression, which returns the
+/// HyperLogLog sketch of its input as binary. Sketches can be stored and
+/// merged later with [`ApproxDistinctMerge`].
+#[derive(Debug)]
+pub struct ApproxDistinctSketch {
+    approx_distinct: ApproxDistinct,
+}
+
+impl ApproxDistinctSketch {
+    /// Create a new APPROX_DISTINCT_SKETCH aggregate function.
+    pub fn new(
+        expr: Arc<dyn PhysicalExpr>,
+        name: impl Into<String>,
+        input_data_type: DataType,
+    ) -> Self {
+        Self {
+            approx_distinct: ApproxDistinct::new(expr, name, input_data_type),
+        }
+    }
+}
+
+impl AggregateExpr for ApproxDistinctSketch {
+    /// Return a reference to Any that can be used for downcasting
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn field(&self) -> Result<Field> {
+        Ok(Field::new(self.name(), DataType::Binary, false))
+    }
+
+    fn state_fields(&self) -> Result<Vec<Field>> {
+        self.approx_distinct.state_fields()
+    }
+
+    fn expressions(&self) -> Vec<Arc<dyn PhysicalExpr>> {
+        self.approx_distinct.expressions()
+    }
+
+    fn create_accumulator(&self) -> Result<Box<dyn Accumulator>> {
+        Ok(Box::new(HLLSketchAccumulator {
+            inner: self.approx_distinct.create_accumulator()?,
+        }))
+    }
+
+    fn name(&self) -> &str {
+        self.approx_distinct.name()
+    }
+}
+
+impl PartialEq<dyn Any> for ApproxDistinctSketch {
+    fn eq(&self, other: &dyn Any) -> bool {
+        down_cast_any_ref(other)
+            .downcast_ref::<Self>()
+            .map(|x| self.approx_distinct.eq(x.approx_distinct.as_any()))
+            .unwrap_or(false)
+    }
+}
+
+/// Accumulates values like APPROX_DISTINCT, but evaluates to the
+/// HyperLogLog registers, which are also its state.
+#[derive(Debug)]
+struct HLLSketchAccumulator {
+    inner: Box<dyn Accumulator>,
+}
+
+impl Accumulator for HLLSketchAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.inner.update_batch(values)
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        self.inner.merge_batch(states)
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        self.inner.state()
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        Ok(self.inner.state()?.swap_remove(0))
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self) + self.inner.size()
+    }
+}
+
+/// APPROX_DISTINCT_MERGE aggregate expression, which merges binary sketches
+/// produced by [`ApproxDistinctSketch`] and returns the approximate number of
+/// distinct values of their union.
+#[derive(Debug)]
+pub struct ApproxDistinctMerge {
+    approx_distinct: ApproxDistinct,
+}
+
+impl ApproxDistinctMerge {
+    /// Create a new APPROX_DISTINCT_MERGE aggregate function.
+    pub fn new(expr: Arc<dyn PhysicalExpr>, name: impl Into<String>) -> Self {
+        Self {
+            approx_distinct: ApproxDistinct::new(expr, name, DataType::Binary),
+        }
+    }
+}
+
+impl AggregateExpr for ApproxDistinctMerge {
+    /// Return a reference to Any that can be used for downcasting
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn field(&self) -> Result<Field> {
+        self.approx_distinct.field()
+    }
+
+    fn state_fields(&self) -> Result<Vec<Field>> {
+        self.approx_distinct.state_fields()
+    }
+
+    fn expressions(&self) -> Vec<Arc<dyn PhysicalExpr>> {
+        self.approx_distinct.expressions()
+    }
+
+    fn create_accumulator(&self) -> Result<Box<dyn Accumulator>> {
+        Ok(Box::new(HLLMergeAccumulator {
+            hll: HyperLogLog::new(),
+        }))
+    }
+
+    fn name(&self) -> &str {
+        self.approx_distinct.name()
+    }
+}
+
+impl PartialEq<dyn Any> for ApproxDistinctMerge {
+    fn eq(&self, other: &dyn Any) -> bool {
+        down_cast_any_ref(other)
+            .downcast_ref::<Self>()
+            .map(|x| self.approx_distinct.eq(x.approx_distinct.as_any()))
+            .unwrap_or(false)
+    }
+}
+
+/// Merges HyperLogLog sketches. The element type of the merged
+/// [`HyperLogLog`] is irrelevant, as values are only added through registers.
+#[derive(Debug)]
+struct HLLMergeAccumulator {
+    hll: HyperLogLog<Vec<u8>>,
+}
+
+impl HLLMergeAccumulator {
+    fn merge_sketches(&mut self, sketches: &ArrayRef) -> Result<()> {
+        let sketches = downcast_value!(sketches, BinaryArray);
+        // null sketches are skipped like null values
+        for sketch in sketches.iter().flatten() {
+            if sketch.len() != SERIALIZED_SIZE {
+                return exec_err!(
+                    "Invalid approx_distinct sketch of {} bytes",
+                    sketch.len()
+                );
+            }
+            // sketches hashed with another function are rejected
+            let other = HyperLogLog::<Vec<u8>>::try_from(sketch)?;
+            self.hll.merge(&other);
+        }
+        Ok(())
+    }
+}
+
+impl Accumulator for HLLMergeAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.merge_sketches(&values[0])
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        self.merge_sketches(&states[0])
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![ScalarValue::from(&self.hll)])
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        Ok(ScalarValue::UInt64(Some(self.hll.count() as u64)))
+    }
+
+    fn size(&self) -> usize {
+        // HLL has static size
+        std::mem::size_of_val(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::col;
+    use arrow::array::Int64Array;
+    use arrow::datatypes::Schema;
+
+    fn sketch(values: Vec<i64>) -> Result<ScalarValue> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+        let agg =
+            ApproxDistinctSketch::new(col("a", &schema)?, "sketch", DataType::Int64);
+        let mut accumulator = agg.create_accumulator()?;
+        accumulator.update_batch(&[Arc::new(Int64Array::from(values))])?;
+        accumulator.evaluate()
+    }
+
+    #[test]
+    fn merge_sketches() -> Result<()> {
+        let sketches = ScalarValue::iter_to_array([
+            sketch((0..1000).collect())?,
+            sketch((500..1500).collect())?,
+            ScalarValue::Binary(None),
+        ])?;
+
+        let schema = Schema::new(vec![Field::new("s", DataType::Binary, true)]);
+        let agg = ApproxDistinctMerge::new(col("s", &schema)?, "merge");
+        let mut accumulator = agg.create_accumulator()?;
+        accumulator.update_batch(&[sketches])?;
+        let ScalarValue::UInt64(Some(count)) = accumulator.evaluate()? else {
+            unreachable!()
+        };
+        assert!((1450..1550).contains(&count), "unexpected count {count}");
+        Ok(())
+    }
+
+    #[test]
+    fn merge_invalid_sketch() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("s", DataType::Binary, true)]);
+        let agg = ApproxDistinctMerge::new(col("s", &schema)?, "merge");
+        let mut accumulator = agg.create_accumulator()?;
+        let sketches: ArrayRef = Arc::new(BinaryArray::from(vec![&b"abc"[..]]));
+        let err = accumulator.update_batch(&[sketches]).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Invalid approx_distinct sketch of 3 bytes"));
+        Ok(())
+    }
+
+    #[test]
+    fn merge_sketch_of_other_hash() -> Result<()> {
+        let ScalarValue::Binary(Some(mut sketch)) = sketch((0..1000).collect())? else {
+            unreachable!()
+        };
+        // the sketch starts with the id of its hash function
+        assert_eq!(sketch[0], 1);
+        sketch[0] = 0;
+
+        let schema = Schema::new(vec![Field::new("s", DataType::Binary, true)]);
+        let agg = ApproxDistinctMerge::new(col("s", &schema)?, "merge");
+        let mut accumulator = agg.create_accumulator()?;
+        let sketches: ArrayRef = Arc::new(BinaryArray::from(vec![sketch.as_slice()]));
+        let err = accumulator.update_batch(&[sketches]).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unsupported HyperLogLog hash function 0, expected 1"));
+        Ok(())
+    }
+}
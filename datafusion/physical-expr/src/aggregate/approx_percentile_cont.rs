@@ -287,6 +287,10 @@ impl ApproxPercentileAccumulator {
         self.digest = TDigest::merge_digests(digests);
     }
 
+    pub(crate) fn digest(&self) -> &TDigest {
+        &self.digest
+    }
+
     pub(crate) fn convert_to_float(values: &ArrayRef) -> Result<Vec<f64>> {
         match values.data_type() {
             DataType::Float64 => {
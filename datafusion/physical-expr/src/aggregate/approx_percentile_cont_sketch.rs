@@ -0,0 +1,281 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines physical expressions for APPROX_PERCENTILE_CONT_SKETCH and
+//! APPROX_PERCENTILE_CONT_MERGE, which export and merge the t-digest
+//! sketches used by APPROX_PERCENTILE_CONT
+
+use crate::aggregate::approx_percentile_cont::ApproxPercentileAccumulator;
+use crate::aggregate::tdigest::TDigest;
+use crate::aggregate::utils::down_cast_any_ref;
+use crate::expressions::{lit, ApproxPercentileCont};
+use crate::{AggregateExpr, PhysicalExpr};
+use arrow::array::{ArrayRef, BinaryArray};
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::{downcast_value, DataFusionError, Result, ScalarValue};
+use datafusion_expr::Accumulator;
+use std::any::Any;
+use std::iter;
+use std::sync::Arc;
+
+/// APPROX_PERCENTILE_CONT_SKETCH aggregate expression, which returns the
+/// t-digest sketch of its input as binary. Sketches can be stored and merged
+/// later with [`ApproxPercentileContMerge`].
+#[derive(Debug)]
+pub struct ApproxPercentileContSketch {
+    name: String,
+    expr: Arc<dyn PhysicalExpr>,
+    approx_percentile: ApproxPercentileCont,
+}
+
+impl ApproxPercentileContSketch {
+    /// Create a new APPROX_PERCENTILE_CONT_SKETCH aggregate function.
+    pub fn try_new(
+        expr: Arc<dyn PhysicalExpr>,
+        name: impl Into<String>,
+        input_data_type: DataType,
+    ) -> Result<Self> {
+        let name: String = name.into();
+        // the percentile is irrelevant, as the accumulator is never evaluated
+        let approx_percentile = ApproxPercentileCont::new(
+            vec![expr.clone(), lit(0.5_f64)],
+            name.clone(),
+            input_data_type,
+        )?;
+        Ok(Self {
+            name,
+            expr,
+            approx_percentile,
+        })
+    }
+}
+
+impl AggregateExpr for ApproxPercentileContSketch {
+    /// Return a reference to Any that can be used for downcasting
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn field(&self) -> Result<Field> {
+        Ok(Field::new(&self.name, DataType::Binary, false))
+    }
+
+    fn state_fields(&self) -> Result<Vec<Field>> {
+        self.approx_percentile.state_fields()
+    }
+
+    fn expressions(&self) -> Vec<Arc<dyn PhysicalExpr>> {
+        vec![self.expr.clone()]
+    }
+
+    fn create_accumulator(&self) -> Result<Box<dyn Accumulator>> {
+        Ok(Box::new(TDigestSketchAccumulator {
+            inner: self.approx_percentile.create_plain_accumulator()?,
+        }))
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl PartialEq<dyn Any> for ApproxPercentileContSketch {
+    fn eq(&self, other: &dyn Any) -> bool {
+        down_cast_any_ref(other)
+            .downcast_ref::<Self>()
+            .map(|x| {
+                self.name == x.name
+                    && self.expr.eq(&x.expr)
+                    && self.approx_percentile == x.approx_percentile
+            })
+            .unwrap_or(false)
+    }
+}
+
+/// Accumulates values like APPROX_PERCENTILE_CONT, but evaluates to the
+/// binary sketch of its t-digest.
+#[derive(Debug)]
+struct TDigestSketchAccumulator {
+    inner: ApproxPercentileAccumulator,
+}
+
+impl Accumulator for TDigestSketchAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.inner.update_batch(values)
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        self.inner.merge_batch(states)
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        self.inner.state()
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        Ok(ScalarValue::Binary(Some(self.inner.digest().to_bytes())))
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self) + self.inner.size()
+            - std::mem::size_of_val(&self.inner)
+    }
+}
+
+/// APPROX_PERCENTILE_CONT_MERGE aggregate expression, which merges binary
+/// sketches produced by [`ApproxPercentileContSketch`] and returns the
+/// approximate percentile of their union.
+#[derive(Debug)]
+pub struct ApproxPercentileContMerge {
+    approx_percentile: ApproxPercentileCont,
+}
+
+impl ApproxPercentileContMerge {
+    /// Create a new APPROX_PERCENTILE_CONT_MERGE aggregate function.
+    pub fn try_new(
+        expr: Vec<Arc<dyn PhysicalExpr>>,
+        name: impl Into<String>,
+    ) -> Result<Self> {
+        // Arguments should be [SketchExpr, DesiredPercentileLiteral]
+        debug_assert_eq!(expr.len(), 2);
+        Ok(Self {
+            approx_percentile: ApproxPercentileCont::new(expr, name, DataType::Float64)?,
+        })
+    }
+}
+
+impl AggregateExpr for ApproxPercentileContMerge {
+    /// Return a reference to Any that can be used for downcasting
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn field(&self) -> Result<Field> {
+        Ok(Field::new(self.name(), DataType::Float64, true))
+    }
+
+    fn state_fields(&self) -> Result<Vec<Field>> {
+        self.approx_percentile.state_fields()
+    }
+
+    fn expressions(&self) -> Vec<Arc<dyn PhysicalExpr>> {
+        self.approx_percentile.expressions()
+    }
+
+    fn create_accumulator(&self) -> Result<Box<dyn Accumulator>> {
+        Ok(Box::new(TDigestMergeAccumulator {
+            inner: self.approx_percentile.create_plain_accumulator()?,
+        }))
+    }
+
+    fn name(&self) -> &str {
+        self.approx_percentile.name()
+    }
+}
+
+impl PartialEq<dyn Any> for ApproxPercentileContMerge {
+    fn eq(&self, other: &dyn Any) -> bool {
+        down_cast_any_ref(other)
+            .downcast_ref::<Self>()
+            .map(|x| self.approx_percentile == x.approx_percentile)
+            .unwrap_or(false)
+    }
+}
+
+/// Merges binary t-digest sketches into the t-digest of an
+/// APPROX_PERCENTILE_CONT accumulator.
+#[derive(Debug)]
+struct TDigestMergeAccumulator {
+    inner: ApproxPercentileAccumulator,
+}
+
+impl Accumulator for TDigestMergeAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let sketches = downcast_value!(values[0], BinaryArray);
+        // null sketches are skipped like null values
+        let digests = sketches
+            .iter()
+            .flatten()
+            .map(TDigest::try_from_bytes)
+            .chain(iter::once(Ok(self.inner.digest().clone())))
+            .collect::<Result<Vec<_>>>()?;
+        self.inner.merge_digests(&digests);
+        Ok(())
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        self.inner.merge_batch(states)
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        self.inner.state()
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        self.inner.evaluate()
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self) + self.inner.size()
+            - std::mem::size_of_val(&self.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::col;
+    use arrow::array::Int64Array;
+    use arrow::datatypes::Schema;
+
+    fn sketch(values: Vec<i64>) -> Result<ScalarValue> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+        let agg = ApproxPercentileContSketch::try_new(
+            col("a", &schema)?,
+            "sketch",
+            DataType::Int64,
+        )?;
+        let mut accumulator = agg.create_accumulator()?;
+        accumulator.update_batch(&[Arc::new(Int64Array::from(values))])?;
+        accumulator.evaluate()
+    }
+
+    #[test]
+    fn merge_sketches() -> Result<()> {
+        let sketches = ScalarValue::iter_to_array([
+            sketch((1..=500).collect())?,
+            ScalarValue::Binary(None),
+            sketch((501..=1000).collect())?,
+        ])?;
+
+        let schema = Schema::new(vec![Field::new("s", DataType::Binary, true)]);
+        let agg = ApproxPercentileContMerge::try_new(
+            vec![col("s", &schema)?, lit(0.5_f64)],
+            "merge",
+        )?;
+        let mut accumulator = agg.create_accumulator()?;
+        accumulator.update_batch(&[sketches])?;
+        let ScalarValue::Float64(Some(median)) = accumulator.evaluate()? else {
+            unreachable!()
+        };
+        assert!(
+            (495.0..=505.0).contains(&median),
+            "unexpected median {median}"
+        );
+        Ok(())
+    }
+}
@@ -23,13 +23,15 @@ use std::fmt::Debug;
 use std::sync::Arc;
 
 use arrow::array::ArrayRef;
+use arrow::compute::{sort, SortOptions};
 use arrow::datatypes::{DataType, Field};
 use arrow_array::cast::AsArray;
 
 use crate::aggregate::utils::down_cast_any_ref;
 use crate::expressions::format_state_name;
-use crate::{AggregateExpr, PhysicalExpr};
+use crate::{AggregateExpr, LexOrdering, PhysicalExpr, PhysicalSortExpr};
 
+use datafusion_common::utils::array_into_list_array;
 use datafusion_common::{Result, ScalarValue};
 use datafusion_expr::Accumulator;
 
@@ -44,6 +46,9 @@ pub struct DistinctArrayAgg {
     expr: Arc<dyn PhysicalExpr>,
     /// If the input expression can have NULLs
     nullable: bool,
+    /// Ordering of the distinct values, e.g. `ARRAY_AGG(DISTINCT a ORDER BY a)`.
+    /// Empty or a single requirement on the input expression
+    ordering_req: LexOrdering,
 }
 
 impl DistinctArrayAgg {
@@ -60,8 +65,21 @@ impl DistinctArrayAgg {
             input_data_type,
             expr,
             nullable,
+            ordering_req: vec![],
         }
     }
+
+    /// Sort the distinct values by `ordering_req`, which can only order
+    /// the input expression itself
+    pub fn with_ordering_req(mut self, ordering_req: LexOrdering) -> Self {
+        self.ordering_req = ordering_req;
+        self
+    }
+
+    /// Ordering of the distinct values
+    pub fn ordering_req(&self) -> &[PhysicalSortExpr] {
+        &self.ordering_req
+    }
 }
 
 impl AggregateExpr for DistinctArrayAgg {
@@ -82,6 +100,7 @@ impl AggregateExpr for DistinctArrayAgg {
     fn create_accumulator(&self) -> Result<Box<dyn Accumulator>> {
         Ok(Box::new(DistinctArrayAggAccumulator::try_new(
             &self.input_data_type,
+            self.ordering_req.first().map(|sort_expr| sort_expr.options),
         )?))
     }
 
@@ -110,6 +129,7 @@ impl PartialEq<dyn Any> for DistinctArrayAgg {
                 self.name == x.name
                     && self.input_data_type == x.input_data_type
                     && self.expr.eq(&x.expr)
+                    && self.ordering_req == x.ordering_req
             })
             .unwrap_or(false)
     }
@@ -119,20 +139,32 @@ impl PartialEq<dyn Any> for DistinctArrayAgg {
 struct DistinctArrayAggAccumulator {
     values: HashSet<ScalarValue>,
     datatype: DataType,
+    /// How to sort the evaluated values, if at all
+    sort_options: Option<SortOptions>,
 }
 
 impl DistinctArrayAggAccumulator {
-    pub fn try_new(datatype: &DataType) -> Result<Self> {
+    pub fn try_new(
+        datatype: &DataType,
+        sort_options: Option<SortOptions>,
+    ) -> Result<Self> {
         Ok(Self {
             values: HashSet::new(),
             datatype: datatype.clone(),
+            sort_options,
         })
     }
+
+    /// The distinct values as a list, in arbitrary order
+    fn unordered_list(&self) -> ScalarValue {
+        let values: Vec<ScalarValue> = self.values.iter().cloned().collect();
+        ScalarValue::List(ScalarValue::new_list(&values, &self.datatype))
+    }
 }
 
 impl Accumulator for DistinctArrayAggAccumulator {
     fn state(&mut self) -> Result<Vec<ScalarValue>> {
-        Ok(vec![self.evaluate()?])
+        Ok(vec![self.unordered_list()])
     }
 
     fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
@@ -162,9 +194,14 @@ impl Accumulator for DistinctArrayAggAccumulator {
     }
 
     fn evaluate(&mut self) -> Result<ScalarValue> {
-        let values: Vec<ScalarValue> = self.values.iter().cloned().collect();
-        let arr = ScalarValue::new_list(&values, &self.datatype);
-        Ok(ScalarValue::List(arr))
+        match self.sort_options {
+            Some(options) if !self.values.is_empty() => {
+                let values = ScalarValue::iter_to_array(self.values.iter().cloned())?;
+                let sorted = sort(&values, Some(options))?;
+                Ok(ScalarValue::List(Arc::new(array_into_list_array(sorted))))
+            }
+            _ => Ok(self.unordered_list()),
+        }
     }
 
     fn size(&self) -> usize {
@@ -434,4 +471,40 @@ mod tests {
 
         check_merge_distinct_array_agg(input1, input2, expected, DataType::Int32)
     }
+
+    #[test]
+    fn distinct_array_agg_ordered() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+        let expr = col("a", &schema)?;
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let agg = DistinctArrayAgg::new(expr.clone(), "bla", DataType::Int32, true)
+            .with_ordering_req(vec![PhysicalSortExpr { expr, options }]);
+
+        let input1: ArrayRef =
+            Arc::new(Int32Array::from(vec![Some(1), Some(2), None, Some(7)]));
+        let input2: ArrayRef = Arc::new(Int32Array::from(vec![4, 2, 5]));
+        let mut accum1 = agg.create_accumulator()?;
+        let mut accum2 = agg.create_accumulator()?;
+        accum1.update_batch(&[input1])?;
+        accum2.update_batch(&[input2])?;
+        let array = accum2.state()?[0].raw_data()?;
+        accum1.merge_batch(&[array])?;
+
+        let expected =
+            ScalarValue::List(Arc::new(
+                ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![
+                    Some(7),
+                    Some(5),
+                    Some(4),
+                    Some(2),
+                    Some(1),
+                    None,
+                ])]),
+            ));
+        assert_eq!(expected, accum1.evaluate()?);
+        Ok(())
+    }
 }
@@ -277,6 +277,10 @@ impl Accumulator for AvgAccumulator {
     }
 
     fn evaluate(&mut self) -> Result<ScalarValue> {
+        // All values may have been retracted from a sliding window
+        if self.count == 0 {
+            return Ok(ScalarValue::Float64(None));
+        }
         Ok(ScalarValue::Float64(
             self.sum.map(|f| f / self.count as f64),
         ))
@@ -359,6 +363,7 @@ impl<T: DecimalType + ArrowNumericType> Accumulator for DecimalAvgAccumulator<T>
     fn evaluate(&mut self) -> Result<ScalarValue> {
         let v = self
             .sum
+            .filter(|_| self.count != 0)
             .map(|v| {
                 DecimalAverager::<T>::try_new(
                     self.sum_scale,
@@ -570,8 +575,10 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::expressions::col;
     use crate::expressions::tests::assert_aggregate;
     use arrow::array::*;
+    use arrow::datatypes::Schema;
     use datafusion_expr::AggregateFunction;
 
     #[test]
@@ -672,4 +679,31 @@ mod tests {
             Arc::new(Float64Array::from(vec![1_f64, 2_f64, 3_f64, 4_f64, 5_f64]));
         assert_aggregate(a, AggregateFunction::Avg, false, ScalarValue::from(3_f64));
     }
+
+    #[test]
+    fn avg_retract_all_values() -> Result<()> {
+        let mut acc = AvgAccumulator::default();
+        let a: ArrayRef = Arc::new(Float64Array::from(vec![Some(1_f64), None]));
+        acc.update_batch(&[a.clone()])?;
+        assert_eq!(acc.evaluate()?, ScalarValue::from(1_f64));
+        acc.retract_batch(&[a])?;
+        assert_eq!(acc.evaluate()?, ScalarValue::Float64(None));
+
+        let avg = Avg::new(
+            col(
+                "a",
+                &Schema::new(vec![Field::new("a", DataType::Decimal128(10, 0), true)]),
+            )?,
+            "avg",
+            DataType::Decimal128(10, 0),
+        );
+        let mut acc = avg.create_sliding_accumulator()?;
+        let a: ArrayRef = Arc::new(
+            Decimal128Array::from(vec![Some(2), None]).with_precision_and_scale(10, 0)?,
+        );
+        acc.update_batch(&[a.clone()])?;
+        acc.retract_batch(&[a])?;
+        assert_eq!(acc.evaluate()?, ScalarValue::Decimal128(None, 14, 4));
+        Ok(())
+    }
 }
@@ -30,7 +30,7 @@ use std::sync::Arc;
 
 use arrow::datatypes::Schema;
 
-use datafusion_common::{exec_err, not_impl_err, Result};
+use datafusion_common::{exec_err, not_impl_err, plan_err, Result};
 use datafusion_expr::AggregateFunction;
 
 use crate::aggregate::regr::RegrType;
@@ -117,6 +117,16 @@ pub fn create_aggregate_expr(
         (AggregateFunction::ApproxDistinct, _) => Arc::new(
             expressions::ApproxDistinct::new(input_phy_exprs[0].clone(), name, data_type),
         ),
+        (AggregateFunction::ApproxDistinctSketch, _) => {
+            Arc::new(expressions::ApproxDistinctSketch::new(
+                input_phy_exprs[0].clone(),
+                name,
+                data_type,
+            ))
+        }
+        (AggregateFunction::ApproxDistinctMerge, _) => Arc::new(
+            expressions::ApproxDistinctMerge::new(input_phy_exprs[0].clone(), name),
+        ),
         (AggregateFunction::ArrayAgg, false) => {
             let expr = input_phy_exprs[0].clone();
             let nullable = expr.nullable(input_schema)?;
@@ -135,19 +145,23 @@ pub fn create_aggregate_expr(
             }
         }
         (AggregateFunction::ArrayAgg, true) => {
-            if !ordering_req.is_empty() {
-                return not_impl_err!(
-                    "ARRAY_AGG(DISTINCT ORDER BY a ASC) order-sensitive aggregations are not available"
+            let expr = input_phy_exprs[0].clone();
+            // the distinct values can only be ordered by themselves
+            if ordering_req.len() > 1 || ordering_req.iter().any(|e| !e.expr.eq(&expr)) {
+                return plan_err!(
+                    "In an aggregate with DISTINCT, ORDER BY expressions must appear in argument list"
                 );
             }
-            let expr = input_phy_exprs[0].clone();
             let is_expr_nullable = expr.nullable(input_schema)?;
-            Arc::new(expressions::DistinctArrayAgg::new(
-                expr,
-                name,
-                data_type,
-                is_expr_nullable,
-            ))
+            Arc::new(
+                expressions::DistinctArrayAgg::new(
+                    expr,
+                    name,
+                    data_type,
+                    is_expr_nullable,
+                )
+                .with_ordering_req(ordering_req.to_vec()),
+            )
         }
         (AggregateFunction::Min, _) => Arc::new(expressions::Min::new(
             input_phy_exprs[0].clone(),
@@ -164,32 +178,20 @@ pub fn create_aggregate_expr(
             name,
             data_type,
         )),
-        (AggregateFunction::Avg, true) => {
-            return not_impl_err!("AVG(DISTINCT) aggregations are not available");
-        }
         (AggregateFunction::Variance, false) => Arc::new(expressions::Variance::new(
             input_phy_exprs[0].clone(),
             name,
             data_type,
         )),
-        (AggregateFunction::Variance, true) => {
-            return not_impl_err!("VAR(DISTINCT) aggregations are not available");
-        }
         (AggregateFunction::VariancePop, false) => Arc::new(
             expressions::VariancePop::new(input_phy_exprs[0].clone(), name, data_type),
         ),
-        (AggregateFunction::VariancePop, true) => {
-            return not_impl_err!("VAR_POP(DISTINCT) aggregations are not available");
-        }
         (AggregateFunction::Covariance, false) => Arc::new(expressions::Covariance::new(
             input_phy_exprs[0].clone(),
             input_phy_exprs[1].clone(),
             name,
             data_type,
         )),
-        (AggregateFunction::Covariance, true) => {
-            return not_impl_err!("COVAR(DISTINCT) aggregations are not available");
-        }
         (AggregateFunction::CovariancePop, false) => {
             Arc::new(expressions::CovariancePop::new(
                 input_phy_exprs[0].clone(),
@@ -198,25 +200,16 @@ pub fn create_aggregate_expr(
                 data_type,
             ))
         }
-        (AggregateFunction::CovariancePop, true) => {
-            return not_impl_err!("COVAR_POP(DISTINCT) aggregations are not available");
-        }
         (AggregateFunction::Stddev, false) => Arc::new(expressions::Stddev::new(
             input_phy_exprs[0].clone(),
             name,
             data_type,
         )),
-        (AggregateFunction::Stddev, true) => {
-            return not_impl_err!("STDDEV(DISTINCT) aggregations are not available");
-        }
         (AggregateFunction::StddevPop, false) => Arc::new(expressions::StddevPop::new(
             input_phy_exprs[0].clone(),
             name,
             data_type,
         )),
-        (AggregateFunction::StddevPop, true) => {
-            return not_impl_err!("STDDEV_POP(DISTINCT) aggregations are not available");
-        }
         (AggregateFunction::Correlation, false) => {
             Arc::new(expressions::Correlation::new(
                 input_phy_exprs[0].clone(),
@@ -225,9 +218,6 @@ pub fn create_aggregate_expr(
                 data_type,
             ))
         }
-        (AggregateFunction::Correlation, true) => {
-            return not_impl_err!("CORR(DISTINCT) aggregations are not available");
-        }
         (AggregateFunction::RegrSlope, false) => Arc::new(expressions::Regr::new(
             input_phy_exprs[0].clone(),
             input_phy_exprs[1].clone(),
@@ -292,7 +282,16 @@ pub fn create_aggregate_expr(
             data_type,
         )),
         (
-            AggregateFunction::RegrSlope
+            AggregateFunction::Avg
+            | AggregateFunction::Variance
+            | AggregateFunction::VariancePop
+            | AggregateFunction::Covariance
+            | AggregateFunction::CovariancePop
+            | AggregateFunction::Stddev
+            | AggregateFunction::StddevPop
+            | AggregateFunction::Correlation
+            | AggregateFunction::Median
+            | AggregateFunction::RegrSlope
             | AggregateFunction::RegrIntercept
             | AggregateFunction::RegrCount
             | AggregateFunction::RegrR2
@@ -303,7 +302,20 @@ pub fn create_aggregate_expr(
             | AggregateFunction::RegrSXY,
             true,
         ) => {
-            return not_impl_err!("{}(DISTINCT) aggregations are not available", fun);
+            // aggregate the distinct rows of the arguments
+            let aggregate = create_aggregate_expr(
+                fun,
+                false,
+                &input_phy_exprs,
+                ordering_req,
+                input_schema,
+                name,
+                ignore_nulls,
+            )?;
+            Arc::new(expressions::DistinctAggregate::new(
+                aggregate,
+                input_phy_types,
+            ))
         }
         (AggregateFunction::ApproxPercentileCont, false) => {
             if input_phy_exprs.len() == 2 {
@@ -340,6 +352,23 @@ pub fn create_aggregate_expr(
                 "approx_percentile_cont_with_weight(DISTINCT) aggregations are not available"
             );
         }
+        (AggregateFunction::ApproxPercentileContSketch, false) => {
+            Arc::new(expressions::ApproxPercentileContSketch::try_new(
+                input_phy_exprs[0].clone(),
+                name,
+                data_type,
+            )?)
+        }
+        (AggregateFunction::ApproxPercentileContMerge, false) => Arc::new(
+            expressions::ApproxPercentileContMerge::try_new(input_phy_exprs, name)?,
+        ),
+        (
+            AggregateFunction::ApproxPercentileContSketch
+            | AggregateFunction::ApproxPercentileContMerge,
+            true,
+        ) => {
+            return not_impl_err!("{}(DISTINCT) aggregations are not available", fun);
+        }
         (AggregateFunction::ApproxMedian, false) => {
             Arc::new(expressions::ApproxMedian::try_new(
                 input_phy_exprs[0].clone(),
@@ -357,9 +386,6 @@ pub fn create_aggregate_expr(
             name,
             data_type,
         )),
-        (AggregateFunction::Median, true) => {
-            return not_impl_err!("MEDIAN(DISTINCT) aggregations are not available");
-        }
         (AggregateFunction::FirstValue, _) => Arc::new(
             expressions::FirstValue::new(
                 input_phy_exprs[0].clone(),
@@ -402,17 +428,23 @@ pub fn create_aggregate_expr(
             ))
         }
         (AggregateFunction::StringAgg, false) => {
-            if !ordering_req.is_empty() {
-                return not_impl_err!(
-                    "STRING_AGG(ORDER BY a ASC) order-sensitive aggregations are not available"
-                );
+            if ordering_req.is_empty() {
+                Arc::new(expressions::StringAgg::new(
+                    input_phy_exprs[0].clone(),
+                    input_phy_exprs[1].clone(),
+                    name,
+                    data_type,
+                ))
+            } else {
+                Arc::new(expressions::OrderSensitiveStringAgg::new(
+                    input_phy_exprs[0].clone(),
+                    input_phy_exprs[1].clone(),
+                    name,
+                    data_type,
+                    ordering_types,
+                    ordering_req.to_vec(),
+                ))
             }
-            Arc::new(expressions::StringAgg::new(
-                input_phy_exprs[0].clone(),
-                input_phy_exprs[1].clone(),
-                name,
-                data_type,
-            ))
         }
         (AggregateFunction::StringAgg, true) => {
             return not_impl_err!("STRING_AGG(DISTINCT) aggregations are not available");
@@ -235,6 +235,10 @@ impl Accumulator for CorrelationAccumulator {
         Ok(ScalarValue::Float64(None))
     }
 
+    fn supports_retract_batch(&self) -> bool {
+        true
+    }
+
     fn size(&self) -> usize {
         std::mem::size_of_val(self) - std::mem::size_of_val(&self.covar)
             + self.covar.size()
@@ -21,7 +21,8 @@ use std::any::Any;
 use std::sync::Arc;
 
 use crate::{AggregateExpr, PhysicalExpr};
-use arrow::array::Float64Array;
+use arrow::array::{AsArray, BooleanArray, Float64Array};
+use arrow::datatypes::{Float64Type, UInt64Type};
 use arrow::{
     array::{ArrayRef, UInt64Array},
     compute::cast,
@@ -30,10 +31,10 @@ use arrow::{
 };
 use datafusion_common::{downcast_value, unwrap_or_internal_err, ScalarValue};
 use datafusion_common::{DataFusionError, Result};
-use datafusion_expr::Accumulator;
+use datafusion_expr::{Accumulator, EmitTo, GroupsAccumulator};
 
 use crate::aggregate::stats::StatsType;
-use crate::aggregate::utils::down_cast_any_ref;
+use crate::aggregate::utils::{down_cast_any_ref, for_each_valid_row};
 use crate::expressions::format_state_name;
 
 /// COVAR and COVAR_SAMP aggregate expression
@@ -84,6 +85,16 @@ impl AggregateExpr for Covariance {
         Ok(Box::new(CovarianceAccumulator::try_new(StatsType::Sample)?))
     }
 
+    fn groups_accumulator_supported(&self) -> bool {
+        true
+    }
+
+    fn create_groups_accumulator(&self) -> Result<Box<dyn GroupsAccumulator>> {
+        Ok(Box::new(CovarianceGroupsAccumulator::new(
+            StatsType::Sample,
+        )))
+    }
+
     fn state_fields(&self) -> Result<Vec<Field>> {
         Ok(vec![
             Field::new(
@@ -163,6 +174,16 @@ impl AggregateExpr for CovariancePop {
         )?))
     }
 
+    fn groups_accumulator_supported(&self) -> bool {
+        true
+    }
+
+    fn create_groups_accumulator(&self) -> Result<Box<dyn GroupsAccumulator>> {
+        Ok(Box::new(CovarianceGroupsAccumulator::new(
+            StatsType::Population,
+        )))
+    }
+
     fn state_fields(&self) -> Result<Vec<Field>> {
         Ok(vec![
             Field::new(
@@ -294,17 +315,11 @@ impl Accumulator for CovarianceAccumulator {
 
             let value1 = unwrap_or_internal_err!(value1);
             let value2 = unwrap_or_internal_err!(value2);
-            let new_count = self.count + 1;
-            let delta1 = value1 - self.mean1;
-            let new_mean1 = delta1 / new_count as f64 + self.mean1;
-            let delta2 = value2 - self.mean2;
-            let new_mean2 = delta2 / new_count as f64 + self.mean2;
-            let new_c = delta1 * (value2 - new_mean2) + self.algo_const;
-
-            self.count += 1;
-            self.mean1 = new_mean1;
-            self.mean2 = new_mean2;
-            self.algo_const = new_c;
+            (self.count, self.mean1, self.mean2, self.algo_const) = update(
+                (self.count, self.mean1, self.mean2, self.algo_const),
+                value1,
+                value2,
+            );
         }
 
         Ok(())
@@ -336,6 +351,14 @@ impl Accumulator for CovarianceAccumulator {
             let value2 = unwrap_or_internal_err!(value2);
 
             let new_count = self.count - 1;
+            if new_count == 0 {
+                // The window is empty, avoid dividing by zero
+                self.count = 0;
+                self.mean1 = 0.0;
+                self.mean2 = 0.0;
+                self.algo_const = 0.0;
+                continue;
+            }
             let delta1 = self.mean1 - value1;
             let new_mean1 = delta1 / new_count as f64 + self.mean1;
             let delta2 = self.mean2 - value2;
@@ -358,25 +381,15 @@ impl Accumulator for CovarianceAccumulator {
         let cs = downcast_value!(states[3], Float64Array);
 
         for i in 0..counts.len() {
-            let c = counts.value(i);
-            if c == 0_u64 {
-                continue;
-            }
-            let new_count = self.count + c;
-            let new_mean1 = self.mean1 * self.count as f64 / new_count as f64
-                + means1.value(i) * c as f64 / new_count as f64;
-            let new_mean2 = self.mean2 * self.count as f64 / new_count as f64
-                + means2.value(i) * c as f64 / new_count as f64;
-            let delta1 = self.mean1 - means1.value(i);
-            let delta2 = self.mean2 - means2.value(i);
-            let new_c = self.algo_const
-                + cs.value(i)
-                + delta1 * delta2 * self.count as f64 * c as f64 / new_count as f64;
-
-            self.count = new_count;
-            self.mean1 = new_mean1;
-            self.mean2 = new_mean2;
-            self.algo_const = new_c;
+            (self.count, self.mean1, self.mean2, self.algo_const) = merge(
+                (self.count, self.mean1, self.mean2, self.algo_const),
+                (
+                    counts.value(i),
+                    means1.value(i),
+                    means2.value(i),
+                    cs.value(i),
+                ),
+            );
         }
         Ok(())
     }
@@ -400,11 +413,205 @@ impl Accumulator for CovarianceAccumulator {
         }
     }
 
+    fn supports_retract_batch(&self) -> bool {
+        true
+    }
+
     fn size(&self) -> usize {
         std::mem::size_of_val(self)
     }
 }
 
+/// The count, the means of both expressions and the co-moment of values
+type CovarianceState = (u64, f64, f64, f64);
+
+/// Returns the state after adding the pair of values `value1` and `value2`
+#[inline]
+fn update(state: CovarianceState, value1: f64, value2: f64) -> CovarianceState {
+    let (count, mean1, mean2, algo_const) = state;
+    let new_count = count + 1;
+    let delta1 = value1 - mean1;
+    let new_mean1 = delta1 / new_count as f64 + mean1;
+    let delta2 = value2 - mean2;
+    let new_mean2 = delta2 / new_count as f64 + mean2;
+    let new_c = delta1 * (value2 - new_mean2) + algo_const;
+    (new_count, new_mean1, new_mean2, new_c)
+}
+
+/// Returns the state after merging the partial state of another accumulator
+#[inline]
+fn merge(state: CovarianceState, partial: CovarianceState) -> CovarianceState {
+    let (count, mean1, mean2, algo_const) = state;
+    let (partial_count, partial_mean1, partial_mean2, partial_c) = partial;
+    if partial_count == 0 {
+        return state;
+    }
+    let new_count = count + partial_count;
+    let new_mean1 = mean1 * count as f64 / new_count as f64
+        + partial_mean1 * partial_count as f64 / new_count as f64;
+    let new_mean2 = mean2 * count as f64 / new_count as f64
+        + partial_mean2 * partial_count as f64 / new_count as f64;
+    let delta1 = mean1 - partial_mean1;
+    let delta2 = mean2 - partial_mean2;
+    let new_c = algo_const
+        + partial_c
+        + delta1 * delta2 * count as f64 * partial_count as f64 / new_count as f64;
+    (new_count, new_mean1, new_mean2, new_c)
+}
+
+/// A [`GroupsAccumulator`] for the covariance of every group, which updates
+/// the count, means and co-moment of all groups of a batch in a single call,
+/// using the same algorithm as [`CovarianceAccumulator`]
+#[derive(Debug)]
+pub struct CovarianceGroupsAccumulator {
+    algo_consts: Vec<f64>,
+    means1: Vec<f64>,
+    means2: Vec<f64>,
+    counts: Vec<u64>,
+    stats_type: StatsType,
+}
+
+impl CovarianceGroupsAccumulator {
+    /// Creates a new `CovarianceGroupsAccumulator`
+    pub fn new(stats_type: StatsType) -> Self {
+        Self {
+            algo_consts: vec![],
+            means1: vec![],
+            means2: vec![],
+            counts: vec![],
+            stats_type,
+        }
+    }
+
+    fn resize(&mut self, total_num_groups: usize) {
+        self.algo_consts.resize(total_num_groups, 0.0);
+        self.means1.resize(total_num_groups, 0.0);
+        self.means2.resize(total_num_groups, 0.0);
+        self.counts.resize(total_num_groups, 0);
+    }
+
+    /// Returns the state of group `group_index`
+    fn get(&self, group_index: usize) -> CovarianceState {
+        (
+            self.counts[group_index],
+            self.means1[group_index],
+            self.means2[group_index],
+            self.algo_consts[group_index],
+        )
+    }
+
+    /// Sets the state of group `group_index`
+    fn set(&mut self, group_index: usize, state: CovarianceState) {
+        (
+            self.counts[group_index],
+            self.means1[group_index],
+            self.means2[group_index],
+            self.algo_consts[group_index],
+        ) = state;
+    }
+}
+
+impl GroupsAccumulator for CovarianceGroupsAccumulator {
+    fn update_batch(
+        &mut self,
+        values: &[ArrayRef],
+        group_indices: &[usize],
+        opt_filter: Option<&BooleanArray>,
+        total_num_groups: usize,
+    ) -> Result<()> {
+        assert_eq!(values.len(), 2, "two arguments to update_batch");
+        let values1 = cast(&values[0], &DataType::Float64)?;
+        let values1 = values1.as_primitive::<Float64Type>();
+        let values2 = cast(&values[1], &DataType::Float64)?;
+        let values2 = values2.as_primitive::<Float64Type>();
+
+        self.resize(total_num_groups);
+        for_each_valid_row(
+            &[values1, values2],
+            group_indices,
+            opt_filter,
+            |group_index, row| {
+                let state = update(
+                    self.get(group_index),
+                    values1.value(row),
+                    values2.value(row),
+                );
+                self.set(group_index, state);
+            },
+        );
+        Ok(())
+    }
+
+    fn merge_batch(
+        &mut self,
+        values: &[ArrayRef],
+        group_indices: &[usize],
+        opt_filter: Option<&BooleanArray>,
+        total_num_groups: usize,
+    ) -> Result<()> {
+        assert_eq!(values.len(), 4, "four arguments to merge_batch");
+        let counts = values[0].as_primitive::<UInt64Type>();
+        let means1 = values[1].as_primitive::<Float64Type>();
+        let means2 = values[2].as_primitive::<Float64Type>();
+        let cs = values[3].as_primitive::<Float64Type>();
+
+        self.resize(total_num_groups);
+        for_each_valid_row(&[counts], group_indices, opt_filter, |group_index, row| {
+            let state = merge(
+                self.get(group_index),
+                (
+                    counts.value(row),
+                    means1.value(row),
+                    means2.value(row),
+                    cs.value(row),
+                ),
+            );
+            self.set(group_index, state);
+        });
+        Ok(())
+    }
+
+    fn evaluate(&mut self, emit_to: EmitTo) -> Result<ArrayRef> {
+        let counts = emit_to.take_needed(&mut self.counts);
+        let algo_consts = emit_to.take_needed(&mut self.algo_consts);
+        let _ = emit_to.take_needed(&mut self.means1);
+        let _ = emit_to.take_needed(&mut self.means2);
+
+        let array = counts
+            .into_iter()
+            .zip(algo_consts)
+            .map(|(count, algo_const)| {
+                let count = match self.stats_type {
+                    StatsType::Population => count,
+                    StatsType::Sample => count.saturating_sub(1),
+                };
+                (count > 0).then(|| algo_const / count as f64)
+            })
+            .collect::<Float64Array>();
+        Ok(Arc::new(array))
+    }
+
+    fn state(&mut self, emit_to: EmitTo) -> Result<Vec<ArrayRef>> {
+        let counts = emit_to.take_needed(&mut self.counts);
+        let means1 = emit_to.take_needed(&mut self.means1);
+        let means2 = emit_to.take_needed(&mut self.means2);
+        let algo_consts = emit_to.take_needed(&mut self.algo_consts);
+
+        Ok(vec![
+            Arc::new(UInt64Array::from(counts)),
+            Arc::new(Float64Array::from(means1)),
+            Arc::new(Float64Array::from(means2)),
+            Arc::new(Float64Array::from(algo_consts)),
+        ])
+    }
+
+    fn size(&self) -> usize {
+        (self.algo_consts.capacity() + self.means1.capacity() + self.means2.capacity())
+            * std::mem::size_of::<f64>()
+            + self.counts.capacity() * std::mem::size_of::<u64>()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -772,4 +979,48 @@ mod tests {
         accum1.merge_batch(&state2)?;
         accum1.evaluate()
     }
+
+    #[test]
+    fn covariance_groups_accumulator() -> Result<()> {
+        let values1: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(1),
+            Some(2),
+            Some(3),
+            None,
+            Some(5),
+            Some(6),
+        ]));
+        let values2: ArrayRef = Arc::new(Float64Array::from(vec![
+            Some(2.0),
+            Some(4.0),
+            Some(7.0),
+            Some(1.0),
+            Some(1.0),
+            Some(3.0),
+        ]));
+        let values = [values1, values2];
+        let group_indices = [0, 0, 0, 1, 1, 1];
+
+        // every group compared to the covariance of the same rows
+        let mut accum = CovarianceGroupsAccumulator::new(StatsType::Sample);
+        accum.update_batch(&values, &group_indices, None, 2)?;
+        let evaluated = accum.evaluate(EmitTo::All)?;
+        let expected: ArrayRef = Arc::new(Float64Array::from(vec![Some(2.5), Some(1.0)]));
+        assert_eq!(&evaluated, &expected);
+
+        // the filter leaves a single row in the second group, into which
+        // the emitted state of the first group is merged, as well as into a
+        // new group
+        let filter = BooleanArray::from(vec![true, true, true, true, true, false]);
+        let mut accum = CovarianceGroupsAccumulator::new(StatsType::Population);
+        accum.update_batch(&values, &group_indices, Some(&filter), 2)?;
+        let state = accum.state(EmitTo::First(1))?;
+        accum.merge_batch(&state, &[1], None, 2)?;
+        accum.merge_batch(&state, &[0], None, 2)?;
+        let evaluated = accum.evaluate(EmitTo::All)?;
+        let evaluated = evaluated.as_primitive::<Float64Type>();
+        assert!((evaluated.value(0) + 0.625).abs() < 1e-12);
+        assert!((evaluated.value(1) - 5.0 / 3.0).abs() < 1e-12);
+        Ok(())
+    }
 }
@@ -0,0 +1,315 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`DistinctAggregate`] evaluating aggregate functions over the distinct
+//! values of their arguments
+
+use std::any::Any;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use arrow::array::ArrayRef;
+use arrow::datatypes::{DataType, Field};
+use arrow_array::cast::AsArray;
+
+use datafusion_common::utils::get_row_at_idx;
+use datafusion_common::{Result, ScalarValue};
+use datafusion_expr::Accumulator;
+
+use crate::aggregate::utils::down_cast_any_ref;
+use crate::expressions::format_state_name;
+use crate::{AggregateExpr, PhysicalExpr};
+
+/// Expression for an `AGG(DISTINCT ...)` aggregation of an aggregate function
+/// without a specialized distinct implementation, such as `AVG(DISTINCT x)`.
+///
+/// The distinct rows of the arguments are collected, including in the state
+/// of partial aggregations, and are only aggregated by the wrapped aggregate
+/// function when evaluated. Rows with a `NULL` argument are ignored.
+#[derive(Debug)]
+pub struct DistinctAggregate {
+    /// The aggregation of the distinct rows
+    aggregate: Arc<dyn AggregateExpr>,
+    /// The data types of the arguments
+    input_data_types: Vec<DataType>,
+}
+
+impl DistinctAggregate {
+    /// Create a new `DistinctAggregate` aggregating the distinct rows of the
+    /// arguments of `aggregate`, which are of `input_data_types`
+    pub fn new(
+        aggregate: Arc<dyn AggregateExpr>,
+        input_data_types: Vec<DataType>,
+    ) -> Self {
+        Self {
+            aggregate,
+            input_data_types,
+        }
+    }
+
+    /// The aggregation of the distinct rows
+    pub fn aggregate(&self) -> &Arc<dyn AggregateExpr> {
+        &self.aggregate
+    }
+}
+
+impl AggregateExpr for DistinctAggregate {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn field(&self) -> Result<Field> {
+        self.aggregate.field()
+    }
+
+    fn create_accumulator(&self) -> Result<Box<dyn Accumulator>> {
+        Ok(Box::new(DistinctAggregateAccumulator {
+            aggregate: self.aggregate.clone(),
+            input_data_types: self.input_data_types.clone(),
+            rows: HashSet::new(),
+        }))
+    }
+
+    fn state_fields(&self) -> Result<Vec<Field>> {
+        // a list of the distinct values of every argument
+        Ok(self
+            .input_data_types
+            .iter()
+            .enumerate()
+            .map(|(idx, data_type)| {
+                Field::new_list(
+                    format_state_name(self.name(), &format!("distinct_{idx}")),
+                    Field::new("item", data_type.clone(), true),
+                    false,
+                )
+            })
+            .collect())
+    }
+
+    fn expressions(&self) -> Vec<Arc<dyn PhysicalExpr>> {
+        self.aggregate.expressions()
+    }
+
+    fn name(&self) -> &str {
+        self.aggregate.name()
+    }
+}
+
+impl PartialEq<dyn Any> for DistinctAggregate {
+    fn eq(&self, other: &dyn Any) -> bool {
+        down_cast_any_ref(other)
+            .downcast_ref::<Self>()
+            .map(|x| {
+                self.input_data_types == x.input_data_types
+                    && self.aggregate.as_ref().eq(x.aggregate.as_any())
+            })
+            .unwrap_or(false)
+    }
+}
+
+#[derive(Debug)]
+struct DistinctAggregateAccumulator {
+    aggregate: Arc<dyn AggregateExpr>,
+    input_data_types: Vec<DataType>,
+    /// The distinct rows of the arguments
+    rows: HashSet<Vec<ScalarValue>>,
+}
+
+impl DistinctAggregateAccumulator {
+    /// The values of the argument at `idx` of all distinct rows
+    fn column(&self, idx: usize) -> impl Iterator<Item = ScalarValue> + '_ {
+        self.rows.iter().map(move |row| row[idx].clone())
+    }
+}
+
+impl Accumulator for DistinctAggregateAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        if values.is_empty() {
+            return Ok(());
+        }
+        for idx in 0..values[0].len() {
+            let row = get_row_at_idx(values, idx)?;
+            if !row.iter().any(ScalarValue::is_null) {
+                self.rows.insert(row);
+            }
+        }
+        Ok(())
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        if states.is_empty() {
+            return Ok(());
+        }
+        for idx in 0..states[0].len() {
+            let values = states
+                .iter()
+                .map(|state| state.as_list::<i32>().value(idx))
+                .collect::<Vec<_>>();
+            self.update_batch(&values)?;
+        }
+        Ok(())
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        self.input_data_types
+            .iter()
+            .enumerate()
+            .map(|(idx, data_type)| {
+                let values = self.column(idx).collect::<Vec<_>>();
+                Ok(ScalarValue::List(ScalarValue::new_list(&values, data_type)))
+            })
+            .collect()
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let mut accumulator = self.aggregate.create_accumulator()?;
+        if !self.rows.is_empty() {
+            let values = (0..self.input_data_types.len())
+                .map(|idx| ScalarValue::iter_to_array(self.column(idx)))
+                .collect::<Result<Vec<_>>>()?;
+            accumulator.update_batch(&values)?;
+        }
+        accumulator.evaluate()
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self)
+            + self.rows.capacity() * std::mem::size_of::<Vec<ScalarValue>>()
+            + self
+                .rows
+                .iter()
+                .map(|row| ScalarValue::size_of_vec(row) - std::mem::size_of_val(row))
+                .sum::<usize>()
+            + self
+                .input_data_types
+                .iter()
+                .map(|data_type| data_type.size())
+                .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::{col, create_aggregate_expr};
+
+    use arrow::datatypes::Schema;
+    use arrow::record_batch::RecordBatch;
+    use arrow_array::Float64Array;
+    use datafusion_expr::AggregateFunction;
+
+    fn distinct_aggregate(
+        fun: AggregateFunction,
+        batches: &[RecordBatch],
+    ) -> Result<ScalarValue> {
+        let schema = batches[0].schema();
+        let exprs = schema
+            .fields()
+            .iter()
+            .map(|field| col(field.name(), &schema))
+            .collect::<Result<Vec<_>>>()?;
+        let agg = create_aggregate_expr(&fun, true, &exprs, &[], &schema, "agg", false)?;
+        assert!(agg.as_any().is::<DistinctAggregate>());
+
+        // aggregate every batch separately, and merge their states
+        let mut merged = agg.create_accumulator()?;
+        for batch in batches {
+            let values = agg
+                .expressions()
+                .iter()
+                .map(|expr| expr.evaluate(batch)?.into_array(batch.num_rows()))
+                .collect::<Result<Vec<_>>>()?;
+            let mut accumulator = agg.create_accumulator()?;
+            accumulator.update_batch(&values)?;
+            let state = accumulator
+                .state()?
+                .iter()
+                .map(|value| value.to_array())
+                .collect::<Result<Vec<_>>>()?;
+            merged.merge_batch(&state)?;
+        }
+        merged.evaluate()
+    }
+
+    #[test]
+    fn avg_distinct() -> Result<()> {
+        let schema =
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Float64, true)]));
+        let batches = [
+            RecordBatch::try_new(
+                schema.clone(),
+                vec![Arc::new(Float64Array::from(vec![
+                    Some(1.0),
+                    Some(1.0),
+                    None,
+                ]))],
+            )?,
+            RecordBatch::try_new(
+                schema.clone(),
+                vec![Arc::new(Float64Array::from(vec![
+                    Some(2.0),
+                    Some(1.0),
+                    Some(6.0),
+                ]))],
+            )?,
+        ];
+        assert_eq!(
+            distinct_aggregate(AggregateFunction::Avg, &batches)?,
+            ScalarValue::Float64(Some(3.0))
+        );
+
+        let empty = RecordBatch::new_empty(schema);
+        assert_eq!(
+            distinct_aggregate(AggregateFunction::Avg, &[empty])?,
+            ScalarValue::Float64(None)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn covariance_distinct() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Float64, true),
+            Field::new("b", DataType::Float64, true),
+        ]));
+        // the rows (1, 2) and (3, 6) are duplicated, (2, NULL) is ignored
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Float64Array::from(vec![
+                    Some(1.0),
+                    Some(1.0),
+                    Some(3.0),
+                    Some(3.0),
+                    Some(2.0),
+                ])),
+                Arc::new(Float64Array::from(vec![
+                    Some(2.0),
+                    Some(2.0),
+                    Some(6.0),
+                    Some(6.0),
+                    None,
+                ])),
+            ],
+        )?;
+        assert_eq!(
+            distinct_aggregate(AggregateFunction::Covariance, &[batch])?,
+            ScalarValue::Float64(Some(4.0))
+        );
+        Ok(())
+    }
+}
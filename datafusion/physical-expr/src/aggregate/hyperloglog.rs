@@ -34,15 +34,21 @@
 //!
 //! This module also borrows some code structure from [pdatastructs.rs](https://github.com/crepererum/pdatastructs.rs/blob/3997ed50f6b6871c9e53c4c5e0f48f431405fc63/src/hyperloglog.rs).
 
-use ahash::RandomState;
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
+use twox_hash::XxHash64;
 
 /// The greater is P, the smaller the error.
 const HLL_P: usize = 14_usize;
 /// The number of bits of the hash value used determining the number of leading zeros
 const HLL_Q: usize = 64_usize - HLL_P;
-const NUM_REGISTERS: usize = 1_usize << HLL_P;
+pub(crate) const NUM_REGISTERS: usize = 1_usize << HLL_P;
+/// Identifies the hash function of the registers in the serialized form,
+/// which is this byte followed by the registers. It must change whenever
+/// the hash function, or its seed, changes.
+pub(crate) const HLL_HASH_ID: u8 = 1;
+/// The size of the serialized form
+pub(crate) const SERIALIZED_SIZE: usize = 1 + NUM_REGISTERS;
 /// Mask to obtain index into the registers
 const HLL_P_MASK: u64 = (NUM_REGISTERS as u64) - 1;
 
@@ -57,16 +63,9 @@ where
 
 /// Fixed seed for the hashing so that values are consistent across runs
 ///
-/// Note that when we later move on to have serialized HLL register binaries
-/// shared across cluster, this SEED will have to be consistent across all
-/// parties otherwise we might have corruption. So ideally for later this seed
-/// shall be part of the serialized form (or stay unchanged across versions).
-const SEED: RandomState = RandomState::with_seeds(
-    0x885f6cab121d01a3_u64,
-    0x71e4379f2976ad8f_u64,
-    0xbf30173dd28a8816_u64,
-    0x0eaea5d736d733a4_u64,
-);
+/// Serialized HLL registers are stored and shared across processes and
+/// versions, so this seed must stay unchanged, see [`HLL_HASH_ID`].
+const SEED: u64 = 0x885f6cab121d01a3_u64;
 
 impl<T> Default for HyperLogLog<T>
 where
@@ -97,12 +96,14 @@ where
         }
     }
 
-    /// choice of hash function: ahash is already an dependency
-    /// and it fits the requirements of being a 64bit hash with
-    /// reasonable performance.
+    /// choice of hash function: xxhash64 is a 64bit hash with reasonable
+    /// performance which, unlike ahash, is specified and computes the same
+    /// values on all platforms and versions.
     #[inline]
     fn hash_value(&self, obj: &T) -> u64 {
-        SEED.hash_one(obj)
+        let mut hasher = XxHash64::with_seed(SEED);
+        obj.hash(&mut hasher);
+        hasher.finish()
     }
 
     /// Adds an element to the HyperLogLog.
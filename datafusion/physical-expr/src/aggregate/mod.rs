@@ -25,8 +25,10 @@ mod hyperloglog;
 mod tdigest;
 
 pub(crate) mod approx_distinct;
+pub(crate) mod approx_distinct_sketch;
 pub(crate) mod approx_median;
 pub(crate) mod approx_percentile_cont;
+pub(crate) mod approx_percentile_cont_sketch;
 pub(crate) mod approx_percentile_cont_with_weight;
 pub(crate) mod array_agg;
 pub(crate) mod array_agg_distinct;
@@ -38,6 +40,7 @@ pub(crate) mod correlation;
 pub(crate) mod count;
 pub(crate) mod count_distinct;
 pub(crate) mod covariance;
+pub(crate) mod distinct;
 pub(crate) mod grouping;
 pub(crate) mod median;
 pub(crate) mod nth_value;
@@ -15,14 +15,18 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! [`StringAgg`] and [`StringAggAccumulator`] accumulator for the `string_agg` function
+//! [`StringAgg`] and [`StringAggAccumulator`] accumulator for the `string_agg` function,
+//! and [`OrderSensitiveStringAgg`] for `string_agg(... ORDER BY ...)`
 
-use crate::aggregate::utils::down_cast_any_ref;
+use crate::aggregate::array_agg_ordered::OrderSensitiveArrayAggAccumulator;
+use crate::aggregate::utils::{down_cast_any_ref, ordering_fields};
 use crate::expressions::{format_state_name, Literal};
-use crate::{AggregateExpr, PhysicalExpr};
+use crate::{
+    reverse_order_bys, AggregateExpr, LexOrdering, PhysicalExpr, PhysicalSortExpr,
+};
 use arrow::array::ArrayRef;
-use arrow::datatypes::{DataType, Field};
-use datafusion_common::cast::as_generic_string_array;
+use arrow::datatypes::{DataType, Field, Fields};
+use datafusion_common::cast::{as_generic_string_array, as_list_array};
 use datafusion_common::{not_impl_err, Result, ScalarValue};
 use datafusion_expr::Accumulator;
 use std::any::Any;
@@ -70,19 +74,8 @@ impl AggregateExpr for StringAgg {
     }
 
     fn create_accumulator(&self) -> Result<Box<dyn Accumulator>> {
-        if let Some(delimiter) = self.delimiter.as_any().downcast_ref::<Literal>() {
-            match delimiter.value() {
-                ScalarValue::Utf8(Some(delimiter))
-                | ScalarValue::LargeUtf8(Some(delimiter)) => {
-                    return Ok(Box::new(StringAggAccumulator::new(delimiter)));
-                }
-                ScalarValue::Null => {
-                    return Ok(Box::new(StringAggAccumulator::new("")));
-                }
-                _ => return not_impl_err!("StringAgg not supported for {}", self.name),
-            }
-        }
-        not_impl_err!("StringAgg not supported for {}", self.name)
+        let delimiter = literal_delimiter(&self.delimiter, &self.name)?;
+        Ok(Box::new(StringAggAccumulator::new(delimiter)))
     }
 
     fn state_fields(&self) -> Result<Vec<Field>> {
@@ -116,6 +109,23 @@ impl PartialEq<dyn Any> for StringAgg {
     }
 }
 
+/// Returns the value of the literal `delimiter` of a `string_agg` aggregation
+fn literal_delimiter<'a>(
+    delimiter: &'a Arc<dyn PhysicalExpr>,
+    name: &str,
+) -> Result<&'a str> {
+    match delimiter
+        .as_any()
+        .downcast_ref::<Literal>()
+        .map(|delimiter| delimiter.value())
+    {
+        Some(ScalarValue::Utf8(Some(delimiter)))
+        | Some(ScalarValue::LargeUtf8(Some(delimiter))) => Ok(delimiter),
+        Some(ScalarValue::Null) => Ok(""),
+        _ => not_impl_err!("StringAgg not supported for {name}"),
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct StringAggAccumulator {
     values: Option<String>,
@@ -168,12 +178,182 @@ impl Accumulator for StringAggAccumulator {
     }
 }
 
+/// Expression for a `STRING_AGG(... ORDER BY ..., ...)` aggregation. Like
+/// [`OrderSensitiveArrayAgg`], the values are collected along with their
+/// orderings, so that partial aggregations of partitions can be merged in
+/// order, and concatenated when the aggregation is evaluated.
+///
+/// [`OrderSensitiveArrayAgg`]: crate::expressions::OrderSensitiveArrayAgg
+#[derive(Debug)]
+pub struct OrderSensitiveStringAgg {
+    name: String,
+    /// The `DataType` for the input expression
+    input_data_type: DataType,
+    expr: Arc<dyn PhysicalExpr>,
+    delimiter: Arc<dyn PhysicalExpr>,
+    /// Ordering data types
+    order_by_data_types: Vec<DataType>,
+    /// Ordering requirement
+    ordering_req: LexOrdering,
+    /// Whether the aggregation is running in reverse
+    reverse: bool,
+}
+
+impl OrderSensitiveStringAgg {
+    /// Create a new `OrderSensitiveStringAgg` aggregate function
+    pub fn new(
+        expr: Arc<dyn PhysicalExpr>,
+        delimiter: Arc<dyn PhysicalExpr>,
+        name: impl Into<String>,
+        input_data_type: DataType,
+        order_by_data_types: Vec<DataType>,
+        ordering_req: LexOrdering,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            input_data_type,
+            expr,
+            delimiter,
+            order_by_data_types,
+            ordering_req,
+            reverse: false,
+        }
+    }
+}
+
+impl AggregateExpr for OrderSensitiveStringAgg {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn field(&self) -> Result<Field> {
+        Ok(Field::new(&self.name, DataType::LargeUtf8, true))
+    }
+
+    fn create_accumulator(&self) -> Result<Box<dyn Accumulator>> {
+        let delimiter = literal_delimiter(&self.delimiter, &self.name)?;
+        let values = OrderSensitiveArrayAggAccumulator::try_new(
+            &self.input_data_type,
+            &self.order_by_data_types,
+            self.ordering_req.clone(),
+            self.reverse,
+        )?;
+        Ok(Box::new(OrderSensitiveStringAggAccumulator {
+            values,
+            delimiter: delimiter.to_string(),
+        }))
+    }
+
+    fn state_fields(&self) -> Result<Vec<Field>> {
+        let orderings = ordering_fields(&self.ordering_req, &self.order_by_data_types);
+        Ok(vec![
+            Field::new_list(
+                format_state_name(&self.name, "string_agg"),
+                Field::new("item", self.input_data_type.clone(), true),
+                true,
+            ),
+            Field::new_list(
+                format_state_name(&self.name, "string_agg_orderings"),
+                Field::new("item", DataType::Struct(Fields::from(orderings)), true),
+                true,
+            ),
+        ])
+    }
+
+    fn expressions(&self) -> Vec<Arc<dyn PhysicalExpr>> {
+        vec![self.expr.clone(), self.delimiter.clone()]
+    }
+
+    fn order_bys(&self) -> Option<&[PhysicalSortExpr]> {
+        (!self.ordering_req.is_empty()).then_some(&self.ordering_req)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn reverse_expr(&self) -> Option<Arc<dyn AggregateExpr>> {
+        Some(Arc::new(Self {
+            name: self.name.to_string(),
+            input_data_type: self.input_data_type.clone(),
+            expr: self.expr.clone(),
+            delimiter: self.delimiter.clone(),
+            order_by_data_types: self.order_by_data_types.clone(),
+            ordering_req: reverse_order_bys(&self.ordering_req),
+            reverse: !self.reverse,
+        }))
+    }
+}
+
+impl PartialEq<dyn Any> for OrderSensitiveStringAgg {
+    fn eq(&self, other: &dyn Any) -> bool {
+        down_cast_any_ref(other)
+            .downcast_ref::<Self>()
+            .map(|x| {
+                self.name == x.name
+                    && self.input_data_type == x.input_data_type
+                    && self.order_by_data_types == x.order_by_data_types
+                    && self.expr.eq(&x.expr)
+                    && self.delimiter.eq(&x.delimiter)
+            })
+            .unwrap_or(false)
+    }
+}
+
+/// Collects the values of a `STRING_AGG(... ORDER BY ...)` aggregation in
+/// order, and concatenates them when evaluated
+#[derive(Debug)]
+struct OrderSensitiveStringAggAccumulator {
+    values: OrderSensitiveArrayAggAccumulator,
+    delimiter: String,
+}
+
+impl Accumulator for OrderSensitiveStringAggAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        // skip the delimiter, which precedes the ordering values
+        let mut values = values.to_vec();
+        if values.len() > 1 {
+            values.remove(1);
+        }
+        self.values.update_batch(&values)
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        self.values.merge_batch(states)
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        self.values.state()
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let ScalarValue::List(list) = self.values.evaluate()? else {
+            return not_impl_err!("StringAgg expects a list of values");
+        };
+        let values = as_list_array(list.as_ref())?.value(0);
+        let strings: Vec<_> = as_generic_string_array::<i64>(&values)?
+            .iter()
+            .flatten()
+            .collect();
+        Ok(ScalarValue::LargeUtf8(
+            (!strings.is_empty()).then(|| strings.join(self.delimiter.as_str())),
+        ))
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self) - std::mem::size_of_val(&self.values)
+            + self.values.size()
+            + self.delimiter.capacity()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::expressions::tests::aggregate;
     use crate::expressions::{col, create_aggregate_expr, try_cast};
-    use arrow::array::ArrayRef;
+    use arrow::array::{ArrayRef, Int32Array};
+    use arrow::compute::SortOptions;
     use arrow::datatypes::*;
     use arrow::record_batch::RecordBatch;
     use arrow_array::LargeStringArray;
@@ -244,4 +424,64 @@ mod tests {
             "|".to_owned(),
         );
     }
+
+    #[test]
+    fn string_agg_ordered() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::LargeUtf8, true),
+            Field::new("b", DataType::Int32, false),
+        ]));
+        let ordering = vec![PhysicalSortExpr {
+            expr: col("b", &schema)?,
+            options: SortOptions::default(),
+        }];
+        let delimiter = Arc::new(Literal::new(ScalarValue::from(",")));
+        let agg = create_aggregate_expr(
+            &AggregateFunction::StringAgg,
+            false,
+            &[col("a", &schema)?, delimiter],
+            &ordering,
+            &schema,
+            "agg",
+            false,
+        )?;
+        assert!(agg.as_any().is::<OrderSensitiveStringAgg>());
+
+        // the input of every partition is sorted by `b`
+        let partitions = vec![
+            (vec![Some("a"), None, Some("d")], vec![1, 2, 4]),
+            (vec![Some("b"), Some("c")], vec![2, 3]),
+        ];
+        let mut merged = agg.create_accumulator()?;
+        for (a, b) in partitions {
+            let batch = RecordBatch::try_new(
+                schema.clone(),
+                vec![
+                    Arc::new(LargeStringArray::from(a)),
+                    Arc::new(Int32Array::from(b)),
+                ],
+            )?;
+            let values = agg
+                .expressions()
+                .iter()
+                .chain(ordering.iter().map(|sort_expr| &sort_expr.expr))
+                .map(|expr| expr.evaluate(&batch)?.into_array(batch.num_rows()))
+                .collect::<Result<Vec<_>>>()?;
+
+            let mut accumulator = agg.create_accumulator()?;
+            accumulator.update_batch(&values)?;
+            let state = accumulator
+                .state()?
+                .iter()
+                .map(|value| value.to_array())
+                .collect::<Result<Vec<_>>>()?;
+            merged.merge_batch(&state)?;
+        }
+
+        assert_eq!(
+            merged.evaluate()?,
+            ScalarValue::LargeUtf8(Some("a,b,c,d".to_owned()))
+        );
+        Ok(())
+    }
 }
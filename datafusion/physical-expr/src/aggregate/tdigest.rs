@@ -30,8 +30,8 @@
 use arrow::datatypes::DataType;
 use arrow_array::types::Float64Type;
 use datafusion_common::cast::as_primitive_array;
-use datafusion_common::Result;
 use datafusion_common::ScalarValue;
+use datafusion_common::{exec_err, Result};
 use std::cmp::Ordering;
 
 pub const DEFAULT_MAX_SIZE: usize = 100;
@@ -632,8 +632,65 @@ impl TDigest {
             centroids,
         }
     }
+
+    /// Serialise this [`TDigest`] into a binary sketch that can be stored and
+    /// merged later.
+    ///
+    /// The sketch contains the same values as [`Self::to_scalar_state()`],
+    /// encoded as little endian: `max_size` as a `u64`, followed by `sum`,
+    /// `count`, `max`, `min` and the mean and weight of every centroid as
+    /// `f64`s.
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            SKETCH_HEADER_SIZE + SKETCH_CENTROID_SIZE * self.centroids.len(),
+        );
+        bytes.extend_from_slice(&(self.max_size as u64).to_le_bytes());
+        for v in [self.sum, self.count, self.max, self.min] {
+            bytes.extend_from_slice(&v.to_le_bytes());
+        }
+        for c in &self.centroids {
+            bytes.extend_from_slice(&c.mean().to_le_bytes());
+            bytes.extend_from_slice(&c.weight().to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Unpack a binary sketch produced by [`Self::to_bytes()`], returning an
+    /// error if `bytes` is not a valid sketch.
+    pub(crate) fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < SKETCH_HEADER_SIZE
+            || (bytes.len() - SKETCH_HEADER_SIZE) % SKETCH_CENTROID_SIZE != 0
+        {
+            return exec_err!(
+                "Invalid approx_percentile_cont sketch of {} bytes",
+                bytes.len()
+            );
+        }
+        let mut values = bytes
+            .chunks_exact(8)
+            .map(|chunk| <[u8; 8]>::try_from(chunk).unwrap());
+        let max_size = u64::from_le_bytes(values.next().unwrap()) as usize;
+        let mut next_f64 = || f64::from_le_bytes(values.next().unwrap());
+        let (sum, count, max, min) = (next_f64(), next_f64(), next_f64(), next_f64());
+        let centroids = (0..(bytes.len() - SKETCH_HEADER_SIZE) / SKETCH_CENTROID_SIZE)
+            .map(|_| Centroid::new(next_f64(), next_f64()))
+            .collect();
+        Ok(Self {
+            max_size,
+            sum,
+            count,
+            max,
+            min,
+            centroids,
+        })
+    }
 }
 
+/// Size of the binary sketch of a [`TDigest`] without centroids
+const SKETCH_HEADER_SIZE: usize = 5 * 8;
+/// Size of a [`Centroid`] in the binary sketch of a [`TDigest`]
+const SKETCH_CENTROID_SIZE: usize = 2 * 8;
+
 #[cfg(debug_assertions)]
 fn is_sorted(values: &[f64]) -> bool {
     values.windows(2).all(|w| w[0].total_cmp(&w[1]).is_le())
@@ -756,6 +813,21 @@ mod tests {
         assert_state_roundtrip!(t);
     }
 
+    #[test]
+    fn test_bytes_roundtrip() {
+        let t = TDigest::new(100);
+        let values: Vec<_> = (1..=1_000).map(f64::from).collect();
+        let t = t.merge_unsorted_f64(values);
+
+        let bytes = t.to_bytes();
+        assert_eq!(TDigest::try_from_bytes(&bytes).unwrap(), t);
+
+        let err = TDigest::try_from_bytes(&bytes[1..]).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Invalid approx_percentile_cont sketch"));
+    }
+
     #[test]
     fn test_size() {
         let t = TDigest::new(10);
@@ -24,7 +24,7 @@ pub use datafusion_physical_expr_common::aggregate::utils::{
     down_cast_any_ref, get_sort_options, ordering_fields,
 };
 
-use arrow::array::{ArrayRef, ArrowNativeTypeOp};
+use arrow::array::{Array, ArrayRef, ArrowNativeTypeOp, BooleanArray};
 use arrow_array::cast::AsArray;
 use arrow_array::types::{
     Decimal128Type, DecimalType, TimestampMicrosecondType, TimestampMillisecondType,
@@ -46,6 +46,26 @@ pub fn get_accum_scalar_values_as_arrays(
         .collect()
 }
 
+/// Calls `f` with the group index and row index of every row that is not
+/// null in any of `values` and not filtered out by `opt_filter`, for use in
+/// [`GroupsAccumulator`]s
+///
+/// [`GroupsAccumulator`]: datafusion_expr::GroupsAccumulator
+pub(crate) fn for_each_valid_row(
+    values: &[&dyn Array],
+    group_indices: &[usize],
+    opt_filter: Option<&BooleanArray>,
+    mut f: impl FnMut(usize, usize),
+) {
+    for (row, &group_index) in group_indices.iter().enumerate() {
+        let filtered =
+            opt_filter.is_some_and(|filter| !filter.is_valid(row) || !filter.value(row));
+        if !filtered && values.iter().all(|values| values.is_valid(row)) {
+            f(group_index, row);
+        }
+    }
+}
+
 /// Computes averages for `Decimal128`/`Decimal256` values, checking for overflow
 ///
 /// This is needed because different precisions for Decimal128/Decimal256 can
@@ -21,10 +21,11 @@ use std::any::Any;
 use std::sync::Arc;
 
 use crate::aggregate::stats::StatsType;
-use crate::aggregate::utils::down_cast_any_ref;
+use crate::aggregate::utils::{down_cast_any_ref, for_each_valid_row};
 use crate::expressions::format_state_name;
 use crate::{AggregateExpr, PhysicalExpr};
-use arrow::array::Float64Array;
+use arrow::array::{AsArray, BooleanArray, Float64Array};
+use arrow::datatypes::{Float64Type, UInt64Type};
 use arrow::{
     array::{ArrayRef, UInt64Array},
     compute::cast,
@@ -33,7 +34,7 @@ use arrow::{
 };
 use datafusion_common::downcast_value;
 use datafusion_common::{DataFusionError, Result, ScalarValue};
-use datafusion_expr::Accumulator;
+use datafusion_expr::{Accumulator, EmitTo, GroupsAccumulator};
 
 /// VAR and VAR_SAMP aggregate expression
 #[derive(Debug)]
@@ -83,6 +84,17 @@ impl AggregateExpr for Variance {
         Ok(Box::new(VarianceAccumulator::try_new(StatsType::Sample)?))
     }
 
+    fn groups_accumulator_supported(&self) -> bool {
+        true
+    }
+
+    fn create_groups_accumulator(&self) -> Result<Box<dyn GroupsAccumulator>> {
+        Ok(Box::new(VarianceGroupsAccumulator::new(
+            StatsType::Sample,
+            false,
+        )))
+    }
+
     fn state_fields(&self) -> Result<Vec<Field>> {
         Ok(vec![
             Field::new(
@@ -155,6 +167,17 @@ impl AggregateExpr for VariancePop {
         )?))
     }
 
+    fn groups_accumulator_supported(&self) -> bool {
+        true
+    }
+
+    fn create_groups_accumulator(&self) -> Result<Box<dyn GroupsAccumulator>> {
+        Ok(Box::new(VarianceGroupsAccumulator::new(
+            StatsType::Population,
+            false,
+        )))
+    }
+
     fn state_fields(&self) -> Result<Vec<Field>> {
         Ok(vec![
             Field::new(
@@ -244,15 +267,8 @@ impl Accumulator for VarianceAccumulator {
         let arr = downcast_value!(values, Float64Array).iter().flatten();
 
         for value in arr {
-            let new_count = self.count + 1;
-            let delta1 = value - self.mean;
-            let new_mean = delta1 / new_count as f64 + self.mean;
-            let delta2 = value - new_mean;
-            let new_m2 = self.m2 + delta1 * delta2;
-
-            self.count += 1;
-            self.mean = new_mean;
-            self.m2 = new_m2;
+            (self.count, self.mean, self.m2) =
+                update(self.count, self.mean, self.m2, value);
         }
 
         Ok(())
@@ -264,6 +280,13 @@ impl Accumulator for VarianceAccumulator {
 
         for value in arr {
             let new_count = self.count - 1;
+            if new_count == 0 {
+                // The window is empty, avoid dividing by zero
+                self.count = 0;
+                self.mean = 0.0;
+                self.m2 = 0.0;
+                continue;
+            }
             let delta1 = self.mean - value;
             let new_mean = delta1 / new_count as f64 + self.mean;
             let delta2 = new_mean - value;
@@ -283,52 +306,209 @@ impl Accumulator for VarianceAccumulator {
         let m2s = downcast_value!(states[2], Float64Array);
 
         for i in 0..counts.len() {
-            let c = counts.value(i);
-            if c == 0_u64 {
-                continue;
-            }
-            let new_count = self.count + c;
-            let new_mean = self.mean * self.count as f64 / new_count as f64
-                + means.value(i) * c as f64 / new_count as f64;
-            let delta = self.mean - means.value(i);
-            let new_m2 = self.m2
-                + m2s.value(i)
-                + delta * delta * self.count as f64 * c as f64 / new_count as f64;
-
-            self.count = new_count;
-            self.mean = new_mean;
-            self.m2 = new_m2;
+            (self.count, self.mean, self.m2) = merge(
+                self.count,
+                self.mean,
+                self.m2,
+                counts.value(i),
+                means.value(i),
+                m2s.value(i),
+            );
         }
         Ok(())
     }
 
     fn evaluate(&mut self) -> Result<ScalarValue> {
-        let count = match self.stats_type {
-            StatsType::Population => self.count,
-            StatsType::Sample => {
-                if self.count > 0 {
-                    self.count - 1
-                } else {
-                    self.count
-                }
-            }
-        };
+        Ok(ScalarValue::Float64(variance(
+            self.count,
+            self.m2,
+            self.stats_type,
+        )))
+    }
+
+    fn supports_retract_batch(&self) -> bool {
+        true
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self)
+    }
+}
+
+/// Returns the count, mean and m2 after adding `value`
+#[inline]
+fn update(count: u64, mean: f64, m2: f64, value: f64) -> (u64, f64, f64) {
+    let new_count = count + 1;
+    let delta1 = value - mean;
+    let new_mean = delta1 / new_count as f64 + mean;
+    let delta2 = value - new_mean;
+    (new_count, new_mean, m2 + delta1 * delta2)
+}
+
+/// Returns the count, mean and m2 after merging the partial state of
+/// another accumulator
+#[inline]
+fn merge(
+    count: u64,
+    mean: f64,
+    m2: f64,
+    partial_count: u64,
+    partial_mean: f64,
+    partial_m2: f64,
+) -> (u64, f64, f64) {
+    if partial_count == 0 {
+        return (count, mean, m2);
+    }
+    let new_count = count + partial_count;
+    let new_mean = mean * count as f64 / new_count as f64
+        + partial_mean * partial_count as f64 / new_count as f64;
+    let delta = mean - partial_mean;
+    let new_m2 = m2
+        + partial_m2
+        + delta * delta * count as f64 * partial_count as f64 / new_count as f64;
+    (new_count, new_mean, new_m2)
+}
+
+/// Returns the variance of `count` values with sum of squared differences
+/// from their mean `m2`
+fn variance(count: u64, m2: f64, stats_type: StatsType) -> Option<f64> {
+    match (count, stats_type) {
+        (0, _) => None,
+        (1, StatsType::Population) => Some(0.0),
+        (1, StatsType::Sample) => None,
+        (_, StatsType::Population) => Some(m2 / count as f64),
+        (_, StatsType::Sample) => Some(m2 / (count - 1) as f64),
+    }
+}
+
+/// A [`GroupsAccumulator`] for the variance, or the standard deviation, of
+/// every group, computed like [`VarianceAccumulator`] but with the count,
+/// mean and m2 of all groups in vectors rather than in an accumulator per
+/// group
+#[derive(Debug)]
+pub struct VarianceGroupsAccumulator {
+    m2s: Vec<f64>,
+    means: Vec<f64>,
+    counts: Vec<u64>,
+    stats_type: StatsType,
+    /// Whether to evaluate the standard deviation rather than the variance
+    sqrt: bool,
+}
+
+impl VarianceGroupsAccumulator {
+    /// Creates a new `VarianceGroupsAccumulator`, of the standard deviation
+    /// if `sqrt` is true
+    pub fn new(stats_type: StatsType, sqrt: bool) -> Self {
+        Self {
+            m2s: vec![],
+            means: vec![],
+            counts: vec![],
+            stats_type,
+            sqrt,
+        }
+    }
+
+    fn resize(&mut self, total_num_groups: usize) {
+        self.m2s.resize(total_num_groups, 0.0);
+        self.means.resize(total_num_groups, 0.0);
+        self.counts.resize(total_num_groups, 0);
+    }
+}
+
+impl GroupsAccumulator for VarianceGroupsAccumulator {
+    fn update_batch(
+        &mut self,
+        values: &[ArrayRef],
+        group_indices: &[usize],
+        opt_filter: Option<&BooleanArray>,
+        total_num_groups: usize,
+    ) -> Result<()> {
+        assert_eq!(values.len(), 1, "single argument to update_batch");
+        let values = cast(&values[0], &DataType::Float64)?;
+        let values = values.as_primitive::<Float64Type>();
+
+        self.resize(total_num_groups);
+        for_each_valid_row(&[values], group_indices, opt_filter, |group_index, row| {
+            (
+                self.counts[group_index],
+                self.means[group_index],
+                self.m2s[group_index],
+            ) = update(
+                self.counts[group_index],
+                self.means[group_index],
+                self.m2s[group_index],
+                values.value(row),
+            );
+        });
+        Ok(())
+    }
+
+    fn merge_batch(
+        &mut self,
+        values: &[ArrayRef],
+        group_indices: &[usize],
+        opt_filter: Option<&BooleanArray>,
+        total_num_groups: usize,
+    ) -> Result<()> {
+        assert_eq!(values.len(), 3, "three arguments to merge_batch");
+        let counts = values[0].as_primitive::<UInt64Type>();
+        let means = values[1].as_primitive::<Float64Type>();
+        let m2s = values[2].as_primitive::<Float64Type>();
+
+        self.resize(total_num_groups);
+        for_each_valid_row(&[counts], group_indices, opt_filter, |group_index, row| {
+            (
+                self.counts[group_index],
+                self.means[group_index],
+                self.m2s[group_index],
+            ) = merge(
+                self.counts[group_index],
+                self.means[group_index],
+                self.m2s[group_index],
+                counts.value(row),
+                means.value(row),
+                m2s.value(row),
+            );
+        });
+        Ok(())
+    }
 
-        Ok(ScalarValue::Float64(match self.count {
-            0 => None,
-            1 => {
-                if let StatsType::Population = self.stats_type {
-                    Some(0.0)
+    fn evaluate(&mut self, emit_to: EmitTo) -> Result<ArrayRef> {
+        let counts = emit_to.take_needed(&mut self.counts);
+        let m2s = emit_to.take_needed(&mut self.m2s);
+        let _ = emit_to.take_needed(&mut self.means);
+
+        let array = counts
+            .into_iter()
+            .zip(m2s)
+            .map(|(count, m2)| {
+                let variance = variance(count, m2, self.stats_type);
+                if self.sqrt {
+                    variance.map(f64::sqrt)
                 } else {
-                    None
+                    variance
                 }
-            }
-            _ => Some(self.m2 / count as f64),
-        }))
+            })
+            .collect::<Float64Array>();
+        Ok(Arc::new(array))
+    }
+
+    fn state(&mut self, emit_to: EmitTo) -> Result<Vec<ArrayRef>> {
+        let counts = emit_to.take_needed(&mut self.counts);
+        let means = emit_to.take_needed(&mut self.means);
+        let m2s = emit_to.take_needed(&mut self.m2s);
+
+        Ok(vec![
+            Arc::new(UInt64Array::from(counts)),
+            Arc::new(Float64Array::from(means)),
+            Arc::new(Float64Array::from(m2s)),
+        ])
     }
 
     fn size(&self) -> usize {
-        std::mem::size_of_val(self)
+        self.m2s.capacity() * std::mem::size_of::<f64>()
+            + self.means.capacity() * std::mem::size_of::<f64>()
+            + self.counts.capacity() * std::mem::size_of::<u64>()
     }
 }
 
@@ -537,4 +717,47 @@ mod tests {
         accum1.merge_batch(&state2)?;
         accum1.evaluate()
     }
+
+    #[test]
+    fn variance_groups_accumulator() -> Result<()> {
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(1),
+            Some(2),
+            None,
+            Some(4),
+            Some(8),
+            Some(3),
+            Some(5),
+        ]));
+        let group_indices = [0, 1, 0, 1, 0, 2, 1];
+        // the last row is filtered out
+        let filter = BooleanArray::from(vec![true, true, true, true, true, true, false]);
+
+        let mut accum = VarianceGroupsAccumulator::new(StatsType::Sample, false);
+        accum.update_batch(&[values.clone()], &group_indices, Some(&filter), 3)?;
+        let evaluated = accum.evaluate(EmitTo::All)?;
+        let expected: ArrayRef =
+            Arc::new(Float64Array::from(vec![Some(24.5), Some(2.0), None]));
+        assert_eq!(&evaluated, &expected);
+
+        // merge the states of two accumulators of the groups, into the
+        // groups in reverse order
+        let mut accum = VarianceGroupsAccumulator::new(StatsType::Population, true);
+        accum.update_batch(&[values.clone()], &group_indices, None, 3)?;
+        let state = accum.state(EmitTo::All)?;
+        let mut merged = VarianceGroupsAccumulator::new(StatsType::Population, true);
+        merged.merge_batch(&state, &[2, 1, 0], None, 3)?;
+        merged.merge_batch(&state, &[2, 1, 0], None, 3)?;
+
+        // first group only
+        let evaluated = merged.evaluate(EmitTo::First(1))?;
+        let expected: ArrayRef = Arc::new(Float64Array::from(vec![Some(0.0)]));
+        assert_eq!(&evaluated, &expected);
+        // the remaining groups 1 and 2
+        let evaluated = merged.evaluate(EmitTo::All)?;
+        let evaluated = evaluated.as_primitive::<Float64Type>();
+        assert!((evaluated.value(0) - (14.0_f64 / 9.0).sqrt()).abs() < 1e-12);
+        assert_eq!(evaluated.value(1), 3.5);
+        Ok(())
+    }
 }
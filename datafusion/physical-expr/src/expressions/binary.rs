@@ -37,7 +37,9 @@ use arrow::datatypes::*;
 use arrow::record_batch::RecordBatch;
 
 use datafusion_common::cast::as_boolean_array;
-use datafusion_common::{internal_err, Result, ScalarValue};
+use datafusion_common::{
+    downcast_value, internal_err, DataFusionError, Result, ScalarValue,
+};
 use datafusion_expr::interval_arithmetic::{apply_operator, Interval};
 use datafusion_expr::type_coercion::binary::get_result_type;
 use datafusion_expr::{ColumnarValue, Operator};
@@ -118,14 +120,8 @@ impl std::fmt::Display for BinaryExpr {
 /// Invoke a compute kernel on a pair of binary data arrays
 macro_rules! compute_utf8_op {
     ($LEFT:expr, $RIGHT:expr, $OP:ident, $DT:ident) => {{
-        let ll = $LEFT
-            .as_any()
-            .downcast_ref::<$DT>()
-            .expect("compute_op failed to downcast left side array");
-        let rr = $RIGHT
-            .as_any()
-            .downcast_ref::<$DT>()
-            .expect("compute_op failed to downcast right side array");
+        let ll = downcast_value!($LEFT, $DT);
+        let rr = downcast_value!($RIGHT, $DT);
         Ok(Arc::new(paste::expr! {[<$OP _utf8>]}(&ll, &rr)?))
     }};
 }
@@ -146,8 +142,8 @@ macro_rules! binary_string_array_op {
 /// Invoke a boolean kernel on a pair of arrays
 macro_rules! boolean_op {
     ($LEFT:expr, $RIGHT:expr, $OP:ident) => {{
-        let ll = as_boolean_array($LEFT).expect("boolean_op failed to downcast array");
-        let rr = as_boolean_array($RIGHT).expect("boolean_op failed to downcast array");
+        let ll = as_boolean_array($LEFT)?;
+        let rr = as_boolean_array($RIGHT)?;
         Ok(Arc::new($OP(&ll, &rr)?))
     }};
 }
@@ -172,14 +168,8 @@ macro_rules! binary_string_array_flag_op {
 /// Invoke a compute kernel on a pair of binary data arrays with flags
 macro_rules! compute_utf8_flag_op {
     ($LEFT:expr, $RIGHT:expr, $OP:ident, $ARRAYTYPE:ident, $NOT:expr, $FLAG:expr) => {{
-        let ll = $LEFT
-            .as_any()
-            .downcast_ref::<$ARRAYTYPE>()
-            .expect("compute_utf8_flag_op failed to downcast array");
-        let rr = $RIGHT
-            .as_any()
-            .downcast_ref::<$ARRAYTYPE>()
-            .expect("compute_utf8_flag_op failed to downcast array");
+        let ll = downcast_value!($LEFT, $ARRAYTYPE);
+        let rr = downcast_value!($RIGHT, $ARRAYTYPE);
 
         let flag = if $FLAG {
             Some($ARRAYTYPE::from(vec!["i"; ll.len()]))
@@ -188,7 +178,7 @@ macro_rules! compute_utf8_flag_op {
         };
         let mut array = paste::expr! {[<$OP _utf8>]}(&ll, &rr, flag.as_ref())?;
         if $NOT {
-            array = not(&array).unwrap();
+            array = not(&array)?;
         }
         Ok(Arc::new(array))
     }};
@@ -215,17 +205,14 @@ macro_rules! binary_string_array_flag_op_scalar {
 /// Invoke a compute kernel on a data array and a scalar value with flag
 macro_rules! compute_utf8_flag_op_scalar {
     ($LEFT:expr, $RIGHT:expr, $OP:ident, $ARRAYTYPE:ident, $NOT:expr, $FLAG:expr) => {{
-        let ll = $LEFT
-            .as_any()
-            .downcast_ref::<$ARRAYTYPE>()
-            .expect("compute_utf8_flag_op_scalar failed to downcast array");
+        let ll = downcast_value!($LEFT, $ARRAYTYPE);
 
         if let ScalarValue::Utf8(Some(string_value))|ScalarValue::LargeUtf8(Some(string_value)) = $RIGHT {
             let flag = if $FLAG { Some("i") } else { None };
             let mut array =
                 paste::expr! {[<$OP _utf8_scalar>]}(&ll, &string_value, flag)?;
             if $NOT {
-                array = not(&array).unwrap();
+                array = not(&array)?;
             }
             Ok(Arc::new(array))
         } else {
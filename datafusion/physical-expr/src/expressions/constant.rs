@@ -0,0 +1,406 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Constant expressions, evaluated once per execution
+
+use std::any::Any;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+
+use crate::expressions::{
+    BinaryExpr, CaseExpr, CastExpr, InListExpr, IsNotNullExpr, IsNullExpr, LikeExpr,
+    Literal, NegativeExpr, NotExpr, TryCastExpr,
+};
+use crate::physical_expr::down_cast_any_ref;
+use crate::sort_properties::SortProperties;
+use crate::{PhysicalExpr, ScalarFunctionExpr};
+
+use arrow::datatypes::{DataType, Schema};
+use arrow::record_batch::{RecordBatch, RecordBatchOptions};
+use datafusion_common::tree_node::{
+    Transformed, TransformedResult, TreeNode, TreeNodeRecursion,
+};
+use datafusion_common::{Result, ScalarValue};
+use datafusion_expr::interval_arithmetic::Interval;
+use datafusion_expr::ColumnarValue;
+
+/// A deterministic expression which does not reference its input, such as
+/// `now() - INTERVAL '1 day'`, whose value is computed on its first
+/// evaluation and reused for all following batches.
+///
+/// As the expressions of a plan are shared by all of its partitions, the
+/// value is computed once per execution of the plan rather than once per
+/// batch. The operators evaluating the expressions discard the value with
+/// [`reset_constants`] when the plan is executed again, so that it is never
+/// reused across executions. Created by [`cache_constant_subtrees`].
+#[derive(Debug)]
+pub struct ConstantExpr {
+    /// The input independent expression
+    expr: Arc<dyn PhysicalExpr>,
+    /// The value of `expr` in the current execution, once evaluated
+    value: Mutex<Option<ScalarValue>>,
+}
+
+impl ConstantExpr {
+    /// Create a new constant expression, which caches the value of `expr`.
+    ///
+    /// `expr` must not reference any columns nor call volatile functions.
+    pub fn new(expr: Arc<dyn PhysicalExpr>) -> Self {
+        Self {
+            expr,
+            value: Mutex::new(None),
+        }
+    }
+
+    /// Get the cached expression
+    pub fn expr(&self) -> &Arc<dyn PhysicalExpr> {
+        &self.expr
+    }
+
+    /// Returns the value of the expression, evaluating it if this is the
+    /// first call since the creation of the expression or the last
+    /// [`Self::reset`]
+    pub fn value(&self) -> Result<ScalarValue> {
+        // partitions evaluating the expression concurrently wait for the
+        // first one, so that the value is only computed once
+        let mut value = self.value.lock().unwrap();
+        if let Some(value) = value.as_ref() {
+            return Ok(value.clone());
+        }
+        // the expression does not reference any columns, so it is evaluated
+        // against a single row without any
+        let batch = RecordBatch::try_new_with_options(
+            Arc::new(Schema::empty()),
+            vec![],
+            &RecordBatchOptions::new().with_row_count(Some(1)),
+        )?;
+        let new_value = match self.expr.evaluate(&batch)? {
+            ColumnarValue::Scalar(value) => value,
+            ColumnarValue::Array(array) => ScalarValue::try_from_array(&array, 0)?,
+        };
+        Ok(value.insert(new_value).clone())
+    }
+
+    /// Discards the cached value, so that the expression is evaluated again
+    /// by the next call to [`Self::value`]
+    pub fn reset(&self) {
+        self.value.lock().unwrap().take();
+    }
+}
+
+impl fmt::Display for ConstantExpr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.expr)
+    }
+}
+
+impl PhysicalExpr for ConstantExpr {
+    /// Return a reference to Any that can be used for downcasting
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn data_type(&self, input_schema: &Schema) -> Result<DataType> {
+        self.expr.data_type(input_schema)
+    }
+
+    fn nullable(&self, input_schema: &Schema) -> Result<bool> {
+        self.expr.nullable(input_schema)
+    }
+
+    fn evaluate(&self, batch: &RecordBatch) -> Result<ColumnarValue> {
+        if batch.num_rows() == 0 {
+            // keep the result of evaluating an empty batch, which may differ
+            return self.expr.evaluate(batch);
+        }
+        Ok(ColumnarValue::Scalar(self.value()?))
+    }
+
+    fn children(&self) -> Vec<Arc<dyn PhysicalExpr>> {
+        vec![self.expr.clone()]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn PhysicalExpr>>,
+    ) -> Result<Arc<dyn PhysicalExpr>> {
+        Ok(Arc::new(ConstantExpr::new(children[0].clone())))
+    }
+
+    fn evaluate_bounds(&self, children: &[&Interval]) -> Result<Interval> {
+        Ok(children[0].clone())
+    }
+
+    fn dyn_hash(&self, state: &mut dyn Hasher) {
+        let mut s = state;
+        self.expr.hash(&mut s);
+    }
+
+    fn get_ordering(&self, _children: &[SortProperties]) -> SortProperties {
+        SortProperties::Singleton
+    }
+}
+
+impl PartialEq<dyn Any> for ConstantExpr {
+    fn eq(&self, other: &dyn Any) -> bool {
+        down_cast_any_ref(other)
+            .downcast_ref::<Self>()
+            .map(|x| self.expr.eq(&x.expr))
+            .unwrap_or(false)
+    }
+}
+
+/// Wraps the largest subtrees of `expr` which are deterministic and do not
+/// reference the input, other than literals, into [`ConstantExpr`]s, so
+/// that they are only evaluated once per execution.
+///
+/// For example, in `ts > now() - INTERVAL '1 day'` the right side is cached.
+/// Subtrees calling volatile functions such as `random()`, and expressions
+/// of unknown types, are never cached.
+///
+/// Only scalar values are cached: the set of an `IN` list of constants is
+/// still built by [`InListExpr`] when it is created, and the regular
+/// expressions of `LIKE` patterns are still compiled for each batch.
+pub fn cache_constant_subtrees(
+    expr: Arc<dyn PhysicalExpr>,
+) -> Result<Arc<dyn PhysicalExpr>> {
+    expr.transform_down(|expr| {
+        if is_constant(&expr) && !expr.as_any().is::<Literal>() {
+            let constant = Arc::new(ConstantExpr::new(expr)) as _;
+            // the children are evaluated as part of the constant
+            Ok(Transformed::new(constant, true, TreeNodeRecursion::Jump))
+        } else {
+            Ok(Transformed::no(expr))
+        }
+    })
+    .data()
+}
+
+/// Discards the cached values of the [`ConstantExpr`]s in `expr`, so that
+/// they are evaluated again by the next execution
+pub fn reset_constants(expr: &Arc<dyn PhysicalExpr>) {
+    if let Some(constant) = expr.as_any().downcast_ref::<ConstantExpr>() {
+        constant.reset();
+    }
+    expr.children().iter().for_each(reset_constants);
+}
+
+/// Returns true if `expr` is deterministic and does not reference its input
+fn is_constant(expr: &Arc<dyn PhysicalExpr>) -> bool {
+    let any = expr.as_any();
+    let is_known = if let Some(func) = any.downcast_ref::<ScalarFunctionExpr>() {
+        matches!(func.fun().is_volatile(), Ok(false))
+    } else {
+        any.is::<Literal>()
+            || any.is::<ConstantExpr>()
+            || any.is::<BinaryExpr>()
+            || any.is::<CastExpr>()
+            || any.is::<TryCastExpr>()
+            || any.is::<NotExpr>()
+            || any.is::<NegativeExpr>()
+            || any.is::<IsNullExpr>()
+            || any.is::<IsNotNullExpr>()
+            || any.is::<LikeExpr>()
+            || any.is::<CaseExpr>()
+            || any.is::<InListExpr>()
+    };
+    is_known && expr.children().iter().all(is_constant)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::expressions::{binary, col, in_list, lit};
+
+    use arrow::array::{BooleanArray, Int64Array};
+    use arrow::datatypes::Field;
+    use datafusion_expr::{
+        Operator, ScalarFunctionDefinition, ScalarUDF, ScalarUDFImpl, Signature,
+        Volatility,
+    };
+
+    /// Returns its argument, counting its invocations
+    #[derive(Debug)]
+    struct CountingUDF {
+        signature: Signature,
+        invocations: Arc<AtomicUsize>,
+    }
+
+    impl ScalarUDFImpl for CountingUDF {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn name(&self) -> &str {
+            "counting"
+        }
+
+        fn signature(&self) -> &Signature {
+            &self.signature
+        }
+
+        fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+            Ok(DataType::Int64)
+        }
+
+        fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+            self.invocations.fetch_add(1, Ordering::SeqCst);
+            Ok(args[0].clone())
+        }
+    }
+
+    /// Calls a [`CountingUDF`] of `volatility` with `arg`
+    fn counting(
+        arg: Arc<dyn PhysicalExpr>,
+        volatility: Volatility,
+        invocations: &Arc<AtomicUsize>,
+    ) -> Arc<dyn PhysicalExpr> {
+        let udf = ScalarUDF::from(CountingUDF {
+            signature: Signature::exact(vec![DataType::Int64], volatility),
+            invocations: invocations.clone(),
+        });
+        Arc::new(ScalarFunctionExpr::new(
+            "counting",
+            ScalarFunctionDefinition::UDF(Arc::new(udf)),
+            vec![arg],
+            DataType::Int64,
+            None,
+            false,
+        ))
+    }
+
+    fn batch(values: Vec<i64>) -> Result<RecordBatch> {
+        Ok(RecordBatch::try_from_iter(vec![(
+            "a",
+            Arc::new(Int64Array::from(values)) as _,
+        )])?)
+    }
+
+    #[test]
+    fn cache_constant_subtree() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+        let invocations = Arc::new(AtomicUsize::new(0));
+        let constant = binary(
+            counting(lit(1_i64), Volatility::Immutable, &invocations),
+            Operator::Plus,
+            lit(1_i64),
+            &schema,
+        )?;
+        let expr = binary(col("a", &schema)?, Operator::Lt, constant.clone(), &schema)?;
+
+        let cached = cache_constant_subtrees(expr.clone())?;
+        // the display is unchanged
+        assert_eq!(cached.to_string(), expr.to_string());
+        let cached_binary = cached.as_any().downcast_ref::<BinaryExpr>().unwrap();
+        assert!(cached_binary
+            .left()
+            .as_any()
+            .is::<crate::expressions::Column>());
+        let right = cached_binary
+            .right()
+            .as_any()
+            .downcast_ref::<ConstantExpr>()
+            .unwrap();
+        assert!(right.expr().eq(&constant));
+
+        for _ in 0..3 {
+            let result = cached.evaluate(&batch(vec![1, 2, 3])?)?;
+            let expected = BooleanArray::from(vec![true, false, false]);
+            assert_eq!(result.into_array(3)?.as_ref(), &expected);
+        }
+        assert_eq!(invocations.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn do_not_cache_volatile_or_input_dependent() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+        let invocations = Arc::new(AtomicUsize::new(0));
+
+        let volatile = counting(lit(1_i64), Volatility::Volatile, &invocations);
+        let cached = cache_constant_subtrees(volatile)?;
+        assert!(cached.as_any().is::<ScalarFunctionExpr>());
+
+        let input = counting(col("a", &schema)?, Volatility::Immutable, &invocations);
+        let cached = cache_constant_subtrees(input)?;
+        assert!(cached.as_any().is::<ScalarFunctionExpr>());
+
+        // literals are already constant
+        let cached = cache_constant_subtrees(lit(1_i64))?;
+        assert!(cached.as_any().is::<Literal>());
+
+        for _ in 0..2 {
+            cached.evaluate(&batch(vec![1, 2])?)?;
+        }
+        assert_eq!(invocations.load(Ordering::SeqCst), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_empty_batch() -> Result<()> {
+        let invocations = Arc::new(AtomicUsize::new(0));
+        let expr =
+            ConstantExpr::new(counting(lit(1_i64), Volatility::Immutable, &invocations));
+        expr.evaluate(&batch(vec![])?)?;
+        assert!(expr.value.lock().unwrap().is_none());
+        assert_eq!(expr.value()?, ScalarValue::Int64(Some(1)));
+        assert_eq!(invocations.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn reset_cached_values() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+        let invocations = Arc::new(AtomicUsize::new(0));
+        let constant = counting(lit(1_i64), Volatility::Immutable, &invocations);
+        let expr = binary(col("a", &schema)?, Operator::Lt, constant, &schema)?;
+        let cached = cache_constant_subtrees(expr)?;
+
+        for execution in 1..=3 {
+            for _ in 0..2 {
+                cached.evaluate(&batch(vec![1, 2, 3])?)?;
+            }
+            assert_eq!(invocations.load(Ordering::SeqCst), execution);
+            reset_constants(&cached);
+        }
+        Ok(())
+    }
+
+    /// The set of an IN list of constants is built once, when planning
+    #[test]
+    fn in_list_of_constants() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+        let invocations = Arc::new(AtomicUsize::new(0));
+        let list = vec![
+            counting(lit(1_i64), Volatility::Immutable, &invocations),
+            lit(3_i64),
+        ];
+        let expr = in_list(col("a", &schema)?, list, &false, &schema)?;
+        let cached = cache_constant_subtrees(expr)?;
+
+        for _ in 0..3 {
+            let result = cached.evaluate(&batch(vec![1, 2, 3])?)?;
+            let expected = BooleanArray::from(vec![true, false, true]);
+            assert_eq!(result.into_array(3)?.as_ref(), &expected);
+        }
+        assert_eq!(invocations.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+}
@@ -15,7 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use arrow::array::{ArrayRef, Datum};
+use arrow::array::{new_null_array, Array, ArrayRef, AsArray, Datum, UInt64Array};
+use arrow::compute::{concat, take};
 use arrow::error::ArrowError;
 use arrow_array::BooleanArray;
 use datafusion_common::{Result, ScalarValue};
@@ -49,10 +50,126 @@ pub(crate) fn apply(
 }
 
 /// Applies a binary [`Datum`] comparison kernel `f` to `lhs` and `rhs`
+///
+/// The comparison of a dictionary array with a scalar is evaluated once for
+/// every value of the dictionary, and the result is mapped over its keys.
 pub(crate) fn apply_cmp(
     lhs: &ColumnarValue,
     rhs: &ColumnarValue,
     f: impl Fn(&dyn Datum, &dyn Datum) -> Result<BooleanArray, ArrowError>,
 ) -> Result<ColumnarValue> {
-    apply(lhs, rhs, |l, r| Ok(Arc::new(f(l, r)?)))
+    let dictionary_result = match (lhs, rhs) {
+        (ColumnarValue::Array(array), ColumnarValue::Scalar(scalar)) => {
+            apply_cmp_dictionary(array, scalar, |values, scalar| f(values, scalar))?
+        }
+        (ColumnarValue::Scalar(scalar), ColumnarValue::Array(array)) => {
+            apply_cmp_dictionary(array, scalar, |values, scalar| f(scalar, values))?
+        }
+        _ => None,
+    };
+    match dictionary_result {
+        Some(result) => Ok(ColumnarValue::Array(result)),
+        None => apply(lhs, rhs, |l, r| Ok(Arc::new(f(l, r)?))),
+    }
+}
+
+/// Applies the comparison kernel `f` to the values of the dictionary `array`
+/// and `scalar`, and takes the results of the keys of `array`.
+///
+/// Returns `None` if `array` is not a dictionary with values of the type of
+/// `scalar`.
+fn apply_cmp_dictionary(
+    array: &ArrayRef,
+    scalar: &ScalarValue,
+    f: impl Fn(&dyn Datum, &dyn Datum) -> Result<BooleanArray, ArrowError>,
+) -> Result<Option<ArrayRef>> {
+    let Some(dict) = array.as_any_dictionary_opt() else {
+        return Ok(None);
+    };
+    let scalar = match scalar {
+        ScalarValue::Dictionary(_, value) => value.as_ref(),
+        scalar => scalar,
+    };
+    let values = dict.values();
+    if values.data_type() != &scalar.data_type() {
+        return Ok(None);
+    }
+    let scalar = scalar.to_scalar()?;
+
+    let keys = dict.keys();
+    if keys.null_count() == 0 {
+        let result = f(values, &scalar)?;
+        return Ok(Some(take(&result, keys, None)?));
+    }
+
+    // Null keys take the result of comparing a null value, which is not null
+    // for all comparisons (e.g. `IS DISTINCT FROM`)
+    let values = concat(&[values.as_ref(), &new_null_array(values.data_type(), 1)])?;
+    let null_index = (values.len() - 1) as u64;
+    let keys = UInt64Array::from_iter_values(
+        dict.normalized_keys()
+            .into_iter()
+            .enumerate()
+            .map(|(i, key)| {
+                if keys.is_null(i) {
+                    null_index
+                } else {
+                    key as u64
+                }
+            }),
+    );
+    let result = f(&values, &scalar)?;
+    Ok(Some(take(&result, &keys, None)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::array::{DictionaryArray, Int32Array, StringArray};
+    use arrow::compute::kernels::cmp::{distinct, eq, like};
+
+    fn dictionary() -> ColumnarValue {
+        let keys = Int32Array::from(vec![Some(0), Some(1), None, Some(0), Some(2)]);
+        let values = StringArray::from(vec![Some("foo"), Some("bar"), None]);
+        let array = DictionaryArray::try_new(keys, Arc::new(values)).unwrap();
+        ColumnarValue::Array(Arc::new(array))
+    }
+
+    fn dictionary_scalar(value: &str) -> ColumnarValue {
+        ColumnarValue::Scalar(ScalarValue::Dictionary(
+            Box::new(arrow_schema::DataType::Int32),
+            Box::new(ScalarValue::from(value)),
+        ))
+    }
+
+    fn to_boolean_array(value: ColumnarValue) -> BooleanArray {
+        value.into_array(5).unwrap().as_boolean().clone()
+    }
+
+    #[test]
+    fn apply_cmp_dictionary_scalar() -> Result<()> {
+        let result = apply_cmp(&dictionary(), &dictionary_scalar("foo"), eq)?;
+        let expected =
+            BooleanArray::from(vec![Some(true), Some(false), None, Some(true), None]);
+        assert_eq!(to_boolean_array(result), expected);
+
+        let result = apply_cmp(
+            &ColumnarValue::Scalar("%a%".into()),
+            &dictionary(),
+            |l, r| like(r, l),
+        )?;
+        let expected =
+            BooleanArray::from(vec![Some(false), Some(true), None, Some(false), None]);
+        assert_eq!(to_boolean_array(result), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn apply_cmp_dictionary_null_keys() -> Result<()> {
+        let result = apply_cmp(&dictionary(), &dictionary_scalar("foo"), distinct)?;
+        let expected = BooleanArray::from(vec![false, true, true, false, true]);
+        assert_eq!(to_boolean_array(result), expected);
+        Ok(())
+    }
 }
@@ -22,6 +22,7 @@ mod binary;
 mod case;
 mod cast;
 mod column;
+mod constant;
 mod datum;
 mod in_list;
 mod is_not_null;
@@ -39,8 +40,14 @@ pub mod helpers {
 }
 
 pub use crate::aggregate::approx_distinct::ApproxDistinct;
+pub use crate::aggregate::approx_distinct_sketch::{
+    ApproxDistinctMerge, ApproxDistinctSketch,
+};
 pub use crate::aggregate::approx_median::ApproxMedian;
 pub use crate::aggregate::approx_percentile_cont::ApproxPercentileCont;
+pub use crate::aggregate::approx_percentile_cont_sketch::{
+    ApproxPercentileContMerge, ApproxPercentileContSketch,
+};
 pub use crate::aggregate::approx_percentile_cont_with_weight::ApproxPercentileContWithWeight;
 pub use crate::aggregate::array_agg::ArrayAgg;
 pub use crate::aggregate::array_agg_distinct::DistinctArrayAgg;
@@ -53,6 +60,7 @@ pub use crate::aggregate::correlation::Correlation;
 pub use crate::aggregate::count::Count;
 pub use crate::aggregate::count_distinct::DistinctCount;
 pub use crate::aggregate::covariance::{Covariance, CovariancePop};
+pub use crate::aggregate::distinct::DistinctAggregate;
 pub use crate::aggregate::grouping::Grouping;
 pub use crate::aggregate::median::Median;
 pub use crate::aggregate::min_max::{Max, Min};
@@ -61,7 +69,7 @@ pub use crate::aggregate::nth_value::NthValueAgg;
 pub use crate::aggregate::regr::{Regr, RegrType};
 pub use crate::aggregate::stats::StatsType;
 pub use crate::aggregate::stddev::{Stddev, StddevPop};
-pub use crate::aggregate::string_agg::StringAgg;
+pub use crate::aggregate::string_agg::{OrderSensitiveStringAgg, StringAgg};
 pub use crate::aggregate::sum::Sum;
 pub use crate::aggregate::sum_distinct::DistinctSum;
 pub use crate::aggregate::variance::{Variance, VariancePop};
@@ -74,6 +82,7 @@ pub use crate::window::ntile::Ntile;
 pub use crate::window::rank::{dense_rank, percent_rank, rank};
 pub use crate::window::rank::{Rank, RankType};
 pub use crate::window::row_number::RowNumber;
+pub use crate::window::session_id::SessionId;
 pub use crate::PhysicalSortExpr;
 pub use datafusion_functions_aggregate::first_last::{
     FirstValuePhysicalExpr as FirstValue, LastValuePhysicalExpr as LastValue,
@@ -83,6 +92,7 @@ pub use binary::{binary, BinaryExpr};
 pub use case::{case, CaseExpr};
 pub use cast::{cast, cast_with_options, CastExpr};
 pub use column::UnKnownColumn;
+pub use constant::{cache_constant_subtrees, reset_constants, ConstantExpr};
 pub use datafusion_expr::utils::format_state_name;
 pub use datafusion_physical_expr_common::expressions::column::{col, Column};
 pub use in_list::{in_list, InListExpr};
@@ -166,9 +166,11 @@ impl AggregateWindowExpr for PlainAggregateWindowExpr {
 
     /// For a given range, calculate accumulation result inside the range on
     /// `value_slice` and update accumulator state.
-    // We assume that `cur_range` contains `last_range` and their start points
-    // are same. In summary if `last_range` is `Range{start: a,end: b}` and
-    // `cur_range` is `Range{start: a1, end: b1}`, it is guaranteed that a1=a and b1>=b.
+    // We assume that the frame starts at the beginning of the partition and
+    // only grows. In summary if `last_range` is `Range{start: a,end: b}` and
+    // `cur_range` is `Range{start: a1, end: b1}`, it is guaranteed that b1>=b.
+    // `a` may be larger than `a1`, as the rows before the end of the last
+    // frame are pruned during stateful evaluation.
     fn get_aggregate_result_inside_range(
         &self,
         last_range: &Range<usize>,
@@ -24,6 +24,7 @@ pub(crate) mod nth_value;
 pub(crate) mod ntile;
 pub(crate) mod rank;
 pub(crate) mod row_number;
+pub(crate) mod session_id;
 mod sliding_aggregate;
 mod window_expr;
 
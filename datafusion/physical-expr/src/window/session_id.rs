@@ -0,0 +1,238 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines physical expression for `session_id` that can evaluated
+//! at runtime during query execution
+
+use crate::expressions::Column;
+use crate::window::BuiltInWindowFunctionExpr;
+use crate::{PhysicalExpr, PhysicalSortExpr};
+
+use arrow::array::{ArrayRef, UInt64Array};
+use arrow::compute::kernels::cmp::gt;
+use arrow::compute::kernels::numeric::add_wrapping;
+use arrow::datatypes::Field;
+use arrow_schema::{DataType, SchemaRef, SortOptions};
+use datafusion_common::{Result, ScalarValue};
+use datafusion_expr::PartitionEvaluator;
+
+use std::any::Any;
+use std::iter;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// SessionId assigns a session number to each row of a partition ordered by
+/// a timestamp: a new session starts whenever the gap to the previous row's
+/// timestamp exceeds a maximum gap.
+#[derive(Debug)]
+pub struct SessionId {
+    name: String,
+    /// The timestamp expression
+    expr: Arc<dyn PhysicalExpr>,
+    /// Maximum gap between two rows of the same session
+    gap: ScalarValue,
+    /// Output data type
+    data_type: DataType,
+}
+
+impl SessionId {
+    /// Create a new SESSION_ID function
+    pub fn new(
+        name: String,
+        expr: Arc<dyn PhysicalExpr>,
+        gap: ScalarValue,
+        data_type: &DataType,
+    ) -> Self {
+        Self {
+            name,
+            expr,
+            gap,
+            data_type: data_type.clone(),
+        }
+    }
+
+    /// Get the maximum gap of this session id function
+    pub fn get_gap(&self) -> &ScalarValue {
+        &self.gap
+    }
+}
+
+impl BuiltInWindowFunctionExpr for SessionId {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn field(&self) -> Result<Field> {
+        let nullable = false;
+        Ok(Field::new(self.name(), self.data_type.clone(), nullable))
+    }
+
+    fn expressions(&self) -> Vec<Arc<dyn PhysicalExpr>> {
+        vec![self.expr.clone()]
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_result_ordering(&self, schema: &SchemaRef) -> Option<PhysicalSortExpr> {
+        // The built-in SESSION_ID window function introduces a new ordering:
+        schema.column_with_name(self.name()).map(|(idx, field)| {
+            let expr = Arc::new(Column::new(field.name(), idx));
+            let options = SortOptions {
+                descending: false,
+                nulls_first: false,
+            }; // ASC, NULLS LAST
+            PhysicalSortExpr { expr, options }
+        })
+    }
+
+    fn create_evaluator(&self) -> Result<Box<dyn PartitionEvaluator>> {
+        Ok(Box::new(SessionIdEvaluator {
+            gap: self.gap.clone(),
+            prev: None,
+            session: 1,
+        }))
+    }
+}
+
+/// Evaluator of SESSION_ID. A row whose timestamp or previous row's timestamp
+/// is NULL belongs to the session of the previous row.
+#[derive(Debug)]
+pub(crate) struct SessionIdEvaluator {
+    gap: ScalarValue,
+    /// Timestamp of the previous row, used by bounded execution
+    prev: Option<ScalarValue>,
+    /// Current session number
+    session: u64,
+}
+
+impl PartitionEvaluator for SessionIdEvaluator {
+    fn is_causal(&self) -> bool {
+        // The SESSION_ID function only needs the previous row to emit results:
+        true
+    }
+
+    fn evaluate(
+        &mut self,
+        values: &[ArrayRef],
+        range: &Range<usize>,
+    ) -> Result<ScalarValue> {
+        let current = ScalarValue::try_from_array(&values[0], range.start)?;
+        if let Some(prev) = self.prev.replace(current.clone()) {
+            if !prev.is_null() && !current.is_null() && current > prev.add(&self.gap)? {
+                self.session += 1;
+            }
+        }
+        Ok(ScalarValue::UInt64(Some(self.session)))
+    }
+
+    fn evaluate_all(&mut self, values: &[ArrayRef], num_rows: usize) -> Result<ArrayRef> {
+        if num_rows == 0 {
+            return Ok(Arc::new(UInt64Array::from(Vec::<u64>::new())));
+        }
+        let timestamps = &values[0];
+        // a row starts a new session if it is later than the previous
+        // timestamp plus the gap
+        let bounds =
+            add_wrapping(&timestamps.slice(0, num_rows - 1), &self.gap.to_scalar()?)?;
+        let new_sessions = gt(&timestamps.slice(1, num_rows - 1), &bounds)?;
+
+        let mut session = 1;
+        let sessions = iter::once(session).chain(new_sessions.iter().map(|is_new| {
+            if is_new == Some(true) {
+                session += 1;
+            }
+            session
+        }));
+        Ok(Arc::new(UInt64Array::from_iter_values(sessions)))
+    }
+
+    fn supports_bounded_execution(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int64Array, TimestampSecondArray};
+    use arrow::record_batch::RecordBatch;
+    use arrow_schema::Schema;
+    use datafusion_common::cast::as_uint64_array;
+
+    fn test_session_id(
+        arr: ArrayRef,
+        gap: ScalarValue,
+        expected: Vec<u64>,
+    ) -> Result<()> {
+        let schema = Schema::new(vec![Field::new("ts", arr.data_type().clone(), true)]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![arr])?;
+        let session_id = SessionId::new(
+            "session_id".to_owned(),
+            Arc::new(Column::new("ts", 0)),
+            gap,
+            &DataType::UInt64,
+        );
+        let values = session_id.evaluate_args(&batch)?;
+
+        let result = session_id
+            .create_evaluator()?
+            .evaluate_all(&values, batch.num_rows())?;
+        assert_eq!(expected, as_uint64_array(&result)?.values().to_vec());
+
+        // bounded execution evaluates one row at a time
+        let mut evaluator = session_id.create_evaluator()?;
+        let result = (0..batch.num_rows())
+            .map(|i| evaluator.evaluate(&values, &(i..i + 1)))
+            .collect::<Result<Vec<_>>>()?;
+        let expected = expected
+            .into_iter()
+            .map(|session| ScalarValue::UInt64(Some(session)))
+            .collect::<Vec<_>>();
+        assert_eq!(expected, result);
+        Ok(())
+    }
+
+    #[test]
+    fn session_id_integers() -> Result<()> {
+        let arr: ArrayRef = Arc::new(Int64Array::from(vec![
+            Some(1),
+            Some(3),
+            Some(8),
+            None,
+            Some(20),
+            Some(25),
+            Some(26),
+        ]));
+        test_session_id(arr, ScalarValue::Int64(Some(4)), vec![1, 1, 2, 2, 2, 3, 3])
+    }
+
+    #[test]
+    fn session_id_timestamps() -> Result<()> {
+        let arr: ArrayRef =
+            Arc::new(TimestampSecondArray::from(vec![0, 600, 1800, 5400, 5401]));
+        let gap = ScalarValue::new_interval_dt(0, 30 * 60 * 1000);
+        test_session_id(arr, gap, vec![1, 1, 1, 2, 2])
+    }
+
+    #[test]
+    fn session_id_empty() -> Result<()> {
+        let arr: ArrayRef = Arc::new(Int64Array::from(Vec::<i64>::new()));
+        test_session_id(arr, ScalarValue::Int64(Some(1)), vec![])
+    }
+}
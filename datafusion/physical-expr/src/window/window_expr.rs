@@ -30,7 +30,8 @@ use arrow::record_batch::RecordBatch;
 use datafusion_common::utils::compare_rows;
 use datafusion_common::{internal_err, DataFusionError, Result, ScalarValue};
 use datafusion_expr::window_state::{
-    PartitionBatchState, WindowAggState, WindowFrameContext, WindowFrameStateGroups,
+    shift_range_value, PartitionBatchState, WindowAggState, WindowFrameContext,
+    WindowFrameStateGroups,
 };
 use datafusion_expr::{Accumulator, PartitionEvaluator, WindowFrame, WindowFrameBound};
 
@@ -215,6 +216,14 @@ pub trait AggregateWindowExpr: WindowExpr {
                 !partition_batch_state.is_end,
             )?;
             state.update(&out_col, partition_batch_state)?;
+            // The accumulator of a frame starting at UNBOUNDED PRECEDING has
+            // already consumed every row before the end of the last frame, so
+            // these rows can be pruned from the partition buffer. The last one
+            // is kept so that the frames of the following rows are not empty.
+            if self.get_window_frame().start_bound.is_unbounded() {
+                let range = &mut state.window_frame_range;
+                range.start = range.end.saturating_sub(1);
+            }
         }
         Ok(())
     }
@@ -402,12 +411,10 @@ fn is_end_bound_safe_for_range(
             let current_row_value = ScalarValue::try_from_array(orderby_col, idx)?;
 
             if sort_options.descending {
-                current_row_value
-                    .sub(delta)
+                shift_range_value(&current_row_value, delta, false)
                     .map(|value| value > most_recent_row_value)
             } else {
-                current_row_value
-                    .add(delta)
+                shift_range_value(&current_row_value, delta, true)
                     .map(|value| most_recent_row_value > value)
             }
         }
@@ -16,6 +16,7 @@
 // under the License.
 
 use crate::aggregates::group_values::GroupValues;
+use crate::hash_table::GroupIndexMap;
 use ahash::RandomState;
 use arrow::compute::cast;
 use arrow::record_batch::RecordBatch;
@@ -24,9 +25,8 @@ use arrow_array::{Array, ArrayRef};
 use arrow_schema::{DataType, SchemaRef};
 use datafusion_common::hash_utils::create_hashes;
 use datafusion_common::{DataFusionError, Result};
-use datafusion_execution::memory_pool::proxy::{RawTableAllocExt, VecAllocExt};
+use datafusion_execution::memory_pool::proxy::VecAllocExt;
 use datafusion_expr::EmitTo;
-use hashbrown::raw::RawTable;
 
 /// A [`GroupValues`] making use of [`Rows`]
 pub struct GroupValuesRows {
@@ -39,15 +39,9 @@ pub struct GroupValuesRows {
     /// Logically maps group values to a group_index in
     /// [`Self::group_values`] and in each accumulator
     ///
-    /// Uses the raw API of hashbrown to avoid actually storing the
-    /// keys (group values) in the table
-    ///
-    /// keys: u64 hashes of the GroupValue
-    /// values: (hash, group_index)
-    map: RawTable<(u64, usize)>,
-
-    /// The size of `map` in bytes
-    map_size: usize,
+    /// The keys (group values) are not stored in the map, which only
+    /// holds the u64 hashes of the GroupValue
+    map: GroupIndexMap,
 
     /// The actual group by values, stored in arrow [`Row`] format.
     /// `group_values[i]` holds the group value for group_index `i`.
@@ -59,6 +53,10 @@ pub struct GroupValuesRows {
     /// [`Row`]: arrow::row::Row
     group_values: Option<Rows>,
 
+    /// Buffer reused to store the group keys of each input batch in the row
+    /// format, to avoid reallocating them for every batch
+    rows_buffer: Rows,
+
     // buffer to be reused to store hashes
     hashes_buffer: Vec<u64>,
 
@@ -76,14 +74,13 @@ impl GroupValuesRows {
                 .collect(),
         )?;
 
-        let map = RawTable::with_capacity(0);
-
+        let rows_buffer = row_converter.empty_rows(0, 0);
         Ok(Self {
             schema,
             row_converter,
-            map,
-            map_size: 0,
+            map: GroupIndexMap::new(),
             group_values: None,
+            rows_buffer,
             hashes_buffer: Default::default(),
             random_state: Default::default(),
         })
@@ -92,9 +89,10 @@ impl GroupValuesRows {
 
 impl GroupValues for GroupValuesRows {
     fn intern(&mut self, cols: &[ArrayRef], groups: &mut Vec<usize>) -> Result<()> {
-        // Convert the group keys into the row format
-        // Avoid reallocation when https://github.com/apache/arrow-rs/issues/4479 is available
-        let group_rows = self.row_converter.convert_columns(cols)?;
+        // Convert the group keys into the row format, reusing the buffer
+        let group_rows = &mut self.rows_buffer;
+        group_rows.clear();
+        self.row_converter.append(group_rows, cols)?;
         let n_rows = group_rows.num_rows();
 
         let mut group_values = match self.group_values.take() {
@@ -112,28 +110,23 @@ impl GroupValues for GroupValuesRows {
         create_hashes(cols, &self.random_state, batch_hashes)?;
 
         for (row, &hash) in batch_hashes.iter().enumerate() {
-            let entry = self.map.get_mut(hash, |(_hash, group_idx)| {
+            let entry = self.map.get(hash, |group_idx| {
                 // verify that a group that we are inserting with hash is
                 // actually the same key value as the group in
                 // existing_idx  (aka group_values @ row)
-                group_rows.row(row) == group_values.row(*group_idx)
+                group_rows.row(row) == group_values.row(group_idx)
             });
 
             let group_idx = match entry {
                 // Existing group_index for this group value
-                Some((_hash, group_idx)) => *group_idx,
+                Some(group_idx) => group_idx,
                 //  1.2 Need to create new entry for the group
                 None => {
                     // Add new entry to aggr_state and save newly created index
                     let group_idx = group_values.num_rows();
                     group_values.push(group_rows.row(row));
 
-                    // for hasher function, use precomputed hash value
-                    self.map.insert_accounted(
-                        (hash, group_idx),
-                        |(hash, _group_index)| *hash,
-                        &mut self.map_size,
-                    );
+                    self.map.insert(hash, group_idx);
                     group_idx
                 }
             };
@@ -149,7 +142,8 @@ impl GroupValues for GroupValuesRows {
         let group_values_size = self.group_values.as_ref().map(|v| v.size()).unwrap_or(0);
         self.row_converter.size()
             + group_values_size
-            + self.map_size
+            + self.rows_buffer.size()
+            + self.map.size()
             + self.hashes_buffer.allocated_size()
     }
 
@@ -186,19 +180,7 @@ impl GroupValues for GroupValuesRows {
                     new_group_values.push(row);
                 }
                 std::mem::swap(&mut new_group_values, &mut group_values);
-
-                // SAFETY: self.map outlives iterator and is not modified concurrently
-                unsafe {
-                    for bucket in self.map.iter() {
-                        // Decrement group index by n
-                        match bucket.as_ref().1.checked_sub(n) {
-                            // Group index was >= n, shift value down
-                            Some(sub) => bucket.as_mut().1 = sub,
-                            // Group index was < n, so remove from table
-                            None => self.map.erase(bucket),
-                        }
-                    }
-                }
+                self.map.remove_first(n);
                 output
             }
         };
@@ -227,9 +209,8 @@ impl GroupValues for GroupValuesRows {
             rows.clear();
             rows
         });
-        self.map.clear();
-        self.map.shrink_to(count, |_| 0); // hasher does not matter since the map is cleared
-        self.map_size = self.map.capacity() * std::mem::size_of::<(u64, usize)>();
+        self.map.clear_shrink(count);
+        self.rows_buffer = self.row_converter.empty_rows(0, 0);
         self.hashes_buffer.clear();
         self.hashes_buffer.shrink_to(count);
     }
@@ -25,6 +25,7 @@ use crate::aggregates::{
     no_grouping::AggregateStream, row_hash::GroupedHashAggregateStream,
     topk_stream::GroupedTopKAggregateStream,
 };
+use crate::common::ConstantsScope;
 use crate::metrics::{ExecutionPlanMetricsSet, MetricsSet};
 use crate::windows::get_ordered_partition_by_indices;
 use crate::{
@@ -36,7 +37,7 @@ use arrow::array::ArrayRef;
 use arrow::datatypes::{Field, Schema, SchemaRef};
 use arrow::record_batch::RecordBatch;
 use datafusion_common::stats::Precision;
-use datafusion_common::{internal_err, not_impl_err, Result};
+use datafusion_common::{internal_err, not_impl_err, ColumnStatistics, Result};
 use datafusion_execution::TaskContext;
 use datafusion_expr::Accumulator;
 use datafusion_physical_expr::{
@@ -265,6 +266,9 @@ pub struct AggregateExec {
     required_input_ordering: Option<LexRequirement>,
     /// Describes how the input is ordered relative to the group by columns
     input_order_mode: InputOrderMode,
+    /// Executions of the plan, which reset the constants of the group by
+    /// expressions
+    constants: ConstantsScope,
     cache: PlanProperties,
 }
 
@@ -381,6 +385,7 @@ impl AggregateExec {
             required_input_ordering,
             limit: None,
             input_order_mode,
+            constants: ConstantsScope::default(),
             cache,
         })
     }
@@ -435,6 +440,14 @@ impl AggregateExec {
         partition: usize,
         context: Arc<TaskContext>,
     ) -> Result<StreamType> {
+        self.constants.enter(
+            &context,
+            self.group_by
+                .expr
+                .iter()
+                .chain(&self.group_by.null_expr)
+                .map(|(expr, _)| expr),
+        );
         // no group by at all
         if self.group_by.expr.is_empty() {
             return Ok(StreamType::AggregateStream(AggregateStream::new(
@@ -553,6 +566,28 @@ impl AggregateExec {
         PlanProperties::new(eq_properties, output_partitioning, exec_mode)
     }
 
+    /// The statistics of the output columns. The grouping columns which are
+    /// input columns keep their statistics, except for their exactness, unless
+    /// there are grouping sets, which add nulls to them.
+    fn group_by_statistics(
+        &self,
+        input_statistics: &Statistics,
+    ) -> Vec<ColumnStatistics> {
+        let mut column_statistics = Statistics::unknown_column(&self.schema());
+        if !self.group_by.is_single() {
+            return column_statistics;
+        }
+        for (idx, (expr, _)) in self.group_by.expr.iter().enumerate() {
+            if let Some(column) = expr.as_any().downcast_ref::<Column>() {
+                column_statistics[idx] = input_statistics.column_statistics
+                    [column.index()]
+                .clone()
+                .to_inexact();
+            }
+        }
+        column_statistics
+    }
+
     pub fn input_order_mode(&self) -> &InputOrderMode {
         &self.input_order_mode
     }
@@ -703,12 +738,10 @@ impl ExecutionPlan for AggregateExec {
     }
 
     fn statistics(&self) -> Result<Statistics> {
-        // TODO stats: group expressions:
-        // - once expressions will be able to compute their own stats, use it here
-        // - case where we group by on a column for which with have the `distinct` stat
         // TODO stats: aggr expression:
         // - aggregations somtimes also preserve invariants such as min, max...
-        let column_statistics = Statistics::unknown_column(&self.schema());
+        let input_statistics = self.input().statistics()?;
+        let column_statistics = self.group_by_statistics(&input_statistics);
         match self.mode {
             AggregateMode::Final | AggregateMode::FinalPartitioned
                 if self.group_by.expr.is_empty() =>
@@ -722,24 +755,36 @@ impl ExecutionPlan for AggregateExec {
             _ => {
                 // When the input row count is 0 or 1, we can adopt that statistic keeping its reliability.
                 // When it is larger than 1, we degrade the precision since it may decrease after aggregation.
-                let num_rows = if let Some(value) =
-                    self.input().statistics()?.num_rows.get_value()
+                let num_rows = if let Some(value) = input_statistics.num_rows.get_value()
                 {
                     if *value > 1 {
-                        self.input().statistics()?.num_rows.to_inexact()
+                        input_statistics.num_rows.to_inexact()
                     } else if *value == 0 {
                         // Aggregation on an empty table creates a null row.
-                        self.input()
-                            .statistics()?
-                            .num_rows
-                            .add(&Precision::Exact(1))
+                        input_statistics.num_rows.add(&Precision::Exact(1))
                     } else {
                         // num_rows = 1 case
-                        self.input().statistics()?.num_rows
+                        input_statistics.num_rows
                     }
                 } else {
                     Precision::Absent
                 };
+                // There are at most as many groups as combinations of the distinct
+                // values of the grouping columns, though every partition of a
+                // partial aggregation may produce all of them
+                let max_groups = match self.mode {
+                    AggregateMode::Partial => None,
+                    _ => max_group_count(&column_statistics[..self.group_by.expr.len()]),
+                };
+                let num_rows = match (num_rows, max_groups) {
+                    (Precision::Absent, Some(max_groups)) => {
+                        Precision::Inexact(max_groups)
+                    }
+                    (num_rows, Some(max_groups)) => {
+                        num_rows.min(&Precision::Inexact(max_groups))
+                    }
+                    (num_rows, None) => num_rows,
+                };
                 Ok(Statistics {
                     num_rows,
                     column_statistics,
@@ -1203,6 +1248,25 @@ pub(crate) fn evaluate_group_by(
         .collect())
 }
 
+/// The maximum number of groups of a grouping by columns with `column_statistics`,
+/// if the number of distinct values of all of them is known. A `NULL` group is
+/// counted for every column which may contain nulls.
+fn max_group_count(column_statistics: &[ColumnStatistics]) -> Option<usize> {
+    if column_statistics.is_empty() {
+        return None;
+    }
+    column_statistics
+        .iter()
+        .try_fold(1_usize, |count, statistics| {
+            let distinct_count = *statistics.distinct_count.get_value()?;
+            let null_groups = match statistics.null_count.get_value() {
+                Some(0) => 0,
+                _ => 1,
+            };
+            count.checked_mul(distinct_count.checked_add(null_groups)?)
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use std::any::Any;
@@ -1217,7 +1281,9 @@ mod tests {
     use crate::expressions::{col, Avg};
     use crate::memory::MemoryExec;
     use crate::test::assert_is_pending;
-    use crate::test::exec::{assert_strong_count_converges_to_zero, BlockingExec};
+    use crate::test::exec::{
+        assert_strong_count_converges_to_zero, BlockingExec, StatisticsExec,
+    };
     use crate::{
         DisplayAs, ExecutionPlan, Partitioning, RecordBatchStream,
         SendableRecordBatchStream, Statistics,
@@ -1892,6 +1958,74 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_sorted_group_by_emits_incrementally() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::UInt32, false),
+            Field::new("b", DataType::Float64, false),
+        ]));
+        // Each batch contains two groups, the second of which is
+        // continued by the next batch
+        let batches = (0..4)
+            .map(|i| {
+                RecordBatch::try_new(
+                    schema.clone(),
+                    vec![
+                        Arc::new(UInt32Array::from(vec![2 * i, 2 * i + 1, 2 * i + 1])),
+                        Arc::new(Float64Array::from(vec![1.0, 2.0, 3.0])),
+                    ],
+                )
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let sort_exprs = vec![PhysicalSortExpr {
+            expr: col("a", &schema)?,
+            options: SortOptions::default(),
+        }];
+        let input = Arc::new(
+            MemoryExec::try_new(&[batches], schema.clone(), None)?
+                .with_sort_information(vec![sort_exprs]),
+        );
+
+        let aggregate = Arc::new(AggregateExec::try_new(
+            AggregateMode::Single,
+            PhysicalGroupBy::new_single(vec![(col("a", &schema)?, "a".to_string())]),
+            vec![Arc::new(Avg::new(
+                col("b", &schema)?,
+                "AVG(b)".to_string(),
+                DataType::Float64,
+            ))],
+            vec![None],
+            input,
+            schema.clone(),
+        )?);
+        assert_eq!(aggregate.input_order_mode(), &InputOrderMode::Sorted);
+
+        let task_ctx = Arc::new(TaskContext::default());
+        let output = common::collect(aggregate.execute(0, task_ctx)?).await?;
+
+        // Groups are emitted as soon as a greater key is seen, rather than
+        // once the whole input has been consumed
+        let num_rows = output.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+        assert_eq!(num_rows, vec![1, 2, 2, 2, 1]);
+
+        let expected = [
+            "+---+--------+",
+            "| a | AVG(b) |",
+            "+---+--------+",
+            "| 0 | 1.0    |",
+            "| 1 | 2.5    |",
+            "| 2 | 1.0    |",
+            "| 3 | 2.5    |",
+            "| 4 | 1.0    |",
+            "| 5 | 2.5    |",
+            "| 6 | 1.0    |",
+            "| 7 | 2.5    |",
+            "+---+--------+",
+        ];
+        assert_batches_eq!(expected, &output);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_drop_cancel_without_groups() -> Result<()> {
         let task_ctx = Arc::new(TaskContext::default());
@@ -2235,4 +2369,62 @@ mod tests {
         assert_eq!(new_agg.schema(), aggregate_exec.schema());
         Ok(())
     }
+
+    #[test]
+    fn group_by_statistics() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, true),
+        ]);
+        let a_statistics = ColumnStatistics {
+            min_value: Precision::Exact(ScalarValue::Int32(Some(1))),
+            max_value: Precision::Exact(ScalarValue::Int32(Some(10))),
+            null_count: Precision::Exact(0),
+            distinct_count: Precision::Exact(10),
+        };
+        let input = Arc::new(StatisticsExec::new(
+            Statistics {
+                num_rows: Precision::Exact(1000),
+                total_byte_size: Precision::Absent,
+                column_statistics: vec![
+                    a_statistics.clone(),
+                    ColumnStatistics::new_unknown(),
+                ],
+            },
+            schema.clone(),
+        ));
+        let schema = Arc::new(schema);
+        let aggregates: Vec<Arc<dyn AggregateExpr>> = vec![Arc::new(Count::new(
+            lit(1i8),
+            "COUNT(1)".to_string(),
+            DataType::Int64,
+        ))];
+        let aggregate = |group_by: &[&str]| -> Result<AggregateExec> {
+            let group_by = group_by
+                .iter()
+                .map(|name| Ok((col(name, &schema)?, name.to_string())))
+                .collect::<Result<_>>()?;
+            AggregateExec::try_new(
+                AggregateMode::Single,
+                PhysicalGroupBy::new_single(group_by),
+                aggregates.clone(),
+                vec![None],
+                input.clone(),
+                schema.clone(),
+            )
+        };
+
+        // at most as many groups as distinct values of a
+        let statistics = aggregate(&["a"])?.statistics()?;
+        assert_eq!(statistics.num_rows, Precision::Inexact(10));
+        assert_eq!(
+            statistics.column_statistics,
+            vec![a_statistics.to_inexact(), ColumnStatistics::new_unknown()]
+        );
+
+        // the distinct values of b are unknown
+        let statistics = aggregate(&["a", "b"])?.statistics()?;
+        assert_eq!(statistics.num_rows, Precision::Inexact(1000));
+        Ok(())
+    }
 }
@@ -20,20 +20,23 @@
 use std::fs;
 use std::fs::{metadata, File};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 
 use super::{ExecutionPlanProperties, SendableRecordBatchStream};
 use crate::stream::RecordBatchReceiverStream;
 use crate::{ColumnStatistics, ExecutionPlan, Statistics};
 
-use arrow::datatypes::Schema;
+use arrow::datatypes::{Schema, SchemaRef};
 use arrow::ipc::writer::{FileWriter, IpcWriteOptions};
 use arrow::record_batch::RecordBatch;
+use arrow::row::{Row, RowConverter, Rows, SortField};
 use arrow_array::Array;
 use datafusion_common::stats::Precision;
-use datafusion_common::{plan_err, DataFusionError, Result};
-use datafusion_execution::memory_pool::MemoryReservation;
-use datafusion_physical_expr::expressions::{BinaryExpr, Column};
+use datafusion_common::{exec_err, plan_err, DataFusionError, Result};
+use datafusion_common_runtime::SpawnedTask;
+use datafusion_execution::memory_pool::{MemoryConsumer, MemoryPool, MemoryReservation};
+use datafusion_execution::TaskContext;
+use datafusion_physical_expr::expressions::{reset_constants, BinaryExpr, Column};
 use datafusion_physical_expr::{PhysicalExpr, PhysicalSortExpr};
 
 use futures::{StreamExt, TryStreamExt};
@@ -42,6 +45,35 @@ use parking_lot::Mutex;
 /// [`MemoryReservation`] used across query execution streams
 pub(crate) type SharedMemoryReservation = Arc<Mutex<MemoryReservation>>;
 
+/// Tracks the executions of an operator, so that the cached values of the
+/// [`ConstantExpr`]s in its expressions are computed once per execution.
+///
+/// All partitions of an execution share its [`TaskContext`], so a new
+/// execution is recognized by a new context.
+///
+/// [`ConstantExpr`]: datafusion_physical_expr::expressions::ConstantExpr
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ConstantsScope {
+    /// The context of the current execution
+    context: Arc<Mutex<Weak<TaskContext>>>,
+}
+
+impl ConstantsScope {
+    /// Resets the constants of `exprs` if `context` belongs to another
+    /// execution than the previous call
+    pub(crate) fn enter<'a>(
+        &self,
+        context: &Arc<TaskContext>,
+        exprs: impl IntoIterator<Item = &'a Arc<dyn PhysicalExpr>>,
+    ) {
+        let mut current = self.context.lock();
+        if current.as_ptr() != Arc::as_ptr(context) {
+            *current = Arc::downgrade(context);
+            exprs.into_iter().for_each(reset_constants);
+        }
+    }
+}
+
 /// Create a vector of record batches from a stream
 pub async fn collect(stream: SendableRecordBatchStream) -> Result<Vec<RecordBatch>> {
     stream.try_collect::<Vec<_>>().await
@@ -126,6 +158,104 @@ pub(crate) fn spawn_buffered(
     }
 }
 
+/// The rows of several batches, converted by [`convert_batches_parallel`]
+#[derive(Debug)]
+pub struct ParallelRows {
+    /// The rows of consecutive batches converted by each worker
+    pages: Vec<Rows>,
+    /// The index of the first row of each page, followed by the number of
+    /// rows
+    offsets: Vec<usize>,
+    /// Memory used by the pages
+    _reservation: MemoryReservation,
+}
+
+impl ParallelRows {
+    /// The rows converted by each worker, in the order of the batches
+    pub fn pages(&self) -> &[Rows] {
+        &self.pages
+    }
+
+    /// The index of the first row of each page, followed by the number of
+    /// rows
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// Number of rows of all batches
+    pub fn num_rows(&self) -> usize {
+        self.offsets.last().copied().unwrap_or(0)
+    }
+
+    /// Returns the row at `index` of all batches
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds
+    pub fn row(&self, index: usize) -> Row<'_> {
+        assert!(index < self.num_rows(), "row index {index} out of bounds");
+        let page = self.offsets.partition_point(|offset| *offset <= index) - 1;
+        self.pages[page].row(index - self.offsets[page])
+    }
+}
+
+/// Converts all columns of `batches`, whose schema is `schema`, to the row
+/// format, splitting the batches across blocking tasks that each write the
+/// rows of consecutive batches into their own page.
+///
+/// The pages are converted by the same [`RowConverter`], so rows of
+/// different pages are comparable. Their memory is reserved from `pool` for
+/// as long as the returned rows are alive.
+pub async fn convert_batches_parallel(
+    batches: Vec<RecordBatch>,
+    schema: &SchemaRef,
+    pool: &Arc<dyn MemoryPool>,
+) -> Result<ParallelRows> {
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|field| SortField::new(field.data_type().clone()))
+        .collect();
+    let converter = Arc::new(RowConverter::new(fields)?);
+
+    let workers = std::thread::available_parallelism()
+        .map_or(1, |workers| workers.get())
+        .clamp(1, batches.len().max(1));
+    let chunk_size = batches.len().div_ceil(workers).max(1);
+
+    let mut offsets = vec![0];
+    let mut tasks = vec![];
+    for chunk in batches.chunks(chunk_size) {
+        let num_rows = chunk.iter().map(|batch| batch.num_rows()).sum::<usize>();
+        offsets.push(offsets[offsets.len() - 1] + num_rows);
+        let chunk = chunk.to_vec();
+        let converter = converter.clone();
+        tasks.push(SpawnedTask::spawn_blocking(move || -> Result<Rows> {
+            let mut rows = converter.empty_rows(num_rows, 0);
+            for batch in &chunk {
+                converter.append(&mut rows, batch.columns())?;
+            }
+            Ok(rows)
+        }));
+    }
+
+    let mut reservation = MemoryConsumer::new("ParallelRows").register(pool);
+    let mut pages = Vec::with_capacity(tasks.len());
+    for task in tasks {
+        let rows = match task.join().await {
+            Ok(rows) => rows?,
+            Err(e) => return exec_err!("Error occurred while converting rows {e}"),
+        };
+        reservation.try_grow(rows.size())?;
+        pages.push(rows);
+    }
+    Ok(ParallelRows {
+        pages,
+        offsets,
+        _reservation: reservation,
+    })
+}
+
 /// Computes the statistics for an in-memory RecordBatch
 ///
 /// Only computes statistics that are in arrows metadata (num rows, byte size and nulls)
@@ -364,6 +494,7 @@ mod tests {
         datatypes::{DataType, Field, Schema},
         record_batch::RecordBatch,
     };
+    use datafusion_execution::memory_pool::UnboundedMemoryPool;
     use datafusion_expr::Operator;
     use datafusion_physical_expr::expressions::{col, Column};
 
@@ -705,6 +836,49 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_convert_batches_parallel() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::UInt64, false),
+            Field::new("b", DataType::Float64, true),
+        ]));
+        let batches = (0..5u64)
+            .map(|i| {
+                RecordBatch::try_new(
+                    schema.clone(),
+                    vec![
+                        Arc::new(UInt64Array::from(vec![i * 2, i * 2 + 1])),
+                        Arc::new(Float64Array::from(vec![Some(i as f64), None])),
+                    ],
+                )
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let pool: Arc<dyn MemoryPool> = Arc::new(UnboundedMemoryPool::default());
+
+        let rows = convert_batches_parallel(batches.clone(), &schema, &pool).await?;
+        assert_eq!(rows.num_rows(), 10);
+        assert_eq!(rows.offsets().len(), rows.pages().len() + 1);
+        assert!(pool.reserved() > 0);
+
+        // the rows are those of a single conversion, in the order of the batches
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|field| SortField::new(field.data_type().clone()))
+            .collect();
+        let converter = RowConverter::new(fields)?;
+        let concatenated = arrow::compute::concat_batches(&schema, &batches)?;
+        let expected = converter.convert_columns(concatenated.columns())?;
+        for (index, row) in expected.iter().enumerate() {
+            assert_eq!(rows.row(index), row);
+        }
+        assert!(rows.row(0) < rows.row(9));
+
+        drop(rows);
+        assert_eq!(pool.reserved(), 0);
+        Ok(())
+    }
+
     #[test]
     fn test_transpose() -> Result<()> {
         let in_data = vec![vec![1, 2, 3], vec![4, 5, 6]];
@@ -0,0 +1,444 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! DedupExec removes consecutive duplicate rows from input sorted on the
+//! deduplicated expressions, computing DISTINCT in bounded memory.
+
+use std::any::Any;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use super::{
+    DisplayAs, ExecutionPlanProperties, PlanProperties, RecordBatchStream,
+    SendableRecordBatchStream, Statistics,
+};
+use crate::windows::get_ordered_partition_by_indices;
+use crate::{
+    metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet},
+    DisplayFormatType, Distribution, ExecutionPlan,
+};
+
+use arrow::array::{ArrayRef, BooleanArray};
+use arrow::compute::filter_record_batch;
+use arrow::datatypes::{Field, Schema, SchemaRef};
+use arrow::record_batch::RecordBatch;
+use arrow::row::{OwnedRow, RowConverter, Rows, SortField};
+use datafusion_common::Result;
+use datafusion_execution::TaskContext;
+use datafusion_physical_expr::equivalence::ProjectionMapping;
+use datafusion_physical_expr::expressions::UnKnownColumn;
+use datafusion_physical_expr::{
+    LexRequirement, Partitioning, PhysicalExpr, PhysicalSortRequirement,
+};
+
+use futures::stream::{Stream, StreamExt};
+use log::trace;
+
+/// DedupExec evaluates its expressions against its input, and removes rows
+/// equal to the previous row.
+///
+/// As its input is required to be sorted on the expressions, the output
+/// contains every distinct combination of their values exactly once, like a
+/// grouped [`AggregateExec`] without aggregate expressions, but without
+/// buffering the groups in a hash table.
+///
+/// [`AggregateExec`]: crate::aggregates::AggregateExec
+#[derive(Debug)]
+pub struct DedupExec {
+    /// The expressions to deduplicate, with their output names
+    expr: Vec<(Arc<dyn PhysicalExpr>, String)>,
+    /// The input plan
+    input: Arc<dyn ExecutionPlan>,
+    /// Whether the input is hash partitioned on `expr`, rather than a single
+    /// partition
+    partitioned: bool,
+    /// The ordering required on the input
+    required_input_ordering: LexRequirement,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+    cache: PlanProperties,
+}
+
+impl DedupExec {
+    /// Create a DedupExec on an input. If `partitioned` is true, the input
+    /// must be hash partitioned on `expr`, otherwise it must be a single
+    /// partition.
+    pub fn try_new(
+        expr: Vec<(Arc<dyn PhysicalExpr>, String)>,
+        input: Arc<dyn ExecutionPlan>,
+        partitioned: bool,
+    ) -> Result<Self> {
+        let input_schema = input.schema();
+        let fields = expr
+            .iter()
+            .map(|(e, name)| {
+                Ok(Field::new(
+                    name,
+                    e.data_type(&input_schema)?,
+                    e.nullable(&input_schema)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let schema = Arc::new(Schema::new(fields));
+
+        // Any order of the expressions places equal rows next to each other,
+        // so prefer the order the input is already sorted in
+        let input_exprs = expr.iter().map(|(e, _)| e.clone()).collect::<Vec<_>>();
+        let mut indices = get_ordered_partition_by_indices(&input_exprs, &input);
+        if indices.len() != input_exprs.len() {
+            indices = (0..input_exprs.len()).collect();
+        }
+        let required_input_ordering = indices
+            .into_iter()
+            .map(|idx| PhysicalSortRequirement::new(input_exprs[idx].clone(), None))
+            .collect();
+
+        let projection_mapping = ProjectionMapping::try_new(&expr, &input_schema)?;
+        let cache = Self::compute_properties(&input, schema, &projection_mapping);
+        Ok(Self {
+            expr,
+            input,
+            partitioned,
+            required_input_ordering,
+            metrics: ExecutionPlanMetricsSet::new(),
+            cache,
+        })
+    }
+
+    /// The expressions to deduplicate, with their output names
+    pub fn expr(&self) -> &[(Arc<dyn PhysicalExpr>, String)] {
+        &self.expr
+    }
+
+    /// The input plan
+    pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.input
+    }
+
+    /// Whether the input is hash partitioned on the expressions, rather than
+    /// a single partition
+    pub fn partitioned(&self) -> bool {
+        self.partitioned
+    }
+
+    /// This function creates the cache object that stores the plan properties such as schema, equivalence properties, ordering, partitioning, etc.
+    fn compute_properties(
+        input: &Arc<dyn ExecutionPlan>,
+        schema: SchemaRef,
+        projection_mapping: &ProjectionMapping,
+    ) -> PlanProperties {
+        let input_eq_properties = input.equivalence_properties();
+        let eq_properties = input_eq_properties.project(projection_mapping, schema);
+
+        // Rows are not moved between partitions, but the partitioning
+        // expressions refer to the output columns
+        let output_partitioning = match input.output_partitioning() {
+            Partitioning::Hash(exprs, part) => {
+                let exprs = exprs
+                    .iter()
+                    .map(|expr| {
+                        input_eq_properties
+                            .project_expr(expr, projection_mapping)
+                            .unwrap_or_else(|| {
+                                Arc::new(UnKnownColumn::new(&expr.to_string()))
+                            })
+                    })
+                    .collect();
+                Partitioning::Hash(exprs, *part)
+            }
+            other => other.clone(),
+        };
+
+        PlanProperties::new(eq_properties, output_partitioning, input.execution_mode())
+    }
+}
+
+impl DisplayAs for DedupExec {
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                let expr = self
+                    .expr
+                    .iter()
+                    .map(|(e, name)| format!("{e} as {name}"))
+                    .collect::<Vec<_>>();
+                write!(f, "DedupExec: expr=[{}]", expr.join(", "))?;
+                if self.partitioned {
+                    write!(f, ", partitioned=true")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for DedupExec {
+    fn name(&self) -> &'static str {
+        "DedupExec"
+    }
+
+    /// Return a reference to Any that can be used for downcasting
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn required_input_distribution(&self) -> Vec<Distribution> {
+        if self.partitioned {
+            let exprs = self.expr.iter().map(|(e, _)| e.clone()).collect();
+            vec![Distribution::HashPartitioned(exprs)]
+        } else {
+            vec![Distribution::SinglePartition]
+        }
+    }
+
+    fn required_input_ordering(&self) -> Vec<Option<LexRequirement>> {
+        vec![Some(self.required_input_ordering.clone())]
+    }
+
+    fn maintains_input_order(&self) -> Vec<bool> {
+        vec![true]
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![self.input.clone()]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        mut children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        DedupExec::try_new(self.expr.clone(), children.swap_remove(0), self.partitioned)
+            .map(|e| Arc::new(e) as _)
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        trace!("Start DedupExec::execute for partition {} of context session_id {} and task_id {:?}", partition, context.session_id(), context.task_id());
+        let schema = self.schema();
+        let sort_fields = schema
+            .fields()
+            .iter()
+            .map(|field| SortField::new(field.data_type().clone()))
+            .collect();
+        let row_converter = RowConverter::new(sort_fields)?;
+        let rows_buffer = row_converter.empty_rows(0, 0);
+        Ok(Box::pin(DedupStream {
+            schema,
+            expr: self.expr.iter().map(|(e, _)| e.clone()).collect(),
+            input: self.input.execute(partition, context)?,
+            row_converter,
+            rows_buffer,
+            last_row: None,
+            baseline_metrics: BaselineMetrics::new(&self.metrics, partition),
+        }))
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+
+    fn statistics(&self) -> Result<Statistics> {
+        // removing duplicates keeps the value ranges of the input, but may
+        // reduce the number of rows
+        Ok(self.input.statistics()?.into_inexact())
+    }
+}
+
+/// The DedupExec stream, which remembers the last row of the previous batch
+/// to remove duplicates spanning batches
+struct DedupStream {
+    /// Output schema
+    schema: SchemaRef,
+    /// The expressions to deduplicate
+    expr: Vec<Arc<dyn PhysicalExpr>>,
+    /// The input partition to deduplicate
+    input: SendableRecordBatchStream,
+    /// Converts the evaluated expressions to comparable rows
+    row_converter: RowConverter,
+    /// Buffer reused for the rows of every batch
+    rows_buffer: Rows,
+    /// The last row emitted, if any
+    last_row: Option<OwnedRow>,
+    /// runtime metrics recording
+    baseline_metrics: BaselineMetrics,
+}
+
+impl DedupStream {
+    fn dedup_batch(&mut self, batch: &RecordBatch) -> Result<RecordBatch> {
+        let columns = self
+            .expr
+            .iter()
+            .map(|expr| expr.evaluate(batch)?.into_array(batch.num_rows()))
+            .collect::<Result<Vec<ArrayRef>>>()?;
+        let rows = &mut self.rows_buffer;
+        rows.clear();
+        self.row_converter.append(rows, &columns)?;
+
+        let mut previous = self.last_row.as_ref().map(|row| row.row());
+        let keep = rows
+            .iter()
+            .map(|row| {
+                let keep = previous != Some(row);
+                previous = Some(row);
+                keep
+            })
+            .collect::<Vec<_>>();
+        if rows.num_rows() > 0 {
+            self.last_row = Some(rows.row(rows.num_rows() - 1).owned());
+        }
+
+        let batch = RecordBatch::try_new(self.schema.clone(), columns)?;
+        Ok(filter_record_batch(&batch, &BooleanArray::from(keep))?)
+    }
+}
+
+impl Stream for DedupStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let poll;
+        loop {
+            match self.input.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(batch))) => {
+                    let timer = self.baseline_metrics.elapsed_compute().timer();
+                    let deduped_batch = self.dedup_batch(&batch)?;
+                    timer.done();
+                    // skip batches that only contain duplicates
+                    if deduped_batch.num_rows() == 0 {
+                        continue;
+                    }
+                    poll = Poll::Ready(Some(Ok(deduped_batch)));
+                    break;
+                }
+                Poll::Ready(value) => {
+                    poll = Poll::Ready(value);
+                    break;
+                }
+                Poll::Pending => {
+                    poll = Poll::Pending;
+                    break;
+                }
+            }
+        }
+        self.baseline_metrics.record_poll(poll)
+    }
+}
+
+impl RecordBatchStream for DedupStream {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collect;
+    use crate::memory::MemoryExec;
+
+    use arrow::array::{Int32Array, StringArray};
+    use arrow::compute::SortOptions;
+    use arrow::datatypes::DataType;
+    use datafusion_common::assert_batches_eq;
+    use datafusion_physical_expr::expressions::col;
+    use datafusion_physical_expr::PhysicalSortExpr;
+
+    fn batch(schema: &SchemaRef, a: Vec<i32>, b: Vec<Option<&str>>) -> RecordBatch {
+        RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(a)),
+                Arc::new(StringArray::from(b)),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn dedup_sorted_batches() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        // duplicates span batches, and a batch of duplicates only
+        let batches = vec![
+            batch(
+                &schema,
+                vec![1, 1, 1, 2],
+                vec![None, Some("x"), Some("x"), None],
+            ),
+            batch(&schema, vec![2, 2], vec![None, None]),
+            batch(
+                &schema,
+                vec![2, 3, 3],
+                vec![Some("y"), Some("y"), Some("z")],
+            ),
+        ];
+        let ordering = ["a", "b"]
+            .iter()
+            .map(|name| {
+                Ok(PhysicalSortExpr {
+                    expr: col(name, &schema)?,
+                    options: SortOptions::default(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let input = MemoryExec::try_new(&[batches], schema.clone(), None)?
+            .with_sort_information(vec![ordering]);
+
+        // the expressions may be in any order
+        let expr = vec![
+            (col("b", &schema)?, "b".to_string()),
+            (col("a", &schema)?, "a".to_string()),
+        ];
+        let dedup = DedupExec::try_new(expr, Arc::new(input), false)?;
+        let required = dedup.required_input_ordering()[0].clone().unwrap();
+        assert_eq!(required[0].expr.to_string(), "a@0");
+
+        let result = collect(Arc::new(dedup), Arc::new(TaskContext::default())).await?;
+        assert_eq!(result.len(), 2);
+        let expected = [
+            "+---+---+",
+            "| b | a |",
+            "+---+---+",
+            "|   | 1 |",
+            "| x | 1 |",
+            "|   | 2 |",
+            "| y | 2 |",
+            "| y | 3 |",
+            "| z | 3 |",
+            "+---+---+",
+        ];
+        assert_batches_eq!(expected, &result);
+        Ok(())
+    }
+}
@@ -22,9 +22,15 @@ use std::fmt;
 use std::fmt::Formatter;
 
 use super::{accept, ExecutionPlan, ExecutionPlanVisitor};
+use crate::aggregates::AggregateExec;
+use crate::joins::{CrossJoinExec, HashJoinExec, NestedLoopJoinExec};
+use crate::sorts::sort::SortExec;
+use crate::windows::WindowAggExec;
 
 use arrow_schema::SchemaRef;
 use datafusion_common::display::{GraphvizBuilder, PlanType, StringifiedPlan};
+use datafusion_common::stats::Precision;
+use datafusion_common::Result;
 use datafusion_physical_expr::{LexOrdering, PhysicalSortExpr};
 
 /// Options for controlling how each [`ExecutionPlan`] should format itself
@@ -43,6 +49,8 @@ pub struct DisplayableExecutionPlan<'a> {
     show_metrics: ShowMetrics,
     /// If statistics should be displayed
     show_statistics: bool,
+    /// If estimated cardinalities, sizes and memory should be displayed
+    show_estimates: bool,
 }
 
 impl<'a> DisplayableExecutionPlan<'a> {
@@ -53,6 +61,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
             inner,
             show_metrics: ShowMetrics::None,
             show_statistics: false,
+            show_estimates: false,
         }
     }
 
@@ -64,6 +73,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
             inner,
             show_metrics: ShowMetrics::Aggregated,
             show_statistics: false,
+            show_estimates: false,
         }
     }
 
@@ -75,6 +85,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
             inner,
             show_metrics: ShowMetrics::Full,
             show_statistics: false,
+            show_estimates: false,
         }
     }
 
@@ -84,6 +95,13 @@ impl<'a> DisplayableExecutionPlan<'a> {
         self
     }
 
+    /// Enable display of the estimated number of output rows, output bytes
+    /// and memory required by each operator, as used by `EXPLAIN ESTIMATE`
+    pub fn set_show_estimates(mut self, show_estimates: bool) -> Self {
+        self.show_estimates = show_estimates;
+        self
+    }
+
     /// Return a `format`able structure that produces a single line
     /// per node.
     ///
@@ -105,6 +123,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
             plan: &'a dyn ExecutionPlan,
             show_metrics: ShowMetrics,
             show_statistics: bool,
+            show_estimates: bool,
         }
         impl<'a> fmt::Display for Wrapper<'a> {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -114,6 +133,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
                     indent: 0,
                     show_metrics: self.show_metrics,
                     show_statistics: self.show_statistics,
+                    show_estimates: self.show_estimates,
                 };
                 accept(self.plan, &mut visitor)
             }
@@ -123,6 +143,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
             plan: self.inner,
             show_metrics: self.show_metrics,
             show_statistics: self.show_statistics,
+            show_estimates: self.show_estimates,
         }
     }
 
@@ -179,6 +200,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
             plan: &'a dyn ExecutionPlan,
             show_metrics: ShowMetrics,
             show_statistics: bool,
+            show_estimates: bool,
         }
 
         impl<'a> fmt::Display for Wrapper<'a> {
@@ -189,6 +211,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
                     indent: 0,
                     show_metrics: self.show_metrics,
                     show_statistics: self.show_statistics,
+                    show_estimates: self.show_estimates,
                 };
                 visitor.pre_visit(self.plan)?;
                 Ok(())
@@ -199,6 +222,7 @@ impl<'a> DisplayableExecutionPlan<'a> {
             plan: self.inner,
             show_metrics: self.show_metrics,
             show_statistics: self.show_statistics,
+            show_estimates: self.show_estimates,
         }
     }
 
@@ -232,6 +256,8 @@ struct IndentVisitor<'a, 'b> {
     show_metrics: ShowMetrics,
     /// If statistics should be displayed
     show_statistics: bool,
+    /// If estimated cardinalities, sizes and memory should be displayed
+    show_estimates: bool,
 }
 
 impl<'a, 'b> ExecutionPlanVisitor for IndentVisitor<'a, 'b> {
@@ -265,6 +291,18 @@ impl<'a, 'b> ExecutionPlanVisitor for IndentVisitor<'a, 'b> {
             let stats = plan.statistics().map_err(|_e| fmt::Error)?;
             write!(self.f, ", statistics=[{}]", stats)?;
         }
+        if self.show_estimates {
+            let stats = plan.statistics().map_err(|_e| fmt::Error)?;
+            write!(
+                self.f,
+                ", estimates=[rows={}, bytes={}",
+                stats.num_rows, stats.total_byte_size
+            )?;
+            if let Some(memory) = estimated_memory(plan).map_err(|_e| fmt::Error)? {
+                write!(self.f, ", memory={memory}")?;
+            }
+            write!(self.f, "]")?;
+        }
         writeln!(self.f)?;
         self.indent += 1;
         Ok(true)
@@ -375,6 +413,37 @@ impl ExecutionPlanVisitor for GraphvizVisitor<'_, '_> {
     }
 }
 
+/// Estimates the memory an operator needs to buffer its input, based on the
+/// statistics of the buffered data. Returns `None` for operators that stream
+/// their input.
+fn estimated_memory(plan: &dyn ExecutionPlan) -> Result<Option<Precision<usize>>> {
+    let any = plan.as_any();
+    let buffered = if let Some(sort) = any.downcast_ref::<SortExec>() {
+        // a sort with a fetch only keeps its top-k rows
+        match sort.fetch() {
+            Some(_) => plan.statistics()?,
+            None => sort.input().statistics()?,
+        }
+    } else if let Some(join) = any.downcast_ref::<HashJoinExec>() {
+        join.left().statistics()?
+    } else if let Some(join) = any.downcast_ref::<CrossJoinExec>() {
+        join.left().statistics()?
+    } else if let Some(join) = any.downcast_ref::<NestedLoopJoinExec>() {
+        join.left().statistics()?
+    } else if let Some(aggregate) = any.downcast_ref::<AggregateExec>() {
+        // the group values and accumulators are held until the input ends
+        if aggregate.group_expr().is_empty() {
+            return Ok(None);
+        }
+        plan.statistics()?
+    } else if let Some(window) = any.downcast_ref::<WindowAggExec>() {
+        window.input().statistics()?
+    } else {
+        return Ok(None);
+    };
+    Ok(Some(buffered.total_byte_size))
+}
+
 /// Trait for types which could have additional details when formatted in `Verbose` mode
 pub trait DisplayAs {
     /// Format according to `DisplayFormatType`, used when verbose representation looks
@@ -466,10 +535,16 @@ mod tests {
     use std::sync::Arc;
 
     use super::DisplayableExecutionPlan;
+    use crate::expressions::col;
+    use crate::sorts::sort::SortExec;
+    use crate::test::scan_partitioned;
     use crate::{DisplayAs, ExecutionPlan, PlanProperties};
 
+    use arrow_schema::SortOptions;
+
     use datafusion_common::{DataFusionError, Result, Statistics};
     use datafusion_execution::{SendableRecordBatchStream, TaskContext};
+    use datafusion_physical_expr::PhysicalSortExpr;
 
     #[derive(Debug, Clone, Copy)]
     enum TestStatsExecPlan {
@@ -572,4 +647,26 @@ mod tests {
     fn test_display_when_stats_ok_with_show_stats() {
         test_stats_display(TestStatsExecPlan::Ok, false);
     }
+
+    #[test]
+    fn test_display_estimates() -> Result<()> {
+        let input = scan_partitioned(1);
+        let schema = input.schema();
+        let sort_expr = PhysicalSortExpr {
+            expr: col("i", &schema)?,
+            options: SortOptions::default(),
+        };
+        let bytes = input.statistics()?.total_byte_size;
+        let sort = SortExec::new(vec![sort_expr], input);
+
+        let display = DisplayableExecutionPlan::new(&sort).set_show_estimates(true);
+        let expected = format!(
+            "SortExec: expr=[i@0 ASC], \
+             estimates=[rows=Exact(100), bytes={bytes}, memory={bytes}]\n  \
+             MemoryExec: partitions=1, partition_sizes=[1], \
+             estimates=[rows=Exact(100), bytes={bytes}]\n"
+        );
+        assert_eq!(display.indent(false).to_string(), expected);
+        Ok(())
+    }
 }
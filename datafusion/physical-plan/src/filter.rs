@@ -27,6 +27,7 @@ use super::{
     ColumnStatistics, DisplayAs, ExecutionPlanProperties, PlanProperties,
     RecordBatchStream, SendableRecordBatchStream, Statistics,
 };
+use crate::common::ConstantsScope;
 use crate::{
     metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet},
     DisplayFormatType, ExecutionPlan,
@@ -62,6 +63,8 @@ pub struct FilterExec {
     metrics: ExecutionPlanMetricsSet,
     /// Selectivity for statistics. 0 = no rows, 100 all rows
     default_selectivity: u8,
+    /// Executions of the plan, which reset the constants of the predicate
+    constants: ConstantsScope,
     cache: PlanProperties,
 }
 
@@ -81,6 +84,7 @@ impl FilterExec {
                     input: input.clone(),
                     metrics: ExecutionPlanMetricsSet::new(),
                     default_selectivity,
+                    constants: ConstantsScope::default(),
                     cache,
                 })
             }
@@ -269,6 +273,7 @@ impl ExecutionPlan for FilterExec {
         context: Arc<TaskContext>,
     ) -> Result<SendableRecordBatchStream> {
         trace!("Start FilterExec::execute for partition {} of context session_id {} and task_id {:?}", partition, context.session_id(), context.task_id());
+        self.constants.enter(&context, [&self.predicate]);
         let baseline_metrics = BaselineMetrics::new(&self.metrics, partition);
         Ok(Box::pin(FilterExecStream {
             schema: self.input.schema(),
@@ -434,6 +439,7 @@ pub type EqualAndNonEqual<'a> =
 #[cfg(test)]
 mod tests {
     use std::iter::Iterator;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::Arc;
 
     use super::*;
@@ -446,7 +452,11 @@ mod tests {
     use arrow::datatypes::{DataType, Field, Schema};
     use arrow_schema::{UnionFields, UnionMode};
     use datafusion_common::{ColumnStatistics, ScalarValue};
-    use datafusion_expr::Operator;
+    use datafusion_expr::{
+        ColumnarValue, Operator, ScalarFunctionDefinition, ScalarUDF, ScalarUDFImpl,
+        Signature, Volatility,
+    };
+    use datafusion_physical_expr::ScalarFunctionExpr;
 
     #[tokio::test]
     async fn collect_columns_predicates() -> Result<()> {
@@ -1128,4 +1138,72 @@ mod tests {
 
         Ok(())
     }
+
+    /// Returns its argument, counting its invocations
+    #[derive(Debug)]
+    struct CountingUDF {
+        signature: Signature,
+        invocations: Arc<AtomicUsize>,
+    }
+
+    impl ScalarUDFImpl for CountingUDF {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn name(&self) -> &str {
+            "counting"
+        }
+
+        fn signature(&self) -> &Signature {
+            &self.signature
+        }
+
+        fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+            Ok(DataType::Int32)
+        }
+
+        fn invoke(&self, args: &[ColumnarValue]) -> Result<ColumnarValue> {
+            self.invocations.fetch_add(1, Ordering::SeqCst);
+            Ok(args[0].clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn constants_evaluated_once_per_execution() -> Result<()> {
+        let input = test::scan_partitioned(2);
+        let schema = input.schema();
+        let invocations = Arc::new(AtomicUsize::new(0));
+        let udf = ScalarUDF::from(CountingUDF {
+            signature: Signature::exact(vec![DataType::Int32], Volatility::Immutable),
+            invocations: invocations.clone(),
+        });
+        let constant = Arc::new(ScalarFunctionExpr::new(
+            "counting",
+            ScalarFunctionDefinition::UDF(Arc::new(udf)),
+            vec![lit(50i32)],
+            DataType::Int32,
+            None,
+            false,
+        ));
+        let predicate = cache_constant_subtrees(binary(
+            col("i", &schema)?,
+            Operator::Lt,
+            constant,
+            &schema,
+        )?)?;
+        let filter: Arc<dyn ExecutionPlan> =
+            Arc::new(FilterExec::try_new(predicate, input)?);
+
+        for execution in 1..=3 {
+            let task_ctx = Arc::new(TaskContext::default());
+            let batches = crate::collect(filter.clone(), task_ctx).await?;
+            let num_rows: usize = batches.iter().map(|batch| batch.num_rows()).sum();
+            assert_eq!(num_rows, 100);
+            // both partitions of an execution share the value, which is
+            // computed again by the next execution
+            assert_eq!(invocations.load(Ordering::SeqCst), execution);
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,621 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Hash tables shared by the operators which match or group rows by hash:
+//! hash joins, aggregations (including `DISTINCT`) and window functions.
+//!
+//! The tables are built on the raw API of hashbrown, whose SwissTable
+//! implementation probes the control bytes of a group of buckets at once
+//! using SIMD instructions. They only store the hash of each key and the
+//! index of its row or group, so the keys themselves stay in the columnar
+//! buffers of the operators, which compare them on hash collisions.
+
+use std::fmt::{self, Debug};
+use std::mem::size_of;
+use std::ops::IndexMut;
+
+use arrow::array::{UInt32BufferBuilder, UInt64BufferBuilder};
+use datafusion_common::{DataFusionError, Result};
+use datafusion_execution::memory_pool::proxy::RawTableAllocExt;
+use hashbrown::raw::RawTable;
+
+/// Maps a `u64` hash value based on the build side ["on" values] to a list of indices with this key's value.
+///
+/// By allocating a `HashMap` with capacity for *at least* the number of rows for entries at the build side,
+/// we make sure that we don't have to re-hash the hashmap, which needs access to the key (the hash in this case) value.
+///
+/// E.g. 1 -> [3, 6, 8] indicates that the column values map to rows 3, 6 and 8 for hash value 1
+/// As the key is a hash value, we need to check possible hash collisions in the probe stage
+/// During this stage it might be the case that a row is contained the same hashmap value,
+/// but the values don't match. Those are checked in the [`equal_rows_arr`](crate::joins::hash_join::equal_rows_arr) method.
+///
+/// The indices (values) are stored in a separate chained list stored in the `Vec<u64>`.
+///
+/// The first value (+1) is stored in the hashmap, whereas the next value is stored in array at the position value.
+///
+/// The chain can be followed until the value "0" has been reached, meaning the end of the list.
+/// Also see chapter 5.3 of [Balancing vectorized query execution with bandwidth-optimized storage](https://dare.uva.nl/search?identifier=5ccbb60a-38b8-4eeb-858a-e7735dd37487)
+///
+/// # Example
+///
+/// ``` text
+/// See the example below:
+///
+/// Insert (10,1)            <-- insert hash value 10 with row index 1
+/// map:
+/// ----------
+/// | 10 | 2 |
+/// ----------
+/// next:
+/// ---------------------
+/// | 0 | 0 | 0 | 0 | 0 |
+/// ---------------------
+/// Insert (20,2)
+/// map:
+/// ----------
+/// | 10 | 2 |
+/// | 20 | 3 |
+/// ----------
+/// next:
+/// ---------------------
+/// | 0 | 0 | 0 | 0 | 0 |
+/// ---------------------
+/// Insert (10,3)           <-- collision! row index 3 has a hash value of 10 as well
+/// map:
+/// ----------
+/// | 10 | 4 |
+/// | 20 | 3 |
+/// ----------
+/// next:
+/// ---------------------
+/// | 0 | 0 | 0 | 2 | 0 |  <--- hash value 10 maps to 4,2 (which means indices values 3,1)
+/// ---------------------
+/// Insert (10,4)          <-- another collision! row index 4 ALSO has a hash value of 10
+/// map:
+/// ---------
+/// | 10 | 5 |
+/// | 20 | 3 |
+/// ---------
+/// next:
+/// ---------------------
+/// | 0 | 0 | 0 | 2 | 4 | <--- hash value 10 maps to 5,4,2 (which means indices values 4,3,1)
+/// ---------------------
+/// ```
+pub struct JoinHashMap {
+    // Stores hash value to last row index
+    map: RawTable<(u64, u64)>,
+    // Stores indices in chained list data structure
+    next: Vec<u64>,
+}
+
+impl JoinHashMap {
+    #[cfg(test)]
+    pub(crate) fn new(map: RawTable<(u64, u64)>, next: Vec<u64>) -> Self {
+        Self { map, next }
+    }
+
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        JoinHashMap {
+            map: RawTable::with_capacity(capacity),
+            next: vec![0; capacity],
+        }
+    }
+
+    /// Estimates the size in bytes of a [`JoinHashMap`] with capacity for
+    /// `num_rows` rows, prior to allocating it.
+    ///
+    /// The final size can be verified using [`Self::size`].
+    pub(crate) fn estimate_size(num_rows: usize) -> Result<usize> {
+        // For majority of cases hashbrown overestimates buckets qty to keep ~1/8 of them empty.
+        // This formula leads to overallocation for small tables (< 8 elements) but fine overall.
+        let estimated_buckets = (num_rows.checked_mul(8).ok_or_else(|| {
+            DataFusionError::Execution(
+                "usize overflow while estimating number of hasmap buckets".to_string(),
+            )
+        })? / 7)
+            .next_power_of_two();
+        // 16 bytes per `(u64, u64)`
+        // + 1 byte for each bucket
+        // + 8 bytes per row for the chained list
+        // + fixed size of JoinHashMap (RawTable + Vec)
+        Ok(16 * estimated_buckets
+            + estimated_buckets
+            + num_rows * size_of::<u64>()
+            + size_of::<JoinHashMap>())
+    }
+
+    /// The size of the allocations of the map and the chained list in bytes
+    pub fn size(&self) -> usize {
+        self.map.allocation_info().1.size() + self.next.capacity() * size_of::<u64>()
+    }
+}
+
+// Type of offsets for obtaining indices from JoinHashMap.
+pub(crate) type JoinHashMapOffset = (usize, Option<u64>);
+
+// Macro for traversing chained values with limit.
+// Early returns in case of reacing output tuples limit.
+macro_rules! chain_traverse {
+    (
+        $input_indices:ident, $match_indices:ident, $hash_values:ident, $next_chain:ident,
+        $input_idx:ident, $chain_idx:ident, $deleted_offset:ident, $remaining_output:ident
+    ) => {
+        let mut i = $chain_idx - 1;
+        loop {
+            let match_row_idx = if let Some(offset) = $deleted_offset {
+                // This arguments means that we prune the next index way before here.
+                if i < offset as u64 {
+                    // End of the list due to pruning
+                    break;
+                }
+                i - offset as u64
+            } else {
+                i
+            };
+            $match_indices.append(match_row_idx);
+            $input_indices.append($input_idx as u32);
+            $remaining_output -= 1;
+            // Follow the chain to get the next index value
+            let next = $next_chain[match_row_idx as usize];
+
+            if $remaining_output == 0 {
+                // In case current input index is the last, and no more chain values left
+                // returning None as whole input has been scanned
+                let next_offset = if $input_idx == $hash_values.len() - 1 && next == 0 {
+                    None
+                } else {
+                    Some(($input_idx, Some(next)))
+                };
+                return ($input_indices, $match_indices, next_offset);
+            }
+            if next == 0 {
+                // end of list
+                break;
+            }
+            i = next - 1;
+        }
+    };
+}
+
+// Trait defining methods that must be implemented by a hash map type to be used for joins.
+pub trait JoinHashMapType {
+    /// The type of list used to store the next list
+    type NextType: IndexMut<usize, Output = u64>;
+    /// Extend with zero
+    fn extend_zero(&mut self, len: usize);
+    /// Returns mutable references to the hash map and the next.
+    fn get_mut(&mut self) -> (&mut RawTable<(u64, u64)>, &mut Self::NextType);
+    /// Returns a reference to the hash map.
+    fn get_map(&self) -> &RawTable<(u64, u64)>;
+    /// Returns a reference to the next.
+    fn get_list(&self) -> &Self::NextType;
+
+    /// Updates hashmap from iterator of row indices & row hashes pairs.
+    fn update_from_iter<'a>(
+        &mut self,
+        iter: impl Iterator<Item = (usize, &'a u64)>,
+        deleted_offset: usize,
+    ) {
+        let (mut_map, mut_list) = self.get_mut();
+        for (row, hash_value) in iter {
+            let item = mut_map.get_mut(*hash_value, |(hash, _)| *hash_value == *hash);
+            if let Some((_, index)) = item {
+                // Already exists: add index to next array
+                let prev_index = *index;
+                // Store new value inside hashmap
+                *index = (row + 1) as u64;
+                // Update chained Vec at `row` with previous value
+                mut_list[row - deleted_offset] = prev_index;
+            } else {
+                mut_map.insert(
+                    *hash_value,
+                    // store the value + 1 as 0 value reserved for end of list
+                    (*hash_value, (row + 1) as u64),
+                    |(hash, _)| *hash,
+                );
+                // chained list at `row` is already initialized with 0
+                // meaning end of list
+            }
+        }
+    }
+
+    /// Returns all pairs of row indices matched by hash.
+    ///
+    /// This method only compares hashes, so additional further check for actual values
+    /// equality may be required.
+    fn get_matched_indices<'a>(
+        &self,
+        iter: impl Iterator<Item = (usize, &'a u64)>,
+        deleted_offset: Option<usize>,
+    ) -> (UInt32BufferBuilder, UInt64BufferBuilder) {
+        let mut input_indices = UInt32BufferBuilder::new(0);
+        let mut match_indices = UInt64BufferBuilder::new(0);
+
+        let hash_map = self.get_map();
+        let next_chain = self.get_list();
+        for (row_idx, hash_value) in iter {
+            // Get the hash and find it in the index
+            if let Some((_, index)) =
+                hash_map.get(*hash_value, |(hash, _)| *hash_value == *hash)
+            {
+                let mut i = *index - 1;
+                loop {
+                    let match_row_idx = if let Some(offset) = deleted_offset {
+                        // This arguments means that we prune the next index way before here.
+                        if i < offset as u64 {
+                            // End of the list due to pruning
+                            break;
+                        }
+                        i - offset as u64
+                    } else {
+                        i
+                    };
+                    match_indices.append(match_row_idx);
+                    input_indices.append(row_idx as u32);
+                    // Follow the chain to get the next index value
+                    let next = next_chain[match_row_idx as usize];
+                    if next == 0 {
+                        // end of list
+                        break;
+                    }
+                    i = next - 1;
+                }
+            }
+        }
+
+        (input_indices, match_indices)
+    }
+
+    /// Matches hashes with taking limit and offset into account.
+    /// Returns pairs of matched indices along with the starting point for next
+    /// matching iteration (`None` if limit has not been reached).
+    ///
+    /// This method only compares hashes, so additional further check for actual values
+    /// equality may be required.
+    fn get_matched_indices_with_limit_offset(
+        &self,
+        hash_values: &[u64],
+        deleted_offset: Option<usize>,
+        limit: usize,
+        offset: JoinHashMapOffset,
+    ) -> (
+        UInt32BufferBuilder,
+        UInt64BufferBuilder,
+        Option<JoinHashMapOffset>,
+    ) {
+        let mut input_indices = UInt32BufferBuilder::new(0);
+        let mut match_indices = UInt64BufferBuilder::new(0);
+
+        let mut remaining_output = limit;
+
+        let hash_map: &RawTable<(u64, u64)> = self.get_map();
+        let next_chain = self.get_list();
+
+        // Calculate initial `hash_values` index before iterating
+        let to_skip = match offset {
+            // None `initial_next_idx` indicates that `initial_idx` processing has'n been started
+            (initial_idx, None) => initial_idx,
+            // Zero `initial_next_idx` indicates that `initial_idx` has been processed during
+            // previous iteration, and it should be skipped
+            (initial_idx, Some(0)) => initial_idx + 1,
+            // Otherwise, process remaining `initial_idx` matches by traversing `next_chain`,
+            // to start with the next index
+            (initial_idx, Some(initial_next_idx)) => {
+                chain_traverse!(
+                    input_indices,
+                    match_indices,
+                    hash_values,
+                    next_chain,
+                    initial_idx,
+                    initial_next_idx,
+                    deleted_offset,
+                    remaining_output
+                );
+
+                initial_idx + 1
+            }
+        };
+
+        let mut row_idx = to_skip;
+        for hash_value in &hash_values[to_skip..] {
+            if let Some((_, index)) =
+                hash_map.get(*hash_value, |(hash, _)| *hash_value == *hash)
+            {
+                chain_traverse!(
+                    input_indices,
+                    match_indices,
+                    hash_values,
+                    next_chain,
+                    row_idx,
+                    index,
+                    deleted_offset,
+                    remaining_output
+                );
+            }
+            row_idx += 1;
+        }
+
+        (input_indices, match_indices, None)
+    }
+}
+
+/// Implementation of `JoinHashMapType` for `JoinHashMap`.
+impl JoinHashMapType for JoinHashMap {
+    type NextType = Vec<u64>;
+
+    // Void implementation
+    fn extend_zero(&mut self, _: usize) {}
+
+    /// Get mutable references to the hash map and the next.
+    fn get_mut(&mut self) -> (&mut RawTable<(u64, u64)>, &mut Self::NextType) {
+        (&mut self.map, &mut self.next)
+    }
+
+    /// Get a reference to the hash map.
+    fn get_map(&self) -> &RawTable<(u64, u64)> {
+        &self.map
+    }
+
+    /// Get a reference to the next.
+    fn get_list(&self) -> &Self::NextType {
+        &self.next
+    }
+}
+
+impl fmt::Debug for JoinHashMap {
+    fn fmt(&self, _f: &mut fmt::Formatter) -> fmt::Result {
+        Ok(())
+    }
+}
+
+/// Maps a `u64` hash value to the index of a group, such as the groups of
+/// an aggregation or the partitions of a window function.
+///
+/// The group keys are not stored in the map: the callers keep them in
+/// their own buffers, indexed by group, and compare them in the `is_match`
+/// closure of [`Self::get`] to resolve hash collisions.
+///
+/// The memory allocated by the map is tracked as it grows, and returned by
+/// [`Self::size`] without having to inspect the table.
+///
+/// # Example
+///
+/// ```
+/// # use datafusion_physical_plan::hash_table::GroupIndexMap;
+/// let keys = ["a", "b", "a"];
+/// // a poor hash function, to show how collisions are resolved
+/// let hash = |key: &str| key.len() as u64;
+///
+/// let mut map = GroupIndexMap::new();
+/// let mut groups = vec![];
+/// let mut group_keys: Vec<&str> = vec![];
+/// for key in keys {
+///     let group_idx = match map.get(hash(key), |idx| group_keys[idx] == key) {
+///         Some(group_idx) => group_idx,
+///         None => {
+///             group_keys.push(key);
+///             map.insert(hash(key), group_keys.len() - 1);
+///             group_keys.len() - 1
+///         }
+///     };
+///     groups.push(group_idx);
+/// }
+/// assert_eq!(groups, [0, 1, 0]);
+/// assert_eq!(map.len(), 2);
+/// ```
+pub struct GroupIndexMap {
+    /// values: (hash, group_index)
+    map: RawTable<(u64, usize)>,
+    /// The size of `map` in bytes
+    map_size: usize,
+}
+
+impl GroupIndexMap {
+    /// Creates an empty map, which does not allocate until a group is
+    /// inserted
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates an empty map with capacity for at least `capacity` groups
+    pub fn with_capacity(capacity: usize) -> Self {
+        let map = RawTable::with_capacity(capacity);
+        let map_size = map.capacity() * size_of::<(u64, usize)>();
+        Self { map, map_size }
+    }
+
+    /// Returns the index of the group with `hash` for which `is_match`
+    /// returns true, if any
+    pub fn get(
+        &self,
+        hash: u64,
+        mut is_match: impl FnMut(usize) -> bool,
+    ) -> Option<usize> {
+        self.map
+            .get(hash, |(_hash, group_idx)| is_match(*group_idx))
+            .map(|(_hash, group_idx)| *group_idx)
+    }
+
+    /// Inserts a new group with `hash`, without checking whether a
+    /// matching group already exists
+    pub fn insert(&mut self, hash: u64, group_idx: usize) {
+        // for hasher function, use precomputed hash value
+        self.map.insert_accounted(
+            (hash, group_idx),
+            |(hash, _group_idx)| *hash,
+            &mut self.map_size,
+        );
+    }
+
+    /// Removes the groups with an index smaller than `n`, and decrements the
+    /// indices of the other groups by `n`, after the first `n` groups have
+    /// been emitted
+    pub fn remove_first(&mut self, n: usize) {
+        // SAFETY: self.map outlives iterator and is not modified concurrently
+        unsafe {
+            for bucket in self.map.iter() {
+                // Decrement group index by n
+                match bucket.as_ref().1.checked_sub(n) {
+                    // Group index was >= n, shift value down
+                    Some(sub) => bucket.as_mut().1 = sub,
+                    // Group index was < n, so remove from table
+                    None => self.map.erase(bucket),
+                }
+            }
+        }
+    }
+
+    /// Removes all groups, keeping the allocated memory
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Removes all groups and shrinks the capacity of the map to
+    /// `capacity` groups
+    pub fn clear_shrink(&mut self, capacity: usize) {
+        self.map.clear();
+        self.map.shrink_to(capacity, |_| 0); // hasher does not matter since the map is cleared
+        self.map_size = self.map.capacity() * size_of::<(u64, usize)>();
+    }
+
+    /// The number of groups in the map
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns true if the map contains no groups
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// The size of the memory allocated by the map in bytes
+    pub fn size(&self) -> usize {
+        self.map_size
+    }
+}
+
+impl Default for GroupIndexMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for GroupIndexMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GroupIndexMap")
+            .field("len", &self.len())
+            .field("size", &self.size())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join_hash_map_chains_rows() {
+        let hashes = [10_u64, 20, 10, 10];
+        let mut map = JoinHashMap::with_capacity(hashes.len());
+        map.update_from_iter(hashes.iter().enumerate().rev(), 0);
+        assert_eq!(map.get_map().len(), 2);
+
+        let probe = [10_u64, 30, 20];
+        let (input_indices, match_indices) =
+            map.get_matched_indices(probe.iter().enumerate(), None);
+        assert_eq!(input_indices.as_slice(), [0, 0, 0, 2]);
+        assert_eq!(match_indices.as_slice(), [0, 2, 3, 1]);
+    }
+
+    #[test]
+    fn join_hash_map_estimate_size() -> Result<()> {
+        // small tables are allocated at least 4 buckets
+        for num_rows in [100, 1000, 10_000] {
+            let map = JoinHashMap::with_capacity(num_rows);
+            assert!(
+                map.size() <= JoinHashMap::estimate_size(num_rows)?,
+                "{num_rows}"
+            );
+        }
+        assert!(JoinHashMap::estimate_size(usize::MAX).is_err());
+        Ok(())
+    }
+
+    /// Interns `keys` into `map`, returning the group of each key
+    fn intern(
+        map: &mut GroupIndexMap,
+        group_keys: &mut Vec<u32>,
+        keys: &[u32],
+    ) -> Vec<usize> {
+        // collides for all keys with the same remainder
+        let hash = |key: u32| (key % 4) as u64;
+        keys.iter()
+            .map(
+                |&key| match map.get(hash(key), |idx| group_keys[idx] == key) {
+                    Some(group_idx) => group_idx,
+                    None => {
+                        group_keys.push(key);
+                        map.insert(hash(key), group_keys.len() - 1);
+                        group_keys.len() - 1
+                    }
+                },
+            )
+            .collect()
+    }
+
+    #[test]
+    fn group_index_map() {
+        let mut map = GroupIndexMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.size(), 0);
+
+        let mut group_keys = vec![];
+        let groups = intern(&mut map, &mut group_keys, &[1, 5, 1, 2, 5, 9]);
+        assert_eq!(groups, [0, 1, 0, 2, 1, 3]);
+        assert_eq!(map.len(), 4);
+        assert!(map.size() >= 4 * size_of::<(u64, usize)>());
+
+        // emit the first two groups
+        map.remove_first(2);
+        group_keys.drain(0..2);
+        assert_eq!(map.len(), 2);
+        let groups = intern(&mut map, &mut group_keys, &[9, 1, 2]);
+        assert_eq!(groups, [1, 2, 0]);
+
+        map.clear_shrink(0);
+        assert!(map.is_empty());
+        assert_eq!(map.size(), 0);
+    }
+
+    #[test]
+    fn group_index_map_grows_accounted() {
+        let mut map = GroupIndexMap::with_capacity(4);
+        let initial_size = map.size();
+        assert!(initial_size >= 4 * size_of::<(u64, usize)>());
+        for i in 0..100 {
+            map.insert(i, i as usize);
+        }
+        assert_eq!(map.len(), 100);
+        assert!(map.size() >= 100 * size_of::<(u64, usize)>());
+        assert_eq!(map.get(42, |idx| idx == 42), Some(42));
+        assert_eq!(map.get(42, |idx| idx == 41), None);
+
+        map.clear();
+        assert!(map.is_empty());
+        assert!(map.size() > initial_size);
+    }
+}
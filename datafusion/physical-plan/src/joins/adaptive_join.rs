@@ -0,0 +1,603 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`AdaptiveJoinExec`] hash join choosing its partition mode at execution time
+
+use std::any::Any;
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+use super::utils::OnceAsync;
+use super::{HashJoinExec, PartitionMode};
+use crate::memory::MemoryExec;
+use crate::metrics::{Count, ExecutionPlanMetricsSet, MetricBuilder, MetricsSet};
+use crate::repartition::RepartitionExec;
+use crate::stream::RecordBatchStreamAdapter;
+use crate::streaming::{PartitionStream, StreamingTableExec};
+use crate::{
+    execution_mode_from_children, DisplayAs, DisplayFormatType, ExecutionPlan,
+    ExecutionPlanProperties, Partitioning, PlanProperties, SendableRecordBatchStream,
+    Statistics,
+};
+
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+use datafusion_common::{plan_err, JoinType, Result};
+use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
+use datafusion_execution::TaskContext;
+use datafusion_physical_expr::{EquivalenceProperties, LexOrdering};
+
+use futures::{StreamExt, TryStreamExt};
+use parking_lot::Mutex;
+
+/// Hash join which chooses between [`PartitionMode::CollectLeft`] and
+/// [`PartitionMode::Partitioned`] based on the observed size of its build
+/// side, for joins whose build side has no statistics to plan with.
+///
+/// When the first partition of the output is executed, the build (left)
+/// input is read and buffered until its size exceeds the thresholds or the
+/// input is exhausted:
+///
+/// * If the whole build input fits under the thresholds, the buffered
+///   batches are joined with the probe (right) input by a [`HashJoinExec`] in
+///   `CollectLeft` mode, and no input is repartitioned.
+/// * Otherwise both inputs are hash repartitioned into as many partitions as
+///   the probe input has, and joined by a [`HashJoinExec`] in `Partitioned`
+///   mode. The buffered batches are replayed before the rest of the build
+///   input, so that no input is read twice.
+///
+/// The output has as many partitions as the probe input, with an unknown
+/// partitioning, as it depends on the chosen mode.
+#[derive(Debug)]
+pub struct AdaptiveJoinExec {
+    /// The join to execute, over the inputs before repartitioning. Its
+    /// partition mode is ignored.
+    join: Arc<HashJoinExec>,
+    /// Maximum total size in bytes of a build input collected into a single
+    /// hash table
+    threshold_byte_size: usize,
+    /// Maximum number of rows of a build input collected into a single hash
+    /// table
+    threshold_num_rows: usize,
+    /// Future that reads the build input and plans the join
+    adapted_fut: OnceAsync<AdaptedJoin>,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+    /// Cache holding plan properties like equivalences, output partitioning etc.
+    cache: PlanProperties,
+}
+
+impl AdaptiveJoinExec {
+    /// Tries to create a new [`AdaptiveJoinExec`] executing `join`, which
+    /// collects its build side when it has less than `threshold_byte_size`
+    /// bytes and less than `threshold_num_rows` rows.
+    ///
+    /// # Error
+    /// This function errors when the join type or a null-aware `join` can't
+    /// be executed in [`PartitionMode::CollectLeft`] mode.
+    pub fn try_new(
+        join: Arc<HashJoinExec>,
+        threshold_byte_size: usize,
+        threshold_num_rows: usize,
+    ) -> Result<Self> {
+        if matches!(
+            join.join_type(),
+            JoinType::Left | JoinType::Full | JoinType::LeftSemi | JoinType::LeftAnti
+        ) {
+            return plan_err!(
+                "AdaptiveJoinExec does not support {} joins",
+                join.join_type()
+            );
+        }
+        if join.null_aware() {
+            return plan_err!("AdaptiveJoinExec does not support null-aware joins");
+        }
+        let cache = Self::compute_properties(&join);
+        Ok(Self {
+            join,
+            threshold_byte_size,
+            threshold_num_rows,
+            adapted_fut: Default::default(),
+            metrics: ExecutionPlanMetricsSet::new(),
+            cache,
+        })
+    }
+
+    /// The join executed by this plan
+    pub fn join(&self) -> &Arc<HashJoinExec> {
+        &self.join
+    }
+
+    /// Maximum total size in bytes of a collected build input
+    pub fn threshold_byte_size(&self) -> usize {
+        self.threshold_byte_size
+    }
+
+    /// Maximum number of rows of a collected build input
+    pub fn threshold_num_rows(&self) -> usize {
+        self.threshold_num_rows
+    }
+
+    /// This function creates the cache object that stores the plan properties such as schema, equivalence properties, ordering, partitioning, etc.
+    fn compute_properties(join: &HashJoinExec) -> PlanProperties {
+        // The equal columns and constants of the join hold in both modes, but
+        // the probe input is only repartitioned, losing its order, in one
+        let join_eq_properties = join.equivalence_properties();
+        let mut eq_properties = EquivalenceProperties::new(join.schema())
+            .add_constants(join_eq_properties.constants().iter().cloned());
+        eq_properties.add_equivalence_group(join_eq_properties.eq_group().clone());
+
+        let partition_count = join.right().output_partitioning().partition_count();
+        PlanProperties::new(
+            eq_properties,
+            Partitioning::UnknownPartitioning(partition_count),
+            execution_mode_from_children([join.left(), join.right()]),
+        )
+    }
+}
+
+impl DisplayAs for AdaptiveJoinExec {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                let on = self
+                    .join
+                    .on()
+                    .iter()
+                    .map(|(c1, c2)| format!("({}, {})", c1, c2))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                write!(
+                    f,
+                    "AdaptiveJoinExec: join_type={:?}, on=[{}]",
+                    self.join.join_type(),
+                    on
+                )
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for AdaptiveJoinExec {
+    fn name(&self) -> &'static str {
+        "AdaptiveJoinExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![self.join.left().clone(), self.join.right().clone()]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let join = HashJoinExec::try_new(
+            children[0].clone(),
+            children[1].clone(),
+            self.join.on().to_vec(),
+            self.join.filter().cloned(),
+            self.join.join_type(),
+            self.join.projection.clone(),
+            *self.join.partition_mode(),
+            self.join.null_equals_null(),
+        )?;
+        Ok(Arc::new(AdaptiveJoinExec::try_new(
+            Arc::new(join),
+            self.threshold_byte_size,
+            self.threshold_num_rows,
+        )?))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let mut adapted_fut = self.adapted_fut.once(|| {
+            adapt_join(
+                self.join.clone(),
+                self.threshold_byte_size,
+                self.threshold_num_rows,
+                context.clone(),
+                AdaptiveJoinMetrics::new(&self.metrics),
+            )
+        });
+
+        let stream = futures::stream::once(async move {
+            let adapted =
+                futures::future::poll_fn(|cx| adapted_fut.get_shared(cx)).await?;
+            adapted.plan.execute(partition, context)
+        })
+        .try_flatten();
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            self.schema(),
+            stream,
+        )))
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+
+    fn statistics(&self) -> Result<Statistics> {
+        // the statistics of the join do not depend on how it is executed
+        self.join.statistics()
+    }
+}
+
+/// Metrics for [`AdaptiveJoinExec`]
+#[derive(Clone, Debug)]
+struct AdaptiveJoinMetrics {
+    /// Number of build input rows read before choosing the partition mode
+    observed_build_rows: Count,
+    /// 1 if the build input was collected into a single hash table
+    collected_build: Count,
+}
+
+impl AdaptiveJoinMetrics {
+    fn new(metrics: &ExecutionPlanMetricsSet) -> Self {
+        Self {
+            observed_build_rows: MetricBuilder::new(metrics)
+                .global_counter("observed_build_rows"),
+            collected_build: MetricBuilder::new(metrics)
+                .global_counter("collected_build"),
+        }
+    }
+}
+
+/// The join planned by [`AdaptiveJoinExec`] after observing its build input
+#[derive(Debug)]
+struct AdaptedJoin {
+    plan: Arc<dyn ExecutionPlan>,
+    /// Reservation for the buffered build input batches replayed into the
+    /// repartitioned build input, freed once all partitions are done with
+    /// the join. It is empty when the build input is collected, as the hash
+    /// join reserves the memory of the batches it collects.
+    _reservation: MemoryReservation,
+}
+
+/// Reads the build input of `join` until it exceeds the thresholds, and
+/// plans the join in the partition mode suited to the observed size
+async fn adapt_join(
+    join: Arc<HashJoinExec>,
+    threshold_byte_size: usize,
+    threshold_num_rows: usize,
+    context: Arc<TaskContext>,
+    metrics: AdaptiveJoinMetrics,
+) -> Result<AdaptedJoin> {
+    let left = join.left().clone();
+    let schema = left.schema();
+    let partition_count = left.output_partitioning().partition_count();
+    let mut reservation =
+        MemoryConsumer::new("AdaptiveJoinInput").register(context.memory_pool());
+
+    // The batches read from each partition, and the partially read stream
+    // once the thresholds are exceeded
+    let mut buffered: Vec<Vec<RecordBatch>> = vec![];
+    let mut partial = None;
+    let (mut num_rows, mut byte_size) = (0, 0);
+    'partitions: for partition in 0..partition_count {
+        let mut stream = left.execute(partition, context.clone())?;
+        let mut batches = vec![];
+        while let Some(batch) = stream.next().await {
+            let batch = batch?;
+            let batch_size = batch.get_array_memory_size();
+            reservation.try_grow(batch_size)?;
+            num_rows += batch.num_rows();
+            byte_size += batch_size;
+            batches.push(batch);
+            if num_rows >= threshold_num_rows || byte_size >= threshold_byte_size {
+                buffered.push(batches);
+                partial = Some(stream);
+                break 'partitions;
+            }
+        }
+        buffered.push(batches);
+    }
+    metrics.observed_build_rows.add(num_rows);
+
+    let (left, right, mode): (Arc<dyn ExecutionPlan>, Arc<dyn ExecutionPlan>, _) =
+        match partial {
+            None => {
+                metrics.collected_build.add(1);
+                // handed over to the hash join, which reserves the memory of
+                // the batches it collects into its hash table
+                reservation.free();
+                let left = MemoryExec::try_new(&[buffered.concat()], schema, None)?;
+                (
+                    Arc::new(left),
+                    join.right().clone(),
+                    PartitionMode::CollectLeft,
+                )
+            }
+            Some(partial) => {
+                // Only the last buffered partition was not read to the end
+                let last = buffered.len() - 1;
+                let mut partial = Some(partial);
+                let partitions = (0..partition_count)
+                    .map(|partition| {
+                        let batches = buffered
+                            .get_mut(partition)
+                            .map(std::mem::take)
+                            .unwrap_or_default();
+                        let rest = match partition.cmp(&last) {
+                            Ordering::Less => None,
+                            Ordering::Equal => partial.take(),
+                            Ordering::Greater => {
+                                Some(left.execute(partition, context.clone())?)
+                            }
+                        };
+                        Ok(Arc::new(ResumedPartition {
+                            schema: schema.clone(),
+                            batches,
+                            rest: Mutex::new(rest),
+                        }) as Arc<dyn PartitionStream>)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                let target_partitions =
+                    join.right().output_partitioning().partition_count();
+                let (left_on, right_on) = join.on().iter().cloned().unzip();
+                let left = StreamingTableExec::try_new(
+                    schema,
+                    partitions,
+                    None,
+                    Vec::<LexOrdering>::new(),
+                    false,
+                )?;
+                let left = RepartitionExec::try_new(
+                    Arc::new(left),
+                    Partitioning::Hash(left_on, target_partitions),
+                )?;
+                let right = RepartitionExec::try_new(
+                    join.right().clone(),
+                    Partitioning::Hash(right_on, target_partitions),
+                )?;
+                (Arc::new(left), Arc::new(right), PartitionMode::Partitioned)
+            }
+        };
+
+    let plan = HashJoinExec::try_new(
+        left,
+        right,
+        join.on().to_vec(),
+        join.filter().cloned(),
+        join.join_type(),
+        join.projection.clone(),
+        mode,
+        join.null_equals_null(),
+    )?;
+    Ok(AdaptedJoin {
+        plan: Arc::new(plan),
+        _reservation: reservation,
+    })
+}
+
+/// A partition of the build input of an [`AdaptiveJoinExec`], whose first
+/// batches were buffered while observing the input size
+struct ResumedPartition {
+    schema: SchemaRef,
+    /// The batches read before choosing the partition mode
+    batches: Vec<RecordBatch>,
+    /// The stream of the rest of the partition, if it was not read to the end
+    rest: Mutex<Option<SendableRecordBatchStream>>,
+}
+
+impl PartitionStream for ResumedPartition {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let buffered = futures::stream::iter(self.batches.clone().into_iter().map(Ok));
+        let stream = match self.rest.lock().take() {
+            Some(rest) => buffered.chain(rest).boxed(),
+            None => buffered.boxed(),
+        };
+        Box::pin(RecordBatchStreamAdapter::new(self.schema.clone(), stream))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{collect, memory::MemoryExec, test::build_table_i32};
+
+    use datafusion_common::assert_batches_sorted_eq;
+    use datafusion_physical_expr::expressions::Column;
+    use datafusion_physical_expr::PhysicalExprRef;
+
+    /// A table with one batch per partition
+    fn table(
+        partitions: Vec<(Vec<i32>, Vec<i32>)>,
+        names: [&str; 3],
+    ) -> Arc<dyn ExecutionPlan> {
+        let partitions = partitions
+            .into_iter()
+            .map(|(a, b)| {
+                let c = vec![0; a.len()];
+                vec![build_table_i32(
+                    (names[0], &a),
+                    (names[1], &b),
+                    (names[2], &c),
+                )]
+            })
+            .collect::<Vec<_>>();
+        let schema = partitions[0][0].schema();
+        Arc::new(MemoryExec::try_new(&partitions, schema, None).unwrap())
+    }
+
+    fn adaptive_join(threshold_num_rows: usize) -> Result<AdaptiveJoinExec> {
+        let left = table(
+            vec![(vec![1, 2], vec![10, 20]), (vec![3, 4], vec![30, 40])],
+            ["a1", "b1", "c1"],
+        );
+        let right = table(
+            vec![
+                (vec![1, 3], vec![100, 300]),
+                (vec![2, 5], vec![200, 500]),
+                (vec![4, 4], vec![400, 401]),
+            ],
+            ["a2", "b2", "c2"],
+        );
+        let on: Vec<(PhysicalExprRef, PhysicalExprRef)> = vec![(
+            Arc::new(Column::new_with_schema("a1", &left.schema())?),
+            Arc::new(Column::new_with_schema("a2", &right.schema())?),
+        )];
+        let join = HashJoinExec::try_new(
+            left,
+            right,
+            on,
+            None,
+            &JoinType::Inner,
+            Some(vec![0, 1, 4]),
+            PartitionMode::Auto,
+            false,
+        )?;
+        AdaptiveJoinExec::try_new(Arc::new(join), usize::MAX, threshold_num_rows)
+    }
+
+    fn metric(join: &AdaptiveJoinExec, name: &str) -> usize {
+        join.metrics()
+            .unwrap()
+            .sum_by_name(name)
+            .map(|m| m.as_usize())
+            .unwrap()
+    }
+
+    async fn assert_joined(join: Arc<AdaptiveJoinExec>) -> Result<()> {
+        assert_eq!(join.output_partitioning().partition_count(), 3);
+        let batches = collect(join.clone(), Arc::new(TaskContext::default())).await?;
+        let expected = [
+            "+----+-----+-----+",
+            "| a1 | b1  | b2  |",
+            "+----+-----+-----+",
+            "| 1  | 10  | 100 |",
+            "| 2  | 20  | 200 |",
+            "| 3  | 30  | 300 |",
+            "| 4  | 40  | 400 |",
+            "| 4  | 40  | 401 |",
+            "+----+-----+-----+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn collect_small_build_input() -> Result<()> {
+        let join = Arc::new(adaptive_join(100)?);
+        assert_joined(join.clone()).await?;
+        assert_eq!(metric(&join, "observed_build_rows"), 4);
+        assert_eq!(metric(&join, "collected_build"), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn repartition_large_build_input() -> Result<()> {
+        let join = Arc::new(adaptive_join(2)?);
+        assert_joined(join.clone()).await?;
+        assert_eq!(metric(&join, "observed_build_rows"), 2);
+        assert_eq!(metric(&join, "collected_build"), 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn release_collected_build_input() -> Result<()> {
+        for (threshold_num_rows, reserved) in [(100, false), (2, true)] {
+            let join = adaptive_join(threshold_num_rows)?;
+            let adapted = adapt_join(
+                join.join().clone(),
+                join.threshold_byte_size(),
+                join.threshold_num_rows(),
+                Arc::new(TaskContext::default()),
+                AdaptiveJoinMetrics::new(&join.metrics),
+            )
+            .await?;
+            // only the batches replayed into the repartitioned build input
+            // stay reserved
+            assert_eq!(adapted._reservation.size() > 0, reserved);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn keep_join_equivalences() -> Result<()> {
+        let join = adaptive_join(2)?;
+        let join = join.join();
+        // the join keys are not projected away
+        let join = HashJoinExec::try_new(
+            join.left().clone(),
+            join.right().clone(),
+            join.on().to_vec(),
+            None,
+            &JoinType::Inner,
+            None,
+            PartitionMode::Auto,
+            false,
+        )?;
+        let adaptive = AdaptiveJoinExec::try_new(Arc::new(join), 0, 0)?;
+
+        let expected = adaptive.join().equivalence_properties().eq_group();
+        assert!(!expected.is_empty());
+        assert_eq!(
+            adaptive
+                .equivalence_properties()
+                .eq_group()
+                .iter()
+                .collect::<Vec<_>>(),
+            expected.iter().collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn reject_left_joins() -> Result<()> {
+        let join = adaptive_join(2)?;
+        let join = join.join();
+        for join_type in [
+            JoinType::Left,
+            JoinType::Full,
+            JoinType::LeftSemi,
+            JoinType::LeftAnti,
+        ] {
+            let left_join = HashJoinExec::try_new(
+                join.left().clone(),
+                join.right().clone(),
+                join.on().to_vec(),
+                None,
+                &join_type,
+                None,
+                PartitionMode::Auto,
+                false,
+            )?;
+            assert!(
+                AdaptiveJoinExec::try_new(Arc::new(left_join), 0, 0).is_err(),
+                "{join_type}"
+            );
+        }
+        Ok(())
+    }
+}
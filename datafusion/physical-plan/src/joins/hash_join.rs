@@ -18,7 +18,6 @@
 //! [`HashJoinExec`] Partitioned Hash Join Operator
 
 use std::fmt;
-use std::mem::size_of;
 use std::sync::Arc;
 use std::task::Poll;
 use std::{any::Any, usize, vec};
@@ -52,7 +51,7 @@ use arrow::array::{
     UInt64Array,
 };
 use arrow::compute::kernels::cmp::{eq, not_distinct};
-use arrow::compute::{and, concat_batches, take, FilterBuilder};
+use arrow::compute::{and, concat_batches, is_not_null, take, FilterBuilder};
 use arrow::datatypes::{Schema, SchemaRef};
 use arrow::record_batch::RecordBatch;
 use arrow::util::bit_util;
@@ -60,7 +59,7 @@ use arrow_array::cast::downcast_array;
 use arrow_schema::ArrowError;
 use datafusion_common::{
     internal_datafusion_err, internal_err, plan_err, project_schema, DataFusionError,
-    JoinSide, JoinType, Result,
+    JoinSide, JoinStrategyHint, JoinType, Result,
 };
 use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
 use datafusion_execution::TaskContext;
@@ -74,7 +73,7 @@ use ahash::RandomState;
 use futures::{ready, Stream, StreamExt, TryStreamExt};
 
 /// HashTable and input data for the left (build side) of a join
-struct JoinLeftData {
+pub(crate) struct JoinLeftData {
     /// The hash table with indices into `batch`
     hash_map: JoinHashMap,
     /// The input rows for the build side
@@ -105,12 +104,12 @@ impl JoinLeftData {
     }
 
     /// return a reference to the hash map
-    fn hash_map(&self) -> &JoinHashMap {
+    pub(crate) fn hash_map(&self) -> &JoinHashMap {
         &self.hash_map
     }
 
     /// returns a reference to the build side batch
-    fn batch(&self) -> &RecordBatch {
+    pub(crate) fn batch(&self) -> &RecordBatch {
         &self.batch
     }
 }
@@ -304,6 +303,15 @@ pub struct HashJoinExec {
     /// Otherwise, rows that have `null`s in the join columns will not be
     /// matched and thus will not appear in the output.
     pub null_equals_null: bool,
+    /// Join strategy requested by an optimizer hint. Join selection honors
+    /// the hint instead of choosing the partition mode and build side from
+    /// statistics.
+    pub strategy_hint: Option<JoinStrategyHint>,
+    /// If true, this is a null-aware [`JoinType::RightAnti`] join evaluating
+    /// `right_key NOT IN (SELECT left_key ...)` with SQL three-valued logic:
+    /// no row is returned once the build side contains a null key, and probe
+    /// rows with a null key are only returned when the build side is empty.
+    pub null_aware: bool,
     /// Cache holding plan properties like equivalences, output partitioning etc.
     cache: PlanProperties,
 }
@@ -366,10 +374,32 @@ impl HashJoinExec {
             projection,
             column_indices,
             null_equals_null,
+            strategy_hint: None,
+            null_aware: false,
             cache,
         })
     }
 
+    /// Return new instance of [HashJoinExec] with the given join strategy hint.
+    pub fn with_strategy_hint(mut self, strategy_hint: Option<JoinStrategyHint>) -> Self {
+        self.strategy_hint = strategy_hint;
+        self
+    }
+
+    /// Return new instance of [HashJoinExec] that is null-aware or not.
+    ///
+    /// # Error
+    /// A null-aware join must be a [`JoinType::RightAnti`] join on a single key.
+    pub fn with_null_aware(mut self, null_aware: bool) -> Result<Self> {
+        if null_aware && (self.join_type != JoinType::RightAnti || self.on.len() != 1) {
+            return plan_err!(
+                "Null-aware HashJoinExec requires a RightAnti join on a single key"
+            );
+        }
+        self.null_aware = null_aware;
+        Ok(self)
+    }
+
     /// left (build) side which gets hashed
     pub fn left(&self) -> &Arc<dyn ExecutionPlan> {
         &self.left
@@ -405,13 +435,26 @@ impl HashJoinExec {
         self.null_equals_null
     }
 
+    /// The join strategy requested by an optimizer hint, if any
+    pub fn strategy_hint(&self) -> Option<JoinStrategyHint> {
+        self.strategy_hint
+    }
+
+    /// Get null_aware
+    pub fn null_aware(&self) -> bool {
+        self.null_aware
+    }
+
     /// Calculate order preservation flags for this hash join.
     fn maintains_input_order(join_type: JoinType) -> Vec<bool> {
         vec![
             false,
             matches!(
                 join_type,
-                JoinType::Inner | JoinType::RightAnti | JoinType::RightSemi
+                JoinType::Inner
+                    | JoinType::Right
+                    | JoinType::RightAnti
+                    | JoinType::RightSemi
             ),
         ]
     }
@@ -448,6 +491,8 @@ impl HashJoinExec {
             self.mode,
             self.null_equals_null,
         )
+        .map(|join| join.with_strategy_hint(self.strategy_hint))?
+        .with_null_aware(self.null_aware)
     }
 
     /// This function creates the cache object that stores the plan properties such as schema, equivalence properties, ordering, partitioning, etc.
@@ -581,10 +626,20 @@ impl DisplayAs for HashJoinExec {
                     .map(|(c1, c2)| format!("({}, {})", c1, c2))
                     .collect::<Vec<String>>()
                     .join(", ");
+                let display_null_aware = if self.null_aware {
+                    ", null_aware=true"
+                } else {
+                    ""
+                };
                 write!(
                     f,
-                    "HashJoinExec: mode={:?}, join_type={:?}, on=[{}]{}{}",
-                    self.mode, self.join_type, on, display_filter, display_projections
+                    "HashJoinExec: mode={:?}, join_type={:?}, on=[{}]{}{}{}",
+                    self.mode,
+                    self.join_type,
+                    on,
+                    display_filter,
+                    display_projections,
+                    display_null_aware
                 )
             }
         }
@@ -672,16 +727,20 @@ impl ExecutionPlan for HashJoinExec {
         self: Arc<Self>,
         children: Vec<Arc<dyn ExecutionPlan>>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
-        Ok(Arc::new(HashJoinExec::try_new(
-            children[0].clone(),
-            children[1].clone(),
-            self.on.clone(),
-            self.filter.clone(),
-            &self.join_type,
-            self.projection.clone(),
-            self.mode,
-            self.null_equals_null,
-        )?))
+        Ok(Arc::new(
+            HashJoinExec::try_new(
+                children[0].clone(),
+                children[1].clone(),
+                self.on.clone(),
+                self.filter.clone(),
+                &self.join_type,
+                self.projection.clone(),
+                self.mode,
+                self.null_equals_null,
+            )?
+            .with_strategy_hint(self.strategy_hint)
+            .with_null_aware(self.null_aware)?,
+        ))
     }
 
     fn execute(
@@ -702,6 +761,12 @@ impl ExecutionPlan for HashJoinExec {
             );
         }
 
+        if self.null_aware && self.mode != PartitionMode::CollectLeft {
+            return internal_err!(
+                "Invalid HashJoinExec, null-aware join requires PartitionMode::CollectLeft"
+            );
+        }
+
         let join_metrics = BuildProbeJoinMetrics::new(partition, &self.metrics);
         let left_fut = match self.mode {
             PartitionMode::CollectLeft => self.left_fut.once(|| {
@@ -769,6 +834,7 @@ impl ExecutionPlan for HashJoinExec {
             random_state: self.random_state.clone(),
             join_metrics,
             null_equals_null: self.null_equals_null,
+            null_aware: self.null_aware,
             reservation,
             state: HashJoinStreamState::WaitBuildSide,
             build_side: BuildSide::Initial(BuildSideInitialState { left_fut }),
@@ -808,7 +874,7 @@ impl ExecutionPlan for HashJoinExec {
 
 /// Reads the left (build) side of the input, buffering it in memory, to build a
 /// hash table (`LeftJoinData`)
-async fn collect_left_input(
+pub(crate) async fn collect_left_input(
     partition: Option<usize>,
     random_state: RandomState,
     left: Arc<dyn ExecutionPlan>,
@@ -852,21 +918,8 @@ async fn collect_left_input(
         .await?;
 
     // Estimation of memory size, required for hashtable, prior to allocation.
-    // Final result can be verified using `RawTable.allocation_info()`
-    //
-    // For majority of cases hashbrown overestimates buckets qty to keep ~1/8 of them empty.
-    // This formula leads to overallocation for small tables (< 8 elements) but fine overall.
-    let estimated_buckets = (num_rows.checked_mul(8).ok_or_else(|| {
-        DataFusionError::Execution(
-            "usize overflow while estimating number of hasmap buckets".to_string(),
-        )
-    })? / 7)
-        .next_power_of_two();
-    // 16 bytes per `(u64, u64)`
-    // + 1 byte for each bucket
-    // + fixed size of JoinHashMap (RawTable + Vec)
-    let estimated_hastable_size =
-        16 * estimated_buckets + estimated_buckets + size_of::<JoinHashMap>();
+    // Final result can be verified using `JoinHashMap::size()`
+    let estimated_hastable_size = JoinHashMap::estimate_size(num_rows)?;
 
     reservation.try_grow(estimated_hastable_size)?;
     metrics.build_mem_used.add(estimated_hastable_size);
@@ -969,6 +1022,9 @@ struct BuildSideReadyState {
     /// For some OUTER joins, we need to know which rows have not been matched
     /// to produce the correct output.
     visited_left_side: BooleanBufferBuilder,
+    /// Whether any build-side row has a null join key. Only computed for
+    /// null-aware joins.
+    left_has_null_keys: bool,
 }
 
 impl BuildSide {
@@ -1087,6 +1143,8 @@ struct HashJoinStream {
     column_indices: Vec<ColumnIndex>,
     /// If null_equals_null is true, null == null else null != null
     null_equals_null: bool,
+    /// If true, the anti join follows `NOT IN` semantics for null keys
+    null_aware: bool,
     /// Memory reservation
     reservation: MemoryReservation,
     /// State of the stream
@@ -1196,6 +1254,52 @@ fn lookup_join_hashmap(
     Ok((build_indices, probe_indices, next_offset))
 }
 
+/// Returns true if any row of `batch` has a null value in one of the join
+/// keys `on`
+fn has_null_keys(batch: &RecordBatch, on: &[PhysicalExprRef]) -> Result<bool> {
+    for expr in on {
+        if expr
+            .evaluate(batch)?
+            .into_array(batch.num_rows())?
+            .null_count()
+            > 0
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Removes the `probe_indices` of the unmatched probe rows of a `RightAnti`
+/// join whose join key is null.
+///
+/// The build indices of a `RightAnti` join are those of the matched rows, and
+/// are not aligned with `probe_indices`. As only the probe side is output, no
+/// build indices are returned.
+fn remove_null_probe_keys(
+    probe_batch: &RecordBatch,
+    probe_on: &[PhysicalExprRef],
+    probe_indices: UInt32Array,
+) -> Result<(UInt64Array, UInt32Array)> {
+    let mut not_null = BooleanArray::from(vec![true; probe_indices.len()]);
+    for expr in probe_on {
+        let keys = expr
+            .evaluate(probe_batch)?
+            .into_array(probe_batch.num_rows())?;
+        if keys.null_count() > 0 {
+            let keys = take(keys.as_ref(), &probe_indices, None)?;
+            not_null = and(&not_null, &is_not_null(keys.as_ref())?)?;
+        }
+    }
+
+    let filter_builder = FilterBuilder::new(&not_null).optimize().build();
+    let probe_filtered = filter_builder.filter(&probe_indices)?;
+    Ok((
+        UInt64Array::from(Vec::<u64>::new()),
+        downcast_array(probe_filtered.as_ref()),
+    ))
+}
+
 // version of eq_dyn supporting equality on null arrays
 fn eq_dyn_null(
     left: &dyn Array,
@@ -1316,10 +1420,17 @@ impl HashJoinStream {
             BooleanBufferBuilder::new(0)
         };
 
+        let left_has_null_keys = if self.null_aware {
+            has_null_keys(left_data.batch(), &self.on_left)?
+        } else {
+            false
+        };
+
         self.state = HashJoinStreamState::FetchProbeBatch;
         self.build_side = BuildSide::Ready(BuildSideReadyState {
             left_data,
             visited_left_side,
+            left_has_null_keys,
         });
 
         Poll::Ready(Ok(StatefulStreamResult::Continue))
@@ -1448,6 +1559,21 @@ impl HashJoinStream {
             self.join_type,
         );
 
+        // `NOT IN` is null rather than true for probe rows with a null key, and
+        // for all probe rows once the build side has a null key, unless the
+        // build side is empty
+        let (left_indices, right_indices) =
+            if !self.null_aware || build_side.left_data.num_rows() == 0 {
+                (left_indices, right_indices)
+            } else if build_side.left_has_null_keys {
+                (
+                    UInt64Array::from(Vec::<u64>::new()),
+                    UInt32Array::from(Vec::<u32>::new()),
+                )
+            } else {
+                remove_null_probe_keys(&state.batch, &self.on_right, right_indices)?
+            };
+
         let result = build_batch_from_indices(
             &self.schema,
             build_side.left_data.batch(),
@@ -2793,6 +2919,102 @@ mod tests {
         Ok(())
     }
 
+    fn build_nullable_table(
+        name: &str,
+        values: Vec<Option<i32>>,
+    ) -> Arc<dyn ExecutionPlan> {
+        let schema = Arc::new(Schema::new(vec![Field::new(name, DataType::Int32, true)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(values))],
+        )
+        .unwrap();
+        Arc::new(MemoryExec::try_new(&[vec![batch]], schema, None).unwrap())
+    }
+
+    async fn null_aware_anti_join_collect(
+        left: Arc<dyn ExecutionPlan>,
+        right: Arc<dyn ExecutionPlan>,
+    ) -> Result<Vec<RecordBatch>> {
+        let on = vec![(
+            Arc::new(Column::new_with_schema("a", &left.schema())?) as _,
+            Arc::new(Column::new_with_schema("b", &right.schema())?) as _,
+        )];
+        let join =
+            join(left, right, on, &JoinType::RightAnti, false)?.with_null_aware(true)?;
+        let stream = join.execute(0, Arc::new(TaskContext::default()))?;
+        common::collect(stream).await
+    }
+
+    #[tokio::test]
+    async fn join_right_anti_null_aware() -> Result<()> {
+        let probe = || build_nullable_table("b", vec![Some(1), None, Some(3), Some(4)]);
+
+        // probe rows with a null key are not returned
+        let left = build_nullable_table("a", vec![Some(1), Some(2)]);
+        let batches = null_aware_anti_join_collect(left, probe()).await?;
+        let expected = ["+---+", "| b |", "+---+", "| 3 |", "| 4 |", "+---+"];
+        assert_batches_sorted_eq!(expected, &batches);
+
+        // no row is returned once the build side has a null key
+        let left = build_nullable_table("a", vec![Some(1), None]);
+        let batches = null_aware_anti_join_collect(left, probe()).await?;
+        assert!(batches.iter().all(|batch| batch.num_rows() == 0));
+
+        // all rows are returned when the build side is empty
+        let left = build_nullable_table("a", vec![]);
+        let batches = null_aware_anti_join_collect(left, probe()).await?;
+        let expected = [
+            "+---+", "| b |", "+---+", "|   |", "| 1 |", "| 3 |", "| 4 |", "+---+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn join_right_anti_null_aware_more_unmatched_than_matched() -> Result<()> {
+        // one matched probe row, and many unmatched ones including null keys
+        let left = build_nullable_table("a", vec![Some(1), Some(100)]);
+        let right = build_nullable_table(
+            "b",
+            vec![
+                Some(1),
+                Some(2),
+                None,
+                Some(3),
+                Some(4),
+                None,
+                Some(5),
+                Some(6),
+            ],
+        );
+        let batches = null_aware_anti_join_collect(left, right).await?;
+        let expected = [
+            "+---+", "| b |", "+---+", "| 2 |", "| 3 |", "| 4 |", "| 5 |", "| 6 |",
+            "+---+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+        Ok(())
+    }
+
+    #[test]
+    fn join_null_aware_requires_right_anti() -> Result<()> {
+        let left = build_nullable_table("a", vec![Some(1)]);
+        let right = build_nullable_table("b", vec![Some(1)]);
+        let on = vec![(
+            Arc::new(Column::new_with_schema("a", &left.schema())?) as _,
+            Arc::new(Column::new_with_schema("b", &right.schema())?) as _,
+        )];
+        let err = join(left, right, on, &JoinType::LeftAnti, false)?
+            .with_null_aware(true)
+            .unwrap_err();
+        assert_contains!(
+            err.to_string(),
+            "Null-aware HashJoinExec requires a RightAnti join on a single key"
+        );
+        Ok(())
+    }
+
     #[apply(batch_sizes)]
     #[tokio::test]
     async fn join_right_anti_with_filter(batch_size: usize) -> Result<()> {
@@ -2933,6 +3155,44 @@ mod tests {
         Ok(())
     }
 
+    #[apply(batch_sizes)]
+    #[tokio::test]
+    async fn join_right_preserves_probe_order(batch_size: usize) -> Result<()> {
+        let task_ctx = prepare_task_ctx(batch_size);
+        let left = build_table(
+            ("a1", &vec![1, 2, 3]),
+            ("b1", &vec![4, 5, 7]),
+            ("c1", &vec![7, 8, 9]),
+        );
+        let right = build_table(
+            ("a2", &vec![10, 20, 30, 40]),
+            ("b1", &vec![3, 4, 6, 5]), // 3 and 6 do not exist on the left
+            ("c2", &vec![70, 80, 90, 100]),
+        );
+        let on = vec![(
+            Arc::new(Column::new_with_schema("b1", &left.schema())?) as _,
+            Arc::new(Column::new_with_schema("b1", &right.schema())?) as _,
+        )];
+
+        let (_, batches) =
+            join_collect(left, right, on, &JoinType::Right, false, task_ctx).await?;
+
+        // Unmatched right rows are produced in the order of the right side
+        let expected = [
+            "+----+----+----+----+----+-----+",
+            "| a1 | b1 | c1 | a2 | b1 | c2  |",
+            "+----+----+----+----+----+-----+",
+            "|    |    |    | 10 | 3  | 70  |",
+            "| 1  | 4  | 7  | 20 | 4  | 80  |",
+            "|    |    |    | 30 | 6  | 90  |",
+            "| 2  | 5  | 8  | 40 | 5  | 100 |",
+            "+----+----+----+----+----+-----+",
+        ];
+        assert_batches_eq!(expected, &batches);
+
+        Ok(())
+    }
+
     #[apply(batch_sizes)]
     #[tokio::test]
     async fn partitioned_join_right_one(batch_size: usize) -> Result<()> {
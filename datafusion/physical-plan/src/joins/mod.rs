@@ -17,14 +17,18 @@
 
 //! DataFusion Join implementations
 
+pub use adaptive_join::AdaptiveJoinExec;
 pub use cross_join::CrossJoinExec;
 pub use hash_join::HashJoinExec;
+pub use multi_hash_join::MultiHashJoinExec;
 pub use nested_loop_join::NestedLoopJoinExec;
 // Note: SortMergeJoin is not used in plans yet
 pub use sort_merge_join::SortMergeJoinExec;
 pub use symmetric_hash_join::SymmetricHashJoinExec;
+mod adaptive_join;
 mod cross_join;
 mod hash_join;
+mod multi_hash_join;
 mod nested_loop_join;
 mod sort_merge_join;
 mod stream_join_utils;
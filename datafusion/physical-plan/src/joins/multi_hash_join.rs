@@ -0,0 +1,658 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`MultiHashJoinExec`] multi-way inner hash join operator
+
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+use std::task::Poll;
+
+use super::hash_join::{collect_left_input, equal_rows_arr, JoinLeftData};
+use super::utils::{
+    adjust_right_output_partitioning, build_join_schema, check_join_is_valid,
+    estimate_join_statistics_from_inputs, BuildProbeJoinMetrics, JoinHashMapType, JoinOn,
+    OnceAsync, OnceFut,
+};
+use crate::hash_utils::create_hashes;
+use crate::metrics::{ExecutionPlanMetricsSet, MetricsSet};
+use crate::{
+    execution_mode_from_children, DisplayAs, DisplayFormatType, Distribution,
+    ExecutionMode, ExecutionPlan, ExecutionPlanProperties, PlanProperties,
+    RecordBatchStream, SendableRecordBatchStream, Statistics,
+};
+
+use arrow::array::{PrimitiveArray, UInt32Array, UInt64Array};
+use arrow::compute::take;
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::{RecordBatch, RecordBatchOptions};
+use arrow_array::cast::downcast_array;
+use datafusion_common::{plan_err, JoinType, Result};
+use datafusion_execution::memory_pool::MemoryConsumer;
+use datafusion_execution::TaskContext;
+use datafusion_physical_expr::equivalence::add_offset_to_expr;
+use datafusion_physical_expr::{EquivalenceProperties, PhysicalExprRef};
+
+use ahash::RandomState;
+use futures::{ready, Stream, StreamExt};
+
+/// Experimental inner join of one probe input with several build inputs.
+///
+/// Each build input (typically a small "dimension" table of a star schema)
+/// is collected into its own hash table, and then the probe input (the
+/// "fact" table) is scanned once, looking every row up in all hash tables.
+/// This is equivalent to a right-deep chain of inner [`HashJoinExec`]s in
+/// [`PartitionMode::CollectLeft`] mode that all join on probe input
+/// columns, but avoids materializing the intermediate results between the
+/// binary joins.
+///
+/// ```text
+///      HashJoinExec                       MultiHashJoinExec
+///       /        \                      /        |        \
+///    dim1    HashJoinExec     ==>     dim1      dim2      fact
+///             /        \
+///          dim2        fact
+/// ```
+///
+/// The output contains the columns of all build inputs, in order, followed
+/// by the columns of the probe input.
+///
+/// [`HashJoinExec`]: super::HashJoinExec
+/// [`PartitionMode::CollectLeft`]: super::PartitionMode::CollectLeft
+#[derive(Debug)]
+pub struct MultiHashJoinExec {
+    /// Build side inputs, each collected into a single hash table
+    builds: Vec<Arc<dyn ExecutionPlan>>,
+    /// Probe side input, which is scanned once
+    probe: Arc<dyn ExecutionPlan>,
+    /// Equijoin columns for each build input: `(build_col, probe_col)`
+    on: Vec<JoinOn>,
+    /// The output schema
+    schema: SchemaRef,
+    /// Futures that consume the build inputs and build the hash tables
+    build_futs: Vec<OnceAsync<JoinLeftData>>,
+    /// Shared the `RandomState` for the hashing algorithm
+    random_state: RandomState,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+    /// Cache holding plan properties like equivalences, output partitioning etc.
+    cache: PlanProperties,
+}
+
+impl MultiHashJoinExec {
+    /// Tries to create a new [`MultiHashJoinExec`].
+    ///
+    /// `on[i]` contains the equijoin columns of `builds[i]` and `probe`.
+    ///
+    /// # Error
+    /// This function errors when there are no build inputs, or when it is
+    /// not possible to join a build input and the probe input on its keys.
+    pub fn try_new(
+        builds: Vec<Arc<dyn ExecutionPlan>>,
+        probe: Arc<dyn ExecutionPlan>,
+        on: Vec<JoinOn>,
+    ) -> Result<Self> {
+        if builds.is_empty() {
+            return plan_err!("MultiHashJoinExec requires at least one build input");
+        }
+        if builds.len() != on.len() {
+            return plan_err!(
+                "MultiHashJoinExec requires join keys for each of its {} build inputs, got {}",
+                builds.len(),
+                on.len()
+            );
+        }
+        let probe_schema = probe.schema();
+        for (build, on) in builds.iter().zip(&on) {
+            if on.is_empty() {
+                return plan_err!(
+                    "On constraints in MultiHashJoinExec should be non-empty"
+                );
+            }
+            check_join_is_valid(&build.schema(), &probe_schema, on)?;
+        }
+
+        // Same schema as the equivalent chain of binary joins
+        let schema = builds.iter().rev().fold(probe_schema, |schema, build| {
+            Arc::new(build_join_schema(&build.schema(), &schema, &JoinType::Inner).0)
+        });
+
+        let cache = Self::compute_properties(&builds, &probe, schema.clone());
+        let build_futs = builds.iter().map(|_| Default::default()).collect();
+
+        Ok(Self {
+            builds,
+            probe,
+            on,
+            schema,
+            build_futs,
+            random_state: RandomState::with_seeds(0, 0, 0, 0),
+            metrics: ExecutionPlanMetricsSet::new(),
+            cache,
+        })
+    }
+
+    /// Build side inputs, each collected into a single hash table
+    pub fn builds(&self) -> &[Arc<dyn ExecutionPlan>] {
+        &self.builds
+    }
+
+    /// Probe side input
+    pub fn probe(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.probe
+    }
+
+    /// Equijoin columns for each build input
+    pub fn on(&self) -> &[JoinOn] {
+        &self.on
+    }
+
+    /// This function creates the cache object that stores the plan properties such as schema, equivalence properties, ordering, partitioning, etc.
+    fn compute_properties(
+        builds: &[Arc<dyn ExecutionPlan>],
+        probe: &Arc<dyn ExecutionPlan>,
+        schema: SchemaRef,
+    ) -> PlanProperties {
+        let build_columns_len = builds
+            .iter()
+            .map(|build| build.schema().fields().len())
+            .sum();
+        let output_partitioning = adjust_right_output_partitioning(
+            probe.output_partitioning(),
+            build_columns_len,
+        );
+
+        // Collecting an unbounded build input never finishes
+        let mode = if builds
+            .iter()
+            .any(|build| build.execution_mode().is_unbounded())
+        {
+            ExecutionMode::PipelineBreaking
+        } else {
+            execution_mode_from_children(builds.iter().chain([probe]))
+        };
+
+        PlanProperties::new(
+            EquivalenceProperties::new(schema),
+            output_partitioning,
+            mode,
+        )
+    }
+}
+
+impl DisplayAs for MultiHashJoinExec {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                let on = self
+                    .on
+                    .iter()
+                    .map(|on| {
+                        let on = on
+                            .iter()
+                            .map(|(b, p)| format!("({}, {})", b, p))
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        format!("[{on}]")
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                write!(f, "MultiHashJoinExec: on=[{on}]")
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for MultiHashJoinExec {
+    fn name(&self) -> &'static str {
+        "MultiHashJoinExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn required_input_distribution(&self) -> Vec<Distribution> {
+        self.builds
+            .iter()
+            .map(|_| Distribution::SinglePartition)
+            .chain([Distribution::UnspecifiedDistribution])
+            .collect()
+    }
+
+    /// The build inputs, followed by the probe input
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        self.builds.iter().chain([&self.probe]).cloned().collect()
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        mut children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let Some(probe) = children.pop() else {
+            return plan_err!("MultiHashJoinExec requires a probe input");
+        };
+        Ok(Arc::new(MultiHashJoinExec::try_new(
+            children,
+            probe,
+            self.on.clone(),
+        )?))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let join_metrics = BuildProbeJoinMetrics::new(partition, &self.metrics);
+        let on = self
+            .on
+            .iter()
+            .map(|on| on.iter().cloned().unzip())
+            .collect::<Vec<(Vec<_>, Vec<_>)>>();
+
+        let build_futs = self
+            .builds
+            .iter()
+            .zip(&self.build_futs)
+            .zip(&on)
+            .enumerate()
+            .map(|(i, ((build, build_fut), (build_on, _)))| {
+                build_fut.once(|| {
+                    let reservation =
+                        MemoryConsumer::new(format!("MultiHashJoinInput[{i}]"))
+                            .register(context.memory_pool());
+                    collect_left_input(
+                        None,
+                        self.random_state.clone(),
+                        build.clone(),
+                        build_on.clone(),
+                        context.clone(),
+                        join_metrics.clone(),
+                        reservation,
+                    )
+                })
+            })
+            .collect();
+
+        let probe = self.probe.execute(partition, context)?;
+
+        Ok(Box::pin(MultiHashJoinStream {
+            schema: self.schema(),
+            on,
+            probe,
+            random_state: self.random_state.clone(),
+            join_metrics,
+            build_futs,
+            build_data: None,
+            hashes_buffer: vec![],
+        }))
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+
+    /// Estimated as the equivalent chain of binary joins, starting with the
+    /// innermost join of the last build input and the probe input
+    fn statistics(&self) -> Result<Statistics> {
+        let probe_columns = self.probe.schema().fields().len();
+        let mut stats = self.probe.statistics()?;
+        let mut schema = self.probe.schema();
+        for (build, on) in self.builds.iter().zip(&self.on).rev() {
+            // the probe columns follow the columns of the build inputs
+            // joined so far
+            let offset = schema.fields().len() - probe_columns;
+            let on = on
+                .iter()
+                .map(|(b, p)| (b.clone(), add_offset_to_expr(p.clone(), offset)))
+                .collect();
+            schema =
+                Arc::new(build_join_schema(&build.schema(), &schema, &JoinType::Inner).0);
+            stats = estimate_join_statistics_from_inputs(
+                &JoinType::Inner,
+                build.statistics()?,
+                stats,
+                &on,
+                &schema,
+            );
+        }
+        Ok(stats)
+    }
+}
+
+/// Stream of the output of a [`MultiHashJoinExec`] for one probe partition
+struct MultiHashJoinStream {
+    /// Output schema
+    schema: SchemaRef,
+    /// Equijoin columns for each build input: `(build_cols, probe_cols)`
+    on: Vec<(Vec<PhysicalExprRef>, Vec<PhysicalExprRef>)>,
+    /// Probe input
+    probe: SendableRecordBatchStream,
+    /// Random state used for hashing initialization
+    random_state: RandomState,
+    /// Metrics
+    join_metrics: BuildProbeJoinMetrics,
+    /// Futures for the hash tables of the build inputs
+    build_futs: Vec<OnceFut<JoinLeftData>>,
+    /// Hash tables of the build inputs, once they are all collected
+    build_data: Option<Vec<Arc<JoinLeftData>>>,
+    /// Scratch space for computing hashes
+    hashes_buffer: Vec<u64>,
+}
+
+impl RecordBatchStream for MultiHashJoinStream {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+impl MultiHashJoinStream {
+    fn poll_next_impl(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Result<RecordBatch>>> {
+        if self.build_data.is_none() {
+            let build_timer = self.join_metrics.build_time.timer();
+            let mut build_data = Vec::with_capacity(self.build_futs.len());
+            for build_fut in self.build_futs.iter_mut() {
+                build_data.push(ready!(build_fut.get_shared(cx))?);
+            }
+            build_timer.done();
+            self.build_data = Some(build_data);
+        }
+        let build_data = self.build_data.as_ref().unwrap();
+
+        loop {
+            let probe_batch = match ready!(self.probe.poll_next_unpin(cx)) {
+                Some(Ok(batch)) => batch,
+                other => return Poll::Ready(other),
+            };
+            self.join_metrics.input_batches.add(1);
+            self.join_metrics.input_rows.add(probe_batch.num_rows());
+
+            let timer = self.join_metrics.join_time.timer();
+            let result = join_probe_batch(
+                &self.schema,
+                build_data,
+                &self.on,
+                &probe_batch,
+                &self.random_state,
+                &mut self.hashes_buffer,
+            );
+            timer.done();
+
+            let batch = result?;
+            if batch.num_rows() > 0 {
+                self.join_metrics.output_batches.add(1);
+                self.join_metrics.output_rows.add(batch.num_rows());
+                return Poll::Ready(Some(Ok(batch)));
+            }
+        }
+    }
+}
+
+impl Stream for MultiHashJoinStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.poll_next_impl(cx)
+    }
+}
+
+/// Joins `probe_batch` with each build input in turn, producing all
+/// combinations of rows that match every build input.
+fn join_probe_batch(
+    schema: &SchemaRef,
+    build_data: &[Arc<JoinLeftData>],
+    on: &[(Vec<PhysicalExprRef>, Vec<PhysicalExprRef>)],
+    probe_batch: &RecordBatch,
+    random_state: &RandomState,
+    hashes_buffer: &mut Vec<u64>,
+) -> Result<RecordBatch> {
+    let num_rows = probe_batch.num_rows();
+    // The probe rows of the matching combinations found so far, in ascending
+    // order, and for each build input joined so far the matching build rows
+    let mut probe_indices = UInt32Array::from_iter_values(0..num_rows as u32);
+    let mut build_indices: Vec<UInt64Array> = Vec::with_capacity(build_data.len());
+
+    for (data, (build_on, probe_on)) in build_data.iter().zip(on) {
+        let probe_values = probe_on
+            .iter()
+            .map(|c| c.evaluate(probe_batch)?.into_array(num_rows))
+            .collect::<Result<Vec<_>>>()?;
+        let build_values = build_on
+            .iter()
+            .map(|c| {
+                c.evaluate(data.batch())?
+                    .into_array(data.batch().num_rows())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        hashes_buffer.clear();
+        hashes_buffer.resize(num_rows, 0);
+        create_hashes(&probe_values, random_state, hashes_buffer)?;
+
+        // Only look up the probe rows that matched all previous build inputs
+        let mut rows = probe_indices.values().to_vec();
+        rows.dedup();
+        let (mut probe_builder, mut build_builder) = data.hash_map().get_matched_indices(
+            rows.iter()
+                .map(|row| (*row as usize, &hashes_buffer[*row as usize])),
+            None,
+        );
+        let matched_build: UInt64Array =
+            PrimitiveArray::new(build_builder.finish().into(), None);
+        let matched_probe: UInt32Array =
+            PrimitiveArray::new(probe_builder.finish().into(), None);
+        let (matched_build, matched_probe) = equal_rows_arr(
+            &matched_build,
+            &matched_probe,
+            &build_values,
+            &probe_values,
+            false,
+        )?;
+
+        // Extend each combination with every build row matching its probe
+        // row. Both `probe_indices` and `matched_probe` are sorted.
+        let matched_build = matched_build.values();
+        let matched_probe = matched_probe.values();
+        let mut combinations = Vec::with_capacity(matched_probe.len());
+        let mut new_build_indices = Vec::with_capacity(matched_probe.len());
+        let mut start = 0;
+        for (combination, probe_row) in probe_indices.values().iter().enumerate() {
+            while start < matched_probe.len() && matched_probe[start] < *probe_row {
+                start += 1;
+            }
+            let mut i = start;
+            while i < matched_probe.len() && matched_probe[i] == *probe_row {
+                combinations.push(combination as u32);
+                new_build_indices.push(matched_build[i]);
+                i += 1;
+            }
+        }
+
+        let combinations = UInt32Array::from(combinations);
+        probe_indices =
+            downcast_array(take(&probe_indices, &combinations, None)?.as_ref());
+        for indices in build_indices.iter_mut() {
+            *indices = downcast_array(take(indices, &combinations, None)?.as_ref());
+        }
+        build_indices.push(UInt64Array::from(new_build_indices));
+    }
+
+    let mut columns = Vec::with_capacity(schema.fields().len());
+    for (data, indices) in build_data.iter().zip(&build_indices) {
+        for column in data.batch().columns() {
+            columns.push(take(column, indices, None)?);
+        }
+    }
+    for column in probe_batch.columns() {
+        columns.push(take(column, &probe_indices, None)?);
+    }
+    let options = RecordBatchOptions::new().with_row_count(Some(probe_indices.len()));
+    Ok(RecordBatch::try_new_with_options(
+        schema.clone(),
+        columns,
+        &options,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::joins::utils::{JoinFilter, JoinOn};
+    use crate::joins::{HashJoinExec, PartitionMode};
+    use crate::{common, memory::MemoryExec, test::build_table_i32};
+
+    use arrow::datatypes::Schema;
+    use datafusion_common::assert_batches_sorted_eq;
+    use datafusion_physical_expr::expressions::Column;
+
+    fn table(
+        a: (&str, &Vec<i32>),
+        b: (&str, &Vec<i32>),
+        c: (&str, &Vec<i32>),
+    ) -> Arc<dyn ExecutionPlan> {
+        let batch = build_table_i32(a, b, c);
+        let schema = batch.schema();
+        Arc::new(MemoryExec::try_new(&[vec![batch]], schema, None).unwrap())
+    }
+
+    fn col(name: &str, schema: &Schema) -> PhysicalExprRef {
+        Arc::new(Column::new_with_schema(name, schema).unwrap())
+    }
+
+    fn dimensions() -> (Arc<dyn ExecutionPlan>, Arc<dyn ExecutionPlan>) {
+        let dim1 = table(
+            ("k1", &vec![1, 2, 3, 2]),
+            ("x1", &vec![10, 20, 30, 21]),
+            ("y1", &vec![0, 0, 0, 0]),
+        );
+        let dim2 = table(
+            ("k2", &vec![7, 8, 9]),
+            ("x2", &vec![70, 80, 90]),
+            ("y2", &vec![0, 0, 0]),
+        );
+        (dim1, dim2)
+    }
+
+    fn fact() -> Arc<dyn ExecutionPlan> {
+        table(
+            ("f1", &vec![1, 2, 2, 4, 3, 1]),
+            ("f2", &vec![7, 8, 9, 7, 5, 9]),
+            ("v", &vec![100, 200, 300, 400, 500, 600]),
+        )
+    }
+
+    async fn collect(plan: Arc<dyn ExecutionPlan>) -> Result<Vec<RecordBatch>> {
+        common::collect(plan.execute(0, Arc::new(TaskContext::default()))?).await
+    }
+
+    #[tokio::test]
+    async fn multi_hash_join() -> Result<()> {
+        let (dim1, dim2) = dimensions();
+        let fact = fact();
+        let on: Vec<JoinOn> = vec![
+            vec![(col("k1", &dim1.schema()), col("f1", &fact.schema()))],
+            vec![(col("k2", &dim2.schema()), col("f2", &fact.schema()))],
+        ];
+        let join = Arc::new(MultiHashJoinExec::try_new(
+            vec![dim1.clone(), dim2.clone()],
+            fact.clone(),
+            on,
+        )?);
+
+        let expected = [
+            "+----+----+----+----+----+----+----+----+-----+",
+            "| k1 | x1 | y1 | k2 | x2 | y2 | f1 | f2 | v   |",
+            "+----+----+----+----+----+----+----+----+-----+",
+            "| 1  | 10 | 0  | 7  | 70 | 0  | 1  | 7  | 100 |",
+            "| 1  | 10 | 0  | 9  | 90 | 0  | 1  | 9  | 600 |",
+            "| 2  | 20 | 0  | 8  | 80 | 0  | 2  | 8  | 200 |",
+            "| 2  | 20 | 0  | 9  | 90 | 0  | 2  | 9  | 300 |",
+            "| 2  | 21 | 0  | 8  | 80 | 0  | 2  | 8  | 200 |",
+            "| 2  | 21 | 0  | 9  | 90 | 0  | 2  | 9  | 300 |",
+            "+----+----+----+----+----+----+----+----+-----+",
+        ];
+        let batches = collect(join.clone()).await?;
+        assert_batches_sorted_eq!(expected, &batches);
+
+        // same result as the chain of binary joins
+        let inner = Arc::new(HashJoinExec::try_new(
+            dim2.clone(),
+            fact.clone(),
+            vec![(col("k2", &dim2.schema()), col("f2", &fact.schema()))],
+            None::<JoinFilter>,
+            &JoinType::Inner,
+            None,
+            PartitionMode::CollectLeft,
+            false,
+        )?);
+        let outer = Arc::new(HashJoinExec::try_new(
+            dim1.clone(),
+            inner.clone(),
+            vec![(col("k1", &dim1.schema()), col("f1", &inner.schema()))],
+            None,
+            &JoinType::Inner,
+            None,
+            PartitionMode::CollectLeft,
+            false,
+        )?);
+        assert_eq!(outer.schema(), join.schema());
+        assert_eq!(outer.statistics()?, join.statistics()?);
+        let batches = collect(outer).await?;
+        assert_batches_sorted_eq!(expected, &batches);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn multi_hash_join_no_match() -> Result<()> {
+        let (dim1, dim2) = dimensions();
+        let fact = table(
+            ("f1", &vec![4, 1]),
+            ("f2", &vec![7, 6]),
+            ("v", &vec![100, 200]),
+        );
+        let on: Vec<JoinOn> = vec![
+            vec![(col("k1", &dim1.schema()), col("f1", &fact.schema()))],
+            vec![(col("k2", &dim2.schema()), col("f2", &fact.schema()))],
+        ];
+        let join = Arc::new(MultiHashJoinExec::try_new(vec![dim1, dim2], fact, on)?);
+        assert!(collect(join).await?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn multi_hash_join_invalid_keys() {
+        let (dim1, dim2) = dimensions();
+        let fact = fact();
+        let on: Vec<JoinOn> =
+            vec![vec![(col("k1", &dim1.schema()), col("f1", &fact.schema()))]];
+        let err = MultiHashJoinExec::try_new(vec![dim1, dim2], fact, on).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: MultiHashJoinExec requires join keys for each of its 2 build inputs, got 1"
+        );
+    }
+}
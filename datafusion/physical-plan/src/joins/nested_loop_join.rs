@@ -40,7 +40,7 @@ use crate::{
 };
 
 use arrow::array::{
-    BooleanBufferBuilder, UInt32Array, UInt32Builder, UInt64Array, UInt64Builder,
+    Array, BooleanBufferBuilder, UInt32Array, UInt32Builder, UInt64Array, UInt64Builder,
 };
 use arrow::datatypes::{Schema, SchemaRef};
 use arrow::record_batch::RecordBatch;
@@ -402,7 +402,8 @@ struct NestedLoopJoinStream {
     // null_equals_null: bool
     /// Join execution metrics
     join_metrics: BuildProbeJoinMetrics,
-    /// Memory reservation for visited_left_side
+    /// Memory reservation for visited_left_side and the join indices of the
+    /// current batch
     reservation: MemoryReservation,
 }
 
@@ -485,6 +486,7 @@ impl NestedLoopJoinStream {
                         &self.column_indices,
                         &self.schema,
                         visited_left_side,
+                        &mut self.reservation,
                     );
 
                     // Recording time & updating output metrics
@@ -570,6 +572,7 @@ impl NestedLoopJoinStream {
                         &self.column_indices,
                         &self.schema,
                         &mut empty_visited_left_side,
+                        &mut self.reservation,
                     );
 
                     // Recording time & updating output metrics
@@ -595,62 +598,70 @@ fn join_left_and_right_batch(
     column_indices: &[ColumnIndex],
     schema: &Schema,
     visited_left_side: &mut BooleanBufferBuilder,
+    reservation: &mut MemoryReservation,
 ) -> Result<RecordBatch> {
-    let indices_result = (0..left_batch.num_rows())
-        .map(|left_row_index| {
-            build_join_indices(left_row_index, right_batch, left_batch, filter)
-        })
-        .collect::<Result<Vec<(UInt64Array, UInt32Array)>>>();
-
-    let mut left_indices_builder = UInt64Builder::new();
-    let mut right_indices_builder = UInt32Builder::new();
-    let left_right_indices = match indices_result {
-        Err(err) => {
-            exec_err!("Fail to build join indices in NestedLoopJoinExec, error:{err}")
+    // The indices of the matching pairs of rows are held until the output
+    // batch is built, so they are accounted for meanwhile
+    let mut indices = Vec::with_capacity(left_batch.num_rows());
+    let mut indices_size = 0;
+    let mut grown = Ok(());
+    for left_row_index in 0..left_batch.num_rows() {
+        let (left_side, right_side) =
+            build_join_indices(left_row_index, right_batch, left_batch, filter).or_else(
+                |err| {
+                    exec_err!(
+                        "Fail to build join indices in NestedLoopJoinExec, error:{err}"
+                    )
+                },
+            )?;
+        let size = left_side.get_array_memory_size() + right_side.get_array_memory_size();
+        grown = reservation.try_grow(size);
+        if grown.is_err() {
+            break;
         }
-        Ok(indices) => {
-            for (left_side, right_side) in indices {
-                left_indices_builder
-                    .append_values(left_side.values(), &vec![true; left_side.len()]);
-                right_indices_builder
-                    .append_values(right_side.values(), &vec![true; right_side.len()]);
-            }
-            Ok((
-                left_indices_builder.finish(),
-                right_indices_builder.finish(),
-            ))
+        indices_size += size;
+        indices.push((left_side, right_side));
+    }
+    let result = grown.and_then(|_| {
+        let mut left_indices_builder = UInt64Builder::new();
+        let mut right_indices_builder = UInt32Builder::new();
+        for (left_side, right_side) in indices {
+            left_indices_builder
+                .append_values(left_side.values(), &vec![true; left_side.len()]);
+            right_indices_builder
+                .append_values(right_side.values(), &vec![true; right_side.len()]);
         }
-    };
-    match left_right_indices {
-        Ok((left_side, right_side)) => {
-            // set the left bitmap
-            // and only full join need the left bitmap
-            if join_type == JoinType::Full {
-                left_side.iter().flatten().for_each(|x| {
-                    visited_left_side.set_bit(x as usize, true);
-                });
-            }
-            // adjust the two side indices base on the join type
-            let (left_side, right_side) = adjust_indices_by_join_type(
-                left_side,
-                right_side,
-                left_batch.num_rows(),
-                right_batch.num_rows(),
-                join_type,
-            );
-
-            build_batch_from_indices(
-                schema,
-                left_batch,
-                right_batch,
-                &left_side,
-                &right_side,
-                column_indices,
-                JoinSide::Left,
-            )
+        let left_side = left_indices_builder.finish();
+        let right_side = right_indices_builder.finish();
+
+        // set the left bitmap
+        // and only full join need the left bitmap
+        if join_type == JoinType::Full {
+            left_side.iter().flatten().for_each(|x| {
+                visited_left_side.set_bit(x as usize, true);
+            });
         }
-        Err(e) => Err(e),
-    }
+        // adjust the two side indices base on the join type
+        let (left_side, right_side) = adjust_indices_by_join_type(
+            left_side,
+            right_side,
+            left_batch.num_rows(),
+            right_batch.num_rows(),
+            join_type,
+        );
+
+        build_batch_from_indices(
+            schema,
+            left_batch,
+            right_batch,
+            &left_side,
+            &right_side,
+            column_indices,
+            JoinSide::Left,
+        )
+    });
+    reservation.shrink(indices_size);
+    result
 }
 
 fn adjust_indices_by_join_type(
@@ -1184,6 +1195,42 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_overallocation_of_join_indices() -> Result<()> {
+        let values = (0..200).collect::<Vec<_>>();
+        let left = build_table(("a1", &values), ("b1", &values), ("c1", &values));
+        let right = build_table(
+            ("a2", &vec![0; 10]),
+            ("b2", &vec![0; 10]),
+            ("c2", &vec![0; 10]),
+        );
+
+        for join_type in [JoinType::Inner, JoinType::Left, JoinType::LeftAnti] {
+            let runtime_config = RuntimeConfig::new().with_memory_limit(4096, 1.0);
+            let runtime = Arc::new(RuntimeEnv::new(runtime_config)?);
+            let task_ctx = Arc::new(TaskContext::default().with_runtime(runtime));
+
+            // the inner table fits, but not all pairs of rows of the join
+            let join = NestedLoopJoinExec::try_new(
+                left.clone(),
+                right.clone(),
+                None,
+                &join_type,
+            )?;
+            let err = common::collect(join.execute(0, task_ctx)?)
+                .await
+                .unwrap_err();
+
+            assert_contains!(
+                err.to_string(),
+                "Resources exhausted: Failed to allocate additional"
+            );
+            assert_contains!(err.to_string(), "NestedLoopJoinStream[0]");
+        }
+
+        Ok(())
+    }
+
     /// Returns the column names on the schema
     fn columns(schema: &Schema) -> Vec<String> {
         schema.fields().iter().map(|f| f.name().clone()).collect()
@@ -48,7 +48,8 @@ use arrow::datatypes::{DataType, SchemaRef, TimeUnit};
 use arrow::error::ArrowError;
 use arrow::record_batch::RecordBatch;
 use datafusion_common::{
-    internal_err, not_impl_err, plan_err, DataFusionError, JoinSide, JoinType, Result,
+    downcast_value, internal_err, not_impl_err, plan_err, DataFusionError, JoinSide,
+    JoinType, Result,
 };
 use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
 use datafusion_execution::TaskContext;
@@ -495,15 +496,15 @@ struct StreamedBatch {
 }
 
 impl StreamedBatch {
-    fn new(batch: RecordBatch, on_column: &[Arc<dyn PhysicalExpr>]) -> Self {
-        let join_arrays = join_arrays(&batch, on_column);
-        StreamedBatch {
+    fn try_new(batch: RecordBatch, on_column: &[Arc<dyn PhysicalExpr>]) -> Result<Self> {
+        let join_arrays = join_arrays(&batch, on_column)?;
+        Ok(StreamedBatch {
             batch,
             idx: 0,
             join_arrays,
             output_indices: vec![],
             buffered_batch_idx: None,
-        }
+        })
     }
 
     fn new_empty(schema: SchemaRef) -> Self {
@@ -562,12 +563,12 @@ struct BufferedBatch {
 }
 
 impl BufferedBatch {
-    fn new(
+    fn try_new(
         batch: RecordBatch,
         range: Range<usize>,
         on_column: &[PhysicalExprRef],
-    ) -> Self {
-        let join_arrays = join_arrays(&batch, on_column);
+    ) -> Result<Self> {
+        let join_arrays = join_arrays(&batch, on_column)?;
 
         // Estimation is calculated as
         //   inner batch size
@@ -584,13 +585,13 @@ impl BufferedBatch {
             + mem::size_of::<Range<usize>>()
             + mem::size_of::<usize>();
 
-        BufferedBatch {
+        Ok(BufferedBatch {
             batch,
             range,
             join_arrays,
             null_joined: vec![],
             size_estimation,
-        }
+        })
     }
 }
 
@@ -824,7 +825,7 @@ impl SMJStream {
                             self.join_metrics.input_batches.add(1);
                             self.join_metrics.input_rows.add(batch.num_rows());
                             self.streamed_batch =
-                                StreamedBatch::new(batch, &self.on_streamed);
+                                StreamedBatch::try_new(batch, &self.on_streamed)?;
                             self.streamed_state = StreamedState::Ready;
                         }
                     }
@@ -880,7 +881,7 @@ impl SMJStream {
                         self.join_metrics.input_rows.add(batch.num_rows());
                         if batch.num_rows() > 0 {
                             let buffered_batch =
-                                BufferedBatch::new(batch, 0..1, &self.on_buffered);
+                                BufferedBatch::try_new(batch, 0..1, &self.on_buffered)?;
                             self.reservation.try_grow(buffered_batch.size_estimation)?;
                             self.join_metrics
                                 .peak_mem_used
@@ -922,11 +923,11 @@ impl SMJStream {
                                 self.join_metrics.input_batches.add(1);
                                 self.join_metrics.input_rows.add(batch.num_rows());
                                 if batch.num_rows() > 0 {
-                                    let buffered_batch = BufferedBatch::new(
+                                    let buffered_batch = BufferedBatch::try_new(
                                         batch,
                                         0..0,
                                         &self.on_buffered,
-                                    );
+                                    )?;
                                     self.reservation
                                         .try_grow(buffered_batch.size_estimation)?;
                                     self.join_metrics
@@ -1432,14 +1433,13 @@ impl BufferedData {
 }
 
 /// Get join array refs of given batch and join columns
-fn join_arrays(batch: &RecordBatch, on_column: &[PhysicalExprRef]) -> Vec<ArrayRef> {
+fn join_arrays(
+    batch: &RecordBatch,
+    on_column: &[PhysicalExprRef],
+) -> Result<Vec<ArrayRef>> {
     on_column
         .iter()
-        .map(|c| {
-            let num_rows = batch.num_rows();
-            let c = c.evaluate(batch).unwrap();
-            c.into_array(num_rows).unwrap()
-        })
+        .map(|c| c.evaluate(batch)?.into_array(batch.num_rows()))
         .collect()
 }
 
@@ -1457,14 +1457,19 @@ fn compare_join_arrays(
         left_arrays.iter().zip(right_arrays).zip(sort_options)
     {
         macro_rules! compare_value {
-            ($T:ty) => {{
-                let left_array = left_array.as_any().downcast_ref::<$T>().unwrap();
-                let right_array = right_array.as_any().downcast_ref::<$T>().unwrap();
+            ($T:ident) => {{
+                let left_array = downcast_value!(left_array, $T);
+                let right_array = downcast_value!(right_array, $T);
                 match (left_array.is_null(left), right_array.is_null(right)) {
                     (false, false) => {
                         let left_value = &left_array.value(left);
                         let right_value = &right_array.value(right);
-                        res = left_value.partial_cmp(right_value).unwrap();
+                        res = left_value.partial_cmp(right_value).ok_or_else(|| {
+                            DataFusionError::Execution(
+                                "Unable to compare join keys in sort merge join"
+                                    .to_string(),
+                            )
+                        })?;
                         if sort_options.descending {
                             res = res.reverse();
                         }
@@ -1518,6 +1523,17 @@ fn compare_join_arrays(
             },
             DataType::Date32 => compare_value!(Date32Array),
             DataType::Date64 => compare_value!(Date64Array),
+            DataType::Time32(TimeUnit::Second) => compare_value!(Time32SecondArray),
+            DataType::Time32(TimeUnit::Millisecond) => {
+                compare_value!(Time32MillisecondArray)
+            }
+            DataType::Time64(TimeUnit::Microsecond) => {
+                compare_value!(Time64MicrosecondArray)
+            }
+            DataType::Time64(TimeUnit::Nanosecond) => {
+                compare_value!(Time64NanosecondArray)
+            }
+            DataType::FixedSizeBinary(_) => compare_value!(FixedSizeBinaryArray),
             _ => {
                 return not_impl_err!(
                     "Unsupported data type in sort merge join comparator"
@@ -1542,13 +1558,11 @@ fn is_join_arrays_equal(
     let mut is_equal = true;
     for (left_array, right_array) in left_arrays.iter().zip(right_arrays) {
         macro_rules! compare_value {
-            ($T:ty) => {{
+            ($T:ident) => {{
                 match (left_array.is_null(left), right_array.is_null(right)) {
                     (false, false) => {
-                        let left_array =
-                            left_array.as_any().downcast_ref::<$T>().unwrap();
-                        let right_array =
-                            right_array.as_any().downcast_ref::<$T>().unwrap();
+                        let left_array = downcast_value!(left_array, $T);
+                        let right_array = downcast_value!(right_array, $T);
                         if left_array.value(left) != right_array.value(right) {
                             is_equal = false;
                         }
@@ -1584,6 +1598,17 @@ fn is_join_arrays_equal(
             },
             DataType::Date32 => compare_value!(Date32Array),
             DataType::Date64 => compare_value!(Date64Array),
+            DataType::Time32(TimeUnit::Second) => compare_value!(Time32SecondArray),
+            DataType::Time32(TimeUnit::Millisecond) => {
+                compare_value!(Time32MillisecondArray)
+            }
+            DataType::Time64(TimeUnit::Microsecond) => {
+                compare_value!(Time64MicrosecondArray)
+            }
+            DataType::Time64(TimeUnit::Nanosecond) => {
+                compare_value!(Time64NanosecondArray)
+            }
+            DataType::FixedSizeBinary(_) => compare_value!(FixedSizeBinaryArray),
             _ => {
                 return not_impl_err!(
                     "Unsupported data type in sort merge join comparator"
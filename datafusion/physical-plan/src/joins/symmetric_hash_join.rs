@@ -42,8 +42,8 @@ use crate::joins::stream_join_utils::{
 };
 use crate::joins::utils::{
     apply_join_filter_to_indices, build_batch_from_indices, build_join_schema,
-    check_join_is_valid, partitioned_join_output_partitioning, ColumnIndex, JoinFilter,
-    JoinHashMapType, JoinOn, JoinOnRef, StatefulStreamResult,
+    check_join_is_valid, estimate_join_statistics, partitioned_join_output_partitioning,
+    ColumnIndex, JoinFilter, JoinHashMapType, JoinOn, JoinOnRef, StatefulStreamResult,
 };
 use crate::{
     execution_mode_from_children,
@@ -454,7 +454,13 @@ impl ExecutionPlan for SymmetricHashJoinExec {
 
     fn statistics(&self) -> Result<Statistics> {
         // TODO stats: it is not possible in general to know the output size of joins
-        Ok(Statistics::new_unknown(&self.schema()))
+        estimate_join_statistics(
+            self.left.clone(),
+            self.right.clone(),
+            self.on.clone(),
+            &self.join_type,
+            &self.schema(),
+        )
     }
 
     fn execute(
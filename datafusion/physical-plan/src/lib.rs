@@ -26,6 +26,7 @@ use crate::display::DisplayableExecutionPlan;
 use crate::metrics::MetricsSet;
 use crate::repartition::RepartitionExec;
 use crate::sorts::sort_preserving_merge::SortPreservingMergeExec;
+use crate::stream::execute_catch_unwind;
 
 use arrow::datatypes::SchemaRef;
 use arrow::record_batch::RecordBatch;
@@ -49,19 +50,23 @@ pub mod analyze;
 pub mod coalesce_batches;
 pub mod coalesce_partitions;
 pub mod common;
+pub mod dedup;
 pub mod display;
 pub mod empty;
 pub mod explain;
 pub mod filter;
+pub mod hash_table;
 pub mod insert;
 pub mod joins;
 pub mod limit;
+pub mod match_recognize;
 pub mod memory;
 pub mod metrics;
 pub mod placeholder_row;
 pub mod projection;
 pub mod recursive_query;
 pub mod repartition;
+pub mod shared_scan;
 pub mod sorts;
 pub mod stream;
 pub mod streaming;
@@ -709,13 +714,14 @@ pub fn execute_stream(
 ) -> Result<SendableRecordBatchStream> {
     match plan.output_partitioning().partition_count() {
         0 => Ok(Box::pin(EmptyRecordBatchStream::new(plan.schema()))),
-        1 => plan.execute(0, context),
+        1 => execute_partition(&plan, 0, context),
         _ => {
             // merge into a single partition
-            let plan = CoalescePartitionsExec::new(plan.clone());
+            let plan: Arc<dyn ExecutionPlan> =
+                Arc::new(CoalescePartitionsExec::new(plan.clone()));
             // CoalescePartitionsExec must produce a single partition
             assert_eq!(1, plan.properties().output_partitioning().partition_count());
-            plan.execute(0, context)
+            execute_partition(&plan, 0, context)
         }
     }
 }
@@ -774,11 +780,25 @@ pub fn execute_stream_partitioned(
     let num_partitions = plan.output_partitioning().partition_count();
     let mut streams = Vec::with_capacity(num_partitions);
     for i in 0..num_partitions {
-        streams.push(plan.execute(i, context.clone())?);
+        streams.push(execute_partition(&plan, i, context.clone())?);
     }
     Ok(streams)
 }
 
+/// Execute `partition` of `plan`, catching panics of its stream if
+/// `datafusion.execution.catch_panics` is enabled
+fn execute_partition(
+    plan: &Arc<dyn ExecutionPlan>,
+    partition: usize,
+    context: Arc<TaskContext>,
+) -> Result<SendableRecordBatchStream> {
+    if context.session_config().options().execution.catch_panics {
+        execute_catch_unwind(plan, partition, context)
+    } else {
+        plan.execute(partition, context)
+    }
+}
+
 /// Utility function yielding a string representation of the given [`ExecutionPlan`].
 pub fn get_plan_string(plan: &Arc<dyn ExecutionPlan>) -> Vec<String> {
     let formatted = displayable(plan.as_ref()).indent(true).to_string();
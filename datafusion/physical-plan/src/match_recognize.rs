@@ -0,0 +1,934 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the MATCH_RECOGNIZE plan, which finds sequences of rows matching a
+//! row pattern within ordered partitions.
+//!
+//! The plan is only available through the API: the SQL parser does not
+//! support the `MATCH_RECOGNIZE` clause yet, so there is no logical plan for
+//! it and [`MatchRecognizeExec`] must be created directly.
+
+use std::any::Any;
+use std::fmt;
+use std::ops::Range;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use crate::expressions::PhysicalSortExpr;
+use crate::metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet};
+use crate::windows::calc_requirements;
+use crate::{
+    DisplayAs, DisplayFormatType, Distribution, ExecutionMode, ExecutionPlan,
+    ExecutionPlanProperties, PhysicalExpr, PlanProperties, RecordBatchStream,
+    SendableRecordBatchStream,
+};
+
+use arrow::array::{ArrayRef, BooleanArray, StringArray, UInt32Array, UInt64Array};
+use arrow::compute::{concat_batches, prep_null_mask_filter, take};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::record_batch::RecordBatch;
+use datafusion_common::cast::as_boolean_array;
+use datafusion_common::utils::evaluate_partition_ranges;
+use datafusion_common::{plan_err, Result};
+use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
+use datafusion_execution::TaskContext;
+use datafusion_physical_expr::{EquivalenceProperties, PhysicalSortRequirement};
+use futures::{ready, Stream, StreamExt};
+
+/// Quantifier of a [`PatternVariable`]. All quantifiers are reluctant, i.e.
+/// they match as few rows as possible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternQuantifier {
+    /// Exactly one row
+    One,
+    /// Zero or one row, `??`
+    ZeroOrOne,
+    /// Any number of rows, `*?`
+    ZeroOrMore,
+    /// At least one row, `+?`
+    OneOrMore,
+}
+
+impl PatternQuantifier {
+    fn is_optional(&self) -> bool {
+        matches!(self, Self::ZeroOrOne | Self::ZeroOrMore)
+    }
+
+    fn is_repeated(&self) -> bool {
+        matches!(self, Self::ZeroOrMore | Self::OneOrMore)
+    }
+}
+
+impl fmt::Display for PatternQuantifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::One => Ok(()),
+            Self::ZeroOrOne => write!(f, "??"),
+            Self::ZeroOrMore => write!(f, "*?"),
+            Self::OneOrMore => write!(f, "+?"),
+        }
+    }
+}
+
+/// A variable of a row pattern, e.g. `B+?` in `PATTERN (A B+? C)`, with the
+/// predicate of its `DEFINE` clause
+#[derive(Debug, Clone)]
+pub struct PatternVariable {
+    /// Name of the variable, returned by `CLASSIFIER()`
+    pub name: String,
+    /// How many rows the variable matches
+    pub quantifier: PatternQuantifier,
+    /// Boolean predicate a row must satisfy to be matched by the variable. Use
+    /// a `true` literal for variables without a definition.
+    pub predicate: Arc<dyn PhysicalExpr>,
+}
+
+/// Finds the sequences of rows of each partition that match a row pattern, as
+/// in `MATCH_RECOGNIZE (... ALL ROWS PER MATCH AFTER MATCH SKIP PAST LAST ROW
+/// PATTERN (...) DEFINE ...)`.
+///
+/// The input must be sorted by the partition keys and the ordering keys. The
+/// pattern is a sequence of [`PatternVariable`]s, which is matched by
+/// simulating the corresponding NFA starting at every row that is not part of
+/// a previous match. The shortest match found first is used.
+///
+/// The output contains the rows of all matches, in input order, followed by
+/// the `match_number` of each match within its partition (counting from 1)
+/// and the `classifier`, the name of the variable matching the row. As with
+/// `SHOW EMPTY MATCHES`, an empty match, which patterns whose variables are
+/// all optional prefer, outputs the row it starts at with a NULL classifier.
+///
+/// All input rows are buffered and accounted for in the memory pool. The
+/// output is split into batches of the configured batch size.
+#[derive(Debug)]
+pub struct MatchRecognizeExec {
+    /// Input plan
+    input: Arc<dyn ExecutionPlan>,
+    /// Partition keys
+    partition_by: Vec<Arc<dyn PhysicalExpr>>,
+    /// Ordering of the rows within a partition
+    order_by: Vec<PhysicalSortExpr>,
+    /// Row pattern
+    pattern: Vec<PatternVariable>,
+    /// Schema after the pattern matching is run
+    schema: SchemaRef,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+    /// Cache holding plan properties like equivalences, output partitioning etc.
+    cache: PlanProperties,
+}
+
+impl MatchRecognizeExec {
+    /// Create a new execution plan matching `pattern`
+    pub fn try_new(
+        input: Arc<dyn ExecutionPlan>,
+        partition_by: Vec<Arc<dyn PhysicalExpr>>,
+        order_by: Vec<PhysicalSortExpr>,
+        pattern: Vec<PatternVariable>,
+    ) -> Result<Self> {
+        if pattern.is_empty() {
+            return plan_err!("MATCH_RECOGNIZE requires a non-empty pattern");
+        }
+        let input_schema = input.schema();
+        for variable in &pattern {
+            let data_type = variable.predicate.data_type(&input_schema)?;
+            if data_type != DataType::Boolean {
+                return plan_err!(
+                    "The definition of pattern variable {} must be a boolean, got {data_type}",
+                    variable.name
+                );
+            }
+        }
+
+        let mut fields = input_schema.fields().to_vec();
+        fields.push(Arc::new(Field::new(
+            "match_number",
+            DataType::UInt64,
+            false,
+        )));
+        fields.push(Arc::new(Field::new("classifier", DataType::Utf8, true)));
+        let schema = Arc::new(Schema::new_with_metadata(
+            fields,
+            input_schema.metadata().clone(),
+        ));
+
+        let cache = Self::compute_properties(schema.clone(), &input);
+        Ok(Self {
+            input,
+            partition_by,
+            order_by,
+            pattern,
+            schema,
+            metrics: ExecutionPlanMetricsSet::new(),
+            cache,
+        })
+    }
+
+    /// Input plan
+    pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.input
+    }
+
+    /// Partition keys
+    pub fn partition_by(&self) -> &[Arc<dyn PhysicalExpr>] {
+        &self.partition_by
+    }
+
+    /// Ordering of the rows within a partition
+    pub fn order_by(&self) -> &[PhysicalSortExpr] {
+        &self.order_by
+    }
+
+    /// Row pattern
+    pub fn pattern(&self) -> &[PatternVariable] {
+        &self.pattern
+    }
+
+    /// This function creates the cache object that stores the plan properties such as schema, equivalence properties, ordering, partitioning, etc.
+    fn compute_properties(
+        schema: SchemaRef,
+        input: &Arc<dyn ExecutionPlan>,
+    ) -> PlanProperties {
+        // The output is a subset of the input rows in input order
+        let eq_properties = EquivalenceProperties::new(schema)
+            .extend(input.equivalence_properties().clone());
+
+        let mode = match input.execution_mode() {
+            ExecutionMode::Bounded => ExecutionMode::Bounded,
+            ExecutionMode::Unbounded | ExecutionMode::PipelineBreaking => {
+                ExecutionMode::PipelineBreaking
+            }
+        };
+
+        PlanProperties::new(eq_properties, input.output_partitioning().clone(), mode)
+    }
+}
+
+impl DisplayAs for MatchRecognizeExec {
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                let partition_by = self
+                    .partition_by
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<_>>();
+                let order_by = self
+                    .order_by
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<_>>();
+                let pattern = self
+                    .pattern
+                    .iter()
+                    .map(|v| format!("{}{}", v.name, v.quantifier))
+                    .collect::<Vec<_>>();
+                let define = self
+                    .pattern
+                    .iter()
+                    .map(|v| format!("{} AS {}", v.name, v.predicate))
+                    .collect::<Vec<_>>();
+                write!(
+                    f,
+                    "MatchRecognizeExec: partition_by=[{}], order_by=[{}], pattern=({}), define=[{}]",
+                    partition_by.join(", "),
+                    order_by.join(", "),
+                    pattern.join(" "),
+                    define.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for MatchRecognizeExec {
+    fn name(&self) -> &'static str {
+        "MatchRecognizeExec"
+    }
+
+    /// Return a reference to Any that can be used for downcasting
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![self.input.clone()]
+    }
+
+    fn maintains_input_order(&self) -> Vec<bool> {
+        vec![true]
+    }
+
+    fn required_input_ordering(&self) -> Vec<Option<Vec<PhysicalSortRequirement>>> {
+        vec![calc_requirements(&self.partition_by, &self.order_by)]
+    }
+
+    fn required_input_distribution(&self) -> Vec<Distribution> {
+        if self.partition_by.is_empty() {
+            vec![Distribution::SinglePartition]
+        } else {
+            vec![Distribution::HashPartitioned(self.partition_by.clone())]
+        }
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(MatchRecognizeExec::try_new(
+            children[0].clone(),
+            self.partition_by.clone(),
+            self.order_by.clone(),
+            self.pattern.clone(),
+        )?))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let input = self.input.execute(partition, context.clone())?;
+        let reservation =
+            MemoryConsumer::new(format!("MatchRecognizeStream[{partition}]"))
+                .register(context.memory_pool());
+        Ok(Box::pin(MatchRecognizeStream {
+            schema: self.schema.clone(),
+            input,
+            batches: vec![],
+            reservation,
+            matches: None,
+            finished: false,
+            partition_by: self.partition_by.clone(),
+            pattern: self.pattern.clone(),
+            batch_size: context.session_config().batch_size(),
+            baseline_metrics: BaselineMetrics::new(&self.metrics, partition),
+        }))
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+}
+
+/// Stream of the rows matching the pattern of a [`MatchRecognizeExec`]
+struct MatchRecognizeStream {
+    schema: SchemaRef,
+    input: SendableRecordBatchStream,
+    batches: Vec<RecordBatch>,
+    /// Memory used by the buffered input batches, and then by the matches
+    reservation: MemoryReservation,
+    /// The matched rows, once the input is exhausted
+    matches: Option<MatchedRows>,
+    finished: bool,
+    partition_by: Vec<Arc<dyn PhysicalExpr>>,
+    pattern: Vec<PatternVariable>,
+    batch_size: usize,
+    baseline_metrics: BaselineMetrics,
+}
+
+/// The rows of the matches found by a [`MatchRecognizeStream`], which are
+/// output in chunks
+struct MatchedRows {
+    /// All input rows
+    batch: RecordBatch,
+    /// The index of each output row in `batch`
+    indices: UInt32Array,
+    match_numbers: UInt64Array,
+    classifiers: StringArray,
+    /// Number of rows output so far
+    offset: usize,
+}
+
+impl MatchedRows {
+    /// Returns the next at most `batch_size` output rows, or `None` once all
+    /// rows are output
+    fn next_batch(
+        &mut self,
+        schema: &SchemaRef,
+        batch_size: usize,
+    ) -> Result<Option<RecordBatch>> {
+        let len = batch_size.min(self.indices.len() - self.offset);
+        if len == 0 {
+            return Ok(None);
+        }
+        let indices = self.indices.slice(self.offset, len);
+        let mut columns = self
+            .batch
+            .columns()
+            .iter()
+            .map(|c| Ok(take(c, &indices, None)?))
+            .collect::<Result<Vec<ArrayRef>>>()?;
+        columns.push(Arc::new(self.match_numbers.slice(self.offset, len)));
+        columns.push(Arc::new(self.classifiers.slice(self.offset, len)));
+        self.offset += len;
+        Ok(Some(RecordBatch::try_new(schema.clone(), columns)?))
+    }
+}
+
+impl MatchRecognizeStream {
+    fn compute_matches(&mut self) -> Result<MatchedRows> {
+        // record compute time on drop
+        let _timer = self.baseline_metrics.elapsed_compute().timer();
+        // the input batches are copied into a single batch
+        let buffered = self.reservation.size();
+        self.reservation.try_grow(buffered)?;
+        let batch = concat_batches(&self.input.schema(), &self.batches)?;
+        self.batches.clear();
+        self.reservation.shrink(buffered);
+        let num_rows = batch.num_rows();
+
+        let partition_columns = self
+            .partition_by
+            .iter()
+            .map(|e| {
+                PhysicalSortExpr {
+                    expr: e.clone(),
+                    options: Default::default(),
+                }
+                .evaluate_to_sort_column(&batch)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let partition_ranges = evaluate_partition_ranges(num_rows, &partition_columns)?;
+
+        // rows whose predicate evaluates to NULL do not match
+        let defined = self
+            .pattern
+            .iter()
+            .map(|variable| {
+                let value = variable.predicate.evaluate(&batch)?.into_array(num_rows)?;
+                Ok(prep_null_mask_filter(as_boolean_array(&value)?))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let quantifiers = self
+            .pattern
+            .iter()
+            .map(|variable| variable.quantifier)
+            .collect::<Vec<_>>();
+        let mut indices = vec![];
+        let mut match_numbers = vec![];
+        let mut classifiers = vec![];
+        for range in partition_ranges {
+            let matches = find_matches(&quantifiers, &defined, range);
+            for (match_number, row_match) in matches.into_iter().enumerate() {
+                let match_number = match_number as u64 + 1;
+                if row_match.rows.is_empty() {
+                    indices.push(row_match.rows.start as u32);
+                    match_numbers.push(match_number);
+                    classifiers.push(None);
+                }
+                for (row, variable) in row_match.rows.zip(row_match.variables) {
+                    indices.push(row as u32);
+                    match_numbers.push(match_number);
+                    classifiers.push(Some(self.pattern[variable].name.as_str()));
+                }
+            }
+        }
+
+        let matches = MatchedRows {
+            batch,
+            indices: UInt32Array::from(indices),
+            match_numbers: UInt64Array::from(match_numbers),
+            classifiers: StringArray::from(classifiers),
+            offset: 0,
+        };
+        self.reservation.try_grow(
+            matches.indices.get_array_memory_size()
+                + matches.match_numbers.get_array_memory_size()
+                + matches.classifiers.get_array_memory_size(),
+        )?;
+        Ok(matches)
+    }
+
+    #[inline]
+    fn poll_next_inner(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<RecordBatch>>> {
+        if self.finished {
+            return Poll::Ready(None);
+        }
+
+        if self.matches.is_none() {
+            let matches = loop {
+                match ready!(self.input.poll_next_unpin(cx)) {
+                    Some(Ok(batch)) => {
+                        match self.reservation.try_grow(batch.get_array_memory_size()) {
+                            Ok(()) => self.batches.push(batch),
+                            Err(e) => break Err(e),
+                        }
+                    }
+                    Some(Err(e)) => break Err(e),
+                    None => break self.compute_matches(),
+                }
+            };
+            match matches {
+                Ok(matches) => self.matches = Some(matches),
+                Err(e) => {
+                    self.finish();
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+        }
+
+        let schema = self.schema.clone();
+        let batch_size = self.batch_size;
+        let output = {
+            // record compute time on drop
+            let _timer = self.baseline_metrics.elapsed_compute().timer();
+            let matches = self.matches.as_mut().expect("matches are computed");
+            matches.next_batch(&schema, batch_size)
+        };
+        match output {
+            Ok(Some(batch)) => Poll::Ready(Some(Ok(batch))),
+            Ok(None) => {
+                self.finish();
+                Poll::Ready(None)
+            }
+            Err(e) => {
+                self.finish();
+                Poll::Ready(Some(Err(e)))
+            }
+        }
+    }
+
+    /// Releases the buffered rows once the stream is done
+    fn finish(&mut self) {
+        self.batches.clear();
+        self.matches = None;
+        self.reservation.free();
+        self.finished = true;
+    }
+}
+
+impl Stream for MatchRecognizeStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let poll = self.poll_next_inner(cx);
+        self.baseline_metrics.record_poll(poll)
+    }
+}
+
+impl RecordBatchStream for MatchRecognizeStream {
+    /// Get the schema
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+/// A match of the pattern
+#[derive(Debug, PartialEq)]
+struct Match {
+    /// The matched rows, an empty range starting at the current row for an
+    /// empty match
+    rows: Range<usize>,
+    /// The index of the variable matching each row
+    variables: Vec<usize>,
+}
+
+/// A state of the pattern NFA
+#[derive(Debug, Clone)]
+struct Thread {
+    /// Index of the current pattern variable
+    variable: usize,
+    /// Whether the current variable matched at least one row
+    matched: bool,
+    /// The variables matching the rows consumed so far
+    path: Vec<usize>,
+}
+
+impl Thread {
+    fn is_accepting(&self, quantifiers: &[PatternQuantifier]) -> bool {
+        self.variable == quantifiers.len()
+    }
+}
+
+/// Returns the states reachable from `threads` without consuming a row, in
+/// order of priority, keeping the first thread of each state
+fn closure(quantifiers: &[PatternQuantifier], threads: Vec<Thread>) -> Vec<Thread> {
+    let mut result: Vec<Thread> = vec![];
+    for thread in threads {
+        let mut chain = vec![];
+        let mut current = thread;
+        loop {
+            let next = (current.variable < quantifiers.len()
+                && (current.matched || quantifiers[current.variable].is_optional()))
+            .then(|| Thread {
+                variable: current.variable + 1,
+                matched: false,
+                path: current.path.clone(),
+            });
+            chain.push(current);
+            match next {
+                Some(next) => current = next,
+                None => break,
+            }
+        }
+        // reluctant quantifiers prefer to move on to the next variable
+        for thread in chain.into_iter().rev() {
+            if !result
+                .iter()
+                .any(|t| t.variable == thread.variable && t.matched == thread.matched)
+            {
+                result.push(thread);
+            }
+        }
+    }
+    result
+}
+
+/// Finds the matches of the pattern within `range`, where `defined[v]` holds
+/// whether each row satisfies the definition of variable `v`. After a match,
+/// the search continues with the row following the match, or the row
+/// following the start of an empty match.
+fn find_matches(
+    quantifiers: &[PatternQuantifier],
+    defined: &[BooleanArray],
+    range: Range<usize>,
+) -> Vec<Match> {
+    let initial = Thread {
+        variable: 0,
+        matched: false,
+        path: vec![],
+    };
+    let mut matches = vec![];
+    let mut start = range.start;
+    while start < range.end {
+        let mut threads = closure(quantifiers, vec![initial.clone()]);
+        // the reluctant quantifiers of a pattern whose variables are all
+        // optional prefer the empty match
+        if threads.iter().any(|t| t.is_accepting(quantifiers)) {
+            matches.push(Match {
+                rows: start..start,
+                variables: vec![],
+            });
+            start += 1;
+            continue;
+        }
+        let mut found = None;
+        for row in start..range.end {
+            let next = threads
+                .into_iter()
+                .filter(|t| {
+                    !t.is_accepting(quantifiers)
+                        && (!t.matched || quantifiers[t.variable].is_repeated())
+                        && defined[t.variable].value(row)
+                })
+                .map(|mut t| {
+                    t.matched = true;
+                    t.path.push(t.variable);
+                    t
+                })
+                .collect();
+            threads = closure(quantifiers, next);
+            if let Some(t) = threads.iter().find(|t| t.is_accepting(quantifiers)) {
+                found = Some(Match {
+                    rows: start..row + 1,
+                    variables: t.path.clone(),
+                });
+                break;
+            }
+            if threads.is_empty() {
+                break;
+            }
+        }
+        match found {
+            Some(m) => {
+                start = m.rows.end;
+                matches.push(m);
+            }
+            None => start += 1,
+        }
+    }
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collect;
+    use crate::expressions::{col, lit, BinaryExpr};
+    use crate::memory::MemoryExec;
+
+    use arrow::array::Int32Array;
+    use arrow::util::pretty::pretty_format_batches;
+    use datafusion_common::DataFusionError;
+    use datafusion_execution::config::SessionConfig;
+    use datafusion_execution::runtime_env::{RuntimeConfig, RuntimeEnv};
+    use datafusion_expr::Operator;
+
+    use PatternQuantifier::*;
+
+    fn defined(values: &[&[bool]]) -> Vec<BooleanArray> {
+        values
+            .iter()
+            .map(|v| BooleanArray::from(v.to_vec()))
+            .collect()
+    }
+
+    #[test]
+    fn match_sequence() {
+        // PATTERN (A B+? C)
+        let quantifiers = [One, OneOrMore, One];
+        let defined = defined(&[
+            &[true, true, false, false, true, false, true],
+            &[false, true, true, true, false, true, false],
+            &[false, false, false, true, false, false, true],
+        ]);
+        let matches = find_matches(&quantifiers, &defined, 0..7);
+        assert_eq!(
+            matches,
+            vec![
+                Match {
+                    rows: 0..4,
+                    variables: vec![0, 1, 1, 2]
+                },
+                Match {
+                    rows: 4..7,
+                    variables: vec![0, 1, 2]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn match_reluctant() {
+        // PATTERN (A*? B): A*? matches as few rows as possible
+        let quantifiers = [ZeroOrMore, One];
+        let defined = defined(&[&[true, true, true], &[true, true, true]]);
+        let matches = find_matches(&quantifiers, &defined, 0..3);
+        assert_eq!(matches.len(), 3);
+        assert_eq!(matches[0].variables, vec![1]);
+
+        // PATTERN (A B?? C)
+        let quantifiers = [One, ZeroOrOne, One];
+        let defined = defined(&[&[true, false], &[false, true], &[false, true]]);
+        let matches = find_matches(&quantifiers, &defined, 0..2);
+        assert_eq!(
+            matches,
+            vec![Match {
+                rows: 0..2,
+                variables: vec![0, 2]
+            }]
+        );
+    }
+
+    #[test]
+    fn match_within_range() {
+        let quantifiers = [One, One];
+        let defined = defined(&[&[true, true, true, true], &[true, true, true, true]]);
+        let matches = find_matches(&quantifiers, &defined, 1..4);
+        assert_eq!(
+            matches,
+            vec![Match {
+                rows: 1..3,
+                variables: vec![0, 1]
+            }]
+        );
+    }
+
+    #[test]
+    fn match_empty() {
+        // PATTERN (A?? B*?) prefers the empty match at every row
+        let quantifiers = [ZeroOrOne, ZeroOrMore];
+        let defined = defined(&[&[true, false], &[true, true]]);
+        let matches = find_matches(&quantifiers, &defined, 0..2);
+        assert_eq!(
+            matches,
+            vec![
+                Match {
+                    rows: 0..0,
+                    variables: vec![]
+                },
+                Match {
+                    rows: 1..1,
+                    variables: vec![]
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn match_recognize_partitions() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("user_id", DataType::Int32, false),
+            Field::new("price", DataType::Int32, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 1, 1, 1, 2, 2, 2])),
+                Arc::new(Int32Array::from(vec![
+                    Some(10),
+                    Some(8),
+                    Some(6),
+                    Some(9),
+                    Some(5),
+                    None,
+                    Some(7),
+                ])),
+            ],
+        )?;
+        let input = Arc::new(MemoryExec::try_new(&[vec![batch]], schema.clone(), None)?);
+
+        // a price drop followed by a rise: PATTERN (START DOWN+? UP)
+        let price = col("price", &schema)?;
+        let below = |value: i32| -> Arc<dyn PhysicalExpr> {
+            Arc::new(BinaryExpr::new(price.clone(), Operator::Lt, lit(value)))
+        };
+        let above = |value: i32| -> Arc<dyn PhysicalExpr> {
+            Arc::new(BinaryExpr::new(price.clone(), Operator::Gt, lit(value)))
+        };
+        let pattern = vec![
+            PatternVariable {
+                name: "START".to_string(),
+                quantifier: One,
+                predicate: lit(true),
+            },
+            PatternVariable {
+                name: "DOWN".to_string(),
+                quantifier: OneOrMore,
+                predicate: below(9),
+            },
+            PatternVariable {
+                name: "UP".to_string(),
+                quantifier: One,
+                predicate: above(6),
+            },
+        ];
+        let exec = Arc::new(MatchRecognizeExec::try_new(
+            input,
+            vec![col("user_id", &schema)?],
+            vec![],
+            pattern,
+        )?);
+
+        let result = collect(exec, Arc::new(TaskContext::default())).await?;
+        let expected = [
+            "+---------+-------+--------------+------------+",
+            "| user_id | price | match_number | classifier |",
+            "+---------+-------+--------------+------------+",
+            "| 1       | 10    | 1            | START      |",
+            "| 1       | 8     | 1            | DOWN       |",
+            "| 1       | 6     | 1            | DOWN       |",
+            "| 1       | 9     | 1            | UP         |",
+            "+---------+-------+--------------+------------+",
+        ];
+        assert_eq!(
+            pretty_format_batches(&result)?.to_string(),
+            expected.join("\n")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn match_recognize_batch_size() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5]))],
+        )?;
+        let input = Arc::new(MemoryExec::try_new(&[vec![batch]], schema.clone(), None)?);
+        // PATTERN (A??) only finds empty matches
+        let pattern = vec![PatternVariable {
+            name: "A".to_string(),
+            quantifier: ZeroOrOne,
+            predicate: lit(true),
+        }];
+        let exec = Arc::new(MatchRecognizeExec::try_new(input, vec![], vec![], pattern)?);
+
+        let task_ctx = TaskContext::default()
+            .with_session_config(SessionConfig::new().with_batch_size(2));
+        let result = collect(exec, Arc::new(task_ctx)).await?;
+        let num_rows = result.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+        assert_eq!(num_rows, vec![2, 2, 1]);
+        let expected = [
+            "+---+--------------+------------+",
+            "| a | match_number | classifier |",
+            "+---+--------------+------------+",
+            "| 1 | 1            |            |",
+            "| 2 | 2            |            |",
+            "| 3 | 3            |            |",
+            "| 4 | 4            |            |",
+            "| 5 | 5            |            |",
+            "+---+--------------+------------+",
+        ];
+        assert_eq!(
+            pretty_format_batches(&result)?.to_string(),
+            expected.join("\n")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn match_recognize_oom() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3]))],
+        )?;
+        let input = Arc::new(MemoryExec::try_new(&[vec![batch]], schema.clone(), None)?);
+        let pattern = vec![PatternVariable {
+            name: "A".to_string(),
+            quantifier: One,
+            predicate: lit(true),
+        }];
+        let exec = Arc::new(MatchRecognizeExec::try_new(input, vec![], vec![], pattern)?);
+
+        let runtime = Arc::new(
+            RuntimeEnv::new(RuntimeConfig::default().with_memory_limit(1, 1.0)).unwrap(),
+        );
+        let task_ctx = Arc::new(TaskContext::default().with_runtime(runtime));
+        let err = collect(exec, task_ctx).await.unwrap_err();
+        assert!(
+            matches!(err, DataFusionError::ResourcesExhausted(_)),
+            "Wrong error type: {err}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn match_recognize_requires_boolean_definitions() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let input = Arc::new(MemoryExec::try_new(&[vec![]], schema.clone(), None)?);
+        let pattern = vec![PatternVariable {
+            name: "A".to_string(),
+            quantifier: One,
+            predicate: col("a", &schema)?,
+        }];
+        let err =
+            MatchRecognizeExec::try_new(input, vec![], vec![], pattern).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("The definition of pattern variable A must be a boolean"));
+        Ok(())
+    }
+}
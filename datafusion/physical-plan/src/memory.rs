@@ -33,7 +33,7 @@ use arrow::record_batch::RecordBatch;
 use datafusion_common::{internal_err, project_schema, Result};
 use datafusion_execution::memory_pool::MemoryReservation;
 use datafusion_execution::TaskContext;
-use datafusion_physical_expr::{EquivalenceProperties, LexOrdering};
+use datafusion_physical_expr::{EquivalenceProperties, LexOrdering, PhysicalExpr};
 
 use futures::Stream;
 
@@ -88,14 +88,25 @@ impl DisplayAs for MemoryExec {
                     })
                     .unwrap_or_default();
 
+                let output_partitioning = match self.cache.output_partitioning() {
+                    partitioning @ Partitioning::Hash(_, _) => {
+                        format!(", output_partitioning={partitioning}")
+                    }
+                    _ => String::new(),
+                };
+
                 if self.show_sizes {
                     write!(
                         f,
-                        "MemoryExec: partitions={}, partition_sizes={partition_sizes:?}{output_ordering}",
+                        "MemoryExec: partitions={}, partition_sizes={partition_sizes:?}{output_ordering}{output_partitioning}",
                         partition_sizes.len(),
                     )
                 } else {
-                    write!(f, "MemoryExec: partitions={}", partition_sizes.len(),)
+                    write!(
+                        f,
+                        "MemoryExec: partitions={}{output_partitioning}",
+                        partition_sizes.len(),
+                    )
                 }
             }
         }
@@ -218,6 +229,21 @@ impl MemoryExec {
         self
     }
 
+    /// Declares that the partitions are hash partitioned on `exprs`, which
+    /// refer to the projected schema, e.g. when they were written by a
+    /// [`RepartitionExec`] with [`Partitioning::Hash`].
+    ///
+    /// If the partitions are not partitioned with the same hash function as
+    /// [`RepartitionExec`], DataFusion may produce incorrect results, as the
+    /// partitions of joined inputs are assumed to hold the same keys.
+    ///
+    /// [`RepartitionExec`]: crate::repartition::RepartitionExec
+    pub fn with_hash_partitioning(mut self, exprs: Vec<Arc<dyn PhysicalExpr>>) -> Self {
+        let partitioning = Partitioning::Hash(exprs, self.partitions.len());
+        self.cache = self.cache.with_partitioning(partitioning);
+        self
+    }
+
     pub fn original_schema(&self) -> SchemaRef {
         self.schema.clone()
     }
@@ -26,6 +26,7 @@ use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 
+use super::common::ConstantsScope;
 use super::expressions::Column;
 use super::metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet};
 use super::{
@@ -58,6 +59,8 @@ pub struct ProjectionExec {
     input: Arc<dyn ExecutionPlan>,
     /// Execution metrics
     metrics: ExecutionPlanMetricsSet,
+    /// Executions of the plan, which reset the constants of the expressions
+    constants: ConstantsScope,
     /// Cache holding plan properties like equivalences, output partitioning etc.
     cache: PlanProperties,
 }
@@ -100,6 +103,7 @@ impl ProjectionExec {
             schema,
             input,
             metrics: ExecutionPlanMetricsSet::new(),
+            constants: ConstantsScope::default(),
             cache,
         })
     }
@@ -226,6 +230,8 @@ impl ExecutionPlan for ProjectionExec {
         context: Arc<TaskContext>,
     ) -> Result<SendableRecordBatchStream> {
         trace!("Start ProjectionExec::execute for partition {} of context session_id {} and task_id {:?}", partition, context.session_id(), context.task_id());
+        self.constants
+            .enter(&context, self.expr.iter().map(|(expr, _)| expr));
         Ok(Box::pin(ProjectionStream {
             schema: self.schema.clone(),
             expr: self.expr.iter().map(|x| x.0.clone()).collect(),
@@ -19,12 +19,13 @@
 //! partitions to M output partitions based on a partitioning scheme, optionally
 //! maintaining the order of the input rows in the output.
 
+use std::io::Cursor;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 use std::{any::Any, vec};
 
-use super::common::SharedMemoryReservation;
+use super::common::{ConstantsScope, SharedMemoryReservation};
 use super::metrics::{self, ExecutionPlanMetricsSet, MetricBuilder, MetricsSet};
 use super::{
     DisplayAs, ExecutionPlanProperties, RecordBatchStream, SendableRecordBatchStream,
@@ -42,7 +43,13 @@ use crate::{DisplayFormatType, ExecutionPlan, Partitioning, PlanProperties, Stat
 use arrow::array::{ArrayRef, UInt64Builder};
 use arrow::datatypes::SchemaRef;
 use arrow::record_batch::RecordBatch;
-use datafusion_common::{arrow_datafusion_err, not_impl_err, DataFusionError, Result};
+use arrow_ipc::reader::StreamReader;
+use arrow_ipc::writer::{IpcWriteOptions, StreamWriter};
+use arrow_ipc::CompressionType;
+use datafusion_common::{
+    arrow_datafusion_err, config_err, internal_datafusion_err, not_impl_err,
+    DataFusionError, Result,
+};
 use datafusion_common_runtime::SpawnedTask;
 use datafusion_execution::memory_pool::MemoryConsumer;
 use datafusion_execution::TaskContext;
@@ -56,10 +63,71 @@ use parking_lot::Mutex;
 
 mod distributor_channels;
 
-type MaybeBatch = Option<Result<RecordBatch>>;
+type MaybeBatch = Option<Result<InFlightBatch>>;
 type InputPartitionsToCurrentPartitionSender = Vec<DistributionSender<MaybeBatch>>;
 type InputPartitionsToCurrentPartitionReceiver = Vec<DistributionReceiver<MaybeBatch>>;
 
+/// A batch buffered in the channels between input and output partitions.
+///
+/// When `datafusion.execution.repartition_compression` is set, batches are
+/// encoded as compressed Arrow IPC streams while they wait to be consumed,
+/// reducing the memory held by the channels at the cost of the CPU needed to
+/// compress and decompress them.
+#[derive(Debug)]
+enum InFlightBatch {
+    /// The batch as produced by the input
+    Uncompressed(RecordBatch),
+    /// The batch encoded as a compressed Arrow IPC stream
+    Compressed(Vec<u8>),
+}
+
+impl InFlightBatch {
+    fn try_new(batch: RecordBatch, compression: Option<CompressionType>) -> Result<Self> {
+        let Some(compression) = compression else {
+            return Ok(Self::Uncompressed(batch));
+        };
+
+        let options =
+            IpcWriteOptions::default().try_with_compression(Some(compression))?;
+        let mut writer =
+            StreamWriter::try_new_with_options(Vec::new(), batch.schema_ref(), options)?;
+        writer.write(&batch)?;
+        writer.finish()?;
+        Ok(Self::Compressed(writer.into_inner()?))
+    }
+
+    /// Memory accounted for this batch while it is buffered
+    fn size(&self) -> usize {
+        match self {
+            Self::Uncompressed(batch) => batch.get_array_memory_size(),
+            Self::Compressed(bytes) => bytes.capacity(),
+        }
+    }
+
+    fn into_batch(self) -> Result<RecordBatch> {
+        match self {
+            Self::Uncompressed(batch) => Ok(batch),
+            Self::Compressed(bytes) => StreamReader::try_new(Cursor::new(bytes), None)?
+                .next()
+                .transpose()?
+                .ok_or_else(|| {
+                    internal_datafusion_err!("Compressed repartition batch was empty")
+                }),
+        }
+    }
+}
+
+/// Parses the `datafusion.execution.repartition_compression` setting
+fn parse_repartition_compression(codec: Option<&str>) -> Result<Option<CompressionType>> {
+    match codec.map(|codec| codec.to_lowercase()).as_deref() {
+        None | Some("none") => Ok(None),
+        Some("lz4") => Ok(Some(CompressionType::LZ4_FRAME)),
+        Some(other) => config_err!(
+            "Unsupported repartition compression codec: {other}. Valid values are: lz4, none"
+        ),
+    }
+}
+
 /// Inner state of [`RepartitionExec`].
 #[derive(Debug)]
 struct RepartitionExecState {
@@ -86,7 +154,15 @@ impl RepartitionExecState {
         preserve_order: bool,
         name: String,
         context: Arc<TaskContext>,
-    ) -> Self {
+    ) -> Result<Self> {
+        let compression = parse_repartition_compression(
+            context
+                .session_config()
+                .options()
+                .execution
+                .repartition_compression
+                .as_deref(),
+        )?;
         let num_input_partitions = input.output_partitioning().partition_count();
         let num_output_partitions = partitioning.partition_count();
 
@@ -138,6 +214,7 @@ impl RepartitionExecState {
                 i,
                 txs.clone(),
                 partitioning.clone(),
+                compression,
                 r_metrics,
                 context.clone(),
             ));
@@ -153,10 +230,10 @@ impl RepartitionExecState {
             spawned_tasks.push(wait_for_task);
         }
 
-        Self {
+        Ok(Self {
             channels,
             abort_helper: Arc::new(spawned_tasks),
-        }
+        })
     }
 }
 
@@ -401,6 +478,9 @@ pub struct RepartitionExec {
     /// Boolean flag to decide whether to preserve ordering. If true means
     /// `SortPreservingRepartitionExec`, false means `RepartitionExec`.
     preserve_order: bool,
+    /// Executions of the plan, which reset the constants of the hash
+    /// partitioning expressions
+    constants: ConstantsScope,
     /// Cache holding plan properties like equivalences, output partitioning etc.
     cache: PlanProperties,
 }
@@ -550,6 +630,9 @@ impl ExecutionPlan for RepartitionExec {
             self.name(),
             partition
         );
+        if let Partitioning::Hash(exprs, _) = &self.partitioning {
+            self.constants.enter(&context, exprs);
+        }
 
         let lazy_state = Arc::clone(&self.state);
         let input = Arc::clone(&self.input);
@@ -571,17 +654,18 @@ impl ExecutionPlan for RepartitionExec {
             let name_captured = name.clone();
             let context_captured = Arc::clone(&context);
             let state = lazy_state
-                .get_or_init(|| async move {
-                    Mutex::new(RepartitionExecState::new(
+                .get_or_try_init(|| async move {
+                    RepartitionExecState::new(
                         input_captured,
                         partitioning,
                         metrics_captured,
                         preserve_order,
                         name_captured,
                         context_captured,
-                    ))
+                    )
+                    .map(Mutex::new)
                 })
-                .await;
+                .await?;
 
             // lock scope
             let (mut rx, reservation, abort_helper) = {
@@ -676,6 +760,7 @@ impl RepartitionExec {
             state: Default::default(),
             metrics: ExecutionPlanMetricsSet::new(),
             preserve_order,
+            constants: ConstantsScope::default(),
             cache,
         })
     }
@@ -757,6 +842,7 @@ impl RepartitionExec {
             (DistributionSender<MaybeBatch>, SharedMemoryReservation),
         >,
         partitioning: Partitioning,
+        compression: Option<CompressionType>,
         metrics: RepartitionMetrics,
         context: Arc<TaskContext>,
     ) -> Result<()> {
@@ -784,11 +870,15 @@ impl RepartitionExec {
 
             for res in partitioner.partition_iter(batch)? {
                 let (partition, batch) = res?;
-                let size = batch.get_array_memory_size();
 
-                let timer = metrics.send_time.timer();
                 // if there is still a receiver, send to it
                 if let Some((tx, reservation)) = output_channels.get_mut(&partition) {
+                    let timer = metrics.repartition_time.timer();
+                    let batch = InFlightBatch::try_new(batch, compression)?;
+                    timer.done();
+                    let size = batch.size();
+
+                    let timer = metrics.send_time.timer();
                     reservation.lock().try_grow(size)?;
 
                     if tx.send(Some(Ok(batch))).await.is_err() {
@@ -796,8 +886,8 @@ impl RepartitionExec {
                         reservation.lock().shrink(size);
                         output_channels.remove(&partition);
                     }
+                    timer.done();
                 }
-                timer.done();
             }
 
             // If the input stream is endless, we may spin forever and
@@ -904,11 +994,10 @@ impl Stream for RepartitionStream {
         loop {
             match self.input.recv().poll_unpin(cx) {
                 Poll::Ready(Some(Some(v))) => {
-                    if let Ok(batch) = &v {
-                        self.reservation
-                            .lock()
-                            .shrink(batch.get_array_memory_size());
-                    }
+                    let v = v.and_then(|batch| {
+                        self.reservation.lock().shrink(batch.size());
+                        batch.into_batch()
+                    });
 
                     return Poll::Ready(Some(v));
                 }
@@ -967,11 +1056,10 @@ impl Stream for PerPartitionStream {
     ) -> Poll<Option<Self::Item>> {
         match self.receiver.recv().poll_unpin(cx) {
             Poll::Ready(Some(Some(v))) => {
-                if let Ok(batch) = &v {
-                    self.reservation
-                        .lock()
-                        .shrink(batch.get_array_memory_size());
-                }
+                let v = v.and_then(|batch| {
+                    self.reservation.lock().shrink(batch.size());
+                    batch.into_batch()
+                });
                 Poll::Ready(Some(v))
             }
             Poll::Ready(Some(None)) => {
@@ -1004,14 +1092,15 @@ mod tests {
                 ErrorExec, MockExec,
             },
         },
-        {collect, expressions::col, memory::MemoryExec},
+        {collect, collect_partitioned, expressions::col, memory::MemoryExec},
     };
 
     use arrow::array::{ArrayRef, StringArray, UInt32Array};
     use arrow::datatypes::{DataType, Field, Schema};
     use arrow::record_batch::RecordBatch;
     use datafusion_common::cast::as_string_array;
-    use datafusion_common::{assert_batches_sorted_eq, exec_err};
+    use datafusion_common::{assert_batches_sorted_eq, assert_contains, exec_err};
+    use datafusion_execution::config::SessionConfig;
     use datafusion_execution::runtime_env::{RuntimeConfig, RuntimeEnv};
 
     use futures::FutureExt;
@@ -1473,6 +1562,50 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn compressed_hash_partition() -> Result<()> {
+        let schema = test_schema();
+        let partitions = vec![create_vec_batches(50)];
+        let partitioning = Partitioning::Hash(vec![col("c0", &schema)?], 4);
+
+        let mut config = SessionConfig::new();
+        config.options_mut().execution.repartition_compression = Some("lz4".into());
+        let task_ctx = Arc::new(TaskContext::default().with_session_config(config));
+
+        let exec = MemoryExec::try_new(&partitions, schema.clone(), None)?;
+        let exec = RepartitionExec::try_new(Arc::new(exec), partitioning.clone())?;
+        let compressed = collect_partitioned(Arc::new(exec), task_ctx).await?;
+        let uncompressed = repartition(&schema, partitions, partitioning).await?;
+
+        assert_eq!(compressed.len(), 4);
+        assert_eq!(compressed, uncompressed);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unsupported_repartition_compression() -> Result<()> {
+        let schema = test_schema();
+        let partitions = vec![create_vec_batches(1)];
+
+        let mut config = SessionConfig::new();
+        config.options_mut().execution.repartition_compression = Some("brotli".into());
+        let task_ctx = Arc::new(TaskContext::default().with_session_config(config));
+
+        let exec = MemoryExec::try_new(&partitions, schema, None)?;
+        let exec =
+            RepartitionExec::try_new(Arc::new(exec), Partitioning::RoundRobinBatch(2))?;
+        let err = collect_partitioned(Arc::new(exec), task_ctx)
+            .await
+            .unwrap_err();
+        assert_contains!(
+            err.to_string(),
+            "Unsupported repartition compression codec: brotli"
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn oom() -> Result<()> {
         // define input partitions
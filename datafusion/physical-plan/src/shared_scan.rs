@@ -0,0 +1,446 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the shared scan plan, which executes its input once and sends
+//! the results to several consumers in the same query
+
+use std::any::Any;
+use std::sync::Arc;
+
+use super::metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet};
+use super::stream::{ObservedStream, RecordBatchStreamAdapter};
+use super::{DisplayAs, PlanProperties, SendableRecordBatchStream, Statistics};
+use crate::common::SharedMemoryReservation;
+use crate::{DisplayFormatType, ExecutionPlan, ExecutionPlanProperties};
+
+use arrow::record_batch::RecordBatch;
+use datafusion_common::{internal_err, DataFusionError, Result};
+use datafusion_common_runtime::SpawnedTask;
+use datafusion_execution::memory_pool::MemoryConsumer;
+use datafusion_execution::TaskContext;
+
+use futures::StreamExt;
+use parking_lot::Mutex;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+type BatchReceiver = UnboundedReceiver<Result<(RecordBatch, Arc<BufferedBatch>)>>;
+type BatchSender = UnboundedSender<Result<(RecordBatch, Arc<BufferedBatch>)>>;
+
+/// Shared scan execution plan: one of several consumers of the same input.
+///
+/// The first consumer executing a partition starts a task reading that
+/// partition of the input, and every batch is sent to all consumers. Batches
+/// are buffered until the slowest consumer reads them, so the input is read
+/// only once even if the consumers are executed one after the other (for
+/// example the build and probe side of a self join), at the cost of holding
+/// the data of the input in memory. Buffered batches are accounted for in
+/// the memory pool until every consumer has received them.
+///
+/// If a consumer executes the same partition again, the input partition is
+/// read again for all consumers that have not started reading it yet.
+#[derive(Debug)]
+pub struct SharedScanExec {
+    /// Input execution plan, shared by all consumers
+    input: Arc<dyn ExecutionPlan>,
+    /// Index of this consumer
+    consumer: usize,
+    /// State shared by all consumers
+    state: Arc<SharedScanState>,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+    cache: PlanProperties,
+}
+
+/// State shared by all consumers of a [`SharedScanExec`]
+#[derive(Debug)]
+struct SharedScanState {
+    /// Number of consumers
+    consumers: usize,
+    /// The partitions of the input that are currently being read
+    partitions: Mutex<Vec<Option<SharedPartition>>>,
+}
+
+/// A partition of the input being read for all consumers
+#[derive(Debug)]
+struct SharedPartition {
+    /// Receivers of the consumers that have not started reading yet
+    receivers: Vec<Option<BatchReceiver>>,
+    /// Task reading the input partition, aborted once all consumers are done
+    task: Arc<SpawnedTask<()>>,
+}
+
+/// Memory reserved for a batch sent to all consumers, released once every
+/// consumer has received it
+#[derive(Debug)]
+struct BufferedBatch {
+    size: usize,
+    reservation: SharedMemoryReservation,
+}
+
+impl Drop for BufferedBatch {
+    fn drop(&mut self) {
+        self.reservation.lock().shrink(self.size);
+    }
+}
+
+impl SharedScanExec {
+    /// Create `consumers` shared scans that all read the results of a
+    /// single execution of `input`
+    pub fn new_group(input: Arc<dyn ExecutionPlan>, consumers: usize) -> Vec<Self> {
+        let partition_count = input.output_partitioning().partition_count();
+        let state = Arc::new(SharedScanState {
+            consumers,
+            partitions: Mutex::new((0..partition_count).map(|_| None).collect()),
+        });
+        (0..consumers)
+            .map(|consumer| SharedScanExec {
+                input: input.clone(),
+                consumer,
+                state: state.clone(),
+                metrics: ExecutionPlanMetricsSet::new(),
+                cache: input.properties().clone(),
+            })
+            .collect()
+    }
+
+    /// Input execution plan
+    pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.input
+    }
+
+    /// Index of this consumer
+    pub fn consumer(&self) -> usize {
+        self.consumer
+    }
+
+    /// Number of consumers sharing the input
+    pub fn consumers(&self) -> usize {
+        self.state.consumers
+    }
+
+    /// Returns true if both plans are consumers of the same input execution
+    pub fn is_shared_with(&self, other: &SharedScanExec) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+
+    /// Reads a partition of the input and sends every batch to all consumers
+    async fn pull_from_input(
+        mut stream: SendableRecordBatchStream,
+        txs: Vec<BatchSender>,
+        reservation: SharedMemoryReservation,
+    ) -> Result<()> {
+        while let Some(batch) = stream.next().await {
+            let batch = batch?;
+            let size = batch.get_array_memory_size();
+            reservation.lock().try_grow(size)?;
+            let buffered = Arc::new(BufferedBatch {
+                size,
+                reservation: Arc::clone(&reservation),
+            });
+
+            let mut any_open = false;
+            for tx in &txs {
+                // a send error means the consumer has already shut down
+                any_open |= tx.send(Ok((batch.clone(), Arc::clone(&buffered)))).is_ok();
+            }
+            if !any_open {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Waits for the input task to complete and propagates its errors to all
+    /// consumers
+    async fn wait_for_task(input_task: SpawnedTask<Result<()>>, txs: Vec<BatchSender>) {
+        match input_task.join().await {
+            // Error in joining task
+            Err(e) => {
+                let e = Arc::new(e);
+                for tx in txs {
+                    let err = Err(DataFusionError::Context(
+                        "Join Error".to_string(),
+                        Box::new(DataFusionError::External(Box::new(Arc::clone(&e)))),
+                    ));
+                    tx.send(err).ok();
+                }
+            }
+            // Error from running input task
+            Ok(Err(e)) => {
+                let e = Arc::new(e);
+                for tx in txs {
+                    // wrap it because need to send error to all consumers
+                    let err = Err(DataFusionError::External(Box::new(Arc::clone(&e))));
+                    tx.send(err).ok();
+                }
+            }
+            Ok(Ok(())) => {}
+        }
+    }
+}
+
+impl DisplayAs for SharedScanExec {
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                write!(
+                    f,
+                    "SharedScanExec: consumer={}, consumers={}",
+                    self.consumer, self.state.consumers
+                )
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for SharedScanExec {
+    fn name(&self) -> &'static str {
+        "SharedScanExec"
+    }
+
+    /// Return a reference to Any that can be used for downcasting
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![self.input.clone()]
+    }
+
+    fn maintains_input_order(&self) -> Vec<bool> {
+        vec![true]
+    }
+
+    fn benefits_from_input_partitioning(&self) -> Vec<bool> {
+        vec![false]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        if Arc::ptr_eq(&children[0], &self.input) {
+            Ok(self)
+        } else {
+            // The input is no longer the one shared with the other consumers
+            Ok(children[0].clone())
+        }
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let (rx, task) = {
+            let mut partitions = self.state.partitions.lock();
+            let Some(shared_partition) = partitions.get_mut(partition) else {
+                return internal_err!("SharedScanExec invalid partition {partition}");
+            };
+
+            // Start reading the input if no read is in progress, or if this
+            // consumer already took its part of the current one
+            let needs_new_read = match shared_partition {
+                Some(shared) => shared.receivers[self.consumer].is_none(),
+                None => true,
+            };
+            if needs_new_read {
+                let reservation = Arc::new(Mutex::new(
+                    MemoryConsumer::new(format!("SharedScanExec[{partition}]"))
+                        .register(context.memory_pool()),
+                ));
+                let stream = self.input.execute(partition, context)?;
+                let (txs, receivers): (Vec<_>, Vec<_>) = (0..self.state.consumers)
+                    .map(|_| {
+                        let (tx, rx) = unbounded_channel();
+                        (tx, Some(rx))
+                    })
+                    .unzip();
+                let input_task = SpawnedTask::spawn(Self::pull_from_input(
+                    stream,
+                    txs.clone(),
+                    reservation,
+                ));
+                let task = SpawnedTask::spawn(Self::wait_for_task(input_task, txs));
+                *shared_partition = Some(SharedPartition {
+                    receivers,
+                    task: Arc::new(task),
+                });
+            }
+
+            let Some(shared) = shared_partition.as_mut() else {
+                return internal_err!("SharedScanExec partition {partition} not started");
+            };
+            let rx = shared.receivers[self.consumer].take();
+            let task = Arc::clone(&shared.task);
+            // Once every consumer is reading, the streams keep the task alive
+            if shared.receivers.iter().all(Option::is_none) {
+                *shared_partition = None;
+            }
+            (rx, task)
+        };
+
+        let Some(rx) = rx else {
+            return internal_err!(
+                "SharedScanExec receiver of partition {partition} missing"
+            );
+        };
+        let stream = futures::stream::unfold((rx, task), |(mut rx, task)| async move {
+            // dropping the buffered batch releases its memory once all
+            // consumers have received it
+            rx.recv()
+                .await
+                .map(|batch| (batch.map(|(batch, _buffered)| batch), (rx, task)))
+        });
+        let stream = Box::pin(RecordBatchStreamAdapter::new(self.schema(), stream));
+        let baseline_metrics = BaselineMetrics::new(&self.metrics, partition);
+        Ok(Box::pin(ObservedStream::new(stream, baseline_metrics)))
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+
+    fn statistics(&self) -> Result<Statistics> {
+        self.input.statistics()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collect;
+    use crate::test::exec::MockExec;
+    use crate::test::{self, make_partition};
+
+    use arrow::datatypes::{DataType, Field, Schema};
+    use datafusion_execution::runtime_env::{RuntimeConfig, RuntimeEnv};
+
+    #[tokio::test]
+    async fn all_consumers_read_all_partitions() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+        let input = test::scan_partitioned(3);
+        let consumers = SharedScanExec::new_group(input.clone(), 2);
+        assert!(consumers[0].is_shared_with(&consumers[1]));
+
+        let expected = collect(input, task_ctx.clone()).await?;
+        for consumer in consumers {
+            assert_eq!(consumer.output_partitioning().partition_count(), 3);
+            let batches = collect(Arc::new(consumer), task_ctx.clone()).await?;
+            assert_eq!(batches, expected);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn consumer_executed_twice() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+        let input = test::scan_partitioned(1);
+        let consumers: Vec<Arc<dyn ExecutionPlan>> =
+            SharedScanExec::new_group(input.clone(), 2)
+                .into_iter()
+                .map(|consumer| Arc::new(consumer) as _)
+                .collect();
+
+        let expected = collect(input, task_ctx.clone()).await?;
+        for consumer in [&consumers[0], &consumers[0], &consumers[1]] {
+            let batches = collect(consumer.clone(), task_ctx.clone()).await?;
+            assert_eq!(batches, expected);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn error_sent_to_all_consumers() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+        let schema = Arc::new(Schema::new(vec![Field::new("i", DataType::Int32, true)]));
+        let input = Arc::new(MockExec::new(
+            vec![
+                Ok(make_partition(2)),
+                internal_err!("input failed"),
+                Ok(make_partition(3)),
+            ],
+            schema,
+        ));
+
+        for consumer in SharedScanExec::new_group(input, 2) {
+            let err = collect(Arc::new(consumer), task_ctx.clone())
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("input failed"), "{err}");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn buffered_batches_reserve_memory() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+        let input = test::scan_partitioned(1);
+        let mut consumers = SharedScanExec::new_group(input, 2).into_iter();
+        let pool = task_ctx.memory_pool();
+
+        // the batches stay buffered until the second consumer reads them
+        let batches =
+            collect(Arc::new(consumers.next().unwrap()), task_ctx.clone()).await?;
+        let size: usize = batches.iter().map(|b| b.get_array_memory_size()).sum();
+        assert!(size > 0);
+        assert_eq!(pool.reserved(), size);
+
+        collect(Arc::new(consumers.next().unwrap()), task_ctx.clone()).await?;
+        assert_eq!(pool.reserved(), 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn oom() -> Result<()> {
+        let runtime = Arc::new(
+            RuntimeEnv::new(RuntimeConfig::default().with_memory_limit(1, 1.0)).unwrap(),
+        );
+        let task_ctx = Arc::new(TaskContext::default().with_runtime(runtime));
+        let input = test::scan_partitioned(1);
+
+        for consumer in SharedScanExec::new_group(input, 2) {
+            let err = collect(Arc::new(consumer), task_ctx.clone())
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("Resources exhausted"), "{err}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn with_new_children() -> Result<()> {
+        let input = test::scan_partitioned(1);
+        let consumer = Arc::new(SharedScanExec::new_group(input.clone(), 2).remove(0));
+
+        let same = consumer.clone().with_new_children(vec![input])?;
+        assert_eq!(same.name(), "SharedScanExec");
+
+        let other = test::scan_partitioned(1);
+        let replaced = consumer.with_new_children(vec![other.clone()])?;
+        assert!(Arc::ptr_eq(&replaced, &other));
+        Ok(())
+    }
+}
@@ -25,7 +25,7 @@ use crate::sorts::stream::PartitionedStream;
 use crate::RecordBatchStream;
 use arrow::datatypes::SchemaRef;
 use arrow::record_batch::RecordBatch;
-use datafusion_common::Result;
+use datafusion_common::{DataFusionError, Result};
 use datafusion_execution::memory_pool::MemoryReservation;
 use futures::Stream;
 use std::pin::Pin;
@@ -96,6 +96,11 @@ pub(crate) struct SortPreservingMergeStream<C: CursorValues> {
 
     /// number of rows produced
     produced: usize,
+
+    /// A batch polled from the stream at the given index, for which no
+    /// memory could be reserved until the rows merged so far are yielded
+    /// and the batches they were merged from are released
+    deferred_batch: Option<(usize, RecordBatch)>,
 }
 
 impl<C: CursorValues> SortPreservingMergeStream<C> {
@@ -120,6 +125,7 @@ impl<C: CursorValues> SortPreservingMergeStream<C> {
             batch_size,
             fetch,
             produced: 0,
+            deferred_batch: None,
         }
     }
 
@@ -141,7 +147,15 @@ impl<C: CursorValues> SortPreservingMergeStream<C> {
             Some(Err(e)) => Poll::Ready(Err(e)),
             Some(Ok((cursor, batch))) => {
                 self.cursors[idx] = Some(Cursor::new(cursor));
-                Poll::Ready(self.in_progress.push_batch(idx, batch))
+                match self.in_progress.push_batch(idx, batch.clone()) {
+                    Err(DataFusionError::ResourcesExhausted(_))
+                        if !self.in_progress.is_empty() =>
+                    {
+                        self.deferred_batch = Some((idx, batch));
+                        Poll::Ready(Ok(()))
+                    }
+                    result => Poll::Ready(result),
+                }
             }
         }
     }
@@ -153,6 +167,12 @@ impl<C: CursorValues> SortPreservingMergeStream<C> {
         if self.aborted {
             return Poll::Ready(None);
         }
+        if let Some((stream_idx, batch)) = self.deferred_batch.take() {
+            if let Err(e) = self.in_progress.push_batch(stream_idx, batch) {
+                self.aborted = true;
+                return Poll::Ready(Some(Err(e)));
+            }
+        }
         // try to initialize the loser tree
         if self.loser_tree.is_empty() {
             // Ensure all non-exhausted streams have a cursor from which
@@ -179,6 +199,11 @@ impl<C: CursorValues> SortPreservingMergeStream<C> {
                     self.aborted = true;
                     return Poll::Ready(Some(Err(e)));
                 }
+                if self.deferred_batch.is_some() {
+                    // the memory limit is reached, yield a smaller batch to
+                    // release the memory of the batches merged so far
+                    break;
+                }
                 self.update_loser_tree();
             }
 
@@ -195,10 +220,11 @@ impl<C: CursorValues> SortPreservingMergeStream<C> {
                 }
             }
 
-            self.produced += self.in_progress.len();
-
-            return Poll::Ready(self.in_progress.build_record_batch().transpose());
+            break;
         }
+
+        self.produced += self.in_progress.len();
+        Poll::Ready(self.in_progress.build_record_batch().transpose())
     }
 
     fn fetch_reached(&mut self) -> bool {
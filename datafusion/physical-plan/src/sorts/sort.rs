@@ -238,6 +238,8 @@ struct ExternalSorter {
     /// the data will be concatenated and sorted in place rather than
     /// sort/merged.
     sort_in_place_threshold_bytes: usize,
+    /// The maximum number of spill files merged at once
+    sort_spill_merge_fan_in: usize,
 }
 
 impl ExternalSorter {
@@ -252,6 +254,7 @@ impl ExternalSorter {
         fetch: Option<usize>,
         sort_spill_reservation_bytes: usize,
         sort_in_place_threshold_bytes: usize,
+        sort_spill_merge_fan_in: usize,
         metrics: &ExecutionPlanMetricsSet,
         runtime: Arc<RuntimeEnv>,
     ) -> Self {
@@ -278,6 +281,7 @@ impl ExternalSorter {
             batch_size,
             sort_spill_reservation_bytes,
             sort_in_place_threshold_bytes,
+            sort_spill_merge_fan_in,
         }
     }
 
@@ -328,9 +332,13 @@ impl ExternalSorter {
     /// 1. An in-memory sort/merge (if the input fit in memory)
     ///
     /// 2. A combined streaming merge incorporating both in-memory
-    /// batches and data from spill files on disk.
-    fn sort(&mut self) -> Result<SendableRecordBatchStream> {
+    /// batches and data from spill files on disk. If there are more spill
+    /// files than `sort_spill_merge_fan_in`, they are first merged into
+    /// fewer spill files with [`Self::merge_spills`].
+    async fn sort(&mut self) -> Result<SendableRecordBatchStream> {
         if self.spilled_before() {
+            self.merge_spills().await?;
+
             let mut streams = vec![];
             if !self.in_mem_batches.is_empty() {
                 let in_mem_stream =
@@ -365,6 +373,50 @@ impl ExternalSorter {
         }
     }
 
+    /// Merges the spill files, `sort_spill_merge_fan_in` at a time, into new
+    /// spill files until they can be merged at once with the stream of the
+    /// in memory batches
+    async fn merge_spills(&mut self) -> Result<()> {
+        let fan_in = self.sort_spill_merge_fan_in.max(2);
+        let max_spills = if self.in_mem_batches.is_empty() {
+            fan_in
+        } else {
+            fan_in - 1
+        };
+        if self.spills.len() <= max_spills {
+            return Ok(());
+        }
+
+        // Release the memory reserved for merge back to the pool, for the
+        // buffers of the merged spill files
+        self.merge_reservation.free();
+
+        while self.spills.len() > max_spills {
+            // merge the oldest spill files, so that the last pass leaves
+            // exactly `max_spills` of them
+            let count = fan_in.min(self.spills.len() - max_spills + 1);
+            let streams = self
+                .spills
+                .drain(..count)
+                .map(|spill| read_spill_as_stream(spill, self.schema.clone()))
+                .collect::<Result<Vec<_>>>()?;
+            let merged = streaming_merge(
+                streams,
+                self.schema.clone(),
+                &self.expr,
+                self.metrics.baseline.intermediate(),
+                self.batch_size,
+                None,
+                self.merge_reservation.new_empty(),
+            )?;
+
+            let spill_file = self.runtime.disk_manager.create_tmp_file("Sorting")?;
+            spill_sorted_stream(merged, spill_file.path(), self.schema.clone()).await?;
+            self.spills.push(spill_file);
+        }
+        Ok(())
+    }
+
     /// How much memory is buffered in this `ExternalSorter`?
     fn used(&self) -> usize {
         self.reservation.size()
@@ -678,6 +730,40 @@ async fn spill_sorted_batches(
     }
 }
 
+/// Spills the sorted batches of `stream` to disk, writing them from a
+/// blocking task as they are produced.
+///
+/// Returns number of the rows spilled to disk.
+async fn spill_sorted_stream(
+    mut stream: SendableRecordBatchStream,
+    path: &Path,
+    schema: SchemaRef,
+) -> Result<u64> {
+    let path: PathBuf = path.into();
+    let (sender, mut receiver) = tokio::sync::mpsc::channel::<RecordBatch>(2);
+    let task = SpawnedTask::spawn_blocking(move || -> Result<u64> {
+        let mut writer = IPCWriter::new(path.as_ref(), schema.as_ref())?;
+        while let Some(batch) = receiver.blocking_recv() {
+            writer.write(&batch)?;
+        }
+        writer.finish()?;
+        Ok(writer.num_rows)
+    });
+
+    while let Some(batch) = stream.next().await {
+        if sender.send(batch?).await.is_err() {
+            // the writer failed, its error is returned below
+            break;
+        }
+    }
+    drop(sender);
+
+    match task.join().await {
+        Ok(r) => r,
+        Err(e) => exec_err!("Error occurred while spilling {e}"),
+    }
+}
+
 pub(crate) fn read_spill_as_stream(
     path: RefCountedTempFile,
     schema: SchemaRef,
@@ -961,6 +1047,7 @@ impl ExecutionPlan for SortExec {
                 self.fetch,
                 execution_options.sort_spill_reservation_bytes,
                 execution_options.sort_in_place_threshold_bytes,
+                execution_options.sort_spill_merge_fan_in,
                 &self.metrics_set,
                 context.runtime_env(),
             );
@@ -972,7 +1059,7 @@ impl ExecutionPlan for SortExec {
                         let batch = batch?;
                         sorter.insert_batch(batch).await?;
                     }
-                    sorter.sort()
+                    sorter.sort().await
                 })
                 .try_flatten(),
             )))
@@ -1096,6 +1183,62 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_sort_spill_merge_fan_in() -> Result<()> {
+        // the 4 spill files are merged 2 at a time, as the in memory batches
+        // take the place of one of them in the final merge
+        let session_config = SessionConfig::new().with_sort_spill_merge_fan_in(2);
+        let sort_spill_reservation_bytes = session_config
+            .options()
+            .execution
+            .sort_spill_reservation_bytes;
+        let rt_config = RuntimeConfig::new()
+            .with_memory_limit(sort_spill_reservation_bytes + 12288, 1.0);
+        let runtime = Arc::new(RuntimeEnv::new(rt_config)?);
+        let task_ctx = Arc::new(
+            TaskContext::default()
+                .with_session_config(session_config)
+                .with_runtime(runtime),
+        );
+
+        let input = test::scan_partitioned(100);
+        let schema = input.schema();
+
+        let sort_exec = Arc::new(SortExec::new(
+            vec![PhysicalSortExpr {
+                expr: col("i", &schema)?,
+                options: SortOptions::default(),
+            }],
+            Arc::new(CoalescePartitionsExec::new(input)),
+        ));
+
+        let result = collect(sort_exec.clone(), task_ctx.clone()).await?;
+
+        let metrics = sort_exec.metrics().unwrap();
+        assert_eq!(metrics.output_rows().unwrap(), 10000);
+        assert_eq!(metrics.spill_count().unwrap(), 4);
+
+        let values = result
+            .iter()
+            .flat_map(|batch| {
+                as_primitive_array::<Int32Type>(batch.column(0))
+                    .unwrap()
+                    .values()
+                    .to_vec()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(values.len(), 10000);
+        assert!(values.windows(2).all(|w| w[0] <= w[1]));
+
+        assert_eq!(
+            task_ctx.runtime_env().memory_pool.reserved(),
+            0,
+            "The sort should have returned all memory used back to the memory manager"
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_sort_fetch_memory_calculation() -> Result<()> {
         // This test mirrors down the size from the example above.
@@ -63,6 +63,11 @@ use log::{debug, trace};
 ///  Input Streams                                             Output stream
 ///    (sorted)                                                  (sorted)
 /// ```
+///
+/// The output batches have up to `batch_size` rows. The input batches that
+/// rows are merged from are buffered until the output batch is yielded, and
+/// are accounted for in the memory pool. If no more memory can be reserved,
+/// the rows merged so far are yielded in a smaller batch to release it.
 #[derive(Debug)]
 pub struct SortPreservingMergeExec {
     /// Input plan
@@ -280,6 +285,7 @@ mod tests {
     use arrow::record_batch::RecordBatch;
     use datafusion_common::{assert_batches_eq, assert_contains};
     use datafusion_execution::config::SessionConfig;
+    use datafusion_execution::runtime_env::{RuntimeConfig, RuntimeEnv};
 
     use futures::{FutureExt, StreamExt};
 
@@ -947,6 +953,57 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_merge_memory_limit() -> Result<()> {
+        // one partition of 100 single row batches, merged with one row of
+        // another partition that comes after all of them
+        let batch = |value: i32| {
+            RecordBatch::try_from_iter(vec![(
+                "a",
+                Arc::new(Int32Array::from(vec![value])) as ArrayRef,
+            )])
+        };
+        let partitions = vec![
+            (0..100).map(batch).collect::<Result<Vec<_>, _>>()?,
+            vec![batch(100)?],
+        ];
+        let schema = partitions[0][0].schema();
+        let batch_memory = partitions[0][0].get_array_memory_size();
+
+        // not enough memory to buffer all batches until a full output batch
+        // is merged
+        let rt_config = RuntimeConfig::new().with_memory_limit(batch_memory * 10, 1.0);
+        let task_ctx = Arc::new(
+            TaskContext::default()
+                .with_session_config(SessionConfig::new().with_batch_size(1000))
+                .with_runtime(Arc::new(RuntimeEnv::new(rt_config)?)),
+        );
+
+        let sort = vec![PhysicalSortExpr {
+            expr: col("a", &schema)?,
+            options: SortOptions::default(),
+        }];
+        let exec = MemoryExec::try_new(&partitions, schema, None)?;
+        let merge = Arc::new(SortPreservingMergeExec::new(sort, Arc::new(exec)));
+
+        let collected = collect(merge, task_ctx).await?;
+        assert!(collected.len() > 1);
+        let values = collected
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<Int32Array>()
+                    .unwrap()
+                    .values()
+                    .to_vec()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(values, (0..=100).collect::<Vec<_>>());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_stable_sort() {
         let task_ctx = Arc::new(TaskContext::default());
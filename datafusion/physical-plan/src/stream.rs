@@ -17,6 +17,8 @@
 
 //! Stream wrappers for physical operators
 
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::Context;
@@ -27,7 +29,7 @@ use super::{ExecutionPlan, RecordBatchStream, SendableRecordBatchStream};
 use crate::displayable;
 
 use arrow::{datatypes::SchemaRef, record_batch::RecordBatch};
-use datafusion_common::{internal_err, Result};
+use datafusion_common::{exec_err, internal_err, Result};
 use datafusion_execution::TaskContext;
 
 use futures::stream::BoxStream;
@@ -454,6 +456,91 @@ impl futures::Stream for ObservedStream {
     }
 }
 
+/// Stream wrapper that catches panics raised while polling a partition
+/// stream and returns them as errors, so that a panic in one partition does
+/// not unwind into (and possibly abort) the caller.
+///
+/// The stream ends after the first panic, as the state of the inner stream
+/// is unknown afterwards.
+pub struct CatchUnwindStream {
+    inner: SendableRecordBatchStream,
+    schema: SchemaRef,
+    partition: usize,
+    panicked: bool,
+}
+
+impl CatchUnwindStream {
+    /// Create a new [`CatchUnwindStream`] wrapping the stream of `partition`
+    pub fn new(inner: SendableRecordBatchStream, partition: usize) -> Self {
+        let schema = inner.schema();
+        Self {
+            inner,
+            schema,
+            partition,
+            panicked: false,
+        }
+    }
+}
+
+impl RecordBatchStream for CatchUnwindStream {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+impl Stream for CatchUnwindStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        if self.panicked {
+            return Poll::Ready(None);
+        }
+        let this = &mut *self;
+        let inner = &mut this.inner;
+        match panic::catch_unwind(AssertUnwindSafe(|| inner.poll_next_unpin(cx))) {
+            Ok(poll) => poll,
+            Err(payload) => {
+                this.panicked = true;
+                Poll::Ready(Some(exec_err!(
+                    "Partition {} panicked: {}",
+                    this.partition,
+                    panic_message(payload.as_ref())
+                )))
+            }
+        }
+    }
+}
+
+/// Execute `partition` of `plan`, returning a panic raised while creating or
+/// polling its stream as an error. See [`CatchUnwindStream`].
+pub fn execute_catch_unwind(
+    plan: &Arc<dyn ExecutionPlan>,
+    partition: usize,
+    context: Arc<TaskContext>,
+) -> Result<SendableRecordBatchStream> {
+    match panic::catch_unwind(AssertUnwindSafe(|| plan.execute(partition, context))) {
+        Ok(stream) => Ok(Box::pin(CatchUnwindStream::new(stream?, partition))),
+        Err(payload) => exec_err!(
+            "Partition {partition} panicked: {}",
+            panic_message(payload.as_ref())
+        ),
+    }
+}
+
+/// The message of a panic payload, which is usually a `&str` or a `String`
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -462,7 +549,6 @@ mod test {
     };
 
     use arrow_schema::{DataType, Field, Schema};
-    use datafusion_common::exec_err;
 
     fn schema() -> SchemaRef {
         Arc::new(Schema::new(vec![Field::new("a", DataType::Float32, true)]))
@@ -546,6 +632,25 @@ mod test {
         assert!(stream.next().await.is_none());
     }
 
+    #[tokio::test]
+    async fn catch_unwind_stream_returns_panics_as_errors() {
+        let task_ctx = Arc::new(TaskContext::default());
+        let input: Arc<dyn ExecutionPlan> =
+            Arc::new(PanicExec::new(schema(), 2).with_partition_panic(1, 2));
+
+        let mut stream = execute_catch_unwind(&input, 1, task_ctx).unwrap();
+        // PanicExec yields two batches, then panics
+        assert!(stream.next().await.unwrap().is_ok());
+        assert!(stream.next().await.unwrap().is_ok());
+        let err = stream.next().await.unwrap().unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: Partition 1 panicked: PanickingStream did panic: 1"
+        );
+        // the stream ends after a panic
+        assert!(stream.next().await.is_none());
+    }
+
     /// Consumes all the input's partitions into a
     /// RecordBatchReceiverStream and runs it to completion
     ///
@@ -15,8 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines the unnest column plan for unnesting values in a column that contains a list
-//! type, conceptually is like joining each row with all the values in the list column.
+//! Defines the unnest column plan for unnesting values in columns that contain a list
+//! type, conceptually is like joining each row with all the values in the list columns.
 use std::{any::Any, sync::Arc};
 
 use super::metrics::{self, ExecutionPlanMetricsSet, MetricBuilder, MetricsSet};
@@ -27,14 +27,15 @@ use crate::{
 };
 
 use arrow::array::{
-    Array, ArrayRef, ArrowPrimitiveType, FixedSizeListArray, GenericListArray,
-    LargeListArray, ListArray, OffsetSizeTrait, PrimitiveArray,
+    Array, ArrayRef, FixedSizeListArray, GenericListArray, OffsetSizeTrait,
+    PrimitiveArray,
 };
 use arrow::compute::kernels;
-use arrow::datatypes::{
-    ArrowNativeType, DataType, Int32Type, Int64Type, Schema, SchemaRef,
-};
+use arrow::datatypes::{DataType, Int64Type, Schema, SchemaRef};
 use arrow::record_batch::RecordBatch;
+use datafusion_common::cast::{
+    as_fixed_size_list_array, as_large_list_array, as_list_array,
+};
 use datafusion_common::{exec_err, Result, UnnestOptions};
 use datafusion_execution::TaskContext;
 use datafusion_physical_expr::EquivalenceProperties;
@@ -43,8 +44,9 @@ use async_trait::async_trait;
 use futures::{Stream, StreamExt};
 use log::trace;
 
-/// Unnest the given column by joining the row with each value in the
-/// nested type.
+/// Unnest the given columns by joining the row with each value in the
+/// nested types. The lists of a row are zipped, with the shorter lists
+/// padded with nulls.
 ///
 /// See [`UnnestOptions`] for more details and an example.
 #[derive(Debug)]
@@ -53,8 +55,8 @@ pub struct UnnestExec {
     input: Arc<dyn ExecutionPlan>,
     /// The schema once the unnest is applied
     schema: SchemaRef,
-    /// The unnest column
-    column: Column,
+    /// The unnest columns
+    columns: Vec<Column>,
     /// Options
     options: UnnestOptions,
     /// Execution metrics
@@ -67,7 +69,7 @@ impl UnnestExec {
     /// Create a new [UnnestExec].
     pub fn new(
         input: Arc<dyn ExecutionPlan>,
-        column: Column,
+        columns: Vec<Column>,
         schema: SchemaRef,
         options: UnnestOptions,
     ) -> Self {
@@ -75,7 +77,7 @@ impl UnnestExec {
         UnnestExec {
             input,
             schema,
-            column,
+            columns,
             options,
             metrics: Default::default(),
             cache,
@@ -134,7 +136,7 @@ impl ExecutionPlan for UnnestExec {
     ) -> Result<Arc<dyn ExecutionPlan>> {
         Ok(Arc::new(UnnestExec::new(
             children[0].clone(),
-            self.column.clone(),
+            self.columns.clone(),
             self.schema.clone(),
             self.options.clone(),
         )))
@@ -155,7 +157,7 @@ impl ExecutionPlan for UnnestExec {
         Ok(Box::pin(UnnestStream {
             input,
             schema: self.schema.clone(),
-            column: self.column.clone(),
+            columns: self.columns.clone(),
             options: self.options.clone(),
             metrics,
         }))
@@ -210,8 +212,8 @@ struct UnnestStream {
     input: SendableRecordBatchStream,
     /// Unnested schema
     schema: Arc<Schema>,
-    /// The unnest column
-    column: Column,
+    /// The unnest columns
+    columns: Vec<Column>,
     /// Options
     options: UnnestOptions,
     /// Metrics
@@ -249,7 +251,7 @@ impl UnnestStream {
                 Some(Ok(batch)) => {
                     let timer = self.metrics.elapsed_compute.timer();
                     let result =
-                        build_batch(&batch, &self.schema, &self.column, &self.options);
+                        build_batch(&batch, &self.schema, &self.columns, &self.options);
                     self.metrics.input_batches.add(1);
                     self.metrics.input_rows.add(batch.num_rows());
                     if let Ok(ref batch) = result {
@@ -276,335 +278,237 @@ impl UnnestStream {
     }
 }
 
-fn build_batch(
-    batch: &RecordBatch,
-    schema: &SchemaRef,
-    column: &Column,
-    options: &UnnestOptions,
-) -> Result<RecordBatch> {
-    let list_array = column.evaluate(batch)?.into_array(batch.num_rows())?;
-    match list_array.data_type() {
-        DataType::List(_) => {
-            let list_array = list_array.as_any().downcast_ref::<ListArray>().unwrap();
-            build_batch_generic_list::<i32, Int32Type>(
-                batch,
-                schema,
-                column.index(),
-                list_array,
-                options,
-            )
-        }
-        DataType::LargeList(_) => {
-            let list_array = list_array
-                .as_any()
-                .downcast_ref::<LargeListArray>()
-                .unwrap();
-            build_batch_generic_list::<i64, Int64Type>(
-                batch,
-                schema,
-                column.index(),
-                list_array,
-                options,
-            )
-        }
-        DataType::FixedSizeList(_, _) => {
-            let list_array = list_array
-                .as_any()
-                .downcast_ref::<FixedSizeListArray>()
-                .unwrap();
-            build_batch_fixedsize_list(batch, schema, column.index(), list_array, options)
-        }
-        _ => exec_err!("Invalid unnest column {column}"),
-    }
+/// A list array whose values can be accessed by the offsets of each row
+trait ListArrayType: Array {
+    /// Returns the values of all lists
+    fn values(&self) -> &ArrayRef;
+
+    /// Returns the start and end offsets of the values of the list at `row`
+    fn value_offsets(&self, row: usize) -> (i64, i64);
 }
 
-fn build_batch_generic_list<T: OffsetSizeTrait, P: ArrowPrimitiveType<Native = T>>(
-    batch: &RecordBatch,
-    schema: &SchemaRef,
-    unnest_column_idx: usize,
-    list_array: &GenericListArray<T>,
-    options: &UnnestOptions,
-) -> Result<RecordBatch> {
-    let unnested_array = unnest_generic_list::<T, P>(list_array, options)?;
-
-    let take_indicies =
-        create_take_indicies_generic::<T, P>(list_array, unnested_array.len(), options);
-
-    batch_from_indices(
-        batch,
-        schema,
-        unnest_column_idx,
-        &unnested_array,
-        &take_indicies,
-    )
+impl<O: OffsetSizeTrait> ListArrayType for GenericListArray<O> {
+    fn values(&self) -> &ArrayRef {
+        self.values()
+    }
+
+    fn value_offsets(&self, row: usize) -> (i64, i64) {
+        let offsets = self.value_offsets();
+        (
+            offsets[row].as_usize() as i64,
+            offsets[row + 1].as_usize() as i64,
+        )
+    }
 }
 
-/// Given this `GenericList` list_array:
-///
-/// ```ignore
-/// [1], null, [2, 3, 4], null, [5, 6]
-/// ```
-/// Its values array is represented like this:
-///
-/// ```ignore
-/// [1, 2, 3, 4, 5, 6]
-/// ```
-///
-/// So if there are no null values or `UnnestOptions.preserve_nulls` is false
-/// we can return the values array without any copying.
-///
-/// Otherwise we'll transfrom the values array using the take kernel and the following take indicies:
-///
-/// ```ignore
-/// 0, null, 1, 2, 3, null, 4, 5
-/// ```
-///
-fn unnest_generic_list<T: OffsetSizeTrait, P: ArrowPrimitiveType<Native = T>>(
-    list_array: &GenericListArray<T>,
-    options: &UnnestOptions,
-) -> Result<Arc<dyn Array + 'static>> {
-    let values = list_array.values();
-    if list_array.null_count() == 0 {
-        return Ok(values.clone());
+impl ListArrayType for FixedSizeListArray {
+    fn values(&self) -> &ArrayRef {
+        self.values()
     }
 
-    let mut take_indicies_builder =
-        PrimitiveArray::<P>::builder(values.len() + list_array.null_count());
-    let offsets = list_array.value_offsets();
-    for row in 0..list_array.len() {
-        if list_array.is_null(row) {
-            if options.preserve_nulls {
-                take_indicies_builder.append_null();
-            }
-        } else {
-            let start = offsets[row].as_usize();
-            let end = offsets[row + 1].as_usize();
-            for idx in start..end {
-                take_indicies_builder.append_value(P::Native::from_usize(idx).unwrap());
-            }
-        }
+    fn value_offsets(&self, row: usize) -> (i64, i64) {
+        let start = self.value_offset(row) as i64;
+        (start, start + self.value_length() as i64)
     }
-    Ok(kernels::take::take(
-        &values,
-        &take_indicies_builder.finish(),
-        None,
-    )?)
 }
 
-fn build_batch_fixedsize_list(
+fn as_list_array_type<'a>(
+    array: &'a ArrayRef,
+    column: &Column,
+) -> Result<&'a dyn ListArrayType> {
+    let list_array: &dyn ListArrayType = match array.data_type() {
+        DataType::List(_) => as_list_array(array)?,
+        DataType::LargeList(_) => as_large_list_array(array)?,
+        DataType::FixedSizeList(_, _) => as_fixed_size_list_array(array)?,
+        _ => return exec_err!("Invalid unnest column {column}"),
+    };
+    Ok(list_array)
+}
+
+fn build_batch(
     batch: &RecordBatch,
     schema: &SchemaRef,
-    unnest_column_idx: usize,
-    list_array: &FixedSizeListArray,
+    columns: &[Column],
     options: &UnnestOptions,
 ) -> Result<RecordBatch> {
-    let unnested_array = unnest_fixed_list(list_array, options)?;
-
-    let take_indicies =
-        create_take_indicies_fixed(list_array, unnested_array.len(), options);
-
-    batch_from_indices(
-        batch,
-        schema,
-        unnest_column_idx,
-        &unnested_array,
-        &take_indicies,
-    )
+    let arrays = columns
+        .iter()
+        .map(|column| column.evaluate(batch)?.into_array(batch.num_rows()))
+        .collect::<Result<Vec<_>>>()?;
+    let list_arrays = arrays
+        .iter()
+        .zip(columns)
+        .map(|(array, column)| as_list_array_type(array, column))
+        .collect::<Result<Vec<_>>>()?;
+
+    let lengths = unnested_lengths(&list_arrays, batch.num_rows(), options);
+    let unnested_arrays = list_arrays
+        .iter()
+        .map(|list_array| unnest_list_array(*list_array, &lengths))
+        .collect::<Result<Vec<_>>>()?;
+    let take_indices = create_take_indices(&lengths);
+
+    let mut arrays = batch
+        .columns()
+        .iter()
+        .enumerate()
+        .map(|(col_idx, arr)| {
+            match columns.iter().position(|column| column.index() == col_idx) {
+                Some(i) => Ok(unnested_arrays[i].clone()),
+                None => Ok(kernels::take::take(arr, &take_indices, None)?),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if options.ordinality.is_some() {
+        arrays.push(create_ordinality(&lengths));
+    }
+
+    Ok(RecordBatch::try_new(schema.clone(), arrays)?)
 }
 
-/// Given this `FixedSizeListArray` list_array:
+/// Returns the number of output rows of each input row, which is the length
+/// of its longest list.
 ///
-/// ```ignore
-/// [1, 2], null, [3, 4], null, [5, 6]
-/// ```
-/// Its values array is represented like this:
+/// For example, given these list columns:
 ///
 /// ```ignore
-/// [1, 2, null, null 3, 4, null, null, 5, 6]
+/// c1: [1, 2], null, [],   [3]
+/// c2: [A],    null, null, [B, C, D]
 /// ```
 ///
-/// So if there are no null values
-/// we can return the values array without any copying.
-///
-/// Otherwise we'll transfrom the values array using the take kernel.
-///
-/// If `UnnestOptions.preserve_nulls` is true the take indicies will look like this:
-///
-/// ```ignore
-/// 0, 1, null, 4, 5, null, 8, 9
-/// ```
-/// Otherwise we drop the nulls and take indicies will look like this:
+/// the lengths are:
 ///
 /// ```ignore
-/// 0, 1, 4, 5, 8, 9
+/// 2, 1, 0, 3
 /// ```
 ///
-fn unnest_fixed_list(
-    list_array: &FixedSizeListArray,
+/// A null list has a length of one if `UnnestOptions.preserve_nulls` is true,
+/// and of zero otherwise, so the length of the second row is zero if
+/// `preserve_nulls` is false.
+fn unnested_lengths(
+    list_arrays: &[&dyn ListArrayType],
+    num_rows: usize,
     options: &UnnestOptions,
-) -> Result<Arc<dyn Array + 'static>> {
-    let values = list_array.values();
-
-    if list_array.null_count() == 0 {
-        Ok(values.clone())
-    } else {
-        let len_without_nulls =
-            values.len() - list_array.null_count() * list_array.value_length() as usize;
-        let null_count = if options.preserve_nulls {
-            list_array.null_count()
-        } else {
-            0
-        };
-        let mut builder =
-            PrimitiveArray::<Int32Type>::builder(len_without_nulls + null_count);
-        let mut take_offset = 0;
-        let fixed_value_length = list_array.value_length() as usize;
-        list_array.iter().for_each(|elem| match elem {
-            Some(_) => {
-                for i in 0..fixed_value_length {
-                    //take_offset + i is always positive
-                    let take_index = take_offset + i;
-                    builder.append_value(take_index as i32);
-                }
-                take_offset += fixed_value_length;
-            }
-            None => {
-                if options.preserve_nulls {
-                    builder.append_null();
-                }
-                take_offset += fixed_value_length;
-            }
-        });
-        Ok(kernels::take::take(&values, &builder.finish(), None)?)
-    }
+) -> Vec<usize> {
+    let null_length = if options.preserve_nulls { 1 } else { 0 };
+    (0..num_rows)
+        .map(|row| {
+            list_arrays
+                .iter()
+                .map(|list_array| {
+                    if list_array.is_null(row) {
+                        null_length
+                    } else {
+                        let (start, end) = list_array.value_offsets(row);
+                        (end - start) as usize
+                    }
+                })
+                .max()
+                .unwrap_or(0)
+        })
+        .collect()
 }
 
-/// Creates take indicies to be used to expand all other column's data.
-/// Every column value needs to be repeated as many times as many elements there is in each corresponding array value.
+/// Returns the values of `list_array`, padded with nulls to the `lengths` of
+/// each row.
 ///
-/// If the column being unnested looks like this:
+/// Given this list array and lengths:
 ///
 /// ```ignore
-/// [1], null, [2, 3, 4], null, [5, 6]
+/// list_array: [1], null, [2, 3, 4], null, [5, 6]
+/// lengths:     2,  1,    3,         0,    2
 /// ```
-/// Then `create_take_indicies_generic` will return an array like this
+///
+/// the values are taken with these indices from the values array
+/// `[1, 2, 3, 4, 5, 6]`:
 ///
 /// ```ignore
-/// [1, null, 2, 2, 2, null, 4, 4]
+/// 0, null, null, 1, 2, 3, 4, 5
 /// ```
 ///
-fn create_take_indicies_generic<T: OffsetSizeTrait, P: ArrowPrimitiveType<Native = T>>(
-    list_array: &GenericListArray<T>,
-    capacity: usize,
-    options: &UnnestOptions,
-) -> PrimitiveArray<P> {
-    let mut builder = PrimitiveArray::<P>::builder(capacity);
-    let null_repeat: usize = if options.preserve_nulls { 1 } else { 0 };
-
-    for row in 0..list_array.len() {
-        let repeat = if list_array.is_null(row) {
-            null_repeat
+/// If no padding is needed, the values array is returned without copying.
+fn unnest_list_array(
+    list_array: &dyn ListArrayType,
+    lengths: &[usize],
+) -> Result<ArrayRef> {
+    let values = list_array.values();
+    let capacity = lengths.iter().sum::<usize>();
+    let mut take_indices_builder = PrimitiveArray::<Int64Type>::builder(capacity);
+    let mut contiguous = true;
+    let mut next_offset = 0;
+    for (row, length) in lengths.iter().enumerate() {
+        let (start, end) = if list_array.is_null(row) {
+            (0, 0)
         } else {
-            list_array.value(row).len()
+            list_array.value_offsets(row)
         };
-
-        // `index` is a positive interger.
-        let index = P::Native::from_usize(row).unwrap();
-        (0..repeat).for_each(|_| builder.append_value(index));
+        if start != next_offset || (end - start) as usize != *length {
+            contiguous = false;
+        }
+        next_offset = end;
+        for i in 0..*length as i64 {
+            if start + i < end {
+                take_indices_builder.append_value(start + i);
+            } else {
+                take_indices_builder.append_null();
+            }
+        }
     }
-
-    builder.finish()
-}
-
-fn create_take_indicies_fixed(
-    list_array: &FixedSizeListArray,
-    capacity: usize,
-    options: &UnnestOptions,
-) -> PrimitiveArray<Int32Type> {
-    let mut builder = PrimitiveArray::<Int32Type>::builder(capacity);
-    let null_repeat: usize = if options.preserve_nulls { 1 } else { 0 };
-
-    for row in 0..list_array.len() {
-        let repeat = if list_array.is_null(row) {
-            null_repeat
-        } else {
-            list_array.value_length() as usize
-        };
-
-        // `index` is a positive interger.
-        let index = <Int32Type as ArrowPrimitiveType>::Native::from_usize(row).unwrap();
-        (0..repeat).for_each(|_| builder.append_value(index));
+    if contiguous && capacity == values.len() {
+        return Ok(values.clone());
     }
-
-    builder.finish()
+    Ok(kernels::take::take(
+        values,
+        &take_indices_builder.finish(),
+        None,
+    )?)
 }
 
-/// Create the final batch given the unnested column array and a `indices` array
-/// that is used by the take kernel to copy values.
+/// Creates take indices to be used to expand all other column's data.
+/// Every column value needs to be repeated as many times as the length
+/// of the corresponding row.
 ///
-/// For example if we have the following `RecordBatch`:
+/// If the lengths look like this:
 ///
 /// ```ignore
-/// c1: [1], null, [2, 3, 4], null, [5, 6]
-/// c2: 'a', 'b',  'c', null, 'd'
+/// 1, 0, 3, 1, 2
 /// ```
-///
-/// then the `unnested_array` contains the unnest column that will replace `c1` in
-/// the final batch:
-///
-/// ```ignore
-/// c1: 1, null, 2, 3, 4, null, 5, 6
-/// ```
-///
-/// And the `indices` array contains the indices that are used by `take` kernel to
-/// repeat the values in `c2`:
-///
-/// ```ignore
-/// 0, 1, 2, 2, 2, 3, 4, 4
-/// ```
-///
-/// so that the final batch will look like:
+/// Then `create_take_indices` will return an array like this
 ///
 /// ```ignore
-/// c1: 1, null, 2, 3, 4, null, 5, 6
-/// c2: 'a', 'b', 'c', 'c', 'c', null, 'd', 'd'
+/// [0, 2, 2, 2, 3, 4, 4]
 /// ```
 ///
-fn batch_from_indices<T>(
-    batch: &RecordBatch,
-    schema: &SchemaRef,
-    unnest_column_idx: usize,
-    unnested_array: &ArrayRef,
-    indices: &PrimitiveArray<T>,
-) -> Result<RecordBatch>
-where
-    T: ArrowPrimitiveType,
-{
-    let arrays = batch
-        .columns()
-        .iter()
-        .enumerate()
-        .map(|(col_idx, arr)| {
-            if col_idx == unnest_column_idx {
-                Ok(unnested_array.clone())
-            } else {
-                Ok(kernels::take::take(&arr, indices, None)?)
-            }
-        })
-        .collect::<Result<Vec<_>>>()?;
+fn create_take_indices(lengths: &[usize]) -> PrimitiveArray<Int64Type> {
+    let capacity = lengths.iter().sum::<usize>();
+    let mut builder = PrimitiveArray::<Int64Type>::builder(capacity);
+    for (row, length) in lengths.iter().enumerate() {
+        (0..*length).for_each(|_| builder.append_value(row as i64));
+    }
+    builder.finish()
+}
 
-    Ok(RecordBatch::try_new(schema.clone(), arrays.to_vec())?)
+/// Creates the ordinality column, with the 1-based position of each output
+/// row within the rows of its input row
+fn create_ordinality(lengths: &[usize]) -> ArrayRef {
+    let capacity = lengths.iter().sum::<usize>();
+    let mut builder = PrimitiveArray::<Int64Type>::builder(capacity);
+    for length in lengths {
+        (1..=*length as i64).for_each(|i| builder.append_value(i));
+    }
+    Arc::new(builder.finish())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use arrow::{
-        array::AsArray,
+        array::{AsArray, Int64Array, ListArray},
         datatypes::{DataType, Field},
     };
     use arrow_array::StringArray;
     use arrow_buffer::{BooleanBufferBuilder, NullBuffer, OffsetBuffer};
+    use datafusion_common::assert_batches_eq;
 
     // Create a ListArray with the following list values:
     //  [A, B, C], [], NULL, [D], NULL, [NULL, F]
@@ -651,32 +555,43 @@ mod tests {
         )
     }
 
+    fn unnest_strings(
+        list_array: &ListArray,
+        options: &UnnestOptions,
+    ) -> Result<Vec<Option<String>>> {
+        let lengths = unnested_lengths(
+            &[list_array as &dyn ListArrayType],
+            list_array.len(),
+            options,
+        );
+        let unnested_array = unnest_list_array(list_array, &lengths)?;
+        Ok(unnested_array
+            .as_string::<i32>()
+            .iter()
+            .map(|s| s.map(String::from))
+            .collect())
+    }
+
+    fn strings(values: &[Option<&str>]) -> Vec<Option<String>> {
+        values.iter().map(|s| s.map(String::from)).collect()
+    }
+
     #[test]
-    fn test_unnest_generic_list() -> datafusion_common::Result<()> {
+    fn test_unnest_list_array() -> Result<()> {
         let list_array = make_test_array();
 
         // Test with preserve_nulls = false
-        let options = UnnestOptions {
-            preserve_nulls: false,
-        };
-        let unnested_array =
-            unnest_generic_list::<i32, Int32Type>(&list_array, &options)?;
-        let strs = unnested_array.as_string::<i32>().iter().collect::<Vec<_>>();
+        let options = UnnestOptions::new().with_preserve_nulls(false);
         assert_eq!(
-            strs,
-            vec![Some("A"), Some("B"), Some("C"), Some("D"), None, Some("F")]
+            unnest_strings(&list_array, &options)?,
+            strings(&[Some("A"), Some("B"), Some("C"), Some("D"), None, Some("F")])
         );
 
         // Test with preserve_nulls = true
-        let options = UnnestOptions {
-            preserve_nulls: true,
-        };
-        let unnested_array =
-            unnest_generic_list::<i32, Int32Type>(&list_array, &options)?;
-        let strs = unnested_array.as_string::<i32>().iter().collect::<Vec<_>>();
+        let options = UnnestOptions::new().with_preserve_nulls(true);
         assert_eq!(
-            strs,
-            vec![
+            unnest_strings(&list_array, &options)?,
+            strings(&[
                 Some("A"),
                 Some("B"),
                 Some("C"),
@@ -685,9 +600,60 @@ mod tests {
                 None,
                 None,
                 Some("F")
-            ]
+            ])
         );
 
         Ok(())
     }
+
+    #[test]
+    fn test_unnest_multiple_columns() -> Result<()> {
+        // c1: [A, B, C], [], NULL, [D], NULL, [NULL, F]
+        // c2: [1], [2, 3], [4], [], NULL, NULL
+        let c1 = Arc::new(make_test_array());
+        let c2 = Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            Some(vec![Some(2), Some(3)]),
+            Some(vec![Some(4)]),
+            Some(vec![]),
+            None,
+            None,
+        ]));
+        let c3 = Arc::new(Int64Array::from(vec![10, 20, 30, 40, 50, 60]));
+        let batch = RecordBatch::try_from_iter(vec![
+            ("c1", c1 as ArrayRef),
+            ("c2", c2 as ArrayRef),
+            ("c3", c3 as ArrayRef),
+        ])?;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("c1", DataType::Utf8, true),
+            Field::new("c2", DataType::Int64, true),
+            Field::new("c3", DataType::Int64, false),
+            Field::new("n", DataType::Int64, false),
+        ]));
+        let columns = vec![Column::new("c1", 0), Column::new("c2", 1)];
+        let options = UnnestOptions::new()
+            .with_preserve_nulls(false)
+            .with_ordinality("n");
+        let result = build_batch(&batch, &schema, &columns, &options)?;
+
+        let expected = [
+            "+----+----+----+---+",
+            "| c1 | c2 | c3 | n |",
+            "+----+----+----+---+",
+            "| A  | 1  | 10 | 1 |",
+            "| B  |    | 10 | 2 |",
+            "| C  |    | 10 | 3 |",
+            "|    | 2  | 20 | 1 |",
+            "|    | 3  | 20 | 2 |",
+            "|    | 4  | 30 | 1 |",
+            "| D  |    | 40 | 1 |",
+            "|    |    | 60 | 1 |",
+            "| F  |    | 60 | 2 |",
+            "+----+----+----+---+",
+        ];
+        assert_batches_eq!(expected, &[result]);
+        Ok(())
+    }
 }
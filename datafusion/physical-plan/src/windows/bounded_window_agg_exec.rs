@@ -28,6 +28,7 @@ use std::sync::Arc;
 use std::task::{Context, Poll};
 
 use crate::expressions::PhysicalSortExpr;
+use crate::hash_table::GroupIndexMap;
 use crate::metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet};
 use crate::windows::{
     calc_requirements, get_ordered_partition_by_indices, get_partition_by_sort_exprs,
@@ -444,10 +445,10 @@ pub struct LinearSearch {
     /// is ordered by a, b and the window expression contains a PARTITION BY b, a
     /// clause, this attribute stores [1, 0].
     ordered_partition_by_indices: Vec<usize>,
-    /// We use this [`RawTable`] to calculate unique partitions for each new
-    /// RecordBatch. First entry in the tuple is the hash value, the second
-    /// entry is the unique ID for each partition (increments from 0 to n).
-    row_map_batch: RawTable<(u64, usize)>,
+    /// We use this map to calculate unique partitions for each new
+    /// RecordBatch. It maps the hash value to the unique ID for each
+    /// partition (increments from 0 to n).
+    row_map_batch: GroupIndexMap,
     /// We use this [`RawTable`] to calculate the output columns that we can
     /// produce at each cycle. First entry in the tuple is the hash value, the
     /// second entry is the unique ID for each partition (increments from 0 to n).
@@ -610,7 +611,7 @@ impl LinearSearch {
             input_buffer_hashes: VecDeque::new(),
             random_state: Default::default(),
             ordered_partition_by_indices,
-            row_map_batch: RawTable::with_capacity(256),
+            row_map_batch: GroupIndexMap::with_capacity(256),
             row_map_out: RawTable::with_capacity(256),
             input_schema,
         }
@@ -650,18 +651,17 @@ impl LinearSearch {
         // res stores PartitionKey and row indices (indices where these partition occurs in the `batch`) for each partition.
         let mut result: Vec<(PartitionKey, Vec<u32>)> = vec![];
         for (hash, row_idx) in batch_hashes.into_iter().zip(0u32..) {
-            let entry = self.row_map_batch.get_mut(hash, |(_, group_idx)| {
+            let entry = self.row_map_batch.get(hash, |group_idx| {
                 // We can safely get the first index of the partition indices
                 // since partition indices has one element during initialization.
                 let row = get_row_at_idx(columns, row_idx as usize).unwrap();
                 // Handle hash collusions with an equality check:
-                row.eq(&result[*group_idx].0)
+                row.eq(&result[group_idx].0)
             });
-            if let Some((_, group_idx)) = entry {
-                result[*group_idx].1.push(row_idx)
+            if let Some(group_idx) = entry {
+                result[group_idx].1.push(row_idx)
             } else {
-                self.row_map_batch
-                    .insert(hash, (hash, result.len()), |(hash, _)| *hash);
+                self.row_map_batch.insert(hash, result.len());
                 let row = get_row_at_idx(columns, row_idx as usize)?;
                 // This is a new partition its only index is row_idx for now.
                 result.push((row, vec![row_idx]));
@@ -1176,8 +1176,10 @@ mod tests {
     use std::task::{Context, Poll};
     use std::time::Duration;
 
+    use super::BoundedWindowAggStream;
     use crate::common::collect;
     use crate::memory::MemoryExec;
+    use crate::metrics::{BaselineMetrics, ExecutionPlanMetricsSet};
     use crate::projection::ProjectionExec;
     use crate::streaming::{PartitionStream, StreamingTableExec};
     use crate::windows::{create_window_expr, BoundedWindowAggExec, InputOrderMode};
@@ -1629,6 +1631,143 @@ mod tests {
         Ok(())
     }
 
+    fn sorted_int64_exec() -> Result<Arc<dyn ExecutionPlan>> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let batches = [vec![1, 1, 2], vec![2, 2, 3], vec![3, 3, 3]]
+            .into_iter()
+            .map(|values| {
+                RecordBatch::try_new(
+                    schema.clone(),
+                    vec![Arc::new(arrow_array::Int64Array::from(values))],
+                )
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(Arc::new(MemoryExec::try_new(&[batches], schema, None)?))
+    }
+
+    /// SUM(a) OVER (ORDER BY a <frame>) for each of the given frames
+    fn cumulative_sum_exec(
+        input: Arc<dyn ExecutionPlan>,
+        frames: Vec<(&str, WindowFrame)>,
+    ) -> Result<BoundedWindowAggExec> {
+        let schema = input.schema();
+        let order_by = vec![PhysicalSortExpr {
+            expr: col("a", &schema)?,
+            options: SortOptions::default(),
+        }];
+        let window_exprs = frames
+            .into_iter()
+            .map(|(name, frame)| {
+                create_window_expr(
+                    &WindowFunctionDefinition::AggregateFunction(AggregateFunction::Sum),
+                    name.to_string(),
+                    &[col("a", &schema)?],
+                    &[],
+                    &order_by,
+                    Arc::new(frame),
+                    &schema,
+                    false,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        BoundedWindowAggExec::try_new(window_exprs, input, vec![], InputOrderMode::Sorted)
+    }
+
+    // Tests that aggregates over frames starting at UNBOUNDED PRECEDING only
+    // keep the last row of the partition in memory, instead of the whole
+    // partition.
+    #[tokio::test]
+    async fn test_window_cumulative_aggregate_bounded_memory() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+        let frame = WindowFrame::new_bounds(
+            WindowFrameUnits::Rows,
+            WindowFrameBound::Preceding(ScalarValue::UInt64(None)),
+            WindowFrameBound::CurrentRow,
+        );
+        let exec = cumulative_sum_exec(sorted_int64_exec()?, vec![("sum", frame)])?;
+
+        let mut stream = BoundedWindowAggStream::new(
+            exec.schema(),
+            exec.window_expr().to_vec(),
+            exec.input().execute(0, task_ctx)?,
+            BaselineMetrics::new(&ExecutionPlanMetricsSet::new(), 0),
+            exec.get_search_algo()?,
+        )?;
+        let mut batches = vec![];
+        while let Some(batch) = stream.next().await {
+            batches.push(batch?);
+            let buffered_rows: usize = stream
+                .partition_buffers
+                .values()
+                .map(|state| state.record_batch.num_rows())
+                .sum();
+            assert!(buffered_rows <= 1, "{buffered_rows} rows buffered");
+        }
+
+        let expected = [
+            "+---+-----+",
+            "| a | sum |",
+            "+---+-----+",
+            "| 1 | 1   |",
+            "| 1 | 2   |",
+            "| 2 | 4   |",
+            "| 2 | 6   |",
+            "| 2 | 8   |",
+            "| 3 | 11  |",
+            "| 3 | 14  |",
+            "| 3 | 17  |",
+            "| 3 | 20  |",
+            "+---+-----+",
+        ];
+        assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+
+    // Tests cumulative RANGE frames whose peer groups span several batches,
+    // which are evaluated after some rows of the partition were pruned.
+    #[tokio::test]
+    async fn test_window_cumulative_range_across_batches() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+        let frames = vec![
+            (
+                "current_row",
+                WindowFrame::new_bounds(
+                    WindowFrameUnits::Range,
+                    WindowFrameBound::Preceding(ScalarValue::Int64(None)),
+                    WindowFrameBound::CurrentRow,
+                ),
+            ),
+            (
+                "one_preceding",
+                WindowFrame::new_bounds(
+                    WindowFrameUnits::Range,
+                    WindowFrameBound::Preceding(ScalarValue::Int64(None)),
+                    WindowFrameBound::Preceding(ScalarValue::Int64(Some(1))),
+                ),
+            ),
+        ];
+        let exec = cumulative_sum_exec(sorted_int64_exec()?, frames)?;
+        let batches = collect(exec.execute(0, task_ctx)?).await?;
+
+        let expected = [
+            "+---+-------------+---------------+",
+            "| a | current_row | one_preceding |",
+            "+---+-------------+---------------+",
+            "| 1 | 2           |               |",
+            "| 1 | 2           |               |",
+            "| 2 | 8           | 2             |",
+            "| 2 | 8           | 2             |",
+            "| 2 | 8           | 2             |",
+            "| 3 | 20          | 8             |",
+            "| 3 | 20          | 8             |",
+            "| 3 | 20          | 8             |",
+            "| 3 | 20          | 8             |",
+            "+---+-------------+---------------+",
+        ];
+        assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+
     // This test, tests whether most recent row guarantee by the input batch of the `BoundedWindowAggExec`
     // helps `BoundedWindowAggExec` to generate low latency result in the `Linear` mode.
     // Input data generated at the source is
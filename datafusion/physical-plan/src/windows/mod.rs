@@ -25,7 +25,7 @@ use crate::{
     aggregates,
     expressions::{
         cume_dist, dense_rank, lag, lead, percent_rank, rank, Literal, NthValue, Ntile,
-        PhysicalSortExpr, RowNumber,
+        PhysicalSortExpr, RowNumber, SessionId,
     },
     udaf, ExecutionPlan, ExecutionPlanProperties, InputOrderMode, PhysicalExpr,
 };
@@ -263,6 +263,21 @@ fn create_built_in_window_expr(
                 ignore_nulls,
             )?)
         }
+        BuiltInWindowFunction::SessionId => {
+            let gap = get_scalar_value_from_args(args, 1)?.ok_or_else(|| {
+                DataFusionError::Execution("SESSION_ID requires a gap".to_string())
+            })?;
+            if gap.is_null() {
+                return exec_err!("SESSION_ID requires a gap, but finds NULL");
+            }
+            // intervals and durations are added to timestamps as is, other
+            // gaps must have the same type as the timestamps
+            let gap = match gap.data_type() {
+                DataType::Interval(_) | DataType::Duration(_) => gap,
+                _ => gap.cast_to(&args[0].data_type(input_schema)?)?,
+            };
+            Arc::new(SessionId::new(name, args[0].clone(), gap, out_data_type))
+        }
         BuiltInWindowFunction::FirstValue => {
             let arg = args[0].clone();
             Arc::new(NthValue::first(
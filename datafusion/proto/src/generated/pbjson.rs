@@ -458,6 +458,10 @@ impl serde::Serialize for AggregateFunction {
             Self::RegrSxy => "REGR_SXY",
             Self::StringAgg => "STRING_AGG",
             Self::NthValueAgg => "NTH_VALUE_AGG",
+            Self::ApproxDistinctSketch => "APPROX_DISTINCT_SKETCH",
+            Self::ApproxDistinctMerge => "APPROX_DISTINCT_MERGE",
+            Self::ApproxPercentileContSketch => "APPROX_PERCENTILE_CONT_SKETCH",
+            Self::ApproxPercentileContMerge => "APPROX_PERCENTILE_CONT_MERGE",
         };
         serializer.serialize_str(variant)
     }
@@ -506,6 +510,10 @@ impl<'de> serde::Deserialize<'de> for AggregateFunction {
             "REGR_SXY",
             "STRING_AGG",
             "NTH_VALUE_AGG",
+            "APPROX_DISTINCT_SKETCH",
+            "APPROX_DISTINCT_MERGE",
+            "APPROX_PERCENTILE_CONT_SKETCH",
+            "APPROX_PERCENTILE_CONT_MERGE",
         ];
 
         struct GeneratedVisitor;
@@ -583,6 +591,10 @@ impl<'de> serde::Deserialize<'de> for AggregateFunction {
                     "REGR_SXY" => Ok(AggregateFunction::RegrSxy),
                     "STRING_AGG" => Ok(AggregateFunction::StringAgg),
                     "NTH_VALUE_AGG" => Ok(AggregateFunction::NthValueAgg),
+                    "APPROX_DISTINCT_SKETCH" => Ok(AggregateFunction::ApproxDistinctSketch),
+                    "APPROX_DISTINCT_MERGE" => Ok(AggregateFunction::ApproxDistinctMerge),
+                    "APPROX_PERCENTILE_CONT_SKETCH" => Ok(AggregateFunction::ApproxPercentileContSketch),
+                    "APPROX_PERCENTILE_CONT_MERGE" => Ok(AggregateFunction::ApproxPercentileContMerge),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -817,6 +829,9 @@ impl serde::Serialize for AggregateUdfExprNode {
         if !self.order_by.is_empty() {
             len += 1;
         }
+        if self.fun_definition.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("datafusion.AggregateUDFExprNode", len)?;
         if !self.fun_name.is_empty() {
             struct_ser.serialize_field("funName", &self.fun_name)?;
@@ -830,6 +845,10 @@ impl serde::Serialize for AggregateUdfExprNode {
         if !self.order_by.is_empty() {
             struct_ser.serialize_field("orderBy", &self.order_by)?;
         }
+        if let Some(v) = self.fun_definition.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("funDefinition", pbjson::private::base64::encode(&v).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -846,6 +865,8 @@ impl<'de> serde::Deserialize<'de> for AggregateUdfExprNode {
             "filter",
             "order_by",
             "orderBy",
+            "fun_definition",
+            "funDefinition",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -854,6 +875,7 @@ impl<'de> serde::Deserialize<'de> for AggregateUdfExprNode {
             Args,
             Filter,
             OrderBy,
+            FunDefinition,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -879,6 +901,7 @@ impl<'de> serde::Deserialize<'de> for AggregateUdfExprNode {
                             "args" => Ok(GeneratedField::Args),
                             "filter" => Ok(GeneratedField::Filter),
                             "orderBy" | "order_by" => Ok(GeneratedField::OrderBy),
+                            "funDefinition" | "fun_definition" => Ok(GeneratedField::FunDefinition),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -902,6 +925,7 @@ impl<'de> serde::Deserialize<'de> for AggregateUdfExprNode {
                 let mut args__ = None;
                 let mut filter__ = None;
                 let mut order_by__ = None;
+                let mut fun_definition__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::FunName => {
@@ -928,6 +952,14 @@ impl<'de> serde::Deserialize<'de> for AggregateUdfExprNode {
                             }
                             order_by__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::FunDefinition => {
+                            if fun_definition__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("funDefinition"));
+                            }
+                            fun_definition__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::BytesDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
                     }
                 }
                 Ok(AggregateUdfExprNode {
@@ -935,6 +967,7 @@ impl<'de> serde::Deserialize<'de> for AggregateUdfExprNode {
                     args: args__.unwrap_or_default(),
                     filter: filter__,
                     order_by: order_by__.unwrap_or_default(),
+                    fun_definition: fun_definition__,
                 })
             }
         }
@@ -2593,6 +2626,7 @@ impl serde::Serialize for BuiltInWindowFunction {
             Self::FirstValue => "FIRST_VALUE",
             Self::LastValue => "LAST_VALUE",
             Self::NthValue => "NTH_VALUE",
+            Self::SessionId => "SESSION_ID",
         };
         serializer.serialize_str(variant)
     }
@@ -2615,6 +2649,7 @@ impl<'de> serde::Deserialize<'de> for BuiltInWindowFunction {
             "FIRST_VALUE",
             "LAST_VALUE",
             "NTH_VALUE",
+            "SESSION_ID",
         ];
 
         struct GeneratedVisitor;
@@ -2666,6 +2701,7 @@ impl<'de> serde::Deserialize<'de> for BuiltInWindowFunction {
                     "FIRST_VALUE" => Ok(BuiltInWindowFunction::FirstValue),
                     "LAST_VALUE" => Ok(BuiltInWindowFunction::LastValue),
                     "NTH_VALUE" => Ok(BuiltInWindowFunction::NthValue),
+                    "SESSION_ID" => Ok(BuiltInWindowFunction::SessionId),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -5498,6 +5534,15 @@ impl serde::Serialize for CsvOptions {
         if !self.null_value.is_empty() {
             len += 1;
         }
+        if !self.double_quote.is_empty() {
+            len += 1;
+        }
+        if !self.quote_style.is_empty() {
+            len += 1;
+        }
+        if self.newlines_in_values {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("datafusion.CsvOptions", len)?;
         if self.has_header {
             struct_ser.serialize_field("hasHeader", &self.has_header)?;
@@ -5541,6 +5586,16 @@ impl serde::Serialize for CsvOptions {
         if !self.null_value.is_empty() {
             struct_ser.serialize_field("nullValue", &self.null_value)?;
         }
+        if !self.double_quote.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("doubleQuote", pbjson::private::base64::encode(&self.double_quote).as_str())?;
+        }
+        if !self.quote_style.is_empty() {
+            struct_ser.serialize_field("quoteStyle", &self.quote_style)?;
+        }
+        if self.newlines_in_values {
+            struct_ser.serialize_field("newlinesInValues", &self.newlines_in_values)?;
+        }
         struct_ser.end()
     }
 }
@@ -5571,6 +5626,12 @@ impl<'de> serde::Deserialize<'de> for CsvOptions {
             "timeFormat",
             "null_value",
             "nullValue",
+            "double_quote",
+            "doubleQuote",
+            "quote_style",
+            "quoteStyle",
+            "newlines_in_values",
+            "newlinesInValues",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -5587,6 +5648,9 @@ impl<'de> serde::Deserialize<'de> for CsvOptions {
             TimestampTzFormat,
             TimeFormat,
             NullValue,
+            DoubleQuote,
+            QuoteStyle,
+            NewlinesInValues,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -5620,6 +5684,9 @@ impl<'de> serde::Deserialize<'de> for CsvOptions {
                             "timestampTzFormat" | "timestamp_tz_format" => Ok(GeneratedField::TimestampTzFormat),
                             "timeFormat" | "time_format" => Ok(GeneratedField::TimeFormat),
                             "nullValue" | "null_value" => Ok(GeneratedField::NullValue),
+                            "doubleQuote" | "double_quote" => Ok(GeneratedField::DoubleQuote),
+                            "quoteStyle" | "quote_style" => Ok(GeneratedField::QuoteStyle),
+                            "newlinesInValues" | "newlines_in_values" => Ok(GeneratedField::NewlinesInValues),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -5651,6 +5718,9 @@ impl<'de> serde::Deserialize<'de> for CsvOptions {
                 let mut timestamp_tz_format__ = None;
                 let mut time_format__ = None;
                 let mut null_value__ = None;
+                let mut double_quote__ = None;
+                let mut quote_style__ = None;
+                let mut newlines_in_values__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::HasHeader => {
@@ -5733,6 +5803,26 @@ impl<'de> serde::Deserialize<'de> for CsvOptions {
                             }
                             null_value__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::DoubleQuote => {
+                            if double_quote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("doubleQuote"));
+                            }
+                            double_quote__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::QuoteStyle => {
+                            if quote_style__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("quoteStyle"));
+                            }
+                            quote_style__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NewlinesInValues => {
+                            if newlines_in_values__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newlinesInValues"));
+                            }
+                            newlines_in_values__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(CsvOptions {
@@ -5748,6 +5838,9 @@ impl<'de> serde::Deserialize<'de> for CsvOptions {
                     timestamp_tz_format: timestamp_tz_format__.unwrap_or_default(),
                     time_format: time_format__.unwrap_or_default(),
                     null_value: null_value__.unwrap_or_default(),
+                    double_quote: double_quote__.unwrap_or_default(),
+                    quote_style: quote_style__.unwrap_or_default(),
+                    newlines_in_values: newlines_in_values__.unwrap_or_default(),
                 })
             }
         }
@@ -5777,6 +5870,12 @@ impl serde::Serialize for CsvScanExecNode {
         if self.optional_escape.is_some() {
             len += 1;
         }
+        if !self.null_value.is_empty() {
+            len += 1;
+        }
+        if self.newlines_in_values {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("datafusion.CsvScanExecNode", len)?;
         if let Some(v) = self.base_conf.as_ref() {
             struct_ser.serialize_field("baseConf", v)?;
@@ -5797,6 +5896,12 @@ impl serde::Serialize for CsvScanExecNode {
                 }
             }
         }
+        if !self.null_value.is_empty() {
+            struct_ser.serialize_field("nullValue", &self.null_value)?;
+        }
+        if self.newlines_in_values {
+            struct_ser.serialize_field("newlinesInValues", &self.newlines_in_values)?;
+        }
         struct_ser.end()
     }
 }
@@ -5814,6 +5919,10 @@ impl<'de> serde::Deserialize<'de> for CsvScanExecNode {
             "delimiter",
             "quote",
             "escape",
+            "null_value",
+            "nullValue",
+            "newlines_in_values",
+            "newlinesInValues",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -5823,6 +5932,8 @@ impl<'de> serde::Deserialize<'de> for CsvScanExecNode {
             Delimiter,
             Quote,
             Escape,
+            NullValue,
+            NewlinesInValues,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -5849,6 +5960,8 @@ impl<'de> serde::Deserialize<'de> for CsvScanExecNode {
                             "delimiter" => Ok(GeneratedField::Delimiter),
                             "quote" => Ok(GeneratedField::Quote),
                             "escape" => Ok(GeneratedField::Escape),
+                            "nullValue" | "null_value" => Ok(GeneratedField::NullValue),
+                            "newlinesInValues" | "newlines_in_values" => Ok(GeneratedField::NewlinesInValues),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -5873,6 +5986,8 @@ impl<'de> serde::Deserialize<'de> for CsvScanExecNode {
                 let mut delimiter__ = None;
                 let mut quote__ = None;
                 let mut optional_escape__ = None;
+                let mut null_value__ = None;
+                let mut newlines_in_values__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::BaseConf => {
@@ -5905,6 +6020,18 @@ impl<'de> serde::Deserialize<'de> for CsvScanExecNode {
                             }
                             optional_escape__ = map_.next_value::<::std::option::Option<_>>()?.map(csv_scan_exec_node::OptionalEscape::Escape);
                         }
+                        GeneratedField::NullValue => {
+                            if null_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullValue"));
+                            }
+                            null_value__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NewlinesInValues => {
+                            if newlines_in_values__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newlinesInValues"));
+                            }
+                            newlines_in_values__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(CsvScanExecNode {
@@ -5913,6 +6040,8 @@ impl<'de> serde::Deserialize<'de> for CsvScanExecNode {
                     delimiter: delimiter__.unwrap_or_default(),
                     quote: quote__.unwrap_or_default(),
                     optional_escape: optional_escape__,
+                    null_value: null_value__.unwrap_or_default(),
+                    newlines_in_values: newlines_in_values__.unwrap_or_default(),
                 })
             }
         }
@@ -6204,6 +6333,18 @@ impl serde::Serialize for CsvWriterOptions {
         if !self.null_value.is_empty() {
             len += 1;
         }
+        if !self.quote.is_empty() {
+            len += 1;
+        }
+        if !self.escape.is_empty() {
+            len += 1;
+        }
+        if self.double_quote {
+            len += 1;
+        }
+        if !self.quote_style.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("datafusion.CsvWriterOptions", len)?;
         if self.compression != 0 {
             let v = CompressionTypeVariant::try_from(self.compression)
@@ -6231,6 +6372,18 @@ impl serde::Serialize for CsvWriterOptions {
         if !self.null_value.is_empty() {
             struct_ser.serialize_field("nullValue", &self.null_value)?;
         }
+        if !self.quote.is_empty() {
+            struct_ser.serialize_field("quote", &self.quote)?;
+        }
+        if !self.escape.is_empty() {
+            struct_ser.serialize_field("escape", &self.escape)?;
+        }
+        if self.double_quote {
+            struct_ser.serialize_field("doubleQuote", &self.double_quote)?;
+        }
+        if !self.quote_style.is_empty() {
+            struct_ser.serialize_field("quoteStyle", &self.quote_style)?;
+        }
         struct_ser.end()
     }
 }
@@ -6255,6 +6408,12 @@ impl<'de> serde::Deserialize<'de> for CsvWriterOptions {
             "timeFormat",
             "null_value",
             "nullValue",
+            "quote",
+            "escape",
+            "double_quote",
+            "doubleQuote",
+            "quote_style",
+            "quoteStyle",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -6267,6 +6426,10 @@ impl<'de> serde::Deserialize<'de> for CsvWriterOptions {
             TimestampFormat,
             TimeFormat,
             NullValue,
+            Quote,
+            Escape,
+            DoubleQuote,
+            QuoteStyle,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -6296,6 +6459,10 @@ impl<'de> serde::Deserialize<'de> for CsvWriterOptions {
                             "timestampFormat" | "timestamp_format" => Ok(GeneratedField::TimestampFormat),
                             "timeFormat" | "time_format" => Ok(GeneratedField::TimeFormat),
                             "nullValue" | "null_value" => Ok(GeneratedField::NullValue),
+                            "quote" => Ok(GeneratedField::Quote),
+                            "escape" => Ok(GeneratedField::Escape),
+                            "doubleQuote" | "double_quote" => Ok(GeneratedField::DoubleQuote),
+                            "quoteStyle" | "quote_style" => Ok(GeneratedField::QuoteStyle),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -6323,6 +6490,10 @@ impl<'de> serde::Deserialize<'de> for CsvWriterOptions {
                 let mut timestamp_format__ = None;
                 let mut time_format__ = None;
                 let mut null_value__ = None;
+                let mut quote__ = None;
+                let mut escape__ = None;
+                let mut double_quote__ = None;
+                let mut quote_style__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Compression => {
@@ -6373,6 +6544,30 @@ impl<'de> serde::Deserialize<'de> for CsvWriterOptions {
                             }
                             null_value__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Quote => {
+                            if quote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("quote"));
+                            }
+                            quote__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Escape => {
+                            if escape__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("escape"));
+                            }
+                            escape__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DoubleQuote => {
+                            if double_quote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("doubleQuote"));
+                            }
+                            double_quote__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::QuoteStyle => {
+                            if quote_style__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("quoteStyle"));
+                            }
+                            quote_style__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(CsvWriterOptions {
@@ -6384,6 +6579,10 @@ impl<'de> serde::Deserialize<'de> for CsvWriterOptions {
                     timestamp_format: timestamp_format__.unwrap_or_default(),
                     time_format: time_format__.unwrap_or_default(),
                     null_value: null_value__.unwrap_or_default(),
+                    quote: quote__.unwrap_or_default(),
+                    escape: escape__.unwrap_or_default(),
+                    double_quote: double_quote__.unwrap_or_default(),
+                    quote_style: quote_style__.unwrap_or_default(),
                 })
             }
         }
@@ -8178,6 +8377,9 @@ impl serde::Serialize for ExplainNode {
         if self.verbose {
             len += 1;
         }
+        if self.estimate {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("datafusion.ExplainNode", len)?;
         if let Some(v) = self.input.as_ref() {
             struct_ser.serialize_field("input", v)?;
@@ -8185,6 +8387,9 @@ impl serde::Serialize for ExplainNode {
         if self.verbose {
             struct_ser.serialize_field("verbose", &self.verbose)?;
         }
+        if self.estimate {
+            struct_ser.serialize_field("estimate", &self.estimate)?;
+        }
         struct_ser.end()
     }
 }
@@ -8197,12 +8402,14 @@ impl<'de> serde::Deserialize<'de> for ExplainNode {
         const FIELDS: &[&str] = &[
             "input",
             "verbose",
+            "estimate",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Input,
             Verbose,
+            Estimate,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -8226,6 +8433,7 @@ impl<'de> serde::Deserialize<'de> for ExplainNode {
                         match value {
                             "input" => Ok(GeneratedField::Input),
                             "verbose" => Ok(GeneratedField::Verbose),
+                            "estimate" => Ok(GeneratedField::Estimate),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -8247,6 +8455,7 @@ impl<'de> serde::Deserialize<'de> for ExplainNode {
             {
                 let mut input__ = None;
                 let mut verbose__ = None;
+                let mut estimate__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Input => {
@@ -8261,11 +8470,18 @@ impl<'de> serde::Deserialize<'de> for ExplainNode {
                             }
                             verbose__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Estimate => {
+                            if estimate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("estimate"));
+                            }
+                            estimate__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(ExplainNode {
                     input: input__,
                     verbose: verbose__.unwrap_or_default(),
+                    estimate: estimate__.unwrap_or_default(),
                 })
             }
         }
@@ -9929,6 +10145,12 @@ impl serde::Serialize for HashJoinExecNode {
         if !self.projection.is_empty() {
             len += 1;
         }
+        if self.strategy_hint != 0 {
+            len += 1;
+        }
+        if self.null_aware {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("datafusion.HashJoinExecNode", len)?;
         if let Some(v) = self.left.as_ref() {
             struct_ser.serialize_field("left", v)?;
@@ -9958,6 +10180,14 @@ impl serde::Serialize for HashJoinExecNode {
         if !self.projection.is_empty() {
             struct_ser.serialize_field("projection", &self.projection)?;
         }
+        if self.strategy_hint != 0 {
+            let v = JoinStrategyHint::try_from(self.strategy_hint)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.strategy_hint)))?;
+            struct_ser.serialize_field("strategyHint", &v)?;
+        }
+        if self.null_aware {
+            struct_ser.serialize_field("nullAware", &self.null_aware)?;
+        }
         struct_ser.end()
     }
 }
@@ -9979,6 +10209,10 @@ impl<'de> serde::Deserialize<'de> for HashJoinExecNode {
             "nullEqualsNull",
             "filter",
             "projection",
+            "strategy_hint",
+            "strategyHint",
+            "null_aware",
+            "nullAware",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -9991,6 +10225,8 @@ impl<'de> serde::Deserialize<'de> for HashJoinExecNode {
             NullEqualsNull,
             Filter,
             Projection,
+            StrategyHint,
+            NullAware,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -10020,6 +10256,8 @@ impl<'de> serde::Deserialize<'de> for HashJoinExecNode {
                             "nullEqualsNull" | "null_equals_null" => Ok(GeneratedField::NullEqualsNull),
                             "filter" => Ok(GeneratedField::Filter),
                             "projection" => Ok(GeneratedField::Projection),
+                            "strategyHint" | "strategy_hint" => Ok(GeneratedField::StrategyHint),
+                            "nullAware" | "null_aware" => Ok(GeneratedField::NullAware),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -10047,6 +10285,8 @@ impl<'de> serde::Deserialize<'de> for HashJoinExecNode {
                 let mut null_equals_null__ = None;
                 let mut filter__ = None;
                 let mut projection__ = None;
+                let mut strategy_hint__ = None;
+                let mut null_aware__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Left => {
@@ -10100,6 +10340,18 @@ impl<'de> serde::Deserialize<'de> for HashJoinExecNode {
                                     .into_iter().map(|x| x.0).collect())
                             ;
                         }
+                        GeneratedField::StrategyHint => {
+                            if strategy_hint__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("strategyHint"));
+                            }
+                            strategy_hint__ = Some(map_.next_value::<JoinStrategyHint>()? as i32);
+                        }
+                        GeneratedField::NullAware => {
+                            if null_aware__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullAware"));
+                            }
+                            null_aware__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(HashJoinExecNode {
@@ -10111,6 +10363,8 @@ impl<'de> serde::Deserialize<'de> for HashJoinExecNode {
                     null_equals_null: null_equals_null__.unwrap_or_default(),
                     filter: filter__,
                     projection: projection__.unwrap_or_default(),
+                    strategy_hint: strategy_hint__.unwrap_or_default(),
+                    null_aware: null_aware__.unwrap_or_default(),
                 })
             }
         }
@@ -11752,6 +12006,12 @@ impl serde::Serialize for JoinNode {
         if self.filter.is_some() {
             len += 1;
         }
+        if self.strategy_hint != 0 {
+            len += 1;
+        }
+        if self.null_aware {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("datafusion.JoinNode", len)?;
         if let Some(v) = self.left.as_ref() {
             struct_ser.serialize_field("left", v)?;
@@ -11781,6 +12041,14 @@ impl serde::Serialize for JoinNode {
         if let Some(v) = self.filter.as_ref() {
             struct_ser.serialize_field("filter", v)?;
         }
+        if self.strategy_hint != 0 {
+            let v = JoinStrategyHint::try_from(self.strategy_hint)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.strategy_hint)))?;
+            struct_ser.serialize_field("strategyHint", &v)?;
+        }
+        if self.null_aware {
+            struct_ser.serialize_field("nullAware", &self.null_aware)?;
+        }
         struct_ser.end()
     }
 }
@@ -11804,6 +12072,10 @@ impl<'de> serde::Deserialize<'de> for JoinNode {
             "null_equals_null",
             "nullEqualsNull",
             "filter",
+            "strategy_hint",
+            "strategyHint",
+            "null_aware",
+            "nullAware",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -11816,6 +12088,8 @@ impl<'de> serde::Deserialize<'de> for JoinNode {
             RightJoinKey,
             NullEqualsNull,
             Filter,
+            StrategyHint,
+            NullAware,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -11845,6 +12119,8 @@ impl<'de> serde::Deserialize<'de> for JoinNode {
                             "rightJoinKey" | "right_join_key" => Ok(GeneratedField::RightJoinKey),
                             "nullEqualsNull" | "null_equals_null" => Ok(GeneratedField::NullEqualsNull),
                             "filter" => Ok(GeneratedField::Filter),
+                            "strategyHint" | "strategy_hint" => Ok(GeneratedField::StrategyHint),
+                            "nullAware" | "null_aware" => Ok(GeneratedField::NullAware),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -11872,6 +12148,8 @@ impl<'de> serde::Deserialize<'de> for JoinNode {
                 let mut right_join_key__ = None;
                 let mut null_equals_null__ = None;
                 let mut filter__ = None;
+                let mut strategy_hint__ = None;
+                let mut null_aware__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Left => {
@@ -11922,6 +12200,18 @@ impl<'de> serde::Deserialize<'de> for JoinNode {
                             }
                             filter__ = map_.next_value()?;
                         }
+                        GeneratedField::StrategyHint => {
+                            if strategy_hint__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("strategyHint"));
+                            }
+                            strategy_hint__ = Some(map_.next_value::<JoinStrategyHint>()? as i32);
+                        }
+                        GeneratedField::NullAware => {
+                            if null_aware__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullAware"));
+                            }
+                            null_aware__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(JoinNode {
@@ -11933,6 +12223,8 @@ impl<'de> serde::Deserialize<'de> for JoinNode {
                     right_join_key: right_join_key__.unwrap_or_default(),
                     null_equals_null: null_equals_null__.unwrap_or_default(),
                     filter: filter__,
+                    strategy_hint: strategy_hint__.unwrap_or_default(),
+                    null_aware: null_aware__.unwrap_or_default(),
                 })
             }
         }
@@ -12118,6 +12410,86 @@ impl<'de> serde::Deserialize<'de> for JoinSide {
         deserializer.deserialize_any(GeneratedVisitor)
     }
 }
+impl serde::Serialize for JoinStrategyHint {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::NoHint => "NO_HINT",
+            Self::BroadcastLeft => "BROADCAST_LEFT",
+            Self::BroadcastRight => "BROADCAST_RIGHT",
+            Self::ShuffleHash => "SHUFFLE_HASH",
+            Self::SortMerge => "SORT_MERGE",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for JoinStrategyHint {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "NO_HINT",
+            "BROADCAST_LEFT",
+            "BROADCAST_RIGHT",
+            "SHUFFLE_HASH",
+            "SORT_MERGE",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = JoinStrategyHint;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "NO_HINT" => Ok(JoinStrategyHint::NoHint),
+                    "BROADCAST_LEFT" => Ok(JoinStrategyHint::BroadcastLeft),
+                    "BROADCAST_RIGHT" => Ok(JoinStrategyHint::BroadcastRight),
+                    "SHUFFLE_HASH" => Ok(JoinStrategyHint::ShuffleHash),
+                    "SORT_MERGE" => Ok(JoinStrategyHint::SortMerge),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
 impl serde::Serialize for JoinType {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -12221,6 +12593,18 @@ impl serde::Serialize for JsonOptions {
         if self.schema_infer_max_rec != 0 {
             len += 1;
         }
+        if self.explicit_nulls {
+            len += 1;
+        }
+        if self.max_file_size_bytes != 0 {
+            len += 1;
+        }
+        if self.rotation_interval_ms != 0 {
+            len += 1;
+        }
+        if self.flush_interval_ms != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("datafusion.JsonOptions", len)?;
         if self.compression != 0 {
             let v = CompressionTypeVariant::try_from(self.compression)
@@ -12231,6 +12615,21 @@ impl serde::Serialize for JsonOptions {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("schemaInferMaxRec", ToString::to_string(&self.schema_infer_max_rec).as_str())?;
         }
+        if self.explicit_nulls {
+            struct_ser.serialize_field("explicitNulls", &self.explicit_nulls)?;
+        }
+        if self.max_file_size_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("maxFileSizeBytes", ToString::to_string(&self.max_file_size_bytes).as_str())?;
+        }
+        if self.rotation_interval_ms != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("rotationIntervalMs", ToString::to_string(&self.rotation_interval_ms).as_str())?;
+        }
+        if self.flush_interval_ms != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("flushIntervalMs", ToString::to_string(&self.flush_interval_ms).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -12244,12 +12643,24 @@ impl<'de> serde::Deserialize<'de> for JsonOptions {
             "compression",
             "schema_infer_max_rec",
             "schemaInferMaxRec",
+            "explicit_nulls",
+            "explicitNulls",
+            "max_file_size_bytes",
+            "maxFileSizeBytes",
+            "rotation_interval_ms",
+            "rotationIntervalMs",
+            "flush_interval_ms",
+            "flushIntervalMs",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Compression,
             SchemaInferMaxRec,
+            ExplicitNulls,
+            MaxFileSizeBytes,
+            RotationIntervalMs,
+            FlushIntervalMs,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -12273,6 +12684,10 @@ impl<'de> serde::Deserialize<'de> for JsonOptions {
                         match value {
                             "compression" => Ok(GeneratedField::Compression),
                             "schemaInferMaxRec" | "schema_infer_max_rec" => Ok(GeneratedField::SchemaInferMaxRec),
+                            "explicitNulls" | "explicit_nulls" => Ok(GeneratedField::ExplicitNulls),
+                            "maxFileSizeBytes" | "max_file_size_bytes" => Ok(GeneratedField::MaxFileSizeBytes),
+                            "rotationIntervalMs" | "rotation_interval_ms" => Ok(GeneratedField::RotationIntervalMs),
+                            "flushIntervalMs" | "flush_interval_ms" => Ok(GeneratedField::FlushIntervalMs),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -12294,6 +12709,10 @@ impl<'de> serde::Deserialize<'de> for JsonOptions {
             {
                 let mut compression__ = None;
                 let mut schema_infer_max_rec__ = None;
+                let mut explicit_nulls__ = None;
+                let mut max_file_size_bytes__ = None;
+                let mut rotation_interval_ms__ = None;
+                let mut flush_interval_ms__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Compression => {
@@ -12310,11 +12729,45 @@ impl<'de> serde::Deserialize<'de> for JsonOptions {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::ExplicitNulls => {
+                            if explicit_nulls__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("explicitNulls"));
+                            }
+                            explicit_nulls__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MaxFileSizeBytes => {
+                            if max_file_size_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxFileSizeBytes"));
+                            }
+                            max_file_size_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RotationIntervalMs => {
+                            if rotation_interval_ms__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rotationIntervalMs"));
+                            }
+                            rotation_interval_ms__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FlushIntervalMs => {
+                            if flush_interval_ms__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("flushIntervalMs"));
+                            }
+                            flush_interval_ms__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(JsonOptions {
                     compression: compression__.unwrap_or_default(),
                     schema_infer_max_rec: schema_infer_max_rec__.unwrap_or_default(),
+                    explicit_nulls: explicit_nulls__.unwrap_or_default(),
+                    max_file_size_bytes: max_file_size_bytes__.unwrap_or_default(),
+                    rotation_interval_ms: rotation_interval_ms__.unwrap_or_default(),
+                    flush_interval_ms: flush_interval_ms__.unwrap_or_default(),
                 })
             }
         }
@@ -12585,12 +13038,39 @@ impl serde::Serialize for JsonWriterOptions {
         if self.compression != 0 {
             len += 1;
         }
+        if self.explicit_nulls {
+            len += 1;
+        }
+        if self.max_file_size_bytes != 0 {
+            len += 1;
+        }
+        if self.rotation_interval_ms != 0 {
+            len += 1;
+        }
+        if self.flush_interval_ms != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("datafusion.JsonWriterOptions", len)?;
         if self.compression != 0 {
             let v = CompressionTypeVariant::try_from(self.compression)
                 .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.compression)))?;
             struct_ser.serialize_field("compression", &v)?;
         }
+        if self.explicit_nulls {
+            struct_ser.serialize_field("explicitNulls", &self.explicit_nulls)?;
+        }
+        if self.max_file_size_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("maxFileSizeBytes", ToString::to_string(&self.max_file_size_bytes).as_str())?;
+        }
+        if self.rotation_interval_ms != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("rotationIntervalMs", ToString::to_string(&self.rotation_interval_ms).as_str())?;
+        }
+        if self.flush_interval_ms != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("flushIntervalMs", ToString::to_string(&self.flush_interval_ms).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -12602,11 +13082,23 @@ impl<'de> serde::Deserialize<'de> for JsonWriterOptions {
     {
         const FIELDS: &[&str] = &[
             "compression",
+            "explicit_nulls",
+            "explicitNulls",
+            "max_file_size_bytes",
+            "maxFileSizeBytes",
+            "rotation_interval_ms",
+            "rotationIntervalMs",
+            "flush_interval_ms",
+            "flushIntervalMs",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Compression,
+            ExplicitNulls,
+            MaxFileSizeBytes,
+            RotationIntervalMs,
+            FlushIntervalMs,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -12629,6 +13121,10 @@ impl<'de> serde::Deserialize<'de> for JsonWriterOptions {
                     {
                         match value {
                             "compression" => Ok(GeneratedField::Compression),
+                            "explicitNulls" | "explicit_nulls" => Ok(GeneratedField::ExplicitNulls),
+                            "maxFileSizeBytes" | "max_file_size_bytes" => Ok(GeneratedField::MaxFileSizeBytes),
+                            "rotationIntervalMs" | "rotation_interval_ms" => Ok(GeneratedField::RotationIntervalMs),
+                            "flushIntervalMs" | "flush_interval_ms" => Ok(GeneratedField::FlushIntervalMs),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -12649,6 +13145,10 @@ impl<'de> serde::Deserialize<'de> for JsonWriterOptions {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut compression__ = None;
+                let mut explicit_nulls__ = None;
+                let mut max_file_size_bytes__ = None;
+                let mut rotation_interval_ms__ = None;
+                let mut flush_interval_ms__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Compression => {
@@ -12657,10 +13157,44 @@ impl<'de> serde::Deserialize<'de> for JsonWriterOptions {
                             }
                             compression__ = Some(map_.next_value::<CompressionTypeVariant>()? as i32);
                         }
+                        GeneratedField::ExplicitNulls => {
+                            if explicit_nulls__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("explicitNulls"));
+                            }
+                            explicit_nulls__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MaxFileSizeBytes => {
+                            if max_file_size_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxFileSizeBytes"));
+                            }
+                            max_file_size_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RotationIntervalMs => {
+                            if rotation_interval_ms__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rotationIntervalMs"));
+                            }
+                            rotation_interval_ms__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FlushIntervalMs => {
+                            if flush_interval_ms__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("flushIntervalMs"));
+                            }
+                            flush_interval_ms__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(JsonWriterOptions {
                     compression: compression__.unwrap_or_default(),
+                    explicit_nulls: explicit_nulls__.unwrap_or_default(),
+                    max_file_size_bytes: max_file_size_bytes__.unwrap_or_default(),
+                    rotation_interval_ms: rotation_interval_ms__.unwrap_or_default(),
+                    flush_interval_ms: flush_interval_ms__.unwrap_or_default(),
                 })
             }
         }
@@ -15265,6 +15799,222 @@ impl<'de> serde::Deserialize<'de> for MaybePhysicalSortExprs {
         deserializer.deserialize_struct("datafusion.MaybePhysicalSortExprs", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for MultiHashJoinExecNode {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.builds.is_empty() {
+            len += 1;
+        }
+        if self.probe.is_some() {
+            len += 1;
+        }
+        if !self.on.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("datafusion.MultiHashJoinExecNode", len)?;
+        if !self.builds.is_empty() {
+            struct_ser.serialize_field("builds", &self.builds)?;
+        }
+        if let Some(v) = self.probe.as_ref() {
+            struct_ser.serialize_field("probe", v)?;
+        }
+        if !self.on.is_empty() {
+            struct_ser.serialize_field("on", &self.on)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MultiHashJoinExecNode {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "builds",
+            "probe",
+            "on",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Builds,
+            Probe,
+            On,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "builds" => Ok(GeneratedField::Builds),
+                            "probe" => Ok(GeneratedField::Probe),
+                            "on" => Ok(GeneratedField::On),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MultiHashJoinExecNode;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct datafusion.MultiHashJoinExecNode")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MultiHashJoinExecNode, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut builds__ = None;
+                let mut probe__ = None;
+                let mut on__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Builds => {
+                            if builds__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("builds"));
+                            }
+                            builds__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Probe => {
+                            if probe__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("probe"));
+                            }
+                            probe__ = map_.next_value()?;
+                        }
+                        GeneratedField::On => {
+                            if on__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("on"));
+                            }
+                            on__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(MultiHashJoinExecNode {
+                    builds: builds__.unwrap_or_default(),
+                    probe: probe__,
+                    on: on__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("datafusion.MultiHashJoinExecNode", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MultiHashJoinOn {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.on.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("datafusion.MultiHashJoinOn", len)?;
+        if !self.on.is_empty() {
+            struct_ser.serialize_field("on", &self.on)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MultiHashJoinOn {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "on",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            On,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "on" => Ok(GeneratedField::On),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MultiHashJoinOn;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct datafusion.MultiHashJoinOn")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MultiHashJoinOn, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut on__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::On => {
+                            if on__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("on"));
+                            }
+                            on__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(MultiHashJoinOn {
+                    on: on__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("datafusion.MultiHashJoinOn", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for NamedStructField {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -19746,6 +20496,9 @@ impl serde::Serialize for PhysicalPlanNode {
                 physical_plan_node::PhysicalPlanType::ParquetSink(v) => {
                     struct_ser.serialize_field("parquetSink", v)?;
                 }
+                physical_plan_node::PhysicalPlanType::MultiHashJoin(v) => {
+                    struct_ser.serialize_field("multiHashJoin", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -19801,6 +20554,8 @@ impl<'de> serde::Deserialize<'de> for PhysicalPlanNode {
             "csvSink",
             "parquet_sink",
             "parquetSink",
+            "multi_hash_join",
+            "multiHashJoin",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -19833,6 +20588,7 @@ impl<'de> serde::Deserialize<'de> for PhysicalPlanNode {
             PlaceholderRow,
             CsvSink,
             ParquetSink,
+            MultiHashJoin,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -19882,6 +20638,7 @@ impl<'de> serde::Deserialize<'de> for PhysicalPlanNode {
                             "placeholderRow" | "placeholder_row" => Ok(GeneratedField::PlaceholderRow),
                             "csvSink" | "csv_sink" => Ok(GeneratedField::CsvSink),
                             "parquetSink" | "parquet_sink" => Ok(GeneratedField::ParquetSink),
+                            "multiHashJoin" | "multi_hash_join" => Ok(GeneratedField::MultiHashJoin),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -20098,6 +20855,13 @@ impl<'de> serde::Deserialize<'de> for PhysicalPlanNode {
                                 return Err(serde::de::Error::duplicate_field("parquetSink"));
                             }
                             physical_plan_type__ = map_.next_value::<::std::option::Option<_>>()?.map(physical_plan_node::PhysicalPlanType::ParquetSink)
+;
+                        }
+                        GeneratedField::MultiHashJoin => {
+                            if physical_plan_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiHashJoin"));
+                            }
+                            physical_plan_type__ = map_.next_value::<::std::option::Option<_>>()?.map(physical_plan_node::PhysicalPlanType::MultiHashJoin)
 ;
                         }
                     }
@@ -22218,6 +22982,9 @@ impl serde::Serialize for RepartitionExecNode {
         if self.partition_method.is_some() {
             len += 1;
         }
+        if self.preserve_order {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("datafusion.RepartitionExecNode", len)?;
         if let Some(v) = self.input.as_ref() {
             struct_ser.serialize_field("input", v)?;
@@ -22237,6 +23004,9 @@ impl serde::Serialize for RepartitionExecNode {
                 }
             }
         }
+        if self.preserve_order {
+            struct_ser.serialize_field("preserveOrder", &self.preserve_order)?;
+        }
         struct_ser.end()
     }
 }
@@ -22252,6 +23022,8 @@ impl<'de> serde::Deserialize<'de> for RepartitionExecNode {
             "roundRobin",
             "hash",
             "unknown",
+            "preserve_order",
+            "preserveOrder",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -22260,6 +23032,7 @@ impl<'de> serde::Deserialize<'de> for RepartitionExecNode {
             RoundRobin,
             Hash,
             Unknown,
+            PreserveOrder,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -22285,6 +23058,7 @@ impl<'de> serde::Deserialize<'de> for RepartitionExecNode {
                             "roundRobin" | "round_robin" => Ok(GeneratedField::RoundRobin),
                             "hash" => Ok(GeneratedField::Hash),
                             "unknown" => Ok(GeneratedField::Unknown),
+                            "preserveOrder" | "preserve_order" => Ok(GeneratedField::PreserveOrder),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -22306,6 +23080,7 @@ impl<'de> serde::Deserialize<'de> for RepartitionExecNode {
             {
                 let mut input__ = None;
                 let mut partition_method__ = None;
+                let mut preserve_order__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Input => {
@@ -22333,11 +23108,18 @@ impl<'de> serde::Deserialize<'de> for RepartitionExecNode {
                             }
                             partition_method__ = map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| repartition_exec_node::PartitionMethod::Unknown(x.0));
                         }
+                        GeneratedField::PreserveOrder => {
+                            if preserve_order__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("preserveOrder"));
+                            }
+                            preserve_order__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(RepartitionExecNode {
                     input: input__,
                     partition_method: partition_method__,
+                    preserve_order: preserve_order__.unwrap_or_default(),
                 })
             }
         }
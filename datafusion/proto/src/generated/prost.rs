@@ -433,6 +433,8 @@ pub struct ExplainNode {
     pub input: ::core::option::Option<::prost::alloc::boxed::Box<LogicalPlanNode>>,
     #[prost(bool, tag = "2")]
     pub verbose: bool,
+    #[prost(bool, tag = "3")]
+    pub estimate: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -471,6 +473,10 @@ pub struct JoinNode {
     pub null_equals_null: bool,
     #[prost(message, optional, tag = "8")]
     pub filter: ::core::option::Option<LogicalExprNode>,
+    #[prost(enumeration = "JoinStrategyHint", tag = "9")]
+    pub strategy_hint: i32,
+    #[prost(bool, tag = "10")]
+    pub null_aware: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -871,6 +877,8 @@ pub struct AggregateUdfExprNode {
     pub filter: ::core::option::Option<::prost::alloc::boxed::Box<LogicalExprNode>>,
     #[prost(message, repeated, tag = "4")]
     pub order_by: ::prost::alloc::vec::Vec<LogicalExprNode>,
+    #[prost(bytes = "vec", optional, tag = "5")]
+    pub fun_definition: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1576,7 +1584,7 @@ pub mod table_reference {
 pub struct PhysicalPlanNode {
     #[prost(
         oneof = "physical_plan_node::PhysicalPlanType",
-        tags = "1, 2, 3, 4, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29"
+        tags = "1, 2, 3, 4, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30"
     )]
     pub physical_plan_type: ::core::option::Option<physical_plan_node::PhysicalPlanType>,
 }
@@ -1643,6 +1651,8 @@ pub mod physical_plan_node {
         CsvSink(::prost::alloc::boxed::Box<super::CsvSinkExecNode>),
         #[prost(message, tag = "29")]
         ParquetSink(::prost::alloc::boxed::Box<super::ParquetSinkExecNode>),
+        #[prost(message, tag = "30")]
+        MultiHashJoin(::prost::alloc::boxed::Box<super::MultiHashJoinExecNode>),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1658,6 +1668,18 @@ pub struct PartitionColumn {
 pub struct JsonWriterOptions {
     #[prost(enumeration = "CompressionTypeVariant", tag = "1")]
     pub compression: i32,
+    /// Whether to write null values of struct fields and columns as `null`
+    #[prost(bool, tag = "2")]
+    pub explicit_nulls: bool,
+    /// Size in bytes at which a new output file is started, 0 if unlimited
+    #[prost(uint64, tag = "3")]
+    pub max_file_size_bytes: u64,
+    /// Age in milliseconds at which a new output file is started, 0 if unlimited
+    #[prost(uint64, tag = "4")]
+    pub rotation_interval_ms: u64,
+    /// Interval in milliseconds at which the output file is flushed, 0 if never
+    #[prost(uint64, tag = "5")]
+    pub flush_interval_ms: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1686,6 +1708,18 @@ pub struct CsvWriterOptions {
     /// Optional value to represent null
     #[prost(string, tag = "8")]
     pub null_value: ::prost::alloc::string::String,
+    /// Optional quote character. Defaults to `b'"'`
+    #[prost(string, tag = "9")]
+    pub quote: ::prost::alloc::string::String,
+    /// Optional escape character, instead of doubling quotes
+    #[prost(string, tag = "10")]
+    pub escape: ::prost::alloc::string::String,
+    /// Whether to double quotes in quoted fields. Defaults to `true`
+    #[prost(bool, tag = "11")]
+    pub double_quote: bool,
+    /// Which fields are quoted, such as `always`. Defaults to `minimal`
+    #[prost(string, tag = "12")]
+    pub quote_style: ::prost::alloc::string::String,
 }
 /// Options controlling CSV format
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1727,6 +1761,15 @@ pub struct CsvOptions {
     /// Optional representation of null value
     #[prost(string, tag = "12")]
     pub null_value: ::prost::alloc::string::String,
+    /// Optional flag to double quotes in quoted fields
+    #[prost(bytes = "vec", tag = "13")]
+    pub double_quote: ::prost::alloc::vec::Vec<u8>,
+    /// Which fields are quoted when writing
+    #[prost(string, tag = "14")]
+    pub quote_style: ::prost::alloc::string::String,
+    /// Whether quoted values may contain newlines
+    #[prost(bool, tag = "15")]
+    pub newlines_in_values: bool,
 }
 /// Options controlling CSV format
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1738,6 +1781,18 @@ pub struct JsonOptions {
     /// Max records for schema inference
     #[prost(uint64, tag = "2")]
     pub schema_infer_max_rec: u64,
+    /// Whether to write null values as `null` when writing
+    #[prost(bool, tag = "3")]
+    pub explicit_nulls: bool,
+    /// Size in bytes at which a new output file is started, 0 if unlimited
+    #[prost(uint64, tag = "4")]
+    pub max_file_size_bytes: u64,
+    /// Age in milliseconds at which a new output file is started, 0 if unlimited
+    #[prost(uint64, tag = "5")]
+    pub rotation_interval_ms: u64,
+    /// Interval in milliseconds at which the output file is flushed, 0 if never
+    #[prost(uint64, tag = "6")]
+    pub flush_interval_ms: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -2376,6 +2431,10 @@ pub struct CsvScanExecNode {
     pub quote: ::prost::alloc::string::String,
     #[prost(oneof = "csv_scan_exec_node::OptionalEscape", tags = "5")]
     pub optional_escape: ::core::option::Option<csv_scan_exec_node::OptionalEscape>,
+    #[prost(string, tag = "6")]
+    pub null_value: ::prost::alloc::string::String,
+    #[prost(bool, tag = "7")]
+    pub newlines_in_values: bool,
 }
 /// Nested message and enum types in `CsvScanExecNode`.
 pub mod csv_scan_exec_node {
@@ -2411,6 +2470,27 @@ pub struct HashJoinExecNode {
     pub filter: ::core::option::Option<JoinFilter>,
     #[prost(uint32, repeated, tag = "9")]
     pub projection: ::prost::alloc::vec::Vec<u32>,
+    #[prost(enumeration = "JoinStrategyHint", tag = "10")]
+    pub strategy_hint: i32,
+    #[prost(bool, tag = "11")]
+    pub null_aware: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MultiHashJoinExecNode {
+    #[prost(message, repeated, tag = "1")]
+    pub builds: ::prost::alloc::vec::Vec<PhysicalPlanNode>,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub probe: ::core::option::Option<::prost::alloc::boxed::Box<PhysicalPlanNode>>,
+    /// The join keys of each build input
+    #[prost(message, repeated, tag = "3")]
+    pub on: ::prost::alloc::vec::Vec<MultiHashJoinOn>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MultiHashJoinOn {
+    #[prost(message, repeated, tag = "1")]
+    pub on: ::prost::alloc::vec::Vec<JoinOn>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -2669,6 +2749,10 @@ pub struct RepartitionExecNode {
     pub input: ::core::option::Option<::prost::alloc::boxed::Box<PhysicalPlanNode>>,
     #[prost(oneof = "repartition_exec_node::PartitionMethod", tags = "2, 3, 4")]
     pub partition_method: ::core::option::Option<repartition_exec_node::PartitionMethod>,
+    /// Whether the order of the input partitions is preserved in each output
+    /// partition, by merging them
+    #[prost(bool, tag = "5")]
+    pub preserve_order: bool,
 }
 /// Nested message and enum types in `RepartitionExecNode`.
 pub mod repartition_exec_node {
@@ -2837,6 +2921,41 @@ impl JoinConstraint {
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum JoinStrategyHint {
+    NoHint = 0,
+    BroadcastLeft = 1,
+    BroadcastRight = 2,
+    ShuffleHash = 3,
+    SortMerge = 4,
+}
+impl JoinStrategyHint {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            JoinStrategyHint::NoHint => "NO_HINT",
+            JoinStrategyHint::BroadcastLeft => "BROADCAST_LEFT",
+            JoinStrategyHint::BroadcastRight => "BROADCAST_RIGHT",
+            JoinStrategyHint::ShuffleHash => "SHUFFLE_HASH",
+            JoinStrategyHint::SortMerge => "SORT_MERGE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NO_HINT" => Some(Self::NoHint),
+            "BROADCAST_LEFT" => Some(Self::BroadcastLeft),
+            "BROADCAST_RIGHT" => Some(Self::BroadcastRight),
+            "SHUFFLE_HASH" => Some(Self::ShuffleHash),
+            "SORT_MERGE" => Some(Self::SortMerge),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum ScalarFunction {
     ///   0 was Abs before
     ///   The first enum value must be zero for open enums
@@ -3055,6 +3174,10 @@ pub enum AggregateFunction {
     RegrSxy = 34,
     StringAgg = 35,
     NthValueAgg = 36,
+    ApproxDistinctSketch = 37,
+    ApproxDistinctMerge = 38,
+    ApproxPercentileContSketch = 39,
+    ApproxPercentileContMerge = 40,
 }
 impl AggregateFunction {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -3102,6 +3225,14 @@ impl AggregateFunction {
             AggregateFunction::RegrSxy => "REGR_SXY",
             AggregateFunction::StringAgg => "STRING_AGG",
             AggregateFunction::NthValueAgg => "NTH_VALUE_AGG",
+            AggregateFunction::ApproxDistinctSketch => "APPROX_DISTINCT_SKETCH",
+            AggregateFunction::ApproxDistinctMerge => "APPROX_DISTINCT_MERGE",
+            AggregateFunction::ApproxPercentileContSketch => {
+                "APPROX_PERCENTILE_CONT_SKETCH"
+            }
+            AggregateFunction::ApproxPercentileContMerge => {
+                "APPROX_PERCENTILE_CONT_MERGE"
+            }
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -3146,6 +3277,10 @@ impl AggregateFunction {
             "REGR_SXY" => Some(Self::RegrSxy),
             "STRING_AGG" => Some(Self::StringAgg),
             "NTH_VALUE_AGG" => Some(Self::NthValueAgg),
+            "APPROX_DISTINCT_SKETCH" => Some(Self::ApproxDistinctSketch),
+            "APPROX_DISTINCT_MERGE" => Some(Self::ApproxDistinctMerge),
+            "APPROX_PERCENTILE_CONT_SKETCH" => Some(Self::ApproxPercentileContSketch),
+            "APPROX_PERCENTILE_CONT_MERGE" => Some(Self::ApproxPercentileContMerge),
             _ => None,
         }
     }
@@ -3164,6 +3299,7 @@ pub enum BuiltInWindowFunction {
     FirstValue = 8,
     LastValue = 9,
     NthValue = 10,
+    SessionId = 11,
 }
 impl BuiltInWindowFunction {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -3183,6 +3319,7 @@ impl BuiltInWindowFunction {
             BuiltInWindowFunction::FirstValue => "FIRST_VALUE",
             BuiltInWindowFunction::LastValue => "LAST_VALUE",
             BuiltInWindowFunction::NthValue => "NTH_VALUE",
+            BuiltInWindowFunction::SessionId => "SESSION_ID",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -3199,6 +3336,7 @@ impl BuiltInWindowFunction {
             "FIRST_VALUE" => Some(Self::FirstValue),
             "LAST_VALUE" => Some(Self::LastValue),
             "NTH_VALUE" => Some(Self::NthValue),
+            "SESSION_ID" => Some(Self::SessionId),
             _ => None,
         }
     }
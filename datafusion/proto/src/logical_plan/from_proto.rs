@@ -30,8 +30,8 @@ use arrow::{
 use datafusion::execution::registry::FunctionRegistry;
 use datafusion_common::{
     arrow_datafusion_err, internal_err, plan_datafusion_err, Column, Constraint,
-    Constraints, DFSchema, DFSchemaRef, DataFusionError, Result, ScalarValue,
-    TableReference,
+    Constraints, DFSchema, DFSchemaRef, DataFusionError, JoinStrategyHint, Result,
+    ScalarValue, TableReference,
 };
 use datafusion_expr::expr::Unnest;
 use datafusion_expr::expr::{Alias, Placeholder};
@@ -474,6 +474,16 @@ impl From<protobuf::AggregateFunction> for AggregateFunction {
             protobuf::AggregateFunction::LastValueAgg => Self::LastValue,
             protobuf::AggregateFunction::NthValueAgg => Self::NthValue,
             protobuf::AggregateFunction::StringAgg => Self::StringAgg,
+            protobuf::AggregateFunction::ApproxDistinctSketch => {
+                Self::ApproxDistinctSketch
+            }
+            protobuf::AggregateFunction::ApproxDistinctMerge => Self::ApproxDistinctMerge,
+            protobuf::AggregateFunction::ApproxPercentileContSketch => {
+                Self::ApproxPercentileContSketch
+            }
+            protobuf::AggregateFunction::ApproxPercentileContMerge => {
+                Self::ApproxPercentileContMerge
+            }
         }
     }
 }
@@ -492,6 +502,7 @@ impl From<protobuf::BuiltInWindowFunction> for BuiltInWindowFunction {
             protobuf::BuiltInWindowFunction::Ntile => Self::Ntile,
             protobuf::BuiltInWindowFunction::NthValue => Self::NthValue,
             protobuf::BuiltInWindowFunction::LastValue => Self::LastValue,
+            protobuf::BuiltInWindowFunction::SessionId => Self::SessionId,
         }
     }
 }
@@ -821,6 +832,24 @@ impl From<protobuf::JoinConstraint> for JoinConstraint {
     }
 }
 
+impl From<protobuf::JoinStrategyHint> for Option<JoinStrategyHint> {
+    fn from(t: protobuf::JoinStrategyHint) -> Self {
+        match t {
+            protobuf::JoinStrategyHint::NoHint => None,
+            protobuf::JoinStrategyHint::BroadcastLeft => {
+                Some(JoinStrategyHint::BroadcastLeft)
+            }
+            protobuf::JoinStrategyHint::BroadcastRight => {
+                Some(JoinStrategyHint::BroadcastRight)
+            }
+            protobuf::JoinStrategyHint::ShuffleHash => {
+                Some(JoinStrategyHint::ShuffleHash)
+            }
+            protobuf::JoinStrategyHint::SortMerge => Some(JoinStrategyHint::SortMerge),
+        }
+    }
+}
+
 impl From<protobuf::Constraints> for Constraints {
     fn from(constraints: protobuf::Constraints) -> Self {
         Constraints::new_unverified(
@@ -1325,7 +1354,10 @@ pub fn parse_expr(
             )))
         }
         ExprType::AggregateUdfExpr(pb) => {
-            let agg_fn = registry.udaf(pb.fun_name.as_str())?;
+            let agg_fn = match &pb.fun_definition {
+                Some(buf) => codec.try_decode_udaf(&pb.fun_name, buf)?,
+                None => registry.udaf(pb.fun_name.as_str())?,
+            };
 
             Ok(Expr::AggregateFunction(expr::AggregateFunction::new_udf(
                 agg_fn,
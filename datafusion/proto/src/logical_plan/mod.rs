@@ -45,8 +45,9 @@ use datafusion::{
     prelude::SessionContext,
 };
 use datafusion_common::{
-    context, internal_err, not_impl_err, parsers::CompressionTypeVariant,
-    plan_datafusion_err, DataFusionError, Result, TableReference,
+    context, file_options::csv_writer::CsvQuoteStyle, internal_err, not_impl_err,
+    parsers::CompressionTypeVariant, plan_datafusion_err, DataFusionError, Result,
+    TableReference,
 };
 use datafusion_expr::{
     dml,
@@ -56,7 +57,7 @@ use datafusion_expr::{
         EmptyRelation, Extension, Join, JoinConstraint, Limit, Prepare, Projection,
         Repartition, Sort, SubqueryAlias, TableScan, Values, Window,
     },
-    DistinctOn, DropView, Expr, LogicalPlan, LogicalPlanBuilder, ScalarUDF,
+    AggregateUDF, DistinctOn, DropView, Expr, LogicalPlan, LogicalPlanBuilder, ScalarUDF,
 };
 
 use prost::bytes::BufMut;
@@ -133,6 +134,16 @@ pub trait LogicalExtensionCodec: Debug + Send + Sync {
     fn try_encode_udf(&self, _node: &ScalarUDF, _buf: &mut Vec<u8>) -> Result<()> {
         Ok(())
     }
+
+    fn try_decode_udaf(&self, name: &str, _buf: &[u8]) -> Result<Arc<AggregateUDF>> {
+        not_impl_err!(
+            "LogicalExtensionCodec is not provided for aggregate function {name}"
+        )
+    }
+
+    fn try_encode_udaf(&self, _node: &AggregateUDF, _buf: &mut Vec<u8>) -> Result<()> {
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -630,9 +641,12 @@ impl AsLogicalPlan for LogicalPlanNode {
             LogicalPlanType::Explain(explain) => {
                 let input: LogicalPlan =
                     into_logical_plan!(explain.input, ctx, extension_codec)?;
-                LogicalPlanBuilder::from(input)
-                    .explain(explain.verbose, false)?
-                    .build()
+                let builder = LogicalPlanBuilder::from(input);
+                if explain.estimate {
+                    builder.explain_estimate(explain.verbose)?.build()
+                } else {
+                    builder.explain(explain.verbose, false)?.build()
+                }
             }
             LogicalPlanType::SubqueryAlias(aliased_relation) => {
                 let input: LogicalPlan =
@@ -683,6 +697,15 @@ impl AsLogicalPlan for LogicalPlanNode {
                         join.join_constraint
                     ))
                 })?;
+                let strategy_hint = protobuf::JoinStrategyHint::try_from(
+                    join.strategy_hint,
+                )
+                .map_err(|_| {
+                    proto_error(format!(
+                        "Received a JoinNode message with unknown JoinStrategyHint {}",
+                        join.strategy_hint
+                    ))
+                })?;
                 let filter: Option<Expr> = join
                     .filter
                     .as_ref()
@@ -715,7 +738,14 @@ impl AsLogicalPlan for LogicalPlanNode {
                     }
                 };
 
-                builder.build()
+                match builder.build()? {
+                    LogicalPlan::Join(join_plan) => Ok(LogicalPlan::Join(Join {
+                        strategy_hint: strategy_hint.into(),
+                        null_aware: join.null_aware,
+                        ..join_plan
+                    })),
+                    plan => Ok(plan),
+                }
             }
             LogicalPlanType::Union(union) => {
                 let mut input_plans: Vec<LogicalPlan> = union
@@ -1172,6 +1202,8 @@ impl AsLogicalPlan for LogicalPlanNode {
                 join_type,
                 join_constraint,
                 null_equals_null,
+                strategy_hint,
+                null_aware,
                 ..
             }) => {
                 let left: protobuf::LogicalPlanNode =
@@ -1198,6 +1230,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                 let join_type: protobuf::JoinType = join_type.to_owned().into();
                 let join_constraint: protobuf::JoinConstraint =
                     join_constraint.to_owned().into();
+                let strategy_hint: protobuf::JoinStrategyHint = (*strategy_hint).into();
                 let filter = filter
                     .as_ref()
                     .map(|e| serialize_expr(e, extension_codec))
@@ -1213,6 +1246,8 @@ impl AsLogicalPlan for LogicalPlanNode {
                             right_join_key,
                             null_equals_null: *null_equals_null,
                             filter,
+                            strategy_hint: strategy_hint.into(),
+                            null_aware: *null_aware,
                         },
                     ))),
                 })
@@ -1454,6 +1489,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                         protobuf::ExplainNode {
                             input: Some(Box::new(input)),
                             verbose: a.verbose,
+                            estimate: a.estimate,
                         },
                     ))),
                 })
@@ -1610,6 +1646,7 @@ impl AsLogicalPlan for LogicalPlanNode {
 pub(crate) fn csv_writer_options_to_proto(
     csv_options: &WriterBuilder,
     compression: &CompressionTypeVariant,
+    quote_style: &CsvQuoteStyle,
 ) -> protobuf::CsvWriterOptions {
     let compression: protobuf::CompressionTypeVariant = compression.into();
     protobuf::CsvWriterOptions {
@@ -1621,6 +1658,10 @@ pub(crate) fn csv_writer_options_to_proto(
         timestamp_format: csv_options.timestamp_format().unwrap_or("").to_owned(),
         time_format: csv_options.time_format().unwrap_or("").to_owned(),
         null_value: csv_options.null().to_owned(),
+        quote: (csv_options.quote() as char).to_string(),
+        escape: (csv_options.escape() as char).to_string(),
+        double_quote: csv_options.double_quote(),
+        quote_style: quote_style.to_string(),
     }
 }
 
@@ -1639,11 +1680,26 @@ pub(crate) fn csv_writer_options_from_proto(
             return Err(proto_error("Error parsing CSV Delimiter"));
         }
     }
+    if let Some(quote) = csv_char_from_proto(&writer_options.quote, "Quote")? {
+        builder = builder.with_quote(quote);
+    }
+    if let Some(escape) = csv_char_from_proto(&writer_options.escape, "Escape")? {
+        builder = builder.with_escape(escape);
+    }
     Ok(builder
         .with_header(writer_options.has_header)
+        .with_double_quote(writer_options.double_quote)
         .with_date_format(writer_options.date_format.clone())
         .with_datetime_format(writer_options.datetime_format.clone())
         .with_timestamp_format(writer_options.timestamp_format.clone())
         .with_time_format(writer_options.time_format.clone())
         .with_null(writer_options.null_value.clone()))
 }
+
+fn csv_char_from_proto(value: &str, name: &str) -> Result<Option<u8>> {
+    match value.chars().next() {
+        Some(c) if c.is_ascii() => Ok(Some(c as u8)),
+        Some(_) => Err(proto_error(format!("CSV {name} is not ASCII"))),
+        None => Ok(None),
+    }
+}
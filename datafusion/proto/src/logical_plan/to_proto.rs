@@ -45,7 +45,8 @@ use arrow::{
     record_batch::RecordBatch,
 };
 use datafusion_common::{
-    Column, Constraint, Constraints, DFSchema, DFSchemaRef, ScalarValue, TableReference,
+    Column, Constraint, Constraints, DFSchema, DFSchemaRef, JoinStrategyHint,
+    ScalarValue, TableReference,
 };
 use datafusion_expr::expr::{
     self, AggregateFunctionDefinition, Alias, Between, BinaryExpr, Cast, GetFieldAccess,
@@ -398,6 +399,14 @@ impl From<&AggregateFunction> for protobuf::AggregateFunction {
             AggregateFunction::LastValue => Self::LastValueAgg,
             AggregateFunction::NthValue => Self::NthValueAgg,
             AggregateFunction::StringAgg => Self::StringAgg,
+            AggregateFunction::ApproxDistinctSketch => Self::ApproxDistinctSketch,
+            AggregateFunction::ApproxDistinctMerge => Self::ApproxDistinctMerge,
+            AggregateFunction::ApproxPercentileContSketch => {
+                Self::ApproxPercentileContSketch
+            }
+            AggregateFunction::ApproxPercentileContMerge => {
+                Self::ApproxPercentileContMerge
+            }
         }
     }
 }
@@ -416,6 +425,7 @@ impl From<&BuiltInWindowFunction> for protobuf::BuiltInWindowFunction {
             BuiltInWindowFunction::Lag => Self::Lag,
             BuiltInWindowFunction::Lead => Self::Lead,
             BuiltInWindowFunction::DenseRank => Self::DenseRank,
+            BuiltInWindowFunction::SessionId => Self::SessionId,
         }
     }
 }
@@ -723,6 +733,18 @@ pub fn serialize_expr(
                     AggregateFunction::StringAgg => {
                         protobuf::AggregateFunction::StringAgg
                     }
+                    AggregateFunction::ApproxDistinctSketch => {
+                        protobuf::AggregateFunction::ApproxDistinctSketch
+                    }
+                    AggregateFunction::ApproxDistinctMerge => {
+                        protobuf::AggregateFunction::ApproxDistinctMerge
+                    }
+                    AggregateFunction::ApproxPercentileContSketch => {
+                        protobuf::AggregateFunction::ApproxPercentileContSketch
+                    }
+                    AggregateFunction::ApproxPercentileContMerge => {
+                        protobuf::AggregateFunction::ApproxPercentileContMerge
+                    }
                 };
 
                 let aggregate_expr = protobuf::AggregateExprNode {
@@ -742,22 +764,34 @@ pub fn serialize_expr(
                     expr_type: Some(ExprType::AggregateExpr(Box::new(aggregate_expr))),
                 }
             }
-            AggregateFunctionDefinition::UDF(fun) => protobuf::LogicalExprNode {
-                expr_type: Some(ExprType::AggregateUdfExpr(Box::new(
-                    protobuf::AggregateUdfExprNode {
-                        fun_name: fun.name().to_string(),
-                        args: serialize_exprs(args, codec)?,
-                        filter: match filter {
-                            Some(e) => Some(Box::new(serialize_expr(e.as_ref(), codec)?)),
-                            None => None,
-                        },
-                        order_by: match order_by {
-                            Some(e) => serialize_exprs(e, codec)?,
-                            None => vec![],
+            AggregateFunctionDefinition::UDF(fun) => {
+                let mut buf = Vec::new();
+                codec
+                    .try_encode_udaf(fun.as_ref(), &mut buf)
+                    .map_err(|e| Error::General(e.to_string()))?;
+
+                let fun_definition = if buf.is_empty() { None } else { Some(buf) };
+
+                protobuf::LogicalExprNode {
+                    expr_type: Some(ExprType::AggregateUdfExpr(Box::new(
+                        protobuf::AggregateUdfExprNode {
+                            fun_name: fun.name().to_string(),
+                            args: serialize_exprs(args, codec)?,
+                            filter: match filter {
+                                Some(e) => {
+                                    Some(Box::new(serialize_expr(e.as_ref(), codec)?))
+                                }
+                                None => None,
+                            },
+                            order_by: match order_by {
+                                Some(e) => serialize_exprs(e, codec)?,
+                                None => vec![],
+                            },
+                            fun_definition,
                         },
-                    },
-                ))),
-            },
+                    ))),
+                }
+            }
             AggregateFunctionDefinition::Name(_) => {
                 return Err(Error::NotImplemented(
                     "Proto serialization error: Trying to serialize a unresolved function"
@@ -1498,6 +1532,24 @@ impl From<JoinConstraint> for protobuf::JoinConstraint {
     }
 }
 
+impl From<Option<JoinStrategyHint>> for protobuf::JoinStrategyHint {
+    fn from(t: Option<JoinStrategyHint>) -> Self {
+        match t {
+            None => protobuf::JoinStrategyHint::NoHint,
+            Some(JoinStrategyHint::BroadcastLeft) => {
+                protobuf::JoinStrategyHint::BroadcastLeft
+            }
+            Some(JoinStrategyHint::BroadcastRight) => {
+                protobuf::JoinStrategyHint::BroadcastRight
+            }
+            Some(JoinStrategyHint::ShuffleHash) => {
+                protobuf::JoinStrategyHint::ShuffleHash
+            }
+            Some(JoinStrategyHint::SortMerge) => protobuf::JoinStrategyHint::SortMerge,
+        }
+    }
+}
+
 impl From<Constraints> for protobuf::Constraints {
     fn from(value: Constraints) -> Self {
         let constraints = value.into_iter().map(|item| item.into()).collect();
@@ -52,7 +52,7 @@ use datafusion_common::config::{
     ColumnOptions, CsvOptions, FormatOptions, JsonOptions, ParquetOptions,
     TableParquetOptions,
 };
-use datafusion_common::file_options::csv_writer::CsvWriterOptions;
+use datafusion_common::file_options::csv_writer::{CsvQuoteStyle, CsvWriterOptions};
 use datafusion_common::file_options::json_writer::JsonWriterOptions;
 use datafusion_common::parsers::CompressionTypeVariant;
 use datafusion_common::stats::Precision;
@@ -794,7 +794,12 @@ impl TryFrom<&protobuf::CsvWriterOptions> for CsvWriterOptions {
     fn try_from(opts: &protobuf::CsvWriterOptions) -> Result<Self, Self::Error> {
         let write_options = csv_writer_options_from_proto(opts)?;
         let compression: CompressionTypeVariant = opts.compression().into();
-        Ok(CsvWriterOptions::new(write_options, compression))
+        let quote_style = match opts.quote_style.as_str() {
+            "" => CsvQuoteStyle::default(),
+            quote_style => quote_style.parse()?,
+        };
+        Ok(CsvWriterOptions::new(write_options, compression)
+            .with_quote_style(quote_style))
     }
 }
 
@@ -803,7 +808,15 @@ impl TryFrom<&protobuf::JsonWriterOptions> for JsonWriterOptions {
 
     fn try_from(opts: &protobuf::JsonWriterOptions) -> Result<Self, Self::Error> {
         let compression: CompressionTypeVariant = opts.compression().into();
-        Ok(JsonWriterOptions::new(compression))
+        let millis = |ms: u64| (ms > 0).then(|| std::time::Duration::from_millis(ms));
+        Ok(JsonWriterOptions::new(compression)
+            .with_explicit_nulls(opts.explicit_nulls)
+            .with_max_file_size_bytes(
+                (opts.max_file_size_bytes > 0)
+                    .then_some(opts.max_file_size_bytes as usize),
+            )
+            .with_rotation_interval(millis(opts.rotation_interval_ms))
+            .with_flush_interval(millis(opts.flush_interval_ms)))
     }
 }
 
@@ -830,6 +843,12 @@ impl TryFrom<&protobuf::CsvOptions> for CsvOptions {
                 .then(|| proto_opts.time_format.clone()),
             null_value: (!proto_opts.null_value.is_empty())
                 .then(|| proto_opts.null_value.clone()),
+            double_quote: proto_opts.double_quote.first().map(|d| *d != 0),
+            quote_style: match proto_opts.quote_style.as_str() {
+                "" => CsvQuoteStyle::default(),
+                quote_style => quote_style.parse()?,
+            },
+            newlines_in_values: proto_opts.newlines_in_values,
         })
     }
 }
@@ -986,6 +1005,13 @@ impl TryFrom<&protobuf::JsonOptions> for JsonOptions {
         Ok(JsonOptions {
             compression: compression.into(),
             schema_infer_max_rec: proto_opts.schema_infer_max_rec as usize,
+            explicit_nulls: proto_opts.explicit_nulls,
+            max_file_size_bytes: (proto_opts.max_file_size_bytes > 0)
+                .then_some(proto_opts.max_file_size_bytes as usize),
+            rotation_interval_ms: (proto_opts.rotation_interval_ms > 0)
+                .then_some(proto_opts.rotation_interval_ms),
+            flush_interval_ms: (proto_opts.flush_interval_ms > 0)
+                .then_some(proto_opts.flush_interval_ms),
         })
     }
 }
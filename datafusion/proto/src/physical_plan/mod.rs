@@ -63,7 +63,7 @@ use datafusion::physical_plan::joins::utils::{ColumnIndex, JoinFilter};
 use datafusion::physical_plan::joins::{
     CrossJoinExec, NestedLoopJoinExec, StreamJoinPartitionMode, SymmetricHashJoinExec,
 };
-use datafusion::physical_plan::joins::{HashJoinExec, PartitionMode};
+use datafusion::physical_plan::joins::{HashJoinExec, MultiHashJoinExec, PartitionMode};
 use datafusion::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
 use datafusion::physical_plan::placeholder_row::PlaceholderRowExec;
 use datafusion::physical_plan::projection::ProjectionExec;
@@ -187,24 +187,30 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     )),
                 }
             }
-            PhysicalPlanType::CsvScan(scan) => Ok(Arc::new(CsvExec::new(
-                parse_protobuf_file_scan_config(
-                    scan.base_conf.as_ref().unwrap(),
-                    registry,
-                )?,
-                scan.has_header,
-                str_to_byte(&scan.delimiter, "delimiter")?,
-                str_to_byte(&scan.quote, "quote")?,
-                if let Some(protobuf::csv_scan_exec_node::OptionalEscape::Escape(
-                    escape,
-                )) = &scan.optional_escape
-                {
-                    Some(str_to_byte(escape, "escape")?)
-                } else {
-                    None
-                },
-                FileCompressionType::UNCOMPRESSED,
-            ))),
+            PhysicalPlanType::CsvScan(scan) => Ok(Arc::new(
+                CsvExec::new(
+                    parse_protobuf_file_scan_config(
+                        scan.base_conf.as_ref().unwrap(),
+                        registry,
+                    )?,
+                    scan.has_header,
+                    str_to_byte(&scan.delimiter, "delimiter")?,
+                    str_to_byte(&scan.quote, "quote")?,
+                    if let Some(protobuf::csv_scan_exec_node::OptionalEscape::Escape(
+                        escape,
+                    )) = &scan.optional_escape
+                    {
+                        Some(str_to_byte(escape, "escape")?)
+                    } else {
+                        None
+                    },
+                    FileCompressionType::UNCOMPRESSED,
+                )
+                .with_null_value(
+                    (!scan.null_value.is_empty()).then(|| scan.null_value.clone()),
+                )
+                .with_newlines_in_values(scan.newlines_in_values),
+            )),
             #[cfg(feature = "parquet")]
             PhysicalPlanType::ParquetScan(scan) => {
                 let base_config = parse_protobuf_file_scan_config(
@@ -260,7 +266,7 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     runtime,
                     extension_codec,
                 )?;
-                match repart.partition_method {
+                let partitioning = match repart.partition_method {
                     Some(PartitionMethod::Hash(ref hash_part)) => {
                         let expr = hash_part
                             .hash_expr
@@ -274,33 +280,26 @@ impl AsExecutionPlan for PhysicalPlanNode {
                                 )
                             })
                             .collect::<Result<Vec<Arc<dyn PhysicalExpr>>, _>>()?;
-
-                        Ok(Arc::new(RepartitionExec::try_new(
-                            input,
-                            Partitioning::Hash(
-                                expr,
-                                hash_part.partition_count.try_into().unwrap(),
-                            ),
-                        )?))
+                        Partitioning::Hash(
+                            expr,
+                            hash_part.partition_count.try_into().unwrap(),
+                        )
                     }
                     Some(PartitionMethod::RoundRobin(partition_count)) => {
-                        Ok(Arc::new(RepartitionExec::try_new(
-                            input,
-                            Partitioning::RoundRobinBatch(
-                                partition_count.try_into().unwrap(),
-                            ),
-                        )?))
+                        Partitioning::RoundRobinBatch(partition_count.try_into().unwrap())
                     }
                     Some(PartitionMethod::Unknown(partition_count)) => {
-                        Ok(Arc::new(RepartitionExec::try_new(
-                            input,
-                            Partitioning::UnknownPartitioning(
-                                partition_count.try_into().unwrap(),
-                            ),
-                        )?))
+                        Partitioning::UnknownPartitioning(
+                            partition_count.try_into().unwrap(),
+                        )
                     }
-                    _ => internal_err!("Invalid partitioning scheme"),
+                    _ => return internal_err!("Invalid partitioning scheme"),
+                };
+                let mut repartition = RepartitionExec::try_new(input, partitioning)?;
+                if repart.preserve_order {
+                    repartition = repartition.with_preserve_order();
                 }
+                Ok(Arc::new(repartition))
             }
             PhysicalPlanType::GlobalLimit(limit) => {
                 let input: Arc<dyn ExecutionPlan> =
@@ -636,6 +635,15 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     protobuf::PartitionMode::Partitioned => PartitionMode::Partitioned,
                     protobuf::PartitionMode::Auto => PartitionMode::Auto,
                 };
+                let strategy_hint = protobuf::JoinStrategyHint::try_from(
+                    hashjoin.strategy_hint,
+                )
+                .map_err(|_| {
+                    proto_error(format!(
+                        "Received a HashJoinNode message with unknown JoinStrategyHint {}",
+                        hashjoin.strategy_hint
+                    ))
+                })?;
                 let projection = if !hashjoin.projection.is_empty() {
                     Some(
                         hashjoin
@@ -647,16 +655,66 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 } else {
                     None
                 };
-                Ok(Arc::new(HashJoinExec::try_new(
-                    left,
-                    right,
-                    on,
-                    filter,
-                    &join_type.into(),
-                    projection,
-                    partition_mode,
-                    hashjoin.null_equals_null,
-                )?))
+                Ok(Arc::new(
+                    HashJoinExec::try_new(
+                        left,
+                        right,
+                        on,
+                        filter,
+                        &join_type.into(),
+                        projection,
+                        partition_mode,
+                        hashjoin.null_equals_null,
+                    )?
+                    .with_strategy_hint(strategy_hint.into())
+                    .with_null_aware(hashjoin.null_aware)?,
+                ))
+            }
+            PhysicalPlanType::MultiHashJoin(multi_join) => {
+                let builds = multi_join
+                    .builds
+                    .iter()
+                    .map(|build| {
+                        build.try_into_physical_plan(registry, runtime, extension_codec)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let probe = into_physical_plan(
+                    &multi_join.probe,
+                    registry,
+                    runtime,
+                    extension_codec,
+                )?;
+                let probe_schema = probe.schema();
+                let on = builds
+                    .iter()
+                    .zip(&multi_join.on)
+                    .map(|(build, on)| {
+                        let build_schema = build.schema();
+                        on.on
+                            .iter()
+                            .map(|col| {
+                                let left = parse_physical_expr(
+                                    col.left.as_ref().ok_or_else(|| {
+                                        proto_error("Missing required field in protobuf")
+                                    })?,
+                                    registry,
+                                    build_schema.as_ref(),
+                                    extension_codec,
+                                )?;
+                                let right = parse_physical_expr(
+                                    col.right.as_ref().ok_or_else(|| {
+                                        proto_error("Missing required field in protobuf")
+                                    })?,
+                                    registry,
+                                    probe_schema.as_ref(),
+                                    extension_codec,
+                                )?;
+                                Ok((left, right))
+                            })
+                            .collect::<Result<Vec<_>>>()
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Arc::new(MultiHashJoinExec::try_new(builds, probe, on)?))
             }
             PhysicalPlanType::SymmetricHashJoin(sym_join) => {
                 let left = into_physical_plan(
@@ -1291,6 +1349,60 @@ impl AsExecutionPlan for PhysicalPlanNode {
                         projection: exec.projection.as_ref().map_or_else(Vec::new, |v| {
                             v.iter().map(|x| *x as u32).collect::<Vec<u32>>()
                         }),
+                        strategy_hint: protobuf::JoinStrategyHint::from(
+                            exec.strategy_hint(),
+                        )
+                        .into(),
+                        null_aware: exec.null_aware(),
+                    },
+                ))),
+            });
+        }
+
+        if let Some(exec) = plan.downcast_ref::<MultiHashJoinExec>() {
+            let builds = exec
+                .builds()
+                .iter()
+                .map(|build| {
+                    protobuf::PhysicalPlanNode::try_from_physical_plan(
+                        build.clone(),
+                        extension_codec,
+                    )
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let probe = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.probe().clone(),
+                extension_codec,
+            )?;
+            let on = exec
+                .on()
+                .iter()
+                .map(|on| {
+                    let on = on
+                        .iter()
+                        .map(|(l, r)| {
+                            Ok(protobuf::JoinOn {
+                                left: Some(serialize_physical_expr(
+                                    l.clone(),
+                                    extension_codec,
+                                )?),
+                                right: Some(serialize_physical_expr(
+                                    r.clone(),
+                                    extension_codec,
+                                )?),
+                            })
+                        })
+                        .collect::<Result<_>>()?;
+                    Ok(protobuf::MultiHashJoinOn { on })
+                })
+                .collect::<Result<_>>()?;
+
+            return Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::MultiHashJoin(Box::new(
+                    protobuf::MultiHashJoinExecNode {
+                        builds,
+                        probe: Some(Box::new(probe)),
+                        on,
                     },
                 ))),
             });
@@ -1567,6 +1679,8 @@ impl AsExecutionPlan for PhysicalPlanNode {
                         } else {
                             None
                         },
+                        null_value: exec.null_value().unwrap_or_default().to_string(),
+                        newlines_in_values: exec.newlines_in_values(),
                     },
                 )),
             });
@@ -1643,6 +1757,7 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     protobuf::RepartitionExecNode {
                         input: Some(Box::new(input)),
                         partition_method: Some(pb_partition_method),
+                        preserve_order: exec.preserve_order(),
                     },
                 ))),
             });
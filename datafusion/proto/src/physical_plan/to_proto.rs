@@ -38,14 +38,16 @@ use datafusion::logical_expr::BuiltinScalarFunction;
 use datafusion::physical_expr::window::{NthValueKind, SlidingAggregateWindowExpr};
 use datafusion::physical_expr::{PhysicalSortExpr, ScalarFunctionExpr};
 use datafusion::physical_plan::expressions::{
-    ApproxDistinct, ApproxMedian, ApproxPercentileCont, ApproxPercentileContWithWeight,
-    ArrayAgg, Avg, BinaryExpr, BitAnd, BitOr, BitXor, BoolAnd, BoolOr, CaseExpr,
-    CastExpr, Column, Correlation, Count, Covariance, CovariancePop, CumeDist,
-    DistinctArrayAgg, DistinctBitXor, DistinctCount, DistinctSum, FirstValue, Grouping,
-    InListExpr, IsNotNullExpr, IsNullExpr, LastValue, Literal, Max, Median, Min,
-    NegativeExpr, NotExpr, NthValue, NthValueAgg, Ntile, OrderSensitiveArrayAgg, Rank,
-    RankType, Regr, RegrType, RowNumber, Stddev, StddevPop, StringAgg, Sum, TryCastExpr,
-    Variance, VariancePop, WindowShift,
+    ApproxDistinct, ApproxDistinctMerge, ApproxDistinctSketch, ApproxMedian,
+    ApproxPercentileCont, ApproxPercentileContMerge, ApproxPercentileContSketch,
+    ApproxPercentileContWithWeight, ArrayAgg, Avg, BinaryExpr, BitAnd, BitOr, BitXor,
+    BoolAnd, BoolOr, CaseExpr, CastExpr, Column, ConstantExpr, Correlation, Count,
+    Covariance, CovariancePop, CumeDist, DistinctAggregate, DistinctArrayAgg,
+    DistinctBitXor, DistinctCount, DistinctSum, FirstValue, Grouping, InListExpr,
+    IsNotNullExpr, IsNullExpr, LastValue, Literal, Max, Median, Min, NegativeExpr,
+    NotExpr, NthValue, NthValueAgg, Ntile, OrderSensitiveArrayAgg,
+    OrderSensitiveStringAgg, Rank, RankType, Regr, RegrType, RowNumber, SessionId,
+    Stddev, StddevPop, StringAgg, Sum, TryCastExpr, Variance, VariancePop, WindowShift,
 };
 use datafusion::physical_plan::udaf::AggregateFunctionExpr;
 use datafusion::physical_plan::windows::{BuiltInWindowExpr, PlainAggregateWindowExpr};
@@ -81,7 +83,13 @@ impl TryFrom<Arc<dyn AggregateExpr>> for protobuf::PhysicalExprNode {
         let codec = DefaultPhysicalExtensionCodec {};
         let expressions = serialize_physical_exprs(a.expressions(), &codec)?;
 
-        let ordering_req = a.order_bys().unwrap_or(&[]).to_vec();
+        // ARRAY_AGG(DISTINCT .. ORDER BY ..) sorts its values itself rather
+        // than requiring a sorted input
+        let ordering_req = match a.as_any().downcast_ref::<DistinctArrayAgg>() {
+            Some(distinct_array_agg) => distinct_array_agg.ordering_req(),
+            None => a.order_bys().unwrap_or(&[]),
+        }
+        .to_vec();
         let ordering_req = serialize_physical_sort_exprs(ordering_req, &codec)?;
 
         if let Some(a) = a.as_any().downcast_ref::<AggregateFunctionExpr>() {
@@ -190,6 +198,11 @@ impl TryFrom<Arc<dyn WindowExpr>> for protobuf::PhysicalWindowExprNode {
                         protobuf::BuiltInWindowFunction::NthValue
                     }
                 }
+            } else if let Some(session_id_expr) =
+                built_in_fn_expr.downcast_ref::<SessionId>()
+            {
+                args.insert(1, Arc::new(Literal::new(session_id_expr.get_gap().clone())));
+                protobuf::BuiltInWindowFunction::SessionId
             } else {
                 return not_impl_err!("BuiltIn function not supported: {expr:?}");
             };
@@ -271,6 +284,15 @@ fn aggr_expr_to_aggr_fn(expr: &dyn AggregateExpr) -> Result<AggrFn> {
     let aggr_expr = expr.as_any();
     let mut distinct = false;
 
+    if let Some(distinct_aggregate) = aggr_expr.downcast_ref::<DistinctAggregate>() {
+        let AggrFn { inner, .. } =
+            aggr_expr_to_aggr_fn(distinct_aggregate.aggregate().as_ref())?;
+        return Ok(AggrFn {
+            inner,
+            distinct: true,
+        });
+    }
+
     let inner = if aggr_expr.downcast_ref::<Count>().is_some() {
         protobuf::AggregateFunction::Count
     } else if aggr_expr.downcast_ref::<DistinctCount>().is_some() {
@@ -354,8 +376,27 @@ fn aggr_expr_to_aggr_fn(expr: &dyn AggregateExpr) -> Result<AggrFn> {
         protobuf::AggregateFunction::LastValueAgg
     } else if aggr_expr.downcast_ref::<StringAgg>().is_some() {
         protobuf::AggregateFunction::StringAgg
+    } else if aggr_expr
+        .downcast_ref::<OrderSensitiveStringAgg>()
+        .is_some()
+    {
+        protobuf::AggregateFunction::StringAgg
     } else if aggr_expr.downcast_ref::<NthValueAgg>().is_some() {
         protobuf::AggregateFunction::NthValueAgg
+    } else if aggr_expr.downcast_ref::<ApproxDistinctSketch>().is_some() {
+        protobuf::AggregateFunction::ApproxDistinctSketch
+    } else if aggr_expr.downcast_ref::<ApproxDistinctMerge>().is_some() {
+        protobuf::AggregateFunction::ApproxDistinctMerge
+    } else if aggr_expr
+        .downcast_ref::<ApproxPercentileContSketch>()
+        .is_some()
+    {
+        protobuf::AggregateFunction::ApproxPercentileContSketch
+    } else if aggr_expr
+        .downcast_ref::<ApproxPercentileContMerge>()
+        .is_some()
+    {
+        protobuf::AggregateFunction::ApproxPercentileContMerge
     } else {
         return not_impl_err!("Aggregate function not supported: {expr:?}");
     };
@@ -421,6 +462,10 @@ pub fn serialize_physical_expr(
                 },
             )),
         })
+    } else if let Some(expr) = expr.downcast_ref::<ConstantExpr>() {
+        // the cached value is not serialized, it is computed again when
+        // the deserialized plan is executed
+        serialize_physical_expr(expr.expr().clone(), codec)
     } else if let Some(expr) = expr.downcast_ref::<BinaryExpr>() {
         let binary_expr = Box::new(protobuf::PhysicalBinaryExprNode {
             l: Some(Box::new(serialize_physical_expr(
@@ -943,6 +988,7 @@ impl TryFrom<&CsvWriterOptions> for protobuf::CsvWriterOptions {
         Ok(csv_writer_options_to_proto(
             &opts.writer_options,
             &opts.compression,
+            &opts.quote_style,
         ))
     }
 }
@@ -954,6 +1000,16 @@ impl TryFrom<&JsonWriterOptions> for protobuf::JsonWriterOptions {
         let compression: protobuf::CompressionTypeVariant = opts.compression.into();
         Ok(protobuf::JsonWriterOptions {
             compression: compression.into(),
+            explicit_nulls: opts.explicit_nulls,
+            max_file_size_bytes: opts.max_file_size_bytes.unwrap_or_default() as u64,
+            rotation_interval_ms: opts
+                .rotation_interval
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or_default(),
+            flush_interval_ms: opts
+                .flush_interval
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or_default(),
         })
     }
 }
@@ -1068,6 +1124,9 @@ impl TryFrom<&CsvOptions> for protobuf::CsvOptions {
             timestamp_tz_format: opts.timestamp_tz_format.clone().unwrap_or_default(),
             time_format: opts.time_format.clone().unwrap_or_default(),
             null_value: opts.null_value.clone().unwrap_or_default(),
+            double_quote: opts.double_quote.map_or_else(Vec::new, |d| vec![d as u8]),
+            quote_style: opts.quote_style.to_string(),
+            newlines_in_values: opts.newlines_in_values,
         })
     }
 }
@@ -1080,6 +1139,10 @@ impl TryFrom<&JsonOptions> for protobuf::JsonOptions {
         Ok(protobuf::JsonOptions {
             compression: compression.into(),
             schema_infer_max_rec: opts.schema_infer_max_rec as u64,
+            explicit_nulls: opts.explicit_nulls,
+            max_file_size_bytes: opts.max_file_size_bytes.unwrap_or_default() as u64,
+            rotation_interval_ms: opts.rotation_interval_ms.unwrap_or_default(),
+            flush_interval_ms: opts.flush_interval_ms.unwrap_or_default(),
         })
     }
 }
@@ -1099,6 +1162,9 @@ impl TryFrom<&FormatOptions> for copy_to_node::FormatOptions {
             }
             FormatOptions::AVRO => copy_to_node::FormatOptions::Avro(AvroOptions {}),
             FormatOptions::ARROW => copy_to_node::FormatOptions::Arrow(ArrowOptions {}),
+            FormatOptions::ORC => {
+                return not_impl_err!("Serializing ORC format options is not supported")
+            }
         })
     }
 }
@@ -37,17 +37,19 @@ use datafusion_common::config::{FormatOptions, TableOptions};
 use datafusion_common::scalar::ScalarStructBuilder;
 use datafusion_common::{
     internal_datafusion_err, internal_err, not_impl_err, plan_err, DFSchema, DFSchemaRef,
-    DataFusionError, FileType, Result, ScalarValue,
+    DataFusionError, FileType, JoinStrategyHint, Result, ScalarValue,
 };
 use datafusion_expr::dml::CopyTo;
 use datafusion_expr::expr::{
     self, Between, BinaryExpr, Case, Cast, GroupingSet, InList, Like, ScalarFunction,
     Sort, Unnest,
 };
-use datafusion_expr::logical_plan::{Extension, UserDefinedLogicalNodeCore};
+use datafusion_expr::function::AccumulatorArgs;
+use datafusion_expr::logical_plan::{Extension, Join, UserDefinedLogicalNodeCore};
 use datafusion_expr::{
-    col, create_udaf, lit, Accumulator, AggregateFunction, ColumnarValue, Expr,
-    ExprSchemable, LogicalPlan, Operator, PartitionEvaluator, ScalarUDF, ScalarUDFImpl,
+    col, create_udaf, lit, Accumulator, AggregateFunction, AggregateUDF,
+    AggregateUDFImpl, ColumnarValue, Expr, ExprSchemable, JoinType, LogicalPlan,
+    LogicalPlanBuilder, Operator, PartitionEvaluator, ScalarUDF, ScalarUDFImpl,
     Signature, TryCast, Volatility, WindowFrame, WindowFrameBound, WindowFrameUnits,
     WindowFunctionDefinition, WindowUDF, WindowUDFImpl,
 };
@@ -251,6 +253,52 @@ async fn roundtrip_custom_listing_tables() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn roundtrip_logical_plan_join_hints() -> Result<()> {
+    let ctx = SessionContext::new();
+
+    for table in ["t1", "t2"] {
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE {table} (
+              a0 INTEGER,
+              a INTEGER,
+              b INTEGER,
+              c INTEGER,
+              d INTEGER
+            )
+            STORED AS CSV
+            WITH HEADER ROW
+            LOCATION '../core/tests/data/window_2.csv';"
+        ))
+        .await?;
+    }
+
+    let left = ctx.table("t1").await?.into_unoptimized_plan();
+    let right = ctx.table("t2").await?.into_unoptimized_plan();
+    let plan = LogicalPlanBuilder::from(left)
+        .join(
+            right,
+            JoinType::LeftAnti,
+            (vec!["t1.a"], vec!["t2.a"]),
+            None,
+        )?
+        .build()?;
+    let LogicalPlan::Join(join) = plan else {
+        return internal_err!("Expected a join");
+    };
+    let plan = LogicalPlan::Join(Join {
+        strategy_hint: Some(JoinStrategyHint::BroadcastRight),
+        null_aware: true,
+        ..join
+    });
+
+    let bytes = logical_plan_to_bytes(&plan)?;
+    let logical_round_trip = logical_plan_from_bytes(&bytes, &ctx)?;
+    assert_eq!(plan, logical_round_trip);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn roundtrip_logical_plan_aggregation_with_pk() -> Result<()> {
     let ctx = SessionContext::new();
@@ -672,6 +720,12 @@ pub mod proto {
         #[prost(string, tag = "1")]
         pub pattern: String,
     }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct MyAggregateUdfNode {
+        #[prost(string, tag = "1")]
+        pub result: String,
+    }
 }
 
 #[derive(PartialEq, Eq, Hash)]
@@ -840,9 +894,44 @@ impl ScalarUDFImpl for MyRegexUdf {
 }
 
 #[derive(Debug)]
-pub struct ScalarUDFExtensionCodec {}
+struct MyAggregateUDF {
+    signature: Signature,
+    // the constant result of the aggregation
+    result: String,
+}
+
+impl MyAggregateUDF {
+    fn new(result: String) -> Self {
+        Self {
+            signature: Signature::exact(vec![DataType::Float64], Volatility::Immutable),
+            result,
+        }
+    }
+}
 
-impl LogicalExtensionCodec for ScalarUDFExtensionCodec {
+/// Implement the AggregateUDFImpl trait for MyAggregateUDF
+impl AggregateUDFImpl for MyAggregateUDF {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "aggregate_udf"
+    }
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+    fn accumulator(&self, _acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        unimplemented!()
+    }
+}
+
+#[derive(Debug)]
+pub struct UDFExtensionCodec {}
+
+impl LogicalExtensionCodec for UDFExtensionCodec {
     fn try_decode(
         &self,
         _buf: &[u8],
@@ -898,6 +987,35 @@ impl LogicalExtensionCodec for ScalarUDFExtensionCodec {
         })?;
         Ok(())
     }
+
+    fn try_decode_udaf(&self, name: &str, buf: &[u8]) -> Result<Arc<AggregateUDF>> {
+        if name == "aggregate_udf" {
+            let proto = proto::MyAggregateUdfNode::decode(buf).map_err(|err| {
+                DataFusionError::Internal(format!(
+                    "failed to decode aggregate_udf: {}",
+                    err
+                ))
+            })?;
+
+            Ok(Arc::new(AggregateUDF::new_from_impl(MyAggregateUDF::new(
+                proto.result,
+            ))))
+        } else {
+            not_impl_err!("unrecognized aggregate UDF implementation, cannot decode")
+        }
+    }
+
+    fn try_encode_udaf(&self, node: &AggregateUDF, buf: &mut Vec<u8>) -> Result<()> {
+        let binding = node.inner();
+        let udaf = binding.as_any().downcast_ref::<MyAggregateUDF>().unwrap();
+        let proto = proto::MyAggregateUdfNode {
+            result: udaf.result.clone(),
+        };
+        proto.encode(buf).map_err(|e| {
+            DataFusionError::Internal(format!("failed to encode udaf: {e:?}"))
+        })?;
+        Ok(())
+    }
 }
 
 #[test]
@@ -1821,7 +1939,36 @@ fn roundtrip_scalar_udf_extension_codec() {
     let ctx = SessionContext::new();
     ctx.register_udf(udf);
 
-    let extension_codec = ScalarUDFExtensionCodec {};
+    let extension_codec = UDFExtensionCodec {};
+    let proto: protobuf::LogicalExprNode =
+        match serialize_expr(&test_expr, &extension_codec) {
+            Ok(p) => p,
+            Err(e) => panic!("Error serializing expression: {:?}", e),
+        };
+    let round_trip: Expr =
+        from_proto::parse_expr(&proto, &ctx, &extension_codec).unwrap();
+
+    assert_eq!(format!("{:?}", &test_expr), format!("{round_trip:?}"));
+
+    roundtrip_json_test(&proto);
+}
+
+#[test]
+fn roundtrip_aggregate_udf_extension_codec() {
+    let udaf = AggregateUDF::from(MyAggregateUDF::new("result".to_string()));
+    let test_expr = Expr::AggregateFunction(expr::AggregateFunction::new_udf(
+        Arc::new(udaf),
+        vec![lit(1.0_f64)],
+        false,
+        Some(Box::new(lit(true))),
+        None,
+        None,
+    ));
+
+    // the aggregate function is not registered, it is decoded by the codec
+    let ctx = SessionContext::new();
+
+    let extension_codec = UDFExtensionCodec {};
     let proto: protobuf::LogicalExprNode =
         match serialize_expr(&test_expr, &extension_codec) {
             Ok(p) => p,
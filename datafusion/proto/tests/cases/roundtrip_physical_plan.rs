@@ -50,7 +50,8 @@ use datafusion::physical_plan::expressions::{
 use datafusion::physical_plan::filter::FilterExec;
 use datafusion::physical_plan::insert::FileSinkExec;
 use datafusion::physical_plan::joins::{
-    HashJoinExec, NestedLoopJoinExec, PartitionMode, StreamJoinPartitionMode,
+    HashJoinExec, MultiHashJoinExec, NestedLoopJoinExec, PartitionMode,
+    StreamJoinPartitionMode,
 };
 use datafusion::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
 use datafusion::physical_plan::placeholder_row::PlaceholderRowExec;
@@ -71,7 +72,9 @@ use datafusion_common::file_options::csv_writer::CsvWriterOptions;
 use datafusion_common::file_options::json_writer::JsonWriterOptions;
 use datafusion_common::parsers::CompressionTypeVariant;
 use datafusion_common::stats::Precision;
-use datafusion_common::{not_impl_err, plan_err, DataFusionError, Result};
+use datafusion_common::{
+    not_impl_err, plan_err, DataFusionError, JoinStrategyHint, Result,
+};
 use datafusion_expr::{
     Accumulator, AccumulatorFactoryFunction, AggregateUDF, ColumnarValue,
     ScalarFunctionDefinition, ScalarUDF, ScalarUDFImpl, Signature, SimpleAggregateUDF,
@@ -228,6 +231,68 @@ fn roundtrip_hash_join() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn roundtrip_multi_hash_join() -> Result<()> {
+    let dim1 = Arc::new(Schema::new(vec![Field::new("d1", DataType::Int64, false)]));
+    let dim2 = Arc::new(Schema::new(vec![Field::new("d2", DataType::Int64, false)]));
+    let fact = Arc::new(Schema::new(vec![
+        Field::new("f1", DataType::Int64, false),
+        Field::new("f2", DataType::Int64, false),
+    ]));
+    let on = vec![
+        vec![(
+            Arc::new(Column::new("d1", 0)) as _,
+            Arc::new(Column::new("f1", 0)) as _,
+        )],
+        vec![(
+            Arc::new(Column::new("d2", 0)) as _,
+            Arc::new(Column::new("f2", 1)) as _,
+        )],
+    ];
+
+    roundtrip_test(Arc::new(MultiHashJoinExec::try_new(
+        vec![
+            Arc::new(EmptyExec::new(dim1)),
+            Arc::new(EmptyExec::new(dim2)),
+        ],
+        Arc::new(EmptyExec::new(fact)),
+        on,
+    )?))
+}
+
+#[test]
+fn roundtrip_hash_join_hints() -> Result<()> {
+    let field_a = Field::new("col", DataType::Int64, true);
+    let schema_left = Arc::new(Schema::new(vec![field_a.clone()]));
+    let schema_right = Arc::new(Schema::new(vec![field_a]));
+    let on = vec![(
+        Arc::new(Column::new("col", schema_left.index_of("col")?)) as _,
+        Arc::new(Column::new("col", schema_right.index_of("col")?)) as _,
+    )];
+
+    let join = HashJoinExec::try_new(
+        Arc::new(EmptyExec::new(schema_left)),
+        Arc::new(EmptyExec::new(schema_right)),
+        on,
+        None,
+        &JoinType::RightAnti,
+        None,
+        PartitionMode::CollectLeft,
+        false,
+    )?
+    .with_strategy_hint(Some(JoinStrategyHint::BroadcastLeft))
+    .with_null_aware(true)?;
+
+    let result = roundtrip_test_and_return(Arc::new(join))?;
+    let result = result.as_any().downcast_ref::<HashJoinExec>().unwrap();
+    assert_eq!(
+        result.strategy_hint(),
+        Some(JoinStrategyHint::BroadcastLeft)
+    );
+    assert!(result.null_aware());
+    Ok(())
+}
+
 #[test]
 fn roundtrip_nested_loop_join() -> Result<()> {
     let field_a = Field::new("col", DataType::Int64, false);
@@ -1028,6 +1093,30 @@ fn roundtrip_union() -> Result<()> {
     roundtrip_test(Arc::new(union))
 }
 
+#[test]
+fn roundtrip_repartition_preserve_order() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+    let input = RepartitionExec::try_new(
+        Arc::new(EmptyExec::new(schema.clone())),
+        Partitioning::RoundRobinBatch(2),
+    )?;
+    let sort_exprs = vec![PhysicalSortExpr {
+        expr: col("a", &schema)?,
+        options: SortOptions::default(),
+    }];
+    let sorted =
+        SortExec::new(sort_exprs, Arc::new(input)).with_preserve_partitioning(true);
+    let repartition =
+        RepartitionExec::try_new(Arc::new(sorted), Partitioning::RoundRobinBatch(4))?
+            .with_preserve_order();
+    assert!(repartition.preserve_order());
+
+    let result = roundtrip_test_and_return(Arc::new(repartition))?;
+    let result = result.as_any().downcast_ref::<RepartitionExec>().unwrap();
+    assert!(result.preserve_order());
+    Ok(())
+}
+
 #[test]
 fn roundtrip_interleave() -> Result<()> {
     let field_a = Field::new("col", DataType::Int64, false);
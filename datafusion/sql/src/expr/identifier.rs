@@ -46,7 +46,8 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             // interpret names with '.' as if they were
             // compound identifiers, but this is not a compound
             // identifier. (e.g. it is "foo.bar" not foo.bar)
-            let normalize_ident = self.normalizer.normalize(id);
+            let normalize_ident =
+                self.resolve_column_name(self.normalizer.normalize(id), schema);
             match schema.field_with_unqualified_name(normalize_ident.as_str()) {
                 Ok(_) => {
                     // found a match without a qualified name, this is a inner table column
@@ -58,9 +59,11 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 Err(_) => {
                     // check the outer_query_schema and try to find a match
                     if let Some(outer) = planner_context.outer_query_schema() {
-                        match outer.qualified_field_with_unqualified_name(
-                            normalize_ident.as_str(),
-                        ) {
+                        let outer_ident =
+                            self.resolve_column_name(normalize_ident.clone(), outer);
+                        match outer
+                            .qualified_field_with_unqualified_name(outer_ident.as_str())
+                        {
                             Ok((qualifier, field)) => {
                                 // found an exact match on a qualified name in the outer plan schema, so this is an outer reference column
                                 Ok(Expr::OuterReferenceColumn(
@@ -84,6 +87,48 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         }
     }
 
+    /// Returns the name of the column of `schema` that `name` refers to when
+    /// case insensitive columns are enabled, see
+    /// [`DFSchema::resolve_column_ignore_case`], or `name` otherwise
+    fn resolve_column_name(&self, name: String, schema: &DFSchema) -> String {
+        if !self.options.enable_case_insensitive_columns {
+            return name;
+        }
+        schema
+            .resolve_column_ignore_case(Column::new_unqualified(name))
+            .name
+    }
+
+    /// Searches `schema` for the column `ids` refer to like the free function
+    /// `search_dfschema`, and if case insensitive columns are enabled and
+    /// none is found, for the column whose name matches case insensitively
+    fn search_dfschema_ignore_case<'ids, 'schema>(
+        &self,
+        ids: &'ids [String],
+        schema: &'schema DFSchema,
+    ) -> Option<(
+        &'schema Field,
+        Option<&'schema TableReference>,
+        &'ids [String],
+    )> {
+        search_dfschema(ids, schema).or_else(|| {
+            if !self.options.enable_case_insensitive_columns {
+                return None;
+            }
+            generate_schema_search_terms(ids).find_map(
+                |(qualifier, column, nested_names)| {
+                    let column =
+                        schema.resolve_column_ignore_case(Column::new(qualifier, column));
+                    let qualifier_and_field = schema
+                        .qualified_field_with_name(column.relation.as_ref(), &column.name)
+                        .ok();
+                    qualifier_and_field
+                        .map(|(qualifier, field)| (field, qualifier, nested_names))
+                },
+            )
+        })
+    }
+
     pub(super) fn sql_compound_identifier_to_expr(
         &self,
         ids: Vec<Ident>,
@@ -121,7 +166,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 return internal_err!("Unsupported compound identifier: {ids:?}");
             }
 
-            let search_result = search_dfschema(&ids, schema);
+            let search_result = self.search_dfschema_ignore_case(&ids, schema);
             match search_result {
                 // found matching field with spare identifier(s) for nested field(s) in structure
                 Some((field, qualifier, nested_names)) if !nested_names.is_empty() => {
@@ -147,7 +192,8 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                     } else {
                         // check the outer_query_schema and try to find a match
                         if let Some(outer) = planner_context.outer_query_schema() {
-                            let search_result = search_dfschema(&ids, outer);
+                            let search_result =
+                                self.search_dfschema_ignore_case(&ids, outer);
                             match search_result {
                                 // found matching field with spare identifier(s) for nested field(s) in structure
                                 Some((field, qualifier, nested_names))
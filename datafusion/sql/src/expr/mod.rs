@@ -194,18 +194,13 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             }
 
             SQLExpr::MapAccess { column, keys } => {
-                if let SQLExpr::Identifier(id) = *column {
-                    self.plan_indexed(
-                        col(self.normalizer.normalize(id)),
-                        keys,
-                        schema,
-                        planner_context,
-                    )
+                let expr = if let SQLExpr::Identifier(id) = *column {
+                    col(self.normalizer.normalize(id))
                 } else {
-                    not_impl_err!(
-                        "map access requires an identifier, found column {column} instead"
-                    )
-                }
+                    // e.g. `make_map('a', 1)['a']`
+                    self.sql_expr_to_logical_expr(*column, schema, planner_context)?
+                };
+                self.plan_indexed(expr, keys, schema, planner_context)
             }
 
             SQLExpr::ArrayIndex { obj, indexes } => {
@@ -572,14 +567,22 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             SQLExpr::AtTimeZone {
                 timestamp,
                 time_zone,
-            } => Ok(Expr::Cast(Cast::new(
-                Box::new(self.sql_expr_to_logical_expr_internal(
+            } => {
+                let timestamp = self.sql_expr_to_logical_expr_internal(
                     *timestamp,
                     schema,
                     planner_context,
-                )?),
-                DataType::Timestamp(TimeUnit::Nanosecond, Some(time_zone.into())),
-            ))),
+                )?;
+                // keep the precision of timestamps, only changing their timezone
+                let unit = match timestamp.get_type(schema)? {
+                    DataType::Timestamp(unit, _) => unit,
+                    _ => TimeUnit::Nanosecond,
+                };
+                Ok(Expr::Cast(Cast::new(
+                    Box::new(timestamp),
+                    DataType::Timestamp(unit, Some(time_zone.into())),
+                )))
+            }
             _ => not_impl_err!("Unsupported ast node in sqltorel: {sql:?}"),
         }
     }
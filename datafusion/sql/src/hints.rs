@@ -0,0 +1,336 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Optimizer hints embedded in SQL comments, such as
+//! `SELECT /*+ BROADCAST(t2) */ * FROM t1 JOIN t2 ON t1.a = t2.a`
+
+use datafusion_common::tree_node::{Transformed, TransformedResult};
+use datafusion_common::{DFSchema, JoinStrategyHint, Result};
+use datafusion_expr::logical_plan::{Join, LogicalPlan};
+use sqlparser::dialect::Dialect;
+use sqlparser::keywords::Keyword;
+use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::{Token, Tokenizer, Whitespace};
+
+/// A single optimizer hint, e.g. `BROADCAST(t2)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqlHint {
+    /// Hint name, in upper case
+    pub name: String,
+    /// Hint arguments, typically relation names or aliases
+    pub args: Vec<String>,
+}
+
+impl SqlHint {
+    /// Create a hint with the given name and arguments
+    pub fn new(name: impl Into<String>, args: Vec<String>) -> Self {
+        Self {
+            name: name.into().to_uppercase(),
+            args,
+        }
+    }
+
+    /// The join strategy requested by this hint, if it is a join hint
+    fn join_strategy(&self, join: &Join) -> Option<JoinStrategyHint> {
+        match self.name.as_str() {
+            "BROADCAST" | "BROADCASTJOIN" | "MAPJOIN" => {
+                if self.names_relation(join.left.schema()) {
+                    Some(JoinStrategyHint::BroadcastLeft)
+                } else if self.names_relation(join.right.schema()) {
+                    Some(JoinStrategyHint::BroadcastRight)
+                } else {
+                    None
+                }
+            }
+            "SHUFFLE_HASH" => self
+                .names_join(join)
+                .then_some(JoinStrategyHint::ShuffleHash),
+            "MERGE" | "MERGEJOIN" | "SHUFFLE_MERGE" => {
+                self.names_join(join).then_some(JoinStrategyHint::SortMerge)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns true if the hint has no arguments, or names one of the inputs
+    /// of `join`
+    fn names_join(&self, join: &Join) -> bool {
+        self.args.is_empty()
+            || self.names_relation(join.left.schema())
+            || self.names_relation(join.right.schema())
+    }
+
+    /// Returns true if every column of `schema` belongs to a relation named
+    /// by one of the hint arguments
+    fn names_relation(&self, schema: &DFSchema) -> bool {
+        !schema.fields().is_empty()
+            && self.args.iter().any(|arg| {
+                schema.iter().all(|(qualifier, _)| {
+                    qualifier.is_some_and(|q| q.table().eq_ignore_ascii_case(arg))
+                })
+            })
+    }
+}
+
+/// Extracts the hints from `/*+ ... */` comments that directly follow a
+/// `SELECT` keyword in `sql`.
+///
+/// Hints are written as a list of `NAME` or `NAME(arg, ...)` items separated
+/// by whitespace or commas. Malformed hint comments are ignored, like any
+/// other comment.
+pub fn extract_hints(sql: &str, dialect: &dyn Dialect) -> Result<Vec<SqlHint>> {
+    if !sql.contains("/*+") {
+        return Ok(vec![]);
+    }
+
+    let tokens = Tokenizer::new(dialect, sql)
+        .tokenize()
+        .map_err(ParserError::from)?;
+
+    let mut hints = vec![];
+    let mut after_select = false;
+    for token in tokens {
+        match token {
+            Token::Word(w) if w.keyword == Keyword::SELECT => after_select = true,
+            Token::Whitespace(Whitespace::MultiLineComment(comment)) if after_select => {
+                if let Some(body) = comment.strip_prefix('+') {
+                    hints.extend(parse_hint_list(body).unwrap_or_default());
+                }
+            }
+            Token::Whitespace(_) => {}
+            _ => after_select = false,
+        }
+    }
+    Ok(hints)
+}
+
+/// Parses the body of a hint comment, returning `None` if it is malformed
+fn parse_hint_list(body: &str) -> Option<Vec<SqlHint>> {
+    let mut hints = vec![];
+    let mut chars = body.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace() || *c == ',').is_some() {}
+        if chars.peek().is_none() {
+            return Some(hints);
+        }
+
+        let name = parse_hint_word(&mut chars)?;
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let mut args = vec![];
+        if chars.next_if_eq(&'(').is_some() {
+            loop {
+                while chars.next_if(|c| c.is_whitespace() || *c == ',').is_some() {}
+                if chars.next_if_eq(&')').is_some() {
+                    break;
+                }
+                args.push(parse_hint_word(&mut chars)?);
+            }
+        }
+        hints.push(SqlHint::new(name, args));
+    }
+}
+
+fn parse_hint_word(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<String> {
+    let mut word = String::new();
+    while let Some(c) = chars.next_if(|c| c.is_alphanumeric() || *c == '_') {
+        word.push(c);
+    }
+    (!word.is_empty()).then_some(word)
+}
+
+/// Returns the number of partitions requested by a `/*+ REPARTITION(n) */`
+/// hint, which is used as the target number of partitions when planning and
+/// executing the query. When several `REPARTITION` hints are given, the first
+/// one wins; hints without a single positive number are ignored.
+pub fn target_partitions(hints: &[SqlHint]) -> Option<usize> {
+    hints.iter().find_map(|hint| match hint.args.as_slice() {
+        [partitions] if hint.name == "REPARTITION" => {
+            partitions.parse().ok().filter(|partitions| *partitions > 0)
+        }
+        _ => None,
+    })
+}
+
+/// Records the join strategy requested by `hints` on every matching join
+/// in `plan`, including joins inside subqueries.
+///
+/// `BROADCAST(t)` (also spelled `BROADCASTJOIN` or `MAPJOIN`) marks joins
+/// with `t` as one input so that `t` is collected into a single hash table,
+/// while `SHUFFLE_HASH` requests a partitioned hash join and `MERGE` (also
+/// spelled `MERGEJOIN` or `SHUFFLE_MERGE`) a sort-merge join, either for
+/// every join or only for joins of the relations they name. When several
+/// hints match a join, the first one wins. Unknown hints are ignored.
+pub fn apply_hints(plan: LogicalPlan, hints: &[SqlHint]) -> Result<LogicalPlan> {
+    if hints.is_empty() {
+        return Ok(plan);
+    }
+
+    plan.transform_up_with_subqueries(&|plan| match plan {
+        LogicalPlan::Join(join) if join.strategy_hint.is_none() => {
+            match hints.iter().find_map(|hint| hint.join_strategy(&join)) {
+                Some(hint) => Ok(Transformed::yes(LogicalPlan::Join(Join {
+                    strategy_hint: Some(hint),
+                    ..join
+                }))),
+                None => Ok(Transformed::no(LogicalPlan::Join(join))),
+            }
+        }
+        _ => Ok(Transformed::no(plan)),
+    })
+    .data()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_schema::{DataType, Field, Schema};
+    use datafusion_expr::logical_plan::table_scan;
+    use datafusion_expr::{JoinType, LogicalPlanBuilder};
+    use sqlparser::dialect::GenericDialect;
+
+    fn hints(sql: &str) -> Vec<SqlHint> {
+        extract_hints(sql, &GenericDialect {}).unwrap()
+    }
+
+    fn hint(name: &str, args: &[&str]) -> SqlHint {
+        SqlHint::new(name, args.iter().map(|a| a.to_string()).collect())
+    }
+
+    #[test]
+    fn extract() {
+        assert_eq!(
+            hints("SELECT /*+ BROADCAST(t2) */ * FROM t1 JOIN t2 ON t1.a = t2.a"),
+            vec![hint("BROADCAST", &["t2"])]
+        );
+        assert_eq!(
+            hints("select /*+ shuffle_hash, broadcast(a, b) */ 1"),
+            vec![hint("SHUFFLE_HASH", &[]), hint("BROADCAST", &["a", "b"])]
+        );
+        assert_eq!(
+            hints("WITH c AS (SELECT 1) SELECT\n/*+ SHUFFLE_HASH(c) */ * FROM c"),
+            vec![hint("SHUFFLE_HASH", &["c"])]
+        );
+    }
+
+    #[test]
+    fn extract_ignores_other_comments() {
+        // not directly after SELECT
+        assert_eq!(hints("SELECT a /*+ BROADCAST(t2) */ FROM t"), vec![]);
+        // not a hint comment
+        assert_eq!(hints("SELECT /* BROADCAST(t2) */ a FROM t"), vec![]);
+        // hint-like string literal
+        assert_eq!(hints("SELECT '/*+ BROADCAST(t2) */'"), vec![]);
+        // malformed
+        assert_eq!(hints("SELECT /*+ BROADCAST(t2 */ a FROM t"), vec![]);
+    }
+
+    fn join(left: &str, right: &str) -> Result<LogicalPlan> {
+        let schema = Schema::new(vec![Field::new("a", DataType::UInt32, false)]);
+        LogicalPlanBuilder::from(table_scan(Some(left), &schema, None)?.build()?)
+            .join(
+                table_scan(Some(right), &schema, None)?.build()?,
+                JoinType::Inner,
+                (vec!["a"], vec!["a"]),
+                None,
+            )?
+            .build()
+    }
+
+    fn applied(plan: LogicalPlan, hints: &[SqlHint]) -> Result<String> {
+        Ok(format!("{:?}", apply_hints(plan, hints)?))
+    }
+
+    #[test]
+    fn apply_broadcast() -> Result<()> {
+        let expected = "Inner Join: t1.a = t2.a Hint: BroadcastRight\
+        \n  TableScan: t1\
+        \n  TableScan: t2";
+        assert_eq!(
+            applied(join("t1", "t2")?, &[hint("BROADCAST", &["T2"])])?,
+            expected
+        );
+
+        let expected = "Inner Join: t1.a = t2.a Hint: BroadcastLeft\
+        \n  TableScan: t1\
+        \n  TableScan: t2";
+        assert_eq!(
+            applied(join("t1", "t2")?, &[hint("MAPJOIN", &["t1"])])?,
+            expected
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn apply_first_matching_hint() -> Result<()> {
+        let hints = [
+            hint("BROADCAST", &["t3"]),
+            hint("UNKNOWN", &["t1"]),
+            hint("SHUFFLE_HASH", &[]),
+            hint("BROADCAST", &["t2"]),
+        ];
+        let expected = "Inner Join: t1.a = t2.a Hint: ShuffleHash\
+        \n  TableScan: t1\
+        \n  TableScan: t2";
+        assert_eq!(applied(join("t1", "t2")?, &hints)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn apply_merge() -> Result<()> {
+        let expected = "Inner Join: t1.a = t2.a Hint: SortMerge\
+        \n  TableScan: t1\
+        \n  TableScan: t2";
+        assert_eq!(
+            applied(join("t1", "t2")?, &[hint("merge", &["t1"])])?,
+            expected
+        );
+        assert_eq!(
+            applied(join("t1", "t2")?, &[hint("SHUFFLE_MERGE", &[])])?,
+            expected
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn repartition() {
+        assert_eq!(target_partitions(&[]), None);
+        assert_eq!(
+            target_partitions(&[
+                hint("BROADCAST", &["t1"]),
+                hint("REPARTITION", &["0"]),
+                hint("REPARTITION", &["a", "b"]),
+                hint("repartition", &["64"]),
+                hint("REPARTITION", &["8"]),
+            ]),
+            Some(64)
+        );
+    }
+
+    #[test]
+    fn apply_no_matching_relation() -> Result<()> {
+        let hints = [
+            hint("BROADCAST", &["t3"]),
+            hint("SHUFFLE_HASH", &["t3"]),
+            hint("MERGE", &["t3"]),
+        ];
+        let expected = "Inner Join: t1.a = t2.a\
+        \n  TableScan: t1\
+        \n  TableScan: t2";
+        assert_eq!(applied(join("t1", "t2")?, &hints)?, expected);
+        Ok(())
+    }
+}
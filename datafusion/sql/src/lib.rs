@@ -30,12 +30,14 @@
 
 mod cte;
 mod expr;
+pub mod hints;
 pub mod parser;
 pub mod planner;
 mod query;
 mod relation;
 mod select;
 mod set_expr;
+pub mod span;
 mod statement;
 #[cfg(feature = "unparser")]
 pub mod unparser;
@@ -49,6 +49,8 @@ fn parse_file_type(s: &str) -> Result<String, ParserError> {
 pub struct ExplainStatement {
     pub analyze: bool,
     pub verbose: bool,
+    /// `EXPLAIN ESTIMATE`: show estimated cardinalities, sizes and memory
+    pub estimate: bool,
     pub statement: Box<Statement>,
 }
 
@@ -57,10 +59,14 @@ impl fmt::Display for ExplainStatement {
         let Self {
             analyze,
             verbose,
+            estimate,
             statement,
         } = self;
 
         write!(f, "EXPLAIN ")?;
+        if *estimate {
+            write!(f, "ESTIMATE ")?;
+        }
         if *analyze {
             write!(f, "ANALYZE ")?;
         }
@@ -516,7 +522,17 @@ impl<'a> DFParser<'a> {
 
     /// Parse a SQL `EXPLAIN`
     pub fn parse_explain(&mut self) -> Result<Statement, ParserError> {
-        let analyze = self.parser.parse_keyword(Keyword::ANALYZE);
+        // ESTIMATE is not a sqlparser keyword, so match the unquoted word
+        let estimate = match &self.parser.peek_token().token {
+            Token::Word(w) => {
+                w.quote_style.is_none() && w.value.eq_ignore_ascii_case("ESTIMATE")
+            }
+            _ => false,
+        };
+        if estimate {
+            self.parser.next_token(); // ESTIMATE
+        }
+        let analyze = !estimate && self.parser.parse_keyword(Keyword::ANALYZE);
         let verbose = self.parser.parse_keyword(Keyword::VERBOSE);
         let statement = self.parse_statement()?;
 
@@ -524,6 +540,7 @@ impl<'a> DFParser<'a> {
             statement: Box::new(statement),
             analyze,
             verbose,
+            estimate,
         }))
     }
 
@@ -1464,25 +1481,47 @@ mod tests {
     #[test]
     fn explain_copy_to_table_to_table() -> Result<(), ParserError> {
         let cases = vec![
-            ("EXPLAIN COPY foo TO bar STORED AS PARQUET", false, false),
+            (
+                "EXPLAIN COPY foo TO bar STORED AS PARQUET",
+                false,
+                false,
+                false,
+            ),
             (
                 "EXPLAIN ANALYZE COPY foo TO bar STORED AS PARQUET",
                 true,
                 false,
+                false,
             ),
             (
                 "EXPLAIN VERBOSE COPY foo TO bar STORED AS PARQUET",
                 false,
                 true,
+                false,
             ),
             (
                 "EXPLAIN ANALYZE VERBOSE COPY foo TO bar STORED AS PARQUET",
                 true,
                 true,
+                false,
+            ),
+            (
+                "EXPLAIN ESTIMATE COPY foo TO bar STORED AS PARQUET",
+                false,
+                false,
+                true,
+            ),
+            (
+                "EXPLAIN ESTIMATE VERBOSE COPY foo TO bar STORED AS PARQUET",
+                false,
+                true,
+                true,
             ),
         ];
-        for (sql, analyze, verbose) in cases {
-            println!("sql: {sql}, analyze: {analyze}, verbose: {verbose}");
+        for (sql, analyze, verbose, estimate) in cases {
+            println!(
+                "sql: {sql}, analyze: {analyze}, verbose: {verbose}, estimate: {estimate}"
+            );
 
             let expected_copy = Statement::CopyTo(CopyToStatement {
                 source: object_name("foo"),
@@ -1495,6 +1534,7 @@ mod tests {
             let expected = Statement::Explain(ExplainStatement {
                 analyze,
                 verbose,
+                estimate,
                 statement: Box::new(expected_copy),
             });
             assert_eq!(verified_stmt(sql), expected);
@@ -96,6 +96,7 @@ pub trait ContextProvider {
 pub struct ParserOptions {
     pub parse_float_as_decimal: bool,
     pub enable_ident_normalization: bool,
+    pub enable_case_insensitive_columns: bool,
 }
 
 impl Default for ParserOptions {
@@ -103,6 +104,7 @@ impl Default for ParserOptions {
         Self {
             parse_float_as_decimal: false,
             enable_ident_normalization: true,
+            enable_case_insensitive_columns: false,
         }
     }
 }
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use super::is_lateral;
 use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
 use datafusion_common::{not_impl_err, Column, Result};
 use datafusion_expr::{JoinType, LogicalPlan, LogicalPlanBuilder};
@@ -57,13 +58,66 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         }
     }
 
+    /// Plans a table that follows `left` in a comma separated `FROM` list and
+    /// starts with a `LATERAL` derived table, e.g. `FROM t1, LATERAL (...) t2`.
+    pub(crate) fn plan_lateral_table_with_joins(
+        &self,
+        left: LogicalPlan,
+        t: TableWithJoins,
+        planner_context: &mut PlannerContext,
+    ) -> Result<LogicalPlan> {
+        // `left, LATERAL x JOIN y` is planned as `(left CROSS JOIN LATERAL x) JOIN y`,
+        // which is only equivalent when `y` does not preserve unmatched rows
+        if let Some(join) = t.joins.iter().find(|join| {
+            !matches!(
+                join.join_operator,
+                JoinOperator::Inner(_)
+                    | JoinOperator::LeftOuter(_)
+                    | JoinOperator::LeftSemi(_)
+                    | JoinOperator::LeftAnti(_)
+                    | JoinOperator::CrossJoin
+            )
+        }) {
+            return not_impl_err!(
+                "Unsupported JOIN operator {:?} after a LATERAL subquery",
+                join.join_operator
+            );
+        }
+        let origin_planner_context = planner_context.clone();
+        let right = self.create_lateral_relation(t.relation, &left, planner_context)?;
+        let mut left = self.parse_cross_join(left, right)?;
+        for join in t.joins {
+            *planner_context = origin_planner_context.clone();
+            left = self.parse_relation_join(left, join, planner_context)?;
+        }
+        *planner_context = origin_planner_context;
+        Ok(left)
+    }
+
     fn parse_relation_join(
         &self,
         left: LogicalPlan,
         join: Join,
         planner_context: &mut PlannerContext,
     ) -> Result<LogicalPlan> {
-        let right = self.create_relation(join.relation, planner_context)?;
+        let right = if is_lateral(&join.relation) {
+            self.create_lateral_relation(join.relation, &left, planner_context)?
+        } else {
+            self.create_relation(join.relation, planner_context)?
+        };
+        if matches!(right, LogicalPlan::Subquery(_))
+            && !matches!(
+                join.join_operator,
+                JoinOperator::Inner(_)
+                    | JoinOperator::LeftOuter(_)
+                    | JoinOperator::CrossJoin
+            )
+        {
+            return not_impl_err!(
+                "Unsupported JOIN operator {:?} for a LATERAL subquery",
+                join.join_operator
+            );
+        }
         match join.join_operator {
             JoinOperator::LeftOuter(constraint) => {
                 self.parse_join(left, right, constraint, JoinType::Left, planner_context)
@@ -15,9 +15,15 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::sync::Arc;
+
 use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
-use datafusion_common::{not_impl_err, plan_err, DFSchema, Result, TableReference};
-use datafusion_expr::{expr::Unnest, Expr, LogicalPlan, LogicalPlanBuilder};
+use datafusion_common::{
+    not_impl_err, plan_err, DFSchema, Result, TableReference, UnnestOptions,
+};
+use datafusion_expr::{
+    expr::Unnest, lit, Expr, LogicalPlan, LogicalPlanBuilder, Subquery,
+};
 use sqlparser::ast::{FunctionArg, FunctionArgExpr, TableFactor};
 
 mod join;
@@ -99,28 +105,59 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             TableFactor::UNNEST {
                 alias,
                 array_exprs,
-                with_offset: false,
-                with_offset_alias: None,
+                with_offset,
+                with_offset_alias,
             } => {
                 // Unnest table factor has empty input
                 let schema = DFSchema::empty();
                 let input = LogicalPlanBuilder::empty(true).build()?;
-                let exprs = array_exprs
+                // Each array is unnested into its own column, and the arrays
+                // are zipped together
+                let unnest_exprs = array_exprs
                     .into_iter()
                     .map(|expr| {
-                        self.sql_expr_to_logical_expr(expr, &schema, planner_context)
+                        let expr = self.sql_expr_to_logical_expr(
+                            expr,
+                            &schema,
+                            planner_context,
+                        )?;
+                        Self::check_unnest_args(std::slice::from_ref(&expr), &schema)?;
+                        Ok(Expr::Unnest(Unnest { exprs: vec![expr] }))
                     })
                     .collect::<Result<Vec<_>>>()?;
-                Self::check_unnest_args(&exprs, &schema)?;
-                let unnest_expr = Expr::Unnest(Unnest { exprs });
-                let logical_plan = self.try_process_unnest(input, vec![unnest_expr])?;
+                let offset = with_offset.then(|| {
+                    with_offset_alias
+                        .map(|alias| self.normalizer.normalize(alias))
+                        .unwrap_or_else(|| "offset".to_string())
+                });
+
+                // Set preserve_nulls to false to ensure compatibility with DuckDB and PostgreSQL
+                let mut options = UnnestOptions::new().with_preserve_nulls(false);
+                if let Some(offset) = &offset {
+                    options = options.with_ordinality(offset.clone());
+                }
+                let mut logical_plan =
+                    self.try_process_unnest(input, unnest_exprs, options)?;
+                if let Some(offset) = offset {
+                    // The ordinality is 1-based, while offsets are 0-based
+                    let exprs = logical_plan
+                        .schema()
+                        .columns()
+                        .into_iter()
+                        .map(|column| {
+                            if column.name == offset {
+                                (Expr::Column(column) - lit(1i64)).alias(&offset)
+                            } else {
+                                Expr::Column(column)
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    logical_plan = LogicalPlanBuilder::from(logical_plan)
+                        .project(exprs)?
+                        .build()?;
+                }
                 (logical_plan, alias)
             }
-            TableFactor::UNNEST { .. } => {
-                return not_impl_err!(
-                    "UNNEST table factor with offset is not supported yet"
-                );
-            }
             // @todo Support TableFactory::TableFunction?
             _ => {
                 return not_impl_err!(
@@ -134,4 +171,37 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             Ok(plan)
         }
     }
+
+    /// Create a `LogicalPlan` for a `LATERAL` derived table, whose subquery
+    /// may refer to the columns of `outer`, the relation on its left.
+    ///
+    /// A correlated subquery is returned wrapped in a [`LogicalPlan::Subquery`]
+    /// so that the join it is the right side of can be decorrelated later on.
+    fn create_lateral_relation(
+        &self,
+        relation: TableFactor,
+        outer: &LogicalPlan,
+        planner_context: &mut PlannerContext,
+    ) -> Result<LogicalPlan> {
+        let old_outer_query_schema =
+            planner_context.set_outer_query_schema(Some(outer.schema().as_ref().clone()));
+        let plan = self.create_relation(relation, planner_context);
+        planner_context.set_outer_query_schema(old_outer_query_schema);
+        let plan = plan?;
+
+        let outer_ref_columns = plan.all_out_ref_exprs();
+        if outer_ref_columns.is_empty() {
+            // An uncorrelated LATERAL subquery is just a regular derived table
+            return Ok(plan);
+        }
+        Ok(LogicalPlan::Subquery(Subquery {
+            subquery: Arc::new(plan),
+            outer_ref_columns,
+        }))
+    }
+}
+
+/// Returns true if `relation` is a `LATERAL` derived table
+pub(crate) fn is_lateral(relation: &TableFactor) -> bool {
+    matches!(relation, TableFactor::Derived { lateral: true, .. })
 }
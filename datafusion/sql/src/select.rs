@@ -19,6 +19,7 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
+use crate::relation::is_lateral;
 use crate::utils::{
     check_columns_satisfy_exprs, extract_aliases, rebase_expr, resolve_aliases_to_exprs,
     resolve_columns, resolve_positions_to_exprs,
@@ -33,7 +34,7 @@ use datafusion_expr::expr_rewriter::{
 };
 use datafusion_expr::utils::{
     expand_qualified_wildcard, expand_wildcard, expr_as_column_expr, expr_to_columns,
-    find_aggregate_exprs, find_window_exprs,
+    find_aggregate_exprs, find_window_exprs, resolve_duplicate_names,
 };
 use datafusion_expr::{
     Expr, Filter, GroupingSet, LogicalPlan, LogicalPlanBuilder, Partitioning,
@@ -58,9 +59,6 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         if !select.lateral_views.is_empty() {
             return not_impl_err!("LATERAL VIEWS");
         }
-        if select.qualify.is_some() {
-            return not_impl_err!("QUALIFY");
-        }
         if select.top.is_some() {
             return not_impl_err!("TOP");
         }
@@ -85,6 +83,18 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             empty_from,
             planner_context,
         )?;
+        // rename or reject duplicate output names, e.g. of `SELECT *` over a join
+        let duplicate_names_policy = self
+            .context_provider
+            .options()
+            .sql_parser
+            .duplicate_column_names
+            .parse()?;
+        let select_exprs = resolve_duplicate_names(
+            select_exprs,
+            base_plan.schema(),
+            duplicate_names_policy,
+        )?;
 
         // having and group by clause may reference aliases defined in select projection
         let projected_plan = self.project(base_plan.clone(), select_exprs.clone())?;
@@ -124,9 +134,38 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             })
             .transpose()?;
 
+        // Optionally the QUALIFY expression, which may refer to aliased
+        // window functions, e.g.
+        //
+        //   SELECT c1, ROW_NUMBER() OVER (PARTITION BY c1) AS rn FROM t QUALIFY rn = 1;
+        //
+        let qualify_expr_opt = select
+            .qualify
+            .map::<Result<Expr>, _>(|qualify_expr| {
+                let qualify_expr = self.sql_expr_to_logical_expr(
+                    qualify_expr,
+                    &combined_schema,
+                    planner_context,
+                )?;
+                // aliases from the projection can conflict with same-named expressions in the input
+                let mut alias_map = alias_map.clone();
+                for f in base_plan.schema().fields() {
+                    alias_map.remove(f.name());
+                }
+                let qualify_expr = resolve_aliases_to_exprs(&qualify_expr, &alias_map)?;
+                normalize_col(qualify_expr, &projected_plan)
+            })
+            .transpose()?;
+
+        // The QUALIFY expression is planned along with the projection, so that
+        // its aggregates and window functions are computed, and is split off
+        // again before the final projection.
+        let mut select_exprs_with_qualify = select_exprs.clone();
+        select_exprs_with_qualify.extend(qualify_expr_opt.clone());
+
         // The outer expressions we will search through for
-        // aggregates. Aggregates may be sourced from the SELECT...
-        let mut aggr_expr_haystack = select_exprs.clone();
+        // aggregates. Aggregates may be sourced from the SELECT or QUALIFY...
+        let mut aggr_expr_haystack = select_exprs_with_qualify.clone();
         // ... or from the HAVING.
         if let Some(having_expr) = &having_expr_opt {
             aggr_expr_haystack.push(having_expr.clone());
@@ -153,8 +192,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                     let group_by_expr =
                         resolve_aliases_to_exprs(&group_by_expr, &alias_map)?;
                     let group_by_expr =
-                        resolve_positions_to_exprs(&group_by_expr, &select_exprs)
-                            .unwrap_or(group_by_expr);
+                        resolve_positions_to_exprs(group_by_expr, &select_exprs)?;
                     let group_by_expr = normalize_col(group_by_expr, &projected_plan)?;
                     self.validate_schema_satisfies_exprs(
                         base_plan.schema(),
@@ -186,7 +224,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         {
             self.aggregate(
                 &base_plan,
-                &select_exprs,
+                &select_exprs_with_qualify,
                 having_expr_opt.as_ref(),
                 &group_by_exprs,
                 &aggr_exprs,
@@ -194,7 +232,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         } else {
             match having_expr_opt {
                 Some(having_expr) => return plan_err!("HAVING clause references: {having_expr} must appear in the GROUP BY clause or be used in an aggregate function"),
-                None => (base_plan.clone(), select_exprs.clone(), having_expr_opt)
+                None => (base_plan.clone(), select_exprs_with_qualify, having_expr_opt)
             }
         };
 
@@ -223,8 +261,23 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             plan
         };
 
+        // process qualify clause
+        let mut qualify_exprs_post_window =
+            select_exprs_post_aggr.split_off(select_exprs.len());
+        let plan = if let Some(qualify_expr_post_window) = qualify_exprs_post_window.pop()
+        {
+            LogicalPlanBuilder::from(plan)
+                .filter(qualify_expr_post_window)?
+                .build()?
+        } else {
+            plan
+        };
+
         // try process unnest expression or do the final projection
-        let plan = self.try_process_unnest(plan, select_exprs_post_aggr)?;
+        // Set preserve_nulls to false to ensure compatibility with DuckDB and PostgreSQL
+        let unnest_options = UnnestOptions::new().with_preserve_nulls(false);
+        let plan =
+            self.try_process_unnest(plan, select_exprs_post_aggr, unnest_options)?;
 
         // process distinct clause
         let plan = match select.distinct {
@@ -236,8 +289,9 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 if !aggr_exprs.is_empty()
                     || !group_by_exprs.is_empty()
                     || !window_func_exprs.is_empty()
+                    || qualify_expr_opt.is_some()
                 {
-                    return not_impl_err!("DISTINCT ON expressions with GROUP BY, aggregation or window functions are not supported ");
+                    return not_impl_err!("DISTINCT ON expressions with GROUP BY, aggregation, window functions or QUALIFY are not supported ");
                 }
 
                 let on_expr = on_expr
@@ -278,15 +332,19 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
     }
 
     /// Try converting Expr(Unnest(Expr)) to Projection/Unnest/Projection
+    ///
+    /// All unnest expressions are unnested together with zip semantics. If
+    /// `options` has an ordinality column, it is added to the output.
     pub(super) fn try_process_unnest(
         &self,
         input: LogicalPlan,
         select_exprs: Vec<Expr>,
+        options: UnnestOptions,
     ) -> Result<LogicalPlan> {
         let mut unnest_columns = vec![];
         let mut inner_projection_exprs = vec![];
 
-        let outer_projection_exprs = select_exprs
+        let mut outer_projection_exprs = select_exprs
             .into_iter()
             .map(|expr| {
                 let Transformed {
@@ -296,11 +354,15 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 } = expr.transform_up_mut(&mut |expr: Expr| {
                     if let Expr::Unnest(Unnest { ref exprs }) = expr {
                         let column_name = expr.display_name()?;
-                        unnest_columns.push(column_name.clone());
-                        // Add alias for the argument expression, to avoid naming conflicts with other expressions
-                        // in the select list. For example: `select unnest(col1), col1 from t`.
-                        inner_projection_exprs
-                            .push(exprs[0].clone().alias(column_name.clone()));
+                        let column = Column::from_name(column_name.clone());
+                        // The same expression is only unnested once
+                        if !unnest_columns.contains(&column) {
+                            unnest_columns.push(column);
+                            // Add alias for the argument expression, to avoid naming conflicts with other expressions
+                            // in the select list. For example: `select unnest(col1), col1 from t`.
+                            inner_projection_exprs
+                                .push(exprs[0].clone().alias(column_name.clone()));
+                        }
                         Ok(Transformed::yes(Expr::Column(Column::from_name(
                             column_name,
                         ))))
@@ -332,15 +394,13 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 .project(inner_projection_exprs)?
                 .build()
         } else {
-            if unnest_columns.len() > 1 {
-                return not_impl_err!("Only support single unnest expression for now");
+            if let Some(ordinality) = &options.ordinality {
+                outer_projection_exprs
+                    .push(Expr::Column(Column::from_name(ordinality.clone())));
             }
-            let unnest_column = unnest_columns.pop().unwrap();
-            // Set preserve_nulls to false to ensure compatibility with DuckDB and PostgreSQL
-            let unnest_options = UnnestOptions::new().with_preserve_nulls(false);
             LogicalPlanBuilder::from(input)
                 .project(inner_projection_exprs)?
-                .unnest_column_with_options(unnest_column, unnest_options)?
+                .unnest_columns_with_options(unnest_columns, options)?
                 .project(outer_projection_exprs)?
                 .build()
         }
@@ -392,16 +452,19 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 self.plan_table_with_joins(from, planner_context)
             }
             _ => {
-                let mut plans = from
-                    .into_iter()
-                    .map(|t| self.plan_table_with_joins(t, planner_context));
+                let mut from = from.into_iter();
+                let mut left =
+                    self.plan_table_with_joins(from.next().unwrap(), planner_context)?;
 
-                let mut left = LogicalPlanBuilder::from(plans.next().unwrap()?);
-
-                for right in plans {
-                    left = left.cross_join(right?)?;
+                for t in from {
+                    left = if is_lateral(&t.relation) {
+                        self.plan_lateral_table_with_joins(left, t, planner_context)?
+                    } else {
+                        let right = self.plan_table_with_joins(t, planner_context)?;
+                        LogicalPlanBuilder::from(left).cross_join(right)?.build()?
+                    };
                 }
-                Ok(left.build()?)
+                Ok(left)
             }
         }
     }
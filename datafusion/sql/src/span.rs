@@ -0,0 +1,191 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Locates the parts of SQL statements that parsing and planning errors
+//! refer to, see [`with_sql_span`]
+
+use datafusion_common::{Column, DataFusionError, SchemaError, SqlSpan};
+use sqlparser::dialect::Dialect;
+use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::{Location, Token, TokenWithLocation, Tokenizer};
+
+/// Attaches the span of `sql` that `err` refers to, if it can be located,
+/// so that clients can underline it with [`DataFusionError::span`]:
+///
+/// * syntax errors refer to the unexpected token
+/// * unknown and ambiguous column references refer to the first occurrence
+///   of the column in the statement
+///
+/// `err` is returned unchanged if it already has a span, or if its span
+/// can't be located.
+pub fn with_sql_span(
+    err: DataFusionError,
+    sql: &str,
+    dialect: &dyn Dialect,
+) -> DataFusionError {
+    if err.span().is_some() {
+        return err;
+    }
+    let Ok(tokens) = Tokenizer::new(dialect, sql).tokenize_with_location() else {
+        return err;
+    };
+    let tokens = tokens
+        .into_iter()
+        .filter(|t| !matches!(t.token, Token::Whitespace(_)))
+        .collect::<Vec<_>>();
+    let span = match err.find_root() {
+        DataFusionError::SQL(ParserError::ParserError(message), _) => {
+            parser_error_location(message).and_then(|location| {
+                let token = tokens.iter().find(|t| {
+                    t.location.line == location.line
+                        && t.location.column == location.column
+                })?;
+                Some(SqlSpan {
+                    start: token.location,
+                    text: token.token.to_string(),
+                })
+            })
+        }
+        DataFusionError::SchemaError(
+            SchemaError::FieldNotFound { field, .. }
+            | SchemaError::AmbiguousReference { field },
+            _,
+        ) => column_span(&tokens, field),
+        _ => None,
+    };
+    match span {
+        Some(span) => err.with_span(span),
+        None => err,
+    }
+}
+
+/// Returns the location of a parser error of sqlparser, which is only
+/// reported as the `at Line: 1, Column 10` suffix of its message
+fn parser_error_location(message: &str) -> Option<Location> {
+    let (_, location) = message.rsplit_once(" at Line: ")?;
+    let (line, column) = location.split_once(", Column ")?;
+    let column = column
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(column, |end| &column[..end]);
+    Some(Location {
+        line: line.parse().ok()?,
+        column: column.parse().ok()?,
+    })
+}
+
+/// Returns the span of the first reference to `column` in `tokens`
+fn column_span(tokens: &[TokenWithLocation], column: &Column) -> Option<SqlSpan> {
+    // identifiers are compared case insensitively, as unquoted identifiers
+    // are normalized
+    let is_word = |token: Option<&TokenWithLocation>, value: &str| {
+        matches!(
+            token,
+            Some(TokenWithLocation { token: Token::Word(w), .. })
+                if w.value.eq_ignore_ascii_case(value)
+        )
+    };
+    tokens.iter().enumerate().find_map(|(i, token)| {
+        let span = match &column.relation {
+            Some(relation) => {
+                let is_reference = is_word(Some(token), relation.table())
+                    && tokens.get(i + 1).map(|t| &t.token) == Some(&Token::Period)
+                    && is_word(tokens.get(i + 2), &column.name);
+                is_reference.then(|| format!("{}.{}", token.token, tokens[i + 2].token))
+            }
+            None => {
+                // the name of a qualified reference is not a reference of
+                // the unqualified column
+                let is_qualified =
+                    i > 0 && tokens.get(i - 1).map(|t| &t.token) == Some(&Token::Period);
+                (!is_qualified && is_word(Some(token), &column.name))
+                    .then(|| token.token.to_string())
+            }
+        };
+        span.map(|text| SqlSpan {
+            start: token.location,
+            text,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datafusion_common::{
+        field_not_found, plan_datafusion_err, unqualified_field_not_found, DFSchema,
+    };
+    use sqlparser::dialect::GenericDialect;
+
+    use crate::parser::DFParser;
+
+    fn span(err: DataFusionError, sql: &str) -> Option<SqlSpan> {
+        with_sql_span(err, sql, &GenericDialect {}).span().cloned()
+    }
+
+    #[test]
+    fn span_of_syntax_error() {
+        let sql = "SELECT a\nFROM t WHERE b = = 1";
+        let err = DFParser::parse_sql(sql).unwrap_err();
+        assert_eq!(
+            span(err.into(), sql),
+            Some(SqlSpan {
+                start: Location {
+                    line: 2,
+                    column: 18
+                },
+                text: "=".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn span_of_unknown_column() {
+        let sql = "SELECT a, t.B FROM t";
+        let schema = DFSchema::empty();
+        let err = unqualified_field_not_found("b", &schema);
+        // the qualified reference does not match the unqualified column
+        assert_eq!(span(err, sql), None);
+
+        let err = field_not_found(Some("t"), "b", &schema);
+        assert_eq!(
+            span(err, sql),
+            Some(SqlSpan {
+                start: Location {
+                    line: 1,
+                    column: 11
+                },
+                text: "t.B".to_string(),
+            })
+        );
+
+        let err = unqualified_field_not_found("a", &schema).context("planning");
+        assert_eq!(
+            span(err, sql),
+            Some(SqlSpan {
+                start: Location { line: 1, column: 8 },
+                text: "a".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn no_span() {
+        let sql = "SELECT a FROM t";
+        let err = plan_datafusion_err!("table 't' not found");
+        assert_eq!(span(err, sql), None);
+    }
+}
@@ -48,7 +48,7 @@ use datafusion_expr::{
     CreateMemoryTable, CreateView, DescribeTable, DmlStatement, DropCatalogSchema,
     DropFunction, DropTable, DropView, EmptyRelation, Explain, ExprSchemable, Filter,
     LogicalPlan, LogicalPlanBuilder, OperateFunctionArg, PlanType, Prepare, SetVariable,
-    Statement as PlanStatement, ToStringifiedPlan, TransactionAccessMode,
+    Statement as PlanStatement, TableType, ToStringifiedPlan, TransactionAccessMode,
     TransactionConclusion, TransactionEnd, TransactionIsolationLevel, TransactionStart,
     Volatility, WriteOp,
 };
@@ -149,8 +149,9 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             DFStatement::Explain(ExplainStatement {
                 verbose,
                 analyze,
+                estimate,
                 statement,
-            }) => self.explain_to_plan(verbose, analyze, *statement),
+            }) => self.explain_to_plan(verbose, analyze, estimate, *statement),
         }
     }
 
@@ -190,9 +191,12 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 format: _,
                 describe_alias: _,
                 ..
-            } => {
-                self.explain_to_plan(verbose, analyze, DFStatement::Statement(statement))
-            }
+            } => self.explain_to_plan(
+                verbose,
+                analyze,
+                false,
+                DFStatement::Statement(statement),
+            ),
             Statement::Query(query) => self.query_to_plan(*query, planner_context),
             Statement::ShowVariable { variable } => self.show_variable_to_plan(&variable),
             Statement::SetVariable {
@@ -328,9 +332,14 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 })))
             }
             Statement::ShowCreate { obj_type, obj_name } => match obj_type {
-                ShowCreateObject::Table => self.show_create_table_to_plan(obj_name),
+                ShowCreateObject::Table => {
+                    self.show_create_table_to_plan(obj_name, false)
+                }
+                ShowCreateObject::View => self.show_create_table_to_plan(obj_name, true),
                 _ => {
-                    not_impl_err!("Only `SHOW CREATE TABLE  ...` statement is supported")
+                    not_impl_err!(
+                        "Only `SHOW CREATE TABLE ...` and `SHOW CREATE VIEW ...` statements are supported"
+                    )
                 }
             },
             Statement::CreateSchema {
@@ -967,11 +976,14 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         let inline_constraints = calc_inline_constraints_from_columns(&columns);
         all_constraints.extend(inline_constraints);
 
-        if (file_type == "PARQUET" || file_type == "AVRO" || file_type == "ARROW")
+        if (file_type == "PARQUET"
+            || file_type == "AVRO"
+            || file_type == "ARROW"
+            || file_type == "ORC")
             && file_compression_type != CompressionTypeVariant::UNCOMPRESSED
         {
             plan_err!(
-                "File compression type cannot be set for PARQUET, AVRO, or ARROW files."
+                "File compression type cannot be set for PARQUET, AVRO, ARROW, or ORC files."
             )?;
         }
 
@@ -1022,6 +1034,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         &self,
         verbose: bool,
         analyze: bool,
+        estimate: bool,
         statement: DFStatement,
     ) -> Result<LogicalPlan> {
         let plan = self.statement_to_plan(statement)?;
@@ -1043,6 +1056,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 vec![plan.to_stringified(PlanType::InitialLogicalPlan)];
             Ok(LogicalPlan::Explain(Explain {
                 verbose,
+                estimate,
                 plan,
                 stringified_plans,
                 schema,
@@ -1475,13 +1489,17 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         self.statement_to_plan(rewrite.pop_front().unwrap()) // length of rewrite is 1
     }
 
+    /// Plans `SHOW CREATE TABLE`, or `SHOW CREATE VIEW` if `view` is true,
+    /// which only accepts views
     fn show_create_table_to_plan(
         &self,
         sql_table_name: ObjectName,
+        view: bool,
     ) -> Result<LogicalPlan> {
+        let object_type = if view { "VIEW" } else { "TABLE" };
         if !self.has_table("information_schema", "tables") {
             return plan_err!(
-                "SHOW CREATE TABLE is not supported unless information_schema is enabled"
+                "SHOW CREATE {object_type} is not supported unless information_schema is enabled"
             );
         }
         // Figure out the where clause
@@ -1492,7 +1510,10 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
 
         // Do a table lookup to verify the table exists
         let table_ref = self.object_name_to_table_reference(sql_table_name)?;
-        let _ = self.context_provider.get_table_source(table_ref)?;
+        let source = self.context_provider.get_table_source(table_ref.clone())?;
+        if view && source.table_type() != TableType::View {
+            return plan_err!("'{table_ref}' is not a view");
+        }
 
         let query = format!(
             "SELECT table_catalog, table_schema, table_name, definition FROM information_schema.views WHERE {where_clause}"
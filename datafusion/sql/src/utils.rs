@@ -27,7 +27,9 @@ use datafusion_common::{
     exec_err, internal_err, plan_err, Column, DataFusionError, Result, ScalarValue,
 };
 use datafusion_expr::expr::{Alias, GroupingSet, WindowFunction};
-use datafusion_expr::utils::{expr_as_column_expr, find_column_exprs};
+use datafusion_expr::utils::{
+    expr_as_column_expr, find_aggregate_exprs, find_column_exprs,
+};
 use datafusion_expr::{expr_vec_fmt, Expr, LogicalPlan};
 use sqlparser::ast::Ident;
 
@@ -147,27 +149,67 @@ pub(crate) fn extract_aliases(exprs: &[Expr]) -> HashMap<String, Expr> {
         .collect::<HashMap<String, Expr>>()
 }
 
-/// Given an expression that's literal int encoding position, lookup the corresponding expression
-/// in the select_exprs list, if the index is within the bounds and it is indeed a position literal;
-/// Otherwise, return None
+/// Given a GROUP BY expression, replace the int literals encoding positions,
+/// either the expression itself or the elements of a `ROLLUP`, `CUBE` or
+/// `GROUPING SETS`, with the corresponding expression in the select_exprs list.
+///
+/// Returns an error if a position is out of the bounds of the select_exprs
+/// list or refers to an aggregate function.
 pub(crate) fn resolve_positions_to_exprs(
-    expr: &Expr,
+    expr: Expr,
     select_exprs: &[Expr],
-) -> Option<Expr> {
+) -> Result<Expr> {
+    let resolve_all = |exprs: Vec<Expr>| {
+        exprs
+            .into_iter()
+            .map(|expr| resolve_position_to_expr(expr, select_exprs))
+            .collect::<Result<Vec<_>>>()
+    };
+    match expr {
+        Expr::GroupingSet(GroupingSet::Rollup(exprs)) => {
+            Ok(Expr::GroupingSet(GroupingSet::Rollup(resolve_all(exprs)?)))
+        }
+        Expr::GroupingSet(GroupingSet::Cube(exprs)) => {
+            Ok(Expr::GroupingSet(GroupingSet::Cube(resolve_all(exprs)?)))
+        }
+        Expr::GroupingSet(GroupingSet::GroupingSets(sets)) => {
+            let sets = sets.into_iter().map(resolve_all).collect::<Result<_>>()?;
+            Ok(Expr::GroupingSet(GroupingSet::GroupingSets(sets)))
+        }
+        expr => resolve_position_to_expr(expr, select_exprs),
+    }
+}
+
+/// Resolve `expr` to the expression in the select_exprs list at its position,
+/// if it is an int literal encoding a 1 based position.
+fn resolve_position_to_expr(expr: Expr, select_exprs: &[Expr]) -> Result<Expr> {
     match expr {
         // sql_expr_to_logical_expr maps number to i64
         // https://github.com/apache/arrow-datafusion/blob/8d175c759e17190980f270b5894348dc4cff9bbf/datafusion/src/sql/planner.rs#L882-L887
-        Expr::Literal(ScalarValue::Int64(Some(position)))
-            if position > &0_i64 && position <= &(select_exprs.len() as i64) =>
-        {
-            let index = (position - 1) as usize;
-            let select_expr = &select_exprs[index];
-            Some(match select_expr {
+        Expr::Literal(ScalarValue::Int64(Some(position))) => {
+            if position <= 0 {
+                return plan_err!("Group by index starts at 1 for column indexes");
+            } else if position > select_exprs.len() as i64 {
+                return plan_err!(
+                    "Group by column out of bounds, specified: {}, max: {}",
+                    position,
+                    select_exprs.len()
+                );
+            }
+            let select_expr = match &select_exprs[(position - 1) as usize] {
                 Expr::Alias(Alias { expr, .. }) => *expr.clone(),
-                _ => select_expr.clone(),
-            })
+                select_expr => select_expr.clone(),
+            };
+            if !find_aggregate_exprs(&[select_expr.clone()]).is_empty() {
+                return plan_err!(
+                    "Group by column {} refers to an aggregate function: {}",
+                    position,
+                    select_expr
+                );
+            }
+            Ok(select_expr)
         }
-        _ => None,
+        expr => Ok(expr),
     }
 }
 
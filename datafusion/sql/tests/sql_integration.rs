@@ -80,6 +80,7 @@ fn parse_decimals() {
             ParserOptions {
                 parse_float_as_decimal: true,
                 enable_ident_normalization: false,
+                enable_case_insensitive_columns: false,
             },
         );
     }
@@ -133,6 +134,7 @@ fn parse_ident_normalization() {
             ParserOptions {
                 parse_float_as_decimal: false,
                 enable_ident_normalization,
+                enable_case_insensitive_columns: false,
             },
         );
         if plan.is_ok() {
@@ -1479,16 +1481,42 @@ fn select_simple_aggregate_with_groupby_position_out_of_range() {
     let sql = "SELECT state, MIN(age) FROM person GROUP BY 0";
     let err = logical_plan(sql).expect_err("query should have failed");
     assert_eq!(
-        "Error during planning: Projection references non-aggregate values: Expression person.state could not be resolved from available columns: Int64(0), MIN(person.age)",
-            err.strip_backtrace()
-        );
+        "Error during planning: Group by index starts at 1 for column indexes",
+        err.strip_backtrace()
+    );
 
     let sql2 = "SELECT state, MIN(age) FROM person GROUP BY 5";
     let err2 = logical_plan(sql2).expect_err("query should have failed");
     assert_eq!(
-        "Error during planning: Projection references non-aggregate values: Expression person.state could not be resolved from available columns: Int64(5), MIN(person.age)",
-            err2.strip_backtrace()
-        );
+        "Error during planning: Group by column out of bounds, specified: 5, max: 2",
+        err2.strip_backtrace()
+    );
+}
+
+#[test]
+fn select_simple_aggregate_with_groupby_position_of_aggregate() {
+    let sql = "SELECT state, MIN(age) AS m FROM person GROUP BY 2";
+    let err = logical_plan(sql).expect_err("query should have failed");
+    assert_eq!(
+        "Error during planning: Group by column 2 refers to an aggregate function: MIN(person.age)",
+        err.strip_backtrace()
+    );
+}
+
+#[test]
+fn select_simple_aggregate_with_rollup_can_use_positions() {
+    quick_test(
+        "SELECT state, age AS b, COUNT(1) FROM person GROUP BY ROLLUP (1, 2)",
+        "Projection: person.state, person.age AS b, COUNT(Int64(1))\
+             \n  Aggregate: groupBy=[[ROLLUP (person.state, person.age)]], aggr=[[COUNT(Int64(1))]]\
+             \n    TableScan: person",
+    );
+    quick_test(
+        "SELECT state, age AS b, COUNT(1) FROM person GROUP BY GROUPING SETS ((1), (b, 1))",
+        "Projection: person.state, person.age AS b, COUNT(Int64(1))\
+             \n  Aggregate: groupBy=[[GROUPING SETS ((person.state), (person.age, person.state))]], aggr=[[COUNT(Int64(1))]]\
+             \n    TableScan: person",
+    );
 }
 
 #[test]
@@ -1922,11 +1950,12 @@ fn create_external_table_with_compression_type() {
         "CREATE EXTERNAL TABLE t STORED AS PARQUET COMPRESSION TYPE BZIP2 LOCATION 'foo.parquet'",
         "CREATE EXTERNAL TABLE t STORED AS ARROW COMPRESSION TYPE GZIP LOCATION 'foo.arrow'",
         "CREATE EXTERNAL TABLE t STORED AS ARROW COMPRESSION TYPE BZIP2 LOCATION 'foo.arrow'",
+        "CREATE EXTERNAL TABLE t STORED AS ORC COMPRESSION TYPE GZIP LOCATION 'foo.orc'",
     ];
     for sql in sqls {
         let err = logical_plan(sql).expect_err("query should have failed");
         assert_eq!(
-            "Error during planning: File compression type cannot be set for PARQUET, AVRO, or ARROW files.",
+            "Error during planning: File compression type cannot be set for PARQUET, AVRO, ARROW, or ORC files.",
             err.strip_backtrace()
         );
     }
@@ -2333,6 +2362,26 @@ fn over_partition_by() {
     quick_test(sql, expected);
 }
 
+#[test]
+fn over_partition_by_qualify() {
+    let sql = "SELECT order_id, MAX(qty) OVER (PARTITION BY order_id) AS m FROM orders QUALIFY m > 10";
+    let expected = "\
+        Projection: orders.order_id, MAX(orders.qty) PARTITION BY [orders.order_id] ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING AS m\
+        \n  Filter: MAX(orders.qty) PARTITION BY [orders.order_id] ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING > Int64(10)\
+        \n    WindowAggr: windowExpr=[[MAX(orders.qty) PARTITION BY [orders.order_id] ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING]]\
+        \n      TableScan: orders";
+    quick_test(sql, expected);
+
+    // the window function may only be used by the QUALIFY clause
+    let sql = "SELECT order_id FROM orders QUALIFY MAX(qty) OVER (PARTITION BY order_id) = qty";
+    let expected = "\
+        Projection: orders.order_id\
+        \n  Filter: MAX(orders.qty) PARTITION BY [orders.order_id] ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING = orders.qty\
+        \n    WindowAggr: windowExpr=[[MAX(orders.qty) PARTITION BY [orders.order_id] ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING]]\
+        \n      TableScan: orders";
+    quick_test(sql, expected);
+}
+
 /// psql result
 /// ```text
 ///                                     QUERY PLAN
@@ -2988,6 +3037,58 @@ fn join_with_aliases() {
     quick_test(sql, expected);
 }
 
+#[test]
+fn cross_join_lateral() {
+    let sql = "SELECT p.id, o.qty FROM person p CROSS JOIN LATERAL \
+            (SELECT qty FROM orders WHERE orders.customer_id = p.id) o";
+    let expected = "Projection: p.id, o.qty\
+            \n  CrossJoin:\
+            \n    SubqueryAlias: p\
+            \n      TableScan: person\
+            \n    Subquery:\
+            \n      SubqueryAlias: o\
+            \n        Projection: orders.qty\
+            \n          Filter: orders.customer_id = outer_ref(p.id)\
+            \n            TableScan: orders";
+    quick_test(sql, expected);
+
+    // a comma separated LATERAL table is planned the same way
+    let sql = "SELECT p.id, o.qty FROM person p, LATERAL \
+            (SELECT qty FROM orders WHERE orders.customer_id = p.id) o";
+    quick_test(sql, expected);
+}
+
+#[test]
+fn left_join_lateral() {
+    let sql = "SELECT p.id, o.total FROM person p LEFT JOIN LATERAL \
+            (SELECT sum(qty) AS total FROM orders WHERE orders.customer_id = p.id) o ON true";
+    let expected = "Projection: p.id, o.total\
+            \n  Left Join:  Filter: Boolean(true)\
+            \n    SubqueryAlias: p\
+            \n      TableScan: person\
+            \n    Subquery:\
+            \n      SubqueryAlias: o\
+            \n        Projection: SUM(orders.qty) AS total\
+            \n          Aggregate: groupBy=[[]], aggr=[[SUM(orders.qty)]]\
+            \n            Filter: orders.customer_id = outer_ref(p.id)\
+            \n              TableScan: orders";
+    quick_test(sql, expected);
+}
+
+#[test]
+fn uncorrelated_lateral() {
+    let sql =
+        "SELECT p.id, o.qty FROM person p CROSS JOIN LATERAL (SELECT qty FROM orders) o";
+    let expected = "Projection: p.id, o.qty\
+            \n  CrossJoin:\
+            \n    SubqueryAlias: p\
+            \n      TableScan: person\
+            \n    SubqueryAlias: o\
+            \n      Projection: orders.qty\
+            \n        TableScan: orders";
+    quick_test(sql, expected);
+}
+
 #[test]
 fn negative_interval_plus_interval_in_projection() {
     let sql = "select -interval '2 days' + interval '5 days';";
@@ -3663,10 +3764,6 @@ fn test_select_distinct_order_by() {
     "SELECT id, number FROM person LATERAL VIEW explode(numbers) exploded_table AS number",
     "This feature is not implemented: LATERAL VIEWS"
 )]
-#[case::select_qualify_unsupported(
-    "SELECT i, p, o FROM person QUALIFY ROW_NUMBER() OVER (PARTITION BY p ORDER BY o) = 1",
-    "This feature is not implemented: QUALIFY"
-)]
 #[case::select_top_unsupported(
     "SELECT TOP (5) * FROM person",
     "This feature is not implemented: TOP"